@@ -6,12 +6,14 @@
 //!
 //! 1. Position the blackboard snapshot in the message array for cache alignment
 //! 2. Add Anthropic `cache_control` markers at the right positions
-//! 3. Track cache efficiency metrics
+//! 3. Track cache efficiency metrics, attributed per tool
 
 pub use super::snapshot::CacheThumbprint;
 
 use serde_json::Value;
 
+use super::snapshot::BlackboardSnapshot;
+
 /// Anthropic cache control marker.
 ///
 /// Insert this into the message array after the blackboard snapshot
@@ -147,3 +149,157 @@ impl CacheEfficiency {
         }
     }
 }
+
+// ─── Per-tool attribution ────────────────────────────────────────────────────
+
+/// Per-tool contribution to the blackboard and to cache misses.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ToolCacheStats {
+    /// Observations this tool posted.
+    pub observations: u64,
+    /// Rendered bytes those observations add to the snapshot.
+    pub bytes_on_board: u64,
+    /// Fresh (non-cached) prompt tokens attributed to this tool's observations.
+    pub attributed_fresh_tokens: u64,
+}
+
+/// Combined report from [`CacheEfficiencyTracker::report`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CacheEfficiencyReport {
+    pub total_prompt_tokens: u64,
+    pub cached_tokens: u64,
+    pub fresh_tokens: u64,
+    pub hit_ratio: f64,
+    /// Fresh tokens not attributable to any tool observation (task context,
+    /// conversation history, or calls made against an unchanged snapshot).
+    pub unattributed_fresh_tokens: u64,
+    pub per_tool: std::collections::BTreeMap<String, ToolCacheStats>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    efficiency: CacheEfficiency,
+    per_tool: std::collections::BTreeMap<String, ToolCacheStats>,
+    /// Observations posted but not yet seen in a snapshot used for an LLM call,
+    /// in posting order: (tool, entry hash, rendered bytes).
+    unattributed: Vec<(String, [u8; 32], usize)>,
+    unattributed_fresh_tokens: u64,
+}
+
+/// Cache efficiency tracker with per-tool attribution.
+///
+/// Wraps [`CacheEfficiency`] and implements
+/// [`ToolObserver`](crate::telemetry::ToolObserver) so a
+/// `BlackboardToolWrapper` can report observations as they are posted.
+///
+/// ## Attribution heuristic
+///
+/// A cache miss on the blackboard prefix happens because the snapshot changed.
+/// The entries responsible are the ones that entered the snapshot since the
+/// previous recorded call (i.e. since the previous thumbprint). So on each
+/// [`record_call`](Self::record_call):
+///
+/// 1. If the snapshot thumbprint equals the one from the previous call, the
+///    board did not change and all fresh tokens are left unattributed.
+/// 2. Otherwise, take the observations that are now present in the snapshot
+///    and have not been attributed yet. Split the call's fresh tokens between
+///    them in proportion to their rendered size. Integer remainders go to the
+///    earliest-posted observations, one token each, so totals are exact and
+///    deterministic.
+/// 3. Observations posted but still pending (not in the snapshot) wait for a
+///    later call. If none of the new entries came from tools, the fresh
+///    tokens are left unattributed.
+///
+/// This deliberately over-attributes: some fresh tokens on a changed snapshot
+/// come from the task prompt or history, not the new entries. It answers
+/// "which tools are churning the prefix", not an exact token accounting.
+#[derive(Debug, Default)]
+pub struct CacheEfficiencyTracker {
+    state: parking_lot::Mutex<TrackerState>,
+}
+
+impl CacheEfficiencyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an observation posted by `tool_name`.
+    pub fn record_observation(&self, tool_name: &str, entry_hash: [u8; 32], rendered_bytes: usize) {
+        let mut state = self.state.lock();
+        let stats = state.per_tool.entry(tool_name.to_string()).or_default();
+        stats.observations += 1;
+        stats.bytes_on_board += rendered_bytes as u64;
+        state
+            .unattributed
+            .push((tool_name.to_string(), entry_hash, rendered_bytes));
+    }
+
+    /// Record an LLM call made against `snapshot`, and attribute its fresh
+    /// tokens per the heuristic documented on the type.
+    pub fn record_call(&self, snapshot: &BlackboardSnapshot, total_prompt: u64, cached: u64) {
+        let mut state = self.state.lock();
+        let fresh = total_prompt.saturating_sub(cached);
+        let changed = state.efficiency.active_thumbprint != Some(snapshot.thumbprint);
+        state.efficiency.record_call(total_prompt, cached);
+        state.efficiency.active_thumbprint = Some(snapshot.thumbprint);
+
+        if !changed {
+            state.unattributed_fresh_tokens += fresh;
+            return;
+        }
+
+        let in_snapshot: std::collections::HashSet<[u8; 32]> =
+            snapshot.entries.iter().map(|e| e.content_hash).collect();
+        let (newly_visible, still_pending): (Vec<_>, Vec<_>) = std::mem::take(&mut state.unattributed)
+            .into_iter()
+            .partition(|(_, hash, _)| in_snapshot.contains(hash));
+        state.unattributed = still_pending;
+
+        let total_bytes: u64 = newly_visible.iter().map(|(_, _, b)| *b as u64).sum();
+        if total_bytes == 0 {
+            state.unattributed_fresh_tokens += fresh;
+            return;
+        }
+
+        let mut shares: Vec<u64> = newly_visible
+            .iter()
+            .map(|(_, _, b)| fresh * *b as u64 / total_bytes)
+            .collect();
+        let mut remainder = fresh - shares.iter().sum::<u64>();
+        for share in shares.iter_mut() {
+            if remainder == 0 {
+                break;
+            }
+            *share += 1;
+            remainder -= 1;
+        }
+
+        for ((tool, _, _), share) in newly_visible.iter().zip(shares) {
+            state.per_tool.entry(tool.clone()).or_default().attributed_fresh_tokens += share;
+        }
+    }
+
+    /// Snapshot of the aggregate efficiency counters.
+    pub fn efficiency(&self) -> CacheEfficiency {
+        self.state.lock().efficiency.clone()
+    }
+
+    /// Combined report: overall efficiency plus per-tool contribution.
+    pub fn report(&self) -> CacheEfficiencyReport {
+        let state = self.state.lock();
+        CacheEfficiencyReport {
+            total_prompt_tokens: state.efficiency.total_prompt_tokens,
+            cached_tokens: state.efficiency.cached_tokens,
+            fresh_tokens: state.efficiency.fresh_tokens,
+            hit_ratio: state.efficiency.hit_ratio(),
+            unattributed_fresh_tokens: state.unattributed_fresh_tokens,
+            per_tool: state.per_tool.clone(),
+        }
+    }
+}
+
+impl crate::telemetry::ToolObserver for CacheEfficiencyTracker {
+    fn on_observation_posted(&self, tool_name: &str, entry_hash: [u8; 32], rendered_bytes: usize) {
+        self.record_observation(tool_name, entry_hash, rendered_bytes);
+    }
+}
@@ -16,6 +16,16 @@ use std::collections::HashMap;
 type Fingerprint = String;
 type PolicyDecision = serde_json::Value;
 
+/// Current on-disk schema version for `BlackboardEntry`.
+///
+/// Bumped whenever a field is added or changes meaning, so persisted
+/// snapshots and write-ahead logs can be migrated on load.
+pub const ENTRY_SCHEMA_VERSION: u32 = 1;
+
+fn default_schema_version() -> u32 {
+    ENTRY_SCHEMA_VERSION
+}
+
 /// What kind of assertion this entry represents.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -39,28 +49,27 @@ pub enum EntryType {
 }
 
 /// Tier determines TTL behavior and storage priority.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EntryTier {
     /// Short-lived, expires per config TTL. Observations, partial results.
     Stm,
     /// Session-scoped. Survives within a crew run but not across runs.
+    #[default]
     Session,
     /// Persistent. Survives across crew runs. Decisions, verified facts.
     Ltm,
 }
 
-impl Default for EntryTier {
-    fn default() -> Self {
-        Self::Session
-    }
-}
-
 /// A single blackboard entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlackboardEntry {
     // ── Identity ────────────────────────────────────────────────────
 
+    /// Schema version this entry was written with.
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+
     /// Content hash: SHA-256(author + content + parent_hash).
     /// This IS the primary key and the cache reference thumbprint.
     /// Computed on construction, never changes.
@@ -143,6 +152,7 @@ impl BlackboardEntry {
         let content = content.into();
         let content_hash = Self::compute_hash(&author, &content, parent_hash.as_ref());
         Self {
+            schema_version: ENTRY_SCHEMA_VERSION,
             content_hash,
             author,
             entry_type,
@@ -161,6 +171,13 @@ impl BlackboardEntry {
         }
     }
 
+    /// Re-attribute the entry to `author`, recomputing its content hash.
+    pub fn with_author(mut self, author: Fingerprint) -> Self {
+        self.content_hash = Self::compute_hash(&author, &self.content, self.parent_hash.as_ref());
+        self.author = author;
+        self
+    }
+
     /// Compute SHA-256(author + content + parent_hash).
     fn compute_hash(
         author: &str,
@@ -246,10 +263,10 @@ impl BlackboardEntry {
         if self.tombstoned {
             return true;
         }
-        let ttl = self.ttl.unwrap_or_else(|| match self.tier {
+        let ttl = self.ttl.unwrap_or(match self.tier {
             EntryTier::Stm => default_stm_ttl,
-            EntryTier::Session => Duration::max_value(),
-            EntryTier::Ltm => Duration::max_value(),
+            EntryTier::Session => Duration::MAX,
+            EntryTier::Ltm => Duration::MAX,
         });
         Utc::now() - self.created_at > ttl
     }
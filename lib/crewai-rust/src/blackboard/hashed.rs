@@ -7,16 +7,24 @@
 //! pruning vs tombstoning. Uses DashMap for concurrent access.
 //!
 //! Storage: in-memory (DashMap) + optional SQLite for persistence.
+//!
+//! When opened with [`HashedBlackboard::open`], live entries are persisted to
+//! SQLite on every epoch boundary and mid-epoch operations go to a JSONL
+//! write-ahead log (see [`super::wal`]), so a crash loses nothing that was
+//! successfully posted.
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use dashmap::DashMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 
-use super::entry::{BlackboardEntry, EntryType, EntryTier};
+use super::entry::{BlackboardEntry, EntryType};
 use super::store::{BlackboardStore, BlackboardQuery, BlackboardResult, BlackboardError, CompactionStats};
 use super::snapshot::{BlackboardSnapshot, CacheThumbprint};
+use super::persistence::SnapshotPersistence;
+use super::wal::{WalOp, WriteAheadLog};
 use super::BlackboardConfig;
 
 /// Hashed blackboard — the content-addressed, epoch-aware implementation.
@@ -44,6 +52,17 @@ pub struct HashedBlackboard {
 
     /// Cached snapshot (invalidated on epoch advance).
     cached_snapshot: RwLock<Option<BlackboardSnapshot>>,
+
+    /// Write-ahead log for mid-epoch operations. `None` = in-memory only.
+    wal: Option<WriteAheadLog>,
+
+    /// SQLite store for epoch snapshots. `None` = in-memory only.
+    persistence: Option<SnapshotPersistence>,
+
+    /// Held across every WAL-logged mutation and across an epoch advance, so
+    /// a post can't log its record after the epoch was promoted but before
+    /// the WAL was truncated (which would drop the record).
+    write_lock: Mutex<()>,
 }
 
 impl HashedBlackboard {
@@ -58,9 +77,150 @@ impl HashedBlackboard {
             canonical_order: RwLock::new(Vec::new()),
             epoch: AtomicU64::new(0),
             cached_snapshot: RwLock::new(None),
+            wal: None,
+            persistence: None,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Open a durable blackboard backed by `<dir>/<board_name>.db` (epoch
+    /// snapshots) and `<dir>/<board_name>.wal.jsonl` (mid-epoch operations).
+    ///
+    /// Recovery: load the last persisted snapshot, then replay the WAL on top.
+    /// Replay is idempotent — posts dedup by hash, tombstones are sticky, and
+    /// epoch advances at or below the snapshot epoch are skipped — so a crash
+    /// between persisting and truncating the WAL is harmless. A record torn
+    /// by a crash mid-append is cut off the end of the WAL before new posts
+    /// are logged.
+    pub fn open(config: BlackboardConfig, dir: impl AsRef<Path>, board_name: &str) -> BlackboardResult<Self> {
+        let dir = dir.as_ref();
+        let fsync = config.wal_fsync;
+        let mut board = Self::new(config);
+
+        let persistence = SnapshotPersistence::open(dir, board_name)?;
+        let state = persistence.load()?;
+        {
+            let mut order = board.canonical_order.write();
+            for entry in state.entries {
+                board.index_entry(&entry);
+                order.push(entry.content_hash);
+                board.live.insert(entry.content_hash, entry);
+            }
+        }
+        board.epoch.store(state.epoch, Ordering::Relaxed);
+
+        let wal = WriteAheadLog::open(dir, board_name, fsync)?;
+        let records = wal.recover()?;
+        let replayed = records.len();
+        for record in records {
+            match record.op {
+                WalOp::Post { entry } => board.apply_post(*entry),
+                WalOp::Tombstone { hash } => {
+                    // An entry compacted away after the tombstone was logged
+                    // is already gone; nothing to restore.
+                    let _ = board.apply_tombstone(&hash);
+                }
+                WalOp::AdvanceEpoch { epoch } => {
+                    if epoch > board.epoch.load(Ordering::Relaxed) {
+                        board.promote_pending();
+                    }
+                }
+            }
+        }
+        if replayed > 0 {
+            log::info!(
+                "Hashed blackboard '{}': replayed {} WAL records on top of epoch {} snapshot",
+                board_name,
+                replayed,
+                state.epoch
+            );
+        }
+
+        board.wal = Some(wal);
+        board.persistence = Some(persistence);
+        Ok(board)
+    }
+
+    /// Append an operation to the WAL, if one is attached.
+    fn log_op(&self, op: WalOp) -> BlackboardResult<()> {
+        match self.wal {
+            Some(ref wal) => wal.append(op),
+            None => Ok(()),
         }
     }
 
+    /// Persist live entries in canonical order. Returns `true` if a snapshot
+    /// was written (i.e. persistence is attached and the write succeeded).
+    fn persist_live(&self, order: &[[u8; 32]]) -> bool {
+        let Some(ref persistence) = self.persistence else {
+            return false;
+        };
+        let entries: Vec<BlackboardEntry> = order
+            .iter()
+            .filter_map(|hash| self.live.get(hash).map(|e| e.clone()))
+            .collect();
+        match persistence.save(self.epoch.load(Ordering::Relaxed), &entries) {
+            Ok(()) => true,
+            Err(e) => {
+                log::error!("Failed to persist blackboard snapshot: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Insert an entry into the pending buffer (no WAL write).
+    fn apply_post(&self, entry: BlackboardEntry) {
+        let hash = entry.content_hash;
+        if self.live.contains_key(&hash) || self.pending.contains_key(&hash) {
+            return;
+        }
+
+        // Handle supersession: if this entry supersedes others, tombstone them.
+        for superseded_hash in &entry.supersedes {
+            if let Some(mut old) = self.live.get_mut(superseded_hash) {
+                old.tombstoned = true;
+            }
+        }
+
+        self.index_entry(&entry);
+        self.pending.insert(hash, entry);
+        *self.cached_snapshot.write() = None;
+    }
+
+    /// Tombstone a live or pending entry (no WAL write).
+    fn apply_tombstone(&self, hash: &[u8; 32]) -> BlackboardResult<()> {
+        if let Some(mut entry) = self.live.get_mut(hash) {
+            entry.tombstoned = true;
+            *self.cached_snapshot.write() = None;
+            return Ok(());
+        }
+        if let Some(mut entry) = self.pending.get_mut(hash) {
+            entry.tombstoned = true;
+            return Ok(());
+        }
+        Err(BlackboardError::NotFound("Entry not found".to_string()))
+    }
+
+    /// Move all pending entries into live and bump the epoch (no WAL write,
+    /// no persistence). Returns the new epoch.
+    fn promote_pending(&self) -> u64 {
+        let mut order = self.canonical_order.write();
+
+        for entry in self.pending.iter() {
+            let hash = *entry.key();
+            let e = entry.value().clone();
+            self.live.insert(hash, e);
+            order.push(hash);
+        }
+        self.pending.clear();
+
+        // Invalidate cached snapshot.
+        *self.cached_snapshot.write() = None;
+
+        // Bump epoch.
+        self.epoch.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
     /// Index an entry in the secondary indices.
     fn index_entry(&self, entry: &BlackboardEntry) {
         // By type
@@ -119,30 +279,27 @@ impl HashedBlackboard {
 
 impl BlackboardStore for HashedBlackboard {
     fn post(&self, entry: BlackboardEntry) -> BlackboardResult<[u8; 32]> {
+        self.post_checked(entry).map(|(hash, _)| hash)
+    }
+
+    fn post_checked(&self, entry: BlackboardEntry) -> BlackboardResult<([u8; 32], bool)> {
         let hash = entry.content_hash;
+        let _guard = self.write_lock.lock();
 
         // Dedup: if this exact hash already exists, skip.
         if self.live.contains_key(&hash) || self.pending.contains_key(&hash) {
-            return Ok(hash);
+            return Ok((hash, false));
         }
 
-        // Handle supersession: if this entry supersedes others, tombstone them.
-        for superseded_hash in &entry.supersedes {
-            if let Some(mut old) = self.live.get_mut(superseded_hash) {
-                old.tombstoned = true;
-            }
+        // Log before applying so a crash after this point is recoverable.
+        if self.wal.is_some() {
+            self.log_op(WalOp::Post { entry: Box::new(entry.clone()) })?;
         }
 
-        // Index the entry.
-        self.index_entry(&entry);
-
         // Add to pending buffer (not yet in canonical snapshot).
-        self.pending.insert(hash, entry);
+        self.apply_post(entry);
 
-        // Invalidate cached snapshot.
-        *self.cached_snapshot.write() = None;
-
-        Ok(hash)
+        Ok((hash, true))
     }
 
     fn get(&self, hash: &[u8; 32]) -> BlackboardResult<Option<BlackboardEntry>> {
@@ -240,36 +397,35 @@ impl BlackboardStore for HashedBlackboard {
     }
 
     fn advance_epoch(&self) -> u64 {
+        let _guard = self.write_lock.lock();
+        let next = self.epoch.load(Ordering::Relaxed) + 1;
+        if let Err(e) = self.log_op(WalOp::AdvanceEpoch { epoch: next }) {
+            log::error!("Failed to log epoch advance: {}", e);
+        }
+
         // Move all pending entries into live.
-        let mut order = self.canonical_order.write();
+        let new_epoch = self.promote_pending();
 
-        for entry in self.pending.iter() {
-            let hash = *entry.key();
-            let e = entry.value().clone();
-            self.live.insert(hash, e);
-            order.push(hash);
+        // Persist the new epoch; only then is it safe to drop the WAL.
+        let order = self.canonical_order.read();
+        if self.persist_live(&order) {
+            if let Some(ref wal) = self.wal {
+                if let Err(e) = wal.truncate() {
+                    log::error!("Failed to truncate blackboard WAL: {}", e);
+                }
+            }
         }
-        self.pending.clear();
-
-        // Invalidate cached snapshot.
-        *self.cached_snapshot.write() = None;
 
-        // Bump epoch.
-        let new_epoch = self.epoch.fetch_add(1, Ordering::Relaxed) + 1;
         new_epoch
     }
 
     fn tombstone(&self, hash: &[u8; 32]) -> BlackboardResult<()> {
-        if let Some(mut entry) = self.live.get_mut(hash) {
-            entry.tombstoned = true;
-            *self.cached_snapshot.write() = None;
-            return Ok(());
-        }
-        if let Some(mut entry) = self.pending.get_mut(hash) {
-            entry.tombstoned = true;
-            return Ok(());
+        let _guard = self.write_lock.lock();
+        if !self.live.contains_key(hash) && !self.pending.contains_key(hash) {
+            return Err(BlackboardError::NotFound("Entry not found".to_string()));
         }
-        Err(BlackboardError::NotFound(format!("Entry not found")))
+        self.log_op(WalOp::Tombstone { hash: *hash })?;
+        self.apply_tombstone(hash)
     }
 
     fn compact(&self) -> BlackboardResult<CompactionStats> {
@@ -345,6 +501,10 @@ impl BlackboardStore for HashedBlackboard {
 
         *self.cached_snapshot.write() = None;
 
+        // Keep the persisted snapshot in step so removed entries don't return
+        // on restart. Pending entries are still covered by the WAL.
+        self.persist_live(&self.canonical_order.read());
+
         Ok(CompactionStats {
             entries_before: before,
             entries_after: self.live.len(),
@@ -355,6 +515,7 @@ impl BlackboardStore for HashedBlackboard {
     }
 
     fn clear(&self) -> BlackboardResult<()> {
+        let _guard = self.write_lock.lock();
         self.live.clear();
         self.pending.clear();
         self.by_type.clear();
@@ -362,10 +523,17 @@ impl BlackboardStore for HashedBlackboard {
         self.by_parent.clear();
         self.canonical_order.write().clear();
         *self.cached_snapshot.write() = None;
+
+        if let Some(ref persistence) = self.persistence {
+            persistence.save(self.epoch.load(Ordering::Relaxed), &[])?;
+        }
+        if let Some(ref wal) = self.wal {
+            wal.truncate()?;
+        }
         Ok(())
     }
 
-    fn export_entries(&self, since_epoch: Option<u64>) -> BlackboardResult<Vec<BlackboardEntry>> {
+    fn export_entries(&self, _since_epoch: Option<u64>) -> BlackboardResult<Vec<BlackboardEntry>> {
         // For simplicity, export all live entries. A production impl would
         // tag entries with the epoch they were committed in.
         Ok(self.live.iter().map(|e| e.value().clone()).collect())
@@ -373,9 +541,13 @@ impl BlackboardStore for HashedBlackboard {
 
     fn import_entries(&self, entries: Vec<BlackboardEntry>) -> BlackboardResult<Vec<[u8; 32]>> {
         let mut imported = Vec::new();
+        let _guard = self.write_lock.lock();
         for entry in entries {
             let hash = entry.content_hash;
             if !self.live.contains_key(&hash) && !self.pending.contains_key(&hash) {
+                if self.wal.is_some() {
+                    self.log_op(WalOp::Post { entry: Box::new(entry.clone()) })?;
+                }
                 self.index_entry(&entry);
                 self.pending.insert(hash, entry);
                 imported.push(hash);
@@ -387,7 +559,7 @@ impl BlackboardStore for HashedBlackboard {
         Ok(imported)
     }
 
-    fn build_context_for_task(&self, task_description: &str, additional_context: &str) -> String {
+    fn build_context_for_task(&self, _task_description: &str, _additional_context: &str) -> String {
         // Use snapshot for consistent view
         match self.snapshot() {
             Ok(snap) => snap.as_prompt().to_string(),
@@ -410,3 +582,172 @@ impl BlackboardStore for HashedBlackboard {
         m
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn config() -> BlackboardConfig {
+        BlackboardConfig {
+            flavor: super::super::BlackboardFlavor::Hashed,
+            prune_expired: false,
+            separate_db: true,
+            lance_s3_uri: None,
+            lance_path: String::new(),
+            max_entries: 10_000,
+            stm_ttl_seconds: 3600,
+            persist_dir: None,
+            wal_fsync: false,
+        }
+    }
+
+    fn entry(content: &str) -> BlackboardEntry {
+        BlackboardEntry::new("agent-a".into(), EntryType::Fact, content, None)
+    }
+
+    #[test]
+    fn test_recovers_mid_epoch_posts_from_wal() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let (a, b, c) = {
+            let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+            let a = board.post(entry("a")).unwrap();
+            assert_eq!(board.advance_epoch(), 1);
+            let b = board.post(entry("b")).unwrap();
+            let c = board.post(entry("c")).unwrap();
+            board.tombstone(&a).unwrap();
+            // Crash: drop without advancing the epoch.
+            (a, b, c)
+        };
+
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        assert_eq!(board.epoch(), 1);
+        assert_eq!(board.len(), 3);
+        assert!(board.get(&a).unwrap().unwrap().tombstoned);
+        assert!(board.get(&b).unwrap().is_some());
+        assert!(board.get(&c).unwrap().is_some());
+        // b and c are still pending, so the snapshot only knows about a (tombstoned).
+        assert!(board.snapshot().unwrap().is_empty());
+
+        assert_eq!(board.advance_epoch(), 2);
+        let snap = board.snapshot().unwrap();
+        let hashes: Vec<_> = snap.entries.iter().map(|e| e.content_hash).collect();
+        assert_eq!(hashes.len(), 2);
+        assert!(hashes.contains(&b) && hashes.contains(&c));
+    }
+
+    #[test]
+    fn test_wal_truncated_after_epoch_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        board.post(entry("a")).unwrap();
+        assert_eq!(board.wal.as_ref().unwrap().replay().unwrap().len(), 1);
+        board.advance_epoch();
+        assert!(board.wal.as_ref().unwrap().replay().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_replay_skips_already_persisted_epoch() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = entry("a");
+        let a_hash = a.content_hash;
+        {
+            let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+            board.post(a.clone()).unwrap();
+            board.advance_epoch();
+        }
+
+        // Simulate a crash after the snapshot was persisted but before the
+        // WAL was truncated: the old records are still on disk.
+        let wal = WriteAheadLog::open(dir.path(), "bb", false).unwrap();
+        wal.append(WalOp::Post { entry: Box::new(a) }).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+        drop(wal);
+
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        assert_eq!(board.epoch(), 1);
+        assert_eq!(board.len(), 1);
+        assert!(board.live.contains_key(&a_hash));
+        assert!(board.pending.is_empty());
+    }
+
+    #[test]
+    fn test_recovers_with_truncated_final_wal_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let b_hash = {
+            let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+            board.post(entry("a")).unwrap();
+            board.advance_epoch();
+            board.post(entry("b")).unwrap()
+        };
+
+        let wal_path = dir.path().join("bb.wal.jsonl");
+        let mut f = std::fs::OpenOptions::new().append(true).open(wal_path).unwrap();
+        f.write_all(br#"{"schema_version":1,"op":"post","entry":{"con"#).unwrap();
+        drop(f);
+
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        assert_eq!(board.epoch(), 1);
+        assert_eq!(board.len(), 2);
+        assert!(board.pending.contains_key(&b_hash));
+    }
+
+    #[test]
+    fn test_posts_racing_epoch_advance_survive_crash() {
+        let dir = tempfile::tempdir().unwrap();
+        let posted = {
+            let board = std::sync::Arc::new(HashedBlackboard::open(config(), dir.path(), "bb").unwrap());
+            let writers: Vec<_> = (0..4)
+                .map(|t| {
+                    let board = board.clone();
+                    std::thread::spawn(move || {
+                        (0..50)
+                            .map(|i| board.post(entry(&format!("{}-{}", t, i))).unwrap())
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            while writers.iter().any(|w| !w.is_finished()) {
+                board.advance_epoch();
+            }
+            writers
+                .into_iter()
+                .flat_map(|w| w.join().unwrap())
+                .collect::<Vec<_>>()
+            // Crash: whatever is still pending must come back from the WAL.
+        };
+
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        assert_eq!(board.len(), posted.len());
+        for hash in &posted {
+            assert!(board.get(hash).unwrap().is_some());
+        }
+    }
+
+    #[test]
+    fn test_post_after_torn_wal_recovery_survives_next_crash() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+            board.post(entry("a")).unwrap();
+        }
+
+        // First crash: a record torn halfway through.
+        let wal_path = dir.path().join("bb.wal.jsonl");
+        let mut f = std::fs::OpenOptions::new().append(true).open(&wal_path).unwrap();
+        f.write_all(br#"{"schema_version":1,"op":"post","entry":{"con"#).unwrap();
+        drop(f);
+
+        let c_hash = {
+            let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+            assert_eq!(board.len(), 1);
+            board.post(entry("c")).unwrap()
+            // Second crash: drop without advancing the epoch.
+        };
+
+        let board = HashedBlackboard::open(config(), dir.path(), "bb").unwrap();
+        assert_eq!(board.len(), 2);
+        assert!(board.get(&c_hash).unwrap().is_some());
+    }
+}
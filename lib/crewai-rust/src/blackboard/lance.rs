@@ -33,7 +33,7 @@
 
 use std::collections::HashMap;
 
-use super::entry::{BlackboardEntry, EntryType};
+use super::entry::BlackboardEntry;
 use super::store::{BlackboardStore, BlackboardQuery, BlackboardResult, BlackboardError, CompactionStats};
 use super::snapshot::{BlackboardSnapshot, CacheThumbprint};
 use super::hashed::HashedBlackboard;
@@ -24,13 +24,19 @@ pub mod hashed;
 pub mod lance;
 pub mod snapshot;
 pub mod cache;
+pub mod wal;
+pub mod persistence;
+pub mod tool_wrapper;
+pub mod views;
 
 use std::sync::OnceLock;
 
 pub use entry::{BlackboardEntry, EntryType};
 pub use store::BlackboardStore;
 pub use snapshot::BlackboardSnapshot;
-pub use cache::CacheThumbprint;
+pub use cache::{CacheEfficiencyTracker, CacheThumbprint};
+pub use tool_wrapper::BlackboardToolWrapper;
+pub use views::{BlackboardViews, ReadOnlyBlackboard, ScopedBlackboardWriter};
 
 /// Global flavor selection, resolved once from env.
 static FLAVOR: OnceLock<BlackboardFlavor> = OnceLock::new();
@@ -107,6 +113,16 @@ pub struct BlackboardConfig {
     /// `CREWAI_BLACKBOARD_STM_TTL=3600`
     /// Default: 3600 (1 hour). 0 = no expiry.
     pub stm_ttl_seconds: u64,
+
+    /// Hashed flavor: directory for the SQLite epoch snapshot and the
+    /// write-ahead log. If unset, the hashed blackboard is in-memory only.
+    /// `CREWAI_BLACKBOARD_PERSIST_DIR=./blackboard`
+    pub persist_dir: Option<String>,
+
+    /// Hashed flavor: fsync the WAL after every post.
+    /// `CREWAI_BLACKBOARD_WAL_FSYNC=true|false`
+    /// Default: false (survives process crashes, not power loss).
+    pub wal_fsync: bool,
 }
 
 impl Default for BlackboardConfig {
@@ -130,6 +146,10 @@ impl Default for BlackboardConfig {
                 .ok()
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(3600),
+            persist_dir: std::env::var("CREWAI_BLACKBOARD_PERSIST_DIR").ok(),
+            wal_fsync: std::env::var("CREWAI_BLACKBOARD_WAL_FSYNC")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
         }
     }
 }
@@ -138,7 +158,20 @@ impl Default for BlackboardConfig {
 pub fn create_blackboard(config: BlackboardConfig) -> Box<dyn BlackboardStore> {
     match config.flavor {
         BlackboardFlavor::Original => Box::new(original::OriginalBlackboard::new(config)),
-        BlackboardFlavor::Hashed => Box::new(hashed::HashedBlackboard::new(config)),
+        BlackboardFlavor::Hashed => match config.persist_dir.clone() {
+            Some(dir) => match hashed::HashedBlackboard::open(config.clone(), &dir, "blackboard") {
+                Ok(board) => Box::new(board),
+                Err(e) => {
+                    log::error!(
+                        "Failed to open persistent blackboard at '{}': {} — falling back to in-memory",
+                        dir,
+                        e
+                    );
+                    Box::new(hashed::HashedBlackboard::new(config))
+                }
+            },
+            None => Box::new(hashed::HashedBlackboard::new(config)),
+        },
         BlackboardFlavor::Lance => Box::new(lance::LanceBlackboard::new(config)),
     }
 }
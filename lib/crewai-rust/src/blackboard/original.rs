@@ -9,10 +9,9 @@
 //!
 //! Use this when you want zero behavior change from stock crewAI.
 
-use std::collections::HashMap;
 use std::sync::RwLock;
 
-use super::entry::{BlackboardEntry, EntryType, EntryTier};
+use super::entry::BlackboardEntry;
 use super::store::{BlackboardStore, BlackboardQuery, BlackboardResult, BlackboardError, CompactionStats};
 use super::snapshot::{BlackboardSnapshot, CacheThumbprint};
 use super::BlackboardConfig;
@@ -117,7 +116,7 @@ impl BlackboardStore for OriginalBlackboard {
             entry.tombstoned = true;
             Ok(())
         } else {
-            Err(BlackboardError::NotFound(format!("Entry not found")))
+            Err(BlackboardError::NotFound("Entry not found".to_string()))
         }
     }
 
@@ -0,0 +1,115 @@
+//! SQLite snapshot persistence for the hashed flavor.
+//!
+//! On every epoch boundary the hashed blackboard writes its live entries,
+//! in canonical order, plus the epoch counter to `<dir>/<board>.db`. Pending
+//! entries are never persisted here — they are covered by the WAL until the
+//! next epoch promotes them.
+
+use std::path::Path;
+
+use parking_lot::Mutex;
+use rusqlite::{params, Connection};
+
+use super::entry::BlackboardEntry;
+use super::store::{BlackboardError, BlackboardResult};
+
+/// A persisted epoch snapshot: epoch counter + live entries in canonical order.
+#[derive(Debug, Clone, Default)]
+pub struct PersistedState {
+    pub epoch: u64,
+    pub entries: Vec<BlackboardEntry>,
+}
+
+/// SQLite-backed store for epoch snapshots.
+pub struct SnapshotPersistence {
+    conn: Mutex<Connection>,
+}
+
+fn storage_err(e: rusqlite::Error) -> BlackboardError {
+    BlackboardError::Storage(e.to_string())
+}
+
+impl SnapshotPersistence {
+    /// Open (or create) the snapshot database for `board_name` inside `dir`.
+    pub fn open(dir: impl AsRef<Path>, board_name: &str) -> BlackboardResult<Self> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .map_err(|e| BlackboardError::Storage(format!("Failed to create dir '{}': {}", dir.display(), e)))?;
+
+        let conn = Connection::open(dir.join(format!("{}.db", board_name))).map_err(storage_err)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS blackboard_meta (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS blackboard_entries (
+                position INTEGER PRIMARY KEY,
+                content_hash TEXT NOT NULL UNIQUE,
+                schema_version INTEGER NOT NULL,
+                entry_json TEXT NOT NULL
+            );",
+        )
+        .map_err(storage_err)?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Replace the stored snapshot with `entries` at `epoch`, atomically.
+    pub fn save(&self, epoch: u64, entries: &[BlackboardEntry]) -> BlackboardResult<()> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction().map_err(storage_err)?;
+
+        tx.execute("DELETE FROM blackboard_entries", []).map_err(storage_err)?;
+        for (position, entry) in entries.iter().enumerate() {
+            let json = serde_json::to_string(entry)
+                .map_err(|e| BlackboardError::Serialization(e.to_string()))?;
+            tx.execute(
+                "INSERT INTO blackboard_entries (position, content_hash, schema_version, entry_json)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![position as i64, entry.hash_hex(), entry.schema_version, json],
+            )
+            .map_err(storage_err)?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO blackboard_meta (key, value) VALUES ('epoch', ?1)",
+            params![epoch.to_string()],
+        )
+        .map_err(storage_err)?;
+
+        tx.commit().map_err(storage_err)
+    }
+
+    /// Load the last saved snapshot. Returns an empty state if nothing was saved yet.
+    pub fn load(&self) -> BlackboardResult<PersistedState> {
+        let conn = self.conn.lock();
+
+        let epoch: u64 = conn
+            .query_row(
+                "SELECT value FROM blackboard_meta WHERE key = 'epoch'",
+                [],
+                |row| row.get::<_, String>(0),
+            )
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+
+        let mut stmt = conn
+            .prepare("SELECT entry_json FROM blackboard_entries ORDER BY position")
+            .map_err(storage_err)?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(storage_err)?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            let json = row.map_err(storage_err)?;
+            let entry: BlackboardEntry = serde_json::from_str(&json)
+                .map_err(|e| BlackboardError::Serialization(e.to_string()))?;
+            entries.push(entry);
+        }
+
+        Ok(PersistedState { epoch, entries })
+    }
+}
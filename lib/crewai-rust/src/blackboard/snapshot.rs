@@ -70,6 +70,20 @@ impl BlackboardSnapshot {
         self.entries.is_empty()
     }
 
+    /// Render a single entry as it appears in the prompt (without trailing newline).
+    ///
+    /// `len() + 1` of this string is the number of bytes the entry contributes
+    /// to the rendered snapshot, which the cache tracker uses for attribution.
+    pub fn render_entry(e: &BlackboardEntry) -> String {
+        format!(
+            "- [{}] ({}, conf={:.2}): {}",
+            &e.hash_hex()[..8],
+            e.author,
+            e.confidence,
+            e.content,
+        )
+    }
+
     /// Render entries into a prompt-friendly string.
     fn render_for_prompt(entries: &[BlackboardEntry]) -> String {
         use super::entry::EntryType;
@@ -98,15 +112,7 @@ impl BlackboardSnapshot {
             let matching: Vec<String> = entries
                 .iter()
                 .filter(|e| e.entry_type == *et && !e.tombstoned)
-                .map(|e| {
-                    format!(
-                        "- [{}] ({}, conf={:.2}): {}",
-                        &e.hash_hex()[..8],
-                        e.author,
-                        e.confidence,
-                        e.content,
-                    )
-                })
+                .map(Self::render_entry)
                 .collect();
             if !matching.is_empty() {
                 sections.insert(label, matching);
@@ -143,6 +143,17 @@ pub trait BlackboardStore: Send + Sync {
     /// For `lance` flavor: inserts into Lance table with embedding.
     fn post(&self, entry: BlackboardEntry) -> BlackboardResult<[u8; 32]>;
 
+    /// Post an entry and report whether it was inserted (`true`) or
+    /// deduplicated against an entry already carrying its hash (`false`).
+    ///
+    /// The default checks `get` before posting; flavors that dedup under a
+    /// lock override it to make the check atomic.
+    fn post_checked(&self, entry: BlackboardEntry) -> BlackboardResult<([u8; 32], bool)> {
+        let existed = self.get(&entry.content_hash)?.is_some();
+        let hash = self.post(entry)?;
+        Ok((hash, !existed))
+    }
+
     /// Post multiple entries atomically.
     fn post_batch(&self, entries: Vec<BlackboardEntry>) -> BlackboardResult<Vec<[u8; 32]>> {
         entries.into_iter().map(|e| self.post(e)).collect()
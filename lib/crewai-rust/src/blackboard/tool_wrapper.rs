@@ -0,0 +1,283 @@
+//! BlackboardToolWrapper — posts tool results to the blackboard as observations.
+//!
+//! Wraps any `BaseTool`. Each successful `run()` posts an `Observation` entry
+//! authored by `tool-<name>` and, when the entry is new to the board, notifies
+//! the registered `ToolObserver`s with its rendered size, which is how the cache efficiency tracker learns
+//! which tool put what on the board.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::telemetry::ToolObserver;
+use crate::tools::base_tool::{BaseTool, EnvVar};
+
+use super::entry::{BlackboardEntry, EntryTier, EntryType};
+use super::snapshot::BlackboardSnapshot;
+use super::store::BlackboardStore;
+
+/// A tool wrapper that records every result on the blackboard.
+pub struct BlackboardToolWrapper {
+    inner: Box<dyn BaseTool>,
+    blackboard: Arc<dyn BlackboardStore>,
+    observers: Vec<Arc<dyn ToolObserver>>,
+    /// Tier for posted observations. Default: `Stm`.
+    tier: EntryTier,
+}
+
+impl fmt::Debug for BlackboardToolWrapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlackboardToolWrapper")
+            .field("inner", &self.inner)
+            .field("blackboard", &self.blackboard.flavor_name())
+            .field("observers", &self.observers.len())
+            .field("tier", &self.tier)
+            .finish()
+    }
+}
+
+impl BlackboardToolWrapper {
+    pub fn new(inner: Box<dyn BaseTool>, blackboard: Arc<dyn BlackboardStore>) -> Self {
+        Self {
+            inner,
+            blackboard,
+            observers: Vec::new(),
+            tier: EntryTier::Stm,
+        }
+    }
+
+    /// Register an observer to be notified of posted observations.
+    pub fn with_observer(mut self, observer: Arc<dyn ToolObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    pub fn with_tier(mut self, tier: EntryTier) -> Self {
+        self.tier = tier;
+        self
+    }
+
+    /// Post a tool result and notify observers. A result already on the board
+    /// (same content hash) is deduplicated and not reported again. Failures are
+    /// logged, never surfaced: the tool result itself is still valid.
+    fn post_observation(&self, args: &HashMap<String, Value>, result: &Value) {
+        let tool_name = self.inner.name().to_string();
+        let content = match result {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        let entry = BlackboardEntry::new(
+            format!("tool-{}", tool_name),
+            EntryType::Observation,
+            content,
+            None,
+        )
+        .with_tier(self.tier)
+        .with_metadata("tool_name", Value::String(tool_name.clone()))
+        .with_metadata("tool_args", serde_json::json!(args));
+
+        // +1 for the newline the snapshot renderer appends after each entry.
+        let rendered_bytes = BlackboardSnapshot::render_entry(&entry).len() + 1;
+
+        match self.blackboard.post_checked(entry) {
+            Ok((hash, true)) => {
+                for observer in &self.observers {
+                    observer.on_observation_posted(&tool_name, hash, rendered_bytes);
+                }
+            }
+            Ok((_, false)) => {}
+            Err(e) => log::warn!("Failed to post '{}' observation to blackboard: {}", tool_name, e),
+        }
+    }
+}
+
+#[async_trait]
+impl BaseTool for BlackboardToolWrapper {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn args_schema(&self) -> Value {
+        self.inner.args_schema()
+    }
+
+    fn env_vars(&self) -> &[EnvVar] {
+        self.inner.env_vars()
+    }
+
+    fn result_as_answer(&self) -> bool {
+        self.inner.result_as_answer()
+    }
+
+    fn max_usage_count(&self) -> Option<u32> {
+        self.inner.max_usage_count()
+    }
+
+    fn current_usage_count(&self) -> u32 {
+        self.inner.current_usage_count()
+    }
+
+    fn increment_usage_count(&mut self) {
+        self.inner.increment_usage_count();
+    }
+
+    fn reset_usage_count(&mut self) {
+        self.inner.reset_usage_count();
+    }
+
+    fn should_cache(&self, args: &Value, result: &Value) -> bool {
+        self.inner.should_cache(args, result)
+    }
+
+    fn run(
+        &mut self,
+        args: HashMap<String, Value>,
+    ) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+        let result = self.inner.run(args.clone())?;
+        self.post_observation(&args, &result);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blackboard::cache::CacheEfficiencyTracker;
+    use crate::blackboard::hashed::HashedBlackboard;
+    use crate::blackboard::{BlackboardConfig, BlackboardFlavor};
+    use crate::tools::base_tool::Tool;
+
+    fn board() -> Arc<dyn BlackboardStore> {
+        Arc::new(HashedBlackboard::new(BlackboardConfig {
+            flavor: BlackboardFlavor::Hashed,
+            prune_expired: false,
+            separate_db: true,
+            lance_s3_uri: None,
+            lance_path: String::new(),
+            max_entries: 10_000,
+            stm_ttl_seconds: 3600,
+            persist_dir: None,
+            wal_fsync: false,
+        }))
+    }
+
+    /// A tool that returns its `q` argument verbatim.
+    fn echo_tool(name: &str) -> Box<dyn BaseTool> {
+        Box::new(Tool::new(
+            name,
+            "test tool",
+            Arc::new(|args: HashMap<String, Value>| Ok(args["q"].clone())),
+        ))
+    }
+
+    fn run(tool: &mut BlackboardToolWrapper, q: &str) {
+        let mut args = HashMap::new();
+        args.insert("q".to_string(), Value::String(q.to_string()));
+        tool.run(args).unwrap();
+    }
+
+    #[test]
+    fn test_scripted_attribution() {
+        let bb = board();
+        let tracker = Arc::new(CacheEfficiencyTracker::new());
+
+        let mut search = BlackboardToolWrapper::new(echo_tool("search"), bb.clone())
+            .with_observer(tracker.clone());
+        let mut calc = BlackboardToolWrapper::new(echo_tool("calc"), bb.clone())
+            .with_observer(tracker.clone());
+
+        // Epoch 1: both tools post, epoch advances, one call misses the cache.
+        run(&mut search, "a long search result with many words in it");
+        run(&mut calc, "42");
+        bb.advance_epoch();
+        let snap1 = bb.snapshot().unwrap();
+        tracker.record_call(&snap1, 1_000, 0);
+
+        let r = tracker.report();
+        let s = &r.per_tool["search"];
+        let c = &r.per_tool["calc"];
+        assert_eq!(s.observations, 1);
+        assert_eq!(c.observations, 1);
+        assert_eq!(s.attributed_fresh_tokens + c.attributed_fresh_tokens, 1_000);
+        let expected_search = 1_000 * s.bytes_on_board / (s.bytes_on_board + c.bytes_on_board);
+        assert!(s.attributed_fresh_tokens - expected_search <= 1);
+        assert!(s.attributed_fresh_tokens > c.attributed_fresh_tokens);
+
+        // Same snapshot again: a cache hit, fresh tokens stay unattributed.
+        tracker.record_call(&snap1, 1_000, 800);
+        let r = tracker.report();
+        assert_eq!(r.unattributed_fresh_tokens, 200);
+        assert_eq!(
+            r.per_tool["search"].attributed_fresh_tokens + r.per_tool["calc"].attributed_fresh_tokens,
+            1_000
+        );
+
+        // calc posts again but the epoch hasn't advanced: still pending, so a
+        // call against the same snapshot attributes nothing.
+        let calc_before = r.per_tool["calc"].attributed_fresh_tokens;
+        run(&mut calc, "43");
+        tracker.record_call(&bb.snapshot().unwrap(), 500, 500);
+        assert_eq!(tracker.report().per_tool["calc"].attributed_fresh_tokens, calc_before);
+
+        // Epoch 2: the pending calc observation becomes visible and gets all
+        // of the next miss.
+        bb.advance_epoch();
+        tracker.record_call(&bb.snapshot().unwrap(), 1_200, 900);
+        let r = tracker.report();
+        assert_eq!(r.per_tool["calc"].observations, 2);
+        assert_eq!(r.per_tool["calc"].attributed_fresh_tokens, calc_before + 300);
+        assert_eq!(r.total_prompt_tokens, 3_700);
+        assert_eq!(r.cached_tokens, 2_200);
+        assert_eq!(r.fresh_tokens, 1_500);
+        assert_eq!(r.unattributed_fresh_tokens, 200);
+    }
+
+    #[test]
+    fn test_changed_snapshot_without_tool_entries_is_unattributed() {
+        let bb = board();
+        let tracker = CacheEfficiencyTracker::new();
+        bb.post(BlackboardEntry::new("agent-a".into(), EntryType::Decision, "go", None))
+            .unwrap();
+        bb.advance_epoch();
+        tracker.record_call(&bb.snapshot().unwrap(), 100, 0);
+        let r = tracker.report();
+        assert!(r.per_tool.is_empty());
+        assert_eq!(r.unattributed_fresh_tokens, 100);
+    }
+
+    #[test]
+    fn test_duplicate_observation_notifies_once() {
+        let bb = board();
+        let tracker = Arc::new(CacheEfficiencyTracker::new());
+        let mut search = BlackboardToolWrapper::new(echo_tool("search"), bb.clone())
+            .with_observer(tracker.clone());
+
+        run(&mut search, "same result");
+        run(&mut search, "same result");
+        assert_eq!(bb.by_type(EntryType::Observation).unwrap().len(), 1);
+        assert_eq!(tracker.report().per_tool["search"].observations, 1);
+
+        // Still deduped once the first post has been promoted to live.
+        bb.advance_epoch();
+        run(&mut search, "same result");
+        assert_eq!(tracker.report().per_tool["search"].observations, 1);
+    }
+
+    #[test]
+    fn test_wrapper_posts_observation() {
+        let bb = board();
+        let mut tool = BlackboardToolWrapper::new(echo_tool("search"), bb.clone());
+        run(&mut tool, "result");
+        let obs = bb.by_type(EntryType::Observation).unwrap();
+        assert_eq!(obs.len(), 1);
+        assert_eq!(obs[0].author, "tool-search");
+        assert_eq!(obs[0].metadata["tool_name"], "search");
+    }
+}
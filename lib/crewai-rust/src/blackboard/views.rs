@@ -0,0 +1,432 @@
+//! Restricted views over a shared blackboard.
+//!
+//! Tool-executing agents often need to read the board without being able to
+//! change it, or to contribute only certain kinds of entries. Both views are
+//! thin `Arc` wrappers around the same underlying store: reads pass straight
+//! through, and disallowed writes fail with `BlackboardError::PolicyDenied`.
+//!
+//! `advance_epoch` cannot report an error, so the views leave the epoch
+//! unchanged and return the current one.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use super::cache::CacheThumbprint;
+use super::entry::{BlackboardEntry, EntryType};
+use super::snapshot::BlackboardSnapshot;
+use super::store::{
+    BlackboardError, BlackboardQuery, BlackboardResult, BlackboardStore, CompactionStats,
+};
+
+/// Constructors for restricted views, available on any shared store.
+pub trait BlackboardViews {
+    /// A view that can read but never write.
+    fn read_only(&self) -> ReadOnlyBlackboard;
+
+    /// A view that may only post `allowed_types`, always as `author`.
+    fn scoped_writer(
+        &self,
+        author: impl Into<String>,
+        allowed_types: impl IntoIterator<Item = EntryType>,
+    ) -> ScopedBlackboardWriter;
+}
+
+impl BlackboardViews for Arc<dyn BlackboardStore> {
+    fn read_only(&self) -> ReadOnlyBlackboard {
+        ReadOnlyBlackboard::new(Arc::clone(self))
+    }
+
+    fn scoped_writer(
+        &self,
+        author: impl Into<String>,
+        allowed_types: impl IntoIterator<Item = EntryType>,
+    ) -> ScopedBlackboardWriter {
+        ScopedBlackboardWriter::new(Arc::clone(self), author, allowed_types)
+    }
+}
+
+/// Forward reads to `self.inner` and reject tombstone, compact, clear and
+/// import. `post` / `post_batch` are left to each view.
+macro_rules! forward_reads_deny_writes {
+    () => {
+        fn get(&self, hash: &[u8; 32]) -> BlackboardResult<Option<BlackboardEntry>> {
+            self.inner.get(hash)
+        }
+
+        fn query(&self, q: &BlackboardQuery) -> BlackboardResult<Vec<BlackboardEntry>> {
+            self.inner.query(q)
+        }
+
+        fn by_type(&self, t: EntryType) -> BlackboardResult<Vec<BlackboardEntry>> {
+            self.inner.by_type(t)
+        }
+
+        fn by_author(&self, author: &str) -> BlackboardResult<Vec<BlackboardEntry>> {
+            self.inner.by_author(author)
+        }
+
+        fn len(&self) -> usize {
+            self.inner.len()
+        }
+
+        fn snapshot(&self) -> BlackboardResult<BlackboardSnapshot> {
+            self.inner.snapshot()
+        }
+
+        fn cache_thumbprint(&self) -> CacheThumbprint {
+            self.inner.cache_thumbprint()
+        }
+
+        fn epoch(&self) -> u64 {
+            self.inner.epoch()
+        }
+
+        fn advance_epoch(&self) -> u64 {
+            log::warn!("{}: advance_epoch denied", self.view_name());
+            self.inner.epoch()
+        }
+
+        fn export_entries(
+            &self,
+            since_epoch: Option<u64>,
+        ) -> BlackboardResult<Vec<BlackboardEntry>> {
+            self.inner.export_entries(since_epoch)
+        }
+
+        fn build_context_for_task(
+            &self,
+            task_description: &str,
+            additional_context: &str,
+        ) -> String {
+            self.inner
+                .build_context_for_task(task_description, additional_context)
+        }
+
+        fn flavor_name(&self) -> &'static str {
+            self.inner.flavor_name()
+        }
+
+        fn stats(&self) -> HashMap<String, serde_json::Value> {
+            let mut m = self.inner.stats();
+            m.insert("view".into(), serde_json::Value::String(self.view_name().into()));
+            m
+        }
+
+        fn tombstone(&self, _hash: &[u8; 32]) -> BlackboardResult<()> {
+            Err(self.denied("tombstone"))
+        }
+
+        fn compact(&self) -> BlackboardResult<CompactionStats> {
+            Err(self.denied("compact"))
+        }
+
+        fn clear(&self) -> BlackboardResult<()> {
+            Err(self.denied("clear"))
+        }
+
+        fn import_entries(
+            &self,
+            _entries: Vec<BlackboardEntry>,
+        ) -> BlackboardResult<Vec<[u8; 32]>> {
+            Err(self.denied("import_entries"))
+        }
+    };
+}
+
+// ─── ReadOnlyBlackboard ──────────────────────────────────────────────────────
+
+/// A view of a blackboard that rejects every write.
+#[derive(Clone)]
+pub struct ReadOnlyBlackboard {
+    inner: Arc<dyn BlackboardStore>,
+}
+
+impl fmt::Debug for ReadOnlyBlackboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadOnlyBlackboard")
+            .field("inner", &self.inner.flavor_name())
+            .finish()
+    }
+}
+
+impl ReadOnlyBlackboard {
+    pub fn new(inner: Arc<dyn BlackboardStore>) -> Self {
+        Self { inner }
+    }
+
+    fn view_name(&self) -> &'static str {
+        "read_only"
+    }
+
+    fn denied(&self, op: &str) -> BlackboardError {
+        BlackboardError::PolicyDenied(format!("{} on a read-only blackboard view", op))
+    }
+}
+
+impl BlackboardStore for ReadOnlyBlackboard {
+    fn post(&self, entry: BlackboardEntry) -> BlackboardResult<[u8; 32]> {
+        Err(BlackboardError::PolicyDenied(format!(
+            "post of {:?} by '{}' on a read-only blackboard view",
+            entry.entry_type, entry.author
+        )))
+    }
+
+    fn post_batch(&self, _entries: Vec<BlackboardEntry>) -> BlackboardResult<Vec<[u8; 32]>> {
+        Err(self.denied("post_batch"))
+    }
+
+    forward_reads_deny_writes!();
+}
+
+// ─── ScopedBlackboardWriter ──────────────────────────────────────────────────
+
+/// A view of a blackboard that may post only certain entry types, always
+/// under a fixed author fingerprint.
+///
+/// Whatever author a posted entry claims is replaced (and its content hash
+/// recomputed), so a worker cannot impersonate another agent. Every other
+/// write is rejected.
+#[derive(Clone)]
+pub struct ScopedBlackboardWriter {
+    inner: Arc<dyn BlackboardStore>,
+    author: String,
+    allowed_types: Vec<EntryType>,
+}
+
+impl fmt::Debug for ScopedBlackboardWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopedBlackboardWriter")
+            .field("inner", &self.inner.flavor_name())
+            .field("author", &self.author)
+            .field("allowed_types", &self.allowed_types)
+            .finish()
+    }
+}
+
+impl ScopedBlackboardWriter {
+    pub fn new(
+        inner: Arc<dyn BlackboardStore>,
+        author: impl Into<String>,
+        allowed_types: impl IntoIterator<Item = EntryType>,
+    ) -> Self {
+        Self {
+            inner,
+            author: author.into(),
+            allowed_types: allowed_types.into_iter().collect(),
+        }
+    }
+
+    /// The fingerprint every posted entry is attributed to.
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    /// Entry types this view may post.
+    pub fn allowed_types(&self) -> &[EntryType] {
+        &self.allowed_types
+    }
+
+    fn view_name(&self) -> &'static str {
+        "scoped_writer"
+    }
+
+    fn denied(&self, op: &str) -> BlackboardError {
+        BlackboardError::PolicyDenied(format!(
+            "{} on a blackboard view scoped to '{}'",
+            op, self.author
+        ))
+    }
+
+    /// Check the entry's type and what it supersedes, and stamp the view's
+    /// author on it. Superseding tombstones the old entry, so a scoped writer
+    /// may only supersede entries it authored itself.
+    fn admit(&self, entry: BlackboardEntry) -> BlackboardResult<BlackboardEntry> {
+        if !self.allowed_types.contains(&entry.entry_type) {
+            return Err(BlackboardError::PolicyDenied(format!(
+                "'{}' may not post {:?} entries (allowed: {:?})",
+                self.author, entry.entry_type, self.allowed_types
+            )));
+        }
+        for hash in &entry.supersedes {
+            let own = self
+                .inner
+                .get(hash)?
+                .is_some_and(|old| old.author == self.author);
+            if !own {
+                return Err(BlackboardError::PolicyDenied(format!(
+                    "'{}' may only supersede its own entries",
+                    self.author
+                )));
+            }
+        }
+        if entry.author == self.author {
+            Ok(entry)
+        } else {
+            Ok(entry.with_author(self.author.clone()))
+        }
+    }
+}
+
+impl BlackboardStore for ScopedBlackboardWriter {
+    fn post(&self, entry: BlackboardEntry) -> BlackboardResult<[u8; 32]> {
+        let entry = self.admit(entry)?;
+        self.inner.post(entry)
+    }
+
+    fn post_checked(&self, entry: BlackboardEntry) -> BlackboardResult<([u8; 32], bool)> {
+        let entry = self.admit(entry)?;
+        self.inner.post_checked(entry)
+    }
+
+    /// All-or-nothing: one disallowed entry rejects the whole batch.
+    fn post_batch(&self, entries: Vec<BlackboardEntry>) -> BlackboardResult<Vec<[u8; 32]>> {
+        let admitted = entries
+            .into_iter()
+            .map(|e| self.admit(e))
+            .collect::<BlackboardResult<Vec<_>>>()?;
+        self.inner.post_batch(admitted)
+    }
+
+    forward_reads_deny_writes!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blackboard::hashed::HashedBlackboard;
+    use crate::blackboard::{BlackboardConfig, BlackboardFlavor};
+
+    fn board() -> Arc<dyn BlackboardStore> {
+        Arc::new(HashedBlackboard::new(BlackboardConfig {
+            flavor: BlackboardFlavor::Hashed,
+            prune_expired: false,
+            separate_db: true,
+            lance_s3_uri: None,
+            lance_path: String::new(),
+            max_entries: 10_000,
+            stm_ttl_seconds: 3600,
+            persist_dir: None,
+            wal_fsync: false,
+        }))
+    }
+
+    fn is_denied<T>(r: BlackboardResult<T>) -> bool {
+        matches!(r, Err(BlackboardError::PolicyDenied(_)))
+    }
+
+    #[test]
+    fn read_only_denies_every_write() {
+        let store = board();
+        let fact = BlackboardEntry::new("planner".into(), EntryType::Fact, "sky is blue", None);
+        let hash = store.post(fact.clone()).unwrap();
+        let view = store.read_only();
+
+        let decision = BlackboardEntry::new("scraper".into(), EntryType::Decision, "ship it", None);
+        assert!(is_denied(view.post(decision.clone())));
+        assert!(is_denied(view.post_batch(vec![decision.clone()])));
+        assert!(is_denied(view.tombstone(&hash)));
+        assert!(is_denied(view.clear()));
+        assert!(is_denied(view.compact()));
+        assert!(is_denied(view.import_entries(vec![decision])));
+
+        let epoch = store.epoch();
+        assert_eq!(view.advance_epoch(), epoch);
+        assert_eq!(store.epoch(), epoch);
+        assert_eq!(store.len(), view.len());
+        assert!(store.get(&hash).unwrap().is_some());
+    }
+
+    #[test]
+    fn read_only_reads_pass_through() {
+        let store = board();
+        let hash = store
+            .post(BlackboardEntry::new("planner".into(), EntryType::Fact, "water is wet", None))
+            .unwrap();
+        let view = store.read_only();
+
+        let via_view = view.get(&hash).unwrap().unwrap();
+        let direct = store.get(&hash).unwrap().unwrap();
+        assert_eq!(via_view.content, direct.content);
+        assert_eq!(via_view.author, direct.author);
+        assert_eq!(view.epoch(), store.epoch());
+        assert_eq!(view.flavor_name(), store.flavor_name());
+        assert_eq!(view.stats()["view"], serde_json::json!("read_only"));
+
+        // Writes made through the store are visible through the view.
+        let later = store
+            .post(BlackboardEntry::new("planner".into(), EntryType::Fact, "fire is hot", None))
+            .unwrap();
+        assert!(view.get(&later).unwrap().is_some());
+    }
+
+    #[test]
+    fn scoped_writer_restricts_entry_types() {
+        let store = board();
+        let writer = store.scoped_writer("scraper", [EntryType::Observation, EntryType::Fact]);
+
+        let obs = BlackboardEntry::new("scraper".into(), EntryType::Observation, "page title: Rust", None);
+        assert!(writer.post(obs).is_ok());
+
+        let decision = BlackboardEntry::new("scraper".into(), EntryType::Decision, "use Rust", None);
+        assert!(is_denied(writer.post(decision.clone())));
+
+        // One disallowed entry rejects the whole batch.
+        let fact = BlackboardEntry::new("scraper".into(), EntryType::Fact, "Rust is fast", None);
+        let before = store.len();
+        assert!(is_denied(writer.post_batch(vec![fact.clone(), decision])));
+        assert_eq!(store.len(), before);
+        assert!(store.get(&fact.content_hash).unwrap().is_none());
+
+        assert!(is_denied(writer.clear()));
+        assert!(is_denied(writer.tombstone(&fact.content_hash)));
+    }
+
+    #[test]
+    fn scoped_writer_overrides_claimed_author() {
+        let store = board();
+        let writer = store.scoped_writer("scraper", [EntryType::Observation]);
+
+        let forged = BlackboardEntry::new("planner".into(), EntryType::Observation, "forged", None);
+        let forged_hash = forged.content_hash;
+        let hash = writer.post(forged).unwrap();
+
+        assert_ne!(hash, forged_hash, "hash must be recomputed for the new author");
+        let stored = store.get(&hash).unwrap().unwrap();
+        assert_eq!(stored.author, "scraper");
+        assert_eq!(stored.content, "forged");
+        assert!(store.get(&forged_hash).unwrap().is_none());
+
+        // Entries already authored by the writer keep their hash.
+        let honest = BlackboardEntry::new("scraper".into(), EntryType::Observation, "honest", None);
+        let honest_hash = honest.content_hash;
+        assert_eq!(writer.post(honest).unwrap(), honest_hash);
+    }
+
+    #[test]
+    fn scoped_writer_cannot_supersede_other_authors() {
+        let store = board();
+        let planner = store
+            .post(BlackboardEntry::new("planner".into(), EntryType::Decision, "use Rust", None))
+            .unwrap();
+        let writer = store.scoped_writer("scraper", [EntryType::Decision, EntryType::Fact]);
+
+        let hijack = BlackboardEntry::new("scraper".into(), EntryType::Decision, "use Go", None)
+            .with_supersedes(vec![planner]);
+        assert!(is_denied(writer.post(hijack.clone())));
+        assert!(is_denied(writer.post_batch(vec![hijack])));
+        // Unknown hashes are refused too, rather than silently accepted.
+        let unknown = BlackboardEntry::new("scraper".into(), EntryType::Fact, "x", None)
+            .with_supersedes(vec![[7; 32]]);
+        assert!(is_denied(writer.post(unknown)));
+        assert!(!store.get(&planner).unwrap().unwrap().tombstoned);
+
+        // Superseding its own entry is still allowed.
+        let first = writer
+            .post(BlackboardEntry::new("scraper".into(), EntryType::Fact, "v1", None))
+            .unwrap();
+        let second = BlackboardEntry::new("scraper".into(), EntryType::Fact, "v2", None)
+            .with_supersedes(vec![first]);
+        assert!(writer.post(second).is_ok());
+    }
+}
@@ -0,0 +1,349 @@
+//! Write-ahead log — crash safety for the hashed flavor between epoch persists.
+//!
+//! The hashed blackboard persists its live entries to SQLite on every epoch
+//! boundary. Anything posted mid-epoch only lives in the in-memory pending
+//! buffer, so a crash would lose it. The WAL closes that gap: every mutating
+//! operation is appended as one JSON line to `<dir>/<board>.wal.jsonl` before
+//! it is applied. On startup the board loads its last persisted snapshot and
+//! replays the WAL on top; after a successful epoch persist the WAL is truncated.
+//!
+//! ## Format
+//!
+//! ```text
+//! {"schema_version":1,"op":"post","entry":{...}}
+//! {"schema_version":1,"op":"tombstone","hash":[...]}
+//! {"schema_version":1,"op":"advance_epoch","epoch":4}
+//! ```
+//!
+//! A crash mid-write can leave a partial final line. Replay skips an
+//! unreadable *last* line, and [`WriteAheadLog::recover`] cuts it off so the
+//! next append starts on a fresh line. An unreadable line anywhere else means
+//! the log itself is damaged, and replay fails rather than silently dropping
+//! operations.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use super::entry::{BlackboardEntry, ENTRY_SCHEMA_VERSION};
+use super::store::{BlackboardError, BlackboardResult};
+
+/// A single logged blackboard operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum WalOp {
+    /// An entry was posted to the pending buffer.
+    Post { entry: Box<BlackboardEntry> },
+    /// An entry (live or pending) was tombstoned.
+    Tombstone { hash: [u8; 32] },
+    /// The epoch advanced to `epoch`. Replay skips it if the snapshot
+    /// already reached that epoch (crash between persist and truncate).
+    AdvanceEpoch { epoch: u64 },
+}
+
+/// One line of the WAL: the operation plus the entry schema it was written with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalRecord {
+    /// `ENTRY_SCHEMA_VERSION` at the time of writing.
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub op: WalOp,
+}
+
+impl WalRecord {
+    pub fn new(op: WalOp) -> Self {
+        Self {
+            schema_version: ENTRY_SCHEMA_VERSION,
+            op,
+        }
+    }
+}
+
+/// Result of reading the log: its intact records and where they end.
+struct WalScan {
+    records: Vec<WalRecord>,
+    /// Byte length of the prefix holding only intact records.
+    intact_len: u64,
+    file_len: u64,
+    /// Whether that prefix ends on a line break (or is empty).
+    ends_with_newline: bool,
+}
+
+/// Append-only JSONL write-ahead log for a single blackboard.
+pub struct WriteAheadLog {
+    path: PathBuf,
+    file: Mutex<File>,
+    /// Call `fsync` after every append. Slower, but survives power loss
+    /// rather than just process crashes.
+    fsync_on_post: bool,
+}
+
+impl WriteAheadLog {
+    /// Open (or create) the WAL for `board_name` inside `dir`.
+    pub fn open(dir: impl AsRef<Path>, board_name: &str, fsync_on_post: bool) -> BlackboardResult<Self> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .map_err(|e| BlackboardError::Storage(format!("Failed to create WAL dir '{}': {}", dir.display(), e)))?;
+
+        let path = dir.join(format!("{}.wal.jsonl", board_name));
+        let file = Self::open_append(&path)?;
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+            fsync_on_post,
+        })
+    }
+
+    fn open_append(path: &Path) -> BlackboardResult<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| BlackboardError::Storage(format!("Failed to open WAL '{}': {}", path.display(), e)))
+    }
+
+    /// Path of the WAL file on disk.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append one operation as a JSON line.
+    pub fn append(&self, op: WalOp) -> BlackboardResult<()> {
+        let record = WalRecord::new(op);
+        let mut line = serde_json::to_string(&record)
+            .map_err(|e| BlackboardError::Serialization(e.to_string()))?;
+        line.push('\n');
+
+        let mut file = self.file.lock();
+        file.write_all(line.as_bytes())
+            .map_err(|e| BlackboardError::Storage(format!("WAL write failed: {}", e)))?;
+        if self.fsync_on_post {
+            file.sync_data()
+                .map_err(|e| BlackboardError::Storage(format!("WAL fsync failed: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Read every intact record in the log, in write order.
+    ///
+    /// An unreadable final line (a record torn by a crash mid-append) is
+    /// skipped with a warning; an unreadable line before it is an error.
+    pub fn replay(&self) -> BlackboardResult<Vec<WalRecord>> {
+        Ok(self.scan()?.records)
+    }
+
+    /// Replay the log and repair a torn tail: the file is cut back to the end
+    /// of the last intact record, so later appends are not glued onto the
+    /// partial line.
+    pub fn recover(&self) -> BlackboardResult<Vec<WalRecord>> {
+        let scan = self.scan()?;
+        if scan.intact_len < scan.file_len || !scan.ends_with_newline {
+            let mut file = self.file.lock();
+            let repaired = OpenOptions::new()
+                .write(true)
+                .open(&self.path)
+                .map_err(|e| BlackboardError::Storage(format!("WAL repair failed: {}", e)))?;
+            repaired
+                .set_len(scan.intact_len)
+                .map_err(|e| BlackboardError::Storage(format!("WAL repair failed: {}", e)))?;
+            repaired
+                .sync_all()
+                .map_err(|e| BlackboardError::Storage(format!("WAL fsync failed: {}", e)))?;
+            *file = Self::open_append(&self.path)?;
+            if !scan.ends_with_newline {
+                // The last record is intact but its newline never made it out.
+                file.write_all(b"\n")
+                    .map_err(|e| BlackboardError::Storage(format!("WAL repair failed: {}", e)))?;
+            }
+            log::warn!(
+                "WAL {}: repaired torn tail ({} bytes dropped)",
+                self.path.display(),
+                scan.file_len - scan.intact_len
+            );
+        }
+        Ok(scan.records)
+    }
+
+    fn scan(&self) -> BlackboardResult<WalScan> {
+        let mut bytes = Vec::new();
+        File::open(&self.path)
+            .and_then(|mut f| f.read_to_end(&mut bytes))
+            .map_err(|e| BlackboardError::Storage(format!("Failed to read WAL '{}': {}", self.path.display(), e)))?;
+
+        let lines: Vec<&[u8]> = bytes.split(|&b| b == b'\n').collect();
+        let last = lines
+            .iter()
+            .rposition(|line| !line.iter().all(|b| b.is_ascii_whitespace()));
+
+        let mut records = Vec::new();
+        let mut offset = 0;
+        let mut intact_len = 0;
+        for (lineno, line) in lines.iter().enumerate() {
+            let end = (offset + line.len() + 1).min(bytes.len());
+            let line_start = offset;
+            offset = end;
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+            match serde_json::from_slice::<WalRecord>(line) {
+                Ok(record) => {
+                    if record.schema_version > ENTRY_SCHEMA_VERSION {
+                        log::warn!(
+                            "WAL {} line {}: schema_version {} is newer than supported {}",
+                            self.path.display(),
+                            lineno + 1,
+                            record.schema_version,
+                            ENTRY_SCHEMA_VERSION
+                        );
+                    }
+                    records.push(record);
+                    intact_len = end;
+                }
+                Err(e) if Some(lineno) == last => {
+                    log::warn!(
+                        "WAL {} line {}: skipping torn final record ({})",
+                        self.path.display(),
+                        lineno + 1,
+                        e
+                    );
+                    intact_len = line_start;
+                }
+                Err(e) => {
+                    return Err(BlackboardError::Serialization(format!(
+                        "WAL {} line {} is corrupt: {}",
+                        self.path.display(),
+                        lineno + 1,
+                        e
+                    )));
+                }
+            }
+        }
+
+        Ok(WalScan {
+            records,
+            intact_len: intact_len as u64,
+            file_len: bytes.len() as u64,
+            ends_with_newline: intact_len == 0 || bytes[intact_len - 1] == b'\n',
+        })
+    }
+
+    /// Discard all records. Called after the epoch snapshot is safely persisted.
+    pub fn truncate(&self) -> BlackboardResult<()> {
+        let mut file = self.file.lock();
+        let truncated = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .open(&self.path)
+            .map_err(|e| BlackboardError::Storage(format!("WAL truncate failed: {}", e)))?;
+        truncated
+            .sync_all()
+            .map_err(|e| BlackboardError::Storage(format!("WAL fsync failed: {}", e)))?;
+        *file = Self::open_append(&self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blackboard::entry::EntryType;
+
+    #[test]
+    fn test_wal_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", false).unwrap();
+
+        let entry = BlackboardEntry::new("agent-a".into(), EntryType::Fact, "sky is blue", None);
+        let hash = entry.content_hash;
+        wal.append(WalOp::Post { entry: Box::new(entry) }).unwrap();
+        wal.append(WalOp::Tombstone { hash }).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+
+        let records = wal.replay().unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|r| r.schema_version == ENTRY_SCHEMA_VERSION));
+        assert!(matches!(records[0].op, WalOp::Post { .. }));
+        assert!(matches!(records[1].op, WalOp::Tombstone { hash: h } if h == hash));
+        assert!(matches!(records[2].op, WalOp::AdvanceEpoch { epoch: 1 }));
+    }
+
+    #[test]
+    fn test_wal_tolerates_truncated_final_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", true).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+
+        // Simulate a crash halfway through writing the second record.
+        let mut f = OpenOptions::new().append(true).open(wal.path()).unwrap();
+        f.write_all(br#"{"schema_version":1,"op":"adv"#).unwrap();
+        drop(f);
+
+        let records = wal.replay().unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    #[test]
+    fn test_wal_rejects_corrupt_record_before_the_end() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", false).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+        let mut f = OpenOptions::new().append(true).open(wal.path()).unwrap();
+        f.write_all(b"{not json}\n").unwrap();
+        drop(f);
+        wal.append(WalOp::AdvanceEpoch { epoch: 2 }).unwrap();
+
+        let err = wal.replay().unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{}", err);
+        assert!(wal.recover().is_err());
+    }
+
+    #[test]
+    fn test_wal_recover_cuts_torn_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", false).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+        let intact = std::fs::metadata(wal.path()).unwrap().len();
+        let mut f = OpenOptions::new().append(true).open(wal.path()).unwrap();
+        f.write_all(br#"{"schema_version":1,"op":"adv"#).unwrap();
+        drop(f);
+
+        assert_eq!(wal.recover().unwrap().len(), 1);
+        assert_eq!(std::fs::metadata(wal.path()).unwrap().len(), intact);
+
+        // The next record starts on its own line.
+        wal.append(WalOp::AdvanceEpoch { epoch: 2 }).unwrap();
+        let records = wal.replay().unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[1].op, WalOp::AdvanceEpoch { epoch: 2 }));
+    }
+
+    #[test]
+    fn test_wal_recover_restores_missing_newline() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", false).unwrap();
+        let mut f = OpenOptions::new().append(true).open(wal.path()).unwrap();
+        f.write_all(br#"{"schema_version":1,"op":"advance_epoch","epoch":1}"#).unwrap();
+        drop(f);
+
+        assert_eq!(wal.recover().unwrap().len(), 1);
+        wal.append(WalOp::AdvanceEpoch { epoch: 2 }).unwrap();
+        assert_eq!(wal.replay().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_wal_truncate() {
+        let dir = tempfile::tempdir().unwrap();
+        let wal = WriteAheadLog::open(dir.path(), "board", false).unwrap();
+        wal.append(WalOp::AdvanceEpoch { epoch: 1 }).unwrap();
+        wal.truncate().unwrap();
+        assert!(wal.replay().unwrap().is_empty());
+
+        // Appends after truncation still land in the file.
+        wal.append(WalOp::AdvanceEpoch { epoch: 2 }).unwrap();
+        assert_eq!(wal.replay().unwrap().len(), 1);
+    }
+}
@@ -11,6 +11,7 @@
 pub mod a2a;
 pub mod agent;
 pub mod agents;
+pub mod blackboard;
 pub mod capabilities;
 pub mod cli;
 pub mod context;
@@ -151,3 +151,21 @@ impl SpanHandle {
         self.ended = true;
     }
 }
+
+// ---------------------------------------------------------------------------
+// ToolObserver
+// ---------------------------------------------------------------------------
+
+/// Observer notified when a tool's output is posted to the blackboard.
+///
+/// Implemented by `blackboard::cache::CacheEfficiencyTracker` to attribute
+/// prompt size and cache misses to individual tools. Observers must be cheap;
+/// they run inline on the tool execution path.
+pub trait ToolObserver: Send + Sync {
+    /// A tool result was posted as an `Observation` entry.
+    ///
+    /// * `tool_name` - Name of the tool that produced the observation.
+    /// * `entry_hash` - Content hash of the posted entry.
+    /// * `rendered_bytes` - Bytes the entry adds to the rendered snapshot.
+    fn on_observation_posted(&self, tool_name: &str, entry_hash: [u8; 32], rendered_bytes: usize);
+}
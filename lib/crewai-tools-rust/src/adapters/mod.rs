@@ -8,6 +8,9 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::builder::{Missing, Set};
+use crate::schema;
+
 // ── McpServerAdapter ─────────────────────────────────────────────────────────
 
 /// Adapter that exposes crewAI tools over the Model Context Protocol (MCP).
@@ -56,6 +59,13 @@ impl McpServerAdapter {
 
 /// Enterprise-grade action tool for executing business workflow actions.
 ///
+/// When the action's schemas are known (typically from the enterprise schema
+/// endpoint's action definition), arguments are validated before the call
+/// and the response is checked afterwards. Response mismatches do not fail
+/// the call: they are listed in `schema_violations` next to the data. With
+/// `coerce` set, obvious mismatches (numeric strings, single values where
+/// arrays are expected) are fixed before checking.
+///
 /// Corresponds to the Python `EnterpriseActionTool` class in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnterpriseActionTool {
@@ -65,6 +75,15 @@ pub struct EnterpriseActionTool {
     pub api_endpoint: Option<String>,
     /// Optional API key for authentication.
     pub api_key: Option<String>,
+    /// JSON Schema for the action's parameters.
+    #[serde(default)]
+    pub parameters_schema: Option<Value>,
+    /// JSON Schema for the action's response.
+    #[serde(default)]
+    pub response_schema: Option<Value>,
+    /// Coerce obvious response mismatches before validating.
+    #[serde(default)]
+    pub coerce: bool,
 }
 
 impl EnterpriseActionTool {
@@ -74,6 +93,9 @@ impl EnterpriseActionTool {
             action_name: action_name.into(),
             api_endpoint: None,
             api_key: None,
+            parameters_schema: None,
+            response_schema: None,
+            coerce: false,
         }
     }
 
@@ -89,6 +111,59 @@ impl EnterpriseActionTool {
         self
     }
 
+    /// Set the parameters schema.
+    pub fn with_parameters_schema(mut self, schema: Value) -> Self {
+        self.parameters_schema = Some(schema);
+        self
+    }
+
+    /// Set the response schema.
+    pub fn with_response_schema(mut self, schema: Value) -> Self {
+        self.response_schema = Some(schema);
+        self
+    }
+
+    /// Take both schemas from an action definition returned by the
+    /// enterprise schema endpoint (its `parameters` and `response` keys).
+    pub fn with_action_schema(mut self, definition: &Value) -> Self {
+        if let Some(parameters) = definition.get("parameters") {
+            self.parameters_schema = Some(parameters.clone());
+        }
+        if let Some(response) = definition.get("response") {
+            self.response_schema = Some(response.clone());
+        }
+        self
+    }
+
+    /// Enable or disable response coercion.
+    pub fn with_coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+
+    /// Start a builder; `build()` is available once `action_name` is set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::EnterpriseActionTool;
+    ///
+    /// let tool = EnterpriseActionTool::builder()
+    ///     .action_name("crm_create_contact")
+    ///     .api_endpoint("https://actions.example.com/run")
+    ///     .coerce(true)
+    ///     .build();
+    /// ```
+    pub fn builder() -> EnterpriseActionToolBuilder<Missing> {
+        EnterpriseActionToolBuilder {
+            action_name: Missing,
+            api_endpoint: None,
+            api_key: None,
+            parameters_schema: None,
+            response_schema: None,
+            coerce: false,
+        }
+    }
+
     /// Returns the tool name.
     pub fn name(&self) -> &str {
         "EnterpriseActionTool"
@@ -99,18 +174,175 @@ impl EnterpriseActionTool {
         "Execute enterprise workflow actions through external service integrations"
     }
 
+    /// Check arguments against the parameters schema, failing with every
+    /// violation listed.
+    pub fn validate_args(&self, args: &HashMap<String, Value>) -> Result<(), anyhow::Error> {
+        let Some(schema) = &self.parameters_schema else {
+            return Ok(());
+        };
+        let value = Value::Object(args.iter().map(|(k, v)| (k.clone(), v.clone())).collect());
+        let violations = schema::validate(&value, schema, schema);
+        if violations.is_empty() {
+            return Ok(());
+        }
+        let details: Vec<String> = violations
+            .iter()
+            .map(|v| format!("{}: {}", if v.path.is_empty() { "/" } else { &v.path }, v.message))
+            .collect();
+        anyhow::bail!(
+            "Invalid arguments for action '{}': {}",
+            self.action_name,
+            details.join("; ")
+        )
+    }
+
+    /// Coerce (if enabled) and validate a response against the response
+    /// schema. Returns `{data, schema_violations, coercions}`; without a
+    /// schema the data is passed through with empty lists.
+    pub fn check_response(&self, mut data: Value) -> Value {
+        let (violations, coercions) = match &self.response_schema {
+            Some(schema) => {
+                let coercions = if self.coerce {
+                    schema::coerce(&mut data, schema, schema)
+                } else {
+                    Vec::new()
+                };
+                (schema::validate(&data, schema, schema), coercions)
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+        if !violations.is_empty() {
+            log::warn!(
+                "Response for action '{}' drifted from its schema ({} violations)",
+                self.action_name,
+                violations.len()
+            );
+        }
+        serde_json::json!({
+            "data": data,
+            "schema_violations": violations,
+            "coercions": coercions,
+        })
+    }
+
     /// Run the enterprise action.
+    ///
+    /// Posts `{"action", "parameters"}` to `api_endpoint` and returns the
+    /// result of [`check_response`](Self::check_response).
+    ///
+    /// # Arguments (in `args`)
+    /// The action's parameters, validated against `parameters_schema`.
+    #[cfg(feature = "reqwest")]
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let endpoint = self
+            .api_endpoint
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("EnterpriseActionTool: api_endpoint is not set"))?;
+        self.validate_args(&args)?;
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let mut request = client.post(endpoint).json(&serde_json::json!({
+            "action": self.action_name,
+            "parameters": args,
+        }));
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = request.send()?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!(
+                "Enterprise action '{}' failed ({}): {}",
+                self.action_name,
+                status,
+                body
+            );
+        }
+        Ok(self.check_response(response.json()?))
+    }
+
+    /// Run the enterprise action.
+    #[cfg(not(feature = "reqwest"))]
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!(
-            "EnterpriseActionTool: not yet implemented - requires enterprise service integration"
+            "EnterpriseActionTool: HTTP support is not enabled - build with a feature that enables reqwest"
         )
     }
 }
 
+/// Builder for [`EnterpriseActionTool`]; `N` tracks whether the action name
+/// has been supplied.
+#[derive(Debug, Clone)]
+pub struct EnterpriseActionToolBuilder<N> {
+    action_name: N,
+    api_endpoint: Option<String>,
+    api_key: Option<String>,
+    parameters_schema: Option<Value>,
+    response_schema: Option<Value>,
+    coerce: bool,
+}
+
+impl<N> EnterpriseActionToolBuilder<N> {
+    pub fn action_name(self, name: impl Into<String>) -> EnterpriseActionToolBuilder<Set<String>> {
+        EnterpriseActionToolBuilder {
+            action_name: Set(name.into()),
+            api_endpoint: self.api_endpoint,
+            api_key: self.api_key,
+            parameters_schema: self.parameters_schema,
+            response_schema: self.response_schema,
+            coerce: self.coerce,
+        }
+    }
+
+    pub fn api_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.api_endpoint = Some(endpoint.into());
+        self
+    }
+
+    pub fn api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn parameters_schema(mut self, schema: Value) -> Self {
+        self.parameters_schema = Some(schema);
+        self
+    }
+
+    pub fn response_schema(mut self, schema: Value) -> Self {
+        self.response_schema = Some(schema);
+        self
+    }
+
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+}
+
+impl EnterpriseActionToolBuilder<Set<String>> {
+    pub fn build(self) -> EnterpriseActionTool {
+        EnterpriseActionTool {
+            action_name: self.action_name.0,
+            api_endpoint: self.api_endpoint,
+            api_key: self.api_key,
+            parameters_schema: self.parameters_schema,
+            response_schema: self.response_schema,
+            coerce: self.coerce,
+        }
+    }
+}
+
 // ── ZapierActionTool ─────────────────────────────────────────────────────────
 
 /// Tool for executing Zapier actions via the Zapier Natural Language Actions API.
 ///
+/// Runs one pre-configured action (or the `action_id` given per call); see
+/// `ZapierActionTools` for listing actions and choosing between them.
+///
 /// Corresponds to the Python `ZapierActionTool` class in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZapierActionTool {
@@ -118,6 +350,9 @@ pub struct ZapierActionTool {
     pub api_key: Option<String>,
     /// Specific action ID to execute, if pre-configured.
     pub action_id: Option<String>,
+    /// NLA API root; defaults to `https://nla.zapier.com/api/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl ZapierActionTool {
@@ -126,6 +361,7 @@ impl ZapierActionTool {
         Self {
             api_key: None,
             action_id: None,
+            base_url: None,
         }
     }
 
@@ -141,6 +377,12 @@ impl ZapierActionTool {
         self
     }
 
+    /// Set the NLA API root.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     /// Returns the tool name.
     pub fn name(&self) -> &str {
         "ZapierActionTool"
@@ -152,9 +394,31 @@ impl ZapierActionTool {
     }
 
     /// Run the Zapier action.
+    ///
+    /// Returns `{action_id, status, review_url, result, input_params}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `instructions` - What the action should do, in plain language.
+    /// * `params` - Optional object of field values overriding the instructions.
+    /// * `preview` - Resolve the action without running it.
+    /// * `action_id` - Action to run (optional if set on struct).
+    #[cfg(feature = "reqwest")]
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let action_id = args
+            .get("action_id")
+            .and_then(|v| v.as_str())
+            .or(self.action_id.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: action_id"))?;
+        let (instructions, params, preview) = crate::tools::zapier::execute_args(&args)?;
+        crate::tools::zapier::Client::connect(self.api_key.as_deref(), self.base_url.as_deref())?
+            .execute(action_id, instructions, &params, preview)
+    }
+
+    /// Run the Zapier action.
+    #[cfg(not(feature = "reqwest"))]
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!(
-            "ZapierActionTool: not yet implemented - requires Zapier NLA API integration"
+            "ZapierActionTool: HTTP support is not enabled - build with a feature that enables reqwest"
         )
     }
 }
@@ -265,8 +529,46 @@ impl LanceDbAdapter {
         "Vector search and storage via LanceDB"
     }
 
+    /// Translate a RAG query filter into a Lance `WHERE` clause.
+    ///
+    /// Returns `None` when the filter places no restriction.
+    #[cfg(feature = "rag")]
+    pub fn filter_expression(&self, filter: &crate::rag::filter::QueryFilter) -> Option<String> {
+        filter.to_lance_filter()
+    }
+
     /// Run a vector search query.
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!("LanceDbAdapter: not yet implemented - requires LanceDB integration")
     }
 }
+
+/// [`VectorStore`](crate::core::VectorStore) over a LanceDB table; like
+/// `run`, it awaits the LanceDB integration.
+#[cfg(feature = "rag")]
+impl crate::core::VectorStore for LanceDbAdapter {
+    fn add(
+        &self,
+        _chunks: Vec<crate::core::Chunk>,
+        _embeddings: Vec<Vec<f32>>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        anyhow::bail!("LanceDbAdapter: not yet implemented - requires LanceDB integration")
+    }
+
+    fn search(
+        &self,
+        _query: &[f32],
+        _top_k: usize,
+        _filter: Option<&crate::rag::filter::QueryFilter>,
+    ) -> Result<Vec<crate::rag::index::SearchHit>, anyhow::Error> {
+        anyhow::bail!("LanceDbAdapter: not yet implemented - requires LanceDB integration")
+    }
+
+    fn delete(&self, _ids: &[String]) -> Result<usize, anyhow::Error> {
+        anyhow::bail!("LanceDbAdapter: not yet implemented - requires LanceDB integration")
+    }
+
+    fn store_name(&self) -> &str {
+        "LanceDbAdapter"
+    }
+}
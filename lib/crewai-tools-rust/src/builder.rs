@@ -0,0 +1,13 @@
+//! Type-state markers for tool builders.
+//!
+//! Tools with required configuration expose a `builder()` whose `build()`
+//! method only exists once every required field has been supplied, so a
+//! missing field is a compile error rather than a runtime one.
+
+/// A required builder field that has not been supplied yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// A required builder field that has been supplied.
+#[derive(Debug, Clone)]
+pub struct Set<T>(pub(crate) T);
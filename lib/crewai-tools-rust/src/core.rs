@@ -0,0 +1,253 @@
+//! Core traits and types shared across features.
+//!
+//! This module defines the foundational abstractions used by loaders, chunkers,
+//! embedding services and (with the `rag` feature) vector stores. It is always
+//! compiled, so tools in any feature can accept an `EmbeddingService` or
+//! produce `Document`s without depending on the `rag` feature; `rag::core`
+//! re-exports it.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A single document loaded from a data source.
+///
+/// Corresponds to document representations in the Python `crewai_tools` RAG system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    /// The text content of the document.
+    pub content: String,
+    /// Metadata associated with the document (source, page number, etc.).
+    pub metadata: std::collections::HashMap<String, Value>,
+}
+
+impl Document {
+    /// Create a new document with the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Add a metadata key-value pair.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.metadata.insert(key.into(), value);
+        self
+    }
+}
+
+/// A chunk of text produced by splitting a document.
+///
+/// Corresponds to chunk representations in the Python `crewai_tools` RAG system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chunk {
+    /// The text content of the chunk.
+    pub content: String,
+    /// Metadata inherited from the source document plus chunk-specific info.
+    pub metadata: std::collections::HashMap<String, Value>,
+    /// Index of this chunk within the source document.
+    pub index: usize,
+}
+
+/// Trait for loading documents from various data sources.
+///
+/// Corresponds to the Python `BaseLoader` class in `crewai_tools.rag`.
+pub trait BaseLoader: Send + Sync {
+    /// Load documents from the configured data source.
+    ///
+    /// Returns a list of documents, or an error if loading fails.
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error>;
+
+    /// Returns the name of this loader.
+    fn loader_name(&self) -> &str;
+}
+
+/// Trait for splitting documents into smaller chunks.
+///
+/// Corresponds to the Python `BaseChunker` class in `crewai_tools.rag`.
+pub trait BaseChunker: Send + Sync {
+    /// Split a document into chunks.
+    ///
+    /// Returns a list of chunks, or an error if chunking fails.
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error>;
+
+    /// Returns the name of this chunker.
+    fn chunker_name(&self) -> &str;
+}
+
+/// Trait for generating text embeddings.
+///
+/// Corresponds to the Python `EmbeddingService` / embedding config in `crewai_tools.rag`.
+pub trait EmbeddingService: Send + Sync {
+    /// Generate an embedding vector for the given text.
+    ///
+    /// Returns a vector of f32 values, or an error if embedding fails.
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error>;
+
+    /// Generate embedding vectors for a batch of texts.
+    ///
+    /// Returns a list of embedding vectors, or an error if embedding fails.
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error>;
+
+    /// Returns the name of the embedding model.
+    fn model_name(&self) -> &str;
+
+    /// Returns the dimensionality of the embedding vectors.
+    fn dimensions(&self) -> usize;
+}
+
+/// Trait for storing embedded chunks and searching them by similarity.
+///
+/// Every backend is driven through the same calls, so a pipeline can move
+/// from [`InMemoryVectorStore`](crate::rag::index::InMemoryVectorStore) to
+/// a vector database without other changes. Chunk IDs are derived from the
+/// chunk text, so adding the same text again replaces it.
+#[cfg(feature = "rag")]
+pub trait VectorStore: Send + Sync {
+    /// Store `chunks` with their `embeddings` (one per chunk, in order).
+    ///
+    /// Returns the ID of each chunk.
+    fn add(
+        &self,
+        chunks: Vec<Chunk>,
+        embeddings: Vec<Vec<f32>>,
+    ) -> Result<Vec<String>, anyhow::Error>;
+
+    /// Return the `top_k` chunks most similar to `query`, best first.
+    ///
+    /// Chunks that fail `filter` are dropped before ranking.
+    fn search(
+        &self,
+        query: &[f32],
+        top_k: usize,
+        filter: Option<&crate::rag::filter::QueryFilter>,
+    ) -> Result<Vec<crate::rag::index::SearchHit>, anyhow::Error>;
+
+    /// Remove the chunks with these IDs.
+    ///
+    /// Returns the number removed; stores that cannot tell return the
+    /// number of IDs given.
+    fn delete(&self, ids: &[String]) -> Result<usize, anyhow::Error>;
+
+    /// Whether each of `ids` is already stored, in order.
+    ///
+    /// Stores that cannot check report none as present, so their chunks are
+    /// always embedded again.
+    fn contains(&self, ids: &[String]) -> Result<Vec<bool>, anyhow::Error> {
+        Ok(vec![false; ids.len()])
+    }
+
+    /// Save the store's contents to `path`. Stores kept on a server
+    /// persist on their own and return an error.
+    fn persist(&self, _path: &std::path::Path) -> Result<(), anyhow::Error> {
+        anyhow::bail!(
+            "{} keeps its data on its server and cannot persist to a file",
+            self.store_name()
+        )
+    }
+
+    /// Replace the store's contents with those saved at `path`.
+    fn load(&self, _path: &std::path::Path) -> Result<(), anyhow::Error> {
+        anyhow::bail!(
+            "{} keeps its data on its server and cannot load from a file",
+            self.store_name()
+        )
+    }
+
+    /// Returns the name of this store.
+    fn store_name(&self) -> &str;
+}
+
+/// A UUID-formatted ID derived from `text`, stable across runs and stores.
+#[cfg(any(feature = "database", feature = "rag"))]
+pub(crate) fn content_id(text: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let hex: String = Sha256::digest(text.as_bytes())[..16]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Trait for generating text with a language model.
+///
+/// Tools that need an LLM (such as `Nl2SqlTool`) take one of these rather
+/// than a specific provider. Any `Fn(&str) -> Result<String, anyhow::Error>`
+/// closure implements it.
+pub trait CompletionService: Send + Sync {
+    /// Complete the prompt, returning the model's reply.
+    fn complete(&self, prompt: &str) -> Result<String, anyhow::Error>;
+
+    /// Returns the name of the model.
+    fn model_name(&self) -> &str {
+        "custom"
+    }
+}
+
+impl<F> CompletionService for F
+where
+    F: Fn(&str) -> Result<String, anyhow::Error> + Send + Sync,
+{
+    fn complete(&self, prompt: &str) -> Result<String, anyhow::Error> {
+        self(prompt)
+    }
+}
+
+/// Cosine similarity of two vectors. Returns 0.0 for mismatched lengths or
+/// zero vectors.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a: f32 = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b: f32 = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// Moderation verdict for one text.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Moderation {
+    /// Whether the provider flagged the text.
+    pub flagged: bool,
+    /// Categories the provider flagged, e.g. `"harassment"`.
+    pub categories: Vec<String>,
+    /// Score per category, from 0.0 to 1.0.
+    pub scores: std::collections::BTreeMap<String, f64>,
+}
+
+/// Trait for checking texts against a content policy.
+///
+/// `ModerationTool` calls OpenAI's moderations endpoint unless given one
+/// of these. Any `Fn(&[&str]) -> Result<Vec<Moderation>, anyhow::Error>`
+/// closure implements it.
+pub trait ModerationService: Send + Sync {
+    /// Moderate a batch of texts, returning one verdict per text in order.
+    fn moderate(&self, texts: &[&str]) -> Result<Vec<Moderation>, anyhow::Error>;
+
+    /// Returns the name of the model.
+    fn model_name(&self) -> &str {
+        "custom"
+    }
+}
+
+impl<F> ModerationService for F
+where
+    F: Fn(&[&str]) -> Result<Vec<Moderation>, anyhow::Error> + Send + Sync,
+{
+    fn moderate(&self, texts: &[&str]) -> Result<Vec<Moderation>, anyhow::Error> {
+        self(texts)
+    }
+}
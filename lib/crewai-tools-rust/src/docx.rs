@@ -0,0 +1,218 @@
+//! DOCX parsing shared by `DocxSearchTool` and the RAG `DocxLoader`.
+//!
+//! A `.docx` is a zip archive whose body lives in `word/document.xml`.
+//! Images and other parts are referenced from the body by relationship id
+//! and resolved through `word/_rels/document.xml.rels`.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+
+/// Largest XML part we are willing to inflate (guards against zip bombs).
+const MAX_XML_BYTES: u64 = 64 * 1024 * 1024;
+
+/// The parts of a DOCX archive needed to read its body.
+pub(crate) struct DocxParts {
+    /// `word/document.xml`.
+    pub document: String,
+    /// Relationship id to target, e.g. `rId5` to `media/image1.png`.
+    #[cfg_attr(not(feature = "rag"), allow(dead_code))]
+    pub relationships: HashMap<String, String>,
+}
+
+/// Read the body and its relationships out of a DOCX archive.
+pub(crate) fn read(path: &str) -> Result<DocxParts, anyhow::Error> {
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open file '{}': {}", path, e))?;
+
+    // Password-protected Office files are OLE compound documents, not zips.
+    let mut magic = [0u8; 4];
+    let n = file.read(&mut magic)?;
+    if n == 4 && magic == [0xD0, 0xCF, 0x11, 0xE0] {
+        anyhow::bail!(
+            "'{}' is password-protected or a legacy .doc file; only unencrypted .docx is supported",
+            path
+        );
+    }
+    file.rewind()?;
+
+    let mut archive = zip::ZipArchive::new(file)
+        .map_err(|e| anyhow::anyhow!("'{}' is not a valid DOCX archive: {}", path, e))?;
+    let document = read_part(&mut archive, "word/document.xml", path)?.ok_or_else(|| {
+        anyhow::anyhow!("'{}' is not a DOCX file: missing word/document.xml", path)
+    })?;
+    let relationships = match read_part(&mut archive, "word/_rels/document.xml.rels", path)? {
+        Some(xml) => parse_relationships(&xml)
+            .map_err(|e| anyhow::anyhow!("'{}' is corrupt: {}", path, e))?,
+        None => HashMap::new(),
+    };
+    Ok(DocxParts {
+        document,
+        relationships,
+    })
+}
+
+/// One XML part of the archive, or `None` when it is absent.
+fn read_part(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+    name: &str,
+    path: &str,
+) -> Result<Option<String>, anyhow::Error> {
+    let entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(zip::result::ZipError::UnsupportedArchive(msg)) => anyhow::bail!(
+            "'{}' cannot be read ({}); encrypted DOCX files are not supported",
+            path,
+            msg
+        ),
+        Err(other) => anyhow::bail!("'{}' is corrupt: {}", path, other),
+    };
+    let mut xml = String::new();
+    entry
+        .take(MAX_XML_BYTES)
+        .read_to_string(&mut xml)
+        .map_err(|e| anyhow::anyhow!("'{}' is corrupt: {}", path, e))?;
+    Ok(Some(xml))
+}
+
+fn parse_relationships(xml: &str) -> Result<HashMap<String, String>, roxmltree::Error> {
+    let doc = roxmltree::Document::parse(xml)?;
+    Ok(doc
+        .descendants()
+        .filter(|n| is_element_named(n, "Relationship"))
+        .filter_map(|n| {
+            Some((n.attribute("Id")?.to_string(), n.attribute("Target")?.to_string()))
+        })
+        .collect())
+}
+
+/// Paragraphs and tables of the body in document order, looking through
+/// content controls (`w:sdt`) that wrap them.
+#[cfg_attr(not(feature = "rag"), allow(dead_code))]
+pub(crate) fn body_blocks<'a, 'input>(
+    body: roxmltree::Node<'a, 'input>,
+) -> Vec<roxmltree::Node<'a, 'input>> {
+    let mut blocks = Vec::new();
+    for child in body.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "p" | "tbl" => blocks.push(child),
+            "sdt" => {
+                for content in child.children().filter(|n| is_element_named(n, "sdtContent")) {
+                    blocks.extend(body_blocks(content));
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// Text of a `w:p`, with tabs and line breaks preserved.
+pub(crate) fn paragraph_text(p: &roxmltree::Node) -> String {
+    let mut text = String::new();
+    for n in p.descendants().filter(|n| n.is_element()) {
+        match n.tag_name().name() {
+            "t" => text.push_str(n.text().unwrap_or("")),
+            "tab" => text.push('\t'),
+            "br" | "cr" => text.push('\n'),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Heading level from the paragraph style (`Heading1`..`Heading9`, `Title` = 0).
+pub(crate) fn heading_level(p: &roxmltree::Node) -> Option<u32> {
+    let style = paragraph_property(p, "pStyle")?
+        .attributes()
+        .find(|a| a.name() == "val")?
+        .value()
+        .to_ascii_lowercase();
+    if style == "title" {
+        return Some(0);
+    }
+    style
+        .strip_prefix("heading")
+        .and_then(|rest| rest.trim().parse().ok())
+}
+
+/// Whether the paragraph is a numbered or bulleted list item.
+#[cfg_attr(not(feature = "rag"), allow(dead_code))]
+pub(crate) fn is_list_item(p: &roxmltree::Node) -> bool {
+    paragraph_property(p, "numPr").is_some()
+}
+
+fn paragraph_property<'a, 'input>(
+    p: &roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    p.children()
+        .find(|n| is_element_named(n, "pPr"))?
+        .children()
+        .find(|n| is_element_named(n, name))
+}
+
+/// A `w:tbl` as a Markdown table; the first row is the header.
+///
+/// Multi-paragraph cells are joined with spaces, pipes are escaped, and
+/// merged cells (`w:gridSpan`) leave empty cells so columns stay aligned.
+#[cfg_attr(not(feature = "rag"), allow(dead_code))]
+pub(crate) fn table_markdown(table: &roxmltree::Node) -> String {
+    let rows: Vec<Vec<String>> = table
+        .children()
+        .filter(|n| is_element_named(n, "tr"))
+        .map(|row| {
+            let mut cells = Vec::new();
+            for cell in row.children().filter(|n| is_element_named(n, "tc")) {
+                let text = cell
+                    .descendants()
+                    .filter(|n| is_element_named(n, "p"))
+                    .map(|p| paragraph_text(&p).split_whitespace().collect::<Vec<_>>().join(" "))
+                    .filter(|t| !t.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                cells.push(text.replace('|', "\\|"));
+                let span = cell
+                    .descendants()
+                    .find(|n| is_element_named(n, "gridSpan"))
+                    .and_then(|n| n.attributes().find(|a| a.name() == "val"))
+                    .and_then(|a| a.value().parse::<usize>().ok())
+                    .unwrap_or(1);
+                cells.resize(cells.len() + span.saturating_sub(1), String::new());
+            }
+            cells
+        })
+        .filter(|cells| !cells.is_empty())
+        .collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return String::new();
+    }
+
+    let line = |cells: &[String]| {
+        let padded = (0..width).map(|i| cells.get(i).map_or("", String::as_str));
+        format!("| {} |", padded.collect::<Vec<_>>().join(" | "))
+    };
+    let mut lines = vec![line(&rows[0]), format!("|{}", " --- |".repeat(width))];
+    lines.extend(rows[1..].iter().map(|row| line(row)));
+    lines.join("\n")
+}
+
+/// Relationship ids of the images drawn inside `node` (DrawingML blips and
+/// legacy VML image data).
+#[cfg_attr(not(feature = "rag"), allow(dead_code))]
+pub(crate) fn image_refs(node: &roxmltree::Node) -> Vec<String> {
+    node.descendants()
+        .filter(|n| is_element_named(n, "blip") || is_element_named(n, "imagedata"))
+        .filter_map(|n| {
+            n.attributes()
+                .find(|a| a.name() == "embed" || a.name() == "id")
+                .map(|a| a.value().to_string())
+        })
+        .collect()
+}
+
+/// Whether `n` is an element with local name `name`, ignoring its namespace.
+pub(crate) fn is_element_named(n: &roxmltree::Node, name: &str) -> bool {
+    n.is_element() && n.tag_name().name() == name
+}
@@ -0,0 +1,660 @@
+//! HTML to Markdown conversion, shared by the RAG framework and the
+//! scraping tools.
+//!
+//! A small, dependency-free converter aimed at documentation pages rather
+//! than full HTML fidelity. The one thing it is strict about is code:
+//! `<pre>` blocks are emitted verbatim as fenced blocks (with the language
+//! taken from a `language-*`/`lang-*` class when present) and inline `<code>`
+//! becomes backticks, so snippets survive chunking and retrieval intact.
+//! Tables become GitHub-flavoured pipe tables, one row per line.
+
+/// Classes that name a language directly, as rustdoc and some highlighters emit.
+const KNOWN_LANGUAGES: &[&str] = &[
+    "bash", "c", "cpp", "csharp", "css", "go", "html", "java", "javascript", "js", "json",
+    "kotlin", "python", "py", "ruby", "rust", "sh", "shell", "sql", "swift", "toml", "ts",
+    "typescript", "xml", "yaml",
+];
+
+/// Elements whose content is never rendered.
+const SKIPPED_ELEMENTS: &[&str] = &["script", "style", "noscript", "head", "template", "svg"];
+
+/// Page chrome dropped by [`page_to_markdown`] and [`page_to_text`] in
+/// addition to `SKIPPED_ELEMENTS`.
+const PAGE_CHROME: &[&str] = &["nav", "footer", "aside", "form", "iframe"];
+
+/// Elements that start a new block.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "p", "div", "section", "article", "header", "footer", "main", "nav", "aside", "ul", "ol",
+    "table", "tr", "blockquote", "figure", "details", "summary", "dl", "dt", "dd",
+];
+
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Start { name: String, attrs: Vec<(String, String)>, self_closing: bool },
+    End { name: String },
+}
+
+/// Convert an HTML document or fragment to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    convert(html, SKIPPED_ELEMENTS)
+}
+
+/// Convert a full web page to readable Markdown, additionally dropping
+/// navigation and footer blocks.
+pub fn page_to_markdown(html: &str) -> String {
+    let skipped: Vec<&str> = SKIPPED_ELEMENTS.iter().chain(PAGE_CHROME).copied().collect();
+    convert(html, &skipped)
+}
+
+/// Convert a full web page to plain text: the content `page_to_markdown`
+/// keeps, with block structure preserved as line breaks but no Markdown
+/// syntax.
+pub fn page_to_text(html: &str) -> String {
+    let skipped: Vec<&str> = SKIPPED_ELEMENTS.iter().chain(PAGE_CHROME).copied().collect();
+    let tokens = tokenize(html);
+    let mut out = Renderer::default();
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Text(text) => out.text(text),
+            Token::Start { name, self_closing, .. } => match name.as_str() {
+                n if skipped.contains(&n) && !self_closing => {
+                    i = skip_to_end(&tokens, i, n);
+                    continue;
+                }
+                "pre" => {
+                    let (code, _, end) = collect_pre(&tokens, i, &[]);
+                    out.blank_line();
+                    out.raw(code.trim_matches('\n'));
+                    out.blank_line();
+                    i = end + 1;
+                    continue;
+                }
+                "br" | "li" | "tr" => out.newline(),
+                "td" | "th" => out.raw(" "),
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "hr" => out.blank_line(),
+                n if BLOCK_ELEMENTS.contains(&n) => out.blank_line(),
+                _ => {}
+            },
+            Token::End { name } => match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => out.blank_line(),
+                n if BLOCK_ELEMENTS.contains(&n) => out.blank_line(),
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+    out.finish()
+}
+
+/// The document's `<title>`, with entities decoded and whitespace collapsed.
+pub fn html_title(html: &str) -> Option<String> {
+    let tokens = tokenize(html);
+    let start = tokens.iter().position(|t| {
+        matches!(t, Token::Start { name, self_closing: false, .. } if name == "title")
+    })?;
+    let (title, _) = collect_text(&tokens, start, "title");
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+fn convert(html: &str, skipped: &[&str]) -> String {
+    render(&tokenize(html), skipped)
+}
+
+fn render(tokens: &[Token], skipped: &[&str]) -> String {
+    let mut out = Renderer::default();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Text(text) => out.text(text),
+            Token::Start { name, attrs, self_closing } => {
+                let name = name.as_str();
+                if skipped.contains(&name) && !self_closing {
+                    i = skip_to_end(tokens, i, name);
+                    continue;
+                }
+                match name {
+                    "table" if !self_closing => {
+                        let (rows, end) = collect_table(tokens, i, skipped);
+                        out.table(&rows);
+                        i = end;
+                        continue;
+                    }
+                    "pre" => {
+                        let (code, language, end) = collect_pre(tokens, i, attrs);
+                        out.code_block(&code, language.as_deref());
+                        i = end + 1;
+                        continue;
+                    }
+                    "code" if !self_closing => {
+                        let (code, end) = collect_text(tokens, i, "code");
+                        out.inline_code(&code);
+                        i = end + 1;
+                        continue;
+                    }
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                        let level = name[1..].parse::<usize>().unwrap_or(1);
+                        out.blank_line();
+                        out.raw(&"#".repeat(level));
+                        out.raw(" ");
+                    }
+                    "br" => out.newline(),
+                    "hr" => {
+                        out.blank_line();
+                        out.raw("---");
+                        out.blank_line();
+                    }
+                    "li" => {
+                        out.newline();
+                        let marker = match out.lists.last_mut() {
+                            Some(Some(n)) => {
+                                *n += 1;
+                                format!("{}. ", n)
+                            }
+                            _ => "- ".to_string(),
+                        };
+                        out.raw(&"  ".repeat(out.lists.len().saturating_sub(1)));
+                        out.raw(&marker);
+                    }
+                    "ul" => {
+                        out.blank_line_unless_nested();
+                        out.lists.push(None);
+                    }
+                    "ol" => {
+                        out.blank_line_unless_nested();
+                        out.lists.push(Some(0));
+                    }
+                    "strong" | "b" => out.raw("**"),
+                    "em" | "i" => out.raw("*"),
+                    "a" => {
+                        out.links.push(attr(attrs, "href").map(String::from));
+                        out.raw("[");
+                    }
+                    "img" => {
+                        let alt = attr(attrs, "alt").unwrap_or("");
+                        if let Some(src) = attr(attrs, "src") {
+                            out.raw(&format!("![{}]({})", alt, src));
+                        }
+                    }
+                    "blockquote" => {
+                        out.blank_line();
+                        out.raw("> ");
+                    }
+                    n if BLOCK_ELEMENTS.contains(&n) => out.blank_line(),
+                    _ => {}
+                }
+            }
+            Token::End { name } => match name.as_str() {
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => out.blank_line(),
+                "strong" | "b" => out.raw("**"),
+                "em" | "i" => out.raw("*"),
+                "a" => {
+                    out.raw("]");
+                    if let Some(Some(href)) = out.links.pop() {
+                        out.raw(&format!("({})", href));
+                    }
+                }
+                "ul" | "ol" => {
+                    out.lists.pop();
+                    out.blank_line_unless_nested();
+                }
+                n if BLOCK_ELEMENTS.contains(&n) => out.blank_line(),
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+
+    out.finish()
+}
+
+// ── Rendering ────────────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct Renderer {
+    out: String,
+    /// Open lists: `None` for `<ul>`, `Some(counter)` for `<ol>`.
+    lists: Vec<Option<usize>>,
+    /// Hrefs of open `<a>` elements.
+    links: Vec<Option<String>>,
+}
+
+impl Renderer {
+    fn at_line_start(&self) -> bool {
+        self.out.is_empty() || self.out.ends_with('\n')
+    }
+
+    /// Append collapsed text.
+    fn text(&mut self, text: &str) {
+        let decoded = decode_entities(text);
+        if decoded.trim().is_empty() {
+            // Whitespace between inline elements still separates words.
+            if !decoded.is_empty() && !self.at_line_start() && !self.out.ends_with(' ') {
+                self.out.push(' ');
+            }
+            return;
+        }
+        let mut pending_space = decoded.starts_with(char::is_whitespace);
+        for word in decoded.split_whitespace() {
+            if pending_space && !self.at_line_start() && !self.out.ends_with(' ') {
+                self.out.push(' ');
+            }
+            self.out.push_str(word);
+            pending_space = true;
+        }
+        if decoded.ends_with(char::is_whitespace) {
+            self.out.push(' ');
+        }
+    }
+
+    fn raw(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let trimmed = self.out.trim_end_matches([' ', '\t']).len();
+        self.out.truncate(trimmed);
+    }
+
+    fn newline(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.at_line_start() {
+            self.out.push('\n');
+        }
+    }
+
+    fn blank_line(&mut self) {
+        let trimmed = self.out.trim_end().len();
+        self.out.truncate(trimmed);
+        if !self.out.is_empty() {
+            self.out.push_str("\n\n");
+        }
+    }
+
+    fn blank_line_unless_nested(&mut self) {
+        if self.lists.is_empty() {
+            self.blank_line();
+        } else {
+            self.newline();
+        }
+    }
+
+    fn inline_code(&mut self, code: &str) {
+        let code = code.trim_matches('\n');
+        let ticks = "`".repeat(longest_backtick_run(code) + 1);
+        let pad = if code.starts_with('`') || code.ends_with('`') { " " } else { "" };
+        self.out.push_str(&format!("{ticks}{pad}{code}{pad}{ticks}"));
+    }
+
+    fn code_block(&mut self, code: &str, language: Option<&str>) {
+        let fence = "`".repeat(longest_backtick_run(code).max(2) + 1);
+        self.blank_line();
+        self.out.push_str(&fence);
+        self.out.push_str(language.unwrap_or(""));
+        self.out.push('\n');
+        self.out.push_str(code.trim_matches('\n'));
+        self.out.push('\n');
+        self.out.push_str(&fence);
+        self.out.push_str("\n\n");
+    }
+
+    /// Emit a pipe table. The first row is the header; short rows are padded.
+    fn table(&mut self, rows: &[Vec<String>]) {
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        self.blank_line();
+        for (n, row) in rows.iter().enumerate() {
+            let cells = (0..columns).map(|c| row.get(c).map_or("", String::as_str));
+            self.out.push_str("| ");
+            self.out.push_str(&cells.collect::<Vec<_>>().join(" | "));
+            self.out.push_str(" |\n");
+            if n == 0 {
+                self.out.push('|');
+                self.out.push_str(&" --- |".repeat(columns));
+                self.out.push('\n');
+            }
+        }
+        self.out.push('\n');
+    }
+
+    fn finish(mut self) -> String {
+        let trimmed = self.out.trim_end().len();
+        self.out.truncate(trimmed);
+        self.out
+    }
+}
+
+fn longest_backtick_run(s: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in s.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    longest
+}
+
+// ── Code extraction ──────────────────────────────────────────────────────────
+
+/// Collect the verbatim text of a `<pre>` starting at `start`, plus its
+/// language hint. Returns the index of the closing `</pre>`.
+fn collect_pre(tokens: &[Token], start: usize, pre_attrs: &[(String, String)]) -> (String, Option<String>, usize) {
+    let mut language = language_hint(pre_attrs);
+    let mut code = String::new();
+    let mut depth = 0;
+    let mut i = start + 1;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Text(t) => code.push_str(&decode_entities(t)),
+            Token::Start { name, attrs, .. } => {
+                if name == "pre" {
+                    depth += 1;
+                } else if name == "br" {
+                    code.push('\n');
+                } else if name == "code" && language.is_none() {
+                    language = language_hint(attrs);
+                }
+            }
+            Token::End { name } if name == "pre" => {
+                if depth == 0 {
+                    return (code, language, i);
+                }
+                depth -= 1;
+            }
+            Token::End { .. } => {}
+        }
+        i += 1;
+    }
+    (code, language, tokens.len() - 1)
+}
+
+// ── Tables ───────────────────────────────────────────────────────────────────
+
+/// Collect the rows of the `<table>` starting at `start`, each cell rendered
+/// to single-line Markdown (a nested table is flattened into its cell). A
+/// header row (`<th>` cells or `<thead>`) is moved first. Returns the index
+/// just past the closing `</table>`.
+fn collect_table(tokens: &[Token], start: usize, skipped: &[&str]) -> (Vec<Vec<String>>, usize) {
+    let end = skip_to_end(tokens, start, "table");
+    // `end` is past `</table>`, or the end of input for an unclosed table.
+    let closed = matches!(
+        tokens.get(end.wrapping_sub(1)),
+        Some(Token::End { name }) if name == "table"
+    );
+    let inner = &tokens[start + 1..if closed { end - 1 } else { end }];
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut header: Option<usize> = None;
+    let mut in_thead = false;
+    let mut i = 0;
+    while i < inner.len() {
+        match &inner[i] {
+            Token::Start { name, .. } if name == "thead" => in_thead = true,
+            Token::End { name } if name == "thead" => in_thead = false,
+            Token::Start { name, .. } if name == "tr" => {
+                rows.push(Vec::new());
+                if in_thead && header.is_none() {
+                    header = Some(rows.len() - 1);
+                }
+            }
+            Token::Start { name, self_closing: false, .. } if name == "td" || name == "th" => {
+                let cell_end = cell_end(inner, i);
+                let cell = render(&inner[i + 1..cell_end], skipped);
+                if rows.is_empty() {
+                    rows.push(Vec::new());
+                }
+                if name == "th" && header.is_none() {
+                    header = Some(rows.len() - 1);
+                }
+                rows.last_mut().unwrap().push(table_cell(&cell));
+                i = cell_end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    rows.retain(|row| !row.is_empty());
+    if let Some(h) = header.filter(|&h| h > 0 && h < rows.len()) {
+        let row = rows.remove(h);
+        rows.insert(0, row);
+    }
+    (rows, end)
+}
+
+/// Index of the token ending the cell opened at `start`: its closing tag, or
+/// the next cell/row when the end tag is omitted.
+fn cell_end(tokens: &[Token], start: usize) -> usize {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start + 1) {
+        match token {
+            Token::Start { name, self_closing: false, .. } if name == "table" => depth += 1,
+            Token::End { name } if name == "table" => depth -= 1,
+            Token::Start { name, .. } | Token::End { name }
+                if depth == 0 && matches!(name.as_str(), "td" | "th" | "tr") =>
+            {
+                return i;
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+/// Squash rendered cell content onto one line and escape pipes.
+fn table_cell(markdown: &str) -> String {
+    markdown
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
+/// Collect the text inside an element, ignoring nested markup.
+fn collect_text(tokens: &[Token], start: usize, element: &str) -> (String, usize) {
+    let mut text = String::new();
+    let mut i = start + 1;
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Text(t) => text.push_str(&decode_entities(t)),
+            Token::End { name } if name == element => return (text, i),
+            _ => {}
+        }
+        i += 1;
+    }
+    (text, tokens.len() - 1)
+}
+
+fn skip_to_end(tokens: &[Token], start: usize, element: &str) -> usize {
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start + 1) {
+        match token {
+            Token::Start { name, self_closing: false, .. } if name == element => depth += 1,
+            Token::End { name } if name == element => {
+                if depth == 0 {
+                    return i + 1;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+/// Language from a class list: `language-rust`, `lang-py`,
+/// `highlight-source-js`, or a bare known language name (`rust`).
+fn language_hint(attrs: &[(String, String)]) -> Option<String> {
+    let classes = attr(attrs, "class")?;
+    for class in classes.split_whitespace() {
+        for prefix in ["language-", "lang-", "highlight-source-", "highlight-"] {
+            if let Some(lang) = class.strip_prefix(prefix) {
+                if !lang.is_empty() {
+                    return Some(lang.to_string());
+                }
+            }
+        }
+    }
+    classes
+        .split_whitespace()
+        .find(|c| KNOWN_LANGUAGES.contains(&c.to_ascii_lowercase().as_str()))
+        .map(|c| c.to_ascii_lowercase())
+}
+
+fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attrs.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+}
+
+// ── Tokenizing ───────────────────────────────────────────────────────────────
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(lt) = rest.find('<') else {
+            tokens.push(Token::Text(rest.to_string()));
+            break;
+        };
+        if lt > 0 {
+            tokens.push(Token::Text(rest[..lt].to_string()));
+        }
+        rest = &rest[lt..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+            continue;
+        }
+
+        let Some(end) = tag_end(rest) else {
+            // A stray '<' that never closes is just text.
+            tokens.push(Token::Text(rest.to_string()));
+            break;
+        };
+        let inner = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = inner.strip_prefix('/') {
+            tokens.push(Token::End {
+                name: name.trim().to_ascii_lowercase(),
+            });
+            continue;
+        }
+        let self_closing = inner.ends_with('/');
+        let inner = inner.trim_end_matches('/');
+        let name_end = inner.find(|c: char| c.is_whitespace()).unwrap_or(inner.len());
+        let name = inner[..name_end].to_ascii_lowercase();
+        if name.is_empty() || !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            tokens.push(Token::Text(format!("<{}>", inner)));
+            continue;
+        }
+        let void = matches!(name.as_str(), "br" | "hr" | "img" | "meta" | "link" | "input" | "wbr");
+        tokens.push(Token::Start {
+            attrs: parse_attrs(&inner[name_end..]),
+            name,
+            self_closing: self_closing || void,
+        });
+    }
+    tokens
+}
+
+/// Index of the `>` closing the tag at the start of `s`, skipping quoted values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices().skip(1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_attrs(s: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            if let Some(q) = after.chars().next().filter(|c| *c == '"' || *c == '\'') {
+                let body = &after[1..];
+                let close = body.find(q).unwrap_or(body.len());
+                value = decode_entities(&body[..close]);
+                rest = body.get(close + 1..).unwrap_or("");
+            } else {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                value = decode_entities(&after[..end]);
+                rest = &after[end..];
+            }
+        }
+        if !key.is_empty() {
+            attrs.push((key, value));
+        }
+        rest = rest.trim_start();
+    }
+    attrs
+}
+
+/// Decode the common named entities and numeric character references.
+pub fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                e if e.starts_with("#x") || e.starts_with("#X") => {
+                    u32::from_str_radix(&e[2..], 16).ok().and_then(char::from_u32)
+                }
+                e if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+                _ => None,
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -8,12 +8,14 @@
 //! - **web_scraping** - Website scraping and content extraction tools
 //! - **database** - Vector database and SQL query tools
 //! - **file_ops** - File reading, writing, and compression tools
-//! - **ai_ml** - AI/ML service integration tools (DALL-E, vision, OCR, RAG)
-//! - **automation** - Workflow automation tools (Composio, Zapier, Apify)
-//! - **cloud_storage** - Cloud storage tools (S3, Bedrock)
-//! - **browser** - Browser automation tools (Browserbase, Hyperbrowser)
+//! - **ai_ml** - AI/ML service integration tools (DALL-E, vision, OCR, transcription, moderation, RAG)
+//! - **automation** - Workflow automation tools (Composio, Zapier, Apify, webhooks, scheduling)
+//! - **cloud_storage** - Cloud storage tools (S3, GCS, Azure Blob, Bedrock)
+//! - **browser** - Browser automation tools (Browserbase, Hyperbrowser, local Chromium)
 //! - **rag** - Retrieval-Augmented Generation framework (loaders, chunkers, embeddings)
 //!
+//! Most crews only need `use crewai_tools::prelude::*;` — see [`prelude`].
+//!
 //! ## Feature Flags
 //!
 //! By default, the `search` and `file_ops` features are enabled. Use the `all` feature
@@ -22,8 +24,9 @@
 /// Crate version, matching the Python crewai-tools package version.
 pub const VERSION: &str = "1.9.3";
 
-// ── Adapters (always available) ──────────────────────────────────────────────
+// ── Adapters and core traits (always available) ─────────────────────────────
 pub mod adapters;
+pub mod core;
 
 // ── Tool modules (feature-gated) ────────────────────────────────────────────
 pub mod tools;
@@ -32,72 +35,145 @@ pub mod tools;
 #[cfg(feature = "rag")]
 pub mod rag;
 
+// ── Prelude, builder markers and schema checking ─────────────────────────────
+pub mod builder;
+pub mod prelude;
+pub mod schema;
+
+// ── Shared document parsing ─────────────────────────────────────────────────
+#[cfg(feature = "lopdf")]
+pub(crate) mod pdf;
+#[cfg(any(feature = "search", feature = "rag"))]
+pub(crate) mod docx;
+#[cfg(any(feature = "search", feature = "rag"))]
+pub(crate) mod youtube;
+#[cfg(any(feature = "search", feature = "rag"))]
+pub(crate) mod xpath;
+#[cfg(any(feature = "search", feature = "rag"))]
+pub(crate) mod markdown;
+#[cfg(any(feature = "search", feature = "rag"))]
+pub(crate) mod tabular;
+#[cfg(any(feature = "rag", feature = "web_scraping"))]
+pub mod html;
+
 // ── Re-exports ──────────────────────────────────────────────────────────────
 
 // Adapters
 pub use adapters::{
-    EnterpriseActionTool, McpServerAdapter, RagAdapter, LanceDbAdapter, ZapierActionTool,
+    EnterpriseActionTool, EnterpriseActionToolBuilder, McpServerAdapter, RagAdapter,
+    LanceDbAdapter, ZapierActionTool,
 };
 
+// Core traits
+pub use crate::core::{
+    BaseChunker, BaseLoader, Chunk, CompletionService, Document, EmbeddingService, Moderation,
+    ModerationService,
+};
+#[cfg(feature = "rag")]
+pub use crate::core::VectorStore;
+
 // Search tools
 #[cfg(feature = "search")]
 pub use tools::search::{
-    ArxivPaperTool, BraveSearchTool, CodeDocsSearchTool, CsvSearchTool, DirectorySearchTool,
-    DocxSearchTool, ExaSearchTool, GithubSearchTool, JsonSearchTool, LinkupSearchTool,
-    MdxSearchTool, MySqlSearchTool, ParallelSearchTool, PdfSearchTool, SerperDevTool,
-    TavilySearchTool, TxtSearchTool, WebsiteSearchTool, XmlSearchTool,
-    YoutubeChannelSearchTool, YoutubeVideoSearchTool,
+    ArxivPaperTool, BraveSearchTool, CacheStats, CodeDocsSearchTool, CsvSearchTool,
+    DirectorySearchTool, DocxSearchTool, ExaSearchTool, GithubSearchTool, JsonSearchTool,
+    LinkupSearchTool, MdxSearchTool, MySqlSearchTool, ParallelSearchTool, PdfSearchTool,
+    SearchCache, SearchResult, SerperDateRange, SerperDevTool, SerperSearchType, TavilySearchTool,
+    TxtSearchTool, WebsiteSearchTool, XmlSearchTool, YoutubeChannelSearchTool,
+    YoutubeVideoSearchTool,
 };
 
+// YouTube transcripts, shared by YoutubeVideoSearchTool and YoutubeVideoLoader
+#[cfg(any(feature = "search", feature = "rag"))]
+pub use youtube::NoTranscript;
+
 // Web scraping tools
 #[cfg(feature = "web_scraping")]
 pub use tools::web_scraping::{
     FirecrawlCrawlWebsiteTool, FirecrawlScrapeWebsiteTool, FirecrawlSearchTool,
-    JinaScrapeWebsiteTool, ScrapeElementFromWebsiteTool, ScrapeWebsiteTool,
-    ScrapegraphScrapeTool, ScrapflyScrapeWebsiteTool, SeleniumScrapingTool,
-    SerperScrapeWebsiteTool, SpiderTool,
+    JinaScrapeWebsiteTool, OutputFormat, ScrapeElementFromWebsiteTool, ScrapeWebsiteTool,
+    ScrapegraphScrapeTool, ScrapflyScrapeWebsiteTool, ScrapflyThrottled,
+    SeleniumScrapingTool, SerperScrapeWebsiteTool, SpiderError, SpiderTool,
 };
 
 // Database tools
 #[cfg(feature = "database")]
 pub use tools::database::{
-    CouchbaseFtsVectorSearchTool, DatabricksQueryTool, MongoDbVectorSearchTool, Nl2SqlTool,
-    QdrantVectorSearchTool, SingleStoreSearchTool, SnowflakeSearchTool,
-    WeaviateVectorSearchTool,
+    ConnectionPool, CouchbaseFtsVectorSearchTool, CouchbaseFtsVectorSearchToolBuilder,
+    DatabricksQueryTool, IngestReport, MongoDbError, MongoDbVectorSearchTool,
+    MongoDbVectorSearchToolBuilder, Nl2SqlTool, PgVectorIndex, PgVectorSearchTool,
+    PgVectorSearchToolBuilder, PoolTimeout, PooledConnection, QdrantError, QdrantVectorSearchTool,
+    QdrantVectorSearchToolBuilder, SingleStoreSearchTool, SnowflakeError, SnowflakeSearchTool,
+    SqliteSearchTool, SqliteSearchToolBuilder, WeaviateVectorSearchTool,
+    WeaviateVectorSearchToolBuilder,
 };
 
 // File operation tools
 #[cfg(feature = "file_ops")]
-pub use tools::file_ops::{DirectoryReadTool, FileCompressorTool, FileReadTool, FileWriterTool};
+pub use tools::file_ops::{
+    CsvWriterTool, DirectoryReadTool, FileChecksumTool, FileCompressorTool, FileDecompressorTool,
+    FileManageTool, FileReadTool, FileWriterTool, JsonWriterTool, StructuredFileWriterTool,
+};
 
 // AI/ML tools
 #[cfg(feature = "ai_ml")]
-pub use tools::ai_ml::{AiMindTool, DalleTool, LlamaIndexTool, OcrTool, RagTool, VisionTool};
+pub use tools::ai_ml::{
+    AiMindTool, DalleTool, EmbeddingTool, LlamaIndexTool, ModerationTool, OcrEngine, OcrTool,
+    RagTool, TranscriptionTool, VisionTool,
+};
 
 // Automation tools
 #[cfg(feature = "automation")]
 pub use tools::automation::{
-    ApifyActorsTool, ComposioTool, GenerateCrewaiAutomationTool, InvokeCrewaiAutomationTool,
-    MergeAgentHandlerTool, ZapierActionTools,
+    ApifyActorsTool, ComposioTool, CronScheduleTool, GenerateCrewaiAutomationTool,
+    InvokeCrewaiAutomationTool, JobExecutor, MergeAgentHandlerTool, ProgressCallback, WebhookTool,
+    ZapierActionTools,
 };
 
+// Credit budgets for metered tools
+#[cfg(any(feature = "web_scraping", feature = "automation"))]
+pub use tools::credit_budget::CreditBudget;
+
+// robots.txt and rate limits for the native scraping tools
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub use tools::crawl_policy::{HostRateLimiter, RobotsCache, RobotsDisallowed};
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub use tools::custom_headers::CustomHeaders;
+
 // Cloud storage tools
 #[cfg(feature = "cloud_storage")]
 pub use tools::cloud_storage::{
-    BedrockInvokeAgentTool, BedrockKbRetrieverTool, S3ReaderTool, S3WriterTool,
+    AzureBlobError, AzureBlobReaderTool, AzureBlobWriterTool, BedrockInvokeAgentTool,
+    BedrockInvokeAgentToolBuilder, BedrockKbRetrieverTool, BedrockKbRetrieverToolBuilder,
+    GcsReaderTool, GcsWriterTool, S3Error, S3ReaderTool, S3WriterTool,
 };
 
 // Browser tools
 #[cfg(feature = "browser")]
-pub use tools::browser::{BrowserbaseLoadTool, HyperbrowserLoadTool, MultiOnTool, StagehandTool};
+pub use tools::browser::{
+    BrowserbaseLoadTool, HyperbrowserLoadTool, MultiOnTool, PlaywrightBrowserTool, StagehandTool,
+};
 
 // RAG framework
 #[cfg(feature = "rag")]
 pub use rag::{
-    chunkers::{DefaultChunker, StructuredChunker, TextChunker, WebChunker},
-    core::{BaseChunker, BaseLoader, EmbeddingService},
+    chunkers::{
+        CodeChunker, DefaultChunker, SemanticChunker, StructuredChunker, TextChunker,
+        TokenChunker, WebChunker,
+    },
+    embeddings::{
+        embedding_service, CachingEmbeddingService, OllamaEmbeddingService, OpenAiEmbeddingService,
+    },
+    filter::{DateRange, MetadataCondition, QueryFilter},
+    html::html_to_markdown,
+    language::{detect_language, DetectedLanguage},
+    index::{InMemoryVectorIndex, InMemoryVectorStore, SearchHit},
     loaders::{
-        CsvLoader, DirectoryLoader, DocxLoader, GithubLoader, JsonLoader, PdfLoader, TextLoader,
+        CsvLoadMode, CsvLoader, DirectoryLoadReport, DirectoryLoader, DocxLoader, FileLoadError,
+        GithubLoader, GithubLoaderError, JsonLoader, MarkdownLoader, PdfLoader, TextLoader,
         WebpageLoader, XmlLoader, YoutubeVideoLoader,
     },
+    pipeline::{IngestSummary, LowConfidencePolicy, QueryResult, RagPipeline, RagPipelineBuilder},
 };
+#[cfg(all(feature = "rag", feature = "cloud_storage"))]
+pub use rag::loaders::S3Loader;
@@ -0,0 +1,38 @@
+//! Markdown scanning shared by `MdxSearchTool` and the RAG `MarkdownLoader`:
+//! frontmatter, fenced code blocks and ATX headings.
+
+/// Index of the first line after a leading `---` YAML frontmatter block.
+pub(crate) fn skip_frontmatter(lines: &[&str]) -> usize {
+    if lines.first().map(|l| l.trim_end()) != Some("---") {
+        return 0;
+    }
+    match lines[1..].iter().position(|l| matches!(l.trim_end(), "---" | "...")) {
+        Some(end) => end + 2,
+        None => 0,
+    }
+}
+
+/// The fence marker if `line` opens a fenced code block.
+pub(crate) fn code_fence(line: &str) -> Option<&'static str> {
+    if line.starts_with("```") {
+        Some("```")
+    } else if line.starts_with("~~~") {
+        Some("~~~")
+    } else {
+        None
+    }
+}
+
+/// Parse an ATX heading (`## Title`) into its level and text.
+pub(crate) fn atx_heading(line: &str) -> Option<(usize, String)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim();
+    (!title.is_empty()).then(|| (level, title.to_string()))
+}
@@ -0,0 +1,58 @@
+//! PDF text extraction shared by `PdfSearchTool` and the RAG `PdfLoader`.
+//!
+//! Text is extracted one page at a time so callers can stream through large
+//! documents instead of materializing the whole text up front.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// An opened PDF whose pages can be extracted individually.
+pub(crate) struct PdfPages {
+    doc: lopdf::Document,
+    pages: BTreeMap<u32, lopdf::ObjectId>,
+}
+
+impl PdfPages {
+    /// Open a PDF. Encrypted files that cannot be opened with an empty
+    /// password are rejected with a clear error.
+    pub(crate) fn open(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let path = path.as_ref();
+        let doc = lopdf::Document::load(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read PDF '{}': {}", path.display(), e))?;
+        if doc.is_encrypted() {
+            anyhow::bail!("PDF '{}' is password-protected", path.display());
+        }
+        let pages = doc.get_pages();
+        Ok(Self { doc, pages })
+    }
+
+    pub(crate) fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// 1-based page numbers in order.
+    pub(crate) fn page_numbers(&self) -> impl Iterator<Item = u32> + '_ {
+        self.pages.keys().copied()
+    }
+
+    /// Extracted text of one page. Pages whose content cannot be decoded
+    /// yield an empty string rather than failing the whole document.
+    pub(crate) fn page_text(&self, page: u32) -> String {
+        match self.doc.extract_text(&[page]) {
+            Ok(text) => text,
+            Err(e) => {
+                log::warn!("Failed to extract text from PDF page {}: {}", page, e);
+                String::new()
+            }
+        }
+    }
+
+    /// Whether the page embeds at least one image.
+    #[cfg_attr(not(feature = "search"), allow(dead_code))]
+    pub(crate) fn page_has_images(&self, page: u32) -> bool {
+        self.pages
+            .get(&page)
+            .and_then(|id| self.doc.get_page_images(*id).ok())
+            .is_some_and(|images| !images.is_empty())
+    }
+}
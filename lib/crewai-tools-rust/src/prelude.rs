@@ -0,0 +1,127 @@
+//! Convenience re-exports for building a set of tools.
+//!
+//! `use crewai_tools::prelude::*;` brings in the core traits, the adapters,
+//! the builder markers, every tool of every enabled feature, and the option
+//! types those tools are configured with (credit budgets, custom headers,
+//! crawl policies, caches, connection pools, query filters). Error types
+//! stay at the crate root.
+//!
+//! Tools with required settings also have a `builder()` whose `build()`
+//! only compiles once they are supplied; the rest are built with `new()`
+//! and `with_*`.
+//!
+//! The single-action `ZapierActionTool` adapter is left out: its `with_*`
+//! methods mirror those of the `ZapierActionTools` tool it would sit next
+//! to. Get one from `ZapierActionTools::action_tool` instead, which reuses
+//! the tool's key and API root, or import it from the crate root.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use crewai_tools::prelude::*;
+//!
+//! let serper = SerperDevTool::new().with_api_key("key").with_max_results(5);
+//! let brave = BraveSearchTool::new().with_max_results(5);
+//! let csv = CsvSearchTool::new().with_file_path("data.csv");
+//! let json = JsonSearchTool::new().with_file_path("data.json");
+//! let txt = TxtSearchTool::new().with_file_path("notes.txt").with_max_results(20);
+//! let xml = XmlSearchTool::new().with_file_path("catalog.xml");
+//! let mdx = MdxSearchTool::new().with_file_path("docs/").with_max_results(20);
+//! let pdf = PdfSearchTool::new().with_file_path("report.pdf").with_snippet_chars(80);
+//! let docx = DocxSearchTool::new().with_file_path("spec.docx");
+//! let reader = FileReadTool::new().with_file_path("README.md");
+//! let writer = FileWriterTool::new().with_directory("out").with_verify(true);
+//! let structured = StructuredFileWriterTool::new().with_filename("result.json").with_checksum(true);
+//! let listing = DirectoryReadTool::new().with_directory(".");
+//! let action = EnterpriseActionTool::builder()
+//!     .action_name("crm_create_contact")
+//!     .api_endpoint("https://actions.example.com/run")
+//!     .build();
+//! ```
+
+pub use crate::adapters::{EnterpriseActionTool, LanceDbAdapter, McpServerAdapter, RagAdapter};
+pub use crate::builder::{Missing, Set};
+pub use crate::core::{
+    BaseChunker, BaseLoader, Chunk, CompletionService, Document, EmbeddingService, Moderation,
+    ModerationService,
+};
+#[cfg(feature = "rag")]
+pub use crate::core::VectorStore;
+
+#[cfg(feature = "search")]
+pub use crate::tools::search::{
+    ArxivPaperTool, BraveSearchTool, CodeDocsSearchTool, CsvSearchTool, DirectorySearchTool,
+    DocxSearchTool, ExaSearchTool, GithubSearchTool, JsonSearchTool, LinkupSearchTool,
+    MdxSearchTool, MySqlSearchTool, ParallelSearchTool, PdfSearchTool, SearchCache, SearchResult,
+    SerperDateRange, SerperDevTool, SerperSearchType, TavilySearchTool, TxtSearchTool,
+    WebsiteSearchTool, XmlSearchTool, YoutubeChannelSearchTool, YoutubeVideoSearchTool,
+};
+
+#[cfg(feature = "web_scraping")]
+pub use crate::tools::web_scraping::{
+    FirecrawlCrawlWebsiteTool, FirecrawlScrapeWebsiteTool, FirecrawlSearchTool,
+    JinaScrapeWebsiteTool, OutputFormat, ScrapeElementFromWebsiteTool, ScrapeWebsiteTool,
+    ScrapegraphScrapeTool, ScrapflyScrapeWebsiteTool, SeleniumScrapingTool,
+    SerperScrapeWebsiteTool, SpiderTool,
+};
+
+#[cfg(feature = "database")]
+pub use crate::tools::database::{
+    ConnectionPool, CouchbaseFtsVectorSearchTool, DatabricksQueryTool, MongoDbVectorSearchTool,
+    Nl2SqlTool, PgVectorSearchTool, QdrantVectorSearchTool, SingleStoreSearchTool,
+    SnowflakeSearchTool, SqliteSearchTool, WeaviateVectorSearchTool,
+};
+
+#[cfg(feature = "file_ops")]
+pub use crate::tools::file_ops::{
+    CsvWriterTool, DirectoryReadTool, FileChecksumTool, FileCompressorTool, FileDecompressorTool,
+    FileManageTool, FileReadTool, FileWriterTool, JsonWriterTool, StructuredFileWriterTool,
+};
+
+#[cfg(feature = "ai_ml")]
+pub use crate::tools::ai_ml::{
+    AiMindTool, DalleTool, EmbeddingTool, LlamaIndexTool, ModerationTool, OcrEngine, OcrTool,
+    RagTool, TranscriptionTool, VisionTool,
+};
+
+#[cfg(feature = "automation")]
+pub use crate::tools::automation::{
+    ApifyActorsTool, ComposioTool, CronScheduleTool, GenerateCrewaiAutomationTool,
+    InvokeCrewaiAutomationTool, MergeAgentHandlerTool, WebhookTool, ZapierActionTools,
+};
+
+#[cfg(any(feature = "web_scraping", feature = "automation"))]
+pub use crate::tools::credit_budget::CreditBudget;
+
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub use crate::tools::crawl_policy::{HostRateLimiter, RobotsCache};
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub use crate::tools::custom_headers::CustomHeaders;
+
+#[cfg(feature = "cloud_storage")]
+pub use crate::tools::cloud_storage::{
+    AzureBlobReaderTool, AzureBlobWriterTool, BedrockInvokeAgentTool, BedrockKbRetrieverTool,
+    GcsReaderTool, GcsWriterTool, S3ReaderTool, S3WriterTool,
+};
+
+#[cfg(feature = "browser")]
+pub use crate::tools::browser::{
+    BrowserbaseLoadTool, HyperbrowserLoadTool, MultiOnTool, PlaywrightBrowserTool, StagehandTool,
+};
+
+#[cfg(feature = "rag")]
+pub use crate::rag::{
+    chunkers::{
+        CodeChunker, DefaultChunker, SemanticChunker, StructuredChunker, TextChunker,
+        TokenChunker, WebChunker,
+    },
+    embeddings::{CachingEmbeddingService, OllamaEmbeddingService, OpenAiEmbeddingService},
+    filter::{DateRange, MetadataCondition, QueryFilter},
+    index::{InMemoryVectorIndex, InMemoryVectorStore},
+    loaders::{
+        CsvLoadMode, CsvLoader, DirectoryLoader, DocxLoader, GithubLoader, JsonLoader,
+        MarkdownLoader, PdfLoader, TextLoader, WebpageLoader, XmlLoader, YoutubeVideoLoader,
+    },
+    pipeline::{LowConfidencePolicy, RagPipeline},
+};
+#[cfg(all(feature = "rag", feature = "cloud_storage"))]
+pub use crate::rag::loaders::S3Loader;
@@ -0,0 +1,447 @@
+//! Top-level item detection for `CodeChunker`: brace depth for Rust,
+//! JavaScript/TypeScript and Go, indentation for Python.
+//!
+//! These are heuristics, not parsers. Braces inside strings, character
+//! literals and comments are ignored; anything unrecognised is left to the
+//! chunker's line windows.
+
+/// A language `CodeChunker` can split along item boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Lang {
+    Rust,
+    Python,
+    JavaScript,
+    TypeScript,
+    Go,
+}
+
+impl Lang {
+    /// A language name or file extension.
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Lang::Rust,
+            "python" | "py" | "pyi" => Lang::Python,
+            "javascript" | "js" | "jsx" | "mjs" | "cjs" => Lang::JavaScript,
+            "typescript" | "ts" | "tsx" | "mts" | "cts" => Lang::TypeScript,
+            "go" | "golang" => Lang::Go,
+            _ => return None,
+        })
+    }
+
+    /// The language of a file, from its extension.
+    pub(super) fn from_path(path: &str) -> Option<Self> {
+        let name = path.rsplit(&['/', '\\'][..]).next().unwrap_or(path);
+        Self::from_name(name.rsplit_once('.')?.1)
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Lang::Rust => "rust",
+            Lang::Python => "python",
+            Lang::JavaScript => "javascript",
+            Lang::TypeScript => "typescript",
+            Lang::Go => "go",
+        }
+    }
+}
+
+/// A top-level item as a 0-based, inclusive line range. The range starts
+/// at the doc comments, attributes or decorators directly above the item.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Item {
+    pub start: usize,
+    pub end: usize,
+    pub kind: &'static str,
+    pub name: String,
+}
+
+/// The top-level items of `lines`, in order and non-overlapping.
+pub(super) fn items(lines: &[&str], lang: Lang) -> Vec<Item> {
+    match lang {
+        Lang::Python => python_items(lines),
+        _ => brace_items(lines, lang),
+    }
+}
+
+// ── Brace languages ──────────────────────────────────────────────────────────
+
+fn brace_items(lines: &[&str], lang: Lang) -> Vec<Item> {
+    let depths = brace_depths(lines, lang);
+    let depth_before = |i: usize| if i == 0 { 0 } else { depths[i - 1].0 };
+
+    let mut items: Vec<Item> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let header = (depth_before(i) == 0)
+            .then(|| item_header(lines[i].trim(), lang))
+            .flatten();
+        let Some((kind, name)) = header else {
+            i += 1;
+            continue;
+        };
+
+        let mut end = i;
+        let mut opened = false;
+        loop {
+            let (after, peak) = depths[end];
+            opened |= peak > 0;
+            let line = lines[end].trim_end();
+            let next = lines.get(end + 1).map(|l| l.trim());
+            let finished = after == 0
+                && (opened
+                    || line.ends_with(';')
+                    || line.ends_with('}')
+                    || match next {
+                        None => true,
+                        Some(next) => {
+                            next.is_empty()
+                                || is_prefix_line(next, lang)
+                                || item_header(next, lang).is_some()
+                        }
+                    });
+            if finished || end + 1 == lines.len() {
+                break;
+            }
+            end += 1;
+        }
+
+        let floor = items.last().map_or(0, |item| item.end + 1);
+        items.push(Item {
+            start: prefix_start(lines, i, floor, lang),
+            end,
+            kind,
+            name,
+        });
+        i = end + 1;
+    }
+    items
+}
+
+/// Brace depth after each line, and the deepest it got within the line.
+fn brace_depths(lines: &[&str], lang: Lang) -> Vec<(usize, usize)> {
+    enum Quote {
+        Double,
+        Backtick,
+        /// Rust raw string closed by `"` and this many `#`.
+        Raw(usize),
+    }
+
+    let mut depth = 0usize;
+    let mut block_comment = false;
+    let mut quote: Option<Quote> = None;
+    let mut depths = Vec::with_capacity(lines.len());
+    for line in lines {
+        let chars: Vec<char> = line.chars().collect();
+        let mut peak = depth;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            if block_comment {
+                if c == '*' && next == Some('/') {
+                    block_comment = false;
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+            match quote {
+                Some(Quote::Double) => {
+                    match c {
+                        '\\' => i += 1,
+                        '"' => quote = None,
+                        _ => {}
+                    }
+                    i += 1;
+                    continue;
+                }
+                Some(Quote::Backtick) => {
+                    match c {
+                        // Go raw strings have no escapes.
+                        '\\' if lang != Lang::Go => i += 1,
+                        '`' => quote = None,
+                        _ => {}
+                    }
+                    i += 1;
+                    continue;
+                }
+                Some(Quote::Raw(hashes)) => {
+                    let closing = chars[i + 1..].iter().take(hashes).filter(|&&h| h == '#');
+                    if c == '"' && closing.count() == hashes {
+                        quote = None;
+                        i += hashes;
+                    }
+                    i += 1;
+                    continue;
+                }
+                None => {}
+            }
+            match c {
+                '/' if next == Some('/') => break,
+                '/' if next == Some('*') => {
+                    block_comment = true;
+                    i += 1;
+                }
+                '"' => quote = Some(Quote::Double),
+                '`' if lang != Lang::Rust => quote = Some(Quote::Backtick),
+                'r' if lang == Lang::Rust && starts_raw_string(&chars, i) => {
+                    let hashes = chars[i + 1..].iter().take_while(|&&h| h == '#').count();
+                    quote = Some(Quote::Raw(hashes));
+                    i += 1 + hashes;
+                }
+                '\'' => {
+                    if let Some(len) = quoted_len(&chars[i..], lang) {
+                        i += len;
+                        continue;
+                    }
+                }
+                '{' => {
+                    depth += 1;
+                    peak = peak.max(depth);
+                }
+                '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            i += 1;
+        }
+        depths.push((depth, peak));
+    }
+    depths
+}
+
+/// Whether the `r` at `chars[i]` opens a Rust raw string (`r"`, `r#"`, `br"`).
+fn starts_raw_string(chars: &[char], i: usize) -> bool {
+    let ident = |c: char| c.is_alphanumeric() || c == '_';
+    let standalone = match i.checked_sub(1).map(|p| chars[p]) {
+        None => true,
+        Some('b') => i < 2 || !ident(chars[i - 2]),
+        Some(prev) => !ident(prev),
+    };
+    let rest = chars[i + 1..].iter().find(|&&h| h != '#');
+    standalone && rest == Some(&'"')
+}
+
+/// Length of a single-quoted literal at the start of `chars`: a string in
+/// JavaScript/TypeScript, a character literal in Rust and Go. `None` for a
+/// Rust lifetime or an unterminated quote.
+fn quoted_len(chars: &[char], lang: Lang) -> Option<usize> {
+    if matches!(lang, Lang::JavaScript | Lang::TypeScript) {
+        let mut i = 1;
+        while i < chars.len() {
+            match chars[i] {
+                '\\' => i += 1,
+                '\'' => return Some(i + 1),
+                _ => {}
+            }
+            i += 1;
+        }
+        return None;
+    }
+    match chars.get(1)? {
+        '\\' => chars.iter().skip(2).take(10).position(|&c| c == '\'').map(|p| p + 3),
+        _ => (chars.get(2) == Some(&'\'')).then_some(3),
+    }
+}
+
+/// The kind and name of the item a line at depth zero starts, if any.
+fn item_header(line: &str, lang: Lang) -> Option<(&'static str, String)> {
+    let modifiers: &[&str] = match lang {
+        Lang::Rust => &["pub", "async", "unsafe", "extern", "default", "\"C\""],
+        Lang::JavaScript | Lang::TypeScript => {
+            &["export", "default", "async", "declare", "abstract"]
+        }
+        Lang::Go | Lang::Python => &[],
+    };
+    let mut tokens = line.split_whitespace().peekable();
+    while let Some(token) = tokens.peek() {
+        let is_modifier = modifiers.contains(token)
+            || (lang == Lang::Rust && token.starts_with("pub("))
+            || (lang == Lang::Rust
+                && *token == "const"
+                && line.split_whitespace().any(|t| t == "fn"));
+        if !is_modifier {
+            break;
+        }
+        tokens.next();
+    }
+    let keyword = tokens.next()?;
+    let name_token = tokens.next().unwrap_or("");
+
+    let kind = match (lang, keyword) {
+        (Lang::Rust, "fn") => "function",
+        (Lang::Rust, "struct") => "struct",
+        (Lang::Rust, "enum") => "enum",
+        (Lang::Rust, "trait") => "trait",
+        (Lang::Rust, "mod") => "module",
+        (Lang::Rust, "const") => "const",
+        (Lang::Rust, "static") => "static",
+        (Lang::Rust, "type") => "type",
+        (Lang::Rust, "union") => "union",
+        (Lang::Rust, "macro_rules!") => "macro",
+        (Lang::Rust, k) if k == "impl" || k.starts_with("impl<") => {
+            let header = line[line.find("impl")?..].split('{').next().unwrap_or("");
+            let header = header.split(" where").next().unwrap_or(header);
+            return Some(("impl", header.trim().to_string()));
+        }
+        (Lang::JavaScript | Lang::TypeScript, "function" | "function*") => "function",
+        (Lang::JavaScript | Lang::TypeScript, "class") => "class",
+        (Lang::TypeScript, "interface") => "interface",
+        (Lang::TypeScript, "enum") => "enum",
+        (Lang::TypeScript, "type") if line.contains('=') => "type",
+        (Lang::JavaScript | Lang::TypeScript, "const" | "let" | "var") => {
+            // Plain values stay with the surrounding module code.
+            let substantial = line.contains("=>")
+                || line.contains("function")
+                || line.contains("class")
+                || line.ends_with('{')
+                || line.ends_with('(');
+            if !substantial {
+                return None;
+            }
+            "variable"
+        }
+        (Lang::Go, "func") => {
+            if name_token.starts_with('(') {
+                // A method: skip the receiver to the name.
+                let after = line[line.find(')')? + 1..].trim_start();
+                let name = identifier(after);
+                return (!name.is_empty()).then_some(("method", name));
+            }
+            "function"
+        }
+        (Lang::Go, "type") if name_token != "(" => {
+            if line.contains(" struct") {
+                "struct"
+            } else if line.contains(" interface") {
+                "interface"
+            } else {
+                "type"
+            }
+        }
+        (Lang::Go, "var" | "const") if name_token != "(" => "variable",
+        _ => return None,
+    };
+    let name = identifier(name_token);
+    Some((kind, if name.is_empty() { "default".to_string() } else { name }))
+}
+
+/// The identifier at the start of `s`.
+fn identifier(s: &str) -> String {
+    s.chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$' || *c == '!')
+        .collect::<String>()
+        .trim_end_matches('!')
+        .to_string()
+}
+
+// ── Python ───────────────────────────────────────────────────────────────────
+
+fn python_items(lines: &[&str]) -> Vec<Item> {
+    let in_string = python_string_lines(lines);
+    let top_level = |i: usize| {
+        !in_string[i]
+            && !lines[i].trim().is_empty()
+            && !lines[i].starts_with(char::is_whitespace)
+            // The closing bracket of a multi-line signature.
+            && !lines[i].starts_with(&[')', ']', '}'][..])
+    };
+
+    let mut items: Vec<Item> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let header = top_level(i).then(|| python_header(lines[i].trim())).flatten();
+        let Some((kind, name)) = header else {
+            i += 1;
+            continue;
+        };
+        let mut end = i;
+        let mut next = i + 1;
+        while next < lines.len() && !top_level(next) {
+            if !lines[next].trim().is_empty() {
+                end = next;
+            }
+            next += 1;
+        }
+        let floor = items.last().map_or(0, |item| item.end + 1);
+        items.push(Item {
+            start: prefix_start(lines, i, floor, Lang::Python),
+            end,
+            kind,
+            name,
+        });
+        i = next;
+    }
+    items
+}
+
+fn python_header(line: &str) -> Option<(&'static str, String)> {
+    let line = line.strip_prefix("async ").unwrap_or(line);
+    let (kind, rest) = if let Some(rest) = line.strip_prefix("def ") {
+        ("function", rest)
+    } else {
+        ("class", line.strip_prefix("class ")?)
+    };
+    let name = identifier(rest.trim_start());
+    (!name.is_empty()).then_some((kind, name))
+}
+
+/// Whether each line starts inside a triple-quoted string.
+fn python_string_lines(lines: &[&str]) -> Vec<bool> {
+    let mut open: Option<&str> = None;
+    lines
+        .iter()
+        .map(|line| {
+            let starts_inside = open.is_some();
+            let mut rest = *line;
+            loop {
+                let delimiter = match open {
+                    Some(delimiter) => delimiter,
+                    None => match (rest.find("\"\"\""), rest.find("'''")) {
+                        (Some(a), Some(b)) if b < a => "'''",
+                        (Some(_), _) => "\"\"\"",
+                        (None, Some(_)) => "'''",
+                        (None, None) => break,
+                    },
+                };
+                let Some(at) = rest.find(delimiter) else {
+                    break;
+                };
+                rest = &rest[at + 3..];
+                open = if open.is_some() { None } else { Some(delimiter) };
+            }
+            starts_inside
+        })
+        .collect()
+}
+
+// ── Shared ───────────────────────────────────────────────────────────────────
+
+/// The first line of the comment, attribute and decorator lines directly
+/// above `header`, not reaching above `floor`.
+fn prefix_start(lines: &[&str], header: usize, floor: usize, lang: Lang) -> usize {
+    let mut start = header;
+    while start > floor && is_prefix_line(lines[start - 1].trim(), lang) {
+        start -= 1;
+    }
+    start
+}
+
+/// Doc comments, comments, attributes and decorators, which belong to the
+/// item below them.
+fn is_prefix_line(line: &str, lang: Lang) -> bool {
+    match lang {
+        Lang::Python => line.starts_with('#') || line.starts_with('@'),
+        Lang::Rust => {
+            (line.starts_with("//") && !line.starts_with("//!"))
+                || line.starts_with("/*")
+                || line.starts_with('*')
+                || (line.starts_with("#[") && !line.starts_with("#!["))
+        }
+        Lang::JavaScript | Lang::TypeScript | Lang::Go => {
+            line.starts_with("//")
+                || line.starts_with("/*")
+                || line.starts_with('*')
+                || (lang != Lang::Go && line.starts_with('@'))
+        }
+    }
+}
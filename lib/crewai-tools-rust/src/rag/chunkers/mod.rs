@@ -4,7 +4,18 @@
 //! handles splitting documents into smaller chunks using different strategies.
 //! These correspond to chunker classes in the Python `crewai_tools.rag` module.
 
-use super::core::{BaseChunker, Chunk, Document};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use serde_json::Value;
+use tiktoken_rs::CoreBPE;
+
+use super::core::{cosine_similarity, BaseChunker, Chunk, Document, EmbeddingService};
+use super::html::html_to_markdown;
+use super::language::stamp_language;
+
+mod code;
 
 // ── DefaultChunker ───────────────────────────────────────────────────────────
 
@@ -156,6 +167,12 @@ impl BaseChunker for StructuredChunker {
 
 /// Chunker optimized for web page content that respects HTML semantic sections.
 ///
+/// HTML is converted to Markdown first (when `strip_html` is set), then packed
+/// into chunks of up to `chunk_size` characters along paragraph boundaries.
+/// Fenced code blocks are atomic: they are never split, and a block larger
+/// than `chunk_size` becomes its own chunk with `oversize_code_block: true`
+/// in its metadata.
+///
 /// Corresponds to the Python web chunker in `crewai_tools.rag`.
 #[derive(Debug, Clone)]
 pub struct WebChunker {
@@ -191,13 +208,761 @@ impl Default for WebChunker {
 }
 
 impl BaseChunker for WebChunker {
-    fn chunk(&self, _document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
-        anyhow::bail!(
-            "WebChunker: not yet implemented - requires HTML-aware splitting"
-        )
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
+        if self.chunk_size == 0 {
+            anyhow::bail!("WebChunker: chunk_size must be greater than zero");
+        }
+        let markdown = if self.strip_html {
+            html_to_markdown(&document.content)
+        } else {
+            document.content.clone()
+        };
+
+        let mut pieces: Vec<(String, bool)> = Vec::new();
+        let mut current = String::new();
+        let flush = |current: &mut String, pieces: &mut Vec<(String, bool)>| {
+            if current.trim().is_empty() {
+                current.clear();
+            } else {
+                pieces.push((std::mem::take(current), false));
+            }
+        };
+
+        for block in markdown_blocks(&markdown) {
+            let (text, is_code) = match &block {
+                MarkdownBlock::Code(t) => (t.as_str(), true),
+                MarkdownBlock::Text(t) => (t.as_str(), false),
+            };
+            let len = text.chars().count();
+
+            if is_code && len > self.chunk_size {
+                flush(&mut current, &mut pieces);
+                pieces.push((text.to_string(), true));
+                continue;
+            }
+
+            let parts = if is_code || len <= self.chunk_size {
+                vec![text.to_string()]
+            } else {
+                split_on_whitespace(text, self.chunk_size)
+            };
+            for part in parts {
+                let joined = current.chars().count() + 2 + part.chars().count();
+                if !current.is_empty() && joined > self.chunk_size {
+                    flush(&mut current, &mut pieces);
+                }
+                if !current.is_empty() {
+                    current.push_str("\n\n");
+                }
+                current.push_str(&part);
+            }
+        }
+        flush(&mut current, &mut pieces);
+
+        Ok(pieces
+            .into_iter()
+            .enumerate()
+            .map(|(index, (content, oversize))| {
+                let mut metadata = document.metadata.clone();
+                if oversize {
+                    metadata.insert("oversize_code_block".to_string(), Value::Bool(true));
+                }
+                // Chunks of a mixed-language page can differ from the page.
+                stamp_language(&mut metadata, &content);
+                Chunk {
+                    content,
+                    metadata,
+                    index,
+                }
+            })
+            .collect())
     }
 
     fn chunker_name(&self) -> &str {
         "WebChunker"
     }
 }
+
+// ── TokenChunker ─────────────────────────────────────────────────────────────
+
+/// Chunker that measures chunk size and overlap in model tokens.
+///
+/// Text is split into sentences (and at blank lines), which are packed into
+/// chunks of at most `chunk_size` tokens as counted by a tiktoken BPE
+/// encoding. A sentence longer than `chunk_size` is split between words,
+/// and a single word longer than that between tokens. Consecutive chunks
+/// share whole trailing sentences worth up to `chunk_overlap` tokens. Each
+/// chunk records its `token_count` and `encoding` in metadata.
+///
+/// `encoding` is a tiktoken encoding (`cl100k_base`, `o200k_base`,
+/// `p50k_base`, `r50k_base`) or an OpenAI model name such as
+/// `text-embedding-3-small`.
+#[derive(Debug, Clone)]
+pub struct TokenChunker {
+    /// Maximum number of tokens per chunk.
+    pub chunk_size: usize,
+    /// Most tokens of trailing sentences repeated at the start of the next chunk.
+    pub chunk_overlap: usize,
+    /// Tokenizer encoding or model name.
+    pub encoding: String,
+}
+
+impl TokenChunker {
+    pub fn new() -> Self {
+        Self {
+            chunk_size: 512,
+            chunk_overlap: 64,
+            encoding: "cl100k_base".to_string(),
+        }
+    }
+
+    pub fn with_chunk_size(mut self, tokens: usize) -> Self {
+        self.chunk_size = tokens;
+        self
+    }
+
+    pub fn with_chunk_overlap(mut self, tokens: usize) -> Self {
+        self.chunk_overlap = tokens;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.encoding = encoding.into();
+        self
+    }
+
+    /// Number of tokens `text` encodes to.
+    pub fn count_tokens(&self, text: &str) -> Result<usize, anyhow::Error> {
+        Ok(bpe(&self.encoding)?.encode_ordinary(text).len())
+    }
+}
+
+impl Default for TokenChunker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BaseChunker for TokenChunker {
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
+        if self.chunk_size == 0 {
+            anyhow::bail!("TokenChunker: chunk_size must be greater than zero");
+        }
+        if self.chunk_overlap >= self.chunk_size {
+            anyhow::bail!("TokenChunker: chunk_overlap must be smaller than chunk_size");
+        }
+        let bpe = bpe(&self.encoding)?;
+        let count = |text: &str| bpe.encode_ordinary(text).len();
+
+        // Sentences, with oversized ones broken up, as (text, tokens).
+        let mut pieces: Vec<(String, usize)> = Vec::new();
+        for sentence in split_sentences(&document.content) {
+            let tokens = count(sentence);
+            if tokens <= self.chunk_size {
+                pieces.push((sentence.to_string(), tokens));
+                continue;
+            }
+            for word in split_words(sentence) {
+                let tokens = count(word);
+                if tokens <= self.chunk_size {
+                    pieces.push((word.to_string(), tokens));
+                } else {
+                    for part in split_tokens(&bpe, word, self.chunk_size)? {
+                        let tokens = count(&part);
+                        pieces.push((part, tokens));
+                    }
+                }
+            }
+        }
+
+        // Pack pieces greedily; piece counts can only overestimate a join.
+        let mut windows: Vec<std::ops::Range<usize>> = Vec::new();
+        let mut start = 0;
+        while start < pieces.len() {
+            let mut end = start;
+            let mut total = 0;
+            while end < pieces.len() && (end == start || total + pieces[end].1 <= self.chunk_size) {
+                total += pieces[end].1;
+                end += 1;
+            }
+            windows.push(start..end);
+            if end == pieces.len() {
+                break;
+            }
+            // Step back over whole pieces that fit in the overlap, always
+            // moving forward by at least one.
+            let mut next = end;
+            let mut overlap = 0;
+            while next > start + 1 && overlap + pieces[next - 1].1 <= self.chunk_overlap {
+                overlap += pieces[next - 1].1;
+                next -= 1;
+            }
+            start = next;
+        }
+
+        let mut chunks = Vec::new();
+        for window in windows {
+            let joined: String = pieces[window].iter().map(|(text, _)| text.as_str()).collect();
+            let content = joined.trim().to_string();
+            if content.is_empty() {
+                continue;
+            }
+            let mut metadata = document.metadata.clone();
+            metadata.insert("token_count".to_string(), Value::from(count(&content)));
+            metadata.insert("encoding".to_string(), Value::String(self.encoding.clone()));
+            stamp_language(&mut metadata, &content);
+            chunks.push(Chunk {
+                content,
+                metadata,
+                index: chunks.len(),
+            });
+        }
+        Ok(chunks)
+    }
+
+    fn chunker_name(&self) -> &str {
+        "TokenChunker"
+    }
+}
+
+/// The BPE for a tiktoken encoding or model name, built once per process.
+fn bpe(encoding: &str) -> Result<Arc<CoreBPE>, anyhow::Error> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Arc<CoreBPE>>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(bpe) = cache.lock().expect("tokenizer cache lock").get(encoding) {
+        return Ok(bpe.clone());
+    }
+    let bpe = match encoding {
+        "cl100k_base" => tiktoken_rs::cl100k_base(),
+        "o200k_base" => tiktoken_rs::o200k_base(),
+        "p50k_base" => tiktoken_rs::p50k_base(),
+        "p50k_edit" => tiktoken_rs::p50k_edit(),
+        "r50k_base" | "gpt2" => tiktoken_rs::r50k_base(),
+        model => tiktoken_rs::get_bpe_from_model(model).map_err(|_| {
+            anyhow::anyhow!(
+                "Unknown tokenizer encoding '{}': use cl100k_base, o200k_base, p50k_base, \
+                 r50k_base or an OpenAI model name",
+                model
+            )
+        }),
+    }?;
+    let bpe = Arc::new(bpe);
+    cache
+        .lock()
+        .expect("tokenizer cache lock")
+        .insert(encoding.to_string(), bpe.clone());
+    Ok(bpe)
+}
+
+// ── CodeChunker ──────────────────────────────────────────────────────────────
+
+/// Chunker for source files that splits along top-level item boundaries.
+///
+/// Rust, Python, JavaScript/TypeScript and Go files become one chunk per
+/// function, class, struct, impl block or similar item, found with brace
+/// and indentation heuristics. Each item keeps the doc comments, attributes
+/// and decorators directly above it, and records `item_kind`, `item_name`,
+/// `start_line` and `end_line` (1-based) in metadata. Code between items
+/// (imports, module docs) is chunked together without an item name. An
+/// item longer than `max_lines` is split into line windows numbered by
+/// `part` of `parts`; files in other languages are chunked by line windows
+/// only.
+///
+/// The language is `language` when set, otherwise it comes from the
+/// extension of the document's `path` or `source` metadata, so
+/// [`GithubLoader`](crate::GithubLoader) and [`DirectoryLoader`](crate::DirectoryLoader)
+/// output can be chunked directly. It is recorded as `code_language`.
+#[derive(Debug, Clone)]
+pub struct CodeChunker {
+    /// Most lines per chunk.
+    pub max_lines: usize,
+    /// Language name or extension, overriding detection from metadata.
+    pub language: Option<String>,
+}
+
+impl CodeChunker {
+    pub fn new() -> Self {
+        Self {
+            max_lines: 120,
+            language: None,
+        }
+    }
+
+    pub fn with_max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = lines;
+        self
+    }
+
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+}
+
+impl Default for CodeChunker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BaseChunker for CodeChunker {
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
+        if self.max_lines == 0 {
+            anyhow::bail!("CodeChunker: max_lines must be greater than zero");
+        }
+        let lang = match &self.language {
+            Some(name) => Some(code::Lang::from_name(name).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "CodeChunker: unsupported language '{}' (expected rust, python, \
+                     javascript, typescript or go)",
+                    name
+                )
+            })?),
+            None => ["path", "source"]
+                .iter()
+                .filter_map(|key| document.metadata.get(*key)?.as_str())
+                .find_map(code::Lang::from_path),
+        };
+        let lines: Vec<&str> = document.content.lines().collect();
+        let items = lang.map(|lang| code::items(&lines, lang)).unwrap_or_default();
+
+        // Items and the code between them, in order.
+        let mut segments: Vec<(usize, usize, Option<&code::Item>)> = Vec::new();
+        let mut next = 0;
+        for item in &items {
+            if item.start > next {
+                segments.push((next, item.start - 1, None));
+            }
+            segments.push((item.start, item.end, Some(item)));
+            next = item.end + 1;
+        }
+        if next < lines.len() {
+            segments.push((next, lines.len() - 1, None));
+        }
+
+        let mut chunks = Vec::new();
+        for (start, end, item) in segments {
+            let blank = |i: &usize| lines[*i].trim().is_empty();
+            let Some(start) = (start..=end).find(|i| !blank(i)) else {
+                continue;
+            };
+            let end = (start..=end).rev().find(|i| !blank(i)).unwrap_or(start);
+            let windows: Vec<(usize, usize)> = (start..=end)
+                .step_by(self.max_lines)
+                .map(|from| (from, (from + self.max_lines - 1).min(end)))
+                .collect();
+            let parts = windows.len();
+            for (part, (from, to)) in windows.into_iter().enumerate() {
+                let mut metadata = document.metadata.clone();
+                if let Some(lang) = lang {
+                    metadata.insert("code_language".to_string(), Value::from(lang.name()));
+                }
+                if let Some(item) = item {
+                    metadata.insert("item_kind".to_string(), Value::from(item.kind));
+                    metadata.insert("item_name".to_string(), Value::from(item.name.as_str()));
+                }
+                metadata.insert("start_line".to_string(), Value::from(from + 1));
+                metadata.insert("end_line".to_string(), Value::from(to + 1));
+                if parts > 1 {
+                    metadata.insert("part".to_string(), Value::from(part + 1));
+                    metadata.insert("parts".to_string(), Value::from(parts));
+                }
+                chunks.push(Chunk {
+                    content: lines[from..=to].join("\n"),
+                    metadata,
+                    index: chunks.len(),
+                });
+            }
+        }
+        Ok(chunks)
+    }
+
+    fn chunker_name(&self) -> &str {
+        "CodeChunker"
+    }
+}
+
+// ── SemanticChunker ──────────────────────────────────────────────────────────
+
+/// Chunker that starts a new chunk where the topic changes, judged by
+/// embedding similarity.
+///
+/// Text is split into sentences (and at blank lines). Each sentence is
+/// embedded together with `window` neighbours on either side, in
+/// `embed_batch` calls of at most `batch_size` texts, and the cosine
+/// similarity of each adjacent pair is computed. A chunk boundary goes
+/// wherever that similarity is at or below the `breakpoint_percentile`th
+/// percentile of all adjacent similarities; text whose similarities are
+/// all equal has no boundaries.
+///
+/// Chunk sizes are guarded in characters: a boundary is ignored while the
+/// chunk is shorter than `min_chunk_size`, a chunk is closed early rather
+/// than grow past `max_chunk_size`, and a short final chunk is merged into
+/// the one before it when they fit together. A sentence longer than
+/// `max_chunk_size` is split at whitespace.
+#[derive(Clone)]
+pub struct SemanticChunker {
+    embedder: Arc<dyn EmbeddingService>,
+    /// Percentile (0-100) of adjacent similarities below which a boundary
+    /// is placed. Lower values give fewer, larger chunks.
+    pub breakpoint_percentile: f32,
+    /// Sentences on each side embedded with a sentence to smooth out short
+    /// ones.
+    pub window: usize,
+    /// Minimum number of characters per chunk.
+    pub min_chunk_size: usize,
+    /// Maximum number of characters per chunk.
+    pub max_chunk_size: usize,
+    /// Most texts per `embed_batch` call.
+    pub batch_size: usize,
+}
+
+impl fmt::Debug for SemanticChunker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemanticChunker")
+            .field("embedder", &self.embedder.model_name())
+            .field("breakpoint_percentile", &self.breakpoint_percentile)
+            .field("window", &self.window)
+            .field("min_chunk_size", &self.min_chunk_size)
+            .field("max_chunk_size", &self.max_chunk_size)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl SemanticChunker {
+    pub fn new(embedder: Arc<dyn EmbeddingService>) -> Self {
+        Self {
+            embedder,
+            breakpoint_percentile: 10.0,
+            window: 1,
+            min_chunk_size: 200,
+            max_chunk_size: 2000,
+            batch_size: 64,
+        }
+    }
+
+    pub fn with_breakpoint_percentile(mut self, percentile: f32) -> Self {
+        self.breakpoint_percentile = percentile;
+        self
+    }
+
+    pub fn with_window(mut self, sentences: usize) -> Self {
+        self.window = sentences;
+        self
+    }
+
+    pub fn with_min_chunk_size(mut self, chars: usize) -> Self {
+        self.min_chunk_size = chars;
+        self
+    }
+
+    pub fn with_max_chunk_size(mut self, chars: usize) -> Self {
+        self.max_chunk_size = chars;
+        self
+    }
+
+    pub fn with_batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size;
+        self
+    }
+
+    /// Embed `texts` in batches of at most `batch_size`.
+    fn embed_all(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(self.batch_size) {
+            let batch: Vec<&str> = batch.iter().map(String::as_str).collect();
+            let vectors = self.embedder.embed_batch(&batch)?;
+            if vectors.len() != batch.len() {
+                anyhow::bail!(
+                    "Embedding service '{}' returned {} vectors for {} sentences",
+                    self.embedder.model_name(),
+                    vectors.len(),
+                    batch.len()
+                );
+            }
+            embeddings.extend(vectors);
+        }
+        Ok(embeddings)
+    }
+}
+
+impl BaseChunker for SemanticChunker {
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
+        if self.max_chunk_size == 0 || self.batch_size == 0 {
+            anyhow::bail!(
+                "SemanticChunker: max_chunk_size and batch_size must be greater than zero"
+            );
+        }
+        if self.min_chunk_size > self.max_chunk_size {
+            anyhow::bail!("SemanticChunker: min_chunk_size must not exceed max_chunk_size");
+        }
+        if !(0.0..=100.0).contains(&self.breakpoint_percentile) {
+            anyhow::bail!("SemanticChunker: breakpoint_percentile must be between 0 and 100");
+        }
+
+        // Sentences, with oversized ones broken up, as (text, chars).
+        let mut sentences: Vec<(String, usize)> = Vec::new();
+        for sentence in split_sentences(&document.content) {
+            let trimmed = sentence.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let chars = trimmed.chars().count();
+            if chars <= self.max_chunk_size {
+                sentences.push((trimmed.to_string(), chars));
+            } else {
+                for part in split_on_whitespace(trimmed, self.max_chunk_size) {
+                    let chars = part.chars().count();
+                    sentences.push((part, chars));
+                }
+            }
+        }
+
+        // Boundaries after sentence `i` where similarity to `i + 1` drops.
+        let mut breaks = vec![false; sentences.len()];
+        if sentences.len() > 1 {
+            let texts: Vec<String> = (0..sentences.len())
+                .map(|i| {
+                    let from = i.saturating_sub(self.window);
+                    let to = (i + self.window).min(sentences.len() - 1);
+                    let window: Vec<&str> =
+                        sentences[from..=to].iter().map(|(text, _)| text.as_str()).collect();
+                    window.join(" ")
+                })
+                .collect();
+            let embeddings = self.embed_all(&texts)?;
+            let similarities: Vec<f32> = embeddings
+                .windows(2)
+                .map(|pair| cosine_similarity(&pair[0], &pair[1]))
+                .collect();
+            let threshold = percentile(&similarities, self.breakpoint_percentile);
+            let flat = similarities
+                .iter()
+                .all(|s| (s - similarities[0]).abs() <= f32::EPSILON);
+            if !flat {
+                for (i, similarity) in similarities.iter().enumerate() {
+                    breaks[i] = *similarity <= threshold;
+                }
+            }
+        }
+
+        // Group sentences, honouring the size guards; a joining space
+        // counts towards the size.
+        let mut groups: Vec<(Vec<&str>, usize)> = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut size = 0;
+        for (i, (text, chars)) in sentences.iter().enumerate() {
+            let chars = *chars;
+            if !current.is_empty() && size + 1 + chars > self.max_chunk_size {
+                groups.push((std::mem::take(&mut current), size));
+                size = 0;
+            }
+            if !current.is_empty() {
+                size += 1;
+            }
+            current.push(text.as_str());
+            size += chars;
+            if breaks[i] && size >= self.min_chunk_size {
+                groups.push((std::mem::take(&mut current), size));
+                size = 0;
+            }
+        }
+        if !current.is_empty() {
+            match groups.last_mut() {
+                Some((last, last_size))
+                    if size < self.min_chunk_size
+                        && *last_size + 1 + size <= self.max_chunk_size =>
+                {
+                    last.append(&mut current);
+                    *last_size += 1 + size;
+                }
+                _ => groups.push((current, size)),
+            }
+        }
+
+        let mut chunks = Vec::new();
+        for (group, _) in groups {
+            let content = group.join(" ");
+            let mut metadata = document.metadata.clone();
+            stamp_language(&mut metadata, &content);
+            chunks.push(Chunk {
+                content,
+                metadata,
+                index: chunks.len(),
+            });
+        }
+        Ok(chunks)
+    }
+
+    fn chunker_name(&self) -> &str {
+        "SemanticChunker"
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// A unit of Markdown that chunkers may not split across.
+#[derive(Debug, Clone, PartialEq)]
+enum MarkdownBlock {
+    /// A fenced code block, fences included, verbatim.
+    Code(String),
+    /// A paragraph, heading, list or other run of non-blank lines.
+    Text(String),
+}
+
+/// Split Markdown into paragraphs and fenced code blocks.
+///
+/// Code blocks keep every line exactly as written, including blank lines
+/// and indentation. An unterminated fence runs to the end of the input.
+fn markdown_blocks(markdown: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut text: Vec<&str> = Vec::new();
+    let mut lines = markdown.lines();
+
+    let flush_text = |text: &mut Vec<&str>, blocks: &mut Vec<MarkdownBlock>| {
+        if !text.is_empty() {
+            blocks.push(MarkdownBlock::Text(text.join("\n")));
+            text.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let fence_char = trimmed.chars().next().filter(|&c| c == '`' || c == '~');
+        let fence_len = fence_char.map_or(0, |f| trimmed.chars().take_while(|&c| c == f).count());
+        if let (Some(fence_char), true) = (fence_char, fence_len >= 3) {
+            flush_text(&mut text, &mut blocks);
+            let fence = &trimmed[..fence_len];
+            let mut code = vec![line];
+            for inner in lines.by_ref() {
+                code.push(inner);
+                let inner = inner.trim();
+                if inner.starts_with(fence) && inner.trim_start_matches(fence_char).is_empty() {
+                    break;
+                }
+            }
+            blocks.push(MarkdownBlock::Code(code.join("\n")));
+        } else if line.trim().is_empty() {
+            flush_text(&mut text, &mut blocks);
+        } else {
+            text.push(line);
+        }
+    }
+    flush_text(&mut text, &mut blocks);
+    blocks
+}
+
+/// The `p`th percentile (0-100) of `values`, interpolating linearly between
+/// the closest ranks. Returns 0.0 for no values.
+fn percentile(values: &[f32], p: f32) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let rank = p / 100.0 * (sorted.len() - 1) as f32;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f32)
+}
+
+/// Split `text` into pieces of at most `max_chars` characters at whitespace.
+/// A single word longer than `max_chars` is split mid-word.
+fn split_on_whitespace(text: &str, max_chars: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+        if current_len > 0 && current_len + 1 + word_len > max_chars {
+            pieces.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if word_len > max_chars {
+            let chars: Vec<char> = word.chars().collect();
+            for part in chars.chunks(max_chars) {
+                pieces.push(part.iter().collect());
+            }
+            continue;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+    pieces
+}
+
+/// Characters that may close a sentence after its final punctuation.
+const SENTENCE_CLOSERS: &[char] = &['"', '\'', ')', ']', '\u{201d}', '\u{2019}'];
+
+/// Split `text` into sentences at `.`, `!` or `?` followed by whitespace,
+/// and at blank lines. Each piece keeps the whitespace before it, so the
+/// pieces concatenate back to `text`.
+fn split_sentences(text: &str) -> Vec<&str> {
+    split_before_whitespace(text, |before, gap| {
+        let before = before.trim_end_matches(SENTENCE_CLOSERS);
+        before.ends_with(&['.', '!', '?'][..]) || gap.matches('\n').count() >= 2
+    })
+}
+
+/// Split `text` before every run of whitespace.
+fn split_words(text: &str) -> Vec<&str> {
+    split_before_whitespace(text, |_, _| true)
+}
+
+/// Split `text` at the start of each whitespace run for which
+/// `is_break(text before the run, the run)` holds.
+fn split_before_whitespace(text: &str, is_break: impl Fn(&str, &str) -> bool) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut prev_whitespace = true;
+    for (i, c) in text.char_indices() {
+        let whitespace = c.is_whitespace();
+        if whitespace && !prev_whitespace {
+            let gap_end = text[i..]
+                .find(|c: char| !c.is_whitespace())
+                .map_or(text.len(), |n| i + n);
+            if is_break(&text[start..i], &text[i..gap_end]) {
+                pieces.push(&text[start..i]);
+                start = i;
+            }
+        }
+        prev_whitespace = whitespace;
+    }
+    if start < text.len() {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+/// Split `text` into runs of at most `max_tokens` tokens. A run is shortened
+/// (or, failing that, lengthened) until it ends on a character boundary.
+fn split_tokens(
+    bpe: &CoreBPE,
+    text: &str,
+    max_tokens: usize,
+) -> Result<Vec<String>, anyhow::Error> {
+    let tokens = bpe.encode_ordinary(text);
+    let mut parts = Vec::new();
+    let mut start = 0;
+    while start < tokens.len() {
+        let limit = (start + max_tokens).min(tokens.len());
+        let decoded = (start + 1..=limit)
+            .rev()
+            .chain(limit + 1..=tokens.len())
+            .find_map(|end| Some((end, bpe.decode(tokens[start..end].to_vec()).ok()?)));
+        let (end, part) = decoded
+            .ok_or_else(|| anyhow::anyhow!("TokenChunker: could not decode tokens"))?;
+        parts.push(part);
+        start = end;
+    }
+    Ok(parts)
+}
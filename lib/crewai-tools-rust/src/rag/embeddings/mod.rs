@@ -0,0 +1,638 @@
+//! Embedding services for the RAG framework.
+//!
+//! Each service implements [`EmbeddingService`](super::core::EmbeddingService)
+//! over a provider's HTTP API. [`embedding_service`] picks one by provider
+//! name, so switching between a local and a hosted model is one string.
+//! [`CachingEmbeddingService`] wraps any of them to skip re-embedding
+//! text it has seen before.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use rusqlite::OptionalExtension;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::core::EmbeddingService;
+
+/// Build the embedding service for `provider` (`openai` or `ollama`).
+///
+/// `model` and `base_url` override the provider's defaults. OpenAI reads
+/// its API key from `OPENAI_API_KEY`.
+pub fn embedding_service(
+    provider: &str,
+    model: Option<&str>,
+    base_url: Option<&str>,
+) -> Result<Arc<dyn EmbeddingService>, anyhow::Error> {
+    match provider.to_ascii_lowercase().as_str() {
+        "ollama" => {
+            let mut service = OllamaEmbeddingService::new();
+            if let Some(model) = model {
+                service = service.with_model(model);
+            }
+            if let Some(url) = base_url {
+                service = service.with_base_url(url);
+            }
+            Ok(Arc::new(service))
+        }
+        "openai" => {
+            let mut service = OpenAiEmbeddingService::new();
+            if let Some(model) = model {
+                service = service.with_model(model);
+            }
+            if let Some(url) = base_url {
+                service = service.with_base_url(url);
+            }
+            Ok(Arc::new(service))
+        }
+        other => anyhow::bail!("Unknown embedding provider '{}': expected openai or ollama", other),
+    }
+}
+
+// ── OllamaEmbeddingService ───────────────────────────────────────────────────
+
+/// Embeddings from a local [Ollama](https://ollama.com) server, so corpus
+/// text never leaves the machine.
+///
+/// Batches go to `/api/embed`; servers older than that endpoint are
+/// detected on the first 404 and sent one text at a time to
+/// `/api/embeddings` from then on. The vector size is learnt from the
+/// first embedding (or a one-off probe when `dimensions()` is asked
+/// first) and cached.
+#[derive(Debug)]
+pub struct OllamaEmbeddingService {
+    /// Embedding model, which must have been pulled with `ollama pull`.
+    pub model: String,
+    /// Ollama server URL.
+    pub base_url: String,
+    /// Timeout for each request.
+    pub timeout: Duration,
+    dimensions: OnceLock<usize>,
+    /// Set once the server turns out to predate `/api/embed`.
+    legacy: AtomicBool,
+}
+
+impl OllamaEmbeddingService {
+    pub fn new() -> Self {
+        Self {
+            model: "nomic-embed-text".to_string(),
+            base_url: "http://localhost:11434".to_string(),
+            timeout: Duration::from_secs(120),
+            dimensions: OnceLock::new(),
+            legacy: AtomicBool::new(false),
+        }
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// POST `body` to `path`, returning the status and JSON reply. A missing
+    /// server or model becomes an error saying how to fix it.
+    fn post(
+        &self,
+        client: &reqwest::blocking::Client,
+        path: &str,
+        body: &Value,
+    ) -> Result<(reqwest::StatusCode, Value), anyhow::Error> {
+        let base_url = self.base_url.trim_end_matches('/');
+        let resp = client
+            .post(format!("{}{}", base_url, path))
+            .json(body)
+            .send()
+            .map_err(|e| {
+                if e.is_connect() {
+                    anyhow::anyhow!(
+                        "Could not reach Ollama at {}: start it with `ollama serve` and make sure \
+                         the model is available with `ollama pull {}`",
+                        base_url,
+                        self.model
+                    )
+                } else {
+                    e.into()
+                }
+            })?;
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        // An unknown route is a plain-text 404; a missing model has a JSON error.
+        if let (reqwest::StatusCode::NOT_FOUND, Some(error)) = (status, payload["error"].as_str()) {
+            anyhow::bail!(
+                "Ollama has no model '{}': pull it with `ollama pull {}` ({})",
+                self.model,
+                self.model,
+                error
+            );
+        }
+        Ok((status, payload))
+    }
+}
+
+impl Default for OllamaEmbeddingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmbeddingService for OllamaEmbeddingService {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        self.embed_batch(&[text])?
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("Ollama returned no embedding"))
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()?;
+        let failed = |status: reqwest::StatusCode, payload: &Value| {
+            anyhow::anyhow!(
+                "Ollama embedding request failed with status {}: {}",
+                status.as_u16(),
+                payload["error"].as_str().unwrap_or("no details")
+            )
+        };
+
+        let mut embeddings: Option<Vec<Vec<f32>>> = None;
+        if !self.legacy.load(Ordering::Relaxed) {
+            let body = serde_json::json!({"model": self.model, "input": texts});
+            let (status, mut payload) = self.post(&client, "/api/embed", &body)?;
+            if status == reqwest::StatusCode::NOT_FOUND {
+                log::debug!("OllamaEmbeddingService: no /api/embed, using /api/embeddings");
+                self.legacy.store(true, Ordering::Relaxed);
+            } else if !status.is_success() {
+                return Err(failed(status, &payload));
+            } else {
+                embeddings = Some(serde_json::from_value(payload["embeddings"].take())?);
+            }
+        }
+        let embeddings = match embeddings {
+            Some(embeddings) => embeddings,
+            None => texts
+                .iter()
+                .map(|text| {
+                    let body = serde_json::json!({"model": self.model, "prompt": text});
+                    let (status, mut payload) = self.post(&client, "/api/embeddings", &body)?;
+                    if !status.is_success() {
+                        return Err(failed(status, &payload));
+                    }
+                    Ok(serde_json::from_value(payload["embedding"].take())?)
+                })
+                .collect::<Result<Vec<Vec<f32>>, anyhow::Error>>()?,
+        };
+
+        if embeddings.len() != texts.len() {
+            anyhow::bail!(
+                "Ollama returned {} embeddings for {} texts",
+                embeddings.len(),
+                texts.len()
+            );
+        }
+        if let Some(first) = embeddings.first() {
+            let _ = self.dimensions.set(first.len());
+        }
+        Ok(embeddings)
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    /// The model's vector size, probed with one embedding on first use.
+    /// Returns 0 when Ollama cannot be reached.
+    fn dimensions(&self) -> usize {
+        if let Some(dimensions) = self.dimensions.get() {
+            return *dimensions;
+        }
+        match self.embed("dimensions") {
+            Ok(vector) => vector.len(),
+            Err(e) => {
+                log::warn!("OllamaEmbeddingService: could not probe '{}': {}", self.model, e);
+                0
+            }
+        }
+    }
+}
+
+// ── OpenAiEmbeddingService ───────────────────────────────────────────────────
+
+/// Embeddings from OpenAI's `/embeddings` endpoint, or any server that
+/// speaks it.
+///
+/// The API key comes from `with_api_key` or `OPENAI_API_KEY`; it may be
+/// omitted when a custom `base_url` is set.
+pub struct OpenAiEmbeddingService {
+    /// OpenAI API key.
+    pub api_key: Option<String>,
+    /// Embedding model.
+    pub model: String,
+    /// API base URL, default `https://api.openai.com/v1`.
+    pub base_url: Option<String>,
+    /// Timeout for each request.
+    pub timeout: Duration,
+    dimensions: OnceLock<usize>,
+}
+
+impl fmt::Debug for OpenAiEmbeddingService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OpenAiEmbeddingService")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("model", &self.model)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl OpenAiEmbeddingService {
+    pub fn new() -> Self {
+        Self {
+            api_key: None,
+            model: "text-embedding-3-small".to_string(),
+            base_url: None,
+            timeout: Duration::from_secs(60),
+            dimensions: OnceLock::new(),
+        }
+    }
+
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Default for OpenAiEmbeddingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmbeddingService for OpenAiEmbeddingService {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        self.embed_batch(&[text])?
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("OpenAI returned no embedding"))
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("OPENAI_API_KEY").ok());
+        if api_key.is_none() && self.base_url.is_none() {
+            anyhow::bail!("Missing OPENAI_API_KEY");
+        }
+        let base_url = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.openai.com/v1")
+            .trim_end_matches('/');
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()?;
+        let mut request = client
+            .post(format!("{}/embeddings", base_url))
+            .json(&serde_json::json!({"model": self.model, "input": texts}));
+        if let Some(key) = api_key {
+            request = request.bearer_auth(key);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let mut payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no details");
+            anyhow::bail!("Embedding request failed with status {}: {}", status.as_u16(), message);
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Item {
+            index: usize,
+            embedding: Vec<f32>,
+        }
+        let mut items: Vec<Item> = serde_json::from_value(payload["data"].take())?;
+        items.sort_by_key(|item| item.index);
+        if items.len() != texts.len() {
+            anyhow::bail!("OpenAI returned {} embeddings for {} texts", items.len(), texts.len());
+        }
+        let embeddings: Vec<Vec<f32>> = items.into_iter().map(|item| item.embedding).collect();
+        let _ = self.dimensions.set(embeddings[0].len());
+        Ok(embeddings)
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    /// The model's vector size: known for OpenAI's own models, otherwise
+    /// probed with one embedding on first use (0 if that fails).
+    fn dimensions(&self) -> usize {
+        match self.model.as_str() {
+            "text-embedding-3-small" | "text-embedding-ada-002" => return 1536,
+            "text-embedding-3-large" => return 3072,
+            _ => {}
+        }
+        if let Some(dimensions) = self.dimensions.get() {
+            return *dimensions;
+        }
+        match self.embed("dimensions") {
+            Ok(vector) => vector.len(),
+            Err(e) => {
+                log::warn!("OpenAiEmbeddingService: could not probe '{}': {}", self.model, e);
+                0
+            }
+        }
+    }
+}
+
+// ── CachingEmbeddingService ──────────────────────────────────────────────────
+
+/// Decorator that remembers embeddings so unchanged text is never sent to
+/// the wrapped service twice.
+///
+/// Vectors are keyed on the inner service's model name and the SHA-256 of
+/// the text, so a cache shared between models never serves one model's
+/// vectors for another. Lookups go to an in-memory LRU layer first, then,
+/// when opened with [`open`](Self::open), to a SQLite file that survives
+/// restarts. `embed_batch` sends only the texts that missed, once each, to
+/// the inner service in a single batch.
+pub struct CachingEmbeddingService {
+    inner: Arc<dyn EmbeddingService>,
+    path: Option<PathBuf>,
+    db: Option<Mutex<rusqlite::Connection>>,
+    memory: Mutex<Lru>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl fmt::Debug for CachingEmbeddingService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingEmbeddingService")
+            .field("inner", &self.inner.model_name())
+            .field("path", &self.path)
+            .field("memory_capacity", &self.memory_capacity())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+impl CachingEmbeddingService {
+    /// Cache `inner` in memory only.
+    pub fn new(inner: Arc<dyn EmbeddingService>) -> Self {
+        Self {
+            inner,
+            path: None,
+            db: None,
+            memory: Mutex::new(Lru::new(10_000)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Cache `inner` in memory and in the SQLite file at `path`, creating
+    /// it if needed.
+    pub fn open(
+        inner: Arc<dyn EmbeddingService>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, anyhow::Error> {
+        let path = path.as_ref();
+        let conn = rusqlite::Connection::open(path).map_err(|e| {
+            anyhow::anyhow!("Cannot open embedding cache '{}': {}", path.display(), e)
+        })?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS embeddings ( \
+                 model TEXT NOT NULL, \
+                 text_sha256 TEXT NOT NULL, \
+                 vector BLOB NOT NULL, \
+                 PRIMARY KEY (model, text_sha256))",
+        )?;
+        let mut service = Self::new(inner);
+        service.path = Some(path.to_path_buf());
+        service.db = Some(Mutex::new(conn));
+        Ok(service)
+    }
+
+    /// Keep at most `entries` vectors in memory.
+    pub fn with_memory_capacity(self, entries: usize) -> Self {
+        self.memory.lock().unwrap().capacity = entries;
+        self
+    }
+
+    /// Most vectors kept in memory.
+    pub fn memory_capacity(&self) -> usize {
+        self.memory.lock().unwrap().capacity
+    }
+
+    /// Texts answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Texts sent to the inner service.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// The cached vector for `hash`, from memory or disk.
+    fn lookup(&self, model: &str, hash: &str) -> Result<Option<Vec<f32>>, anyhow::Error> {
+        let key = (model.to_string(), hash.to_string());
+        if let Some(vector) = self.memory.lock().unwrap().get(&key) {
+            return Ok(Some(vector));
+        }
+        let Some(db) = &self.db else {
+            return Ok(None);
+        };
+        let blob: Option<Vec<u8>> = {
+            let conn = db.lock().unwrap();
+            let mut stmt = conn.prepare_cached(
+                "SELECT vector FROM embeddings WHERE model = ?1 AND text_sha256 = ?2",
+            )?;
+            stmt.query_row(rusqlite::params![model, hash], |row| row.get(0))
+                .optional()?
+        };
+        Ok(blob.map(|blob| {
+            let vector: Vec<f32> = blob
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            self.memory.lock().unwrap().insert(key, vector.clone());
+            vector
+        }))
+    }
+
+    /// Remember freshly embedded vectors, keyed by text hash.
+    fn store(&self, model: &str, entries: &[(&str, &[f32])]) -> Result<(), anyhow::Error> {
+        if let Some(db) = &self.db {
+            let mut conn = db.lock().unwrap();
+            let tx = conn.transaction()?;
+            {
+                let mut stmt = tx.prepare_cached(
+                    "INSERT OR REPLACE INTO embeddings (model, text_sha256, vector) \
+                     VALUES (?1, ?2, ?3)",
+                )?;
+                for (hash, vector) in entries {
+                    let blob: Vec<u8> = vector.iter().flat_map(|v| v.to_le_bytes()).collect();
+                    stmt.execute(rusqlite::params![model, hash, blob])?;
+                }
+            }
+            tx.commit()?;
+        }
+        let mut memory = self.memory.lock().unwrap();
+        for (hash, vector) in entries {
+            memory.insert((model.to_string(), hash.to_string()), vector.to_vec());
+        }
+        Ok(())
+    }
+}
+
+impl EmbeddingService for CachingEmbeddingService {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        self.embed_batch(&[text])?
+            .pop()
+            .ok_or_else(|| anyhow::anyhow!("Embedding service returned no embedding"))
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        let model = self.inner.model_name();
+        let hashes: Vec<String> = texts
+            .iter()
+            .map(|text| format!("{:x}", Sha256::digest(text.as_bytes())))
+            .collect();
+
+        let mut vectors: Vec<Option<Vec<f32>>> = Vec::with_capacity(texts.len());
+        // Distinct missed texts, in first-seen order, by hash.
+        let mut missed: Vec<usize> = Vec::new();
+        let mut seen: HashSet<&str> = HashSet::new();
+        for (i, hash) in hashes.iter().enumerate() {
+            let vector = self.lookup(model, hash)?;
+            if vector.is_none() && seen.insert(hash.as_str()) {
+                missed.push(i);
+            }
+            vectors.push(vector);
+        }
+
+        if !missed.is_empty() {
+            let batch: Vec<&str> = missed.iter().map(|&i| texts[i]).collect();
+            let embedded = self.inner.embed_batch(&batch)?;
+            if embedded.len() != batch.len() {
+                anyhow::bail!(
+                    "Embedding service '{}' returned {} vectors for {} texts",
+                    model,
+                    embedded.len(),
+                    batch.len()
+                );
+            }
+            let entries: Vec<(&str, &[f32])> = missed
+                .iter()
+                .zip(&embedded)
+                .map(|(&i, vector)| (hashes[i].as_str(), vector.as_slice()))
+                .collect();
+            self.store(model, &entries)?;
+            let fresh: HashMap<&str, &Vec<f32>> = entries
+                .iter()
+                .map(|(hash, _)| *hash)
+                .zip(&embedded)
+                .collect();
+            for (vector, hash) in vectors.iter_mut().zip(&hashes) {
+                if vector.is_none() {
+                    *vector = fresh.get(hash.as_str()).map(|v| v.to_vec());
+                }
+            }
+        }
+
+        let misses = missed.len() as u64;
+        self.misses.fetch_add(misses, Ordering::Relaxed);
+        self.hits.fetch_add(texts.len() as u64 - misses, Ordering::Relaxed);
+        Ok(vectors.into_iter().flatten().collect())
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+}
+
+/// Least-recently-used map from (model, text hash) to vector.
+struct Lru {
+    capacity: usize,
+    entries: HashMap<(String, String), (Vec<f32>, u64)>,
+    /// Keys by last use, oldest first.
+    order: BTreeMap<u64, (String, String)>,
+    tick: u64,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn get(&mut self, key: &(String, String)) -> Option<Vec<f32>> {
+        self.tick += 1;
+        let (vector, used) = self.entries.get_mut(key)?;
+        self.order.remove(used);
+        *used = self.tick;
+        self.order.insert(self.tick, key.clone());
+        Some(vector.clone())
+    }
+
+    fn insert(&mut self, key: (String, String), vector: Vec<f32>) {
+        self.tick += 1;
+        if let Some((_, used)) = self.entries.insert(key.clone(), (vector, self.tick)) {
+            self.order.remove(&used);
+        }
+        self.order.insert(self.tick, key);
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+}
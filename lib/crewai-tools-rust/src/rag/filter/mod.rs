@@ -0,0 +1,375 @@
+//! Query-time filters for the RAG framework.
+//!
+//! A [`QueryFilter`] scopes retrieval to a subset of the indexed chunks:
+//! "answer only from the 2024 annual report", "exclude anything from reddit".
+//! Filters are applied before ranking in [`InMemoryVectorIndex`](super::index::InMemoryVectorIndex)
+//! and can be translated to a Lance `WHERE` clause for the LanceDB adapter.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Metadata key holding a chunk's source path or URL.
+pub const SOURCE_KEY: &str = "source";
+
+// ── MetadataCondition ────────────────────────────────────────────────────────
+
+/// How a [`MetadataCondition`] compares its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionOp {
+    /// The metadata value equals the condition value.
+    Equals,
+    /// The metadata value contains the condition value: substring for
+    /// strings, membership for arrays.
+    Contains,
+}
+
+/// A single condition on a metadata field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataCondition {
+    /// Metadata key to test.
+    pub key: String,
+    /// Comparison to apply.
+    pub op: ConditionOp,
+    /// Value to compare against.
+    pub value: Value,
+}
+
+impl MetadataCondition {
+    pub fn equals(key: impl Into<String>, value: Value) -> Self {
+        Self {
+            key: key.into(),
+            op: ConditionOp::Equals,
+            value,
+        }
+    }
+
+    pub fn contains(key: impl Into<String>, value: Value) -> Self {
+        Self {
+            key: key.into(),
+            op: ConditionOp::Contains,
+            value,
+        }
+    }
+
+    /// Whether `metadata` satisfies this condition. Missing keys never match.
+    pub fn matches(&self, metadata: &HashMap<String, Value>) -> bool {
+        let Some(actual) = metadata.get(&self.key) else {
+            return false;
+        };
+        match self.op {
+            ConditionOp::Equals => actual == &self.value,
+            ConditionOp::Contains => match (actual, &self.value) {
+                (Value::String(a), Value::String(v)) => a.contains(v.as_str()),
+                (Value::Array(items), v) => items.contains(v),
+                _ => false,
+            },
+        }
+    }
+}
+
+// ── DateRange ────────────────────────────────────────────────────────────────
+
+/// An inclusive date range on a metadata field holding an ISO-8601 date.
+///
+/// Dates compare lexicographically at the precision of the bound, so a
+/// bound of `2024-12-31` includes `2024-12-31T23:59:00Z`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DateRange {
+    /// Metadata field to read, typically `modified` or `published`.
+    #[serde(default = "default_date_field")]
+    pub field: String,
+    /// Earliest accepted date, inclusive.
+    #[serde(default)]
+    pub from: Option<String>,
+    /// Latest accepted date, inclusive.
+    #[serde(default)]
+    pub to: Option<String>,
+}
+
+fn default_date_field() -> String {
+    "modified".to_string()
+}
+
+impl DateRange {
+    pub fn new(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            from: None,
+            to: None,
+        }
+    }
+
+    pub fn with_from(mut self, date: impl Into<String>) -> Self {
+        self.from = Some(date.into());
+        self
+    }
+
+    pub fn with_to(mut self, date: impl Into<String>) -> Self {
+        self.to = Some(date.into());
+        self
+    }
+
+    /// Whether `metadata` has a date inside the range. Missing or non-string
+    /// dates never match.
+    pub fn matches(&self, metadata: &HashMap<String, Value>) -> bool {
+        let Some(date) = metadata.get(&self.field).and_then(|v| v.as_str()) else {
+            return false;
+        };
+        let at_precision = |bound: &str| &date[..floor_char_boundary(date, bound.len())];
+        if let Some(from) = &self.from {
+            if at_precision(from) < from.as_str() {
+                return false;
+            }
+        }
+        if let Some(to) = &self.to {
+            if at_precision(to) > to.as_str() {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn floor_char_boundary(s: &str, mut i: usize) -> usize {
+    if i >= s.len() {
+        return s.len();
+    }
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+// ── QueryFilter ──────────────────────────────────────────────────────────────
+
+/// Scopes a RAG query to matching chunks.
+///
+/// All parts are combined with AND. Source globs are matched against the
+/// `source` metadata field: a chunk must match at least one include glob (if
+/// any are given) and no exclude glob.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryFilter {
+    /// Source globs to include, e.g. `reports/2024/*.pdf`.
+    #[serde(default)]
+    pub include_sources: Vec<String>,
+    /// Source globs to exclude, e.g. `*reddit.com*`.
+    #[serde(default)]
+    pub exclude_sources: Vec<String>,
+    /// Metadata conditions that must all hold.
+    #[serde(default)]
+    pub conditions: Vec<MetadataCondition>,
+    /// Optional date range.
+    #[serde(default)]
+    pub date_range: Option<DateRange>,
+}
+
+impl QueryFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn include_source(mut self, glob: impl Into<String>) -> Self {
+        self.include_sources.push(glob.into());
+        self
+    }
+
+    pub fn exclude_source(mut self, glob: impl Into<String>) -> Self {
+        self.exclude_sources.push(glob.into());
+        self
+    }
+
+    pub fn with_condition(mut self, condition: MetadataCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn with_date_range(mut self, range: DateRange) -> Self {
+        self.date_range = Some(range);
+        self
+    }
+
+    /// Parse a filter from tool arguments.
+    pub fn from_value(value: &Value) -> Result<Self, anyhow::Error> {
+        serde_json::from_value(value.clone()).map_err(|e| anyhow::anyhow!("Invalid filter: {}", e))
+    }
+
+    /// Whether the filter places no restriction at all.
+    pub fn is_empty(&self) -> bool {
+        self.include_sources.is_empty()
+            && self.exclude_sources.is_empty()
+            && self.conditions.is_empty()
+            && self.date_range.is_none()
+    }
+
+    /// Whether a chunk with `metadata` passes the filter.
+    pub fn matches(&self, metadata: &HashMap<String, Value>) -> bool {
+        let source = metadata.get(SOURCE_KEY).and_then(|v| v.as_str());
+
+        if !self.include_sources.is_empty() {
+            match source {
+                Some(s) if self.include_sources.iter().any(|g| glob_match(g, s)) => {}
+                _ => return false,
+            }
+        }
+        if let Some(s) = source {
+            if self.exclude_sources.iter().any(|g| glob_match(g, s)) {
+                return false;
+            }
+        }
+        if !self.conditions.iter().all(|c| c.matches(metadata)) {
+            return false;
+        }
+        match &self.date_range {
+            Some(range) => range.matches(metadata),
+            None => true,
+        }
+    }
+
+    /// Translate the filter to a Lance SQL `WHERE` clause.
+    ///
+    /// Metadata keys map to column names of the same name. Source globs and
+    /// `contains` values become `LIKE … ESCAPE '\'` patterns, so a literal
+    /// `%` or `_` in them matches only itself. Returns `None` for an empty
+    /// filter.
+    pub fn to_lance_filter(&self) -> Option<String> {
+        let mut clauses = Vec::new();
+
+        if !self.include_sources.is_empty() {
+            let any: Vec<String> = self
+                .include_sources
+                .iter()
+                .map(|g| lance_like(&lance_column(SOURCE_KEY), "LIKE", &glob_to_like(g)))
+                .collect();
+            clauses.push(format!("({})", any.join(" OR ")));
+        }
+        for glob in &self.exclude_sources {
+            clauses.push(lance_like(&lance_column(SOURCE_KEY), "NOT LIKE", &glob_to_like(glob)));
+        }
+        for condition in &self.conditions {
+            let column = lance_column(&condition.key);
+            clauses.push(match (condition.op, &condition.value) {
+                (ConditionOp::Equals, Value::Null) => format!("{} IS NULL", column),
+                (ConditionOp::Equals, v) => format!("{} = {}", column, lance_literal(v)),
+                (ConditionOp::Contains, Value::String(s)) => {
+                    lance_like(&column, "LIKE", &format!("%{}%", like_escape(s)))
+                }
+                (ConditionOp::Contains, v) => {
+                    format!("array_has({}, {})", column, lance_literal(v))
+                }
+            });
+        }
+        if let Some(range) = &self.date_range {
+            let column = lance_column(&range.field);
+            if let Some(from) = &range.from {
+                clauses.push(format!("{} >= {}", column, lance_string(from)));
+            }
+            if let Some(to) = &range.to {
+                // Inclusive at the bound's precision: everything up to the
+                // next value that no longer shares the bound as a prefix.
+                clauses.push(format!(
+                    "({} <= {} OR {})",
+                    column,
+                    lance_string(to),
+                    lance_like(&column, "LIKE", &format!("{}%", like_escape(to)))
+                ));
+            }
+        }
+
+        if clauses.is_empty() {
+            None
+        } else {
+            Some(clauses.join(" AND "))
+        }
+    }
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Match `text` against a glob where `*` matches any run of characters
+/// (including `/`) and `?` matches exactly one.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+/// Translate a glob to a LIKE pattern. The glob's literal `%`, `_` and `\`
+/// are escaped so they match only themselves; see [`lance_like`].
+fn glob_to_like(glob: &str) -> String {
+    let mut like = String::with_capacity(glob.len());
+    let mut after_star = false;
+    for c in glob.chars() {
+        match c {
+            // A run of stars is one `%`.
+            '*' if after_star => {}
+            '*' => like.push('%'),
+            '?' => like.push('_'),
+            '%' | '_' | '\\' => {
+                like.push('\\');
+                like.push(c);
+            }
+            c => like.push(c),
+        }
+        after_star = c == '*';
+    }
+    like
+}
+
+/// Escape LIKE wildcards (and the escape character itself) in a literal.
+fn like_escape(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// `column LIKE 'pattern' ESCAPE '\'` (or `NOT LIKE`), for a pattern whose
+/// literal parts went through [`like_escape`].
+fn lance_like(column: &str, op: &str, pattern: &str) -> String {
+    format!("{} {} {} ESCAPE '\\'", column, op, lance_string(pattern))
+}
+
+fn lance_column(key: &str) -> String {
+    if key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        key.to_string()
+    } else {
+        format!("`{}`", key.replace('`', "``"))
+    }
+}
+
+fn lance_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+fn lance_literal(value: &Value) -> String {
+    match value {
+        Value::String(s) => lance_string(s),
+        Value::Null => "NULL".to_string(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,265 @@
+//! Vector indexes for the RAG framework.
+//!
+//! [`InMemoryVectorIndex`] keeps embedded chunks in memory and ranks them by
+//! cosine similarity. [`InMemoryVectorStore`] does the same behind the
+//! [`VectorStore`] trait, with chunk IDs and JSON file persistence; it is the
+//! default store used by [`RagPipeline`](super::pipeline::RagPipeline).
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub use super::core::cosine_similarity;
+use super::core::{content_id, Chunk, VectorStore};
+use super::filter::QueryFilter;
+
+/// A chunk returned by a vector search, with its similarity score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    /// The chunk's ID in its [`VectorStore`]; `None` from an
+    /// [`InMemoryVectorIndex`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The chunk text.
+    pub content: String,
+    /// The chunk metadata.
+    pub metadata: HashMap<String, Value>,
+    /// Cosine similarity to the query, in `[-1.0, 1.0]`.
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedChunk {
+    chunk: Chunk,
+    embedding: Vec<f32>,
+}
+
+/// Brute-force in-memory vector index.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryVectorIndex {
+    entries: Vec<IndexedChunk>,
+}
+
+impl InMemoryVectorIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk with its embedding.
+    pub fn add(&mut self, chunk: Chunk, embedding: Vec<f32>) {
+        self.entries.push(IndexedChunk { chunk, embedding });
+    }
+
+    /// Number of indexed chunks.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the `top_k` chunks most similar to `query`.
+    ///
+    /// When a filter is given, chunks that fail it are dropped before
+    /// ranking, so `top_k` counts matching chunks only. A filter that
+    /// eliminates every chunk yields an empty result.
+    pub fn search(&self, query: &[f32], top_k: usize, filter: Option<&QueryFilter>) -> Vec<SearchHit> {
+        let filter = filter.filter(|f| !f.is_empty());
+        let mut scored: Vec<(f32, &IndexedChunk)> = self
+            .entries
+            .iter()
+            .filter(|e| match filter {
+                Some(f) => f.matches(&e.chunk.metadata),
+                None => true,
+            })
+            .map(|e| (cosine_similarity(query, &e.embedding), e))
+            .collect();
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+
+        scored
+            .into_iter()
+            .map(|(score, e)| SearchHit {
+                id: None,
+                content: e.chunk.content.clone(),
+                metadata: e.chunk.metadata.clone(),
+                score,
+            })
+            .collect()
+    }
+}
+
+// ── InMemoryVectorStore ──────────────────────────────────────────────────────
+
+/// File format version written by [`InMemoryVectorStore::persist`].
+const STORE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredChunk {
+    id: String,
+    chunk: Chunk,
+    embedding: Vec<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoreFile {
+    version: u32,
+    entries: Vec<StoredChunk>,
+}
+
+/// Brute-force in-memory [`VectorStore`], saved to and loaded from JSON.
+///
+/// Chunks are ranked by cosine similarity to the query. IDs come from the
+/// chunk text, so re-adding a chunk replaces it in place.
+#[derive(Debug, Default)]
+pub struct InMemoryVectorStore {
+    entries: RwLock<Vec<StoredChunk>>,
+}
+
+impl InMemoryVectorStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A store holding the contents saved at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, anyhow::Error> {
+        let store = Self::new();
+        store.load(path.as_ref())?;
+        Ok(store)
+    }
+
+    /// Number of stored chunks.
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl VectorStore for InMemoryVectorStore {
+    fn add(
+        &self,
+        chunks: Vec<Chunk>,
+        embeddings: Vec<Vec<f32>>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        if chunks.len() != embeddings.len() {
+            anyhow::bail!(
+                "InMemoryVectorStore: {} embeddings for {} chunks",
+                embeddings.len(),
+                chunks.len()
+            );
+        }
+        let mut entries = self.entries.write().unwrap();
+        let mut positions: HashMap<String, usize> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.id.clone(), i))
+            .collect();
+        let mut ids = Vec::with_capacity(chunks.len());
+        for (chunk, embedding) in chunks.into_iter().zip(embeddings) {
+            let id = content_id(&chunk.content);
+            let stored = StoredChunk {
+                id: id.clone(),
+                chunk,
+                embedding,
+            };
+            match positions.get(&id) {
+                Some(&i) => entries[i] = stored,
+                None => {
+                    positions.insert(id.clone(), entries.len());
+                    entries.push(stored);
+                }
+            }
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    fn search(
+        &self,
+        query: &[f32],
+        top_k: usize,
+        filter: Option<&QueryFilter>,
+    ) -> Result<Vec<SearchHit>, anyhow::Error> {
+        let filter = filter.filter(|f| !f.is_empty());
+        let entries = self.entries.read().unwrap();
+        let mut scored: Vec<(f32, &StoredChunk)> = entries
+            .iter()
+            .filter(|e| match filter {
+                Some(f) => f.matches(&e.chunk.metadata),
+                None => true,
+            })
+            .map(|e| (cosine_similarity(query, &e.embedding), e))
+            .collect();
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(top_k);
+
+        Ok(scored
+            .into_iter()
+            .map(|(score, e)| SearchHit {
+                id: Some(e.id.clone()),
+                content: e.chunk.content.clone(),
+                metadata: e.chunk.metadata.clone(),
+                score,
+            })
+            .collect())
+    }
+
+    fn delete(&self, ids: &[String]) -> Result<usize, anyhow::Error> {
+        let mut entries = self.entries.write().unwrap();
+        let before = entries.len();
+        entries.retain(|e| !ids.contains(&e.id));
+        Ok(before - entries.len())
+    }
+
+    fn contains(&self, ids: &[String]) -> Result<Vec<bool>, anyhow::Error> {
+        let entries = self.entries.read().unwrap();
+        let stored: HashSet<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        Ok(ids.iter().map(|id| stored.contains(id.as_str())).collect())
+    }
+
+    /// Write every chunk and embedding to `path` as JSON, replacing the
+    /// file only once the new one is complete.
+    fn persist(&self, path: &Path) -> Result<(), anyhow::Error> {
+        let file = StoreFile {
+            version: STORE_FORMAT_VERSION,
+            entries: self.entries.read().unwrap().clone(),
+        };
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, serde_json::to_vec(&file)?)
+            .map_err(|e| anyhow::anyhow!("Cannot write '{}': {}", partial.display(), e))?;
+        std::fs::rename(&partial, path)
+            .map_err(|e| anyhow::anyhow!("Cannot write '{}': {}", path.display(), e))?;
+        Ok(())
+    }
+
+    fn load(&self, path: &Path) -> Result<(), anyhow::Error> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read '{}': {}", path.display(), e))?;
+        let file: StoreFile = serde_json::from_slice(&bytes).map_err(|e| {
+            anyhow::anyhow!("'{}' is not a saved vector store: {}", path.display(), e)
+        })?;
+        if file.version != STORE_FORMAT_VERSION {
+            anyhow::bail!(
+                "'{}' has vector store format version {}, expected {}",
+                path.display(),
+                file.version,
+                STORE_FORMAT_VERSION
+            );
+        }
+        *self.entries.write().unwrap() = file.entries;
+        Ok(())
+    }
+
+    fn store_name(&self) -> &str {
+        "InMemoryVectorStore"
+    }
+}
@@ -0,0 +1,218 @@
+//! Lightweight language detection for the RAG framework.
+//!
+//! Multilingual corpora need routing: different embedders, different prompts.
+//! Loaders and chunkers call [`stamp_language`] to record an ISO 639-1 code
+//! and a confidence in `language` / `language_confidence` metadata.
+//!
+//! Detection is deliberately cheap. At most [`MAX_SAMPLE_CHARS`] characters
+//! are examined, taken from the start, middle and end of the text. Non-Latin
+//! scripts are identified by Unicode block; Latin-script languages by
+//! stopword frequency.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Metadata key for the detected ISO 639-1 language code.
+pub const LANGUAGE_KEY: &str = "language";
+/// Metadata key for the detection confidence in `[0.0, 1.0]`.
+pub const LANGUAGE_CONFIDENCE_KEY: &str = "language_confidence";
+/// Upper bound on the characters examined per detection.
+pub const MAX_SAMPLE_CHARS: usize = 2048;
+/// Below this many letters, no language is reported.
+const MIN_LETTERS: usize = 12;
+
+/// A detected language and how sure the detector is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectedLanguage {
+    /// ISO 639-1 code, e.g. `"en"`.
+    pub code: &'static str,
+    /// Confidence in `[0.0, 1.0]`.
+    pub confidence: f32,
+}
+
+/// Stopwords for the Latin-script languages we distinguish.
+const STOPWORDS: &[(&str, &[&str])] = &[
+    ("en", &["the", "and", "of", "to", "is", "in", "that", "it", "for", "was", "with", "as", "are", "this", "be", "on", "by", "not", "have", "from"]),
+    ("de", &["der", "die", "und", "das", "ist", "nicht", "mit", "den", "ein", "eine", "zu", "von", "sich", "auf", "auch", "dem", "des", "im", "für", "wird"]),
+    ("fr", &["le", "la", "les", "et", "des", "est", "une", "un", "du", "que", "qui", "dans", "pour", "pas", "sur", "avec", "au", "ce", "sont", "par"]),
+    ("es", &["el", "la", "los", "las", "y", "que", "es", "una", "un", "del", "por", "con", "para", "se", "no", "en", "al", "su", "como", "más"]),
+    ("it", &["il", "la", "che", "di", "e", "è", "un", "una", "per", "non", "sono", "del", "della", "con", "gli", "le", "nel", "alla", "come", "anche"]),
+    ("pt", &["o", "a", "os", "as", "que", "de", "não", "uma", "um", "do", "da", "para", "com", "em", "por", "se", "mais", "são", "ao", "foi"]),
+    ("nl", &["de", "het", "een", "en", "van", "is", "dat", "niet", "op", "te", "zijn", "met", "voor", "die", "ook", "aan", "er", "maar", "om", "wordt"]),
+    ("sv", &["och", "att", "det", "som", "en", "är", "på", "av", "för", "med", "inte", "till", "den", "har", "de", "om", "ett", "var", "jag", "men"]),
+    ("pl", &["i", "w", "nie", "na", "się", "z", "jest", "to", "że", "do", "jak", "o", "ale", "co", "po", "tak", "od", "za", "są", "przez"]),
+    ("tr", &["ve", "bir", "bu", "da", "de", "için", "ile", "çok", "daha", "olarak", "gibi", "ama", "olan", "en", "mi", "var", "ne", "kadar", "sonra", "değil"]),
+];
+
+#[derive(Debug, Default)]
+struct ScriptCounts {
+    latin: usize,
+    cyrillic: usize,
+    greek: usize,
+    arabic: usize,
+    hebrew: usize,
+    kana: usize,
+    han: usize,
+    hangul: usize,
+    thai: usize,
+    devanagari: usize,
+    /// Letters specific to Ukrainian (і ї є ґ).
+    ukrainian: usize,
+    /// Letters specific to Persian (پ چ ژ گ).
+    persian: usize,
+}
+
+impl ScriptCounts {
+    fn add(&mut self, c: char) {
+        match c as u32 {
+            0x0041..=0x005A | 0x0061..=0x007A | 0x00C0..=0x024F => self.latin += 1,
+            0x0400..=0x04FF => {
+                self.cyrillic += 1;
+                if matches!(c, 'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ') {
+                    self.ukrainian += 1;
+                }
+            }
+            0x0370..=0x03FF => self.greek += 1,
+            0x0600..=0x06FF => {
+                self.arabic += 1;
+                if matches!(c, 'پ' | 'چ' | 'ژ' | 'گ') {
+                    self.persian += 1;
+                }
+            }
+            0x0590..=0x05FF => self.hebrew += 1,
+            0x3040..=0x30FF => self.kana += 1,
+            0x4E00..=0x9FFF | 0x3400..=0x4DBF => self.han += 1,
+            0xAC00..=0xD7AF | 0x1100..=0x11FF => self.hangul += 1,
+            0x0E00..=0x0E7F => self.thai += 1,
+            0x0900..=0x097F => self.devanagari += 1,
+            _ => {}
+        }
+    }
+
+    fn total(&self) -> usize {
+        self.latin
+            + self.cyrillic
+            + self.greek
+            + self.arabic
+            + self.hebrew
+            + self.kana
+            + self.han
+            + self.hangul
+            + self.thai
+            + self.devanagari
+    }
+}
+
+/// Detect the dominant language of `text`.
+///
+/// Returns `None` when the text has too few letters to judge.
+pub fn detect_language(text: &str) -> Option<DetectedLanguage> {
+    let sample = sample_text(text);
+
+    let mut counts = ScriptCounts::default();
+    for c in sample.chars() {
+        counts.add(c);
+    }
+    let total = counts.total();
+    if total < MIN_LETTERS {
+        return None;
+    }
+    let share = |n: usize| n as f32 / total as f32;
+
+    // Japanese mixes kana with kanji; Han without kana is Chinese.
+    let cjk = counts.kana + counts.han;
+    let candidates = [
+        (if counts.kana > 0 { "ja" } else { "zh" }, cjk),
+        ("ko", counts.hangul),
+        (if counts.ukrainian > 0 { "uk" } else { "ru" }, counts.cyrillic),
+        ("el", counts.greek),
+        (if counts.persian > 0 { "fa" } else { "ar" }, counts.arabic),
+        ("he", counts.hebrew),
+        ("th", counts.thai),
+        ("hi", counts.devanagari),
+    ];
+    let (code, n) = candidates.iter().copied().max_by_key(|(_, n)| *n)?;
+    if n > counts.latin {
+        return Some(DetectedLanguage {
+            code,
+            confidence: share(n),
+        });
+    }
+
+    detect_latin(&sample).map(|(code, confidence)| DetectedLanguage {
+        code,
+        confidence: (confidence * share(counts.latin)).clamp(0.0, 1.0),
+    })
+}
+
+/// Score Latin-script text against each stopword list.
+fn detect_latin(sample: &str) -> Option<(&'static str, f32)> {
+    let words: Vec<String> = sample
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let mut scores: Vec<(&'static str, usize)> = STOPWORDS
+        .iter()
+        .map(|(code, list)| (*code, words.iter().filter(|w| list.contains(&w.as_str())).count()))
+        .collect();
+    scores.sort_by_key(|s| std::cmp::Reverse(s.1));
+
+    let (best_code, best) = scores[0];
+    let second = scores.get(1).map_or(0, |s| s.1);
+    if best == 0 {
+        return None;
+    }
+
+    // How clearly the winner beats the runner-up, scaled down for texts
+    // with few stopword hits to go on.
+    let margin = best as f32 / (best + second) as f32;
+    let evidence = (best as f32 / 8.0).min(1.0);
+    Some((best_code, margin * evidence))
+}
+
+/// Up to `MAX_SAMPLE_CHARS` characters from the start, middle and end.
+///
+/// Windows are located by byte offset so sampling a huge text never walks it.
+fn sample_text(text: &str) -> String {
+    let window = MAX_SAMPLE_CHARS / 3;
+    if text.len() <= MAX_SAMPLE_CHARS {
+        return text.to_string();
+    }
+    let starts = [0, text.len() / 2, text.len().saturating_sub(window * 4)];
+    let mut sample = String::with_capacity(MAX_SAMPLE_CHARS * 4);
+    for mut start in starts {
+        while !text.is_char_boundary(start) {
+            start += 1;
+        }
+        sample.extend(text[start..].chars().take(window));
+        sample.push(' ');
+    }
+    sample
+}
+
+/// Detect the language of `text` and record it in `metadata`.
+///
+/// Leaves `metadata` untouched when nothing can be detected.
+pub fn stamp_language(metadata: &mut HashMap<String, Value>, text: &str) {
+    if let Some(detected) = detect_language(text) {
+        metadata.insert(LANGUAGE_KEY.to_string(), Value::String(detected.code.to_string()));
+        metadata.insert(
+            LANGUAGE_CONFIDENCE_KEY.to_string(),
+            serde_json::json!((detected.confidence * 100.0).round() / 100.0),
+        );
+    }
+}
+
+/// Read the confidence recorded by [`stamp_language`], if any.
+pub fn language_confidence(metadata: &HashMap<String, Value>) -> Option<f32> {
+    metadata
+        .get(LANGUAGE_CONFIDENCE_KEY)
+        .and_then(|v| v.as_f64())
+        .map(|c| c as f32)
+}
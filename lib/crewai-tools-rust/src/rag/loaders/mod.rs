@@ -4,19 +4,48 @@
 //! handles loading documents from a specific file format or data source.
 //! These correspond to loader classes in the Python `crewai_tools.rag` module.
 
+use serde_json::Value;
+
 use super::core::{BaseLoader, Document};
+use super::html::html_to_markdown;
+use super::language::stamp_language;
+use crate::{docx, markdown, tabular, xpath, youtube};
+#[cfg(feature = "cloud_storage")]
+use crate::tools::cloud_storage::S3;
 
 // ── CsvLoader ────────────────────────────────────────────────────────────────
 
+/// How [`CsvLoader`] turns rows into documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvLoadMode {
+    /// One document per data row.
+    #[default]
+    Rows,
+    /// A single document holding every row, separated by blank lines.
+    Combined,
+}
+
 /// Load documents from CSV files.
 ///
 /// Corresponds to Python CSV loader in `crewai_tools.rag`.
+///
+/// In [`CsvLoadMode::Rows`] each row becomes a document whose metadata holds
+/// `source`, `row_index` (0-based, data rows only) and every column value
+/// under its header name, so rows can be filtered by column at query time.
+/// Ragged rows are kept: missing trailing cells are left out and extra cells
+/// are named `column_N`.
 #[derive(Debug, Clone)]
 pub struct CsvLoader {
     /// Path to the CSV file.
     pub file_path: String,
     /// Column to use as document content. If `None`, all columns are concatenated.
     pub content_column: Option<String>,
+    /// Field delimiter (default `,`).
+    pub delimiter: u8,
+    /// Whether the first row holds column names; `None` detects it.
+    pub has_header: Option<bool>,
+    /// One document per row, or one for the whole file.
+    pub mode: CsvLoadMode,
 }
 
 impl CsvLoader {
@@ -24,6 +53,9 @@ impl CsvLoader {
         Self {
             file_path: file_path.into(),
             content_column: None,
+            delimiter: b',',
+            has_header: None,
+            mode: CsvLoadMode::default(),
         }
     }
 
@@ -31,11 +63,132 @@ impl CsvLoader {
         self.content_column = Some(column.into());
         self
     }
+
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = Some(has_header);
+        self
+    }
+
+    pub fn with_mode(mut self, mode: CsvLoadMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Render one row: the content column, or `col: value` lines.
+    fn row_content(&self, headers: &[String], fields: &[String]) -> String {
+        match &self.content_column {
+            Some(column) => headers
+                .iter()
+                .position(|h| h == column)
+                .and_then(|i| fields.get(i))
+                .cloned()
+                .unwrap_or_default(),
+            None => headers
+                .iter()
+                .zip(fields)
+                .map(|(header, value)| format!("{}: {}", header, value))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
 }
 
 impl BaseLoader for CsvLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("CsvLoader: not yet implemented - requires CSV parsing integration")
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.delimiter)
+            .from_path(&self.file_path)
+            .map_err(|e| anyhow::anyhow!("Failed to open CSV file '{}': {}", self.file_path, e))?;
+
+        let mut rows = Vec::new();
+        for (i, record) in reader.byte_records().enumerate() {
+            match record {
+                Ok(record) => rows.push(
+                    record
+                        .iter()
+                        .map(|f| String::from_utf8_lossy(f).into_owned())
+                        .collect::<Vec<String>>(),
+                ),
+                Err(e) => log::warn!(
+                    "CsvLoader: skipping unreadable row {} in '{}': {}",
+                    i + 1,
+                    self.file_path,
+                    e
+                ),
+            }
+        }
+        let mut rows = rows.into_iter().peekable();
+        let Some(first) = rows.peek() else {
+            return Ok(Vec::new());
+        };
+
+        let first: Vec<String> = first.iter().map(|f| f.trim().to_string()).collect();
+        let has_header = self.has_header.unwrap_or_else(|| tabular::looks_like_header(&first));
+        let mut headers: Vec<String> = if has_header {
+            rows.next();
+            first
+        } else {
+            Vec::new()
+        };
+        let rows: Vec<Vec<String>> = rows.collect();
+        let widest = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for n in headers.len()..widest {
+            headers.push(format!("column_{}", n + 1));
+        }
+        if let Some(column) = &self.content_column {
+            if !headers.contains(column) {
+                anyhow::bail!(
+                    "CsvLoader: unknown content column '{}'; available columns: {:?}",
+                    column,
+                    headers
+                );
+            }
+        }
+
+        let source = Value::String(self.file_path.clone());
+        if self.mode == CsvLoadMode::Combined {
+            let content = rows
+                .iter()
+                .map(|fields| self.row_content(&headers, fields))
+                .filter(|row| !row.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            if content.trim().is_empty() {
+                return Ok(Vec::new());
+            }
+            let mut document = Document::new(content)
+                .with_metadata("source", source)
+                .with_metadata("row_count", serde_json::json!(rows.len()))
+                .with_metadata("columns", serde_json::json!(headers));
+            stamp_language(&mut document.metadata, &document.content);
+            return Ok(vec![document]);
+        }
+
+        let mut documents = Vec::new();
+        for (row_index, fields) in rows.iter().enumerate() {
+            let content = self.row_content(&headers, fields);
+            if content.trim().is_empty() {
+                continue;
+            }
+            let mut document = Document::new(content);
+            for (header, value) in headers.iter().zip(fields) {
+                document = document.with_metadata(header.clone(), Value::String(value.clone()));
+            }
+            // Set last so a column named `source` cannot hide the file.
+            document = document
+                .with_metadata("source", source.clone())
+                .with_metadata("row_index", serde_json::json!(row_index));
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
     }
 
     fn loader_name(&self) -> &str {
@@ -109,7 +262,39 @@ impl PdfLoader {
 
 impl BaseLoader for PdfLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("PdfLoader: not yet implemented - requires PDF parsing library")
+        let pdf = crate::pdf::PdfPages::open(&self.file_path)?;
+        let total_pages = pdf.page_count();
+
+        let mut documents = Vec::new();
+        let mut combined = String::new();
+        for page in pdf.page_numbers() {
+            let text = pdf.page_text(page);
+            if self.split_by_page {
+                if text.trim().is_empty() {
+                    continue;
+                }
+                let mut document = Document::new(text)
+                    .with_metadata("source", Value::String(self.file_path.clone()))
+                    .with_metadata("page", serde_json::json!(page))
+                    .with_metadata("total_pages", serde_json::json!(total_pages));
+                stamp_language(&mut document.metadata, &document.content);
+                documents.push(document);
+            } else {
+                if !combined.is_empty() {
+                    combined.push_str("\n\n");
+                }
+                combined.push_str(&text);
+            }
+        }
+
+        if !self.split_by_page && !combined.trim().is_empty() {
+            let mut document = Document::new(combined)
+                .with_metadata("source", Value::String(self.file_path.clone()))
+                .with_metadata("total_pages", serde_json::json!(total_pages));
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
     }
 
     fn loader_name(&self) -> &str {
@@ -146,7 +331,18 @@ impl TextLoader {
 
 impl BaseLoader for TextLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("TextLoader: not yet implemented - requires file I/O integration")
+        let encoding = self.encoding.to_ascii_lowercase();
+        if encoding != "utf-8" && encoding != "utf8" {
+            anyhow::bail!("TextLoader: unsupported encoding '{}' (only utf-8 is supported)", self.encoding);
+        }
+        let bytes = std::fs::read(&self.file_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", self.file_path, e))?;
+        let content = String::from_utf8_lossy(&bytes).into_owned();
+
+        let mut document =
+            Document::new(content).with_metadata("source", Value::String(self.file_path.clone()));
+        stamp_language(&mut document.metadata, &document.content);
+        Ok(vec![document])
     }
 
     fn loader_name(&self) -> &str {
@@ -154,6 +350,115 @@ impl BaseLoader for TextLoader {
     }
 }
 
+// ── MarkdownLoader ───────────────────────────────────────────────────────────
+
+/// Load documents from Markdown (`.md`, `.mdx`) files.
+///
+/// Corresponds to Python Markdown loader in `crewai_tools.rag`.
+///
+/// YAML frontmatter is removed from the content and its top-level keys are
+/// copied into metadata, so filters can match on them. The rest of the file
+/// becomes one document per heading at `split_level` or shallower (by
+/// default the shallowest level the file uses), with `heading`,
+/// `heading_level`, and the heading trail in `breadcrumb` (outermost first)
+/// and `heading_path` ("Guide > Install"). Text before the first such
+/// heading is its own document. Content is kept verbatim, code fences
+/// included, and `#` lines inside fences never start a section.
+#[derive(Debug, Clone)]
+pub struct MarkdownLoader {
+    /// Path to the Markdown file.
+    pub file_path: String,
+    /// Deepest heading level that starts a new document.
+    pub split_level: Option<usize>,
+}
+
+impl MarkdownLoader {
+    pub fn new(file_path: impl Into<String>) -> Self {
+        Self {
+            file_path: file_path.into(),
+            split_level: None,
+        }
+    }
+
+    pub fn with_split_level(mut self, level: usize) -> Self {
+        self.split_level = Some(level);
+        self
+    }
+}
+
+/// Lines gathered for one output document.
+#[derive(Default)]
+struct MarkdownSection<'a> {
+    heading: Option<(String, usize)>,
+    breadcrumb: Vec<String>,
+    lines: Vec<&'a str>,
+}
+
+impl BaseLoader for MarkdownLoader {
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
+        let source = std::fs::read_to_string(&self.file_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", self.file_path, e))?;
+        let lines: Vec<&str> = source.lines().collect();
+        let body_start = markdown::skip_frontmatter(&lines);
+        let frontmatter = match body_start {
+            0 => serde_json::Map::new(),
+            end => frontmatter_metadata(&lines[1..end - 1], &self.file_path),
+        };
+        let body = &lines[body_start..];
+
+        let headings = markdown_headings(body);
+        let split_level = self
+            .split_level
+            .or_else(|| headings.iter().map(|(_, level, _)| *level).min());
+
+        let mut sections = vec![MarkdownSection::default()];
+        let mut trail: Vec<(usize, String)> = Vec::new();
+        let mut headings = headings.into_iter().peekable();
+        for (i, line) in body.iter().enumerate() {
+            if let Some((_, level, title)) = headings.next_if(|(at, _, _)| *at == i) {
+                trail.retain(|(l, _)| *l < level);
+                trail.push((level, title.clone()));
+                if split_level.is_some_and(|split| level <= split) {
+                    sections.push(MarkdownSection {
+                        heading: Some((title, level)),
+                        breadcrumb: trail.iter().map(|(_, t)| t.clone()).collect(),
+                        lines: Vec::new(),
+                    });
+                }
+            }
+            sections.last_mut().expect("sections is never empty").lines.push(*line);
+        }
+
+        let mut documents = Vec::new();
+        for (index, section) in sections.into_iter().enumerate() {
+            let Some(first) = section.lines.iter().position(|l| !l.trim().is_empty()) else {
+                continue;
+            };
+            let last = section.lines.iter().rposition(|l| !l.trim().is_empty()).unwrap_or(first);
+
+            let mut document = Document::new(section.lines[first..=last].join("\n"));
+            document.metadata.extend(frontmatter.clone());
+            document = document
+                .with_metadata("source", Value::String(self.file_path.clone()))
+                .with_metadata("section_index", serde_json::json!(index))
+                .with_metadata("heading_path", Value::String(section.breadcrumb.join(" > ")))
+                .with_metadata("breadcrumb", serde_json::json!(section.breadcrumb));
+            if let Some((heading, level)) = section.heading {
+                document = document
+                    .with_metadata("heading", Value::String(heading))
+                    .with_metadata("heading_level", serde_json::json!(level));
+            }
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
+    }
+
+    fn loader_name(&self) -> &str {
+        "MarkdownLoader"
+    }
+}
+
 // ── WebpageLoader ────────────────────────────────────────────────────────────
 
 /// Load documents from web pages by fetching and parsing HTML.
@@ -183,9 +488,24 @@ impl WebpageLoader {
 
 impl BaseLoader for WebpageLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!(
-            "WebpageLoader: not yet implemented - requires HTTP client and HTML parsing"
-        )
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .user_agent("Mozilla/5.0 (compatible; CrewAI/1.0)")
+            .build()?;
+        let html = client.get(&self.url).send()?.error_for_status()?.text()?;
+
+        let body = if self.extract_main_content {
+            main_content(&html).unwrap_or(&html)
+        } else {
+            &html
+        };
+        let mut document = Document::new(html_to_markdown(body))
+            .with_metadata("source", Value::String(self.url.clone()));
+        if let Some(title) = html_title(&html) {
+            document = document.with_metadata("title", Value::String(title));
+        }
+        stamp_language(&mut document.metadata, &document.content);
+        Ok(vec![document])
     }
 
     fn loader_name(&self) -> &str {
@@ -198,6 +518,15 @@ impl BaseLoader for WebpageLoader {
 /// Load documents from all supported files in a directory.
 ///
 /// Corresponds to Python directory loader in `crewai_tools.rag`.
+///
+/// Each file is handed to the loader for its extension: plain text to
+/// [`TextLoader`], `md`/`markdown`/`mdx` to [`MarkdownLoader`], `csv`/`tsv`
+/// to [`CsvLoader`], `json` to [`JsonLoader`], `pdf` to [`PdfLoader`],
+/// `docx` to [`DocxLoader`] and `xml`/`rss`/`atom` to [`XmlLoader`]; other
+/// files are skipped. Hidden entries and the names
+/// in `ignore` are never visited. A file that fails to load does not stop
+/// the rest: [`DirectoryLoader::load_report`] lists it under `errors`, and
+/// `load` logs and skips it unless `strict` is set.
 #[derive(Debug, Clone)]
 pub struct DirectoryLoader {
     /// Path to the directory.
@@ -206,6 +535,26 @@ pub struct DirectoryLoader {
     pub extensions: Vec<String>,
     /// Whether to search subdirectories recursively.
     pub recursive: bool,
+    /// File and directory names to skip wherever they appear.
+    pub ignore: Vec<String>,
+    /// Whether to visit entries whose names start with `.`.
+    pub include_hidden: bool,
+    /// Whether `load` fails when any file fails instead of skipping it.
+    pub strict: bool,
+}
+
+/// Documents loaded from a directory, with the files that failed.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryLoadReport {
+    pub documents: Vec<Document>,
+    pub errors: Vec<FileLoadError>,
+}
+
+/// A file or directory [`DirectoryLoader`] could not read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLoadError {
+    pub path: String,
+    pub error: String,
 }
 
 impl DirectoryLoader {
@@ -214,6 +563,9 @@ impl DirectoryLoader {
             directory: directory.into(),
             extensions: Vec::new(),
             recursive: true,
+            ignore: [".git", "target", "node_modules"].map(String::from).to_vec(),
+            include_hidden: false,
+            strict: false,
         }
     }
 
@@ -226,13 +578,153 @@ impl DirectoryLoader {
         self.recursive = recursive;
         self
     }
+
+    pub fn with_ignore(mut self, names: Vec<String>) -> Self {
+        self.ignore = names;
+        self
+    }
+
+    pub fn with_include_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Load every matching file, collecting failures instead of stopping.
+    ///
+    /// Only a missing or unreadable top-level directory is an error.
+    pub fn load_report(&self) -> Result<DirectoryLoadReport, anyhow::Error> {
+        let root = std::path::Path::new(&self.directory);
+        if !root.is_dir() {
+            anyhow::bail!("DirectoryLoader: '{}' is not a directory", self.directory);
+        }
+        let wanted: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|e| e.trim_start_matches('.').to_ascii_lowercase())
+            .collect();
+
+        let mut report = DirectoryLoadReport::default();
+        let mut stack = vec![root.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if dir == root => {
+                    anyhow::bail!("Failed to read directory '{}': {}", self.directory, e)
+                }
+                Err(e) => {
+                    report.fail(&dir, e);
+                    continue;
+                }
+            };
+            let mut paths: Vec<std::path::PathBuf> =
+                entries.filter_map(Result::ok).map(|e| e.path()).collect();
+            paths.sort();
+
+            let mut subdirs = Vec::new();
+            for path in paths {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+                if (!self.include_hidden && name.starts_with('.'))
+                    || self.ignore.iter().any(|ignored| ignored == name)
+                {
+                    continue;
+                }
+                // Symlinked directories are not followed, so cycles cannot occur.
+                let Ok(link) = std::fs::symlink_metadata(&path) else {
+                    continue;
+                };
+                if link.is_dir() {
+                    if self.recursive {
+                        subdirs.push(path);
+                    }
+                    continue;
+                }
+                if !path.is_file() {
+                    continue;
+                }
+                let extension = path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                if !wanted.is_empty() && !wanted.contains(&extension) {
+                    continue;
+                }
+                let file = path.to_string_lossy().into_owned();
+                let Some(loader) = loader_for(&file, &extension) else {
+                    if !wanted.is_empty() {
+                        report.fail(&path, format!("no loader for .{} files", extension));
+                    }
+                    continue;
+                };
+                match loader.load() {
+                    Ok(documents) => {
+                        report.documents.extend(documents.into_iter().map(|mut document| {
+                            document
+                                .metadata
+                                .entry("source".to_string())
+                                .or_insert_with(|| Value::String(file.clone()));
+                            document
+                        }))
+                    }
+                    Err(e) => report.fail(&path, e),
+                }
+            }
+            // Popped in reverse, so subdirectories are visited in name order.
+            stack.extend(subdirs.into_iter().rev());
+        }
+        Ok(report)
+    }
+}
+
+impl DirectoryLoadReport {
+    fn fail(&mut self, path: &std::path::Path, error: impl std::fmt::Display) {
+        self.errors.push(FileLoadError {
+            path: path.to_string_lossy().into_owned(),
+            error: error.to_string(),
+        });
+    }
+}
+
+/// The loader for a file with `extension`, if the format is supported.
+fn loader_for(file: &str, extension: &str) -> Option<Box<dyn BaseLoader>> {
+    let loader: Box<dyn BaseLoader> = match extension {
+        "txt" | "text" | "rst" | "log" => Box::new(TextLoader::new(file)),
+        "md" | "markdown" | "mdx" => Box::new(MarkdownLoader::new(file)),
+        "csv" => Box::new(CsvLoader::new(file)),
+        "tsv" => Box::new(CsvLoader::new(file).with_delimiter(b'\t')),
+        "json" => Box::new(JsonLoader::new(file)),
+        "pdf" => Box::new(PdfLoader::new(file)),
+        "docx" => Box::new(DocxLoader::new(file)),
+        "xml" | "rss" | "atom" => Box::new(XmlLoader::new(file)),
+        _ => return None,
+    };
+    Some(loader)
 }
 
 impl BaseLoader for DirectoryLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!(
-            "DirectoryLoader: not yet implemented - requires directory traversal and multi-format loading"
-        )
+        let report = self.load_report()?;
+        if self.strict && !report.errors.is_empty() {
+            let failures: Vec<String> = report
+                .errors
+                .iter()
+                .map(|e| format!("{}: {}", e.path, e.error))
+                .collect();
+            anyhow::bail!(
+                "DirectoryLoader: {} file(s) failed to load:\n{}",
+                failures.len(),
+                failures.join("\n")
+            );
+        }
+        for failure in &report.errors {
+            log::warn!("DirectoryLoader: skipping '{}': {}", failure.path, failure.error);
+        }
+        Ok(report.documents)
     }
 
     fn loader_name(&self) -> &str {
@@ -245,23 +737,119 @@ impl BaseLoader for DirectoryLoader {
 /// Load documents from DOCX (Microsoft Word) files.
 ///
 /// Corresponds to Python DOCX loader in `crewai_tools.rag`.
+///
+/// The body is rendered as Markdown: headings become `#` lines, list items
+/// `-` lines and tables Markdown tables, so chunkers keep their structure.
+/// By default the file is one document; with `split_by_heading` each
+/// top-level heading starts a new one whose `heading` metadata holds its
+/// text. Image content is not extracted, but `image_count` and `images`
+/// (media file names) record what each document contained.
 #[derive(Debug, Clone)]
 pub struct DocxLoader {
     /// Path to the DOCX file.
     pub file_path: String,
+    /// One document per top-level heading instead of one per file.
+    pub split_by_heading: bool,
 }
 
 impl DocxLoader {
     pub fn new(file_path: impl Into<String>) -> Self {
         Self {
             file_path: file_path.into(),
+            split_by_heading: false,
         }
     }
+
+    pub fn with_split_by_heading(mut self, split: bool) -> Self {
+        self.split_by_heading = split;
+        self
+    }
+}
+
+/// Body text gathered for one output document.
+#[derive(Default)]
+struct DocxSection {
+    heading: Option<(String, u32)>,
+    headings: Vec<String>,
+    blocks: Vec<String>,
+    images: Vec<String>,
 }
 
 impl BaseLoader for DocxLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("DocxLoader: not yet implemented - requires DOCX parsing library")
+        let parts = docx::read(&self.file_path)?;
+        let xml = roxmltree::Document::parse(&parts.document)
+            .map_err(|e| anyhow::anyhow!("Malformed DOCX '{}': {}", self.file_path, e))?;
+        let Some(body) = xml.descendants().find(|n| docx::is_element_named(n, "body")) else {
+            return Ok(Vec::new());
+        };
+        let blocks = docx::body_blocks(body);
+
+        // The shallowest `HeadingN` in use; a `Title` never splits.
+        let top_level = blocks
+            .iter()
+            .filter(|b| b.tag_name().name() == "p")
+            .filter_map(docx::heading_level)
+            .filter(|level| *level >= 1)
+            .min();
+
+        let mut sections = vec![DocxSection::default()];
+        for block in &blocks {
+            let rendered = if block.tag_name().name() == "tbl" {
+                docx::table_markdown(block)
+            } else {
+                let text = docx::paragraph_text(block).trim().to_string();
+                match docx::heading_level(block) {
+                    Some(_) if text.is_empty() => text,
+                    Some(level) => {
+                        if self.split_by_heading && Some(level) == top_level {
+                            sections.push(DocxSection {
+                                heading: Some((text.clone(), level)),
+                                ..DocxSection::default()
+                            });
+                        }
+                        let section = sections.last_mut().expect("sections is never empty");
+                        section.headings.push(text.clone());
+                        format!("{} {}", "#".repeat(level.clamp(1, 6) as usize), text)
+                    }
+                    None if !text.is_empty() && docx::is_list_item(block) => format!("- {}", text),
+                    None => text,
+                }
+            };
+            let section = sections.last_mut().expect("sections is never empty");
+            for id in docx::image_refs(block) {
+                let target = parts.relationships.get(&id).unwrap_or(&id);
+                let name = target.rsplit('/').next().unwrap_or(target);
+                section.images.push(name.to_string());
+            }
+            if !rendered.is_empty() {
+                section.blocks.push(rendered);
+            }
+        }
+
+        let mut documents = Vec::new();
+        for (index, section) in sections.into_iter().enumerate() {
+            if section.blocks.is_empty() && section.images.is_empty() {
+                continue;
+            }
+            let mut document = Document::new(section.blocks.join("\n\n"))
+                .with_metadata("source", Value::String(self.file_path.clone()))
+                .with_metadata("image_count", serde_json::json!(section.images.len()))
+                .with_metadata("images", serde_json::json!(section.images));
+            if self.split_by_heading {
+                document = document.with_metadata("section_index", serde_json::json!(index));
+                if let Some((heading, level)) = section.heading {
+                    document = document
+                        .with_metadata("heading", Value::String(heading))
+                        .with_metadata("heading_level", serde_json::json!(level));
+                }
+            } else {
+                document = document.with_metadata("headings", serde_json::json!(section.headings));
+            }
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
     }
 
     fn loader_name(&self) -> &str {
@@ -274,12 +862,20 @@ impl BaseLoader for DocxLoader {
 /// Load documents from XML files.
 ///
 /// Corresponds to Python XML loader in `crewai_tools.rag`.
+///
+/// With `content_xpath` each matching node becomes a document, with its
+/// `path` and `attributes` in metadata. Otherwise RSS and Atom feeds (when
+/// `detect_feeds` is on) give one document per `item`/`entry` with `title`,
+/// `link`, `description`, `published` and `author` in metadata, and any
+/// other file becomes a single document of its element text in order.
 #[derive(Debug, Clone)]
 pub struct XmlLoader {
     /// Path to the XML file.
     pub file_path: String,
     /// XPath expression for selecting content nodes.
     pub content_xpath: Option<String>,
+    /// Whether to split RSS/Atom feeds into one document per item.
+    pub detect_feeds: bool,
 }
 
 impl XmlLoader {
@@ -287,6 +883,7 @@ impl XmlLoader {
         Self {
             file_path: file_path.into(),
             content_xpath: None,
+            detect_feeds: true,
         }
     }
 
@@ -294,11 +891,174 @@ impl XmlLoader {
         self.content_xpath = Some(xpath.into());
         self
     }
+
+    pub fn with_detect_feeds(mut self, detect: bool) -> Self {
+        self.detect_feeds = detect;
+        self
+    }
+
+    /// One document per node selected by `expr`.
+    fn xpath_documents(
+        &self,
+        doc: &roxmltree::Document,
+        expr: &str,
+    ) -> Result<Vec<Document>, anyhow::Error> {
+        let mut documents = Vec::new();
+        for hit in xpath::select(doc, expr)? {
+            let (content, path, attributes) = match hit {
+                xpath::XPathHit::Element(node) => {
+                    let attributes: serde_json::Map<String, Value> = node
+                        .attributes()
+                        .map(|a| (a.name().to_string(), Value::String(a.value().to_string())))
+                        .collect();
+                    (xpath::text_content(&node), xpath::element_path(&node), attributes)
+                }
+                xpath::XPathHit::Attribute {
+                    element,
+                    name,
+                    value,
+                } => (
+                    value,
+                    format!("{}/@{}", xpath::element_path(&element), name),
+                    serde_json::Map::new(),
+                ),
+                xpath::XPathHit::Text(node) => {
+                    let parent = node.parent().map(|p| xpath::element_path(&p));
+                    (
+                        node.text().unwrap_or_default().to_string(),
+                        format!("{}/text()", parent.unwrap_or_default()),
+                        serde_json::Map::new(),
+                    )
+                }
+            };
+            let content = content.trim();
+            if content.is_empty() {
+                continue;
+            }
+            let mut document = Document::new(content)
+                .with_metadata("source", Value::String(self.file_path.clone()))
+                .with_metadata("path", Value::String(path))
+                .with_metadata("attributes", Value::Object(attributes));
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
+    }
+
+    /// One document per RSS `item` or Atom `entry`, or `None` when `root`
+    /// is not a feed.
+    fn feed_documents(&self, root: roxmltree::Node) -> Option<Vec<Document>> {
+        let (channel, item_name) = match root.tag_name().name() {
+            "rss" => (root.children().find(|n| is_element_named(n, "channel"))?, "item"),
+            // RSS 1.0 keeps items beside the channel.
+            "RDF" => (root, "item"),
+            "feed" => (root, "entry"),
+            _ => return None,
+        };
+        let feed_title = child_text(&channel, "title").or_else(|| {
+            let channel = root.children().find(|n| is_element_named(n, "channel"))?;
+            child_text(&channel, "title")
+        });
+
+        let mut documents = Vec::new();
+        for (index, item) in channel
+            .children()
+            .filter(|n| is_element_named(n, item_name))
+            .enumerate()
+        {
+            let title = child_text(&item, "title");
+            let link = feed_link(&item);
+            let description = ["description", "summary", "content", "encoded"]
+                .iter()
+                .find_map(|name| child_text(&item, name))
+                .map(|text| {
+                    if text.contains('<') {
+                        html_to_markdown(&text).trim().to_string()
+                    } else {
+                        text
+                    }
+                });
+            let content = [title.as_deref(), description.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            if content.is_empty() {
+                continue;
+            }
+
+            let mut document = Document::new(content)
+                .with_metadata("source", Value::String(self.file_path.clone()))
+                .with_metadata("item_index", serde_json::json!(index));
+            let published = ["pubDate", "published", "updated", "date"]
+                .iter()
+                .find_map(|name| child_text(&item, name));
+            let author = item
+                .children()
+                .find(|n| is_element_named(n, "author") || is_element_named(n, "creator"))
+                .map(|author| child_text(&author, "name").unwrap_or_else(|| xml_text(&author)))
+                .filter(|author| !author.is_empty());
+            let id = child_text(&item, "guid").or_else(|| child_text(&item, "id"));
+            let fields = [
+                ("title", title),
+                ("link", link),
+                ("description", description),
+                ("published", published),
+                ("author", author),
+                ("id", id),
+                ("feed_title", feed_title.clone()),
+            ];
+            for (key, value) in fields {
+                if let Some(value) = value {
+                    document = document.with_metadata(key, Value::String(value));
+                }
+            }
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Some(documents)
+    }
 }
 
 impl BaseLoader for XmlLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("XmlLoader: not yet implemented - requires XML parsing library")
+        let text = std::fs::read_to_string(&self.file_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", self.file_path, e))?;
+        let doc = roxmltree::Document::parse_with_options(
+            &text,
+            roxmltree::ParsingOptions {
+                allow_dtd: true,
+                ..Default::default()
+            },
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to parse XML '{}': {}", self.file_path, e))?;
+
+        if let Some(expr) = &self.content_xpath {
+            return self.xpath_documents(&doc, expr);
+        }
+        let root = doc.root_element();
+        if self.detect_feeds {
+            if let Some(documents) = self.feed_documents(root) {
+                return Ok(documents);
+            }
+        }
+
+        let content = root
+            .descendants()
+            .filter(|n| n.is_text())
+            .filter_map(|n| n.text())
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if content.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut document = Document::new(content)
+            .with_metadata("source", Value::String(self.file_path.clone()))
+            .with_metadata("root", Value::String(root.tag_name().name().to_string()));
+        stamp_language(&mut document.metadata, &document.content);
+        Ok(vec![document])
     }
 
     fn loader_name(&self) -> &str {
@@ -308,9 +1068,59 @@ impl BaseLoader for XmlLoader {
 
 // ── GithubLoader ─────────────────────────────────────────────────────────────
 
+/// Errors from [`GithubLoader`] that callers may want to handle.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast::<GithubLoaderError>()`
+/// to recover the documents loaded before a rate limit.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GithubLoaderError {
+    /// The repository or branch does not exist, or is private and no token
+    /// was given.
+    #[error(
+        "GitHub repository '{repository}' or branch '{branch}' not found (private repositories \
+         need github_token or GITHUB_TOKEN)"
+    )]
+    NotFound { repository: String, branch: String },
+    /// The rate limit ran out mid-load. `documents` holds the files loaded
+    /// before it did; `skipped` counts the files not fetched.
+    #[error(
+        "GitHub rate limit reached after loading {} file(s); {skipped} file(s) were skipped{}",
+        .documents.len(),
+        resets_at(.reset)
+    )]
+    RateLimited {
+        documents: Vec<Document>,
+        skipped: usize,
+        /// Unix time at which the limit resets.
+        reset: Option<u64>,
+    },
+}
+
+fn resets_at(reset: &Option<u64>) -> String {
+    match reset {
+        Some(reset) => format!(" (the limit resets at unix time {})", reset),
+        None => String::new(),
+    }
+}
+
+/// Extensions of files that are never text.
+const BINARY_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "webp", "ico", "bmp", "pdf", "zip", "gz", "tgz", "bz2", "xz",
+    "7z", "jar", "exe", "dll", "so", "dylib", "a", "o", "class", "wasm", "woff", "woff2", "ttf",
+    "otf", "mp3", "mp4", "mov", "avi", "bin",
+];
+
 /// Load documents from a GitHub repository.
 ///
 /// Corresponds to Python GitHub loader in `crewai_tools.rag`.
+///
+/// Lists the branch with the git trees API and fetches each matching file
+/// as a raw blob, one document per file with `path`, `sha` and `size` in
+/// metadata. Binary files and files over `max_file_size` are skipped, and
+/// at most `max_files` are fetched. A token (`github_token` or
+/// `GITHUB_TOKEN`) is needed for private repositories and raises the rate
+/// limit; if the limit still runs out, the error is a
+/// [`GithubLoaderError::RateLimited`] carrying the files loaded so far.
 #[derive(Debug, Clone)]
 pub struct GithubLoader {
     /// Repository in "owner/repo" format.
@@ -321,6 +1131,12 @@ pub struct GithubLoader {
     pub branch: String,
     /// File extensions to include.
     pub extensions: Vec<String>,
+    /// Most files to fetch.
+    pub max_files: usize,
+    /// Largest file to fetch, in bytes.
+    pub max_file_size: u64,
+    /// GitHub API base URL.
+    pub api_url: String,
 }
 
 impl GithubLoader {
@@ -330,6 +1146,9 @@ impl GithubLoader {
             github_token: None,
             branch: "main".to_string(),
             extensions: Vec::new(),
+            max_files: 500,
+            max_file_size: 1024 * 1024,
+            api_url: "https://api.github.com".to_string(),
         }
     }
 
@@ -347,11 +1166,168 @@ impl GithubLoader {
         self.extensions = exts;
         self
     }
+
+    pub fn with_max_files(mut self, max: usize) -> Self {
+        self.max_files = max;
+        self
+    }
+
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    pub fn with_api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = url.into();
+        self
+    }
+}
+
+/// A GitHub response, or the rate limit that refused it.
+enum GithubReply {
+    Ok(reqwest::blocking::Response),
+    RateLimited { reset: Option<u64> },
+}
+
+impl GithubLoader {
+    fn get(
+        &self,
+        client: &reqwest::blocking::Client,
+        token: Option<&str>,
+        path: &str,
+        accept: &str,
+    ) -> Result<GithubReply, anyhow::Error> {
+        let mut request = client
+            .get(format!("{}{}", self.api_url.trim_end_matches('/'), path))
+            .header("Accept", accept)
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send()?;
+        let header = |name: &str| response.headers().get(name).and_then(|v| v.to_str().ok());
+        let limited = matches!(response.status().as_u16(), 403 | 429)
+            && (header("x-ratelimit-remaining") == Some("0") || header("retry-after").is_some());
+        if limited {
+            let reset = header("x-ratelimit-reset").and_then(|v| v.parse().ok());
+            return Ok(GithubReply::RateLimited { reset });
+        }
+        if response.status().as_u16() == 404 {
+            return Err(GithubLoaderError::NotFound {
+                repository: self.repository.clone(),
+                branch: self.branch.clone(),
+            }
+            .into());
+        }
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            anyhow::bail!("GitHub API error ({}): {}", status, body);
+        }
+        Ok(GithubReply::Ok(response))
+    }
 }
 
 impl BaseLoader for GithubLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!("GithubLoader: not yet implemented - requires GitHub API integration")
+        let token = self
+            .github_token
+            .clone()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok());
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .user_agent("crewai-tools")
+            .build()?;
+        let wanted: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|e| e.trim_start_matches('.').to_ascii_lowercase())
+            .collect();
+
+        let tree_path = format!("/repos/{}/git/trees/{}?recursive=1", self.repository, self.branch);
+        let tree: Value =
+            match self.get(&client, token.as_deref(), &tree_path, "application/vnd.github+json")? {
+                GithubReply::Ok(response) => response.json()?,
+                GithubReply::RateLimited { reset } => {
+                    return Err(GithubLoaderError::RateLimited {
+                        documents: Vec::new(),
+                        skipped: 0,
+                        reset,
+                    }
+                    .into())
+                }
+            };
+        if tree["truncated"] == Value::Bool(true) {
+            log::warn!(
+                "GithubLoader: the tree of '{}' is too large to list in full; some files \
+                 are missing",
+                self.repository
+            );
+        }
+
+        let files: Vec<&Value> = tree["tree"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|entry| entry["type"] == "blob")
+            .filter(|entry| {
+                let path = entry["path"].as_str().unwrap_or_default();
+                let extension = path
+                    .rsplit_once('.')
+                    .map(|(_, ext)| ext.to_ascii_lowercase())
+                    .unwrap_or_default();
+                !BINARY_EXTENSIONS.contains(&extension.as_str())
+                    && (wanted.is_empty() || wanted.contains(&extension))
+            })
+            .filter(|entry| entry["size"].as_u64().unwrap_or(0) <= self.max_file_size)
+            .take(self.max_files)
+            .collect();
+
+        let mut documents = Vec::new();
+        for (i, file) in files.iter().enumerate() {
+            let path = file["path"].as_str().unwrap_or_default();
+            let sha = file["sha"].as_str().unwrap_or_default();
+            let blob_path = format!("/repos/{}/git/blobs/{}", self.repository, sha);
+            let raw = "application/vnd.github.raw";
+            let bytes = match self.get(&client, token.as_deref(), &blob_path, raw)? {
+                GithubReply::Ok(response) => response.bytes()?,
+                GithubReply::RateLimited { reset } => {
+                    return Err(GithubLoaderError::RateLimited {
+                        documents,
+                        skipped: files.len() - i,
+                        reset,
+                    }
+                    .into())
+                }
+            };
+            // Binary content that slipped past the extension check.
+            let text = match std::str::from_utf8(&bytes) {
+                Ok(text) if !text.contains('\0') => text,
+                _ => {
+                    log::debug!("GithubLoader: skipping binary file '{}'", path);
+                    continue;
+                }
+            };
+            if text.trim().is_empty() {
+                continue;
+            }
+            let mut document = Document::new(text)
+                .with_metadata(
+                    "source",
+                    Value::String(format!(
+                        "https://github.com/{}/blob/{}/{}",
+                        self.repository, self.branch, path
+                    )),
+                )
+                .with_metadata("repository", Value::String(self.repository.clone()))
+                .with_metadata("branch", Value::String(self.branch.clone()))
+                .with_metadata("path", Value::String(path.to_string()))
+                .with_metadata("sha", Value::String(sha.to_string()))
+                .with_metadata("size", serde_json::json!(bytes.len()));
+            stamp_language(&mut document.metadata, &document.content);
+            documents.push(document);
+        }
+        Ok(documents)
     }
 
     fn loader_name(&self) -> &str {
@@ -359,10 +1335,258 @@ impl BaseLoader for GithubLoader {
     }
 }
 
+// ── S3Loader ─────────────────────────────────────────────────────────────────
+
+/// Load documents from the objects under an S3 prefix.
+///
+/// Corresponds to Python S3 loader in `crewai_tools.rag`.
+///
+/// Every object under `prefix` whose extension has a loader (the same
+/// dispatch as [`DirectoryLoader`]: text, Markdown, CSV, JSON, PDF, DOCX,
+/// XML) is streamed to a temporary file, loaded, and tagged with its
+/// `s3://bucket/key` URI as `source` plus `bucket`, `key` and `size`
+/// metadata. Up to `max_concurrency` objects download at once; objects over
+/// `max_object_size` are skipped. Credentials follow the standard AWS
+/// provider chain (environment, shared credentials profile, EC2 instance
+/// role), overridden by the key fields. S3 errors such as access denied
+/// fail the load; an object that downloads but does not parse is logged
+/// and skipped.
+#[cfg(feature = "cloud_storage")]
+#[derive(Clone)]
+pub struct S3Loader {
+    /// S3 bucket name.
+    pub bucket: String,
+    /// Key prefix to load from ("" for the whole bucket).
+    pub prefix: String,
+    /// AWS region.
+    pub region: Option<String>,
+    /// AWS access key ID (prefer environment variables or IAM roles).
+    pub access_key_id: Option<String>,
+    /// AWS secret access key (prefer environment variables or IAM roles).
+    pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials.
+    pub session_token: Option<String>,
+    /// S3-compatible endpoint (MinIO, LocalStack); uses path-style URLs.
+    pub endpoint_url: Option<String>,
+    /// Most objects downloaded at once.
+    pub max_concurrency: usize,
+    /// Largest object loaded, in bytes.
+    pub max_object_size: u64,
+}
+
+#[cfg(feature = "cloud_storage")]
+impl std::fmt::Debug for S3Loader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3Loader")
+            .field("bucket", &self.bucket)
+            .field("prefix", &self.prefix)
+            .field("region", &self.region)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| "<redacted>"))
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_concurrency", &self.max_concurrency)
+            .field("max_object_size", &self.max_object_size)
+            .finish()
+    }
+}
+
+#[cfg(feature = "cloud_storage")]
+impl S3Loader {
+    pub fn new(bucket: impl Into<String>, prefix: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            prefix: prefix.into(),
+            region: None,
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+            endpoint_url: None,
+            max_concurrency: 4,
+            max_object_size: 50 * 1024 * 1024,
+        }
+    }
+
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn with_access_key_id(mut self, key: impl Into<String>) -> Self {
+        self.access_key_id = Some(key.into());
+        self
+    }
+
+    pub fn with_secret_access_key(mut self, key: impl Into<String>) -> Self {
+        self.secret_access_key = Some(key.into());
+        self
+    }
+
+    pub fn with_session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_concurrency(mut self, n: usize) -> Self {
+        self.max_concurrency = n;
+        self
+    }
+
+    pub fn with_max_object_size(mut self, bytes: u64) -> Self {
+        self.max_object_size = bytes;
+        self
+    }
+
+    /// Download `key` into `dir` and load it with the loader for `extension`.
+    fn load_object(
+        &self,
+        s3: &S3,
+        dir: &std::path::Path,
+        index: usize,
+        key: &str,
+        extension: &str,
+    ) -> Result<Vec<Document>, anyhow::Error> {
+        use std::io::Read;
+
+        let uri = format!("s3://{}/{}", self.bucket, key);
+        let response = s3.send(reqwest::Method::GET, key, Vec::new(), Vec::new(), Vec::new())?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().unwrap_or_default();
+            return Err(s3.failure("read", key, status, &body));
+        }
+        let path = dir.join(format!("{}.{}", index, extension));
+        let mut file = std::fs::File::create(&path)?;
+        let size = std::io::copy(&mut response.take(self.max_object_size + 1), &mut file)?;
+        drop(file);
+        if size > self.max_object_size {
+            log::warn!("S3Loader: skipping {}: over max_object_size", uri);
+            return Ok(Vec::new());
+        }
+
+        let file = path.to_string_lossy();
+        let Some(loader) = loader_for(&file, extension) else {
+            return Ok(Vec::new());
+        };
+        let mut documents = match loader.load() {
+            Ok(documents) => documents,
+            Err(e) => {
+                log::warn!("S3Loader: skipping {}: {}", uri, e);
+                return Ok(Vec::new());
+            }
+        };
+        for document in &mut documents {
+            document.metadata.insert("source".to_string(), Value::String(uri.clone()));
+            document.metadata.insert("bucket".to_string(), Value::String(self.bucket.clone()));
+            document.metadata.insert("key".to_string(), Value::String(key.to_string()));
+            document.metadata.insert("size".to_string(), serde_json::json!(size));
+        }
+        Ok(documents)
+    }
+}
+
+#[cfg(feature = "cloud_storage")]
+impl BaseLoader for S3Loader {
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Mutex;
+
+        let s3 = S3::connect(
+            self.bucket.clone(),
+            self.region.as_deref(),
+            self.access_key_id.as_deref(),
+            self.secret_access_key.as_deref(),
+            self.session_token.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        let listing = s3.list_objects(&self.prefix, None, u64::MAX, None)?;
+
+        // (key, extension) of every object with a loader, in listing order.
+        let mut objects = Vec::new();
+        for object in listing["objects"].as_array().into_iter().flatten() {
+            let Some(key) = object["key"].as_str() else {
+                continue;
+            };
+            let name = key.rsplit('/').next().unwrap_or(key);
+            let extension = match name.rsplit_once('.') {
+                Some((_, ext)) => ext.to_ascii_lowercase(),
+                None => continue,
+            };
+            if loader_for(key, &extension).is_none() {
+                continue;
+            }
+            if object["size"].as_u64().is_some_and(|size| size > self.max_object_size) {
+                log::warn!(
+                    "S3Loader: skipping s3://{}/{}: over max_object_size ({} bytes)",
+                    self.bucket,
+                    key,
+                    self.max_object_size
+                );
+                continue;
+            }
+            objects.push((key.to_string(), extension));
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "crewai-s3loader-{}-{}",
+            std::process::id(),
+            NEXT_S3_DOWNLOAD.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+        let next = AtomicUsize::new(0);
+        let results = Mutex::new(Vec::new());
+        std::thread::scope(|scope| {
+            for _ in 0..self.max_concurrency.max(1).min(objects.len()) {
+                scope.spawn(|| loop {
+                    let i = next.fetch_add(1, Ordering::SeqCst);
+                    let Some((key, extension)) = objects.get(i) else {
+                        break;
+                    };
+                    let loaded = self.load_object(&s3, &dir, i, key, extension);
+                    results.lock().expect("results lock").push((i, loaded));
+                });
+            }
+        });
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut results = results.into_inner().expect("results lock");
+        results.sort_by_key(|(i, _)| *i);
+        let mut documents = Vec::new();
+        for (_, loaded) in results {
+            documents.extend(loaded?);
+        }
+        Ok(documents)
+    }
+
+    fn loader_name(&self) -> &str {
+        "S3Loader"
+    }
+}
+
+/// Distinguishes the temp directories of concurrent `S3Loader` loads.
+#[cfg(feature = "cloud_storage")]
+static NEXT_S3_DOWNLOAD: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
 // ── YoutubeVideoLoader ───────────────────────────────────────────────────────
 
 /// Load transcripts from YouTube videos.
 ///
+/// Produces one document per chapter when the video has chapters (from the
+/// player's chapter markers or timestamp lines in the description), with the
+/// chapter title in `chapter` metadata. Videos without chapters are split
+/// into `window_seconds`-long documents instead. Every document carries its
+/// start and end timestamps and the video's title and channel.
+///
+/// The transcript is taken from creator captions in `language`, then
+/// auto-generated captions in `language`, then any track; a fallback is
+/// recorded in `transcript_note` metadata. Videos with no transcript at all
+/// fail with [`NoTranscript`](crate::NoTranscript) so ingestion can skip them.
+///
 /// Corresponds to Python YouTube video loader in `crewai_tools.rag`.
 #[derive(Debug, Clone)]
 pub struct YoutubeVideoLoader {
@@ -370,6 +1594,10 @@ pub struct YoutubeVideoLoader {
     pub video_url: String,
     /// Preferred language for transcripts.
     pub language: String,
+    /// Section length used when the video has no chapters.
+    pub window_seconds: u32,
+    /// YouTube base URL.
+    pub base_url: String,
 }
 
 impl YoutubeVideoLoader {
@@ -377,6 +1605,8 @@ impl YoutubeVideoLoader {
         Self {
             video_url: video_url.into(),
             language: "en".to_string(),
+            window_seconds: 60,
+            base_url: youtube::YOUTUBE_URL.to_string(),
         }
     }
 
@@ -384,16 +1614,171 @@ impl YoutubeVideoLoader {
         self.language = lang.into();
         self
     }
+
+    pub fn with_window_seconds(mut self, seconds: u32) -> Self {
+        self.window_seconds = seconds;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
 }
 
 impl BaseLoader for YoutubeVideoLoader {
     fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
-        anyhow::bail!(
-            "YoutubeVideoLoader: not yet implemented - requires YouTube transcript API integration"
-        )
+        let video =
+            youtube::fetch_transcript_from(&self.base_url, &self.video_url, &self.language)?;
+        let segmentation = if video.chapters.is_empty() { "window" } else { "chapter" };
+        let note = if !youtube::language_matches(&video.language, &self.language) {
+            Some(format!(
+                "No '{}' transcript; using '{}' captions",
+                self.language, video.language
+            ))
+        } else if video.auto_generated {
+            Some(format!("No creator '{}' captions; using auto-generated ones", self.language))
+        } else {
+            None
+        };
+
+        let documents = youtube::sections(&video, self.window_seconds)
+            .into_iter()
+            .map(|section| {
+                let mut doc = Document::new(section.text.clone())
+                    .with_metadata(
+                        "source",
+                        Value::String(youtube::deep_link(&video.video_id, section.start)),
+                    )
+                    .with_metadata("video_id", Value::String(video.video_id.clone()))
+                    .with_metadata("title", Value::String(video.title.clone()))
+                    .with_metadata("channel", Value::String(video.channel.clone()))
+                    .with_metadata("start_seconds", Value::from(section.start))
+                    .with_metadata("end_seconds", Value::from(section.end))
+                    .with_metadata(
+                        "timestamp",
+                        Value::String(youtube::format_timestamp(section.start)),
+                    )
+                    .with_metadata(
+                        "end_timestamp",
+                        Value::String(youtube::format_timestamp(section.end)),
+                    )
+                    .with_metadata("citation", Value::String(section.citation()))
+                    .with_metadata("segmentation", Value::String(segmentation.to_string()))
+                    .with_metadata("transcript_language", Value::String(video.language.clone()))
+                    .with_metadata("auto_generated", Value::Bool(video.auto_generated));
+                if let Some(chapter) = &section.chapter {
+                    doc = doc.with_metadata("chapter", Value::String(chapter.clone()));
+                }
+                if let Some(note) = &note {
+                    doc = doc.with_metadata("transcript_note", Value::String(note.clone()));
+                }
+                stamp_language(&mut doc.metadata, &doc.content);
+                doc
+            })
+            .collect();
+        Ok(documents)
     }
 
     fn loader_name(&self) -> &str {
         "YoutubeVideoLoader"
     }
 }
+
+// ── Helpers ──────────────────────────────────────────────────────────────────
+
+/// Top-level keys of a YAML frontmatter block. Frontmatter that is not a
+/// mapping, or does not parse, is logged and ignored.
+fn frontmatter_metadata(lines: &[&str], path: &str) -> serde_json::Map<String, Value> {
+    match serde_yaml::from_str::<Value>(&lines.join("\n")) {
+        Ok(Value::Object(map)) => map,
+        Ok(Value::Null) => serde_json::Map::new(),
+        Ok(_) => {
+            log::warn!("MarkdownLoader: frontmatter in '{}' is not a mapping; ignoring it", path);
+            serde_json::Map::new()
+        }
+        Err(e) => {
+            log::warn!("MarkdownLoader: malformed frontmatter in '{}': {}", path, e);
+            serde_json::Map::new()
+        }
+    }
+}
+
+/// ATX headings outside code fences, as `(line index, level, title)`.
+fn markdown_headings(lines: &[&str]) -> Vec<(usize, usize, String)> {
+    let mut headings = Vec::new();
+    let mut fence: Option<&str> = None;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+        } else if let Some(marker) = markdown::code_fence(trimmed) {
+            fence = Some(marker);
+        } else if line.len() - trimmed.len() < 4 {
+            // Four spaces of indentation make an indented code block.
+            if let Some((level, title)) = markdown::atx_heading(trimmed) {
+                headings.push((i, level, title));
+            }
+        }
+    }
+    headings
+}
+
+/// The contents of the first `<main>` or `<article>` element, if any.
+fn main_content(html: &str) -> Option<&str> {
+    let lower = html.to_ascii_lowercase();
+    for tag in ["main", "article"] {
+        let Some(open) = lower.find(&format!("<{}", tag)) else {
+            continue;
+        };
+        let Some(body_start) = lower[open..].find('>').map(|i| open + i + 1) else {
+            continue;
+        };
+        if let Some(close) = lower[body_start..].rfind(&format!("</{}>", tag)) {
+            return Some(&html[body_start..body_start + close]);
+        }
+    }
+    None
+}
+
+/// The text of the `<title>` element, if any.
+fn html_title(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let open = lower.find("<title")?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find("</title>")?;
+    let title = super::html::decode_entities(html[start..end].trim());
+    (!title.is_empty()).then_some(title)
+}
+
+/// Whether `n` is an element with local name `name`, ignoring its namespace.
+fn is_element_named(n: &roxmltree::Node, name: &str) -> bool {
+    n.is_element() && n.tag_name().name() == name
+}
+
+/// Trimmed text of an element, including CDATA and nested markup.
+fn xml_text(node: &roxmltree::Node) -> String {
+    xpath::text_content(node).trim().to_string()
+}
+
+/// Text of the first child element named `name`, if it has any.
+fn child_text(node: &roxmltree::Node, name: &str) -> Option<String> {
+    let text = xml_text(&node.children().find(|n| is_element_named(n, name))?);
+    (!text.is_empty()).then_some(text)
+}
+
+/// A feed item's link: RSS `<link>` text, or the Atom `<link>` whose `rel`
+/// is `alternate` (or absent).
+fn feed_link(item: &roxmltree::Node) -> Option<String> {
+    let links: Vec<roxmltree::Node> =
+        item.children().filter(|n| is_element_named(n, "link")).collect();
+    links
+        .iter()
+        .find(|l| l.has_attribute("href") && matches!(l.attribute("rel"), None | Some("alternate")))
+        .or_else(|| links.iter().find(|l| l.has_attribute("href")))
+        .and_then(|l| l.attribute("href"))
+        .map(str::to_string)
+        .or_else(|| links.first().map(xml_text).filter(|t| !t.is_empty()))
+}
@@ -3,9 +3,25 @@
 //! This module provides the building blocks for RAG pipelines:
 //!
 //! - **core** - Base traits for loaders, chunkers, and embedding services
+//!   (re-exported from the always-available [`crate::core`])
 //! - **loaders** - Concrete document loaders for various file formats and sources
 //! - **chunkers** - Text chunking strategies for splitting documents into segments
+//! - **embeddings** - Embedding services for hosted and local models
+//! - **filter** - Query-time metadata and source filters
+//! - **html** - HTML to Markdown conversion that keeps code blocks intact
+//!   (re-exported from the shared [`crate::html`])
+//! - **index** - In-memory vector index and vector store
+//! - **language** - Cheap language detection stamped into document metadata
+//! - **pipeline** - End-to-end load/chunk/embed/store/retrieve pipeline
 
 pub mod chunkers;
-pub mod core;
+pub use crate::core;
+pub mod embeddings;
+pub mod filter;
+pub use crate::html;
+pub mod index;
+pub mod language;
 pub mod loaders;
+pub mod pipeline;
+
+pub use pipeline::RagPipeline;
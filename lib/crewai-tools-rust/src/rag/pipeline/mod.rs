@@ -0,0 +1,427 @@
+//! End-to-end RAG pipeline: load, chunk, embed, store, retrieve.
+//!
+//! Corresponds to the Python RAG client used by `RagTool` in `crewai_tools`.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::core::{
+    content_id, BaseChunker, BaseLoader, Chunk, Document, EmbeddingService, VectorStore,
+};
+use super::filter::QueryFilter;
+use super::index::{InMemoryVectorStore, SearchHit};
+use super::language::language_confidence;
+use crate::builder::{Missing, Set};
+
+/// What to do with chunks whose detected-language confidence falls below
+/// the pipeline's threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LowConfidencePolicy {
+    /// Index them like any other chunk.
+    Keep,
+    /// Drop them.
+    Skip,
+    /// Put them in a separate index, queried only on request.
+    SeparateIndex,
+}
+
+/// The result of a pipeline query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryResult {
+    /// The query text.
+    pub query: String,
+    /// Ranked hits, best first.
+    pub hits: Vec<SearchHit>,
+    /// The filter the query was scoped with, echoed back so an empty result
+    /// can be traced to it.
+    pub filter: Option<QueryFilter>,
+}
+
+/// Counts and timings from one [`RagPipeline::ingest`] run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IngestSummary {
+    /// Documents loaded.
+    pub documents: usize,
+    /// Chunks the documents were split into.
+    pub chunks: usize,
+    /// Chunks embedded and added to the store.
+    pub stored: usize,
+    /// Chunks left out because the store already held their content.
+    pub skipped: usize,
+    /// Chunks below the language threshold, dropped or set aside.
+    pub low_confidence: usize,
+    /// Time spent in the loaders.
+    pub load_time: Duration,
+    /// Time spent in the chunker.
+    pub chunk_time: Duration,
+    /// Time spent in the embedding service.
+    pub embed_time: Duration,
+    /// Time spent writing to the store.
+    pub store_time: Duration,
+    /// Wall time of the whole run.
+    pub total_time: Duration,
+}
+
+/// A RAG pipeline: loaders feed a chunker, chunks are embedded in batches
+/// and written to a [`VectorStore`], and queries are answered from it.
+///
+/// Chunks are identified by a hash of their content, so ingesting again
+/// only embeds chunks the store does not already hold.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use std::sync::Arc;
+/// # fn demo(embedder: Arc<dyn crewai_tools::EmbeddingService>) -> anyhow::Result<()> {
+/// use crewai_tools::{DirectoryLoader, RagPipeline, TokenChunker};
+///
+/// let pipeline = RagPipeline::builder()
+///     .loader(DirectoryLoader::new("docs"))
+///     .chunker(TokenChunker::new())
+///     .embedder(embedder)
+///     .build();
+/// let summary = pipeline.ingest()?;
+/// let hits = pipeline.retrieve("How do I install it?", 5)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RagPipeline {
+    loaders: Vec<Box<dyn BaseLoader>>,
+    chunker: Box<dyn BaseChunker>,
+    embedder: Arc<dyn EmbeddingService>,
+    store: Arc<dyn VectorStore>,
+    /// Chunks routed here by `LowConfidencePolicy::SeparateIndex`.
+    low_confidence_store: InMemoryVectorStore,
+    /// Minimum `language_confidence` and what to do with chunks below it.
+    language_threshold: Option<(f32, LowConfidencePolicy)>,
+    /// Number of hits returned when the caller does not specify one.
+    pub top_k: usize,
+    /// Most chunks per `embed_batch` call.
+    pub batch_size: usize,
+}
+
+impl fmt::Debug for RagPipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let loaders: Vec<&str> = self.loaders.iter().map(|l| l.loader_name()).collect();
+        f.debug_struct("RagPipeline")
+            .field("loaders", &loaders)
+            .field("chunker", &self.chunker.chunker_name())
+            .field("embedder", &self.embedder.model_name())
+            .field("store", &self.store.store_name())
+            .field("top_k", &self.top_k)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl RagPipeline {
+    /// A pipeline with no loaders over a fresh [`InMemoryVectorStore`];
+    /// feed it with [`add_documents`](Self::add_documents).
+    pub fn new(chunker: Box<dyn BaseChunker>, embedder: Arc<dyn EmbeddingService>) -> Self {
+        Self {
+            loaders: Vec::new(),
+            chunker,
+            embedder,
+            store: Arc::new(InMemoryVectorStore::new()),
+            low_confidence_store: InMemoryVectorStore::new(),
+            language_threshold: None,
+            top_k: 5,
+            batch_size: 64,
+        }
+    }
+
+    /// Start a builder; `build()` is available once the chunker and the
+    /// embedder are set.
+    pub fn builder() -> RagPipelineBuilder<Missing, Missing> {
+        RagPipelineBuilder {
+            chunker: Missing,
+            embedder: Missing,
+            loaders: Vec::new(),
+            store: None,
+            language_threshold: None,
+            top_k: 5,
+            batch_size: 64,
+        }
+    }
+
+    pub fn with_top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    pub fn with_batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size;
+        self
+    }
+
+    /// Store chunks in `store` instead of the in-memory default.
+    pub fn with_store(mut self, store: Arc<dyn VectorStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Route chunks whose language confidence is below `min_confidence`
+    /// according to `policy`. Chunks with no detected language count as
+    /// confidence 0.
+    pub fn with_language_threshold(mut self, min_confidence: f32, policy: LowConfidencePolicy) -> Self {
+        self.language_threshold = Some((min_confidence, policy));
+        self
+    }
+
+    /// The store chunks are written to and queried from.
+    pub fn store(&self) -> &Arc<dyn VectorStore> {
+        &self.store
+    }
+
+    /// Chunks set aside by `LowConfidencePolicy::SeparateIndex`.
+    pub fn low_confidence_store(&self) -> &InMemoryVectorStore {
+        &self.low_confidence_store
+    }
+
+    /// Load every loader's documents, then chunk, embed and store them.
+    ///
+    /// Chunks whose content the store already holds are skipped without
+    /// being embedded, so re-ingesting a mostly unchanged corpus is cheap.
+    pub fn ingest(&self) -> Result<IngestSummary, anyhow::Error> {
+        let started = Instant::now();
+        let mut summary = IngestSummary::default();
+        let mut documents = Vec::new();
+        for loader in &self.loaders {
+            let loaded = loader
+                .load()
+                .map_err(|e| anyhow::anyhow!("{} failed: {}", loader.loader_name(), e))?;
+            documents.extend(loaded);
+        }
+        summary.load_time = started.elapsed();
+        summary.documents = documents.len();
+        self.store_documents(&documents, &mut summary)?;
+        summary.total_time = started.elapsed();
+        Ok(summary)
+    }
+
+    /// Chunk, embed and store `documents`. Returns the number of chunks added
+    /// to the main store.
+    pub fn add_documents(&self, documents: &[Document]) -> Result<usize, anyhow::Error> {
+        let mut summary = IngestSummary {
+            documents: documents.len(),
+            ..Default::default()
+        };
+        self.store_documents(documents, &mut summary)?;
+        Ok(summary.stored)
+    }
+
+    /// The `top_k` chunks most relevant to `query`, best first, each with
+    /// its score and metadata (including `source`).
+    pub fn retrieve(&self, query: &str, top_k: usize) -> Result<Vec<SearchHit>, anyhow::Error> {
+        let embedding = self.embedder.embed(query)?;
+        self.store.search(&embedding, top_k, None)
+    }
+
+    /// Retrieve the chunks most relevant to `query`.
+    ///
+    /// `top_k` defaults to the pipeline's `top_k`. A filter that matches no
+    /// chunks returns an empty hit list, not an error.
+    pub fn query(
+        &self,
+        query: &str,
+        top_k: Option<usize>,
+        filter: Option<&QueryFilter>,
+    ) -> Result<QueryResult, anyhow::Error> {
+        let embedding = self.embedder.embed(query)?;
+        let hits = self
+            .store
+            .search(&embedding, top_k.unwrap_or(self.top_k), filter)?;
+        Ok(QueryResult {
+            query: query.to_string(),
+            hits,
+            filter: filter.cloned(),
+        })
+    }
+
+    fn store_documents(
+        &self,
+        documents: &[Document],
+        summary: &mut IngestSummary,
+    ) -> Result<(), anyhow::Error> {
+        let started = Instant::now();
+        let mut chunks = Vec::new();
+        for document in documents {
+            chunks.extend(self.chunker.chunk(document)?);
+        }
+        summary.chunk_time += started.elapsed();
+        summary.chunks += chunks.len();
+
+        let mut set_aside = Vec::new();
+        if let Some((min, policy)) = self.language_threshold {
+            if policy != LowConfidencePolicy::Keep {
+                let (keep, low): (Vec<_>, Vec<_>) = chunks
+                    .into_iter()
+                    .partition(|c| language_confidence(&c.metadata).unwrap_or(0.0) >= min);
+                chunks = keep;
+                summary.low_confidence += low.len();
+                if policy == LowConfidencePolicy::SeparateIndex {
+                    set_aside = low;
+                }
+            }
+        }
+        self.embed_into(&self.low_confidence_store, set_aside, summary)?;
+
+        // Skip content the store already holds, or that repeats earlier in
+        // this batch.
+        let ids: Vec<String> = chunks.iter().map(|c| content_id(&c.content)).collect();
+        let stored = self.store.contains(&ids)?;
+        let mut seen = HashSet::new();
+        let before = chunks.len();
+        let mut stored = stored.into_iter();
+        let mut ids = ids.into_iter();
+        chunks.retain(|_| {
+            let (present, id) = (stored.next().unwrap_or(false), ids.next());
+            !present && seen.insert(id)
+        });
+        summary.skipped += before - chunks.len();
+
+        let stored = self.embed_into(self.store.as_ref(), chunks, summary)?;
+        summary.stored += stored;
+        Ok(())
+    }
+
+    /// Embed `chunks` in batches and add them to `store`, returning how many
+    /// were added.
+    fn embed_into(
+        &self,
+        store: &dyn VectorStore,
+        chunks: Vec<Chunk>,
+        summary: &mut IngestSummary,
+    ) -> Result<usize, anyhow::Error> {
+        let total = chunks.len();
+        let mut chunks = chunks.into_iter().peekable();
+        while chunks.peek().is_some() {
+            let batch: Vec<Chunk> = chunks.by_ref().take(self.batch_size.max(1)).collect();
+            let texts: Vec<&str> = batch.iter().map(|c| c.content.as_str()).collect();
+            let started = Instant::now();
+            let embeddings = self.embedder.embed_batch(&texts)?;
+            summary.embed_time += started.elapsed();
+            if embeddings.len() != batch.len() {
+                anyhow::bail!(
+                    "Embedding service '{}' returned {} vectors for {} chunks",
+                    self.embedder.model_name(),
+                    embeddings.len(),
+                    batch.len()
+                );
+            }
+            let started = Instant::now();
+            store.add(batch, embeddings)?;
+            summary.store_time += started.elapsed();
+        }
+        Ok(total)
+    }
+}
+
+/// Builder for [`RagPipeline`]; `C` and `E` track whether the chunker and
+/// the embedder have been supplied.
+pub struct RagPipelineBuilder<C, E> {
+    chunker: C,
+    embedder: E,
+    loaders: Vec<Box<dyn BaseLoader>>,
+    store: Option<Arc<dyn VectorStore>>,
+    language_threshold: Option<(f32, LowConfidencePolicy)>,
+    top_k: usize,
+    batch_size: usize,
+}
+
+impl<C, E> fmt::Debug for RagPipelineBuilder<C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let loaders: Vec<&str> = self.loaders.iter().map(|l| l.loader_name()).collect();
+        f.debug_struct("RagPipelineBuilder")
+            .field("loaders", &loaders)
+            .field("store", &self.store.as_ref().map(|s| s.store_name().to_string()))
+            .field("top_k", &self.top_k)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl<C, E> RagPipelineBuilder<C, E> {
+    pub fn chunker(
+        self,
+        chunker: impl BaseChunker + 'static,
+    ) -> RagPipelineBuilder<Set<Box<dyn BaseChunker>>, E> {
+        RagPipelineBuilder {
+            chunker: Set(Box::new(chunker)),
+            embedder: self.embedder,
+            loaders: self.loaders,
+            store: self.store,
+            language_threshold: self.language_threshold,
+            top_k: self.top_k,
+            batch_size: self.batch_size,
+        }
+    }
+
+    pub fn embedder(
+        self,
+        embedder: Arc<dyn EmbeddingService>,
+    ) -> RagPipelineBuilder<C, Set<Arc<dyn EmbeddingService>>> {
+        RagPipelineBuilder {
+            chunker: self.chunker,
+            embedder: Set(embedder),
+            loaders: self.loaders,
+            store: self.store,
+            language_threshold: self.language_threshold,
+            top_k: self.top_k,
+            batch_size: self.batch_size,
+        }
+    }
+
+    /// Add a loader; `ingest` runs loaders in the order they were added.
+    pub fn loader(mut self, loader: impl BaseLoader + 'static) -> Self {
+        self.loaders.push(Box::new(loader));
+        self
+    }
+
+    pub fn loaders(mut self, loaders: Vec<Box<dyn BaseLoader>>) -> Self {
+        self.loaders.extend(loaders);
+        self
+    }
+
+    /// Store chunks in `store` instead of a fresh [`InMemoryVectorStore`].
+    pub fn store(mut self, store: Arc<dyn VectorStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    pub fn language_threshold(mut self, min_confidence: f32, policy: LowConfidencePolicy) -> Self {
+        self.language_threshold = Some((min_confidence, policy));
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    pub fn batch_size(mut self, size: usize) -> Self {
+        self.batch_size = size;
+        self
+    }
+}
+
+impl RagPipelineBuilder<Set<Box<dyn BaseChunker>>, Set<Arc<dyn EmbeddingService>>> {
+    pub fn build(self) -> RagPipeline {
+        RagPipeline {
+            loaders: self.loaders,
+            chunker: self.chunker.0,
+            embedder: self.embedder.0,
+            store: self
+                .store
+                .unwrap_or_else(|| Arc::new(InMemoryVectorStore::new())),
+            low_confidence_store: InMemoryVectorStore::new(),
+            language_threshold: self.language_threshold,
+            top_k: self.top_k,
+            batch_size: self.batch_size,
+        }
+    }
+}
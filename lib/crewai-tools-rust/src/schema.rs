@@ -0,0 +1,264 @@
+//! JSON Schema checking shared by tool argument and response validation.
+//!
+//! Supports the subset of JSON Schema used by OpenAPI documents and the
+//! enterprise action schemas: `type` (single or list), `nullable`,
+//! `properties`, `required`, `additionalProperties: false`, `items`, `enum`,
+//! and local `$ref`s (`#/components/schemas/…`, `#/definitions/…`) resolved
+//! against a root document.
+//!
+//! Validation never fails outright: it returns every violation found so
+//! callers can decide whether to reject (arguments) or report and pass the
+//! data through (responses).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One place where a value does not match its schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the offending value (`""` for the root).
+    pub path: String,
+    /// What was wrong.
+    pub message: String,
+}
+
+/// One change made by [`coerce`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Coercion {
+    /// JSON Pointer to the coerced value.
+    pub path: String,
+    /// The value before coercion.
+    pub from: Value,
+    /// The value after coercion.
+    pub to: Value,
+}
+
+/// Check `value` against `schema`. `root` is the document `$ref`s resolve
+/// against; pass the schema itself when it is self-contained.
+pub fn validate(value: &Value, schema: &Value, root: &Value) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+    check(value, schema, root, "", &mut violations, 0);
+    violations
+}
+
+/// Fix obvious mismatches in place: numeric strings where numbers are
+/// expected, `"true"`/`"false"` where booleans are expected, and single
+/// values where arrays are expected. Returns what was changed.
+pub fn coerce(value: &mut Value, schema: &Value, root: &Value) -> Vec<Coercion> {
+    let mut coercions = Vec::new();
+    coerce_at(value, schema, root, "", &mut coercions, 0);
+    coercions
+}
+
+/// Bound on `$ref` indirection, so cyclic schemas cannot recurse forever.
+const MAX_DEPTH: usize = 64;
+
+fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    let mut current = schema;
+    for _ in 0..MAX_DEPTH {
+        match current.get("$ref").and_then(|r| r.as_str()) {
+            Some(reference) => match reference.strip_prefix('#').and_then(|p| root.pointer(p)) {
+                Some(target) => current = target,
+                None => return current,
+            },
+            None => return current,
+        }
+    }
+    current
+}
+
+/// The schema's allowed types, or `None` if it does not constrain the type.
+fn schema_types(schema: &Value) -> Option<Vec<&str>> {
+    let mut types: Vec<&str> = match schema.get("type")? {
+        Value::String(t) => vec![t.as_str()],
+        Value::Array(ts) => ts.iter().filter_map(|t| t.as_str()).collect(),
+        _ => return None,
+    };
+    if schema.get("nullable").and_then(|n| n.as_bool()) == Some(true) {
+        types.push("null");
+    }
+    Some(types)
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn type_matches(value: &Value, expected: &str) -> bool {
+    match (expected, value) {
+        ("integer", Value::Number(n)) => {
+            n.is_i64() || n.is_u64() || n.as_f64().is_some_and(|f| f.fract() == 0.0)
+        }
+        ("number", Value::Number(_)) => true,
+        _ => type_name(value) == expected,
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn check(
+    value: &Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    out: &mut Vec<SchemaViolation>,
+    depth: usize,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let schema = resolve(schema, root);
+
+    if let Some(types) = schema_types(schema) {
+        if !types.iter().any(|t| type_matches(value, t)) {
+            out.push(SchemaViolation {
+                path: path.to_string(),
+                message: format!("expected {}, found {}", types.join(" or "), type_name(value)),
+            });
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !allowed.contains(value) {
+            out.push(SchemaViolation {
+                path: path.to_string(),
+                message: format!("{} is not one of {}", value, Value::Array(allowed.clone())),
+            });
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+                for key in required.iter().filter_map(|k| k.as_str()) {
+                    if !map.contains_key(key) {
+                        out.push(SchemaViolation {
+                            path: child_path(path, key),
+                            message: "required property is missing".to_string(),
+                        });
+                    }
+                }
+            }
+            let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+            for (key, child) in map {
+                match properties.and_then(|p| p.get(key)) {
+                    Some(child_schema) => {
+                        check(child, child_schema, root, &child_path(path, key), out, depth + 1)
+                    }
+                    None if closed => out.push(SchemaViolation {
+                        path: child_path(path, key),
+                        message: "property is not allowed by the schema".to_string(),
+                    }),
+                    None => {
+                        if let Some(extra) = schema.get("additionalProperties").filter(|a| a.is_object()) {
+                            check(child, extra, root, &child_path(path, key), out, depth + 1);
+                        }
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check(item, item_schema, root, &format!("{}/{}", path, i), out, depth + 1);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn coerce_at(
+    value: &mut Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    out: &mut Vec<Coercion>,
+    depth: usize,
+) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+    let schema = resolve(schema, root);
+
+    if let Some(types) = schema_types(schema) {
+        if !types.iter().any(|t| type_matches(value, t)) {
+            if let Some(coerced) = coerce_scalar(value, &types) {
+                out.push(Coercion {
+                    path: path.to_string(),
+                    from: value.clone(),
+                    to: coerced.clone(),
+                });
+                *value = coerced;
+            } else if types.contains(&"array") && !value.is_null() {
+                let single = value.take();
+                let mut wrapped = Value::Array(vec![single.clone()]);
+                if let Some(item_schema) = schema.get("items") {
+                    coerce_at(&mut wrapped[0], item_schema, root, &format!("{}/0", path), out, depth + 1);
+                }
+                out.push(Coercion {
+                    path: path.to_string(),
+                    from: single,
+                    to: wrapped.clone(),
+                });
+                *value = wrapped;
+                return;
+            }
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            let properties = schema.get("properties").and_then(|p| p.as_object());
+            for (key, child) in map.iter_mut() {
+                if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+                    coerce_at(child, child_schema, root, &child_path(path, key), out, depth + 1);
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter_mut().enumerate() {
+                    coerce_at(item, item_schema, root, &format!("{}/{}", path, i), out, depth + 1);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Convert a string to the number or boolean the schema expects, if it
+/// unambiguously is one.
+fn coerce_scalar(value: &Value, types: &[&str]) -> Option<Value> {
+    let s = value.as_str()?.trim();
+    if types.contains(&"integer") {
+        if let Ok(i) = s.parse::<i64>() {
+            return Some(Value::from(i));
+        }
+    }
+    if types.contains(&"number") {
+        if let Some(n) = s.parse::<f64>().ok().filter(|f| f.is_finite()).and_then(serde_json::Number::from_f64) {
+            return Some(Value::Number(n));
+        }
+    }
+    if types.contains(&"boolean") {
+        match s {
+            "true" => return Some(Value::Bool(true)),
+            "false" => return Some(Value::Bool(false)),
+            _ => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,12 @@
+//! Row heuristics shared by `CsvSearchTool` and the RAG `CsvLoader`.
+
+use std::collections::HashSet;
+
+/// A first row whose cells are all non-empty, non-numeric and unique.
+pub(crate) fn looks_like_header(row: &[String]) -> bool {
+    let mut seen = HashSet::new();
+    !row.is_empty()
+        && row.iter().all(|cell| {
+            !cell.is_empty() && cell.parse::<f64>().is_err() && seen.insert(cell.as_str())
+        })
+}
@@ -0,0 +1,148 @@
+//! `ffmpeg` helpers for `TranscriptionTool`: splitting audio that is too
+//! large to upload in one request.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::missing_binary;
+
+const FFMPEG_HINT: &str = "Audio over the upload limit is split with ffmpeg (apt install \
+     ffmpeg, brew install ffmpeg) or point ffmpeg_path at the binary.";
+
+/// One piece of a split recording.
+pub(super) struct Chunk {
+    pub path: PathBuf,
+    /// Offset of the chunk into the original recording, in seconds.
+    pub start: f64,
+}
+
+/// Split `audio` into chunks of at most `chunk_seconds` in `out_dir`.
+///
+/// With `on_silence`, each cut is moved back to the last pause in the
+/// second half of its window so words are not cut in two; windows without
+/// a pause are cut at the full length.
+pub(super) fn split(
+    binary: &str,
+    audio: &Path,
+    out_dir: &Path,
+    chunk_seconds: f64,
+    on_silence: bool,
+) -> Result<Vec<Chunk>, anyhow::Error> {
+    let Some(extension) = audio.extension().and_then(|e| e.to_str()) else {
+        anyhow::bail!("Cannot split '{}': it has no file extension", audio.display());
+    };
+    let list = out_dir.join("chunks.csv");
+
+    let mut command = Command::new(binary);
+    command.args(["-hide_banner", "-loglevel", "error", "-i"]).arg(audio);
+    command.args(["-f", "segment", "-reset_timestamps", "1", "-c", "copy"]);
+    if on_silence {
+        let (duration, silences) = detect_silences(binary, audio)?;
+        let cuts: Vec<String> = cut_points(&silences, duration, chunk_seconds)
+            .iter()
+            .map(|t| format!("{:.3}", t))
+            .collect();
+        // With no cuts the whole file becomes one chunk.
+        command.arg("-segment_times").arg(if cuts.is_empty() {
+            format!("{:.3}", duration + 1.0)
+        } else {
+            cuts.join(",")
+        });
+    } else {
+        command.arg("-segment_time").arg(format!("{:.3}", chunk_seconds));
+    }
+    command
+        .arg("-segment_list")
+        .arg(&list)
+        .arg(out_dir.join(format!("chunk%03d.{}", extension)));
+    run(binary, audio, &mut command)?;
+
+    // Each line is `file,start,end`.
+    let listed = std::fs::read_to_string(&list).map_err(|e| {
+        anyhow::anyhow!("ffmpeg wrote no chunk list for '{}': {}", audio.display(), e)
+    })?;
+    listed
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.split(',');
+            match (fields.next(), fields.next().and_then(|s| s.trim().parse().ok())) {
+                (Some(name), Some(start)) => Ok(Chunk {
+                    path: out_dir.join(name),
+                    start,
+                }),
+                _ => Err(anyhow::anyhow!("Unexpected line in ffmpeg chunk list: {}", line)),
+            }
+        })
+        .collect()
+}
+
+/// Total duration and `(start, end)` of each pause, from ffmpeg's
+/// `silencedetect` filter.
+fn detect_silences(binary: &str, audio: &Path) -> Result<(f64, Vec<(f64, f64)>), anyhow::Error> {
+    let mut command = Command::new(binary);
+    command
+        .args(["-hide_banner", "-nostats", "-i"])
+        .arg(audio)
+        .args(["-af", "silencedetect=noise=-35dB:d=0.4", "-f", "null", "-"]);
+    let stderr = run(binary, audio, &mut command)?;
+
+    let mut duration = 0.0;
+    let mut silences = Vec::new();
+    let mut open = None;
+    for line in stderr.lines() {
+        if let Some(rest) = line.trim().strip_prefix("Duration: ") {
+            duration = rest.split(',').next().and_then(parse_clock).unwrap_or(0.0);
+        } else if let Some(start) = value_after(line, "silence_start: ") {
+            open = Some(start);
+        } else if let Some(end) = value_after(line, "silence_end: ") {
+            silences.push((open.take().unwrap_or(end), end));
+        }
+    }
+    if duration <= 0.0 {
+        anyhow::bail!("ffmpeg did not report a duration for '{}'", audio.display());
+    }
+    Ok((duration, silences))
+}
+
+/// Cut times splitting `duration` into windows of at most `chunk_seconds`,
+/// each placed mid-pause when a pause falls in the window's second half.
+fn cut_points(silences: &[(f64, f64)], duration: f64, chunk_seconds: f64) -> Vec<f64> {
+    let mut cuts = Vec::new();
+    let mut last = 0.0;
+    while duration - last > chunk_seconds {
+        let limit = last + chunk_seconds;
+        let cut = silences
+            .iter()
+            .rev()
+            .map(|(start, end)| (start + end) / 2.0)
+            .find(|mid| *mid > last + chunk_seconds / 2.0 && *mid <= limit)
+            .unwrap_or(limit);
+        cuts.push(cut);
+        last = cut;
+    }
+    cuts
+}
+
+fn run(binary: &str, audio: &Path, command: &mut Command) -> Result<String, anyhow::Error> {
+    let output = command.output().map_err(|e| missing_binary(binary, FFMPEG_HINT, e))?;
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    if !output.status.success() {
+        anyhow::bail!("ffmpeg failed on '{}': {}", audio.display(), stderr.trim());
+    }
+    Ok(stderr)
+}
+
+/// The number following `key` on a log line.
+fn value_after(line: &str, key: &str) -> Option<f64> {
+    let (_, rest) = line.split_once(key)?;
+    rest.split_whitespace().next()?.parse().ok()
+}
+
+/// Seconds in an `HH:MM:SS.ss` timestamp.
+fn parse_clock(clock: &str) -> Option<f64> {
+    clock
+        .trim()
+        .split(':')
+        .try_fold(0.0, |total, part| Some(total * 60.0 + part.parse::<f64>().ok()?))
+}
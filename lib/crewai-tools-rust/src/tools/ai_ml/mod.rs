@@ -1,13 +1,34 @@
 //! AI/ML tools for crewAI.
 //!
 //! This module contains tools for AI and machine learning integrations,
-//! including image generation, computer vision, OCR, and RAG pipelines.
-//! Each struct corresponds to a Python tool class in `crewai_tools`.
+//! including image generation, computer vision, OCR, transcription, moderation,
+//! and RAG pipelines. Each struct corresponds to a Python tool class in
+//! `crewai_tools`.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::core::{cosine_similarity, EmbeddingService, Moderation, ModerationService};
+use crate::rag::embeddings::embedding_service;
+use crate::rag::filter::QueryFilter;
+use crate::builder::{Missing, Set};
+use crate::rag::pipeline::{IngestSummary, RagPipeline, RagPipelineBuilder};
+
+mod ffmpeg;
+mod tesseract;
+
+/// Error for a helper binary that failed to start, with an install hint
+/// when it is not installed at all.
+fn missing_binary(binary: &str, hint: &str, error: std::io::Error) -> anyhow::Error {
+    if error.kind() == std::io::ErrorKind::NotFound {
+        anyhow::anyhow!("'{}' was not found. {}", binary, hint)
+    } else {
+        anyhow::anyhow!("Failed to run '{}': {}", binary, error)
+    }
+}
+
 // ── DalleTool ────────────────────────────────────────────────────────────────
 
 /// Generate images using OpenAI's DALL-E API.
@@ -70,6 +91,12 @@ impl Default for DalleTool {
 
 /// Analyze images using vision-capable LLM models.
 ///
+/// Sends the image and a prompt to an OpenAI-compatible chat completions
+/// endpoint as an `image_url` content part. Remote images are passed by
+/// URL; local files are inlined as a base64 `data:` URL with their MIME
+/// type. Point `base_url` at any compatible server (Ollama, OpenRouter,
+/// vLLM); no API key is required once a base URL is set.
+///
 /// Corresponds to Python `VisionTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisionTool {
@@ -77,6 +104,26 @@ pub struct VisionTool {
     pub api_key: Option<String>,
     /// Model to use for vision analysis.
     pub model: String,
+    /// OpenAI-compatible API root, e.g. `http://localhost:11434/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Largest local image that will be inlined, in bytes.
+    #[serde(default = "default_max_image_bytes")]
+    pub max_image_bytes: u64,
+    /// Cap on the answer's length, in tokens.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Request timeout.
+    #[serde(default = "default_vision_timeout")]
+    pub timeout: std::time::Duration,
+}
+
+fn default_max_image_bytes() -> u64 {
+    20 * 1024 * 1024
+}
+
+fn default_vision_timeout() -> std::time::Duration {
+    std::time::Duration::from_secs(120)
 }
 
 impl VisionTool {
@@ -84,6 +131,10 @@ impl VisionTool {
         Self {
             api_key: None,
             model: "gpt-4o".to_string(),
+            base_url: None,
+            max_image_bytes: default_max_image_bytes(),
+            max_tokens: None,
+            timeout: default_vision_timeout(),
         }
     }
 
@@ -97,8 +148,140 @@ impl VisionTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("VisionTool: not yet implemented - requires vision model API integration")
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_image_bytes(mut self, bytes: u64) -> Self {
+        self.max_image_bytes = bytes;
+        self
+    }
+
+    pub fn with_max_tokens(mut self, tokens: u32) -> Self {
+        self.max_tokens = Some(tokens);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Ask the model about an image.
+    ///
+    /// Returns `{answer, model, usage: {prompt_tokens, completion_tokens,
+    /// total_tokens}}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `image_url` - URL of the image (http(s) or `data:`).
+    /// * `image_path` - Local image file; used when `image_url` is absent.
+    /// * `prompt` - What to ask about the image (alias: `question`);
+    ///   defaults to a request for a description.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let image_url = match (
+            args.get("image_url").and_then(|v| v.as_str()),
+            args.get("image_path").and_then(|v| v.as_str()),
+        ) {
+            (Some(url), _) => url.to_string(),
+            (None, Some(path)) => self.data_url(std::path::Path::new(path))?,
+            (None, None) => anyhow::bail!("Missing required argument: image_url or image_path"),
+        };
+        let prompt = args
+            .get("prompt")
+            .or_else(|| args.get("question"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("Describe this image in detail.");
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("OPENAI_API_KEY").ok());
+        if api_key.is_none() && self.base_url.is_none() {
+            anyhow::bail!("Missing OPENAI_API_KEY");
+        }
+        let base_url = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.openai.com/v1")
+            .trim_end_matches('/');
+
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "messages": [{
+                "role": "user",
+                "content": [
+                    {"type": "text", "text": prompt},
+                    {"type": "image_url", "image_url": {"url": image_url}},
+                ],
+            }],
+        });
+        if let Some(max_tokens) = self.max_tokens {
+            body["max_tokens"] = Value::from(max_tokens);
+        }
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()?;
+        let mut request = client
+            .post(format!("{}/chat/completions", base_url))
+            .json(&body);
+        if let Some(key) = api_key {
+            request = request.bearer_auth(key);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no details");
+            anyhow::bail!("Vision request failed with status {}: {}", status.as_u16(), message);
+        }
+
+        let answer = payload
+            .pointer("/choices/0/message/content")
+            .and_then(|c| c.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Vision response has no choices[0].message.content"))?;
+        let usage = &payload["usage"];
+        Ok(serde_json::json!({
+            "answer": answer,
+            "model": payload["model"].as_str().unwrap_or(&self.model),
+            "usage": {
+                "prompt_tokens": usage["prompt_tokens"],
+                "completion_tokens": usage["completion_tokens"],
+                "total_tokens": usage["total_tokens"],
+            },
+        }))
+    }
+
+    /// A `data:` URL for a local image, refusing files over the size limit.
+    fn data_url(&self, path: &std::path::Path) -> Result<String, anyhow::Error> {
+        use base64::Engine;
+
+        let size = std::fs::metadata(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read image '{}': {}", path.display(), e))?
+            .len();
+        if size > self.max_image_bytes {
+            anyhow::bail!(
+                "Image '{}' is {} bytes, over the {} byte limit; downscale it (about 2048px on \
+                 the long side is plenty for most models) or raise max_image_bytes",
+                path.display(),
+                size,
+                self.max_image_bytes
+            );
+        }
+        let bytes = std::fs::read(path)?;
+        let mime = crate::tools::mime::detect(path, &bytes);
+        if !mime.starts_with("image/") {
+            anyhow::bail!("'{}' does not look like an image ({})", path.display(), mime);
+        }
+        Ok(format!(
+            "data:{};base64,{}",
+            mime,
+            base64::engine::general_purpose::STANDARD.encode(&bytes)
+        ))
     }
 }
 
@@ -110,27 +293,67 @@ impl Default for VisionTool {
 
 // ── OcrTool ──────────────────────────────────────────────────────────────────
 
+/// OCR backend for [`OcrTool`].
+///
+/// Parses from (and deserializes as) its lowercase name, so an unknown
+/// engine is rejected when the tool is configured rather than at run time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OcrEngine {
+    /// The `tesseract` command-line program.
+    #[default]
+    Tesseract,
+}
+
+impl OcrEngine {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tesseract => "tesseract",
+        }
+    }
+}
+
+impl std::str::FromStr for OcrEngine {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "tesseract" => Ok(Self::Tesseract),
+            other => anyhow::bail!("Unknown OCR engine '{}': expected 'tesseract'", other),
+        }
+    }
+}
+
 /// Extract text from images using Optical Character Recognition.
 ///
+/// The Tesseract engine runs the `tesseract` binary, which must be
+/// installed. Multi-page TIFFs are recognized page by page, and PDFs are
+/// first rendered to images with poppler's `pdftoppm`. With `tsv` output
+/// each page also lists its text blocks with their mean word confidence.
+///
 /// Corresponds to Python `OCRTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OcrTool {
-    /// OCR engine to use (e.g., "tesseract", "cloud_vision").
-    pub engine: String,
+    /// OCR engine to use.
+    pub engine: OcrEngine,
     /// Language codes for OCR (e.g., ["eng", "deu"]).
     pub languages: Vec<String>,
+    /// Path to the tesseract binary; found on `PATH` when unset.
+    #[serde(default)]
+    pub tesseract_path: Option<String>,
 }
 
 impl OcrTool {
     pub fn new() -> Self {
         Self {
-            engine: "tesseract".to_string(),
+            engine: OcrEngine::Tesseract,
             languages: vec!["eng".to_string()],
+            tesseract_path: None,
         }
     }
 
-    pub fn with_engine(mut self, engine: impl Into<String>) -> Self {
-        self.engine = engine.into();
+    pub fn with_engine(mut self, engine: OcrEngine) -> Self {
+        self.engine = engine;
         self
     }
 
@@ -139,30 +362,680 @@ impl OcrTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("OcrTool: not yet implemented - requires OCR engine integration")
+    pub fn with_tesseract_path(mut self, path: impl Into<String>) -> Self {
+        self.tesseract_path = Some(path.into());
+        self
+    }
+
+    /// Recognize the text in an image or PDF.
+    ///
+    /// Returns `{engine, languages, text, pages: [{page, text}]}`, with
+    /// `blocks: [{text, confidence}]` on each page for `tsv` output. `text`
+    /// joins the pages with form feeds.
+    ///
+    /// # Arguments (in `args`)
+    /// * `image_path` - Image, multi-page TIFF or PDF to read.
+    /// * `image_base64` - Image bytes as base64 or a `data:` URL; used
+    ///   when `image_path` is absent.
+    /// * `languages` - Override the configured language codes.
+    /// * `output` - `"text"` (default) or `"tsv"`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let languages: Vec<String> = match args.get("languages").and_then(|v| v.as_array()) {
+            Some(codes) => codes
+                .iter()
+                .filter_map(|c| c.as_str().map(str::to_string))
+                .collect(),
+            None => self.languages.clone(),
+        };
+        let tsv = match args.get("output").and_then(|v| v.as_str()).unwrap_or("text") {
+            "text" => false,
+            "tsv" => true,
+            other => anyhow::bail!("Unknown output '{}': expected 'text' or 'tsv'", other),
+        };
+
+        let scratch = std::env::temp_dir().join(format!(
+            "crewai-ocr-{}-{}",
+            std::process::id(),
+            NEXT_OCR_SCRATCH.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&scratch)?;
+        let result = self.recognize(&args, &scratch, &languages, tsv);
+        let _ = std::fs::remove_dir_all(&scratch);
+        let pages = result?;
+
+        let text = pages
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\u{c}");
+        let pages: Vec<Value> = pages
+            .into_iter()
+            .enumerate()
+            .map(|(i, page)| {
+                let mut entry = serde_json::json!({ "page": i + 1, "text": page.text });
+                if tsv {
+                    entry["blocks"] = page
+                        .blocks
+                        .into_iter()
+                        .map(|(text, confidence)| {
+                            serde_json::json!({ "text": text, "confidence": confidence })
+                        })
+                        .collect();
+                }
+                entry
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "engine": self.engine.as_str(),
+            "languages": languages,
+            "text": text,
+            "pages": pages,
+        }))
+    }
+
+    /// Resolve the input into image files under `scratch` and OCR each.
+    fn recognize(
+        &self,
+        args: &HashMap<String, Value>,
+        scratch: &std::path::Path,
+        languages: &[String],
+        tsv: bool,
+    ) -> Result<Vec<tesseract::Page>, anyhow::Error> {
+        use base64::Engine;
+
+        let input = match (
+            args.get("image_path").and_then(|v| v.as_str()),
+            args.get("image_base64").and_then(|v| v.as_str()),
+        ) {
+            (Some(path), _) => std::path::PathBuf::from(path),
+            (None, Some(encoded)) => {
+                let encoded = encoded.split_once(";base64,").map_or(encoded, |(_, data)| data);
+                let bytes = base64::engine::general_purpose::STANDARD
+                    .decode(encoded.trim())
+                    .map_err(|e| anyhow::anyhow!("image_base64 is not valid base64: {}", e))?;
+                let path = scratch.join("input");
+                std::fs::write(&path, &bytes)?;
+                path
+            }
+            (None, None) => anyhow::bail!("Missing required argument: image_path or image_base64"),
+        };
+        let head = {
+            use std::io::Read;
+            let mut head = [0u8; 5];
+            let read = std::fs::File::open(&input)
+                .and_then(|mut f| f.read(&mut head))
+                .map_err(|e| anyhow::anyhow!("Cannot read '{}': {}", input.display(), e))?;
+            head[..read].to_vec()
+        };
+
+        let binary = self.tesseract_path.as_deref().unwrap_or("tesseract");
+        if head.starts_with(b"%PDF-") {
+            let images = scratch.join("pages");
+            std::fs::create_dir_all(&images)?;
+            let mut pages = Vec::new();
+            for image in tesseract::rasterize_pdf(&input, &images)? {
+                pages.extend(tesseract::recognize(binary, &image, languages, tsv)?);
+            }
+            Ok(pages)
+        } else {
+            tesseract::recognize(binary, &input, languages, tsv)
+        }
     }
 }
 
+static NEXT_OCR_SCRATCH: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
 impl Default for OcrTool {
     fn default() -> Self {
         Self::new()
     }
 }
 
+// ── TranscriptionTool ────────────────────────────────────────────────────────
+
+/// Transcribe audio files with OpenAI's Whisper API.
+///
+/// Files over `max_upload_bytes` (the API's 25MB limit by default) are
+/// split with `ffmpeg` into chunks of at most `chunk_seconds`, cut at
+/// pauses unless `split_on_silence` is off. The chunk transcripts are
+/// concatenated, with `verbose_json` segment times shifted to the
+/// original recording.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionTool {
+    /// OpenAI API key; falls back to `OPENAI_API_KEY`.
+    pub api_key: Option<String>,
+    /// Transcription model.
+    pub model: String,
+    /// OpenAI-compatible API root, e.g. `http://localhost:8000/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Spoken language as an ISO-639-1 code; detected when unset.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Largest file uploaded in one request, in bytes.
+    #[serde(default = "default_max_upload_bytes")]
+    pub max_upload_bytes: u64,
+    /// Longest chunk when a file has to be split, in seconds.
+    #[serde(default = "default_chunk_seconds")]
+    pub chunk_seconds: u32,
+    /// Cut chunks at pauses rather than at exact `chunk_seconds` marks.
+    #[serde(default = "default_split_on_silence")]
+    pub split_on_silence: bool,
+    /// Path to the ffmpeg binary; found on `PATH` when unset.
+    #[serde(default)]
+    pub ffmpeg_path: Option<String>,
+    /// Request timeout, per chunk.
+    #[serde(default = "default_transcription_timeout")]
+    pub timeout: std::time::Duration,
+}
+
+fn default_max_upload_bytes() -> u64 {
+    25 * 1024 * 1024
+}
+
+fn default_chunk_seconds() -> u32 {
+    600
+}
+
+fn default_split_on_silence() -> bool {
+    true
+}
+
+fn default_transcription_timeout() -> std::time::Duration {
+    std::time::Duration::from_secs(300)
+}
+
+impl TranscriptionTool {
+    pub fn new() -> Self {
+        Self {
+            api_key: None,
+            model: "whisper-1".to_string(),
+            base_url: None,
+            language: None,
+            max_upload_bytes: default_max_upload_bytes(),
+            chunk_seconds: default_chunk_seconds(),
+            split_on_silence: default_split_on_silence(),
+            ffmpeg_path: None,
+            timeout: default_transcription_timeout(),
+        }
+    }
+
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    pub fn with_max_upload_bytes(mut self, bytes: u64) -> Self {
+        self.max_upload_bytes = bytes;
+        self
+    }
+
+    pub fn with_chunk_seconds(mut self, seconds: u32) -> Self {
+        self.chunk_seconds = seconds.max(1);
+        self
+    }
+
+    pub fn with_split_on_silence(mut self, on_silence: bool) -> Self {
+        self.split_on_silence = on_silence;
+        self
+    }
+
+    pub fn with_ffmpeg_path(mut self, path: impl Into<String>) -> Self {
+        self.ffmpeg_path = Some(path.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Transcribe an audio file.
+    ///
+    /// Returns `{text, model, chunks}`; `verbose_json` adds `language`,
+    /// `duration` and `segments: [{start_seconds, end_seconds, timestamp,
+    /// text}]`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `audio_path` - Audio file to transcribe (mp3, m4a, wav, webm, ...).
+    /// * `language` - Override the configured language.
+    /// * `response_format` - `"text"` (default) or `"verbose_json"`.
+    /// * `prompt` - Optional spelling hints or preceding context.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let path = args
+            .get("audio_path")
+            .and_then(|v| v.as_str())
+            .map(std::path::Path::new)
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: audio_path"))?;
+        let verbose = match args
+            .get("response_format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("text")
+        {
+            "text" => false,
+            "verbose_json" => true,
+            other => anyhow::bail!(
+                "Unknown response_format '{}': expected 'text' or 'verbose_json'",
+                other
+            ),
+        };
+        let language = args
+            .get("language")
+            .and_then(|v| v.as_str())
+            .or(self.language.as_deref());
+        let prompt = args.get("prompt").and_then(|v| v.as_str());
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("OPENAI_API_KEY").ok());
+        if api_key.is_none() && self.base_url.is_none() {
+            anyhow::bail!("Missing OPENAI_API_KEY");
+        }
+
+        let size = std::fs::metadata(path)
+            .map_err(|e| anyhow::anyhow!("Cannot read audio '{}': {}", path.display(), e))?
+            .len();
+        let scratch = std::env::temp_dir().join(format!(
+            "crewai-transcribe-{}-{}",
+            std::process::id(),
+            NEXT_TRANSCRIPTION_SCRATCH.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+        let chunks = if size > self.max_upload_bytes {
+            std::fs::create_dir_all(&scratch)?;
+            ffmpeg::split(
+                self.ffmpeg_path.as_deref().unwrap_or("ffmpeg"),
+                path,
+                &scratch,
+                f64::from(self.chunk_seconds),
+                self.split_on_silence,
+            )
+        } else {
+            Ok(vec![ffmpeg::Chunk {
+                path: path.to_path_buf(),
+                start: 0.0,
+            }])
+        };
+        let result = chunks.and_then(|chunks| {
+            let client = reqwest::blocking::Client::builder()
+                .timeout(self.timeout)
+                .build()?;
+            chunks
+                .iter()
+                .map(|chunk| {
+                    let transcript = self.transcribe(
+                        &client,
+                        api_key.as_deref(),
+                        chunk,
+                        language,
+                        prompt,
+                        verbose,
+                    )?;
+                    Ok((chunk.start, transcript))
+                })
+                .collect::<Result<Vec<_>, anyhow::Error>>()
+        });
+        let _ = std::fs::remove_dir_all(&scratch);
+        let transcripts = result?;
+
+        let text = transcripts
+            .iter()
+            .filter_map(|(_, t)| t["text"].as_str().map(str::trim))
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut result = serde_json::json!({
+            "text": text,
+            "model": self.model,
+            "chunks": transcripts.len(),
+        });
+        if verbose {
+            let segments: Vec<Value> = transcripts
+                .iter()
+                .flat_map(|(offset, t)| {
+                    t["segments"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(move |seg| {
+                            let start = offset + seg["start"].as_f64().unwrap_or(0.0);
+                            let end = offset + seg["end"].as_f64().unwrap_or(0.0);
+                            serde_json::json!({
+                                "start_seconds": start,
+                                "end_seconds": end,
+                                "timestamp": crate::youtube::format_timestamp(start as u32),
+                                "text": seg["text"].as_str().unwrap_or("").trim(),
+                            })
+                        })
+                })
+                .collect();
+            result["language"] = transcripts
+                .first()
+                .map_or(Value::Null, |(_, t)| t["language"].clone());
+            result["duration"] = transcripts
+                .last()
+                .and_then(|(offset, t)| t["duration"].as_f64().map(|d| offset + d))
+                .map_or(Value::Null, Value::from);
+            result["segments"] = Value::from(segments);
+        }
+        Ok(result)
+    }
+
+    /// Upload one file to `/audio/transcriptions`, returning the JSON reply.
+    fn transcribe(
+        &self,
+        client: &reqwest::blocking::Client,
+        api_key: Option<&str>,
+        chunk: &ffmpeg::Chunk,
+        language: Option<&str>,
+        prompt: Option<&str>,
+        verbose: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let size = std::fs::metadata(&chunk.path)?.len();
+        if size > self.max_upload_bytes {
+            anyhow::bail!(
+                "Audio chunk at {:.0}s is {} bytes, over the {} byte upload limit; lower \
+                 chunk_seconds",
+                chunk.start,
+                size,
+                self.max_upload_bytes
+            );
+        }
+        let mut form = reqwest::blocking::multipart::Form::new()
+            .text("model", self.model.clone())
+            .text("response_format", if verbose { "verbose_json" } else { "json" })
+            .file("file", &chunk.path)
+            .map_err(|e| anyhow::anyhow!("Cannot read audio '{}': {}", chunk.path.display(), e))?;
+        if let Some(language) = language {
+            form = form.text("language", language.to_string());
+        }
+        if let Some(prompt) = prompt {
+            form = form.text("prompt", prompt.to_string());
+        }
+
+        let base_url = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.openai.com/v1")
+            .trim_end_matches('/');
+        let mut request = client
+            .post(format!("{}/audio/transcriptions", base_url))
+            .multipart(form);
+        if let Some(key) = api_key {
+            request = request.bearer_auth(key);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no details");
+            anyhow::bail!(
+                "Transcription request failed with status {}: {}",
+                status.as_u16(),
+                message
+            );
+        }
+        Ok(payload)
+    }
+}
+
+static NEXT_TRANSCRIPTION_SCRATCH: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
+impl Default for TranscriptionTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── ModerationTool ───────────────────────────────────────────────────────────
+
+/// Check text against a content policy before it is published.
+///
+/// Uses OpenAI's `/moderations` endpoint, or any [`ModerationService`]
+/// attached with `with_service`. Each text gets an `allowed` verdict: with
+/// a `threshold`, a text is allowed while every category score stays below
+/// it; without one, the provider's own `flagged` decides. Merged agent
+/// outputs can be passed as a batch and gated on the overall `allowed`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ModerationTool {
+    /// OpenAI API key; falls back to `OPENAI_API_KEY`.
+    pub api_key: Option<String>,
+    /// Moderation model.
+    pub model: String,
+    /// OpenAI-compatible API root.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Highest category score a text may have and still be allowed.
+    #[serde(default)]
+    pub threshold: Option<f64>,
+    /// Provider used instead of OpenAI (not serialized).
+    #[serde(skip)]
+    pub service: Option<Arc<dyn ModerationService>>,
+}
+
+impl std::fmt::Debug for ModerationTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModerationTool")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("model", &self.model)
+            .field("base_url", &self.base_url)
+            .field("threshold", &self.threshold)
+            .field("service", &self.service.as_ref().map(|s| s.model_name().to_string()))
+            .finish()
+    }
+}
+
+impl ModerationTool {
+    pub fn new() -> Self {
+        Self {
+            api_key: None,
+            model: "omni-moderation-latest".to_string(),
+            base_url: None,
+            threshold: None,
+            service: None,
+        }
+    }
+
+    pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    pub fn with_service(mut self, service: Arc<dyn ModerationService>) -> Self {
+        self.service = Some(service);
+        self
+    }
+
+    /// Moderate one text or a batch.
+    ///
+    /// Returns `{allowed, model, results: [{allowed, flagged, categories,
+    /// scores}]}`; the top-level `allowed` is true only when every text is.
+    /// With a threshold, `categories` lists the categories at or above it.
+    ///
+    /// # Arguments (in `args`)
+    /// * `input` - A text, or an array of texts.
+    /// * `threshold` - Override the configured threshold.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let texts: Option<Vec<&str>> = match args.get("input") {
+            Some(Value::String(text)) => Some(vec![text.as_str()]),
+            Some(Value::Array(items)) => items.iter().map(|t| t.as_str()).collect(),
+            Some(_) => None,
+            None => anyhow::bail!("Missing required argument: input"),
+        };
+        let texts =
+            texts.ok_or_else(|| anyhow::anyhow!("'input' must be a string or an array of strings"))?;
+        if texts.is_empty() {
+            anyhow::bail!("'input' must contain at least one text");
+        }
+        let threshold = args
+            .get("threshold")
+            .and_then(|v| v.as_f64())
+            .or(self.threshold);
+
+        let (verdicts, model) = match &self.service {
+            Some(service) => (service.moderate(&texts)?, service.model_name().to_string()),
+            None => self.moderate_openai(&texts)?,
+        };
+        if verdicts.len() != texts.len() {
+            anyhow::bail!(
+                "Moderation returned {} results for {} texts",
+                verdicts.len(),
+                texts.len()
+            );
+        }
+
+        let results: Vec<Value> = verdicts
+            .into_iter()
+            .map(|verdict| {
+                let (allowed, categories) = match threshold {
+                    Some(threshold) => {
+                        let over: Vec<String> = verdict
+                            .scores
+                            .iter()
+                            .filter(|(_, score)| **score >= threshold)
+                            .map(|(name, _)| name.clone())
+                            .collect();
+                        (over.is_empty(), over)
+                    }
+                    None => (!verdict.flagged, verdict.categories),
+                };
+                serde_json::json!({
+                    "allowed": allowed,
+                    "flagged": verdict.flagged,
+                    "categories": categories,
+                    "scores": verdict.scores,
+                })
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "allowed": results.iter().all(|r| r["allowed"] == true),
+            "model": model,
+            "results": results,
+        }))
+    }
+
+    fn moderate_openai(&self, texts: &[&str]) -> Result<(Vec<Moderation>, String), anyhow::Error> {
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("OPENAI_API_KEY").ok());
+        if api_key.is_none() && self.base_url.is_none() {
+            anyhow::bail!("Missing OPENAI_API_KEY");
+        }
+        let base_url = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.openai.com/v1")
+            .trim_end_matches('/');
+
+        let client = reqwest::blocking::Client::new();
+        let mut request = client
+            .post(format!("{}/moderations", base_url))
+            .json(&serde_json::json!({ "model": self.model, "input": texts }));
+        if let Some(key) = api_key {
+            request = request.bearer_auth(key);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload
+                .pointer("/error/message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no details");
+            anyhow::bail!("Moderation request failed with status {}: {}", status.as_u16(), message);
+        }
+
+        let verdicts = payload["results"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("Moderation response has no results"))?
+            .iter()
+            .map(|result| Moderation {
+                flagged: result["flagged"].as_bool().unwrap_or(false),
+                categories: result["categories"]
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter(|(_, flagged)| flagged.as_bool() == Some(true))
+                    .map(|(name, _)| name.clone())
+                    .collect(),
+                scores: result["category_scores"]
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(name, score)| Some((name.clone(), score.as_f64()?)))
+                    .collect(),
+            })
+            .collect();
+        let model = payload["model"].as_str().unwrap_or(&self.model).to_string();
+        Ok((verdicts, model))
+    }
+}
+
+impl Default for ModerationTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ── AiMindTool ───────────────────────────────────────────────────────────────
 
-/// Query an AI Mind knowledge base for intelligent responses.
+/// Query a MindsDB AI Mind for answers over its datasources.
+///
+/// The question is sent to the Minds API as an OpenAI-compatible chat
+/// completion with the mind's name as the model. With `create_if_missing`
+/// set, the mind is first registered over `datasources` if the API does not
+/// know it yet.
 ///
 /// Corresponds to Python `AIMindTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiMindTool {
-    /// AI Mind API endpoint.
+    /// AI Mind API endpoint; defaults to `https://mdb.ai`.
     pub api_endpoint: Option<String>,
-    /// API key for authentication.
+    /// API key for authentication; falls back to `MINDS_API_KEY`.
     pub api_key: Option<String>,
     /// Mind name or ID.
     pub mind_name: Option<String>,
+    /// Register the mind over `datasources` when it does not exist.
+    #[serde(default)]
+    pub create_if_missing: bool,
+    /// Datasource configs used by `create_if_missing`, each shaped like
+    /// `{"name", "engine", "description", "connection_data", "tables"}`.
+    #[serde(default)]
+    pub datasources: Vec<Value>,
 }
 
 impl AiMindTool {
@@ -171,6 +1044,8 @@ impl AiMindTool {
             api_endpoint: None,
             api_key: None,
             mind_name: None,
+            create_if_missing: false,
+            datasources: Vec::new(),
         }
     }
 
@@ -189,9 +1064,136 @@ impl AiMindTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("AiMindTool: not yet implemented - requires AI Mind API integration")
+    pub fn with_create_if_missing(mut self, create: bool) -> Self {
+        self.create_if_missing = create;
+        self
+    }
+
+    pub fn with_datasource(mut self, datasource: Value) -> Self {
+        self.datasources.push(datasource);
+        self
+    }
+
+    /// Ask the mind a question.
+    ///
+    /// Returns `{answer, mind}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - The question to ask (alias: `question`).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("query")
+            .or_else(|| args.get("question"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+        let mind = self
+            .mind_name
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("AiMindTool: no mind configured - use with_mind_name()"))?;
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("MINDS_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing MINDS_API_KEY"))?;
+        let endpoint = self
+            .api_endpoint
+            .as_deref()
+            .unwrap_or("https://mdb.ai")
+            .trim_end_matches('/');
+
+        let client = reqwest::blocking::Client::new();
+        if self.create_if_missing {
+            self.ensure_mind(&client, endpoint, &api_key, mind)?;
+        }
+
+        let resp = client
+            .post(format!("{}/api/v1/chat/completions", endpoint))
+            .bearer_auth(&api_key)
+            .json(&serde_json::json!({
+                "model": mind,
+                "messages": [{"role": "user", "content": query}],
+                "stream": false,
+            }))
+            .send()?;
+        let payload = minds_response(resp, mind)?;
+        let answer = payload
+            .pointer("/choices/0/message/content")
+            .and_then(|c| c.as_str())
+            .ok_or_else(|| anyhow::anyhow!("AI Mind response has no choices[0].message.content"))?;
+        Ok(serde_json::json!({ "answer": answer, "mind": mind }))
     }
+
+    /// Create `mind` over the configured datasources unless it already exists.
+    fn ensure_mind(
+        &self,
+        client: &reqwest::blocking::Client,
+        endpoint: &str,
+        api_key: &str,
+        mind: &str,
+    ) -> Result<(), anyhow::Error> {
+        let minds = format!("{}/api/projects/mindsdb/minds", endpoint);
+        let existing = client
+            .get(format!("{}/{}", minds, mind))
+            .bearer_auth(api_key)
+            .send()?;
+        if existing.status() != reqwest::StatusCode::NOT_FOUND {
+            minds_response(existing, mind)?;
+            return Ok(());
+        }
+        if self.datasources.is_empty() {
+            anyhow::bail!(
+                "AI Mind '{}' does not exist and no datasources are configured to create it",
+                mind
+            );
+        }
+
+        let mut names = Vec::with_capacity(self.datasources.len());
+        for datasource in &self.datasources {
+            let name = datasource["name"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("AI Mind datasource is missing a name"))?;
+            let resp = client
+                .post(format!("{}/api/datasources", endpoint))
+                .bearer_auth(api_key)
+                .json(datasource)
+                .send()?;
+            // A datasource left over from an earlier run can be reused as is.
+            if resp.status() != reqwest::StatusCode::CONFLICT {
+                minds_response(resp, mind)?;
+            }
+            names.push(name);
+        }
+
+        let resp = client
+            .post(minds)
+            .bearer_auth(api_key)
+            .json(&serde_json::json!({ "name": mind, "datasources": names }))
+            .send()?;
+        minds_response(resp, mind)?;
+        Ok(())
+    }
+}
+
+/// The JSON body of a Minds API response, or an error naming the mind when
+/// the API does not know it.
+fn minds_response(resp: reqwest::blocking::Response, mind: &str) -> Result<Value, anyhow::Error> {
+    let status = resp.status();
+    let payload: Value = resp.json().unwrap_or(Value::Null);
+    if status == reqwest::StatusCode::NOT_FOUND {
+        anyhow::bail!(
+            "AI Mind '{}' not found; check the mind name, or set create_if_missing to create it",
+            mind
+        );
+    }
+    if !status.is_success() {
+        let message = payload
+            .pointer("/error/message")
+            .or_else(|| payload.get("detail"))
+            .and_then(|m| m.as_str())
+            .unwrap_or("no details");
+        anyhow::bail!("AI Mind request failed with status {}: {}", status.as_u16(), message);
+    }
+    Ok(payload)
 }
 
 impl Default for AiMindTool {
@@ -204,31 +1206,71 @@ impl Default for AiMindTool {
 
 /// Retrieval-Augmented Generation tool for querying document collections.
 ///
+/// A thin wrapper over a [`RagPipeline`] attached with `with_pipeline`:
+/// [`ingest`](Self::ingest) and `run` delegate to it. Start that pipeline
+/// from [`pipeline_builder`](Self::pipeline_builder) to pick the embedding
+/// model by provider string: `with_embedding_provider("ollama")` keeps
+/// embeddings local, `"openai"` (the default) uses OpenAI.
+///
 /// Corresponds to Python `RagTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RagTool {
-    /// Embedding model to use.
+    /// Embedding provider: `openai` or `ollama`.
+    #[serde(default = "default_embedding_provider")]
+    pub embedding_provider: String,
+    /// Embedding model to use; the provider's default when unset.
     pub embedding_model: Option<String>,
+    /// Embedding provider URL; the provider's default when unset.
+    pub embedding_base_url: Option<String>,
     /// Number of documents to retrieve.
     pub top_k: usize,
     /// Data source path or URL.
     pub data_source: Option<String>,
+    /// Pipeline queries are run against.
+    #[serde(skip)]
+    pub pipeline: Option<Arc<RagPipeline>>,
+}
+
+fn default_embedding_provider() -> String {
+    "openai".to_string()
 }
 
 impl RagTool {
     pub fn new() -> Self {
         Self {
+            embedding_provider: default_embedding_provider(),
             embedding_model: None,
+            embedding_base_url: None,
             top_k: 5,
             data_source: None,
+            pipeline: None,
         }
     }
 
+    pub fn with_embedding_provider(mut self, provider: impl Into<String>) -> Self {
+        self.embedding_provider = provider.into();
+        self
+    }
+
     pub fn with_embedding_model(mut self, model: impl Into<String>) -> Self {
         self.embedding_model = Some(model.into());
         self
     }
 
+    pub fn with_embedding_base_url(mut self, url: impl Into<String>) -> Self {
+        self.embedding_base_url = Some(url.into());
+        self
+    }
+
+    /// The embedding service for the configured provider, model and URL.
+    pub fn embedder(&self) -> Result<Arc<dyn EmbeddingService>, anyhow::Error> {
+        embedding_service(
+            &self.embedding_provider,
+            self.embedding_model.as_deref(),
+            self.embedding_base_url.as_deref(),
+        )
+    }
+
     pub fn with_top_k(mut self, k: usize) -> Self {
         self.top_k = k;
         self
@@ -239,10 +1281,64 @@ impl RagTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "RagTool: not yet implemented - requires embedding model and vector store integration"
-        )
+    pub fn with_pipeline(mut self, pipeline: Arc<RagPipeline>) -> Self {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    /// A pipeline builder with this tool's embedder and `top_k` already
+    /// set; add loaders, a chunker and optionally a store, then `build()`.
+    pub fn pipeline_builder(
+        &self,
+    ) -> Result<RagPipelineBuilder<Missing, Set<Arc<dyn EmbeddingService>>>, anyhow::Error> {
+        Ok(RagPipeline::builder()
+            .embedder(self.embedder()?)
+            .top_k(self.top_k))
+    }
+
+    /// Run the attached pipeline's loaders into its store.
+    pub fn ingest(&self) -> Result<IngestSummary, anyhow::Error> {
+        self.attached()?.ingest()
+    }
+
+    fn attached(&self) -> Result<&RagPipeline, anyhow::Error> {
+        self.pipeline
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("RagTool: no pipeline configured - use with_pipeline()"))
+    }
+
+    /// Retrieve the chunks most relevant to a query.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - The question or search text.
+    /// * `top_k` - Override the number of chunks returned.
+    /// * `filter` - Optional [`QueryFilter`] object, e.g.
+    ///   `{"include_sources": ["reports/2024/*"], "date_range": {"field": "published", "from": "2024-01-01"}}`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+
+        let pipeline = self.attached()?;
+
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map(|k| k as usize)
+            .unwrap_or(self.top_k);
+        let filter = match args.get("filter") {
+            Some(Value::Null) | None => None,
+            Some(v) => Some(QueryFilter::from_value(v)?),
+        };
+
+        let result = pipeline.query(query, Some(top_k), filter.as_ref())?;
+        Ok(serde_json::json!({
+            "query": result.query,
+            "count": result.hits.len(),
+            "results": result.hits,
+            "filter": result.filter,
+        }))
     }
 }
 
@@ -252,6 +1348,120 @@ impl Default for RagTool {
     }
 }
 
+// ── EmbeddingTool ────────────────────────────────────────────────────────────
+
+/// Compute text embeddings with any [`EmbeddingService`].
+///
+/// Returns raw vectors for downstream dedupe or clustering, or, in
+/// `similarity` mode, just the cosine similarity of two texts so the
+/// vectors never have to pass through the model's context.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct EmbeddingTool {
+    /// Service that produces the embeddings (not serialized).
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+}
+
+impl std::fmt::Debug for EmbeddingTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddingTool")
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .finish()
+    }
+}
+
+impl EmbeddingTool {
+    pub fn new() -> Self {
+        Self { embedder: None }
+    }
+
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Embed text, or compare two texts.
+    ///
+    /// Returns `{model, dimensions, vectors}` in `embed` mode and
+    /// `{model, similarity}` in `similarity` mode.
+    ///
+    /// # Arguments (in `args`)
+    /// * `mode` - `embed` (default) or `similarity`.
+    /// * `text` - A single text to embed.
+    /// * `texts` - Several texts to embed in one batch; in `similarity`
+    ///   mode, exactly two texts to compare.
+    /// * `text_a`, `text_b` - The pair to compare in `similarity` mode.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let embedder = self
+            .embedder
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("EmbeddingTool: no embedder configured - use with_embedder()"))?;
+        let texts = match args.get("texts") {
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|t| {
+                    t.as_str()
+                        .ok_or_else(|| anyhow::anyhow!("'texts' must be an array of strings"))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            Some(Value::Null) | None => Vec::new(),
+            Some(_) => anyhow::bail!("'texts' must be an array of strings"),
+        };
+
+        match args.get("mode").and_then(|v| v.as_str()).unwrap_or("embed") {
+            "embed" => {
+                let texts = match args.get("text").and_then(|v| v.as_str()) {
+                    Some(text) if texts.is_empty() => vec![text],
+                    Some(_) => anyhow::bail!("Pass either 'text' or 'texts', not both"),
+                    None if texts.is_empty() => {
+                        anyhow::bail!("Missing required argument: text or texts")
+                    }
+                    None => texts,
+                };
+                let vectors = embedder.embed_batch(&texts)?;
+                let dimensions = vectors.first().map_or(embedder.dimensions(), Vec::len);
+                Ok(serde_json::json!({
+                    "model": embedder.model_name(),
+                    "dimensions": dimensions,
+                    "vectors": vectors,
+                }))
+            }
+            "similarity" => {
+                let pair = match (
+                    args.get("text_a").and_then(|v| v.as_str()),
+                    args.get("text_b").and_then(|v| v.as_str()),
+                ) {
+                    (Some(a), Some(b)) => vec![a, b],
+                    _ if texts.len() == 2 => texts,
+                    _ => anyhow::bail!("similarity mode needs text_a and text_b, or two texts"),
+                };
+                let vectors = embedder.embed_batch(&pair)?;
+                let [a, b] = vectors.as_slice() else {
+                    anyhow::bail!("Embedder returned {} vectors for 2 texts", vectors.len());
+                };
+                if a.len() != b.len() {
+                    anyhow::bail!(
+                        "Embedder returned vectors of different sizes ({} and {})",
+                        a.len(),
+                        b.len()
+                    );
+                }
+                Ok(serde_json::json!({
+                    "model": embedder.model_name(),
+                    "similarity": cosine_similarity(a, b),
+                }))
+            }
+            other => anyhow::bail!("Unknown mode '{}': expected embed or similarity", other),
+        }
+    }
+}
+
+impl Default for EmbeddingTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ── LlamaIndexTool ───────────────────────────────────────────────────────────
 
 /// Query data using LlamaIndex (formerly GPT Index) integrations.
@@ -0,0 +1,149 @@
+//! Tesseract CLI backend for `OcrTool`, plus `pdftoppm` for rasterizing PDF
+//! pages.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::missing_binary;
+
+const TESSERACT_HINT: &str = "Install Tesseract (apt install tesseract-ocr, brew install \
+     tesseract, or see https://tesseract-ocr.github.io/tessdoc/Installation.html) or point \
+     tesseract_path at the binary.";
+const PDFTOPPM_HINT: &str =
+    "OCR of PDFs needs poppler's pdftoppm (apt install poppler-utils, brew install poppler).";
+
+/// Text and (for TSV output) blocks recognized on one page.
+pub(super) struct Page {
+    pub text: String,
+    /// `(text, mean word confidence)` per block, in reading order.
+    pub blocks: Vec<(String, f64)>,
+}
+
+/// Run `binary` on `image`, returning one entry per page (multi-page TIFFs
+/// yield several).
+pub(super) fn recognize(
+    binary: &str,
+    image: &Path,
+    languages: &[String],
+    tsv: bool,
+) -> Result<Vec<Page>, anyhow::Error> {
+    let mut command = Command::new(binary);
+    command.arg(image).arg("stdout");
+    if !languages.is_empty() {
+        command.arg("-l").arg(languages.join("+"));
+    }
+    if tsv {
+        command.arg("tsv");
+    }
+    let output = command.output().map_err(|e| missing_binary(binary, TESSERACT_HINT, e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "tesseract failed on '{}': {}",
+            image.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(if tsv {
+        parse_tsv(&stdout)
+    } else {
+        // Tesseract ends every page with a form feed.
+        stdout
+            .split('\u{c}')
+            .map(str::trim)
+            .enumerate()
+            .filter(|(i, text)| *i == 0 || !text.is_empty())
+            .map(|(_, text)| Page {
+                text: text.to_string(),
+                blocks: Vec::new(),
+            })
+            .collect()
+    })
+}
+
+/// One OCR block: its number, words and per-word confidences.
+type TsvBlock = (usize, Vec<String>, Vec<f64>);
+
+/// Group TSV word rows by page and block.
+fn parse_tsv(tsv: &str) -> Vec<Page> {
+    let mut pages: Vec<Vec<TsvBlock>> = Vec::new();
+    for line in tsv.lines().skip(1) {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 12 || fields[0] != "5" {
+            continue;
+        }
+        let (Ok(page @ 1..), Ok(block)) = (fields[1].parse::<usize>(), fields[2].parse::<usize>())
+        else {
+            continue;
+        };
+        let word = fields[11].trim();
+        if word.is_empty() {
+            continue;
+        }
+        while pages.len() < page {
+            pages.push(Vec::new());
+        }
+        let blocks = &mut pages[page - 1];
+        if blocks.last().map(|(b, _, _)| *b) != Some(block) {
+            blocks.push((block, Vec::new(), Vec::new()));
+        }
+        let (_, words, confidences) = blocks.last_mut().expect("pushed above");
+        words.push(word.to_string());
+        if let Ok(conf) = fields[10].parse::<f64>() {
+            if conf >= 0.0 {
+                confidences.push(conf);
+            }
+        }
+    }
+    pages
+        .into_iter()
+        .map(|blocks| {
+            let blocks: Vec<(String, f64)> = blocks
+                .into_iter()
+                .map(|(_, words, confidences)| {
+                    let mean = if confidences.is_empty() {
+                        0.0
+                    } else {
+                        confidences.iter().sum::<f64>() / confidences.len() as f64
+                    };
+                    (words.join(" "), (mean * 100.0).round() / 100.0)
+                })
+                .collect();
+            Page {
+                text: blocks
+                    .iter()
+                    .map(|(text, _)| text.as_str())
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+                blocks,
+            }
+        })
+        .collect()
+}
+
+/// Render every page of `pdf` to a PNG in `out_dir` with `pdftoppm`,
+/// returning the images in page order.
+pub(super) fn rasterize_pdf(pdf: &Path, out_dir: &Path) -> Result<Vec<PathBuf>, anyhow::Error> {
+    let prefix = out_dir.join("page");
+    let output = Command::new("pdftoppm")
+        .args(["-r", "300", "-png"])
+        .arg(pdf)
+        .arg(&prefix)
+        .output()
+        .map_err(|e| missing_binary("pdftoppm", PDFTOPPM_HINT, e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "pdftoppm failed on '{}': {}",
+            pdf.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    // pdftoppm zero-pads page numbers to the page count's width, so a
+    // plain name sort is page order.
+    let mut pages: Vec<PathBuf> = std::fs::read_dir(out_dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+        .collect();
+    pages.sort();
+    Ok(pages)
+}
@@ -0,0 +1,337 @@
+//! Crew definitions for `GenerateCrewaiAutomationTool`: the prompts sent to
+//! the LLM, parsing and validating its reply, and rendering the result.
+//!
+//! Definitions follow crewAI's YAML config layout: `agents` and `tasks`
+//! are maps keyed by name, in declaration order, and each task names the
+//! agent that performs it.
+
+use std::fmt;
+
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+
+/// Output formats the tool can produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Format {
+    Yaml,
+    Json,
+    Python,
+}
+
+impl Format {
+    pub(super) fn parse(name: &str) -> Result<Self, anyhow::Error> {
+        match name.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "json" => Ok(Self::Json),
+            "python" | "py" => Ok(Self::Python),
+            other => {
+                anyhow::bail!("Unknown output format '{}': expected yaml, json or python", other)
+            }
+        }
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+            Self::Python => "python",
+        }
+    }
+
+    /// The format the LLM is asked to answer in; Python is rendered from a
+    /// validated JSON definition rather than generated directly.
+    fn wire(self) -> Self {
+        match self {
+            Self::Python => Self::Json,
+            other => other,
+        }
+    }
+}
+
+/// A map that keeps its keys in document order.
+#[derive(Debug, Clone)]
+pub(super) struct Ordered<T>(pub Vec<(String, T)>);
+
+impl<T> Default for Ordered<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Ordered<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for OrderedVisitor<T> {
+            type Value = Ordered<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map keyed by name")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Ordered(entries))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedVisitor(std::marker::PhantomData))
+    }
+}
+
+impl<T: Serialize> Serialize for Ordered<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct Agent {
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub goal: String,
+    #[serde(default)]
+    pub backstory: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(super) struct Task {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub expected_output: String,
+    #[serde(default)]
+    pub agent: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct CrewSettings {
+    #[serde(default = "default_process")]
+    pub process: String,
+}
+
+fn default_process() -> String {
+    "sequential".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct CrewDefinition {
+    #[serde(default)]
+    pub agents: Ordered<Agent>,
+    #[serde(default)]
+    pub tasks: Ordered<Task>,
+    #[serde(default = "default_crew")]
+    pub crew: CrewSettings,
+}
+
+fn default_crew() -> CrewSettings {
+    CrewSettings {
+        process: default_process(),
+    }
+}
+
+/// The first request for a definition.
+pub(super) fn prompt(format: Format, description: &str) -> String {
+    format!(
+        "Design a crewAI crew for the following goal.\n\n{}\n\n\
+         Reply with only a {} document, no commentary, shaped like:\n{}\n\
+         Rules:\n\
+         - `agents` and `tasks` are maps keyed by snake_case names.\n\
+         - Every agent has a non-empty role, goal and backstory.\n\
+         - Every task has a description, an expected_output, and an `agent` naming one of \
+           the agents.\n\
+         - `context` (optional) lists earlier tasks whose output the task needs.\n\
+         - `crew.process` is `sequential` or `hierarchical`.",
+        description,
+        format.wire().name().to_uppercase(),
+        example(format.wire())
+    )
+}
+
+/// A follow-up request asking the LLM to fix its previous reply.
+pub(super) fn repair_prompt(format: Format, previous: &str, problems: &[String]) -> String {
+    format!(
+        "Your previous crew definition was invalid:\n- {}\n\n\
+         Previous reply:\n{}\n\n\
+         Reply with only the corrected {} document, shaped like:\n{}",
+        problems.join("\n- "),
+        previous,
+        format.wire().name().to_uppercase(),
+        example(format.wire())
+    )
+}
+
+fn example(format: Format) -> &'static str {
+    match format {
+        Format::Json => {
+            r#"{"agents": {"researcher": {"role": "...", "goal": "...", "backstory": "..."}},
+ "tasks": {"research_task": {"description": "...", "expected_output": "...", "agent": "researcher"}},
+ "crew": {"process": "sequential"}}"#
+        }
+        _ => {
+            "agents:\n  researcher:\n    role: ...\n    goal: ...\n    backstory: ...\n\
+             tasks:\n  research_task:\n    description: ...\n    expected_output: ...\n    \
+             agent: researcher\n\
+             crew:\n  process: sequential"
+        }
+    }
+}
+
+/// Parse and validate an LLM reply, returning every problem found.
+pub(super) fn parse(format: Format, reply: &str) -> Result<CrewDefinition, Vec<String>> {
+    let body = strip_fences(reply);
+    let parsed = match format.wire() {
+        Format::Yaml => serde_yaml::from_str(body).map_err(|e| format!("not valid YAML: {}", e)),
+        _ => serde_json::from_str(body).map_err(|e| format!("not valid JSON: {}", e)),
+    };
+    let definition: CrewDefinition = parsed.map_err(|problem| vec![problem])?;
+    let problems = validate(&definition);
+    if problems.is_empty() {
+        Ok(definition)
+    } else {
+        Err(problems)
+    }
+}
+
+fn validate(definition: &CrewDefinition) -> Vec<String> {
+    let mut problems = Vec::new();
+    if definition.agents.0.is_empty() {
+        problems.push("no agents are defined".to_string());
+    }
+    if definition.tasks.0.is_empty() {
+        problems.push("no tasks are defined".to_string());
+    }
+    let mut seen = Vec::new();
+    for (name, agent) in &definition.agents.0 {
+        check_name("agent", name, &seen, &mut problems);
+        let fields = [("role", &agent.role), ("goal", &agent.goal), ("backstory", &agent.backstory)];
+        for (field, value) in fields {
+            if value.trim().is_empty() {
+                problems.push(format!("agent '{}' has no {}", name, field));
+            }
+        }
+        seen.push(name.as_str());
+    }
+    let agents = seen;
+    let mut seen: Vec<&str> = Vec::new();
+    for (name, task) in &definition.tasks.0 {
+        check_name("task", name, &seen, &mut problems);
+        let fields = [
+            ("description", &task.description),
+            ("expected_output", &task.expected_output),
+        ];
+        for (field, value) in fields {
+            if value.trim().is_empty() {
+                problems.push(format!("task '{}' has no {}", name, field));
+            }
+        }
+        if task.agent.trim().is_empty() {
+            problems.push(format!("task '{}' has no agent", name));
+        } else if !agents.contains(&task.agent.as_str()) {
+            problems.push(format!("task '{}' references undefined agent '{}'", name, task.agent));
+        }
+        for dependency in &task.context {
+            if !seen.contains(&dependency.as_str()) {
+                problems.push(format!(
+                    "task '{}' has context '{}', which is not an earlier task",
+                    name, dependency
+                ));
+            }
+        }
+        seen.push(name.as_str());
+    }
+    if !matches!(definition.crew.process.as_str(), "sequential" | "hierarchical") {
+        problems.push(format!(
+            "crew process '{}' must be sequential or hierarchical",
+            definition.crew.process
+        ));
+    }
+    problems
+}
+
+/// Names become Python identifiers and YAML keys, so keep them simple.
+fn check_name(kind: &str, name: &str, seen: &[&str], problems: &mut Vec<String>) {
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        problems.push(format!("{} name '{}' is not a snake_case identifier", kind, name));
+    }
+    if seen.contains(&name) {
+        problems.push(format!("{} '{}' is defined twice", kind, name));
+    }
+}
+
+/// The reply without a surrounding Markdown code fence.
+fn strip_fences(reply: &str) -> &str {
+    let trimmed = reply.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    // Drop the info string (```yaml) and the closing fence.
+    let body = rest.split_once('\n').map_or("", |(_, body)| body);
+    body.trim_end().strip_suffix("```").unwrap_or(body).trim()
+}
+
+/// Render a validated definition in `format`.
+pub(super) fn render(format: Format, definition: &CrewDefinition) -> Result<String, anyhow::Error> {
+    Ok(match format {
+        Format::Yaml => serde_yaml::to_string(definition)?,
+        Format::Json => serde_json::to_string_pretty(definition)?,
+        Format::Python => python(definition),
+    })
+}
+
+fn python(definition: &CrewDefinition) -> String {
+    // JSON string literals are valid Python string literals.
+    let quote = |s: &str| serde_json::to_string(s).unwrap_or_default();
+    let mut out = String::from("from crewai import Agent, Crew, Process, Task\n");
+    for (name, agent) in &definition.agents.0 {
+        out.push_str(&format!(
+            "\n{} = Agent(\n    role={},\n    goal={},\n    backstory={},\n)\n",
+            name,
+            quote(&agent.role),
+            quote(&agent.goal),
+            quote(&agent.backstory)
+        ));
+    }
+    for (name, task) in &definition.tasks.0 {
+        out.push_str(&format!(
+            "\n{} = Task(\n    description={},\n    expected_output={},\n    agent={},\n",
+            name,
+            quote(&task.description),
+            quote(&task.expected_output),
+            task.agent
+        ));
+        if !task.context.is_empty() {
+            out.push_str(&format!("    context=[{}],\n", task.context.join(", ")));
+        }
+        out.push_str(")\n");
+    }
+    let names = |entries: Vec<&String>| {
+        entries
+            .into_iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    out.push_str(&format!(
+        "\ncrew = Crew(\n    agents=[{}],\n    tasks=[{}],\n    process=Process.{},\n)\n",
+        names(definition.agents.0.iter().map(|(n, _)| n).collect()),
+        names(definition.tasks.0.iter().map(|(n, _)| n).collect()),
+        definition.crew.process
+    ));
+    out
+}
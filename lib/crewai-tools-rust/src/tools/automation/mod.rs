@@ -5,9 +5,19 @@
 //! a Python tool class in `crewai_tools`.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::adapters::ZapierActionTool;
+use crate::core::{cosine_similarity, CompletionService, EmbeddingService};
+use super::credit_budget::CreditBudget;
+use super::retry;
+use super::zapier;
+
+mod crew_definition;
+mod schedule;
+
 // ── ComposioTool ─────────────────────────────────────────────────────────────
 
 /// Execute actions through the Composio platform (400+ app integrations).
@@ -62,6 +72,11 @@ impl Default for ComposioTool {
 
 /// Run Apify actors (web scraping, automation, data processing).
 ///
+/// Each call starts a run of the actor, waits up to `wait_secs` for it to
+/// finish (long-polling the run), then reads the items of the run's default
+/// dataset. A run that ends in `FAILED`, `ABORTED` or `TIMED-OUT` is an
+/// error carrying its status message and log URL.
+///
 /// Corresponds to Python `ApifyActorsTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApifyActorsTool {
@@ -69,13 +84,47 @@ pub struct ApifyActorsTool {
     pub api_token: Option<String>,
     /// Actor ID to run (e.g., "apify/web-scraper").
     pub actor_id: Option<String>,
+    /// API root; defaults to `https://api.apify.com`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Memory for the run, in megabytes; the actor's default when unset.
+    #[serde(default)]
+    pub memory_mbytes: Option<u32>,
+    /// Limit on the run's own duration, enforced by Apify in whole seconds.
+    #[serde(default)]
+    pub run_timeout: Option<std::time::Duration>,
+    /// How long to wait for the run to finish, in seconds.
+    #[serde(default = "default_apify_wait_secs")]
+    pub wait_secs: u64,
+    /// Maximum number of dataset items returned.
+    #[serde(default)]
+    pub max_items: Option<usize>,
+    /// Optional item budget. Every dataset item returned counts as one credit.
+    #[serde(skip)]
+    pub credit_budget: Option<CreditBudget>,
+}
+
+fn default_apify_wait_secs() -> u64 {
+    300
 }
 
+/// Run statuses after which a run will not change again.
+const APIFY_TERMINAL: &[&str] = &["SUCCEEDED", "FAILED", "ABORTED", "TIMED-OUT"];
+
+/// Longest `waitForFinish` the Apify API accepts, in seconds.
+const APIFY_MAX_WAIT: u64 = 60;
+
 impl ApifyActorsTool {
     pub fn new() -> Self {
         Self {
             api_token: None,
             actor_id: None,
+            base_url: None,
+            memory_mbytes: None,
+            run_timeout: None,
+            wait_secs: default_apify_wait_secs(),
+            max_items: None,
+            credit_budget: None,
         }
     }
 
@@ -89,11 +138,228 @@ impl ApifyActorsTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("ApifyActorsTool: not yet implemented - requires Apify API integration")
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_memory_mbytes(mut self, mbytes: u32) -> Self {
+        self.memory_mbytes = Some(mbytes);
+        self
+    }
+
+    pub fn with_run_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.run_timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_wait_secs(mut self, secs: u64) -> Self {
+        self.wait_secs = secs;
+        self
+    }
+
+    pub fn with_max_items(mut self, n: usize) -> Self {
+        self.max_items = Some(n);
+        self
+    }
+
+    /// Cap the dataset items this tool may consume.
+    pub fn with_credit_budget(mut self, credits: u64) -> Self {
+        self.credit_budget = Some(CreditBudget::new(credits));
+        self
+    }
+
+    /// Draw from a budget shared with other tool instances.
+    pub fn with_shared_credit_budget(mut self, budget: CreditBudget) -> Self {
+        self.credit_budget = Some(budget);
+        self
+    }
+
+    /// Running total of credits consumed against the budget (0 without one).
+    pub fn credits_used(&self) -> u64 {
+        self.credit_budget.as_ref().map_or(0, |b| b.consumed())
+    }
+
+    /// Run an Apify actor, wait for it, and return its dataset items.
+    ///
+    /// With a budget or `max_items`, the run is started with `maxItems` set
+    /// to the smaller of the two so pay-per-result actors stop early, and
+    /// the dataset fetch is limited to the same count. The result carries
+    /// `budget_exhausted: true` once the budget is used up.
+    ///
+    /// # Arguments (in `args`)
+    /// * `actor_id` - Actor to run (optional if set on struct).
+    /// * `run_input` - JSON input passed to the actor.
+    /// * `max_items` - Override the configured item cap.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let actor_id = args
+            .get("actor_id")
+            .and_then(|v| v.as_str())
+            .or(self.actor_id.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: actor_id"))?;
+
+        let api_token = self
+            .api_token
+            .clone()
+            .or_else(|| std::env::var("APIFY_TOKEN").ok())
+            .or_else(|| std::env::var("APIFY_API_TOKEN").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing APIFY_TOKEN"))?;
+
+        let run_input = args
+            .get("run_input")
+            .cloned()
+            .unwrap_or_else(|| Value::Object(serde_json::Map::new()));
+
+        let budget = self.credit_budget.as_ref();
+        if budget.is_some_and(|b| b.is_exhausted()) {
+            return Ok(serde_json::json!({
+                "actor_id": actor_id,
+                "items": [],
+                "count": 0,
+                "credits_used": 0,
+                "budget_exhausted": true,
+            }));
+        }
+        let max_items = args
+            .get("max_items")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .or(self.max_items);
+        let limit = match (max_items, budget) {
+            (Some(n), Some(b)) => Some(n.min(b.remaining() as usize)),
+            (Some(n), None) => Some(n),
+            (None, Some(b)) => Some(b.remaining() as usize),
+            (None, None) => None,
+        };
+
+        let base = self
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.apify.com")
+            .trim_end_matches('/');
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(self.wait_secs);
+        let wait_for_finish = || {
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            left.as_secs().min(APIFY_MAX_WAIT).to_string()
+        };
+
+        // Long-polled requests hold the connection for up to a minute.
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(APIFY_MAX_WAIT + 30))
+            .build()?;
+        let mut query = vec![("waitForFinish", wait_for_finish())];
+        if let Some(mbytes) = self.memory_mbytes {
+            query.push(("memory", mbytes.to_string()));
+        }
+        if let Some(timeout) = self.run_timeout {
+            query.push(("timeout", timeout.as_secs().to_string()));
+        }
+        if let Some(n) = limit {
+            query.push(("maxItems", n.to_string()));
+        }
+        // The API addresses actors as `user~name`.
+        let mut run = apify_data(
+            client
+                .post(format!("{}/v2/acts/{}/runs", base, actor_id.replace('/', "~")))
+                .bearer_auth(&api_token)
+                .query(&query)
+                .json(&run_input)
+                .send()?,
+        )?;
+        let run_id = run["id"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Apify did not return a run id: {}", run))?
+            .to_string();
+
+        let status = loop {
+            let status = run["status"].as_str().unwrap_or("READY").to_string();
+            if APIFY_TERMINAL.contains(&status.as_str()) {
+                break status;
+            }
+            if std::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "Apify run {} of {} is still {} after {}s; see {}/v2/actor-runs/{}/log",
+                    run_id,
+                    actor_id,
+                    status,
+                    self.wait_secs,
+                    base,
+                    run_id
+                );
+            }
+            run = apify_data(
+                client
+                    .get(format!("{}/v2/actor-runs/{}", base, run_id))
+                    .bearer_auth(&api_token)
+                    .query(&[("waitForFinish", wait_for_finish())])
+                    .send()?,
+            )?;
+        };
+        if status != "SUCCEEDED" {
+            anyhow::bail!(
+                "Apify run {} of {} ended with status {}: {} (log: {}/v2/actor-runs/{}/log)",
+                run_id,
+                actor_id,
+                status,
+                run["statusMessage"].as_str().unwrap_or("no status message"),
+                base,
+                run_id
+            );
+        }
+
+        let dataset_id = run["defaultDatasetId"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Apify run {} has no default dataset", run_id))?;
+        let mut query = vec![("clean", "true".to_string())];
+        if let Some(n) = limit {
+            query.push(("limit", n.to_string()));
+        }
+        let resp = client
+            .get(format!("{}/v2/datasets/{}/items", base, dataset_id))
+            .bearer_auth(&api_token)
+            .query(&query)
+            .send()?
+            .error_for_status()?
+            .json::<Value>()?;
+
+        let mut items = match resp {
+            Value::Array(items) => items,
+            other => vec![other],
+        };
+        let returned = items.len();
+        items.truncate(limit.unwrap_or(returned));
+        let kept = items.len();
+        if let Some(b) = budget {
+            b.charge(kept as u64);
+        }
+        let budget_exhausted = budget.is_some_and(|b| kept < returned || b.is_exhausted());
+
+        Ok(serde_json::json!({
+            "actor_id": actor_id,
+            "run_id": run_id,
+            "status": status,
+            "count": kept,
+            "items": items,
+            "credits_used": kept,
+            "budget_exhausted": budget_exhausted,
+        }))
     }
 }
 
+/// The `data` object of an Apify API response.
+fn apify_data(resp: reqwest::blocking::Response) -> Result<Value, anyhow::Error> {
+    let status = resp.status();
+    let mut payload: Value = resp.json().unwrap_or(Value::Null);
+    if !status.is_success() {
+        let message = payload
+            .pointer("/error/message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("no details");
+        anyhow::bail!("Apify request failed with status {}: {}", status.as_u16(), message);
+    }
+    Ok(payload["data"].take())
+}
+
 impl Default for ApifyActorsTool {
     fn default() -> Self {
         Self::new()
@@ -104,6 +370,12 @@ impl Default for ApifyActorsTool {
 
 /// Execute multiple Zapier actions via the Natural Language Actions API.
 ///
+/// Lists the actions exposed to the API key, or runs one of them from
+/// plain-language instructions. When `allowed_actions` is non-empty, only
+/// those action IDs are listed or run. Pass `preview: true` to have Zapier
+/// resolve the action's fields without running it; the result's
+/// `review_url` shows what would happen.
+///
 /// Corresponds to Python `ZapierActionTools` (plural) in `crewai_tools`.
 /// This differs from the adapter `ZapierActionTool` (singular) by supporting
 /// multi-action orchestration.
@@ -113,6 +385,9 @@ pub struct ZapierActionTools {
     pub api_key: Option<String>,
     /// List of allowed action IDs. If empty, all actions are allowed.
     pub allowed_actions: Vec<String>,
+    /// NLA API root; defaults to `https://nla.zapier.com/api/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl ZapierActionTools {
@@ -120,6 +395,7 @@ impl ZapierActionTools {
         Self {
             api_key: None,
             allowed_actions: Vec::new(),
+            base_url: None,
         }
     }
 
@@ -133,10 +409,69 @@ impl ZapierActionTools {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "ZapierActionTools: not yet implemented - requires Zapier NLA API integration"
-        )
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    fn allows(&self, action_id: &str) -> bool {
+        self.allowed_actions.is_empty() || self.allowed_actions.iter().any(|a| a == action_id)
+    }
+
+    /// The single-action [`ZapierActionTool`] for `action_id`, sharing this
+    /// tool's API key and API root, so the key is configured in one place.
+    /// Fails if `action_id` is not in `allowed_actions`.
+    pub fn action_tool(&self, action_id: &str) -> Result<ZapierActionTool, anyhow::Error> {
+        if !self.allows(action_id) {
+            anyhow::bail!("Zapier action '{}' is not in allowed_actions", action_id);
+        }
+        Ok(ZapierActionTool {
+            api_key: self.api_key.clone(),
+            action_id: Some(action_id.to_string()),
+            base_url: self.base_url.clone(),
+        })
+    }
+
+    /// List the available actions, or execute one.
+    ///
+    /// `list` returns `{actions: [{id, description, params}], count}`;
+    /// `execute` returns `{action_id, status, review_url, result,
+    /// input_params}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `mode` - `list`, or `execute` (the default when `action_id` is given).
+    /// * `action_id` - Action to execute.
+    /// * `instructions` - What the action should do, in plain language.
+    /// * `params` - Optional object of field values overriding the instructions.
+    /// * `preview` - Resolve the action without running it.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let action_id = args.get("action_id").and_then(|v| v.as_str());
+        let mode = args
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or(if action_id.is_some() { "execute" } else { "list" });
+        let client = zapier::Client::connect(self.api_key.as_deref(), self.base_url.as_deref())?;
+
+        match mode {
+            "list" => {
+                let actions: Vec<Value> = client
+                    .actions()?
+                    .into_iter()
+                    .filter(|action| action["id"].as_str().is_some_and(|id| self.allows(id)))
+                    .collect();
+                Ok(serde_json::json!({ "count": actions.len(), "actions": actions }))
+            }
+            "execute" => {
+                let action_id = action_id
+                    .ok_or_else(|| anyhow::anyhow!("Missing required argument: action_id"))?;
+                if !self.allows(action_id) {
+                    anyhow::bail!("Zapier action '{}' is not in allowed_actions", action_id);
+                }
+                let (instructions, params, preview) = zapier::execute_args(&args)?;
+                client.execute(action_id, instructions, &params, preview)
+            }
+            other => anyhow::bail!("Unknown mode '{}': expected list or execute", other),
+        }
     }
 }
 
@@ -151,17 +486,36 @@ impl Default for ZapierActionTools {
 /// Generate crewAI automation configurations (crews, agents, tasks) from
 /// natural language descriptions.
 ///
+/// The configured LLM drafts the crew; its reply must parse and pass
+/// validation (complete agents and tasks, tasks assigned only to defined
+/// agents) before it is returned. An invalid reply gets one repair attempt
+/// with the problems listed back to the model. `python` output is rendered
+/// from the validated definition rather than generated.
+///
 /// Corresponds to Python `GenerateCrewaiAutomationTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GenerateCrewaiAutomationTool {
     /// Output format: "yaml", "python", "json".
     pub output_format: String,
+    /// Model that drafts the crew (not serialized).
+    #[serde(skip)]
+    pub llm: Option<Arc<dyn CompletionService>>,
+}
+
+impl std::fmt::Debug for GenerateCrewaiAutomationTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenerateCrewaiAutomationTool")
+            .field("output_format", &self.output_format)
+            .field("llm", &self.llm.as_ref().map(|l| l.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl GenerateCrewaiAutomationTool {
     pub fn new() -> Self {
         Self {
             output_format: "yaml".to_string(),
+            llm: None,
         }
     }
 
@@ -170,10 +524,61 @@ impl GenerateCrewaiAutomationTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "GenerateCrewaiAutomationTool: not yet implemented - requires LLM code generation integration"
-        )
+    /// Set the model that drafts crews. Any `Fn(&str) -> Result<String>`
+    /// closure works.
+    pub fn with_llm(mut self, llm: Arc<dyn CompletionService>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Generate a crew definition.
+    ///
+    /// Returns `{format, definition, agents, tasks, repaired}`, where
+    /// `definition` is the rendered document and `repaired` tells whether
+    /// the repair attempt was needed.
+    ///
+    /// # Arguments (in `args`)
+    /// * `description` - What the crew should accomplish.
+    /// * `output_format` - Override the configured format.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let description = args
+            .get("description")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: description"))?;
+        let format = crew_definition::Format::parse(
+            args.get("output_format")
+                .and_then(|v| v.as_str())
+                .unwrap_or(&self.output_format),
+        )?;
+        let llm = self
+            .llm
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No LLM configured; use with_llm"))?;
+
+        let reply = llm.complete(&crew_definition::prompt(format, description))?;
+        let (definition, repaired) = match crew_definition::parse(format, &reply) {
+            Ok(definition) => (definition, false),
+            Err(problems) => {
+                log::debug!("Repairing generated crew definition: {}", problems.join("; "));
+                let prompt = crew_definition::repair_prompt(format, &reply, &problems);
+                let second = llm.complete(&prompt)?;
+                let definition = crew_definition::parse(format, &second).map_err(|problems| {
+                    anyhow::anyhow!(
+                        "Generated crew definition is invalid after a repair attempt: {}",
+                        problems.join("; ")
+                    )
+                })?;
+                (definition, true)
+            }
+        };
+
+        Ok(serde_json::json!({
+            "format": format.name(),
+            "definition": crew_definition::render(format, &definition)?,
+            "agents": definition.agents.0.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            "tasks": definition.tasks.0.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            "repaired": repaired,
+        }))
     }
 }
 
@@ -185,29 +590,204 @@ impl Default for GenerateCrewaiAutomationTool {
 
 // ── InvokeCrewaiAutomationTool ───────────────────────────────────────────────
 
-/// Invoke a previously generated or defined crewAI automation pipeline.
+/// Receives each status body while a kicked-off crew is still running.
+pub type ProgressCallback = dyn Fn(&Value) + Send + Sync;
+
+/// Invoke a deployed crewAI Enterprise crew and wait for its result.
+///
+/// Kicks the crew off with the given inputs, then polls its status every
+/// `poll_interval_ms` until it succeeds, fails, or `timeout` passes.
+/// Status checks retry connection errors, 429 and 5xx with backoff, and
+/// every error after the kickoff names the `kickoff_id` so the run can be
+/// picked up again by passing it back in.
 ///
 /// Corresponds to Python `InvokeCrewAIAutomationTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct InvokeCrewaiAutomationTool {
-    /// Path to the automation configuration file.
-    pub config_path: Option<String>,
+    /// Base URL of the deployed crew; falls back to `CREWAI_CREW_API_URL`.
+    pub crew_api_url: Option<String>,
+    /// Bearer token for the crew; falls back to `CREWAI_CREW_BEARER_TOKEN`.
+    pub crew_bearer_token: Option<String>,
+    /// Delay between status checks, in milliseconds.
+    pub poll_interval_ms: u64,
+    /// How long to wait for the crew to finish.
+    pub timeout: std::time::Duration,
+    /// Retries per status check before giving up.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries, in milliseconds.
+    pub backoff_base_ms: u64,
+    /// Called with each status body while the crew is still running (not
+    /// serialized).
+    #[serde(skip)]
+    pub on_progress: Option<Arc<ProgressCallback>>,
+}
+
+impl std::fmt::Debug for InvokeCrewaiAutomationTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InvokeCrewaiAutomationTool")
+            .field("crew_api_url", &self.crew_api_url)
+            .field("crew_bearer_token", &self.crew_bearer_token.as_ref().map(|_| "<redacted>"))
+            .field("poll_interval_ms", &self.poll_interval_ms)
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("backoff_base_ms", &self.backoff_base_ms)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
 }
 
 impl InvokeCrewaiAutomationTool {
     pub fn new() -> Self {
-        Self { config_path: None }
+        Self {
+            crew_api_url: None,
+            crew_bearer_token: None,
+            poll_interval_ms: 2000,
+            timeout: std::time::Duration::from_secs(600),
+            max_retries: 3,
+            backoff_base_ms: 500,
+            on_progress: None,
+        }
     }
 
-    pub fn with_config_path(mut self, path: impl Into<String>) -> Self {
-        self.config_path = Some(path.into());
+    pub fn with_crew_api_url(mut self, url: impl Into<String>) -> Self {
+        self.crew_api_url = Some(url.into());
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "InvokeCrewaiAutomationTool: not yet implemented - requires crew runtime integration"
-        )
+    pub fn with_crew_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.crew_bearer_token = Some(token.into());
+        self
+    }
+
+    pub fn with_poll_interval_ms(mut self, ms: u64) -> Self {
+        self.poll_interval_ms = ms;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn with_backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+
+    /// Report intermediate status bodies (e.g. `{"state": "RUNNING",
+    /// "last_step": {...}}`) to `callback`.
+    pub fn with_progress(mut self, callback: impl Fn(&Value) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Kick off the crew and wait for its result.
+    ///
+    /// Returns `{kickoff_id, state, result}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `inputs` - Object of crew inputs.
+    /// * `kickoff_id` - Resume waiting on an earlier kickoff instead of
+    ///   starting a new one.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let base = self
+            .crew_api_url
+            .clone()
+            .or_else(|| std::env::var("CREWAI_CREW_API_URL").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing CREWAI_CREW_API_URL"))?;
+        let base = base.trim_end_matches('/');
+        let token = self
+            .crew_bearer_token
+            .clone()
+            .or_else(|| std::env::var("CREWAI_CREW_BEARER_TOKEN").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing CREWAI_CREW_BEARER_TOKEN"))?;
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+
+        let kickoff_id = match args.get("kickoff_id").and_then(|v| v.as_str()) {
+            Some(id) => id.to_string(),
+            None => {
+                let inputs = match args.get("inputs") {
+                    Some(Value::Object(inputs)) => Value::Object(inputs.clone()),
+                    Some(Value::Null) | None => Value::Object(serde_json::Map::new()),
+                    Some(_) => anyhow::bail!("'inputs' must be an object"),
+                };
+                let resp = client
+                    .post(format!("{}/kickoff", base))
+                    .bearer_auth(&token)
+                    .json(&serde_json::json!({ "inputs": inputs }))
+                    .send()?;
+                let status = resp.status();
+                let body: Value = resp.json().unwrap_or(Value::Null);
+                if !status.is_success() {
+                    anyhow::bail!("Crew kickoff failed with status {}: {}", status.as_u16(), body);
+                }
+                match body["kickoff_id"].as_str() {
+                    Some(id) => id.to_string(),
+                    None => anyhow::bail!("Crew kickoff returned no kickoff_id: {}", body),
+                }
+            }
+        };
+
+        let deadline = std::time::Instant::now() + self.timeout;
+        let poll_interval = std::time::Duration::from_millis(self.poll_interval_ms);
+        let status_url = format!("{}/status/{}", base, kickoff_id);
+        loop {
+            let resp = retry::send_with_retry(
+                "crewAI status",
+                self.max_retries,
+                self.backoff_base_ms,
+                || client.get(&status_url).bearer_auth(&token),
+            )
+            .map_err(|e| anyhow::anyhow!("Lost track of crew kickoff {}: {}", kickoff_id, e))?;
+            let code = resp.status();
+            let body: Value = resp.json().unwrap_or(Value::Null);
+            if !code.is_success() {
+                anyhow::bail!(
+                    "Status check for crew kickoff {} failed with status {}: {}",
+                    kickoff_id,
+                    code.as_u16(),
+                    body
+                );
+            }
+
+            let state = body["state"].as_str().unwrap_or("").to_ascii_lowercase();
+            match state.as_str() {
+                "success" | "completed" => {
+                    return Ok(serde_json::json!({
+                        "kickoff_id": kickoff_id,
+                        "state": body["state"],
+                        "result": body["result"],
+                    }));
+                }
+                "failed" | "failure" | "error" => {
+                    let detail = ["error", "result", "status"]
+                        .iter()
+                        .find_map(|key| body[*key].as_str().filter(|s| !s.is_empty()))
+                        .map_or_else(|| body.to_string(), str::to_string);
+                    anyhow::bail!("Crew kickoff {} failed: {}", kickoff_id, detail);
+                }
+                _ => {}
+            }
+            if let Some(callback) = &self.on_progress {
+                callback(&body);
+            }
+            if std::time::Instant::now() + poll_interval >= deadline {
+                anyhow::bail!(
+                    "Crew kickoff {} still {} after {:?}; pass kickoff_id to keep waiting",
+                    kickoff_id,
+                    body["state"].as_str().unwrap_or("pending"),
+                    self.timeout
+                );
+            }
+            std::thread::sleep(poll_interval);
+        }
     }
 }
 
@@ -221,17 +801,56 @@ impl Default for InvokeCrewaiAutomationTool {
 
 /// Handle merging of agent outputs and coordinate multi-agent workflows.
 ///
+/// Strategies:
+/// * `concat` joins the outputs under a heading per agent.
+/// * `vote` groups equivalent answers and returns the largest group. Answers
+///   are equivalent when they match after normalizing case, whitespace and
+///   surrounding punctuation, or, with an embedder attached, when their
+///   cosine similarity reaches `similarity_threshold`. Ties go to the
+///   answer given first and are reported with `tie: true`.
+/// * `summarize` asks the attached LLM for one answer that cites its
+///   sources by agent name.
+///
+/// Merging an empty `outputs` list is an error under every strategy.
+///
 /// Corresponds to Python `MergeAgentHandlerTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MergeAgentHandlerTool {
     /// Merge strategy: "concat", "summarize", "vote".
     pub merge_strategy: String,
+    /// Cosine similarity at which `vote` treats two answers as the same.
+    #[serde(default = "default_similarity_threshold")]
+    pub similarity_threshold: f32,
+    /// Model used by `summarize` (not serialized).
+    #[serde(skip)]
+    pub llm: Option<Arc<dyn CompletionService>>,
+    /// Embedder used by `vote` to match paraphrased answers (not serialized).
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+}
+
+fn default_similarity_threshold() -> f32 {
+    0.9
+}
+
+impl std::fmt::Debug for MergeAgentHandlerTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeAgentHandlerTool")
+            .field("merge_strategy", &self.merge_strategy)
+            .field("similarity_threshold", &self.similarity_threshold)
+            .field("llm", &self.llm.as_ref().map(|l| l.model_name().to_string()))
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl MergeAgentHandlerTool {
     pub fn new() -> Self {
         Self {
             merge_strategy: "concat".to_string(),
+            similarity_threshold: default_similarity_threshold(),
+            llm: None,
+            embedder: None,
         }
     }
 
@@ -240,15 +859,662 @@ impl MergeAgentHandlerTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "MergeAgentHandlerTool: not yet implemented - requires multi-agent coordination"
-        )
+    pub fn with_similarity_threshold(mut self, threshold: f32) -> Self {
+        self.similarity_threshold = threshold;
+        self
+    }
+
+    /// Set the model that writes `summarize` answers. Any
+    /// `Fn(&str) -> Result<String>` closure works.
+    pub fn with_llm(mut self, llm: Arc<dyn CompletionService>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Merge the outputs of several agents.
+    ///
+    /// Every strategy returns `{strategy, result, agents, count}`, where
+    /// `agents` are the agents behind `result`. `vote` adds `tally: [{answer,
+    /// agents, votes}]` (largest first) and `tie`; `summarize` adds `cited`,
+    /// the agents the summary names.
+    ///
+    /// # Arguments (in `args`)
+    /// * `outputs` - Array of `{agent, content}` objects.
+    /// * `strategy` - Override the configured strategy.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let items = args
+            .get("outputs")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: outputs"))?;
+        if items.is_empty() {
+            anyhow::bail!("No outputs to merge");
+        }
+        let outputs = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let content = item["content"]
+                    .as_str()
+                    .ok_or_else(|| anyhow::anyhow!("outputs[{}] has no content", i))?;
+                let agent = item["agent"]
+                    .as_str()
+                    .map_or_else(|| format!("agent_{}", i + 1), str::to_string);
+                Ok((agent, content))
+            })
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        let strategy = args
+            .get("strategy")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.merge_strategy);
+        let agents: Vec<&str> = outputs.iter().map(|(agent, _)| agent.as_str()).collect();
+
+        match strategy {
+            "concat" => Ok(serde_json::json!({
+                "strategy": strategy,
+                "result": outputs
+                    .iter()
+                    .map(|(agent, content)| format!("## {}\n\n{}", agent, content.trim()))
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+                "agents": agents,
+                "count": outputs.len(),
+            })),
+            "vote" => self.vote(&outputs),
+            "summarize" => {
+                let llm = self
+                    .llm
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("summarize needs an LLM; use with_llm"))?;
+                let mut prompt = String::from(
+                    "Merge the answers below into one answer. Resolve disagreements, and cite \
+                     the agents each point comes from by name in square brackets, e.g. \
+                     [researcher].\n",
+                );
+                for (agent, content) in &outputs {
+                    prompt.push_str(&format!("\n[{}]\n{}\n", agent, content.trim()));
+                }
+                let summary = llm.complete(&prompt)?;
+                let cited: Vec<&str> = agents
+                    .iter()
+                    .copied()
+                    .filter(|agent| summary.contains(agent))
+                    .collect();
+                Ok(serde_json::json!({
+                    "strategy": strategy,
+                    "result": summary,
+                    "agents": agents,
+                    "cited": cited,
+                    "count": outputs.len(),
+                }))
+            }
+            other => anyhow::bail!(
+                "Unknown merge strategy '{}': expected concat, vote or summarize",
+                other
+            ),
+        }
+    }
+
+    fn vote(&self, outputs: &[(String, &str)]) -> Result<Value, anyhow::Error> {
+        let normalized: Vec<String> = outputs.iter().map(|(_, c)| normalize_answer(c)).collect();
+        let vectors = match &self.embedder {
+            Some(embedder) => {
+                let texts: Vec<&str> = outputs.iter().map(|(_, c)| *c).collect();
+                Some(embedder.embed_batch(&texts)?)
+            }
+            None => None,
+        };
+
+        // Each group holds indexes into `outputs`; its first member speaks
+        // for it.
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for i in 0..outputs.len() {
+            let same = |j: usize| {
+                normalized[i] == normalized[j]
+                    || vectors.as_ref().is_some_and(|v| {
+                        cosine_similarity(&v[i], &v[j]) >= self.similarity_threshold
+                    })
+            };
+            match groups.iter_mut().find(|group| same(group[0])) {
+                Some(group) => group.push(i),
+                None => groups.push(vec![i]),
+            }
+        }
+        // Stable, so equal-sized groups keep first-given order.
+        groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+        let tie = groups.len() > 1 && groups[0].len() == groups[1].len();
+        let agents_of =
+            |group: &[usize]| group.iter().map(|&i| outputs[i].0.as_str()).collect::<Vec<_>>();
+        let tally: Vec<Value> = groups
+            .iter()
+            .map(|group| {
+                serde_json::json!({
+                    "answer": outputs[group[0]].1,
+                    "agents": agents_of(group),
+                    "votes": group.len(),
+                })
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "strategy": "vote",
+            "result": outputs[groups[0][0]].1,
+            "agents": agents_of(&groups[0]),
+            "tally": tally,
+            "tie": tie,
+            "count": outputs.len(),
+        }))
     }
 }
 
+/// An answer reduced to what a vote compares: lowercase, single-spaced,
+/// without surrounding punctuation or quotes.
+fn normalize_answer(answer: &str) -> String {
+    answer
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|c: char| c.is_ascii_punctuation())
+        .to_lowercase()
+}
+
 impl Default for MergeAgentHandlerTool {
     fn default() -> Self {
         Self::new()
     }
 }
+
+// ── WebhookTool ──────────────────────────────────────────────────────────────
+
+/// Call an HTTP webhook with a JSON payload.
+///
+/// A lightweight alternative to Zapier or Composio for internal endpoints.
+/// Calls may only go to the host of the configured `url` or to a host in
+/// `allowed_hosts` (`*.example.com` matches subdomains), so a URL smuggled
+/// into the agent's arguments cannot reach arbitrary endpoints. Connection
+/// errors, 429 and 5xx responses are retried with exponential backoff;
+/// other responses, including 4xx, are returned as they are. Credentials
+/// are never serialized and `Debug` redacts them.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct WebhookTool {
+    /// Webhook URL.
+    pub url: Option<String>,
+    /// HTTP method.
+    pub method: String,
+    /// Headers sent with every call.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Bearer token (never serialized).
+    #[serde(default, skip_serializing)]
+    pub bearer_token: Option<String>,
+    /// Basic auth username.
+    #[serde(default)]
+    pub basic_username: Option<String>,
+    /// Basic auth password (never serialized).
+    #[serde(default, skip_serializing)]
+    pub basic_password: Option<String>,
+    /// Hosts calls may go to, besides the configured URL's.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    /// Request timeout.
+    pub timeout: std::time::Duration,
+    /// Retries for connection errors, 429 and 5xx.
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries, in milliseconds.
+    pub backoff_base_ms: u64,
+}
+
+impl std::fmt::Debug for WebhookTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |secret: &Option<String>| secret.as_ref().map(|_| "<redacted>");
+        f.debug_struct("WebhookTool")
+            .field("url", &self.url)
+            .field("method", &self.method)
+            .field("headers", &self.headers)
+            .field("bearer_token", &redacted(&self.bearer_token))
+            .field("basic_username", &self.basic_username)
+            .field("basic_password", &redacted(&self.basic_password))
+            .field("allowed_hosts", &self.allowed_hosts)
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("backoff_base_ms", &self.backoff_base_ms)
+            .finish()
+    }
+}
+
+impl WebhookTool {
+    pub fn new() -> Self {
+        Self {
+            url: None,
+            method: "POST".to_string(),
+            headers: HashMap::new(),
+            bearer_token: None,
+            basic_username: None,
+            basic_password: None,
+            allowed_hosts: Vec::new(),
+            timeout: std::time::Duration::from_secs(30),
+            max_retries: 2,
+            backoff_base_ms: 500,
+        }
+    }
+
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        self.method = method.into();
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: Option<String>,
+    ) -> Self {
+        self.basic_username = Some(username.into());
+        self.basic_password = password;
+        self
+    }
+
+    pub fn with_allowed_hosts(mut self, hosts: Vec<String>) -> Self {
+        self.allowed_hosts = hosts;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn with_backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+
+    /// Whether calls may go to `host`.
+    fn allows(&self, host: &str) -> bool {
+        let configured = self
+            .url
+            .as_deref()
+            .and_then(|url| reqwest::Url::parse(url).ok())
+            .and_then(|url| url.host_str().map(str::to_ascii_lowercase));
+        configured.as_deref() == Some(host)
+            || self.allowed_hosts.iter().any(|allowed| {
+                let allowed = allowed.to_ascii_lowercase();
+                match allowed.strip_prefix("*.") {
+                    Some(domain) => host
+                        .strip_suffix(domain)
+                        .is_some_and(|sub| sub.ends_with('.')),
+                    None => allowed == host,
+                }
+            })
+    }
+
+    /// Call the webhook.
+    ///
+    /// Returns `{status, headers, body}`, with `body` parsed as JSON when
+    /// it is JSON and as text otherwise.
+    ///
+    /// # Arguments (in `args`)
+    /// * `payload` - JSON body. For `GET` and `DELETE`, an object payload
+    ///   is sent as query parameters instead.
+    /// * `url` - Override the configured URL; its host must be allowed.
+    /// * `method` - Override the configured method.
+    /// * `headers` - Object of extra headers for this call.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .or(self.url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| anyhow::anyhow!("Invalid webhook URL '{}': {}", url, e))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            anyhow::bail!("Webhook URL must be http or https, not '{}'", url.scheme());
+        }
+        let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+        if !self.allows(&host) {
+            anyhow::bail!("Webhook host '{}' is not in allowed_hosts", host);
+        }
+        let method = args
+            .get("method")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.method)
+            .to_ascii_uppercase();
+        let method = reqwest::Method::from_bytes(method.as_bytes())
+            .map_err(|_| anyhow::anyhow!("Invalid HTTP method '{}'", method))?;
+        let mut headers = self.headers.clone();
+        if let Some(extra) = args.get("headers").and_then(|v| v.as_object()) {
+            for (name, value) in extra {
+                let value = value
+                    .as_str()
+                    .ok_or_else(|| anyhow::anyhow!("Header '{}' must be a string", name))?;
+                headers.insert(name.clone(), value.to_string());
+            }
+        }
+        let payload = args.get("payload").filter(|p| !p.is_null());
+        let as_query = matches!(method, reqwest::Method::GET | reqwest::Method::DELETE);
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            // A redirect could lead off the allowlist.
+            .redirect(reqwest::redirect::Policy::none())
+            .build()?;
+        let resp = retry::send_with_retry("Webhook", self.max_retries, self.backoff_base_ms, || {
+            let mut request = client.request(method.clone(), url.clone());
+            for (name, value) in &headers {
+                request = request.header(name.as_str(), value.as_str());
+            }
+            if let Some(token) = &self.bearer_token {
+                request = request.bearer_auth(token);
+            } else if let Some(username) = &self.basic_username {
+                request = request.basic_auth(username, self.basic_password.as_ref());
+            }
+            match payload {
+                Some(Value::Object(fields)) if as_query => {
+                    let query: Vec<(&str, String)> = fields
+                        .iter()
+                        .map(|(k, v)| {
+                            (k.as_str(), v.as_str().map_or_else(|| v.to_string(), String::from))
+                        })
+                        .collect();
+                    request.query(&query)
+                }
+                Some(_) if as_query => request,
+                Some(body) => request.json(body),
+                None => request,
+            }
+        })?;
+
+        let status = resp.status().as_u16();
+        let response_headers: serde_json::Map<String, Value> = resp
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    Value::from(String::from_utf8_lossy(value.as_bytes()).into_owned()),
+                )
+            })
+            .collect();
+        let text = resp.text()?;
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+        Ok(serde_json::json!({
+            "status": status,
+            "headers": response_headers,
+            "body": body,
+        }))
+    }
+}
+
+impl Default for WebhookTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── CronScheduleTool ─────────────────────────────────────────────────────────
+
+/// Runs a scheduled job: `(tool, args)` to the tool's result.
+pub type JobExecutor = dyn Fn(&str, &Value) -> Result<Value, anyhow::Error> + Send + Sync;
+
+/// Schedule delayed or recurring tool invocations.
+///
+/// Agents use `run` to schedule, list and cancel jobs, which are persisted
+/// to a JSON file at `store_path`. Nothing runs on its own: the host
+/// application calls [`run_due`](Self::run_due) periodically, which hands
+/// each due job to the executor set with `with_executor` and then
+/// reschedules or drops it. A recurring job that missed several runs while
+/// the host was down fires once, then continues from the current time.
+///
+/// Due jobs are rescheduled before they are executed, so a crash during
+/// execution skips that run instead of repeating it.
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use crewai_tools::{CronScheduleTool, InvokeCrewaiAutomationTool};
+///
+/// let crew = InvokeCrewaiAutomationTool::new();
+/// let scheduler = CronScheduleTool::new().with_executor(move |tool, args| match tool {
+///     "invoke_crewai_automation" => {
+///         let args: HashMap<_, _> = serde_json::from_value(args.clone())?;
+///         crew.run(args)
+///     }
+///     other => anyhow::bail!("Unknown tool '{}'", other),
+/// });
+/// scheduler.run_due()?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CronScheduleTool {
+    /// JSON file holding pending jobs.
+    pub store_path: std::path::PathBuf,
+    /// Runs due jobs (not serialized).
+    #[serde(skip)]
+    pub executor: Option<Arc<JobExecutor>>,
+}
+
+impl std::fmt::Debug for CronScheduleTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CronScheduleTool")
+            .field("store_path", &self.store_path)
+            .field("executor", &self.executor.is_some())
+            .finish()
+    }
+}
+
+impl CronScheduleTool {
+    pub fn new() -> Self {
+        Self {
+            store_path: "scheduled_jobs.json".into(),
+            executor: None,
+        }
+    }
+
+    pub fn with_store_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.store_path = path.into();
+        self
+    }
+
+    pub fn with_executor(
+        mut self,
+        executor: impl Fn(&str, &Value) -> Result<Value, anyhow::Error> + Send + Sync + 'static,
+    ) -> Self {
+        self.executor = Some(Arc::new(executor));
+        self
+    }
+
+    /// Schedule, list or cancel jobs.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `schedule` (default), `list` or `cancel`.
+    /// * `schedule` - When to run: an RFC 3339 timestamp (once) or a cron
+    ///   expression such as `0 9 * * 1-5` (recurring, UTC).
+    /// * `delay_seconds` - Run once this many seconds from now, instead of
+    ///   `schedule`.
+    /// * `tool` - Name of the tool to invoke.
+    /// * `args` - Object of arguments for the tool.
+    /// * `job_id` - Job to cancel.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let action = args.get("action").and_then(|v| v.as_str()).unwrap_or("schedule");
+        match action {
+            "schedule" => self.schedule(&args, chrono::Utc::now()),
+            "list" => {
+                let store = schedule::Store::load(&self.store_path)?;
+                Ok(serde_json::json!({ "jobs": store.jobs }))
+            }
+            "cancel" => {
+                let id = args
+                    .get("job_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Missing required argument: job_id"))?;
+                let mut store = schedule::Store::load(&self.store_path)?;
+                let index = store
+                    .jobs
+                    .iter()
+                    .position(|job| job.id == id)
+                    .ok_or_else(|| anyhow::anyhow!("No scheduled job '{}'", id))?;
+                let job = store.jobs.remove(index);
+                store.save(&self.store_path)?;
+                Ok(serde_json::json!({ "cancelled": job }))
+            }
+            other => {
+                anyhow::bail!("Unknown action '{}': expected schedule, list or cancel", other)
+            }
+        }
+    }
+
+    fn schedule(
+        &self,
+        args: &HashMap<String, Value>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Value, anyhow::Error> {
+        let tool = args
+            .get("tool")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: tool"))?;
+        let tool_args = args.get("args").cloned().unwrap_or_else(|| serde_json::json!({}));
+        if !tool_args.is_object() {
+            anyhow::bail!("'args' must be an object of tool arguments");
+        }
+        let (spec, next_run) = match args.get("delay_seconds").and_then(|v| v.as_u64()) {
+            Some(delay) => {
+                let at = i64::try_from(delay)
+                    .ok()
+                    .and_then(chrono::Duration::try_seconds)
+                    .and_then(|delay| now.checked_add_signed(delay))
+                    .ok_or_else(|| anyhow::anyhow!("delay_seconds {} is too large", delay))?;
+                (at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true), Some(at))
+            }
+            None => {
+                let spec = args.get("schedule").and_then(|v| v.as_str()).ok_or_else(|| {
+                    anyhow::anyhow!("Missing required argument: schedule (or delay_seconds)")
+                })?;
+                (spec.trim().to_string(), schedule::Schedule::parse(spec)?.first_run(now))
+            }
+        };
+        let next_run =
+            next_run.ok_or_else(|| anyhow::anyhow!("Schedule '{}' never fires", spec))?;
+
+        let mut store = schedule::Store::load(&self.store_path)?;
+        store.next_id += 1;
+        let job = schedule::Job {
+            id: format!("job-{}", store.next_id),
+            tool: tool.to_string(),
+            args: tool_args,
+            schedule: spec,
+            next_run,
+            created_at: now,
+            last_run: None,
+            runs: 0,
+            last_error: None,
+        };
+        store.jobs.push(job.clone());
+        store.save(&self.store_path)?;
+        Ok(serde_json::json!({ "scheduled": job }))
+    }
+
+    /// Execute every job that is due now. See [`run_due_at`](Self::run_due_at).
+    pub fn run_due(&self) -> Result<Vec<Value>, anyhow::Error> {
+        self.run_due_at(std::time::SystemTime::now())
+    }
+
+    /// Execute every job due at `now` and reschedule recurring ones from
+    /// `now`. Returns one report per job run: `{job_id, tool, ok, result}`
+    /// on success or `{job_id, tool, ok, error}` when the executor failed.
+    /// Executor failures are recorded on the job and do not stop the rest.
+    pub fn run_due_at(&self, now: std::time::SystemTime) -> Result<Vec<Value>, anyhow::Error> {
+        let executor = self.executor.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("No job executor configured; set one with with_executor")
+        })?;
+        let now = chrono::DateTime::<chrono::Utc>::from(now);
+
+        // Claim the due jobs before running any of them.
+        let mut store = schedule::Store::load(&self.store_path)?;
+        let mut due = Vec::new();
+        store.jobs.retain_mut(|job| {
+            if job.next_run > now {
+                return true;
+            }
+            due.push(job.clone());
+            let next = schedule::Schedule::parse(&job.schedule)
+                .ok()
+                .and_then(|schedule| schedule.next_run(now));
+            match next {
+                Some(next) => {
+                    job.next_run = next;
+                    true
+                }
+                None => false,
+            }
+        });
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+        store.save(&self.store_path)?;
+
+        let mut reports = Vec::new();
+        let mut outcomes = Vec::new();
+        for job in &due {
+            let outcome = executor(&job.tool, &job.args);
+            reports.push(match &outcome {
+                Ok(result) => serde_json::json!({
+                    "job_id": job.id,
+                    "tool": job.tool,
+                    "ok": true,
+                    "result": result,
+                }),
+                Err(e) => {
+                    log::warn!("Scheduled job {} ({}) failed: {}", job.id, job.tool, e);
+                    serde_json::json!({
+                        "job_id": job.id,
+                        "tool": job.tool,
+                        "ok": false,
+                        "error": e.to_string(),
+                    })
+                }
+            });
+            outcomes.push((job.id.clone(), outcome.err().map(|e| e.to_string())));
+        }
+
+        // Record the runs on jobs that are still scheduled.
+        let mut store = schedule::Store::load(&self.store_path)?;
+        for (id, error) in outcomes {
+            if let Some(job) = store.jobs.iter_mut().find(|job| job.id == id) {
+                job.last_run = Some(now);
+                job.runs += 1;
+                job.last_error = error;
+            }
+        }
+        store.save(&self.store_path)?;
+        Ok(reports)
+    }
+}
+
+impl Default for CronScheduleTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
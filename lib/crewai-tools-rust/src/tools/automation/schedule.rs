@@ -0,0 +1,225 @@
+//! Schedules and the job store for `CronScheduleTool`.
+//!
+//! A schedule is either an RFC 3339 timestamp (run once) or a five-field
+//! cron expression (`minute hour day-of-month month day-of-week`),
+//! evaluated in UTC. Fields take `*`, numbers, `a-b` ranges, `/n` steps and
+//! comma lists; `@hourly`, `@daily`, `@weekly`, `@monthly` and `@yearly`
+//! are accepted as shorthands.
+
+use std::path::Path;
+
+use chrono::{DateTime, Datelike, Duration, TimeZone, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// When a job runs.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Schedule {
+    Once(DateTime<Utc>),
+    Cron(Cron),
+}
+
+impl Schedule {
+    pub(super) fn parse(spec: &str) -> Result<Self, anyhow::Error> {
+        let spec = spec.trim();
+        if let Ok(at) = DateTime::parse_from_rfc3339(spec) {
+            return Ok(Self::Once(at.with_timezone(&Utc)));
+        }
+        Cron::parse(spec).map(Self::Cron).map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid schedule '{}': expected an RFC 3339 timestamp or a cron expression ({})",
+                spec,
+                e
+            )
+        })
+    }
+
+    /// The first run of a job scheduled at `now`. One-shot timestamps in
+    /// the past are kept as they are, so the job fires on the next check.
+    pub(super) fn first_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Once(at) => Some(*at),
+            Self::Cron(cron) => cron.next_after(now),
+        }
+    }
+
+    /// The run after one that fired at `now`, or `None` when the job is
+    /// done. Runs missed while the host was down are skipped, not replayed.
+    pub(super) fn next_run(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Once(_) => None,
+            Self::Cron(cron) => cron.next_after(now),
+        }
+    }
+}
+
+/// A parsed cron expression, one bit per allowed value.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Cron {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day-of-month / day-of-week were `*`: cron matches a day when
+    /// either restricted field does, or the one that is restricted.
+    any_day: bool,
+    any_weekday: bool,
+}
+
+impl Cron {
+    fn parse(spec: &str) -> Result<Self, String> {
+        let spec = match spec {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = spec.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(format!("expected 5 fields, got {}", fields.len()));
+        };
+        let mut weekdays = field(weekday, 0, 7)?;
+        // Both 0 and 7 mean Sunday.
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            minutes: field(minute, 0, 59)?,
+            hours: field(hour, 0, 23)?,
+            days: field(day, 1, 31)?,
+            months: field(month, 1, 12)?,
+            weekdays,
+            any_day: day.starts_with('*'),
+            any_weekday: weekday.starts_with('*'),
+        })
+    }
+
+    fn matches_day(&self, t: DateTime<Utc>) -> bool {
+        let day = self.days & (1 << t.day()) != 0;
+        let weekday = self.weekdays & (1 << t.weekday().num_days_from_sunday()) != 0;
+        match (self.any_day, self.any_weekday) {
+            (true, true) => true,
+            (true, false) => weekday,
+            (false, true) => day,
+            (false, false) => day || weekday,
+        }
+    }
+
+    /// The first matching minute strictly after `after`, searching up to
+    /// five years ahead (`None` for expressions like `0 0 31 2 *`).
+    pub(super) fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut t = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = after + Duration::days(5 * 366);
+        while t <= limit {
+            if self.months & (1 << t.month()) == 0 {
+                let (year, month) = match t.month() {
+                    12 => (t.year() + 1, 1),
+                    m => (t.year(), m + 1),
+                };
+                t = Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).single()?;
+            } else if !self.matches_day(t) {
+                t = t.with_hour(0)?.with_minute(0)? + Duration::days(1);
+            } else if self.hours & (1 << t.hour()) == 0 {
+                t = t.with_minute(0)? + Duration::hours(1);
+            } else if self.minutes & (1 << t.minute()) == 0 {
+                t += Duration::minutes(1);
+            } else {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+/// Parse one cron field into a bitmask of the values it allows.
+fn field(spec: &str, min: u32, max: u32) -> Result<u64, String> {
+    let number = |s: &str| {
+        s.parse::<u32>()
+            .ok()
+            .filter(|n| (min..=max).contains(n))
+            .ok_or_else(|| format!("'{}' is not a number from {} to {}", s, min, max))
+    };
+    let mut bits = 0u64;
+    for part in spec.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => match step.parse::<u32>() {
+                Ok(step @ 1..) => (range, Some(step)),
+                _ => return Err(format!("invalid step in '{}'", part)),
+            },
+            None => (part, None),
+        };
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (number(start)?, number(end)?),
+            // `5/15` means "from 5, every 15".
+            None if step.is_some() => (number(range)?, max),
+            None => {
+                let n = number(range)?;
+                (n, n)
+            }
+        };
+        if start > end {
+            return Err(format!("range '{}' runs backwards", range));
+        }
+        for n in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            bits |= 1 << n;
+        }
+    }
+    Ok(bits)
+}
+
+/// A pending job, as persisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct Job {
+    pub id: String,
+    pub tool: String,
+    pub args: Value,
+    /// The schedule as given: a timestamp or a cron expression.
+    pub schedule: String,
+    pub next_run: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub last_run: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub runs: u64,
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+/// The store file: pending jobs plus the counter for job ids.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct Store {
+    #[serde(default)]
+    pub next_id: u64,
+    #[serde(default)]
+    pub jobs: Vec<Job>,
+}
+
+impl Store {
+    /// Load `path`; a missing file is an empty store.
+    pub(super) fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| {
+                anyhow::anyhow!("Invalid job store '{}': {}", path.display(), e)
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the store through a temporary file, so a crash mid-write
+    /// leaves the previous version in place.
+    pub(super) fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
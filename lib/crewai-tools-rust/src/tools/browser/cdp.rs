@@ -0,0 +1,125 @@
+//! A minimal blocking Chrome DevTools Protocol client for the hosted
+//! browser tools: commands and events over one WebSocket, in flattened
+//! session mode, with a deadline for every read.
+
+use std::collections::VecDeque;
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+/// Events kept while waiting for command replies; older ones are dropped.
+const MAX_QUEUED_EVENTS: usize = 1000;
+
+pub(super) struct Cdp {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    next_id: u64,
+    deadline: Instant,
+    events: VecDeque<Value>,
+}
+
+impl Cdp {
+    /// Connect to a browser's DevTools WebSocket. Every later read fails
+    /// once `deadline` passes.
+    pub(super) fn connect(url: &str, deadline: Instant) -> Result<Self, anyhow::Error> {
+        let (socket, _) = tungstenite::connect(url)
+            .map_err(|e| anyhow::anyhow!("Could not connect to the remote browser: {}", e))?;
+        Ok(Self {
+            socket,
+            next_id: 0,
+            deadline,
+            events: VecDeque::new(),
+        })
+    }
+
+    /// Send `method` (to the page `session`, or the browser when `None`)
+    /// and wait for its result. Events that arrive meanwhile are queued
+    /// for [`Cdp::next_event`].
+    pub(super) fn call(
+        &mut self,
+        session: Option<&str>,
+        method: &str,
+        params: Value,
+    ) -> Result<Value, anyhow::Error> {
+        self.next_id += 1;
+        let id = self.next_id;
+        let mut command = serde_json::json!({"id": id, "method": method, "params": params});
+        if let Some(session) = session {
+            command["sessionId"] = Value::from(session);
+        }
+        self.socket.send(Message::Text(command.to_string()))?;
+        loop {
+            let message = self.read(method)?;
+            if message["id"].as_u64() == Some(id) {
+                if let Some(error) = message.get("error") {
+                    anyhow::bail!(
+                        "Browser command {} failed: {}",
+                        method,
+                        error["message"].as_str().unwrap_or("unknown error")
+                    );
+                }
+                return Ok(message["result"].clone());
+            }
+            if message.get("method").is_some() {
+                if self.events.len() == MAX_QUEUED_EVENTS {
+                    self.events.pop_front();
+                }
+                self.events.push_back(message);
+            }
+        }
+    }
+
+    /// The next event, queued or read; `waiting_for` names it in timeouts.
+    pub(super) fn next_event(&mut self, waiting_for: &str) -> Result<Value, anyhow::Error> {
+        if let Some(event) = self.events.pop_front() {
+            return Ok(event);
+        }
+        loop {
+            let message = self.read(waiting_for)?;
+            if message.get("method").is_some() {
+                return Ok(message);
+            }
+        }
+    }
+
+    /// Read the next JSON message before the deadline.
+    fn read(&mut self, waiting_for: &str) -> Result<Value, anyhow::Error> {
+        let timed_out = || anyhow::anyhow!("Timed out waiting for {}", waiting_for);
+        let remaining = self
+            .deadline
+            .checked_duration_since(Instant::now())
+            .filter(|d| !d.is_zero())
+            .ok_or_else(timed_out)?;
+        let stream = match self.socket.get_mut() {
+            MaybeTlsStream::Plain(stream) => stream,
+            MaybeTlsStream::Rustls(stream) => &mut stream.sock,
+            _ => anyhow::bail!("Unsupported WebSocket stream"),
+        };
+        stream.set_read_timeout(Some(remaining.max(Duration::from_millis(1))))?;
+        loop {
+            match self.socket.read() {
+                Ok(Message::Text(text)) => return Ok(serde_json::from_str(&text)?),
+                Ok(Message::Close(_)) => anyhow::bail!("The remote browser closed the connection"),
+                Ok(_) => continue,
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return Err(timed_out())
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for Cdp {
+    fn drop(&mut self) {
+        let _ = self.socket.close(None);
+        let _ = self.socket.flush();
+    }
+}
@@ -0,0 +1,167 @@
+//! Launching a local Chromium for `PlaywrightBrowserTool`: find the binary,
+//! start it with a DevTools port, and read the WebSocket URL it prints.
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::time::Instant;
+
+const CHROMIUM_HINT: &str = "Install Chromium or Chrome (apt install chromium, brew install \
+     --cask chromium), set CHROME_PATH, or point chrome_path at the binary.";
+
+/// Binary names tried on `PATH`, in order.
+const CANDIDATES: &[&str] = &[
+    "chromium",
+    "chromium-browser",
+    "google-chrome",
+    "google-chrome-stable",
+    "chrome",
+    "msedge",
+];
+
+/// Installs outside `PATH` that are worth checking.
+const KNOWN_PATHS: &[&str] = &[
+    "/Applications/Chromium.app/Contents/MacOS/Chromium",
+    "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+    r"C:\Program Files\Google\Chrome\Application\chrome.exe",
+    r"C:\Program Files (x86)\Google\Chrome\Application\chrome.exe",
+];
+
+static NEXT_PROFILE: AtomicU64 = AtomicU64::new(0);
+
+/// A running browser, killed (and its throwaway profile removed) on drop.
+pub(super) struct Chromium {
+    child: Child,
+    temp_profile: Option<PathBuf>,
+}
+
+impl Chromium {
+    /// Start Chromium and return it with its browser DevTools WebSocket URL.
+    ///
+    /// Without `user_data_dir` a fresh profile is created in the temp
+    /// directory, so no cookies or history leak between sessions.
+    pub(super) fn launch(
+        binary: Option<&str>,
+        headless: bool,
+        user_data_dir: Option<&str>,
+        deadline: Instant,
+    ) -> Result<(Self, String), anyhow::Error> {
+        let binary = match binary {
+            Some(binary) => PathBuf::from(binary),
+            None => find_binary()
+                .ok_or_else(|| anyhow::anyhow!("Chromium was not found. {}", CHROMIUM_HINT))?,
+        };
+        let (profile, temp_profile) = match user_data_dir {
+            Some(dir) => (PathBuf::from(dir), None),
+            None => {
+                let dir = std::env::temp_dir().join(format!(
+                    "crewai-chromium-{}-{}",
+                    std::process::id(),
+                    NEXT_PROFILE.fetch_add(1, Ordering::Relaxed)
+                ));
+                std::fs::create_dir_all(&dir)?;
+                (dir.clone(), Some(dir))
+            }
+        };
+
+        let mut command = Command::new(&binary);
+        command
+            .arg("--remote-debugging-port=0")
+            .arg(format!("--user-data-dir={}", profile.display()))
+            .args(["--no-first-run", "--no-default-browser-check"])
+            .arg("--disable-background-networking");
+        if headless {
+            command.arg("--headless=new");
+        }
+        command
+            .arg("about:blank")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped());
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => {
+                if let Some(dir) = &temp_profile {
+                    let _ = std::fs::remove_dir_all(dir);
+                }
+                let binary = binary.display().to_string();
+                return Err(if e.kind() == std::io::ErrorKind::NotFound {
+                    anyhow::anyhow!(
+                        "Could not start Chromium: '{}' was not found. {}",
+                        binary,
+                        CHROMIUM_HINT
+                    )
+                } else {
+                    anyhow::anyhow!("Could not start Chromium '{}': {}", binary, e)
+                });
+            }
+        };
+
+        // Chromium prints the URL on stderr; the pipe is drained for the
+        // browser's lifetime so it never blocks on a full buffer.
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut sender = Some(sender);
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if let Some(url) = line.split("DevTools listening on ").nth(1) {
+                    if let Some(sender) = sender.take() {
+                        let _ = sender.send(url.trim().to_string());
+                    }
+                }
+            }
+        });
+        let browser = Self {
+            child,
+            temp_profile,
+        };
+        let wait = deadline.saturating_duration_since(Instant::now());
+        match receiver.recv_timeout(wait) {
+            Ok(url) => Ok((browser, url)),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                anyhow::bail!("Chromium did not open its DevTools port in time")
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                anyhow::bail!(
+                    "Chromium exited before opening its DevTools port; is another instance \
+                     using the profile '{}'?",
+                    profile.display()
+                )
+            }
+        }
+    }
+}
+
+impl Drop for Chromium {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        if let Some(dir) = &self.temp_profile {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// `CHROME_PATH`, the first candidate on `PATH`, or a known install.
+fn find_binary() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("CHROME_PATH") {
+        return Some(PathBuf::from(path));
+    }
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let dirs: Vec<PathBuf> = std::env::split_paths(&path).collect();
+    CANDIDATES
+        .iter()
+        .flat_map(|name| {
+            dirs.iter().flat_map(move |dir| {
+                [dir.join(name), dir.join(format!("{}.exe", name))]
+            })
+        })
+        .chain(KNOWN_PATHS.iter().map(PathBuf::from))
+        .find(|candidate| is_file(candidate))
+}
+
+fn is_file(path: &Path) -> bool {
+    path.metadata().map(|m| m.is_file()).unwrap_or(false)
+}
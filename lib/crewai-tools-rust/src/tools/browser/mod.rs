@@ -4,22 +4,61 @@
 //! Each struct corresponds to a Python tool class in `crewai_tools`.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+mod cdp;
+mod chromium;
+
 // ── BrowserbaseLoadTool ──────────────────────────────────────────────────────
 
 /// Load and interact with web pages using the Browserbase cloud browser service.
 ///
 /// Corresponds to Python `BrowserbaseLoadTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Each run creates a Browserbase session, drives its browser over the
+/// DevTools protocol to load the page, waits for the network to go idle
+/// (or for a selector), and returns the page text, HTML, or a screenshot.
+/// The session is released afterwards even when loading fails, so no
+/// billable browser time is left running. With `text_only`, images, media
+/// and fonts are not downloaded and the page text is returned.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BrowserbaseLoadTool {
-    /// Browserbase API key.
+    /// Browserbase API key (falls back to `BROWSERBASE_API_KEY`).
     pub api_key: Option<String>,
-    /// Browserbase project ID.
+    /// Browserbase project ID (falls back to `BROWSERBASE_PROJECT_ID`).
     pub project_id: Option<String>,
     /// Whether to enable text-only mode (no images).
     pub text_only: bool,
+    /// Browserbase API base URL.
+    #[serde(default = "default_browserbase_base_url")]
+    pub base_url: String,
+    /// Longest a page may take to load, including any wait.
+    #[serde(default = "default_browserbase_timeout")]
+    pub timeout: Duration,
+}
+
+fn default_browserbase_base_url() -> String {
+    "https://api.browserbase.com".to_string()
+}
+
+fn default_browserbase_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+impl std::fmt::Debug for BrowserbaseLoadTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BrowserbaseLoadTool")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("project_id", &self.project_id)
+            .field("text_only", &self.text_only)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
 }
 
 impl BrowserbaseLoadTool {
@@ -28,6 +67,8 @@ impl BrowserbaseLoadTool {
             api_key: None,
             project_id: None,
             text_only: false,
+            base_url: default_browserbase_base_url(),
+            timeout: default_browserbase_timeout(),
         }
     }
 
@@ -46,10 +87,78 @@ impl BrowserbaseLoadTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "BrowserbaseLoadTool: not yet implemented - requires Browserbase API integration"
-        )
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Load a page in a Browserbase browser.
+    ///
+    /// Returns `{url, title, session_id, output, content}`; for
+    /// screenshots `content` is a base64 PNG.
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - Page to load.
+    /// * `output` - `text` (the default), `html` or `screenshot`.
+    /// * `wait_for_selector` - CSS selector to wait for instead of network
+    ///   idle, for pages that keep polling.
+    /// * `full_page` - Capture the whole page rather than the viewport
+    ///   (screenshots only).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+        let output = args.get("output").and_then(|v| v.as_str()).unwrap_or("text");
+        match output {
+            "text" | "html" => {}
+            "screenshot" if self.text_only => {
+                anyhow::bail!("output 'screenshot' needs text_only turned off")
+            }
+            "screenshot" => {}
+            other => {
+                anyhow::bail!("Unknown output '{}': expected text, html or screenshot", other)
+            }
+        }
+        let selector = args.get("wait_for_selector").and_then(|v| v.as_str());
+        let full_page = args.get("full_page").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("BROWSERBASE_API_KEY").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Browserbase API key not set: use with_api_key() or BROWSERBASE_API_KEY"
+                )
+            })?;
+        let project_id = self
+            .project_id
+            .clone()
+            .or_else(|| std::env::var("BROWSERBASE_PROJECT_ID").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Browserbase project ID not set: use with_project_id() or \
+                     BROWSERBASE_PROJECT_ID"
+                )
+            })?;
+
+        let deadline = Instant::now() + self.timeout.max(Duration::from_secs(1));
+        let session = BrowserbaseSession::create(
+            self.base_url.trim_end_matches('/'),
+            &api_key,
+            &project_id,
+        )?;
+        let mut cdp = cdp::Cdp::connect(&session.connect_url, deadline)?;
+        let page = open_page(&mut cdp, self.text_only)?;
+        let mut result = load_page(&mut cdp, &page, url, selector, output, full_page)?;
+        result["session_id"] = Value::from(session.id.as_str());
+        Ok(result)
     }
 }
 
@@ -59,24 +168,266 @@ impl Default for BrowserbaseLoadTool {
     }
 }
 
+/// A running Browserbase session, released when dropped.
+struct BrowserbaseSession {
+    http: reqwest::blocking::Client,
+    base_url: String,
+    api_key: String,
+    project_id: String,
+    id: String,
+    connect_url: String,
+}
+
+impl BrowserbaseSession {
+    fn create(base_url: &str, api_key: &str, project_id: &str) -> Result<Self, anyhow::Error> {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()?;
+        let resp = http
+            .post(format!("{}/v1/sessions", base_url))
+            .header("X-BB-API-Key", api_key)
+            .json(&serde_json::json!({"projectId": project_id}))
+            .send()?;
+        let status = resp.status();
+        let body: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = body["message"]
+                .as_str()
+                .or_else(|| body["error"].as_str())
+                .unwrap_or("no details");
+            match status.as_u16() {
+                401 | 403 => anyhow::bail!(
+                    "Browserbase rejected the API key (HTTP {}): {}",
+                    status.as_u16(),
+                    message
+                ),
+                402 | 429 => anyhow::bail!(
+                    "Browserbase session quota reached (HTTP {}): {}; wait for running \
+                     sessions to end or raise the plan's concurrency and minutes limits",
+                    status.as_u16(),
+                    message
+                ),
+                _ => anyhow::bail!(
+                    "Browserbase session creation failed (HTTP {}): {}",
+                    status.as_u16(),
+                    message
+                ),
+            }
+        }
+        let field = |name: &str| {
+            body[name]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Browserbase session response has no {}", name))
+        };
+        Ok(Self {
+            id: field("id")?,
+            connect_url: field("connectUrl")?,
+            http,
+            base_url: base_url.to_string(),
+            api_key: api_key.to_string(),
+            project_id: project_id.to_string(),
+        })
+    }
+}
+
+impl Drop for BrowserbaseSession {
+    fn drop(&mut self) {
+        let released = self
+            .http
+            .post(format!("{}/v1/sessions/{}", self.base_url, self.id))
+            .header("X-BB-API-Key", &self.api_key)
+            .json(&serde_json::json!({
+                "projectId": self.project_id,
+                "status": "REQUEST_RELEASE",
+            }))
+            .send()
+            .map_err(anyhow::Error::from)
+            .and_then(|resp| match resp.status() {
+                status if status.is_success() => Ok(()),
+                status => Err(anyhow::anyhow!("HTTP {}", status.as_u16())),
+            });
+        if let Err(e) = released {
+            log::warn!("Could not release Browserbase session {}: {}", self.id, e);
+        }
+    }
+}
+
+/// Attach to a page of the remote browser, creating one if needed, and
+/// return its CDP session ID.
+fn open_page(cdp: &mut cdp::Cdp, text_only: bool) -> Result<String, anyhow::Error> {
+    let targets = cdp.call(None, "Target.getTargets", serde_json::json!({}))?;
+    let existing = targets["targetInfos"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|t| t["type"] == "page")
+        .and_then(|t| t["targetId"].as_str().map(str::to_string));
+    let target = match existing {
+        Some(target) => target,
+        None => {
+            let created =
+                cdp.call(None, "Target.createTarget", serde_json::json!({"url": "about:blank"}))?;
+            created["targetId"]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("The remote browser did not open a page"))?
+        }
+    };
+    let attached = cdp.call(
+        None,
+        "Target.attachToTarget",
+        serde_json::json!({"targetId": target, "flatten": true}),
+    )?;
+    let page = attached["sessionId"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("Could not attach to the remote browser's page"))?;
+    let session = Some(page.as_str());
+    cdp.call(session, "Page.enable", serde_json::json!({}))?;
+    cdp.call(session, "Page.setLifecycleEventsEnabled", serde_json::json!({"enabled": true}))?;
+    if text_only {
+        cdp.call(session, "Network.enable", serde_json::json!({}))?;
+        cdp.call(
+            session,
+            "Network.setBlockedURLs",
+            serde_json::json!({"urls": TEXT_ONLY_BLOCKED}),
+        )?;
+    }
+    Ok(page)
+}
+
+/// Resources skipped in text-only mode.
+const TEXT_ONLY_BLOCKED: &[&str] = &[
+    "*.png", "*.jpg", "*.jpeg", "*.gif", "*.webp", "*.svg", "*.ico", "*.avif", "*.mp4",
+    "*.webm", "*.mp3", "*.woff", "*.woff2", "*.ttf", "*.otf",
+];
+
+/// Navigate `page` to `url`, wait for it to settle, and extract `output`.
+fn load_page(
+    cdp: &mut cdp::Cdp,
+    page: &str,
+    url: &str,
+    selector: Option<&str>,
+    output: &str,
+    full_page: bool,
+) -> Result<Value, anyhow::Error> {
+    let session = Some(page);
+    let navigated = cdp.call(session, "Page.navigate", serde_json::json!({"url": url}))?;
+    if let Some(error) = navigated["errorText"].as_str().filter(|e| !e.is_empty()) {
+        anyhow::bail!("Could not load {}: {}", url, error);
+    }
+    let frame = navigated["frameId"].as_str().unwrap_or_default().to_string();
+
+    let evaluate = |cdp: &mut cdp::Cdp, expression: &str| evaluate(cdp, page, expression);
+    match selector {
+        Some(selector) => {
+            let check = format!("!!document.querySelector({})", Value::from(selector));
+            // Polled rather than observed; every call is held to the deadline.
+            loop {
+                let found = evaluate(cdp, &check).map_err(|e| {
+                    anyhow::anyhow!("Waiting for selector '{}' failed: {}", selector, e)
+                })?;
+                if found == Value::Bool(true) {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(250));
+            }
+        }
+        None => loop {
+            let event = cdp.next_event("the page's network to go idle")?;
+            if event["method"] == "Page.lifecycleEvent"
+                && event["params"]["name"] == "networkIdle"
+                && (frame.is_empty() || event["params"]["frameId"] == frame.as_str())
+            {
+                break;
+            }
+        },
+    }
+
+    let content = match output {
+        "screenshot" => {
+            let shot = cdp.call(
+                session,
+                "Page.captureScreenshot",
+                serde_json::json!({"format": "png", "captureBeyondViewport": full_page}),
+            )?;
+            shot["data"].clone()
+        }
+        "html" => evaluate(cdp, "document.documentElement.outerHTML")?,
+        _ => evaluate(cdp, "document.body ? document.body.innerText : ''")?,
+    };
+    Ok(serde_json::json!({
+        "url": evaluate(cdp, "location.href")?,
+        "title": evaluate(cdp, "document.title")?,
+        "output": output,
+        "content": content,
+    }))
+}
+
+/// Evaluate `expression` in `page` and return its value.
+fn evaluate(cdp: &mut cdp::Cdp, page: &str, expression: &str) -> Result<Value, anyhow::Error> {
+    let result = cdp.call(
+        Some(page),
+        "Runtime.evaluate",
+        serde_json::json!({"expression": expression, "returnByValue": true}),
+    )?;
+    if let Some(exception) = result.get("exceptionDetails") {
+        anyhow::bail!("Page script failed: {}", exception["text"]);
+    }
+    Ok(result["result"]["value"].clone())
+}
+
 // ── HyperbrowserLoadTool ─────────────────────────────────────────────────────
 
 /// Load and interact with web pages using the Hyperbrowser service.
 ///
 /// Corresponds to Python `HyperbrowserLoadTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `scrape` fetches one page and `crawl` follows links from it; both run
+/// as Hyperbrowser jobs that are polled every `poll_interval_ms` within a
+/// total budget of `timeout` seconds. A crawl that runs out of time returns
+/// the pages finished so far with `status: "timed_out"` rather than failing.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct HyperbrowserLoadTool {
-    /// Hyperbrowser API key.
+    /// Hyperbrowser API key (falls back to `HYPERBROWSER_API_KEY`).
     pub api_key: Option<String>,
-    /// Session timeout in seconds.
-    pub timeout: u64,
+    /// Total time budget for a job, including polling.
+    pub timeout: Duration,
+    /// Delay between job status checks, in milliseconds.
+    #[serde(default = "default_hyperbrowser_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// Hyperbrowser API base URL.
+    #[serde(default = "default_hyperbrowser_base_url")]
+    pub base_url: String,
+}
+
+fn default_hyperbrowser_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_hyperbrowser_base_url() -> String {
+    "https://app.hyperbrowser.ai".to_string()
+}
+
+impl std::fmt::Debug for HyperbrowserLoadTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperbrowserLoadTool")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("timeout", &self.timeout)
+            .field("poll_interval_ms", &self.poll_interval_ms)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
 }
 
 impl HyperbrowserLoadTool {
     pub fn new() -> Self {
         Self {
             api_key: None,
-            timeout: 30,
+            timeout: Duration::from_secs(30),
+            poll_interval_ms: default_hyperbrowser_poll_interval_ms(),
+            base_url: default_hyperbrowser_base_url(),
         }
     }
 
@@ -85,15 +436,181 @@ impl HyperbrowserLoadTool {
         self
     }
 
-    pub fn with_timeout(mut self, timeout: u64) -> Self {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "HyperbrowserLoadTool: not yet implemented - requires Hyperbrowser API integration"
-        )
+    pub fn with_poll_interval_ms(mut self, ms: u64) -> Self {
+        self.poll_interval_ms = ms;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Scrape a page or crawl a site.
+    ///
+    /// A scrape returns `{operation, job_id, status, url, metadata,
+    /// markdown|html}`; a crawl returns `{operation, job_id, status,
+    /// total_pages, pages: [{url, status, metadata, markdown|html, error}]}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - Page to scrape, or where the crawl starts.
+    /// * `operation` - `scrape` (the default) or `crawl`.
+    /// * `formats` - Content formats to return: any of `markdown` (the
+    ///   default) and `html`.
+    /// * `max_pages` - Most pages to crawl (default 10).
+    /// * `params` - Extra request fields passed to Hyperbrowser as is, e.g.
+    ///   `{"sessionOptions": {"useProxy": true}}`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+        let operation = args.get("operation").and_then(|v| v.as_str()).unwrap_or("scrape");
+        if !matches!(operation, "scrape" | "crawl") {
+            anyhow::bail!("Unknown operation '{}': expected scrape or crawl", operation);
+        }
+        let formats: Vec<String> = match args.get("formats") {
+            None | Some(Value::Null) => vec!["markdown".to_string()],
+            Some(Value::String(format)) => vec![format.clone()],
+            Some(Value::Array(formats)) => formats
+                .iter()
+                .map(|f| f.as_str().map(str::to_string))
+                .collect::<Option<_>>()
+                .ok_or_else(|| anyhow::anyhow!("'formats' must be a list of strings"))?,
+            Some(_) => anyhow::bail!("'formats' must be a list of strings"),
+        };
+        if let Some(bad) = formats.iter().find(|f| !matches!(f.as_str(), "markdown" | "html")) {
+            anyhow::bail!("Unknown format '{}': expected markdown or html", bad);
+        }
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("HYPERBROWSER_API_KEY").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Hyperbrowser API key not set: use with_api_key() or HYPERBROWSER_API_KEY"
+                )
+            })?;
+
+        let mut body = serde_json::json!({
+            "url": url,
+            "scrapeOptions": {"formats": formats},
+        });
+        if operation == "crawl" {
+            let max_pages = args.get("max_pages").and_then(|v| v.as_u64()).unwrap_or(10);
+            body["maxPages"] = Value::from(max_pages);
+        }
+        match args.get("params") {
+            Some(Value::Object(params)) => {
+                for (key, value) in params {
+                    body[key.as_str()] = value.clone();
+                }
+            }
+            Some(Value::Null) | None => {}
+            Some(_) => anyhow::bail!("'params' must be an object"),
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        let api = HyperbrowserApi {
+            http: reqwest::blocking::Client::builder()
+                .timeout(self.timeout.clamp(Duration::from_secs(1), Duration::from_secs(60)))
+                .build()?,
+            base: format!("{}/api/{}", self.base_url.trim_end_matches('/'), operation),
+            api_key,
+        };
+        let started = api.request(api.http.post(&api.base).json(&body))?;
+        let job_id = started["jobId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Hyperbrowser returned no jobId: {}", started))?;
+
+        let poll_interval = Duration::from_millis(self.poll_interval_ms);
+        let status = loop {
+            let status_url = format!("{}/{}/status", api.base, job_id);
+            let status = api.request(api.http.get(&status_url))?;
+            match status["status"].as_str().unwrap_or("pending") {
+                "completed" => break "completed",
+                "failed" => {
+                    // The job itself carries the reason.
+                    let job = api.request(api.http.get(format!("{}/{}", api.base, job_id)))?;
+                    anyhow::bail!(
+                        "Hyperbrowser {} job {} failed: {}",
+                        operation,
+                        job_id,
+                        job["error"].as_str().unwrap_or("no details")
+                    );
+                }
+                state if Instant::now() + poll_interval >= deadline => {
+                    if operation == "scrape" {
+                        anyhow::bail!(
+                            "Hyperbrowser scrape job {} still {} after {:?}",
+                            job_id,
+                            state,
+                            self.timeout
+                        );
+                    }
+                    break "timed_out";
+                }
+                _ => std::thread::sleep(poll_interval),
+            }
+        };
+
+        if operation == "scrape" {
+            let job = api.request(api.http.get(format!("{}/{}", api.base, job_id)))?;
+            let mut result = serde_json::json!({
+                "operation": "scrape",
+                "job_id": job_id,
+                "status": status,
+                "url": url,
+                "metadata": job["data"]["metadata"],
+            });
+            for format in &formats {
+                result[format.as_str()] = job["data"][format.as_str()].clone();
+            }
+            return Ok(result);
+        }
+
+        // Crawl results come in batches of pages.
+        let mut pages = Vec::new();
+        let mut batch = 1;
+        loop {
+            let job = api.request(
+                api.http
+                    .get(format!("{}/{}", api.base, job_id))
+                    .query(&[("page", batch)]),
+            )?;
+            for page in job["data"].as_array().into_iter().flatten() {
+                let mut entry = serde_json::json!({
+                    "url": page["url"],
+                    "status": page["status"],
+                    "metadata": page["metadata"],
+                });
+                for format in &formats {
+                    entry[format.as_str()] = page[format.as_str()].clone();
+                }
+                if let Some(error) = page["error"].as_str() {
+                    entry["error"] = Value::from(error);
+                }
+                pages.push(entry);
+            }
+            let batches = job["totalPageBatches"].as_u64().unwrap_or(1);
+            if batch >= batches {
+                break;
+            }
+            batch += 1;
+        }
+        Ok(serde_json::json!({
+            "operation": "crawl",
+            "job_id": job_id,
+            "status": status,
+            "total_pages": pages.len(),
+            "pages": pages,
+        }))
     }
 }
 
@@ -103,17 +620,108 @@ impl Default for HyperbrowserLoadTool {
     }
 }
 
+/// Authenticated calls to one Hyperbrowser job endpoint.
+struct HyperbrowserApi {
+    http: reqwest::blocking::Client,
+    /// `.../api/scrape` or `.../api/crawl`.
+    base: String,
+    api_key: String,
+}
+
+impl HyperbrowserApi {
+    fn request(&self, request: reqwest::blocking::RequestBuilder) -> Result<Value, anyhow::Error> {
+        let resp = request.header("x-api-key", &self.api_key).send()?;
+        let status = resp.status();
+        let body: Value = resp.json().unwrap_or(Value::Null);
+        if status.is_success() {
+            return Ok(body);
+        }
+        let message = body["message"]
+            .as_str()
+            .or_else(|| body["error"].as_str())
+            .unwrap_or("no details");
+        match status.as_u16() {
+            401 | 403 => anyhow::bail!("Hyperbrowser rejected the API key: {}", message),
+            402 | 429 => anyhow::bail!(
+                "Hyperbrowser usage limit reached (HTTP {}): {}",
+                status.as_u16(),
+                message
+            ),
+            code => anyhow::bail!("Hyperbrowser request failed (HTTP {}): {}", code, message),
+        }
+    }
+}
+
 // ── StagehandTool ────────────────────────────────────────────────────────────
 
 /// AI-powered browser automation using the Stagehand framework.
 ///
 /// Corresponds to Python `StagehandTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Drives a Stagehand server session with its three primitives: `act`
+/// performs a natural-language action, `extract` pulls data matching a
+/// JSON schema, and `observe` lists the actions available on the page.
+/// Sessions outlive a single run: every result carries `session_id`, and
+/// passing it back continues in the same browser, so an agent can log in
+/// on one step and read the dashboard on the next. `close` ends a session.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct StagehandTool {
     /// Stagehand API key or configuration.
     pub api_key: Option<String>,
-    /// Whether to run in headless mode.
+    /// Whether to run in headless mode. Applies to servers that launch
+    /// their own local browsers.
     pub headless: bool,
+    /// Browserbase project ID (falls back to `BROWSERBASE_PROJECT_ID`).
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// LLM provider that interprets instructions, e.g. `openai` or
+    /// `anthropic`.
+    #[serde(default = "default_stagehand_model_provider")]
+    pub model_provider: String,
+    /// Model name at that provider.
+    #[serde(default = "default_stagehand_model_name")]
+    pub model_name: String,
+    /// API key for the model provider (falls back to the provider's usual
+    /// environment variable, e.g. `OPENAI_API_KEY`).
+    #[serde(default, skip_serializing)]
+    pub model_api_key: Option<String>,
+    /// Stagehand server URL.
+    #[serde(default = "default_stagehand_server_url")]
+    pub server_url: String,
+    /// Longest a single command may take.
+    #[serde(default = "default_stagehand_timeout")]
+    pub timeout: Duration,
+}
+
+fn default_stagehand_model_provider() -> String {
+    "openai".to_string()
+}
+
+fn default_stagehand_model_name() -> String {
+    "gpt-4o".to_string()
+}
+
+fn default_stagehand_server_url() -> String {
+    "https://api.stagehand.browserbase.com/v1".to_string()
+}
+
+fn default_stagehand_timeout() -> Duration {
+    Duration::from_secs(120)
+}
+
+impl std::fmt::Debug for StagehandTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StagehandTool")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("headless", &self.headless)
+            .field("project_id", &self.project_id)
+            .field("model_provider", &self.model_provider)
+            .field("model_name", &self.model_name)
+            .field("model_api_key", &self.model_api_key.as_ref().map(|_| "<redacted>"))
+            .field("server_url", &self.server_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
 }
 
 impl StagehandTool {
@@ -121,6 +729,12 @@ impl StagehandTool {
         Self {
             api_key: None,
             headless: true,
+            project_id: None,
+            model_provider: default_stagehand_model_provider(),
+            model_name: default_stagehand_model_name(),
+            model_api_key: None,
+            server_url: default_stagehand_server_url(),
+            timeout: default_stagehand_timeout(),
         }
     }
 
@@ -134,10 +748,177 @@ impl StagehandTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "StagehandTool: not yet implemented - requires Stagehand framework integration"
-        )
+    pub fn with_project_id(mut self, id: impl Into<String>) -> Self {
+        self.project_id = Some(id.into());
+        self
+    }
+
+    /// Use `model` from `provider` (e.g. `"anthropic"`,
+    /// `"claude-3-5-sonnet-latest"`) to interpret instructions.
+    pub fn with_model(mut self, provider: impl Into<String>, model: impl Into<String>) -> Self {
+        self.model_provider = provider.into();
+        self.model_name = model.into();
+        self
+    }
+
+    pub fn with_model_api_key(mut self, key: impl Into<String>) -> Self {
+        self.model_api_key = Some(key.into());
+        self
+    }
+
+    pub fn with_server_url(mut self, url: impl Into<String>) -> Self {
+        self.server_url = url.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Run one Stagehand command.
+    ///
+    /// Returns `{session_id, command, result}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `command` - `act`, `extract`, `observe` or `close`.
+    /// * `instruction` - What to do, extract or look for, e.g. "click the
+    ///   login button" (required for `act`).
+    /// * `url` - Page to open before running the command.
+    /// * `schema` - JSON Schema of the data to `extract`.
+    /// * `session_id` - Continue in the browser of an earlier result;
+    ///   omitted, a new session starts.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let str_arg = |name: &str| args.get(name).and_then(|v| v.as_str());
+        let command = str_arg("command")
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: command"))?;
+        let instruction = str_arg("instruction");
+        let body = match command {
+            "act" => serde_json::json!({
+                "action": instruction.ok_or_else(|| {
+                    anyhow::anyhow!("Missing required argument: instruction")
+                })?,
+            }),
+            "extract" => {
+                let mut body = serde_json::json!({"instruction": instruction});
+                match args.get("schema") {
+                    Some(Value::Object(schema)) => {
+                        body["schemaDefinition"] = Value::Object(schema.clone())
+                    }
+                    // Schemas often arrive as JSON text from an LLM.
+                    Some(Value::String(text)) => {
+                        body["schemaDefinition"] = serde_json::from_str(text)
+                            .map_err(|e| anyhow::anyhow!("'schema' is not valid JSON: {}", e))?
+                    }
+                    Some(Value::Null) | None => {}
+                    Some(_) => anyhow::bail!("'schema' must be a JSON Schema object"),
+                }
+                body
+            }
+            "observe" => serde_json::json!({"instruction": instruction}),
+            "close" => Value::Null,
+            other => anyhow::bail!(
+                "Unknown command '{}': expected act, extract, observe or close",
+                other
+            ),
+        };
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("BROWSERBASE_API_KEY").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Stagehand API key not set: use with_api_key() or BROWSERBASE_API_KEY"
+                )
+            })?;
+        let project_id = self
+            .project_id
+            .clone()
+            .or_else(|| std::env::var("BROWSERBASE_PROJECT_ID").ok())
+            .unwrap_or_default();
+        let http = reqwest::blocking::Client::builder()
+            .timeout(self.timeout.max(Duration::from_secs(1)))
+            .build()?;
+        let server = self.server_url.trim_end_matches('/');
+        let call = |path: &str, body: &Value, model_key: Option<&str>| {
+            let mut request = http
+                .post(format!("{}{}", server, path))
+                .header("x-bb-api-key", &api_key)
+                .header("x-bb-project-id", &project_id)
+                .header("x-stream-response", "false");
+            if let Some(key) = model_key {
+                request = request.header("x-model-api-key", key);
+            }
+            stagehand_response(request.json(body).send()?)
+        };
+
+        let session_id = match str_arg("session_id") {
+            Some(id) => id.to_string(),
+            None if command == "close" => anyhow::bail!("Missing required argument: session_id"),
+            None => {
+                let model_key = self.model_api_key()?;
+                let started = call(
+                    "/sessions/start",
+                    &serde_json::json!({
+                        "modelName": format!("{}/{}", self.model_provider, self.model_name),
+                        "browserbaseSessionCreateParams": {"projectId": project_id},
+                        "localBrowserLaunchOptions": {"headless": self.headless},
+                    }),
+                    Some(&model_key),
+                )?;
+                started["sessionId"]
+                    .as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| anyhow::anyhow!("Stagehand started no session: {}", started))?
+            }
+        };
+        let session_path = format!("/sessions/{}", session_id);
+        let in_session = |action: &str, body: &Value, model_key: Option<&str>| {
+            call(&format!("{}/{}", session_path, action), body, model_key).map_err(|e| {
+                anyhow::anyhow!("Stagehand {} in session {} failed: {}", action, session_id, e)
+            })
+        };
+
+        if command == "close" {
+            in_session("end", &serde_json::json!({}), None)?;
+            return Ok(serde_json::json!({
+                "session_id": session_id,
+                "command": "close",
+                "result": {"closed": true},
+            }));
+        }
+        if let Some(url) = str_arg("url") {
+            in_session("navigate", &serde_json::json!({"url": url}), None)?;
+        }
+        let model_key = self.model_api_key()?;
+        let result = in_session(command, &body, Some(&model_key))?;
+        Ok(serde_json::json!({
+            "session_id": session_id,
+            "command": command,
+            "result": result,
+        }))
+    }
+
+    /// The model provider's key, from the builder or its usual variable.
+    fn model_api_key(&self) -> Result<String, anyhow::Error> {
+        if let Some(key) = &self.model_api_key {
+            return Ok(key.clone());
+        }
+        let var = match self.model_provider.as_str() {
+            "openai" => "OPENAI_API_KEY",
+            "anthropic" => "ANTHROPIC_API_KEY",
+            "google" | "gemini" => "GOOGLE_API_KEY",
+            "groq" => "GROQ_API_KEY",
+            "cerebras" => "CEREBRAS_API_KEY",
+            other => anyhow::bail!(
+                "No model API key for provider '{}': use with_model_api_key()",
+                other
+            ),
+        };
+        std::env::var(var).map_err(|_| {
+            anyhow::anyhow!("Model API key not set: use with_model_api_key() or {}", var)
+        })
     }
 }
 
@@ -147,17 +928,79 @@ impl Default for StagehandTool {
     }
 }
 
+/// The `data` of a Stagehand server reply, or its error.
+fn stagehand_response(resp: reqwest::blocking::Response) -> Result<Value, anyhow::Error> {
+    let status = resp.status();
+    let body: Value = resp.json().unwrap_or(Value::Null);
+    let message = || {
+        body["message"]
+            .as_str()
+            .or_else(|| body["error"].as_str())
+            .unwrap_or("no details")
+            .to_string()
+    };
+    match status.as_u16() {
+        200..=299 if body["success"] != Value::Bool(false) => Ok(body["data"].clone()),
+        200..=299 => anyhow::bail!("{}", message()),
+        401 | 403 => anyhow::bail!("the API key was rejected: {}", message()),
+        404 => anyhow::bail!(
+            "the session is no longer running ({}); omit session_id to start a new one",
+            message()
+        ),
+        code => anyhow::bail!("HTTP {}: {}", code, message()),
+    }
+}
+
 // ── MultiOnTool ──────────────────────────────────────────────────────────────
 
 /// Autonomous web browsing and task completion using the MultiOn API.
 ///
 /// Corresponds to Python `MultiOnTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Each run sends a natural-language command to MultiOn's browse agent.
+/// The agent works in a session whose id comes back in every result;
+/// passing it as `session_id` continues from the same page. While the
+/// agent reports `CONTINUE`, the tool repeats the command up to
+/// `max_steps` times so a single call can finish a multi-step task.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MultiOnTool {
     /// MultiOn API key.
     pub api_key: Option<String>,
     /// Whether to run in local mode.
     pub local: bool,
+    /// Browse calls per run while the agent reports `CONTINUE`.
+    #[serde(default = "default_multion_max_steps")]
+    pub max_steps: u32,
+    /// MultiOn API base URL.
+    #[serde(default = "default_multion_base_url")]
+    pub base_url: String,
+    /// Timeout for each browse call.
+    #[serde(default = "default_multion_timeout")]
+    pub timeout: Duration,
+}
+
+fn default_multion_max_steps() -> u32 {
+    3
+}
+
+fn default_multion_base_url() -> String {
+    "https://api.multion.ai/v1".to_string()
+}
+
+fn default_multion_timeout() -> Duration {
+    Duration::from_secs(120)
+}
+
+impl std::fmt::Debug for MultiOnTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiOnTool")
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("local", &self.local)
+            .field("max_steps", &self.max_steps)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
 }
 
 impl MultiOnTool {
@@ -165,6 +1008,9 @@ impl MultiOnTool {
         Self {
             api_key: None,
             local: false,
+            max_steps: default_multion_max_steps(),
+            base_url: default_multion_base_url(),
+            timeout: default_multion_timeout(),
         }
     }
 
@@ -178,8 +1024,107 @@ impl MultiOnTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("MultiOnTool: not yet implemented - requires MultiOn API integration")
+    pub fn with_max_steps(mut self, steps: u32) -> Self {
+        self.max_steps = steps;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Run a browse command.
+    ///
+    /// Returns `{message, url, status, session_id, steps}`, where `status`
+    /// is the agent's last verdict: `CONTINUE`, `DONE`, `NOT_SURE` (or
+    /// `ASK_USER`).
+    ///
+    /// # Arguments (in `args`)
+    /// * `cmd` - What the agent should do, e.g. "find the top post on
+    ///   Hacker News".
+    /// * `url` - Page to start from.
+    /// * `session_id` - Continue the session of an earlier result.
+    /// * `max_steps` - Overrides the tool's `max_steps` for this run.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let cmd = args
+            .get("cmd")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: cmd"))?;
+        let max_steps = args
+            .get("max_steps")
+            .and_then(|v| v.as_u64())
+            .map_or(self.max_steps, |n| n.min(u32::MAX as u64) as u32)
+            .max(1);
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("MULTION_API_KEY").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!("MultiOn API key not set: use with_api_key() or MULTION_API_KEY")
+            })?;
+        let http = reqwest::blocking::Client::builder()
+            .timeout(self.timeout.max(Duration::from_secs(1)))
+            .build()?;
+        let endpoint = format!("{}/web/browse", self.base_url.trim_end_matches('/'));
+
+        let mut body = serde_json::json!({"cmd": cmd, "local": self.local});
+        if let Some(url) = args.get("url").and_then(|v| v.as_str()) {
+            body["url"] = Value::from(url);
+        }
+        if let Some(id) = args.get("session_id").and_then(|v| v.as_str()) {
+            body["session_id"] = Value::from(id);
+        }
+
+        let mut steps = 0;
+        loop {
+            let resp = http
+                .post(&endpoint)
+                .header("X_MULTION_API_KEY", &api_key)
+                .json(&body)
+                .send()?;
+            let status = resp.status();
+            let reply: Value = resp.json().unwrap_or(Value::Null);
+            if !status.is_success() {
+                let detail = reply["detail"]
+                    .as_str()
+                    .or_else(|| reply["message"].as_str())
+                    .unwrap_or("no details");
+                match status.as_u16() {
+                    401 | 403 => anyhow::bail!("MultiOn rejected the API key: {}", detail),
+                    404 if body.get("session_id").is_some() => anyhow::bail!(
+                        "MultiOn session {} is no longer running ({}); omit session_id to \
+                         start a new one",
+                        body["session_id"].as_str().unwrap_or_default(),
+                        detail
+                    ),
+                    code => anyhow::bail!("MultiOn API error (HTTP {}): {}", code, detail),
+                }
+            }
+            steps += 1;
+            if let Some(id) = reply["session_id"].as_str() {
+                body["session_id"] = Value::from(id);
+            }
+            // Follow-up steps continue from wherever the agent is now.
+            if let Some(map) = body.as_object_mut() {
+                map.remove("url");
+            }
+            let agent_status = reply["status"].as_str().unwrap_or("DONE");
+            if agent_status != "CONTINUE" || steps >= max_steps {
+                return Ok(serde_json::json!({
+                    "message": reply["message"],
+                    "url": reply["url"],
+                    "status": agent_status,
+                    "session_id": body.get("session_id").cloned().unwrap_or(Value::Null),
+                    "steps": steps,
+                }));
+            }
+        }
     }
 }
 
@@ -188,3 +1133,420 @@ impl Default for MultiOnTool {
         Self::new()
     }
 }
+
+// ── PlaywrightBrowserTool ────────────────────────────────────────────────────
+
+/// Drive a local Chromium without a cloud browser service.
+///
+/// Runs a list of page commands (navigate, wait for a selector, read text
+/// or HTML, click, fill, screenshot) in a Chromium launched on this machine
+/// over the DevTools protocol, or in one already running at `browser_url`.
+/// The browser stays open after a run: every result carries `session_id`,
+/// and passing it back continues on the same page with its cookies and
+/// form state. A `close` command ends the session; any still open are
+/// closed when the last clone of the tool is dropped.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlaywrightBrowserTool {
+    /// Whether to launch Chromium without a window.
+    #[serde(default = "default_true")]
+    pub headless: bool,
+    /// Profile directory to launch with, keeping logins between sessions.
+    /// Chromium locks a profile, so only one session can use it at a time.
+    /// Without it each session gets a throwaway profile.
+    #[serde(default)]
+    pub user_data_dir: Option<String>,
+    /// Chromium or Chrome binary (falls back to `CHROME_PATH`, then the
+    /// usual install locations).
+    #[serde(default)]
+    pub chrome_path: Option<String>,
+    /// DevTools WebSocket URL of an already running browser to use instead
+    /// of launching one, e.g. `ws://127.0.0.1:9222/devtools/browser/<id>`.
+    #[serde(default)]
+    pub browser_url: Option<String>,
+    /// Longest a run may take, including launching the browser.
+    #[serde(default = "default_local_browser_timeout")]
+    pub timeout: Duration,
+    #[serde(skip)]
+    sessions: Arc<Mutex<HashMap<String, LocalSession>>>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_local_browser_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Handles are unique per process so a stale id never reaches a new page.
+static NEXT_LOCAL_SESSION: AtomicU64 = AtomicU64::new(1);
+
+/// A page kept open between runs.
+struct LocalSession {
+    ws_url: String,
+    target: String,
+    /// The browser launched for this session; `None` for `browser_url`.
+    _browser: Option<chromium::Chromium>,
+}
+
+/// One entry of the `commands` argument.
+enum PageCommand {
+    Navigate { url: String },
+    WaitForSelector { selector: String, timeout_ms: Option<u64> },
+    Text { selector: Option<String> },
+    Html { selector: Option<String> },
+    Click { selector: String },
+    Fill { selector: String, value: String },
+    Screenshot { full_page: bool },
+    Close,
+}
+
+impl std::fmt::Debug for PlaywrightBrowserTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PlaywrightBrowserTool")
+            .field("headless", &self.headless)
+            .field("user_data_dir", &self.user_data_dir)
+            .field("chrome_path", &self.chrome_path)
+            .field("browser_url", &self.browser_url)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl PlaywrightBrowserTool {
+    pub fn new() -> Self {
+        Self {
+            headless: true,
+            user_data_dir: None,
+            chrome_path: None,
+            browser_url: None,
+            timeout: default_local_browser_timeout(),
+            sessions: Arc::default(),
+        }
+    }
+
+    pub fn with_headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    pub fn with_user_data_dir(mut self, dir: impl Into<String>) -> Self {
+        self.user_data_dir = Some(dir.into());
+        self
+    }
+
+    pub fn with_chrome_path(mut self, path: impl Into<String>) -> Self {
+        self.chrome_path = Some(path.into());
+        self
+    }
+
+    pub fn with_browser_url(mut self, url: impl Into<String>) -> Self {
+        self.browser_url = Some(url.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Run page commands in a new or existing browser session.
+    ///
+    /// Returns `{session_id, url, closed, results}` with one result per
+    /// command: `navigate` gives `{url, title}`, `text`/`html` give
+    /// `content`, and `screenshot` gives a base64 PNG in `content`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `commands` - Array of `{action, ...}` objects, run in order:
+    ///   `navigate` (`url`), `wait_for_selector` (`selector`, optional
+    ///   `timeout_ms`), `text` / `html` (optional `selector`), `click`
+    ///   (`selector`), `fill` (`selector`, `value`), `screenshot` (optional
+    ///   `full_page`), and `close`, which must come last.
+    /// * `session_id` - Continue the session of an earlier result; omitted,
+    ///   a new browser page is opened.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let commands = page_commands(&args)?;
+        let deadline = Instant::now() + self.timeout.max(Duration::from_secs(1));
+
+        let (session_id, ws_url, target, created) =
+            match args.get("session_id").and_then(|v| v.as_str()) {
+                Some(id) => {
+                    let sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+                    let session = sessions.get(id).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Unknown browser session '{}'; omit session_id to start a new one",
+                            id
+                        )
+                    })?;
+                    (id.to_string(), session.ws_url.clone(), session.target.clone(), false)
+                }
+                None => {
+                    let session = self.start_session(deadline)?;
+                    let id = format!(
+                        "browser-{}",
+                        NEXT_LOCAL_SESSION.fetch_add(1, Ordering::Relaxed)
+                    );
+                    let (ws_url, target) = (session.ws_url.clone(), session.target.clone());
+                    self.sessions
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(id.clone(), session);
+                    (id, ws_url, target, true)
+                }
+            };
+
+        let outcome = run_page_commands(&ws_url, &target, &commands, deadline);
+        let closed = matches!(commands.last(), Some(PageCommand::Close));
+        // A session that failed on its first run was never handed out.
+        if closed || (created && outcome.is_err()) {
+            self.sessions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .remove(&session_id);
+        }
+        let (url, results) =
+            outcome.map_err(|e| anyhow::anyhow!("Browser session {}: {}", session_id, e))?;
+        Ok(serde_json::json!({
+            "session_id": session_id,
+            "url": url,
+            "closed": closed,
+            "results": results,
+        }))
+    }
+
+    /// Launch (or connect to) a browser and open a page for a new session.
+    fn start_session(&self, deadline: Instant) -> Result<LocalSession, anyhow::Error> {
+        let (browser, ws_url) = match &self.browser_url {
+            Some(url) => (None, url.clone()),
+            None => {
+                let (browser, url) = chromium::Chromium::launch(
+                    self.chrome_path.as_deref(),
+                    self.headless,
+                    self.user_data_dir.as_deref(),
+                    deadline,
+                )?;
+                (Some(browser), url)
+            }
+        };
+        let mut cdp = cdp::Cdp::connect(&ws_url, deadline)?;
+        let created =
+            cdp.call(None, "Target.createTarget", serde_json::json!({"url": "about:blank"}))?;
+        let target = created["targetId"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("The browser did not open a page"))?;
+        Ok(LocalSession {
+            ws_url,
+            target,
+            _browser: browser,
+        })
+    }
+}
+
+impl Default for PlaywrightBrowserTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse and check `commands` before any browser is started.
+fn page_commands(args: &HashMap<String, Value>) -> Result<Vec<PageCommand>, anyhow::Error> {
+    let entries = match args.get("commands") {
+        Some(Value::Array(entries)) if !entries.is_empty() => entries,
+        Some(_) => anyhow::bail!("'commands' must be a non-empty array of {{action, ...}} objects"),
+        None => anyhow::bail!("Missing required argument: commands"),
+    };
+    let mut commands = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let field = |name: &str| entry.get(name).and_then(|v| v.as_str()).map(str::to_string);
+        let action = field("action").unwrap_or_default();
+        let required = |name: &str| {
+            field(name).ok_or_else(|| {
+                anyhow::anyhow!("Command {} ({}) needs a '{}' string", i + 1, action, name)
+            })
+        };
+        commands.push(match action.as_str() {
+            "navigate" => PageCommand::Navigate { url: required("url")? },
+            "wait_for_selector" => PageCommand::WaitForSelector {
+                selector: required("selector")?,
+                timeout_ms: entry.get("timeout_ms").and_then(|v| v.as_u64()),
+            },
+            "text" => PageCommand::Text { selector: field("selector") },
+            "html" => PageCommand::Html { selector: field("selector") },
+            "click" => PageCommand::Click { selector: required("selector")? },
+            "fill" => PageCommand::Fill {
+                selector: required("selector")?,
+                value: required("value")?,
+            },
+            "screenshot" => PageCommand::Screenshot {
+                full_page: entry.get("full_page").and_then(|v| v.as_bool()).unwrap_or(false),
+            },
+            "close" if i + 1 == entries.len() => PageCommand::Close,
+            "close" => anyhow::bail!("Command {} (close) must be the last command", i + 1),
+            other => anyhow::bail!(
+                "Command {} has unknown action '{}': expected navigate, wait_for_selector, \
+                 text, html, click, fill, screenshot or close",
+                i + 1,
+                other
+            ),
+        });
+    }
+    Ok(commands)
+}
+
+/// Attach to `target` and run `commands`; returns the final URL (null once
+/// closed) and one result per command.
+fn run_page_commands(
+    ws_url: &str,
+    target: &str,
+    commands: &[PageCommand],
+    deadline: Instant,
+) -> Result<(Value, Vec<Value>), anyhow::Error> {
+    let mut cdp = cdp::Cdp::connect(ws_url, deadline)?;
+    let attached = cdp.call(
+        None,
+        "Target.attachToTarget",
+        serde_json::json!({"targetId": target, "flatten": true}),
+    )?;
+    let page = attached["sessionId"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("The page was closed"))?;
+    let page = page.as_str();
+    cdp.call(Some(page), "Page.enable", serde_json::json!({}))?;
+    cdp.call(Some(page), "Page.setLifecycleEventsEnabled", serde_json::json!({"enabled": true}))?;
+
+    let mut results = Vec::with_capacity(commands.len());
+    for (i, command) in commands.iter().enumerate() {
+        if let PageCommand::Close = command {
+            cdp.call(None, "Target.closeTarget", serde_json::json!({"targetId": target}))?;
+            results.push(serde_json::json!({"action": "close"}));
+            return Ok((Value::Null, results));
+        }
+        let result = page_command(&mut cdp, page, command, deadline)
+            .map_err(|e| anyhow::anyhow!("command {} failed: {}", i + 1, e))?;
+        results.push(result);
+    }
+    Ok((evaluate(&mut cdp, page, "location.href")?, results))
+}
+
+/// Run one command other than `close` on `page`.
+fn page_command(
+    cdp: &mut cdp::Cdp,
+    page: &str,
+    command: &PageCommand,
+    deadline: Instant,
+) -> Result<Value, anyhow::Error> {
+    // Runs `body` with `el` bound to the first match of `selector`.
+    let element = |cdp: &mut cdp::Cdp, selector: &str, body: &str| {
+        let script = format!(
+            "(() => {{ const el = document.querySelector({}); if (!el) return null; {} }})()",
+            Value::from(selector),
+            body
+        );
+        match evaluate(cdp, page, &script)? {
+            Value::Null => anyhow::bail!("No element matches selector '{}'", selector),
+            value => Ok(value),
+        }
+    };
+
+    Ok(match command {
+        PageCommand::Navigate { url } => {
+            let navigated =
+                cdp.call(Some(page), "Page.navigate", serde_json::json!({"url": url}))?;
+            if let Some(error) = navigated["errorText"].as_str().filter(|e| !e.is_empty()) {
+                anyhow::bail!("Could not load {}: {}", url, error);
+            }
+            // Same-document navigations (e.g. `#anchor`) start no loader.
+            if let Some(loader) = navigated["loaderId"].as_str().filter(|l| !l.is_empty()) {
+                loop {
+                    let event = cdp.next_event("the page to load")?;
+                    if event["method"] == "Page.lifecycleEvent"
+                        && event["params"]["name"] == "load"
+                        && event["params"]["loaderId"] == loader
+                    {
+                        break;
+                    }
+                }
+            }
+            serde_json::json!({
+                "action": "navigate",
+                "url": evaluate(cdp, page, "location.href")?,
+                "title": evaluate(cdp, page, "document.title")?,
+            })
+        }
+        PageCommand::WaitForSelector {
+            selector,
+            timeout_ms,
+        } => {
+            let until = timeout_ms
+                .map(|ms| Instant::now() + Duration::from_millis(ms))
+                .map_or(deadline, |until| until.min(deadline));
+            let check = format!("!!document.querySelector({})", Value::from(selector.as_str()));
+            while evaluate(cdp, page, &check)? != Value::Bool(true) {
+                let remaining = until.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    anyhow::bail!("Timed out waiting for selector '{}'", selector);
+                }
+                std::thread::sleep(remaining.min(Duration::from_millis(250)));
+            }
+            serde_json::json!({"action": "wait_for_selector", "selector": selector})
+        }
+        PageCommand::Text { selector } => {
+            let content = match selector {
+                Some(selector) => element(cdp, selector, "return el.innerText;")?,
+                None => evaluate(cdp, page, "document.body ? document.body.innerText : ''")?,
+            };
+            serde_json::json!({"action": "text", "selector": selector, "content": content})
+        }
+        PageCommand::Html { selector } => {
+            let content = match selector {
+                Some(selector) => element(cdp, selector, "return el.outerHTML;")?,
+                None => evaluate(cdp, page, "document.documentElement.outerHTML")?,
+            };
+            serde_json::json!({"action": "html", "selector": selector, "content": content})
+        }
+        PageCommand::Click { selector } => {
+            let point = element(
+                cdp,
+                selector,
+                "el.scrollIntoView({block: 'center'}); const r = el.getBoundingClientRect(); \
+                 return {x: r.left + r.width / 2, y: r.top + r.height / 2};",
+            )?;
+            // A real mouse click, so page handlers see trusted events.
+            for kind in ["mouseMoved", "mousePressed", "mouseReleased"] {
+                cdp.call(
+                    Some(page),
+                    "Input.dispatchMouseEvent",
+                    serde_json::json!({
+                        "type": kind,
+                        "x": point["x"],
+                        "y": point["y"],
+                        "button": "left",
+                        "clickCount": 1,
+                    }),
+                )?;
+            }
+            serde_json::json!({"action": "click", "selector": selector})
+        }
+        PageCommand::Fill { selector, value } => {
+            element(
+                cdp,
+                selector,
+                "el.focus(); if ('value' in el) el.value = ''; else el.textContent = ''; \
+                 return true;",
+            )?;
+            cdp.call(Some(page), "Input.insertText", serde_json::json!({"text": value}))?;
+            serde_json::json!({"action": "fill", "selector": selector})
+        }
+        PageCommand::Screenshot { full_page } => {
+            let shot = cdp.call(
+                Some(page),
+                "Page.captureScreenshot",
+                serde_json::json!({"format": "png", "captureBeyondViewport": full_page}),
+            )?;
+            serde_json::json!({"action": "screenshot", "content": shot["data"]})
+        }
+        PageCommand::Close => serde_json::json!({"action": "close"}),
+    })
+}
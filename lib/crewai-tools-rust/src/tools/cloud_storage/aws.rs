@@ -0,0 +1,457 @@
+//! AWS credentials, regions and Signature Version 4 request signing for the
+//! cloud storage tools.
+//!
+//! Credentials come from the standard provider chain: explicit struct
+//! fields, then `AWS_ACCESS_KEY_ID` / `AWS_SECRET_ACCESS_KEY` /
+//! `AWS_SESSION_TOKEN`, then the shared credentials file (`AWS_PROFILE`,
+//! `AWS_SHARED_CREDENTIALS_FILE`), then the EC2 instance metadata service.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use super::{hex, uri_encode};
+
+const IMDS_ENDPOINT: &str = "http://169.254.169.254";
+
+/// A resolved set of AWS credentials.
+#[derive(Clone)]
+pub(super) struct Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+    /// When temporary credentials stop working, if known.
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl Credentials {
+    /// Resolve credentials, preferring the explicit `access_key_id` /
+    /// `secret_access_key` pair when one is configured.
+    pub(super) fn resolve(
+        access_key_id: Option<&str>,
+        secret_access_key: Option<&str>,
+        session_token: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        match (access_key_id, secret_access_key) {
+            (Some(id), Some(secret)) => {
+                return Ok(Self {
+                    access_key_id: id.to_string(),
+                    secret_access_key: secret.to_string(),
+                    session_token: session_token.map(str::to_string),
+                    expiration: None,
+                })
+            }
+            (None, None) => {}
+            _ => anyhow::bail!("access_key_id and secret_access_key must be set together"),
+        }
+        if let (Ok(id), Ok(secret)) = (
+            std::env::var("AWS_ACCESS_KEY_ID"),
+            std::env::var("AWS_SECRET_ACCESS_KEY"),
+        ) {
+            return Ok(Self {
+                access_key_id: id,
+                secret_access_key: secret,
+                session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+                expiration: std::env::var("AWS_CREDENTIAL_EXPIRATION")
+                    .ok()
+                    .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+                    .map(|at| at.with_timezone(&Utc)),
+            });
+        }
+        if let Some(credentials) = from_profile()? {
+            return Ok(credentials);
+        }
+        let imds_disabled = std::env::var("AWS_EC2_METADATA_DISABLED")
+            .is_ok_and(|v| v.eq_ignore_ascii_case("true"));
+        if !imds_disabled {
+            if let Some(credentials) = from_instance_metadata() {
+                return Ok(credentials);
+            }
+        }
+        anyhow::bail!(
+            "No AWS credentials found: set access_key_id/secret_access_key, \
+             AWS_ACCESS_KEY_ID/AWS_SECRET_ACCESS_KEY, a profile in ~/.aws/credentials, \
+             or run with an instance role"
+        )
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("expiration", &self.expiration)
+            .finish()
+    }
+}
+
+/// The region to use: `explicit`, then `AWS_REGION`, then
+/// `AWS_DEFAULT_REGION`, then `us-east-1`.
+pub(super) fn region(explicit: Option<&str>) -> String {
+    explicit
+        .map(str::to_string)
+        .or_else(|| std::env::var("AWS_REGION").ok())
+        .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+        .unwrap_or_else(|| "us-east-1".to_string())
+}
+
+/// Read the active profile from the shared credentials file, if there is one.
+fn from_profile() -> Result<Option<Credentials>, anyhow::Error> {
+    let path = match std::env::var("AWS_SHARED_CREDENTIALS_FILE") {
+        Ok(path) => std::path::PathBuf::from(path),
+        Err(_) => {
+            let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))
+            else {
+                return Ok(None);
+            };
+            std::path::Path::new(&home).join(".aws").join("credentials")
+        }
+    };
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    let profile = std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string());
+    let mut in_profile = false;
+    let mut values = std::collections::HashMap::new();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            in_profile = section.trim() == profile;
+        } else if in_profile {
+            if let Some((key, value)) = line.split_once('=') {
+                values.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+    match (values.remove("aws_access_key_id"), values.remove("aws_secret_access_key")) {
+        (Some(id), Some(secret)) => Ok(Some(Credentials {
+            access_key_id: id,
+            secret_access_key: secret,
+            session_token: values.remove("aws_session_token"),
+            expiration: None,
+        })),
+        (None, None) if std::env::var("AWS_PROFILE").is_ok() => anyhow::bail!(
+            "AWS profile '{}' has no credentials in '{}'",
+            profile,
+            path.display()
+        ),
+        (None, None) => Ok(None),
+        _ => anyhow::bail!(
+            "AWS profile '{}' in '{}' needs both aws_access_key_id and aws_secret_access_key",
+            profile,
+            path.display()
+        ),
+    }
+}
+
+/// Fetch the instance role's credentials from IMDSv2. Any failure means
+/// "not on EC2" and yields `None`.
+fn from_instance_metadata() -> Option<Credentials> {
+    let endpoint = std::env::var("AWS_EC2_METADATA_SERVICE_ENDPOINT")
+        .unwrap_or_else(|_| IMDS_ENDPOINT.to_string());
+    let endpoint = endpoint.trim_end_matches('/');
+    let client = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(2))
+        .connect_timeout(Duration::from_secs(1))
+        .build()
+        .ok()?;
+    let token = client
+        .put(format!("{}/latest/api/token", endpoint))
+        .header("X-aws-ec2-metadata-token-ttl-seconds", "21600")
+        .send()
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .text()
+        .ok()?;
+    let get = |path: &str| {
+        client
+            .get(format!("{}/latest/meta-data/iam/security-credentials/{}", endpoint, path))
+            .header("X-aws-ec2-metadata-token", &token)
+            .send()
+            .ok()?
+            .error_for_status()
+            .ok()?
+            .text()
+            .ok()
+    };
+    let role = get("")?.lines().next()?.trim().to_string();
+    let body: serde_json::Value = serde_json::from_str(&get(&role)?).ok()?;
+    Some(Credentials {
+        access_key_id: body["AccessKeyId"].as_str()?.to_string(),
+        secret_access_key: body["SecretAccessKey"].as_str()?.to_string(),
+        session_token: body["Token"].as_str().map(str::to_string),
+        expiration: body["Expiration"]
+            .as_str()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc)),
+    })
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// A request to sign. `path` must already be URI-encoded; query pairs are
+/// raw and encoded here.
+pub(super) struct Request<'a> {
+    pub method: reqwest::Method,
+    /// Scheme, host and optional port, e.g. `https://bucket.s3.us-east-1.amazonaws.com`.
+    pub base: &'a str,
+    pub path: &'a str,
+    pub query: Vec<(&'a str, String)>,
+    pub headers: Vec<(&'a str, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request<'_> {
+    /// Build the signed request for `service` in `region` on `client`.
+    pub(super) fn sign(
+        self,
+        client: &reqwest::blocking::Client,
+        credentials: &Credentials,
+        region: &str,
+        service: &str,
+    ) -> Result<reqwest::blocking::RequestBuilder, anyhow::Error> {
+        let host = endpoint_host(self.base)?;
+        let now = Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let payload_hash = hex(&Sha256::digest(&self.body));
+        let query = canonical_query(&self.query);
+
+        let mut headers: Vec<(String, String)> = self
+            .headers
+            .iter()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v.trim().to_string()))
+            .collect();
+        headers.push(("host".to_string(), host));
+        headers.push(("x-amz-content-sha256".to_string(), payload_hash.clone()));
+        headers.push(("x-amz-date".to_string(), amz_date.clone()));
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token".to_string(), token.clone()));
+        }
+        headers.sort();
+        let signed_headers = signed_headers(&headers);
+        let (scope, signature) = signature(
+            credentials,
+            now,
+            region,
+            service,
+            &canonical_request(&self.method, self.path, &query, &headers, &payload_hash),
+        );
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.base.trim_end_matches('/'), self.path)
+        } else {
+            format!("{}{}?{}", self.base.trim_end_matches('/'), self.path, query)
+        };
+        let mut request = client.request(self.method, url);
+        for (name, value) in headers.into_iter().filter(|(name, _)| name != "host") {
+            request = request.header(name, value);
+        }
+        Ok(request
+            .header(
+                "authorization",
+                format!(
+                    "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                    credentials.access_key_id, scope, signed_headers, signature
+                ),
+            )
+            .body(self.body))
+    }
+
+    /// A presigned URL for this request, valid for `expires_in` seconds
+    /// from `now`. The request's headers are signed as well, so whoever
+    /// uses the URL must send them unchanged; the body is not.
+    ///
+    /// The expiry is checked here rather than left to AWS: it must be at
+    /// most seven days, and must not outlast temporary credentials, since
+    /// the link stops working when they expire.
+    pub(super) fn presign(
+        self,
+        credentials: &Credentials,
+        region: &str,
+        service: &str,
+        now: DateTime<Utc>,
+        expires_in: u64,
+    ) -> Result<String, anyhow::Error> {
+        if !(1..=MAX_PRESIGN_SECS).contains(&expires_in) {
+            anyhow::bail!(
+                "expires_in must be between 1 and {} seconds (7 days)",
+                MAX_PRESIGN_SECS
+            );
+        }
+        if let Some(expiration) = credentials.expiration {
+            let remaining = (expiration - now).num_seconds();
+            if expires_in as i64 > remaining {
+                anyhow::bail!(
+                    "The AWS credentials expire at {} ({}s from now), before a {}s link would; \
+                     use a shorter expires_in or long-term credentials",
+                    expiration.to_rfc3339(),
+                    remaining.max(0),
+                    expires_in
+                );
+            }
+        }
+        let host = endpoint_host(self.base)?;
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let scope = format!("{}/{}/{}/aws4_request", now.format("%Y%m%d"), region, service);
+
+        let mut headers: Vec<(String, String)> = self
+            .headers
+            .iter()
+            .map(|(k, v)| (k.to_ascii_lowercase(), v.trim().to_string()))
+            .collect();
+        headers.push(("host".to_string(), host));
+        headers.sort();
+        let signed_headers = signed_headers(&headers);
+
+        let mut query = self.query;
+        query.push(("X-Amz-Algorithm", "AWS4-HMAC-SHA256".to_string()));
+        query.push(("X-Amz-Credential", format!("{}/{}", credentials.access_key_id, scope)));
+        query.push(("X-Amz-Date", amz_date));
+        query.push(("X-Amz-Expires", expires_in.to_string()));
+        query.push(("X-Amz-SignedHeaders", signed_headers));
+        if let Some(token) = &credentials.session_token {
+            query.push(("X-Amz-Security-Token", token.clone()));
+        }
+        let query = canonical_query(&query);
+        let (_, signature) = signature(
+            credentials,
+            now,
+            region,
+            service,
+            &canonical_request(&self.method, self.path, &query, &headers, "UNSIGNED-PAYLOAD"),
+        );
+        Ok(format!(
+            "{}{}?{}&X-Amz-Signature={}",
+            self.base.trim_end_matches('/'),
+            self.path,
+            query,
+            signature
+        ))
+    }
+}
+
+/// Longest expiry SigV4 accepts for a presigned URL: seven days.
+pub(super) const MAX_PRESIGN_SECS: u64 = 7 * 24 * 3600;
+
+/// The `host` header value for `base`, with any explicit port.
+fn endpoint_host(base: &str) -> Result<String, anyhow::Error> {
+    let url = reqwest::Url::parse(base)
+        .map_err(|e| anyhow::anyhow!("Invalid AWS endpoint '{}': {}", base, e))?;
+    Ok(match url.port() {
+        Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+        None => url.host_str().unwrap_or_default().to_string(),
+    })
+}
+
+/// Encode and sort query pairs into the canonical query string.
+fn canonical_query(query: &[(&str, String)]) -> String {
+    let mut query: Vec<(String, String)> = query
+        .iter()
+        .map(|(k, v)| (uri_encode(k, false), uri_encode(v, false)))
+        .collect();
+    query.sort();
+    query
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// `a;b;c` from sorted, lowercased headers.
+fn signed_headers(headers: &[(String, String)]) -> String {
+    headers
+        .iter()
+        .map(|(k, _)| k.as_str())
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn canonical_request(
+    method: &reqwest::Method,
+    path: &str,
+    query: &str,
+    headers: &[(String, String)],
+    payload_hash: &str,
+) -> String {
+    let canonical_headers: String =
+        headers.iter().map(|(k, v)| format!("{}:{}\n", k, v)).collect();
+    format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        method,
+        path,
+        query,
+        canonical_headers,
+        signed_headers(headers),
+        payload_hash
+    )
+}
+
+/// The credential scope and hex signature of `canonical_request`.
+fn signature(
+    credentials: &Credentials,
+    now: DateTime<Utc>,
+    region: &str,
+    service: &str,
+    canonical_request: &str,
+) -> (String, String) {
+    let date = now.format("%Y%m%d").to_string();
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        now.format("%Y%m%dT%H%M%SZ"),
+        scope,
+        hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+    let key = hmac(format!("AWS4{}", credentials.secret_access_key).as_bytes(), &date);
+    let key = hmac(&hmac(&hmac(&key, region), service), "aws4_request");
+    (scope, hex(&hmac(&key, &string_to_sign)))
+}
+
+/// The `Code` and `Message` of an AWS XML error body; the message falls
+/// back to the raw body.
+pub(super) fn error_parts(body: &str) -> (Option<String>, String) {
+    let doc = roxmltree::Document::parse(body).ok();
+    let text = |name: &str| {
+        doc.as_ref()?
+            .descendants()
+            .find(|n| n.has_tag_name(name))
+            .and_then(|n| n.text())
+            .map(str::to_string)
+    };
+    let message = text("Message").unwrap_or_else(|| body.trim().to_string());
+    (text("Code"), message)
+}
+
+/// Exponential backoff with full jitter before retry `attempt` (0-based):
+/// a random wait up to `base_ms * 2^attempt`, so clients throttled together
+/// do not retry in lockstep.
+pub(super) fn jittered_backoff(base_ms: u64, attempt: u32) -> Duration {
+    use std::hash::{BuildHasher, Hasher};
+
+    let cap = base_ms.saturating_mul(1u64 << attempt.min(16));
+    // RandomState is randomly keyed per instance; good enough for jitter.
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u32(attempt);
+    Duration::from_millis(hasher.finish() % cap.saturating_add(1))
+}
+
+/// Whether an AWS JSON-protocol response is a throttling error.
+pub(super) fn is_throttled(resp: &reqwest::blocking::Response) -> bool {
+    resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || resp
+            .headers()
+            .get("x-amzn-errortype")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|t| t.starts_with("ThrottlingException"))
+}
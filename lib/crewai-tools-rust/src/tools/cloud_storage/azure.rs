@@ -0,0 +1,470 @@
+//! Azure Blob Storage REST client for `AzureBlobReaderTool` and
+//! `AzureBlobWriterTool`: connection strings, Shared Key and SAS
+//! authorization, reads, listings and uploads.
+//!
+//! Credentials come from an explicit connection string, an explicit
+//! account name with an account key or SAS token, or the Azure CLI's
+//! environment variables: `AZURE_STORAGE_CONNECTION_STRING`, then
+//! `AZURE_STORAGE_ACCOUNT` with `AZURE_STORAGE_KEY` or
+//! `AZURE_STORAGE_SAS_TOKEN`.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+
+use super::{read_object_body, uri_encode, xml_text, AzureBlobError};
+
+const API_VERSION: &str = "2021-08-06";
+/// Azurite's well-known development account (`UseDevelopmentStorage=true`).
+const DEV_ACCOUNT: &str = "devstoreaccount1";
+const DEV_KEY: &str =
+    "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+const DEV_ENDPOINT: &str = "http://127.0.0.1:10000/devstoreaccount1";
+
+/// How requests are authorized.
+enum Auth {
+    /// The decoded account key, for Shared Key signatures.
+    SharedKey(Vec<u8>),
+    /// A SAS token's query string, without the leading `?`.
+    Sas(String),
+}
+
+impl Auth {
+    fn shared_key(key: &str) -> Result<Self, anyhow::Error> {
+        STANDARD
+            .decode(key.trim())
+            .map(Self::SharedKey)
+            .map_err(|_| anyhow::anyhow!("Azure storage account key is not valid base64"))
+    }
+
+    fn sas(token: &str) -> Self {
+        Self::Sas(token.trim().trim_start_matches('?').to_string())
+    }
+}
+
+/// Account name, authorization and blob endpoint from a connection string.
+fn from_connection_string(conn: &str) -> Result<(String, Auth, String), anyhow::Error> {
+    let parts: HashMap<&str, &str> = conn
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(k, v)| (k.trim(), v.trim()))
+        .collect();
+    if parts.get("UseDevelopmentStorage").is_some_and(|v| v.eq_ignore_ascii_case("true")) {
+        return Ok((DEV_ACCOUNT.to_string(), Auth::shared_key(DEV_KEY)?, DEV_ENDPOINT.to_string()));
+    }
+    let endpoint = parts.get("BlobEndpoint").map(|e| e.to_string());
+    // SAS connection strings may carry only the endpoint, not the name.
+    let account = parts
+        .get("AccountName")
+        .map(|a| a.to_string())
+        .or_else(|| {
+            let host = reqwest::Url::parse(endpoint.as_deref()?).ok()?;
+            host.host_str()?.split('.').next().map(str::to_string)
+        })
+        .ok_or_else(|| anyhow::anyhow!("Azure connection string has no AccountName"))?;
+    let auth = match (parts.get("AccountKey"), parts.get("SharedAccessSignature")) {
+        (Some(key), _) => Auth::shared_key(key)?,
+        (None, Some(sas)) => Auth::sas(sas),
+        (None, None) => anyhow::bail!(
+            "Azure connection string has neither AccountKey nor SharedAccessSignature"
+        ),
+    };
+    let endpoint = endpoint.unwrap_or_else(|| {
+        format!(
+            "{}://{}.blob.{}",
+            parts.get("DefaultEndpointsProtocol").unwrap_or(&"https"),
+            account,
+            parts.get("EndpointSuffix").unwrap_or(&"core.windows.net")
+        )
+    });
+    Ok((account, auth, endpoint))
+}
+
+/// An authorized client for one container.
+pub(super) struct Client {
+    http: reqwest::blocking::Client,
+    account: String,
+    auth: Auth,
+    endpoint: String,
+    /// The endpoint's own path (Azurite puts the account name there).
+    endpoint_path: String,
+    container: String,
+}
+
+impl Client {
+    /// Resolve credentials for `container`. An explicit connection string
+    /// wins; explicit account settings are completed from the environment;
+    /// otherwise the environment's connection string, then its account
+    /// settings, are used.
+    pub(super) fn connect(
+        container: String,
+        connection_string: Option<&str>,
+        account_name: Option<&str>,
+        account_key: Option<&str>,
+        sas_token: Option<&str>,
+        endpoint_url: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let explicit = account_name.is_some() || account_key.is_some() || sas_token.is_some();
+        let connection_string = match connection_string {
+            Some(conn) => Some(conn.to_string()),
+            None if explicit => None,
+            None => env("AZURE_STORAGE_CONNECTION_STRING"),
+        };
+        let (account, auth, default_endpoint) = match connection_string {
+            Some(conn) => from_connection_string(&conn)?,
+            None => {
+                let account = account_name
+                    .map(str::to_string)
+                    .or_else(|| env("AZURE_STORAGE_ACCOUNT"))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "No Azure storage credentials: set a connection string or an \
+                             account name with an account key or SAS token (or \
+                             AZURE_STORAGE_CONNECTION_STRING / AZURE_STORAGE_ACCOUNT)"
+                        )
+                    })?;
+                let key = match (account_key, sas_token) {
+                    (Some(key), _) => Some(key.to_string()),
+                    (None, Some(_)) => None,
+                    (None, None) => env("AZURE_STORAGE_KEY"),
+                };
+                let sas = sas_token.map(str::to_string).or_else(|| env("AZURE_STORAGE_SAS_TOKEN"));
+                let auth = match (key, sas) {
+                    (Some(key), _) => Auth::shared_key(&key)?,
+                    (None, Some(sas)) => Auth::sas(&sas),
+                    (None, None) => anyhow::bail!(
+                        "No key or SAS token for Azure storage account '{}': set account_key \
+                         or sas_token (or AZURE_STORAGE_KEY / AZURE_STORAGE_SAS_TOKEN)",
+                        account
+                    ),
+                };
+                let endpoint = format!("https://{}.blob.core.windows.net", account);
+                (account, auth, endpoint)
+            }
+        };
+        let endpoint = endpoint_url
+            .unwrap_or(&default_endpoint)
+            .trim_end_matches('/')
+            .to_string();
+        let endpoint_path = reqwest::Url::parse(&endpoint)
+            .map_err(|e| anyhow::anyhow!("Invalid Azure blob endpoint '{}': {}", endpoint, e))?
+            .path()
+            .trim_end_matches('/')
+            .to_string();
+        Ok(Self {
+            http: reqwest::blocking::Client::builder()
+                .timeout(Duration::from_secs(120))
+                .build()?,
+            account,
+            auth,
+            endpoint,
+            endpoint_path,
+            container,
+        })
+    }
+
+    /// The blob's URL, without any SAS token.
+    pub(super) fn url(&self, blob: &str) -> String {
+        format!("{}/{}/{}", self.endpoint, self.container, uri_encode(blob, true))
+    }
+
+    /// Send a request for `blob` (`None` for the container itself).
+    fn send(
+        &self,
+        method: reqwest::Method,
+        blob: Option<&str>,
+        query: Vec<(&str, String)>,
+        mut headers: Vec<(&str, String)>,
+        body: Vec<u8>,
+    ) -> Result<reqwest::blocking::Response, anyhow::Error> {
+        let mut path = format!("/{}", self.container);
+        if let Some(blob) = blob {
+            path.push('/');
+            path.push_str(&uri_encode(blob, true));
+        }
+        let mut params: Vec<String> = query
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, uri_encode(v, false)))
+            .collect();
+        if let Auth::Sas(sas) = &self.auth {
+            params.push(sas.clone());
+        }
+        let mut url = format!("{}{}", self.endpoint, path);
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&params.join("&"));
+        }
+
+        headers.push(("x-ms-date", Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string()));
+        headers.push(("x-ms-version", API_VERSION.to_string()));
+        if let Auth::SharedKey(key) = &self.auth {
+            let resource = format!("{}{}", self.endpoint_path, path);
+            let signature =
+                self.sign(key, method.as_str(), &resource, &query, &headers, body.len());
+            headers.push(("authorization", format!("SharedKey {}:{}", self.account, signature)));
+        }
+        let mut request = self.http.request(method, url).body(body);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        Ok(request.send()?)
+    }
+
+    /// The Shared Key signature of a request, base64-encoded.
+    fn sign(
+        &self,
+        key: &[u8],
+        method: &str,
+        resource: &str,
+        query: &[(&str, String)],
+        headers: &[(&str, String)],
+        content_length: usize,
+    ) -> String {
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+                .unwrap_or("")
+        };
+        let mut string_to_sign = format!("{}\n", method);
+        for name in [
+            "content-encoding",
+            "content-language",
+            "content-length",
+            "content-md5",
+            "content-type",
+            "date",
+            "if-modified-since",
+            "if-match",
+            "if-none-match",
+            "if-unmodified-since",
+            "range",
+        ] {
+            match name {
+                "content-length" if content_length > 0 => {
+                    string_to_sign.push_str(&content_length.to_string())
+                }
+                "content-length" => {}
+                _ => string_to_sign.push_str(header(name)),
+            }
+            string_to_sign.push('\n');
+        }
+        let mut ms_headers: Vec<(String, &str)> = headers
+            .iter()
+            .filter(|(n, _)| n.to_ascii_lowercase().starts_with("x-ms-"))
+            .map(|(n, v)| (n.to_ascii_lowercase(), v.trim()))
+            .collect();
+        ms_headers.sort();
+        for (name, value) in ms_headers {
+            string_to_sign.push_str(&format!("{}:{}\n", name, value));
+        }
+        string_to_sign.push_str(&format!("/{}{}", self.account, resource));
+        let mut params: Vec<(String, &str)> =
+            query.iter().map(|(k, v)| (k.to_ascii_lowercase(), v.as_str())).collect();
+        params.sort();
+        for (name, value) in params {
+            string_to_sign.push_str(&format!("\n{}:{}", name, value));
+        }
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(string_to_sign.as_bytes());
+        STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    /// Map an error response to an [`AzureBlobError`].
+    fn failure(
+        &self,
+        operation: &str,
+        blob: &str,
+        resp: reqwest::blocking::Response,
+    ) -> anyhow::Error {
+        let status = resp.status();
+        let header_code = resp
+            .headers()
+            .get("x-ms-error-code")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = resp.text().unwrap_or_default();
+        let doc = roxmltree::Document::parse(&body).ok();
+        let root = doc.as_ref().map(|d| d.root_element());
+        let code = header_code.or_else(|| root.and_then(|r| xml_text(r, "Code")));
+        // Messages end with RequestId and Time lines; keep the first.
+        let message = root
+            .and_then(|r| xml_text(r, "Message"))
+            .and_then(|m| m.lines().next().map(str::to_string))
+            .unwrap_or_else(|| status.canonical_reason().unwrap_or("error").to_string());
+        let container = self.container.clone();
+        let key = blob.to_string();
+        let error = match (status.as_u16(), code.as_deref()) {
+            (_, Some("ContainerNotFound")) => AzureBlobError::ContainerNotFound {
+                account: self.account.clone(),
+                container,
+            },
+            (_, Some("BlobNotFound")) | (404, None) => {
+                AzureBlobError::BlobNotFound { container, key }
+            }
+            (401 | 403, _) => AzureBlobError::AccessDenied { container, key, code, message },
+            (_, Some("BlobAlreadyExists" | "ConditionNotMet")) | (409 | 412, None) => {
+                AzureBlobError::AlreadyExists { container, key }
+            }
+            _ => AzureBlobError::Api {
+                operation: operation.to_string(),
+                container,
+                key,
+                status: status.as_u16(),
+                code,
+                message,
+            },
+        };
+        error.into()
+    }
+
+    pub(super) fn get_object(
+        &self,
+        blob: &str,
+        range: Option<&str>,
+        max_bytes: u64,
+    ) -> Result<Value, anyhow::Error> {
+        let range = match range {
+            // Blob ranges have no suffix form; resolve `-500` to offsets.
+            Some(suffix) if suffix.starts_with("bytes=-") => {
+                let last: u64 = suffix["bytes=-".len()..].parse()?;
+                let size = self.blob_size(blob)?;
+                Some(format!("bytes={}-{}", size.saturating_sub(last), size.saturating_sub(1)))
+            }
+            other => other.map(str::to_string),
+        };
+        let headers = match &range {
+            Some(range) => vec![("x-ms-range", range.clone())],
+            None => Vec::new(),
+        };
+        let resp = self.send(reqwest::Method::GET, Some(blob), Vec::new(), headers, Vec::new())?;
+        if !resp.status().is_success() {
+            return Err(self.failure("read", blob, resp));
+        }
+        let uri = format!("az://{}/{}", self.container, blob);
+        let mut result = read_object_body(resp, &uri, range.as_deref(), max_bytes)?;
+        result["container"] = Value::from(self.container.as_str());
+        result["key"] = Value::from(blob);
+        Ok(result)
+    }
+
+    fn blob_size(&self, blob: &str) -> Result<u64, anyhow::Error> {
+        let resp =
+            self.send(reqwest::Method::HEAD, Some(blob), Vec::new(), Vec::new(), Vec::new())?;
+        if !resp.status().is_success() {
+            return Err(self.failure("read", blob, resp));
+        }
+        resp.headers()
+            .get("content-length")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(|| anyhow::anyhow!("Azure returned no size for blob '{}'", blob))
+    }
+
+    pub(super) fn list_objects(
+        &self,
+        prefix: &str,
+        delimiter: Option<&str>,
+        max_keys: u64,
+        mut marker: Option<String>,
+    ) -> Result<Value, anyhow::Error> {
+        let mut objects = Vec::new();
+        let mut common_prefixes = Vec::new();
+        loop {
+            let remaining = max_keys - objects.len() as u64 - common_prefixes.len() as u64;
+            let mut query = vec![
+                ("restype", "container".to_string()),
+                ("comp", "list".to_string()),
+                ("prefix", prefix.to_string()),
+                ("maxresults", remaining.min(5000).to_string()),
+            ];
+            if let Some(delimiter) = delimiter {
+                query.push(("delimiter", delimiter.to_string()));
+            }
+            if let Some(marker) = &marker {
+                query.push(("marker", marker.clone()));
+            }
+            let resp = self.send(reqwest::Method::GET, None, query, Vec::new(), Vec::new())?;
+            if !resp.status().is_success() {
+                return Err(self.failure("list", prefix, resp));
+            }
+            let body = resp.text()?;
+            let doc = roxmltree::Document::parse(&body)
+                .map_err(|e| anyhow::anyhow!("Invalid Azure blob listing: {}", e))?;
+            let root = doc.root_element();
+            let blobs = root.children().filter(|n| n.has_tag_name("Blobs"));
+            for node in blobs.flat_map(|b| b.children()).filter(|n| n.is_element()) {
+                if node.has_tag_name("Blob") {
+                    let properties = node.children().find(|n| n.has_tag_name("Properties"));
+                    let property = |name: &str| properties.and_then(|p| xml_text(p, name));
+                    // Listings give RFC 1123 dates; report RFC 3339 like S3.
+                    let last_modified = property("Last-Modified").map(|date| {
+                        chrono::DateTime::parse_from_rfc2822(&date)
+                            .map(|d| d.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+                            .unwrap_or(date)
+                    });
+                    objects.push(serde_json::json!({
+                        "key": xml_text(node, "Name"),
+                        "size": property("Content-Length").and_then(|s| s.parse::<u64>().ok()),
+                        "last_modified": last_modified,
+                    }));
+                } else if node.has_tag_name("BlobPrefix") {
+                    common_prefixes.extend(xml_text(node, "Name"));
+                }
+            }
+            marker = xml_text(root, "NextMarker").filter(|m| !m.is_empty());
+            let listed = (objects.len() + common_prefixes.len()) as u64;
+            if marker.is_none() || listed >= max_keys {
+                break;
+            }
+        }
+        Ok(serde_json::json!({
+            "container": self.container,
+            "prefix": prefix,
+            "objects": objects,
+            "common_prefixes": common_prefixes,
+            "truncated": marker.is_some(),
+            "next_continuation_token": marker,
+        }))
+    }
+
+    /// Upload a block blob in a single request.
+    pub(super) fn put_object(
+        &self,
+        blob: &str,
+        body: Vec<u8>,
+        content_type: &str,
+        if_not_exists: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let mut headers = vec![
+            ("x-ms-blob-type", "BlockBlob".to_string()),
+            ("content-type", content_type.to_string()),
+        ];
+        if if_not_exists {
+            headers.push(("if-none-match", "*".to_string()));
+        }
+        let resp = self.send(reqwest::Method::PUT, Some(blob), Vec::new(), headers, body)?;
+        if !resp.status().is_success() {
+            return Err(self.failure("write", blob, resp));
+        }
+        let header = |name: &str| {
+            resp.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim_matches('"').to_string())
+        };
+        Ok(serde_json::json!({
+            "etag": header("etag"),
+            "version_id": header("x-ms-version-id"),
+        }))
+    }
+}
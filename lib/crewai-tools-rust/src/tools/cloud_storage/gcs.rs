@@ -0,0 +1,485 @@
+//! Google Cloud Storage JSON API client for `GcsReaderTool` and
+//! `GcsWriterTool`: credentials, object reads, listings, uploads and V4
+//! signed URLs.
+//!
+//! Credentials come from an explicit access token, an explicit credentials
+//! file, or Application Default Credentials: `GOOGLE_APPLICATION_CREDENTIALS`,
+//! then gcloud's `application_default_credentials.json`, then the GCE
+//! metadata server. Credentials files may hold a service account key or
+//! gcloud user credentials (`authorized_user`).
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::{hex, read_object_body, uri_encode};
+
+const DEFAULT_ENDPOINT: &str = "https://storage.googleapis.com";
+const SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+const DEFAULT_TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+/// Longest lifetime GCS accepts for a V4 signed URL: seven days.
+const MAX_SIGNED_URL_SECS: u64 = 7 * 24 * 3600;
+
+/// A service account key file.
+#[derive(Clone, Deserialize)]
+pub(super) struct ServiceAccount {
+    client_email: String,
+    private_key: String,
+    #[serde(default = "default_token_uri")]
+    token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    DEFAULT_TOKEN_URI.to_string()
+}
+
+/// How requests are authorized.
+#[derive(Clone)]
+pub(super) enum Credentials {
+    /// A ready-made OAuth access token.
+    Token(String),
+    ServiceAccount(ServiceAccount),
+    /// gcloud user credentials (`gcloud auth application-default login`).
+    AuthorizedUser {
+        client_id: String,
+        client_secret: String,
+        refresh_token: String,
+    },
+    /// The GCE / GKE / Cloud Run metadata server.
+    Metadata,
+}
+
+impl Credentials {
+    pub(super) fn resolve(
+        access_token: Option<&str>,
+        credentials_path: Option<&Path>,
+    ) -> Result<Self, anyhow::Error> {
+        if let Some(token) = access_token {
+            return Ok(Self::Token(token.to_string()));
+        }
+        if let Some(path) = credentials_path {
+            return Self::from_file(path);
+        }
+        if let Ok(path) = std::env::var("GOOGLE_APPLICATION_CREDENTIALS") {
+            return Self::from_file(Path::new(&path));
+        }
+        match gcloud_adc_path().filter(|path| path.is_file()) {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self::Metadata),
+        }
+    }
+
+    fn from_file(path: &Path) -> Result<Self, anyhow::Error> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            anyhow::anyhow!("Could not read GCP credentials '{}': {}", path.display(), e)
+        })?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Invalid GCP credentials '{}': {}", path.display(), e))?;
+        match json["type"].as_str() {
+            Some("service_account") => Ok(Self::ServiceAccount(serde_json::from_value(json)?)),
+            Some("authorized_user") => {
+                let field = |name: &str| {
+                    json[name].as_str().map(str::to_string).ok_or_else(|| {
+                        anyhow::anyhow!("GCP credentials '{}' lack {}", path.display(), name)
+                    })
+                };
+                Ok(Self::AuthorizedUser {
+                    client_id: field("client_id")?,
+                    client_secret: field("client_secret")?,
+                    refresh_token: field("refresh_token")?,
+                })
+            }
+            other => anyhow::bail!(
+                "Unsupported GCP credentials type {:?} in '{}': expected service_account or \
+                 authorized_user",
+                other,
+                path.display()
+            ),
+        }
+    }
+
+    /// An OAuth access token for these credentials.
+    fn access_token(&self, http: &reqwest::blocking::Client) -> Result<String, anyhow::Error> {
+        let resp = match self {
+            Self::Token(token) => return Ok(token.clone()),
+            Self::ServiceAccount(account) => http.post(&account.token_uri).form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", service_account_jwt(account)?.as_str()),
+            ]),
+            Self::AuthorizedUser {
+                client_id,
+                client_secret,
+                refresh_token,
+            } => http.post(DEFAULT_TOKEN_URI).form(&[
+                ("grant_type", "refresh_token"),
+                ("client_id", client_id.as_str()),
+                ("client_secret", client_secret.as_str()),
+                ("refresh_token", refresh_token.as_str()),
+            ]),
+            Self::Metadata => {
+                let host = std::env::var("GCE_METADATA_HOST")
+                    .unwrap_or_else(|_| "metadata.google.internal".to_string());
+                http.get(format!(
+                    "http://{}/computeMetadata/v1/instance/service-accounts/default/token",
+                    host
+                ))
+                .header("Metadata-Flavor", "Google")
+                .timeout(Duration::from_secs(3))
+            }
+        };
+        let resp = resp.send().map_err(|e| match self {
+            Self::Metadata => anyhow::anyhow!(
+                "No GCP credentials found: set credentials_path or \
+                 GOOGLE_APPLICATION_CREDENTIALS, run `gcloud auth application-default login`, \
+                 or run on GCP ({})",
+                e
+            ),
+            _ => e.into(),
+        })?;
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload["error_description"]
+                .as_str()
+                .or_else(|| payload["error"].as_str())
+                .unwrap_or("unknown error");
+            anyhow::bail!("GCP token request failed with status {}: {}", status, message);
+        }
+        payload["access_token"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("GCP token response has no access_token"))
+    }
+}
+
+/// Where gcloud keeps Application Default Credentials.
+fn gcloud_adc_path() -> Option<PathBuf> {
+    let config = match std::env::var_os("CLOUDSDK_CONFIG") {
+        Some(dir) => PathBuf::from(dir),
+        None if cfg!(windows) => PathBuf::from(std::env::var_os("APPDATA")?).join("gcloud"),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config").join("gcloud"),
+    };
+    Some(config.join("application_default_credentials.json"))
+}
+
+fn rsa_key(account: &ServiceAccount) -> Result<rsa::RsaPrivateKey, anyhow::Error> {
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs8::DecodePrivateKey;
+
+    rsa::RsaPrivateKey::from_pkcs8_pem(&account.private_key)
+        .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(&account.private_key))
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Private key of service account {} is not a valid RSA PEM key",
+                account.client_email
+            )
+        })
+}
+
+fn rsa_sign(account: &ServiceAccount, message: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+    use rsa::signature::{SignatureEncoding, Signer};
+
+    let signer = rsa::pkcs1v15::SigningKey::<Sha256>::new(rsa_key(account)?);
+    Ok(signer.sign(message).to_vec())
+}
+
+/// A JWT assertion for the OAuth token exchange, valid for one hour.
+fn service_account_jwt(account: &ServiceAccount) -> Result<String, anyhow::Error> {
+    let now = Utc::now().timestamp();
+    let header = serde_json::json!({"alg": "RS256", "typ": "JWT"});
+    let claims = serde_json::json!({
+        "iss": account.client_email,
+        "scope": SCOPE,
+        "aud": account.token_uri,
+        "iat": now,
+        "exp": now + 3600,
+    });
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(header.to_string()),
+        URL_SAFE_NO_PAD.encode(claims.to_string())
+    );
+    let signature = rsa_sign(account, signing_input.as_bytes())?;
+    Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// An authorized client for one endpoint.
+pub(super) struct Client {
+    http: reqwest::blocking::Client,
+    endpoint: String,
+    token: String,
+    credentials: Credentials,
+}
+
+impl Client {
+    /// Resolve credentials and fetch an access token. `endpoint` falls
+    /// back to `STORAGE_EMULATOR_HOST`, then the public endpoint.
+    pub(super) fn connect(
+        access_token: Option<&str>,
+        credentials_path: Option<&Path>,
+        endpoint: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(120))
+            .build()?;
+        let credentials = Credentials::resolve(access_token, credentials_path)?;
+        let token = credentials.access_token(&http)?;
+        let endpoint = endpoint
+            .map(str::to_string)
+            .or_else(|| {
+                std::env::var("STORAGE_EMULATOR_HOST").ok().map(|host| {
+                    if host.contains("://") {
+                        host
+                    } else {
+                        format!("http://{}", host)
+                    }
+                })
+            })
+            .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+        Ok(Self {
+            http,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            token,
+            credentials,
+        })
+    }
+
+    /// The object's `https://storage.googleapis.com/bucket/key` URL.
+    pub(super) fn url(&self, bucket: &str, key: &str) -> String {
+        format!("{}/{}/{}", self.endpoint, uri_encode(bucket, false), uri_encode(key, true))
+    }
+
+    fn failure(
+        &self,
+        operation: &str,
+        bucket: &str,
+        key: &str,
+        status: reqwest::StatusCode,
+        body: &str,
+    ) -> anyhow::Error {
+        let message = serde_json::from_str::<Value>(body)
+            .ok()
+            .and_then(|v| v.pointer("/error/message").and_then(|m| m.as_str()).map(String::from))
+            .unwrap_or_else(|| body.trim().to_string());
+        let uri = format!("gs://{}/{}", bucket, key);
+        match status.as_u16() {
+            404 if message.contains("bucket") => anyhow::anyhow!(
+                "GCS bucket '{}' does not exist; check the bucket name and project",
+                bucket
+            ),
+            404 => anyhow::anyhow!("GCS object {} not found", uri),
+            401 | 403 => anyhow::anyhow!(
+                "Access denied to {}: {}; check the credentials and the bucket's IAM roles",
+                uri,
+                message
+            ),
+            412 => anyhow::anyhow!("GCS object {} already exists", uri),
+            _ => anyhow::anyhow!(
+                "GCS {} of {} failed with status {}: {}",
+                operation,
+                uri,
+                status,
+                message
+            ),
+        }
+    }
+
+    pub(super) fn get_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        range: Option<&str>,
+        max_bytes: u64,
+    ) -> Result<Value, anyhow::Error> {
+        let mut request = self
+            .http
+            .get(format!(
+                "{}/storage/v1/b/{}/o/{}",
+                self.endpoint,
+                uri_encode(bucket, false),
+                uri_encode(key, false)
+            ))
+            .query(&[("alt", "media")])
+            .bearer_auth(&self.token);
+        if let Some(range) = range {
+            request = request.header("range", range);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            return Err(self.failure("read", bucket, key, status, &body));
+        }
+        let uri = format!("gs://{}/{}", bucket, key);
+        let mut result = read_object_body(resp, &uri, range, max_bytes)?;
+        result["bucket"] = Value::from(bucket);
+        result["key"] = Value::from(key);
+        Ok(result)
+    }
+
+    pub(super) fn list_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        delimiter: Option<&str>,
+        max_keys: u64,
+        mut page_token: Option<String>,
+    ) -> Result<Value, anyhow::Error> {
+        let mut objects = Vec::new();
+        let mut common_prefixes: Vec<String> = Vec::new();
+        loop {
+            let remaining = max_keys - objects.len() as u64 - common_prefixes.len() as u64;
+            let mut query = vec![
+                ("prefix", prefix.to_string()),
+                ("maxResults", remaining.min(1000).to_string()),
+            ];
+            if let Some(delimiter) = delimiter {
+                query.push(("delimiter", delimiter.to_string()));
+            }
+            if let Some(token) = &page_token {
+                query.push(("pageToken", token.clone()));
+            }
+            let resp = self
+                .http
+                .get(format!("{}/storage/v1/b/{}/o", self.endpoint, uri_encode(bucket, false)))
+                .query(&query)
+                .bearer_auth(&self.token)
+                .send()?;
+            let status = resp.status();
+            let body = resp.text()?;
+            if !status.is_success() {
+                return Err(self.failure("list", bucket, prefix, status, &body));
+            }
+            let page: Value = serde_json::from_str(&body)?;
+            for item in page["items"].as_array().into_iter().flatten() {
+                objects.push(serde_json::json!({
+                    "key": item["name"],
+                    // The JSON API sends 64-bit integers as strings.
+                    "size": item["size"].as_str().and_then(|s| s.parse::<u64>().ok()),
+                    "last_modified": item["updated"],
+                }));
+            }
+            common_prefixes.extend(
+                page["prefixes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|p| p.as_str().map(str::to_string)),
+            );
+            page_token = page["nextPageToken"].as_str().map(str::to_string);
+            let listed = (objects.len() + common_prefixes.len()) as u64;
+            if page_token.is_none() || listed >= max_keys {
+                break;
+            }
+        }
+        Ok(serde_json::json!({
+            "bucket": bucket,
+            "prefix": prefix,
+            "objects": objects,
+            "common_prefixes": common_prefixes,
+            "truncated": page_token.is_some(),
+            "next_continuation_token": page_token,
+        }))
+    }
+
+    pub(super) fn insert_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        body: Vec<u8>,
+        content_type: &str,
+        if_not_exists: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let mut query = vec![("uploadType", "media"), ("name", key)];
+        if if_not_exists {
+            query.push(("ifGenerationMatch", "0"));
+        }
+        let resp = self
+            .http
+            .post(format!(
+                "{}/upload/storage/v1/b/{}/o",
+                self.endpoint,
+                uri_encode(bucket, false)
+            ))
+            .query(&query)
+            .bearer_auth(&self.token)
+            .header("content-type", content_type)
+            .body(body)
+            .send()?;
+        let status = resp.status();
+        let text = resp.text()?;
+        if !status.is_success() {
+            return Err(self.failure("write", bucket, key, status, &text));
+        }
+        let object: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+        Ok(serde_json::json!({
+            "etag": object["etag"],
+            "version_id": object["generation"],
+        }))
+    }
+
+    /// A V4 signed URL granting `method` on the object for `expires_in`
+    /// seconds. Needs service account credentials to sign with.
+    pub(super) fn signed_url(
+        &self,
+        method: &str,
+        bucket: &str,
+        key: &str,
+        expires_in: u64,
+    ) -> Result<String, anyhow::Error> {
+        let Credentials::ServiceAccount(account) = &self.credentials else {
+            anyhow::bail!(
+                "Signed URLs need service account credentials (a key file in credentials_path \
+                 or GOOGLE_APPLICATION_CREDENTIALS)"
+            );
+        };
+        if !(1..=MAX_SIGNED_URL_SECS).contains(&expires_in) {
+            anyhow::bail!("expires_in must be between 1 and {} seconds", MAX_SIGNED_URL_SECS);
+        }
+        let endpoint = reqwest::Url::parse(&self.endpoint)?;
+        let host = match endpoint.port() {
+            Some(port) => format!("{}:{}", endpoint.host_str().unwrap_or_default(), port),
+            None => endpoint.host_str().unwrap_or_default().to_string(),
+        };
+        let now = Utc::now();
+        let timestamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let scope = format!("{}/auto/storage/goog4_request", now.format("%Y%m%d"));
+        let path = format!("/{}/{}", uri_encode(bucket, false), uri_encode(key, true));
+        let query = [
+            ("X-Goog-Algorithm", "GOOG4-RSA-SHA256".to_string()),
+            ("X-Goog-Credential", format!("{}/{}", account.client_email, scope)),
+            ("X-Goog-Date", timestamp.clone()),
+            ("X-Goog-Expires", expires_in.to_string()),
+            ("X-Goog-SignedHeaders", "host".to_string()),
+        ]
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, uri_encode(v, false)))
+        .collect::<Vec<_>>()
+        .join("&");
+        let canonical_request = format!(
+            "{}\n{}\n{}\nhost:{}\n\nhost\nUNSIGNED-PAYLOAD",
+            method, path, query, host
+        );
+        let string_to_sign = format!(
+            "GOOG4-RSA-SHA256\n{}\n{}\n{}",
+            timestamp,
+            scope,
+            hex(&Sha256::digest(canonical_request.as_bytes()))
+        );
+        let signature = hex(&rsa_sign(account, string_to_sign.as_bytes())?);
+        Ok(format!(
+            "{}://{}{}?{}&X-Goog-Signature={}",
+            endpoint.scheme(),
+            host,
+            path,
+            query,
+            signature
+        ))
+    }
+}
@@ -8,12 +8,59 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::builder::{Missing, Set};
+use crate::core::Document;
+
+mod aws;
+mod azure;
+mod gcs;
+
+/// S3 errors that need different fixes.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<S3Error>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum S3Error {
+    /// The bucket does not exist (or is in another account or region).
+    #[error("S3 bucket '{bucket}' does not exist; check the bucket name and region")]
+    NoSuchBucket { bucket: String },
+    /// The credentials lack permission for the operation.
+    #[error(
+        "Access denied to s3://{bucket}/{key}: {message}; check the credentials and the \
+         IAM or bucket policy"
+    )]
+    AccessDenied { bucket: String, key: String, message: String },
+    /// The object does not exist.
+    #[error("S3 object s3://{bucket}/{key} not found")]
+    NoSuchKey { bucket: String, key: String },
+    /// A write with `if_not_exists` found an object already at the key.
+    #[error("S3 object s3://{bucket}/{key} already exists")]
+    AlreadyExists { bucket: String, key: String },
+    /// Any other error response.
+    #[error("S3 {operation} of s3://{bucket}/{key} failed (HTTP {status}): {message}")]
+    Api {
+        operation: String,
+        bucket: String,
+        key: String,
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+}
+
 // ── S3ReaderTool ─────────────────────────────────────────────────────────────
 
-/// Read objects from Amazon S3 buckets.
+/// Read objects from Amazon S3 buckets, or list the keys under a prefix.
 ///
 /// Corresponds to Python `S3ReaderTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Credentials follow the standard AWS provider chain (environment, shared
+/// credentials profile, EC2 instance role); the key fields override it.
+/// Objects with a text content type come back as `content`, anything else
+/// as base64 in `content_base64`. Reads larger than `max_bytes` are refused
+/// unless a `range` narrows them, so an agent can sample the start of a
+/// large file without downloading all of it.
+/// The `presign` action hands out a time-limited download link instead.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct S3ReaderTool {
     /// AWS region.
     pub region: Option<String>,
@@ -23,6 +70,34 @@ pub struct S3ReaderTool {
     pub access_key_id: Option<String>,
     /// AWS secret access key (prefer environment variables or IAM roles).
     pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials.
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// S3-compatible endpoint (MinIO, LocalStack); uses path-style URLs.
+    /// Falls back to `AWS_ENDPOINT_URL_S3`, then `AWS_ENDPOINT_URL`.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Largest read returned, in bytes.
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: u64,
+}
+
+fn default_max_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+impl std::fmt::Debug for S3ReaderTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3ReaderTool")
+            .field("region", &self.region)
+            .field("bucket", &self.bucket)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| "<redacted>"))
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_bytes", &self.max_bytes)
+            .finish()
+    }
 }
 
 impl S3ReaderTool {
@@ -32,6 +107,9 @@ impl S3ReaderTool {
             bucket: None,
             access_key_id: None,
             secret_access_key: None,
+            session_token: None,
+            endpoint_url: None,
+            max_bytes: default_max_bytes(),
         }
     }
 
@@ -55,8 +133,75 @@ impl S3ReaderTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("S3ReaderTool: not yet implemented - requires AWS S3 SDK integration")
+    pub fn with_session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = bytes;
+        self
+    }
+
+    /// Read an object, list keys, or presign a download link.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `read`, `list` or `presign`; defaults to `list` when
+    ///   only a `prefix` is given and `read` otherwise. `presign` returns
+    ///   a time-limited GET `url` and its `expires_at` instead of the
+    ///   content.
+    /// * `file_path` - `s3://bucket/key` to read, instead of `bucket`/`key`.
+    /// * `bucket` - Bucket, overriding the configured one.
+    /// * `key` - Object key to read.
+    /// * `range` - Bytes to read, e.g. `0-1023`, `1024-` or `-500` (the last
+    ///   500); a `bytes=` prefix is optional.
+    /// * `prefix` - Key prefix to list.
+    /// * `delimiter` - Group keys sharing a prefix up to this character
+    ///   (usually `/`) into `common_prefixes`.
+    /// * `max_keys` - Most keys to list (default 1000).
+    /// * `continuation_token` - Resume a truncated listing.
+    /// * `expires_in` - Lifetime of a presigned URL in seconds (default 900,
+    ///   at most seven days, and no longer than temporary credentials last).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let str_arg = |name: &str| args.get(name).and_then(|v| v.as_str()).map(str::to_string);
+        let (bucket, key) = storage_target(&args, self.bucket.as_deref(), "s3", "bucket")?;
+        let action = match str_arg("action") {
+            Some(action) => action,
+            None if key.is_none() && args.contains_key("prefix") => "list".to_string(),
+            None => "read".to_string(),
+        };
+
+        let s3 = S3::connect(
+            bucket,
+            self.region.as_deref(),
+            self.access_key_id.as_deref(),
+            self.secret_access_key.as_deref(),
+            self.session_token.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        match action.as_str() {
+            "read" => {
+                let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+                let range = str_arg("range").map(|r| byte_range(&r)).transpose()?;
+                s3.get_object(&key, range.as_deref(), self.max_bytes)
+            }
+            "list" => s3.list_objects(
+                str_arg("prefix").as_deref().unwrap_or(""),
+                str_arg("delimiter").as_deref(),
+                args.get("max_keys").and_then(|v| v.as_u64()).unwrap_or(1000).max(1),
+                str_arg("continuation_token"),
+            ),
+            "presign" => {
+                let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+                s3.presign(reqwest::Method::GET, &key, Vec::new(), presign_expiry(&args))
+            }
+            other => anyhow::bail!("Unknown action '{}': expected read, list or presign", other),
+        }
     }
 }
 
@@ -66,12 +211,577 @@ impl Default for S3ReaderTool {
     }
 }
 
+/// The `expires_in` argument of a presign request, in seconds.
+fn presign_expiry(args: &HashMap<String, Value>) -> u64 {
+    args.get("expires_in").and_then(|v| v.as_u64()).unwrap_or(DEFAULT_PRESIGN_SECS)
+}
+
+/// Presigned URLs last 15 minutes unless `expires_in` says otherwise.
+const DEFAULT_PRESIGN_SECS: u64 = 15 * 60;
+
+/// Normalize a `range` argument to an HTTP `Range` header value.
+fn byte_range(range: &str) -> Result<String, anyhow::Error> {
+    let spec = range.trim();
+    let spec = spec.strip_prefix("bytes=").unwrap_or(spec);
+    let valid = spec.split_once('-').is_some_and(|(start, end)| {
+        let number = |s: &str| s.is_empty() || s.parse::<u64>().is_ok();
+        number(start)
+            && number(end)
+            && !(start.is_empty() && end.is_empty())
+            && match (start.parse::<u64>(), end.parse::<u64>()) {
+                (Ok(start), Ok(end)) => start <= end,
+                _ => true,
+            }
+    });
+    if !valid {
+        anyhow::bail!("Invalid range '{}': expected e.g. 0-1023, 1024- or -500", range);
+    }
+    Ok(format!("bytes={}", spec))
+}
+
+/// The bucket and (optional) key named by `file_path`
+/// (`{scheme}://bucket/key`) or the `bucket_arg` and `key` arguments,
+/// falling back to `configured` for the bucket.
+fn storage_target(
+    args: &HashMap<String, Value>,
+    configured: Option<&str>,
+    scheme: &str,
+    bucket_arg: &str,
+) -> Result<(String, Option<String>), anyhow::Error> {
+    let str_arg = |name: &str| args.get(name).and_then(|v| v.as_str()).map(str::to_string);
+    let (bucket, key) = match str_arg("file_path") {
+        Some(path) => {
+            let (bucket, key) = path
+                .strip_prefix(scheme)
+                .and_then(|rest| rest.strip_prefix("://"))
+                .and_then(|rest| rest.split_once('/'))
+                .filter(|(bucket, key)| !bucket.is_empty() && !key.is_empty())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("file_path must look like {}://{}/key", scheme, bucket_arg)
+                })?;
+            (Some(bucket.to_string()), Some(key.to_string()))
+        }
+        None => (str_arg(bucket_arg), str_arg("key")),
+    };
+    let bucket = bucket
+        .or_else(|| configured.map(str::to_string))
+        .ok_or_else(|| anyhow::anyhow!("Missing required argument: {}", bucket_arg))?;
+    Ok((bucket, key))
+}
+
+/// One bucket, with resolved credentials and addressing. Also used by the
+/// RAG `S3Loader`.
+pub(crate) struct S3 {
+    client: reqwest::blocking::Client,
+    credentials: aws::Credentials,
+    region: String,
+    endpoint: Option<String>,
+    bucket: String,
+}
+
+impl S3 {
+    pub(crate) fn connect(
+        bucket: String,
+        region: Option<&str>,
+        access_key_id: Option<&str>,
+        secret_access_key: Option<&str>,
+        session_token: Option<&str>,
+        endpoint_url: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            client: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(120))
+                .build()?,
+            credentials: aws::Credentials::resolve(
+                access_key_id,
+                secret_access_key,
+                session_token,
+            )?,
+            region: aws::region(region),
+            endpoint: endpoint_url
+                .map(str::to_string)
+                .or_else(|| std::env::var("AWS_ENDPOINT_URL_S3").ok())
+                .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok()),
+            bucket,
+        })
+    }
+
+    /// Base URL and path for `key` (empty for the bucket itself). Custom
+    /// endpoints and dotted bucket names use path-style addressing.
+    fn address(&self, key: &str) -> (String, String) {
+        let key = uri_encode(key, true);
+        match &self.endpoint {
+            Some(endpoint) => (
+                endpoint.trim_end_matches('/').to_string(),
+                format!("/{}/{}", uri_encode(&self.bucket, false), key),
+            ),
+            None if self.bucket.contains('.') => (
+                format!("https://s3.{}.amazonaws.com", self.region),
+                format!("/{}/{}", self.bucket, key),
+            ),
+            None => (
+                format!("https://{}.s3.{}.amazonaws.com", self.bucket, self.region),
+                format!("/{}", key),
+            ),
+        }
+    }
+
+    /// Public URL of `key`.
+    fn url(&self, key: &str) -> String {
+        let (base, path) = self.address(key);
+        format!("{}{}", base, path)
+    }
+
+    pub(crate) fn send(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        query: Vec<(&str, String)>,
+        headers: Vec<(&str, String)>,
+        body: Vec<u8>,
+    ) -> Result<reqwest::blocking::Response, anyhow::Error> {
+        let (base, path) = self.address(key);
+        let request = aws::Request {
+            method,
+            base: &base,
+            path: &path,
+            query,
+            headers,
+            body,
+        };
+        Ok(request.sign(&self.client, &self.credentials, &self.region, "s3")?.send()?)
+    }
+
+    /// A presigned `method` URL for `key`, valid for `expires_in` seconds.
+    /// `headers` are signed too and must be sent with the request.
+    fn presign(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        headers: Vec<(&str, String)>,
+        expires_in: u64,
+    ) -> Result<Value, anyhow::Error> {
+        let (base, path) = self.address(key);
+        let now = chrono::Utc::now();
+        let method_name = method.to_string();
+        let required_headers: serde_json::Map<String, Value> =
+            headers.iter().map(|(k, v)| (k.to_string(), Value::from(v.as_str()))).collect();
+        let request = aws::Request {
+            method,
+            base: &base,
+            path: &path,
+            query: Vec::new(),
+            headers,
+            body: Vec::new(),
+        };
+        let url = request.presign(&self.credentials, &self.region, "s3", now, expires_in)?;
+        let expires_at = now + chrono::Duration::seconds(expires_in as i64);
+        Ok(serde_json::json!({
+            "bucket": self.bucket,
+            "key": key,
+            "method": method_name,
+            "url": url,
+            "headers": required_headers,
+            "expires_in": expires_in,
+            "expires_at": expires_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        }))
+    }
+
+    /// The error for a failed `operation` on `key`, from S3's error body.
+    pub(crate) fn failure(
+        &self,
+        operation: &str,
+        key: &str,
+        status: reqwest::StatusCode,
+        body: &str,
+    ) -> anyhow::Error {
+        let (code, message) = aws::error_parts(body);
+        let bucket = self.bucket.clone();
+        let key = key.to_string();
+        let error = match code.as_deref() {
+            Some("NoSuchBucket") => S3Error::NoSuchBucket { bucket },
+            Some("NoSuchKey") => S3Error::NoSuchKey { bucket, key },
+            None if status == reqwest::StatusCode::NOT_FOUND => S3Error::NoSuchKey { bucket, key },
+            Some("AccessDenied") => S3Error::AccessDenied { bucket, key, message },
+            None if status == reqwest::StatusCode::FORBIDDEN => {
+                S3Error::AccessDenied { bucket, key, message }
+            }
+            Some("PreconditionFailed") => S3Error::AlreadyExists { bucket, key },
+            None if status == reqwest::StatusCode::PRECONDITION_FAILED => {
+                S3Error::AlreadyExists { bucket, key }
+            }
+            _ => S3Error::Api {
+                operation: operation.to_string(),
+                bucket,
+                key,
+                status: status.as_u16(),
+                code,
+                message,
+            },
+        };
+        error.into()
+    }
+
+    fn get_object(
+        &self,
+        key: &str,
+        range: Option<&str>,
+        max_bytes: u64,
+    ) -> Result<Value, anyhow::Error> {
+        let headers = range.map(|r| vec![("range", r.to_string())]).unwrap_or_default();
+        let resp = self.send(reqwest::Method::GET, key, Vec::new(), headers, Vec::new())?;
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            return Err(self.failure("read", key, status, &body));
+        }
+        let uri = format!("s3://{}/{}", self.bucket, key);
+        let mut result = read_object_body(resp, &uri, range, max_bytes)?;
+        result["bucket"] = Value::from(self.bucket.as_str());
+        result["key"] = Value::from(key);
+        Ok(result)
+    }
+
+    pub(crate) fn list_objects(
+        &self,
+        prefix: &str,
+        delimiter: Option<&str>,
+        max_keys: u64,
+        mut continuation_token: Option<String>,
+    ) -> Result<Value, anyhow::Error> {
+        let mut objects = Vec::new();
+        let mut common_prefixes = Vec::new();
+        let mut truncated;
+        loop {
+            let remaining = max_keys - objects.len() as u64 - common_prefixes.len() as u64;
+            let mut query = vec![
+                ("list-type", "2".to_string()),
+                ("prefix", prefix.to_string()),
+                ("max-keys", remaining.min(1000).to_string()),
+            ];
+            if let Some(delimiter) = delimiter {
+                query.push(("delimiter", delimiter.to_string()));
+            }
+            if let Some(token) = &continuation_token {
+                query.push(("continuation-token", token.clone()));
+            }
+            let resp = self.send(reqwest::Method::GET, "", query, Vec::new(), Vec::new())?;
+            let status = resp.status();
+            let body = resp.text()?;
+            if !status.is_success() {
+                return Err(self.failure("list", prefix, status, &body));
+            }
+            let doc = roxmltree::Document::parse(&body)
+                .map_err(|e| anyhow::anyhow!("Invalid S3 listing: {}", e))?;
+            let root = doc.root_element();
+            for node in root.children().filter(|n| n.is_element()) {
+                if node.has_tag_name("Contents") {
+                    objects.push(serde_json::json!({
+                        "key": xml_text(node, "Key"),
+                        "size": xml_text(node, "Size").and_then(|s| s.parse::<u64>().ok()),
+                        "last_modified": xml_text(node, "LastModified"),
+                    }));
+                } else if node.has_tag_name("CommonPrefixes") {
+                    common_prefixes.extend(xml_text(node, "Prefix"));
+                }
+            }
+            truncated = xml_text(root, "IsTruncated").as_deref() == Some("true");
+            continuation_token = xml_text(root, "NextContinuationToken");
+            let listed = (objects.len() + common_prefixes.len()) as u64;
+            if !truncated || continuation_token.is_none() || listed >= max_keys {
+                break;
+            }
+        }
+        Ok(serde_json::json!({
+            "bucket": self.bucket,
+            "prefix": prefix,
+            "objects": objects,
+            "common_prefixes": common_prefixes,
+            "truncated": truncated,
+            "next_continuation_token": if truncated { continuation_token } else { None },
+        }))
+    }
+
+    fn put_object(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        mut headers: Vec<(&str, String)>,
+        if_not_exists: bool,
+    ) -> Result<Value, anyhow::Error> {
+        if if_not_exists {
+            headers.push(("if-none-match", "*".to_string()));
+        }
+        let resp = self.send(reqwest::Method::PUT, key, Vec::new(), headers, body)?;
+        let status = resp.status();
+        let header = |name: &str| {
+            resp.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim_matches('"').to_string())
+        };
+        let (etag, version_id) = (header("etag"), header("x-amz-version-id"));
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            return Err(self.failure("write", key, status, &body));
+        }
+        Ok(serde_json::json!({
+            "etag": etag,
+            "version_id": version_id,
+            "multipart": false,
+        }))
+    }
+
+    /// Upload `body` in `part_size` parts, aborting the upload on failure
+    /// so the parts do not linger (and accrue storage charges).
+    fn multipart_upload(
+        &self,
+        key: &str,
+        body: Vec<u8>,
+        headers: Vec<(&str, String)>,
+        part_size: usize,
+        if_not_exists: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let resp = self.send(
+            reqwest::Method::POST,
+            key,
+            vec![("uploads", String::new())],
+            headers,
+            Vec::new(),
+        )?;
+        let status = resp.status();
+        let text = resp.text()?;
+        if !status.is_success() {
+            return Err(self.failure("write", key, status, &text));
+        }
+        let upload_id = roxmltree::Document::parse(&text)
+            .ok()
+            .and_then(|doc| xml_text(doc.root_element(), "UploadId"))
+            .ok_or_else(|| anyhow::anyhow!("S3 did not return an UploadId: {}", text))?;
+
+        let uploaded = self.upload_parts(key, &upload_id, &body, part_size, if_not_exists);
+        if uploaded.is_err() {
+            let abort = self.send(
+                reqwest::Method::DELETE,
+                key,
+                vec![("uploadId", upload_id.clone())],
+                Vec::new(),
+                Vec::new(),
+            );
+            if let Err(e) = abort.and_then(|resp| Ok(resp.error_for_status()?)) {
+                log::warn!("Could not abort S3 multipart upload {}: {}", upload_id, e);
+            }
+        }
+        uploaded
+    }
+
+    fn upload_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        body: &[u8],
+        part_size: usize,
+        if_not_exists: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let mut manifest = String::from("<CompleteMultipartUpload>");
+        let mut parts = 0;
+        for (index, chunk) in body.chunks(part_size).enumerate() {
+            let number = index + 1;
+            let query = vec![
+                ("partNumber", number.to_string()),
+                ("uploadId", upload_id.to_string()),
+            ];
+            let resp = self.send(reqwest::Method::PUT, key, query, Vec::new(), chunk.to_vec())?;
+            let status = resp.status();
+            let etag = resp
+                .headers()
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            if !status.is_success() {
+                let text = resp.text().unwrap_or_default();
+                return Err(self.failure("write", key, status, &text));
+            }
+            let etag =
+                etag.ok_or_else(|| anyhow::anyhow!("S3 returned no ETag for part {}", number))?;
+            manifest.push_str(&format!(
+                "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                number, etag
+            ));
+            parts = number;
+        }
+        manifest.push_str("</CompleteMultipartUpload>");
+
+        let mut headers = vec![("content-type", "application/xml".to_string())];
+        if if_not_exists {
+            headers.push(("if-none-match", "*".to_string()));
+        }
+        let query = vec![("uploadId", upload_id.to_string())];
+        let resp = self.send(reqwest::Method::POST, key, query, headers, manifest.into_bytes())?;
+        let status = resp.status();
+        let version_id = resp
+            .headers()
+            .get("x-amz-version-id")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let text = resp.text()?;
+        // CompleteMultipartUpload can fail with a 200 and an <Error> body.
+        let doc = roxmltree::Document::parse(&text).ok();
+        let failed = doc.as_ref().is_some_and(|doc| doc.root_element().has_tag_name("Error"));
+        if !status.is_success() || failed {
+            return Err(self.failure("write", key, status, &text));
+        }
+        let etag = doc
+            .as_ref()
+            .and_then(|doc| xml_text(doc.root_element(), "ETag"))
+            .map(|etag| etag.trim_matches('"').to_string());
+        Ok(serde_json::json!({
+            "etag": etag,
+            "version_id": version_id,
+            "multipart": true,
+            "parts": parts,
+        }))
+    }
+}
+
+/// Read a successful object download into the result shape shared by the
+/// reader tools: `content_type`, `size`, `total_size`, `range`, and either
+/// `content` (text) or `content_base64`. `uri` names the object in errors.
+fn read_object_body(
+    resp: reqwest::blocking::Response,
+    uri: &str,
+    range: Option<&str>,
+    max_bytes: u64,
+) -> Result<Value, anyhow::Error> {
+    use base64::Engine;
+    use std::io::Read;
+
+    let header = |name: &str| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let content_type = header("content-type");
+    // `Content-Range: bytes 0-1023/52428800` carries the full size.
+    let total_size = header("content-range")
+        .and_then(|r| r.rsplit_once('/').and_then(|(_, total)| total.parse::<u64>().ok()))
+        .or_else(|| resp.content_length());
+    if let Some(length) = resp.content_length().filter(|&len| len > max_bytes) {
+        anyhow::bail!(
+            "Object {} is {} bytes, over max_bytes ({}); pass a range such as 0-{} \
+             to read part of it",
+            uri,
+            length,
+            max_bytes,
+            max_bytes.saturating_sub(1)
+        );
+    }
+    let mut bytes = Vec::new();
+    resp.take(max_bytes + 1).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > max_bytes {
+        anyhow::bail!(
+            "Object {} is over max_bytes ({}); pass a range to read part of it",
+            uri,
+            max_bytes
+        );
+    }
+
+    let mut result = serde_json::json!({
+        "content_type": content_type,
+        "size": bytes.len(),
+        "total_size": total_size,
+    });
+    if let Some(range) = range {
+        result["range"] = Value::from(range);
+    }
+    // Untyped and octet-stream objects are text when they decode as such.
+    let texty = match content_type.as_deref() {
+        Some(content_type) => is_text_type(content_type),
+        None => true,
+    };
+    match String::from_utf8(bytes) {
+        Ok(text) if texty && !text.contains('\0') => result["content"] = Value::from(text),
+        // A range can end mid-character; keep text types as text.
+        Err(e) if texty && range.is_some() && e.utf8_error().error_len().is_none() => {
+            let valid = e.utf8_error().valid_up_to();
+            let mut bytes = e.into_bytes();
+            bytes.truncate(valid);
+            result["content"] = Value::from(String::from_utf8(bytes)?);
+        }
+        Ok(text) => {
+            result["content_base64"] =
+                base64::engine::general_purpose::STANDARD.encode(text).into()
+        }
+        Err(e) => {
+            result["content_base64"] =
+                base64::engine::general_purpose::STANDARD.encode(e.into_bytes()).into()
+        }
+    }
+    Ok(result)
+}
+
+/// Percent-encode `s` as AWS and GCS signatures expect: everything but
+/// unreserved characters, and `/` too unless `keep_slash`.
+fn uri_encode(s: &str, keep_slash: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            b'/' if keep_slash => out.push('/'),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Text of `node`'s first `name` child.
+fn xml_text(node: roxmltree::Node<'_, '_>, name: &str) -> Option<String> {
+    node.children()
+        .find(|n| n.has_tag_name(name))
+        .and_then(|n| n.text())
+        .map(str::to_string)
+}
+
+/// Content types that may be returned as text rather than base64.
+fn is_text_type(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    essence.starts_with("text/")
+        || essence == "application/octet-stream"
+        || essence.ends_with("+json")
+        || essence.ends_with("+xml")
+        || matches!(
+            essence.as_str(),
+            "application/json"
+                | "application/xml"
+                | "application/yaml"
+                | "application/x-yaml"
+                | "application/javascript"
+                | "application/x-ndjson"
+                | "application/csv"
+        )
+}
+
 // ── S3WriterTool ─────────────────────────────────────────────────────────────
 
 /// Write objects to Amazon S3 buckets.
 ///
 /// Corresponds to Python `S3WriterTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Content above `multipart_threshold` is sent as a multipart upload in
+/// `part_size` parts, and the upload is aborted if any part fails. With
+/// `if_not_exists`, writes are conditional and fail with
+/// [`S3Error::AlreadyExists`] instead of replacing an existing object.
+/// The `presign` action returns a time-limited upload link instead, for
+/// someone else to PUT the content. Credentials resolve as for
+/// [`S3ReaderTool`].
+#[derive(Clone, Serialize, Deserialize)]
 pub struct S3WriterTool {
     /// AWS region.
     pub region: Option<String>,
@@ -81,6 +791,57 @@ pub struct S3WriterTool {
     pub access_key_id: Option<String>,
     /// AWS secret access key (prefer environment variables or IAM roles).
     pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials.
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// S3-compatible endpoint (MinIO, LocalStack); uses path-style URLs.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Uploads larger than this many bytes use multipart upload.
+    #[serde(default = "default_multipart_threshold")]
+    pub multipart_threshold: usize,
+    /// Size of each multipart part, in bytes (S3's minimum is 5 MiB).
+    #[serde(default = "default_part_size")]
+    pub part_size: usize,
+    /// Server-side encryption: `AES256` or `aws:kms`.
+    #[serde(default)]
+    pub server_side_encryption: Option<String>,
+    /// KMS key for `aws:kms` encryption.
+    #[serde(default)]
+    pub kms_key_id: Option<String>,
+    /// Storage class, e.g. `STANDARD_IA` or `GLACIER_IR`.
+    #[serde(default)]
+    pub storage_class: Option<String>,
+    /// Fail instead of overwriting an existing object.
+    #[serde(default)]
+    pub if_not_exists: bool,
+}
+
+fn default_multipart_threshold() -> usize {
+    16 * 1024 * 1024
+}
+
+fn default_part_size() -> usize {
+    8 * 1024 * 1024
+}
+
+impl std::fmt::Debug for S3WriterTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3WriterTool")
+            .field("region", &self.region)
+            .field("bucket", &self.bucket)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| "<redacted>"))
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("multipart_threshold", &self.multipart_threshold)
+            .field("part_size", &self.part_size)
+            .field("server_side_encryption", &self.server_side_encryption)
+            .field("kms_key_id", &self.kms_key_id)
+            .field("storage_class", &self.storage_class)
+            .field("if_not_exists", &self.if_not_exists)
+            .finish()
+    }
 }
 
 impl S3WriterTool {
@@ -90,6 +851,14 @@ impl S3WriterTool {
             bucket: None,
             access_key_id: None,
             secret_access_key: None,
+            session_token: None,
+            endpoint_url: None,
+            multipart_threshold: default_multipart_threshold(),
+            part_size: default_part_size(),
+            server_side_encryption: None,
+            kms_key_id: None,
+            storage_class: None,
+            if_not_exists: false,
         }
     }
 
@@ -113,8 +882,152 @@ impl S3WriterTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("S3WriterTool: not yet implemented - requires AWS S3 SDK integration")
+    pub fn with_session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_multipart_threshold(mut self, bytes: usize) -> Self {
+        self.multipart_threshold = bytes;
+        self
+    }
+
+    pub fn with_part_size(mut self, bytes: usize) -> Self {
+        self.part_size = bytes;
+        self
+    }
+
+    /// Encrypt with `AES256` (S3-managed keys) or `aws:kms`.
+    pub fn with_server_side_encryption(mut self, algorithm: impl Into<String>) -> Self {
+        self.server_side_encryption = Some(algorithm.into());
+        self
+    }
+
+    /// Encrypt with this KMS key (implies `aws:kms`).
+    pub fn with_kms_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.kms_key_id = Some(key_id.into());
+        self
+    }
+
+    pub fn with_storage_class(mut self, class: impl Into<String>) -> Self {
+        self.storage_class = Some(class.into());
+        self
+    }
+
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Upload an object, or presign an upload link.
+    ///
+    /// Returns `{bucket, key, url, etag, size, multipart}` (plus `parts`
+    /// for multipart uploads and `version_id` on versioned buckets). With
+    /// `action: presign`, returns `{bucket, key, method, url, headers,
+    /// expires_in, expires_at}` for a PUT that someone else performs; it
+    /// must send `headers` as given.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `write` (the default) or `presign`.
+    /// * `file_path` - `s3://bucket/key` to write, instead of `bucket`/`key`.
+    /// * `bucket` - Bucket, overriding the configured one.
+    /// * `key` - Object key to write.
+    /// * `content` - Text to write.
+    /// * `content_base64` - Binary content, base64-encoded, instead of `content`.
+    /// * `content_type` - MIME type (defaults to `text/plain; charset=utf-8`
+    ///   for `content` and `application/octet-stream` for `content_base64`).
+    /// * `if_not_exists` - Fail if the key exists (always on when configured).
+    /// * `expires_in` - Lifetime of a presigned URL in seconds (default 900,
+    ///   at most seven days, and no longer than temporary credentials last).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use base64::Engine;
+
+        let (bucket, key) = storage_target(&args, self.bucket.as_deref(), "s3", "bucket")?;
+        let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+        match args.get("action").and_then(|v| v.as_str()).unwrap_or("write") {
+            "write" => {}
+            "presign" => {
+                // Only a content type the caller asked for is pinned.
+                let headers = args
+                    .get("content_type")
+                    .and_then(|v| v.as_str())
+                    .map(|ct| vec![("content-type", ct.to_string())])
+                    .unwrap_or_default();
+                let s3 = S3::connect(
+                    bucket,
+                    self.region.as_deref(),
+                    self.access_key_id.as_deref(),
+                    self.secret_access_key.as_deref(),
+                    self.session_token.as_deref(),
+                    self.endpoint_url.as_deref(),
+                )?;
+                return s3.presign(reqwest::Method::PUT, &key, headers, presign_expiry(&args));
+            }
+            other => anyhow::bail!("Unknown action '{}': expected write or presign", other),
+        }
+        let (body, default_type) = match (
+            args.get("content").and_then(|v| v.as_str()),
+            args.get("content_base64").and_then(|v| v.as_str()),
+        ) {
+            (Some(text), None) => (text.as_bytes().to_vec(), "text/plain; charset=utf-8"),
+            (None, Some(encoded)) => (
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded.trim())
+                    .map_err(|e| anyhow::anyhow!("content_base64 is not valid base64: {}", e))?,
+                "application/octet-stream",
+            ),
+            (Some(_), Some(_)) => anyhow::bail!("Pass either content or content_base64, not both"),
+            (None, None) => {
+                anyhow::bail!("Missing required argument: content (or content_base64)")
+            }
+        };
+        let content_type = args
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or(default_type)
+            .to_string();
+        let if_not_exists = self.if_not_exists
+            || args.get("if_not_exists").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let mut headers = vec![("content-type", content_type)];
+        let encryption = self
+            .server_side_encryption
+            .clone()
+            .or_else(|| self.kms_key_id.as_ref().map(|_| "aws:kms".to_string()));
+        if let Some(algorithm) = encryption {
+            headers.push(("x-amz-server-side-encryption", algorithm));
+        }
+        if let Some(key_id) = &self.kms_key_id {
+            headers.push(("x-amz-server-side-encryption-aws-kms-key-id", key_id.clone()));
+        }
+        if let Some(class) = &self.storage_class {
+            headers.push(("x-amz-storage-class", class.clone()));
+        }
+
+        let s3 = S3::connect(
+            bucket,
+            self.region.as_deref(),
+            self.access_key_id.as_deref(),
+            self.secret_access_key.as_deref(),
+            self.session_token.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        let size = body.len();
+        let mut result = if size > self.multipart_threshold {
+            s3.multipart_upload(&key, body, headers, self.part_size.max(1), if_not_exists)?
+        } else {
+            s3.put_object(&key, body, headers, if_not_exists)?
+        };
+        result["bucket"] = Value::from(s3.bucket.as_str());
+        result["key"] = Value::from(key.as_str());
+        result["url"] = Value::from(s3.url(&key));
+        result["size"] = Value::from(size);
+        Ok(result)
     }
 }
 
@@ -124,6 +1037,648 @@ impl Default for S3WriterTool {
     }
 }
 
+// ── GcsReaderTool ────────────────────────────────────────────────────────────
+
+/// Read objects from Google Cloud Storage, list the names under a prefix,
+/// or create signed URLs for sharing them.
+///
+/// The Google Cloud counterpart of [`S3ReaderTool`], with the same
+/// arguments and result shapes. Credentials come from `access_token`,
+/// a key file in `credentials_path`, or Application Default Credentials
+/// (`GOOGLE_APPLICATION_CREDENTIALS`, gcloud's login, or the metadata
+/// server on GCP). Signed URLs need a service account key.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GcsReaderTool {
+    /// GCS bucket name.
+    pub bucket: Option<String>,
+    /// Service account key or gcloud user credentials file.
+    #[serde(default)]
+    pub credentials_path: Option<std::path::PathBuf>,
+    /// OAuth access token, used as is instead of `credentials_path`.
+    #[serde(default, skip_serializing)]
+    pub access_token: Option<String>,
+    /// Alternative endpoint, e.g. a fake-gcs-server emulator. Falls back to
+    /// `STORAGE_EMULATOR_HOST`.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Largest read returned, in bytes.
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: u64,
+}
+
+impl std::fmt::Debug for GcsReaderTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcsReaderTool")
+            .field("bucket", &self.bucket)
+            .field("credentials_path", &self.credentials_path)
+            .field("access_token", &self.access_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_bytes", &self.max_bytes)
+            .finish()
+    }
+}
+
+impl GcsReaderTool {
+    pub fn new() -> Self {
+        Self {
+            bucket: None,
+            credentials_path: None,
+            access_token: None,
+            endpoint_url: None,
+            max_bytes: default_max_bytes(),
+        }
+    }
+
+    pub fn with_bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    pub fn with_credentials_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.credentials_path = Some(path.into());
+        self
+    }
+
+    pub fn with_access_token(mut self, token: impl Into<String>) -> Self {
+        self.access_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = bytes;
+        self
+    }
+
+    /// Read an object, list names, or sign a download URL.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `read`, `list` or `signed_url`; defaults to `list` when
+    ///   only a `prefix` is given and `read` otherwise.
+    /// * `file_path` - `gs://bucket/key` to read, instead of `bucket`/`key`.
+    /// * `bucket` - Bucket, overriding the configured one.
+    /// * `key` - Object name to read.
+    /// * `range` - Bytes to read, e.g. `0-1023`, `1024-` or `-500`.
+    /// * `prefix` - Name prefix to list.
+    /// * `delimiter` - Group names sharing a prefix up to this character
+    ///   (usually `/`) into `common_prefixes`.
+    /// * `max_keys` - Most names to list (default 1000).
+    /// * `continuation_token` - Resume a truncated listing.
+    /// * `expires_in` - Lifetime of a signed URL in seconds (default 3600,
+    ///   at most seven days).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let str_arg = |name: &str| args.get(name).and_then(|v| v.as_str()).map(str::to_string);
+        let (bucket, key) = storage_target(&args, self.bucket.as_deref(), "gs", "bucket")?;
+        let action = match str_arg("action") {
+            Some(action) => action,
+            None if key.is_none() && args.contains_key("prefix") => "list".to_string(),
+            None => "read".to_string(),
+        };
+        let key_arg =
+            || key.clone().ok_or_else(|| anyhow::anyhow!("Missing required argument: key"));
+
+        let gcs = gcs::Client::connect(
+            self.access_token.as_deref(),
+            self.credentials_path.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        match action.as_str() {
+            "read" => {
+                let range = str_arg("range").map(|r| byte_range(&r)).transpose()?;
+                gcs.get_object(&bucket, &key_arg()?, range.as_deref(), self.max_bytes)
+            }
+            "list" => gcs.list_objects(
+                &bucket,
+                str_arg("prefix").as_deref().unwrap_or(""),
+                str_arg("delimiter").as_deref(),
+                args.get("max_keys").and_then(|v| v.as_u64()).unwrap_or(1000).max(1),
+                str_arg("continuation_token"),
+            ),
+            "signed_url" => {
+                let key = key_arg()?;
+                let expires_in = args.get("expires_in").and_then(|v| v.as_u64()).unwrap_or(3600);
+                Ok(serde_json::json!({
+                    "bucket": bucket,
+                    "key": key,
+                    "signed_url": gcs.signed_url("GET", &bucket, &key, expires_in)?,
+                    "expires_in": expires_in,
+                }))
+            }
+            other => {
+                anyhow::bail!("Unknown action '{}': expected read, list or signed_url", other)
+            }
+        }
+    }
+}
+
+impl Default for GcsReaderTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── GcsWriterTool ────────────────────────────────────────────────────────────
+
+/// Write objects to Google Cloud Storage.
+///
+/// The Google Cloud counterpart of [`S3WriterTool`], with the same
+/// arguments and result shape; `version_id` is the object's generation.
+/// With `if_not_exists`, writes fail instead of replacing an existing
+/// object. Credentials resolve as for [`GcsReaderTool`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GcsWriterTool {
+    /// GCS bucket name.
+    pub bucket: Option<String>,
+    /// Service account key or gcloud user credentials file.
+    #[serde(default)]
+    pub credentials_path: Option<std::path::PathBuf>,
+    /// OAuth access token, used as is instead of `credentials_path`.
+    #[serde(default, skip_serializing)]
+    pub access_token: Option<String>,
+    /// Alternative endpoint, e.g. a fake-gcs-server emulator.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Fail instead of overwriting an existing object.
+    #[serde(default)]
+    pub if_not_exists: bool,
+}
+
+impl std::fmt::Debug for GcsWriterTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcsWriterTool")
+            .field("bucket", &self.bucket)
+            .field("credentials_path", &self.credentials_path)
+            .field("access_token", &self.access_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("if_not_exists", &self.if_not_exists)
+            .finish()
+    }
+}
+
+impl GcsWriterTool {
+    pub fn new() -> Self {
+        Self {
+            bucket: None,
+            credentials_path: None,
+            access_token: None,
+            endpoint_url: None,
+            if_not_exists: false,
+        }
+    }
+
+    pub fn with_bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    pub fn with_credentials_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.credentials_path = Some(path.into());
+        self
+    }
+
+    pub fn with_access_token(mut self, token: impl Into<String>) -> Self {
+        self.access_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Upload an object.
+    ///
+    /// Returns `{bucket, key, url, etag, version_id, size}`, plus
+    /// `signed_url` when one is requested.
+    ///
+    /// # Arguments (in `args`)
+    /// * `file_path` - `gs://bucket/key` to write, instead of `bucket`/`key`.
+    /// * `bucket` - Bucket, overriding the configured one.
+    /// * `key` - Object name to write.
+    /// * `content` - Text to write.
+    /// * `content_base64` - Binary content, base64-encoded, instead of `content`.
+    /// * `content_type` - MIME type (defaults to `text/plain; charset=utf-8`
+    ///   for `content` and `application/octet-stream` for `content_base64`).
+    /// * `if_not_exists` - Fail if the object exists (always on when configured).
+    /// * `signed_url` - Also return a signed download URL for sharing.
+    /// * `expires_in` - Lifetime of that URL in seconds (default 3600).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use base64::Engine;
+
+        let (bucket, key) = storage_target(&args, self.bucket.as_deref(), "gs", "bucket")?;
+        let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+        let (body, default_type) = match (
+            args.get("content").and_then(|v| v.as_str()),
+            args.get("content_base64").and_then(|v| v.as_str()),
+        ) {
+            (Some(text), None) => (text.as_bytes().to_vec(), "text/plain; charset=utf-8"),
+            (None, Some(encoded)) => (
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded.trim())
+                    .map_err(|e| anyhow::anyhow!("content_base64 is not valid base64: {}", e))?,
+                "application/octet-stream",
+            ),
+            (Some(_), Some(_)) => anyhow::bail!("Pass either content or content_base64, not both"),
+            (None, None) => {
+                anyhow::bail!("Missing required argument: content (or content_base64)")
+            }
+        };
+        let content_type = args
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or(default_type);
+        let if_not_exists = self.if_not_exists
+            || args.get("if_not_exists").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let gcs = gcs::Client::connect(
+            self.access_token.as_deref(),
+            self.credentials_path.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        let size = body.len();
+        let mut result = gcs.insert_object(&bucket, &key, body, content_type, if_not_exists)?;
+        result["bucket"] = Value::from(bucket.as_str());
+        result["key"] = Value::from(key.as_str());
+        result["url"] = Value::from(gcs.url(&bucket, &key));
+        result["size"] = Value::from(size);
+        if args.get("signed_url").and_then(|v| v.as_bool()).unwrap_or(false) {
+            let expires_in = args.get("expires_in").and_then(|v| v.as_u64()).unwrap_or(3600);
+            result["signed_url"] = Value::from(gcs.signed_url("GET", &bucket, &key, expires_in)?);
+        }
+        Ok(result)
+    }
+}
+
+impl Default for GcsWriterTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── AzureBlobReaderTool ──────────────────────────────────────────────────────
+
+/// Azure Blob Storage errors that need different fixes.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<AzureBlobError>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AzureBlobError {
+    /// The container does not exist in the storage account.
+    #[error(
+        "Azure container '{container}' does not exist in storage account '{account}'; check \
+         the container name"
+    )]
+    ContainerNotFound { account: String, container: String },
+    /// The account key or SAS token was rejected, or lacks permission for
+    /// the operation (`code` tells which, e.g. `AuthenticationFailed`).
+    #[error(
+        "Access denied to az://{container}/{key}: {message}; check the account key, the SAS \
+         token's permissions and expiry, or the storage account's firewall"
+    )]
+    AccessDenied {
+        container: String,
+        key: String,
+        code: Option<String>,
+        message: String,
+    },
+    /// The blob does not exist.
+    #[error("Azure blob az://{container}/{key} not found")]
+    BlobNotFound { container: String, key: String },
+    /// A write with `if_not_exists` found a blob already at the key.
+    #[error("Azure blob az://{container}/{key} already exists")]
+    AlreadyExists { container: String, key: String },
+    /// Any other error response.
+    #[error("Azure {operation} of az://{container}/{key} failed (HTTP {status}): {message}")]
+    Api {
+        operation: String,
+        container: String,
+        key: String,
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+}
+
+/// Read blobs from Azure Blob Storage, or list the names under a prefix.
+///
+/// The Azure counterpart of [`S3ReaderTool`], with the same arguments and
+/// result shapes except that buckets are `container`s. Authenticates with a
+/// connection string, or an account name plus account key or SAS token;
+/// unset settings fall back to `AZURE_STORAGE_CONNECTION_STRING`,
+/// `AZURE_STORAGE_ACCOUNT`, `AZURE_STORAGE_KEY` and `AZURE_STORAGE_SAS_TOKEN`.
+/// Failures are [`AzureBlobError`]s.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AzureBlobReaderTool {
+    /// Container name.
+    pub container: Option<String>,
+    /// Storage account name.
+    #[serde(default)]
+    pub account_name: Option<String>,
+    /// Storage connection string, used instead of the account settings.
+    #[serde(default, skip_serializing)]
+    pub connection_string: Option<String>,
+    /// Storage account key.
+    #[serde(default, skip_serializing)]
+    pub account_key: Option<String>,
+    /// Shared access signature, used when there is no account key.
+    #[serde(default, skip_serializing)]
+    pub sas_token: Option<String>,
+    /// Blob endpoint, e.g. an Azurite emulator; defaults to the account's
+    /// `https://<account>.blob.core.windows.net`.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Largest read returned, in bytes.
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: u64,
+}
+
+impl std::fmt::Debug for AzureBlobReaderTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AzureBlobReaderTool")
+            .field("container", &self.container)
+            .field("account_name", &self.account_name)
+            .field("connection_string", &self.connection_string.as_ref().map(|_| "<redacted>"))
+            .field("account_key", &self.account_key.as_ref().map(|_| "<redacted>"))
+            .field("sas_token", &self.sas_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_bytes", &self.max_bytes)
+            .finish()
+    }
+}
+
+impl AzureBlobReaderTool {
+    pub fn new() -> Self {
+        Self {
+            container: None,
+            account_name: None,
+            connection_string: None,
+            account_key: None,
+            sas_token: None,
+            endpoint_url: None,
+            max_bytes: default_max_bytes(),
+        }
+    }
+
+    pub fn with_container(mut self, container: impl Into<String>) -> Self {
+        self.container = Some(container.into());
+        self
+    }
+
+    pub fn with_account_name(mut self, name: impl Into<String>) -> Self {
+        self.account_name = Some(name.into());
+        self
+    }
+
+    pub fn with_connection_string(mut self, connection_string: impl Into<String>) -> Self {
+        self.connection_string = Some(connection_string.into());
+        self
+    }
+
+    pub fn with_account_key(mut self, key: impl Into<String>) -> Self {
+        self.account_key = Some(key.into());
+        self
+    }
+
+    pub fn with_sas_token(mut self, token: impl Into<String>) -> Self {
+        self.sas_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = bytes;
+        self
+    }
+
+    /// Read a blob or list names.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `read` or `list`; defaults to `list` when only a
+    ///   `prefix` is given and `read` otherwise.
+    /// * `file_path` - `az://container/key` to read, instead of
+    ///   `container`/`key`.
+    /// * `container` - Container, overriding the configured one.
+    /// * `key` - Blob name to read.
+    /// * `range` - Bytes to read, e.g. `0-1023`, `1024-` or `-500`.
+    /// * `prefix` - Name prefix to list.
+    /// * `delimiter` - Group names sharing a prefix up to this character
+    ///   (usually `/`) into `common_prefixes`.
+    /// * `max_keys` - Most names to list (default 1000).
+    /// * `continuation_token` - Resume a truncated listing.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let str_arg = |name: &str| args.get(name).and_then(|v| v.as_str()).map(str::to_string);
+        let (container, key) =
+            storage_target(&args, self.container.as_deref(), "az", "container")?;
+        let action = match str_arg("action") {
+            Some(action) => action,
+            None if key.is_none() && args.contains_key("prefix") => "list".to_string(),
+            None => "read".to_string(),
+        };
+
+        let azure = azure::Client::connect(
+            container,
+            self.connection_string.as_deref(),
+            self.account_name.as_deref(),
+            self.account_key.as_deref(),
+            self.sas_token.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        match action.as_str() {
+            "read" => {
+                let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+                let range = str_arg("range").map(|r| byte_range(&r)).transpose()?;
+                azure.get_object(&key, range.as_deref(), self.max_bytes)
+            }
+            "list" => azure.list_objects(
+                str_arg("prefix").as_deref().unwrap_or(""),
+                str_arg("delimiter").as_deref(),
+                args.get("max_keys").and_then(|v| v.as_u64()).unwrap_or(1000).max(1),
+                str_arg("continuation_token"),
+            ),
+            other => anyhow::bail!("Unknown action '{}': expected read or list", other),
+        }
+    }
+}
+
+impl Default for AzureBlobReaderTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── AzureBlobWriterTool ──────────────────────────────────────────────────────
+
+/// Write blobs to Azure Blob Storage.
+///
+/// The Azure counterpart of [`S3WriterTool`]: content is uploaded as a
+/// block blob, overwriting any existing blob unless `if_not_exists` is set,
+/// in which case the write fails with [`AzureBlobError::AlreadyExists`].
+/// Credentials resolve as for [`AzureBlobReaderTool`].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AzureBlobWriterTool {
+    /// Container name.
+    pub container: Option<String>,
+    /// Storage account name.
+    #[serde(default)]
+    pub account_name: Option<String>,
+    /// Storage connection string, used instead of the account settings.
+    #[serde(default, skip_serializing)]
+    pub connection_string: Option<String>,
+    /// Storage account key.
+    #[serde(default, skip_serializing)]
+    pub account_key: Option<String>,
+    /// Shared access signature, used when there is no account key.
+    #[serde(default, skip_serializing)]
+    pub sas_token: Option<String>,
+    /// Blob endpoint, e.g. an Azurite emulator.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Fail instead of overwriting an existing blob.
+    #[serde(default)]
+    pub if_not_exists: bool,
+}
+
+impl std::fmt::Debug for AzureBlobWriterTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AzureBlobWriterTool")
+            .field("container", &self.container)
+            .field("account_name", &self.account_name)
+            .field("connection_string", &self.connection_string.as_ref().map(|_| "<redacted>"))
+            .field("account_key", &self.account_key.as_ref().map(|_| "<redacted>"))
+            .field("sas_token", &self.sas_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("if_not_exists", &self.if_not_exists)
+            .finish()
+    }
+}
+
+impl AzureBlobWriterTool {
+    pub fn new() -> Self {
+        Self {
+            container: None,
+            account_name: None,
+            connection_string: None,
+            account_key: None,
+            sas_token: None,
+            endpoint_url: None,
+            if_not_exists: false,
+        }
+    }
+
+    pub fn with_container(mut self, container: impl Into<String>) -> Self {
+        self.container = Some(container.into());
+        self
+    }
+
+    pub fn with_account_name(mut self, name: impl Into<String>) -> Self {
+        self.account_name = Some(name.into());
+        self
+    }
+
+    pub fn with_connection_string(mut self, connection_string: impl Into<String>) -> Self {
+        self.connection_string = Some(connection_string.into());
+        self
+    }
+
+    pub fn with_account_key(mut self, key: impl Into<String>) -> Self {
+        self.account_key = Some(key.into());
+        self
+    }
+
+    pub fn with_sas_token(mut self, token: impl Into<String>) -> Self {
+        self.sas_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_if_not_exists(mut self, if_not_exists: bool) -> Self {
+        self.if_not_exists = if_not_exists;
+        self
+    }
+
+    /// Upload a blob.
+    ///
+    /// Returns `{container, key, url, etag, version_id, size}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `file_path` - `az://container/key` to write, instead of
+    ///   `container`/`key`.
+    /// * `container` - Container, overriding the configured one.
+    /// * `key` - Blob name to write.
+    /// * `content` - Text to write.
+    /// * `content_base64` - Binary content, base64-encoded, instead of `content`.
+    /// * `content_type` - MIME type (defaults to `text/plain; charset=utf-8`
+    ///   for `content` and `application/octet-stream` for `content_base64`).
+    /// * `if_not_exists` - Fail if the blob exists (always on when configured).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use base64::Engine;
+
+        let (container, key) =
+            storage_target(&args, self.container.as_deref(), "az", "container")?;
+        let key = key.ok_or_else(|| anyhow::anyhow!("Missing required argument: key"))?;
+        let (body, default_type) = match (
+            args.get("content").and_then(|v| v.as_str()),
+            args.get("content_base64").and_then(|v| v.as_str()),
+        ) {
+            (Some(text), None) => (text.as_bytes().to_vec(), "text/plain; charset=utf-8"),
+            (None, Some(encoded)) => (
+                base64::engine::general_purpose::STANDARD
+                    .decode(encoded.trim())
+                    .map_err(|e| anyhow::anyhow!("content_base64 is not valid base64: {}", e))?,
+                "application/octet-stream",
+            ),
+            (Some(_), Some(_)) => anyhow::bail!("Pass either content or content_base64, not both"),
+            (None, None) => {
+                anyhow::bail!("Missing required argument: content (or content_base64)")
+            }
+        };
+        let content_type = args
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or(default_type);
+        let if_not_exists = self.if_not_exists
+            || args.get("if_not_exists").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        let azure = azure::Client::connect(
+            container.clone(),
+            self.connection_string.as_deref(),
+            self.account_name.as_deref(),
+            self.account_key.as_deref(),
+            self.sas_token.as_deref(),
+            self.endpoint_url.as_deref(),
+        )?;
+        let size = body.len();
+        let mut result = azure.put_object(&key, body, content_type, if_not_exists)?;
+        result["container"] = Value::from(container);
+        result["key"] = Value::from(key.as_str());
+        result["url"] = Value::from(azure.url(&key));
+        result["size"] = Value::from(size);
+        Ok(result)
+    }
+}
+
+impl Default for AzureBlobWriterTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ── BedrockInvokeAgentTool ───────────────────────────────────────────────────
 
 /// Invoke an Amazon Bedrock Agent for AI-powered task execution.
@@ -156,6 +1711,27 @@ impl BedrockInvokeAgentTool {
         self
     }
 
+    /// Start a builder; `build()` is available once both `agent_id` and
+    /// `agent_alias_id` are set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::BedrockInvokeAgentTool;
+    ///
+    /// let tool = BedrockInvokeAgentTool::builder()
+    ///     .agent_id("AGENT123")
+    ///     .agent_alias_id("ALIAS456")
+    ///     .region("us-east-1")
+    ///     .build();
+    /// ```
+    pub fn builder() -> BedrockInvokeAgentToolBuilder<Missing, Missing> {
+        BedrockInvokeAgentToolBuilder {
+            agent_id: Missing,
+            agent_alias_id: Missing,
+            region: None,
+        }
+    }
+
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!(
             "BedrockInvokeAgentTool: not yet implemented - requires AWS Bedrock SDK integration"
@@ -163,12 +1739,61 @@ impl BedrockInvokeAgentTool {
     }
 }
 
+/// Builder for [`BedrockInvokeAgentTool`]; `A` and `L` track whether the
+/// agent ID and alias ID have been supplied.
+#[derive(Debug, Clone)]
+pub struct BedrockInvokeAgentToolBuilder<A, L> {
+    agent_id: A,
+    agent_alias_id: L,
+    region: Option<String>,
+}
+
+impl<A, L> BedrockInvokeAgentToolBuilder<A, L> {
+    pub fn agent_id(self, id: impl Into<String>) -> BedrockInvokeAgentToolBuilder<Set<String>, L> {
+        BedrockInvokeAgentToolBuilder {
+            agent_id: Set(id.into()),
+            agent_alias_id: self.agent_alias_id,
+            region: self.region,
+        }
+    }
+
+    pub fn agent_alias_id(self, id: impl Into<String>) -> BedrockInvokeAgentToolBuilder<A, Set<String>> {
+        BedrockInvokeAgentToolBuilder {
+            agent_id: self.agent_id,
+            agent_alias_id: Set(id.into()),
+            region: self.region,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+}
+
+impl BedrockInvokeAgentToolBuilder<Set<String>, Set<String>> {
+    pub fn build(self) -> BedrockInvokeAgentTool {
+        BedrockInvokeAgentTool {
+            region: self.region,
+            agent_id: self.agent_id.0,
+            agent_alias_id: self.agent_alias_id.0,
+        }
+    }
+}
+
 // ── BedrockKbRetrieverTool ───────────────────────────────────────────────────
 
 /// Retrieve information from an Amazon Bedrock Knowledge Base.
 ///
 /// Corresponds to Python `BedrockKBRetrieverTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Calls the bedrock-agent-runtime `Retrieve` API. Each result carries the
+/// chunk `content`, its relevance `score` and `source` location (an S3 URI
+/// for S3 data sources), with the same fields plus the chunk's Bedrock
+/// metadata under `metadata`, so results deserialize as [`Document`]s;
+/// [`retrieve`](Self::retrieve) returns them as such for a `RagPipeline`.
+/// Throttled calls are retried with jittered exponential backoff.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BedrockKbRetrieverTool {
     /// AWS region.
     pub region: Option<String>,
@@ -176,6 +1801,49 @@ pub struct BedrockKbRetrieverTool {
     pub knowledge_base_id: String,
     /// Number of results to retrieve.
     pub top_k: usize,
+    /// AWS access key ID; defaults to the standard credential chain.
+    #[serde(default)]
+    pub access_key_id: Option<String>,
+    /// AWS secret access key; defaults to the standard credential chain.
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials.
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// Endpoint override; falls back to
+    /// `AWS_ENDPOINT_URL_BEDROCK_AGENT_RUNTIME`, then `AWS_ENDPOINT_URL`.
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+    /// Retries for throttled requests.
+    #[serde(default = "default_bedrock_max_retries")]
+    pub max_retries: u32,
+    /// Base delay for exponential backoff between retries, in milliseconds.
+    #[serde(default = "default_bedrock_backoff_base_ms")]
+    pub backoff_base_ms: u64,
+}
+
+fn default_bedrock_max_retries() -> u32 {
+    3
+}
+
+fn default_bedrock_backoff_base_ms() -> u64 {
+    500
+}
+
+impl std::fmt::Debug for BedrockKbRetrieverTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BedrockKbRetrieverTool")
+            .field("region", &self.region)
+            .field("knowledge_base_id", &self.knowledge_base_id)
+            .field("top_k", &self.top_k)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| "<redacted>"))
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_retries", &self.max_retries)
+            .field("backoff_base_ms", &self.backoff_base_ms)
+            .finish()
+    }
 }
 
 impl BedrockKbRetrieverTool {
@@ -184,6 +1852,12 @@ impl BedrockKbRetrieverTool {
             region: None,
             knowledge_base_id: knowledge_base_id.into(),
             top_k: 5,
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+            endpoint_url: None,
+            max_retries: default_bedrock_max_retries(),
+            backoff_base_ms: default_bedrock_backoff_base_ms(),
         }
     }
 
@@ -197,9 +1871,356 @@ impl BedrockKbRetrieverTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "BedrockKbRetrieverTool: not yet implemented - requires AWS Bedrock SDK integration"
-        )
+    pub fn with_credentials(
+        mut self,
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+    ) -> Self {
+        self.access_key_id = Some(access_key_id.into());
+        self.secret_access_key = Some(secret_access_key.into());
+        self
+    }
+
+    pub fn with_session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    pub fn with_endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn with_max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn with_backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+
+    /// Start a builder; `build()` is available once `knowledge_base_id` is
+    /// set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::BedrockKbRetrieverTool;
+    ///
+    /// let tool = BedrockKbRetrieverTool::builder()
+    ///     .knowledge_base_id("KB12345678")
+    ///     .region("eu-west-1")
+    ///     .top_k(10)
+    ///     .build();
+    /// ```
+    pub fn builder() -> BedrockKbRetrieverToolBuilder<Missing> {
+        BedrockKbRetrieverToolBuilder {
+            knowledge_base_id: Missing,
+            region: None,
+            top_k: 5,
+            access_key_id: None,
+            secret_access_key: None,
+            session_token: None,
+            endpoint_url: None,
+            max_retries: default_bedrock_max_retries(),
+            backoff_base_ms: default_bedrock_backoff_base_ms(),
+        }
+    }
+
+    /// Query the knowledge base.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - Text to retrieve chunks for.
+    /// * `filter` - Metadata filter: either a Bedrock `RetrievalFilter`
+    ///   (e.g. `{"equals": {"key": "year", "value": 2024}}`) or a plain
+    ///   object of `key: value` pairs that must all match.
+    /// * `top_k` - Number of results, overriding the configured one.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .filter(|q| !q.trim().is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map_or(self.top_k, |k| k as usize);
+        let documents = self.retrieve_with(query, args.get("filter"), top_k)?;
+        let results: Vec<Value> = documents
+            .into_iter()
+            .map(|doc| {
+                serde_json::json!({
+                    "content": doc.content,
+                    "score": doc.metadata.get("score"),
+                    "source": doc.metadata.get("source"),
+                    "metadata": doc.metadata,
+                })
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "knowledge_base_id": self.knowledge_base_id,
+            "query": query,
+            "results": results,
+        }))
+    }
+
+    /// Retrieve the `top_k` chunks for `query` as documents, with `score`,
+    /// `source` and `location_type` added to each chunk's metadata.
+    pub fn retrieve(
+        &self,
+        query: &str,
+        filter: Option<&Value>,
+    ) -> Result<Vec<Document>, anyhow::Error> {
+        self.retrieve_with(query, filter, self.top_k)
+    }
+
+    fn retrieve_with(
+        &self,
+        query: &str,
+        filter: Option<&Value>,
+        top_k: usize,
+    ) -> Result<Vec<Document>, anyhow::Error> {
+        let mut search = serde_json::json!({ "numberOfResults": top_k.clamp(1, 100) });
+        if let Some(filter) = filter.filter(|f| !f.is_null()) {
+            search["filter"] = retrieval_filter(filter)?;
+        }
+        let body = serde_json::to_vec(&serde_json::json!({
+            "retrievalQuery": { "text": query },
+            "retrievalConfiguration": { "vectorSearchConfiguration": search },
+        }))?;
+
+        let credentials = aws::Credentials::resolve(
+            self.access_key_id.as_deref(),
+            self.secret_access_key.as_deref(),
+            self.session_token.as_deref(),
+        )?;
+        let region = aws::region(self.region.as_deref());
+        let base = self
+            .endpoint_url
+            .clone()
+            .or_else(|| std::env::var("AWS_ENDPOINT_URL_BEDROCK_AGENT_RUNTIME").ok())
+            .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok())
+            .unwrap_or_else(|| format!("https://bedrock-agent-runtime.{}.amazonaws.com", region));
+        let path = format!(
+            "/knowledgebases/{}/retrieve",
+            uri_encode(&self.knowledge_base_id, false)
+        );
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+
+        let mut attempt = 0;
+        let resp = loop {
+            let request = aws::Request {
+                method: reqwest::Method::POST,
+                base: base.trim_end_matches('/'),
+                path: &path,
+                query: Vec::new(),
+                headers: vec![("content-type", "application/json".to_string())],
+                body: body.clone(),
+            };
+            let resp = request.sign(&client, &credentials, &region, "bedrock")?.send()?;
+            if !aws::is_throttled(&resp) || attempt >= self.max_retries {
+                break resp;
+            }
+            let wait = aws::jittered_backoff(self.backoff_base_ms, attempt);
+            log::debug!("Bedrock Retrieve throttled; retrying in {:?}", wait);
+            std::thread::sleep(wait);
+            attempt += 1;
+        };
+
+        let status = resp.status();
+        let error_type = resp
+            .headers()
+            .get("x-amzn-errortype")
+            .and_then(|v| v.to_str().ok())
+            .map(|t| t.split(':').next().unwrap_or(t).to_string());
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload["message"]
+                .as_str()
+                .or_else(|| payload["Message"].as_str())
+                .unwrap_or("unknown error");
+            anyhow::bail!(
+                "Bedrock Retrieve on knowledge base '{}' failed with status {}{}: {}",
+                self.knowledge_base_id,
+                status,
+                error_type.map(|t| format!(" ({})", t)).unwrap_or_default(),
+                message
+            );
+        }
+        Ok(payload["retrievalResults"]
+            .as_array()
+            .map(|results| results.iter().map(kb_document).collect())
+            .unwrap_or_default())
+    }
+}
+
+/// Builder for [`BedrockKbRetrieverTool`]; `K` tracks whether the knowledge
+/// base ID has been supplied.
+#[derive(Clone)]
+pub struct BedrockKbRetrieverToolBuilder<K> {
+    knowledge_base_id: K,
+    region: Option<String>,
+    top_k: usize,
+    access_key_id: Option<String>,
+    secret_access_key: Option<String>,
+    session_token: Option<String>,
+    endpoint_url: Option<String>,
+    max_retries: u32,
+    backoff_base_ms: u64,
+}
+
+impl<K: std::fmt::Debug> std::fmt::Debug for BedrockKbRetrieverToolBuilder<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BedrockKbRetrieverToolBuilder")
+            .field("knowledge_base_id", &self.knowledge_base_id)
+            .field("region", &self.region)
+            .field("top_k", &self.top_k)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &self.secret_access_key.as_ref().map(|_| "<redacted>"))
+            .field("session_token", &self.session_token.as_ref().map(|_| "<redacted>"))
+            .field("endpoint_url", &self.endpoint_url)
+            .field("max_retries", &self.max_retries)
+            .field("backoff_base_ms", &self.backoff_base_ms)
+            .finish()
+    }
+}
+
+impl<K> BedrockKbRetrieverToolBuilder<K> {
+    pub fn knowledge_base_id(self, id: impl Into<String>) -> BedrockKbRetrieverToolBuilder<Set<String>> {
+        BedrockKbRetrieverToolBuilder {
+            knowledge_base_id: Set(id.into()),
+            region: self.region,
+            top_k: self.top_k,
+            access_key_id: self.access_key_id,
+            secret_access_key: self.secret_access_key,
+            session_token: self.session_token,
+            endpoint_url: self.endpoint_url,
+            max_retries: self.max_retries,
+            backoff_base_ms: self.backoff_base_ms,
+        }
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    pub fn credentials(
+        mut self,
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+    ) -> Self {
+        self.access_key_id = Some(access_key_id.into());
+        self.secret_access_key = Some(secret_access_key.into());
+        self
+    }
+
+    pub fn session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+        self
+    }
+
+    pub fn endpoint_url(mut self, url: impl Into<String>) -> Self {
+        self.endpoint_url = Some(url.into());
+        self
+    }
+
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    pub fn backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+}
+
+impl BedrockKbRetrieverToolBuilder<Set<String>> {
+    pub fn build(self) -> BedrockKbRetrieverTool {
+        BedrockKbRetrieverTool {
+            region: self.region,
+            knowledge_base_id: self.knowledge_base_id.0,
+            top_k: self.top_k,
+            access_key_id: self.access_key_id,
+            secret_access_key: self.secret_access_key,
+            session_token: self.session_token,
+            endpoint_url: self.endpoint_url,
+            max_retries: self.max_retries,
+            backoff_base_ms: self.backoff_base_ms,
+        }
+    }
+}
+
+/// Bedrock `RetrievalFilter` operators; a filter object whose only key is
+/// one of these is passed through unchanged.
+const FILTER_OPERATORS: &[&str] = &[
+    "andAll",
+    "orAll",
+    "equals",
+    "notEquals",
+    "greaterThan",
+    "greaterThanOrEquals",
+    "lessThan",
+    "lessThanOrEquals",
+    "in",
+    "notIn",
+    "startsWith",
+    "listContains",
+    "stringContains",
+];
+
+/// Turn the `filter` argument into a `RetrievalFilter`.
+fn retrieval_filter(filter: &Value) -> Result<Value, anyhow::Error> {
+    let fields = filter
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("filter must be an object"))?;
+    if fields.len() == 1 && fields.keys().all(|k| FILTER_OPERATORS.contains(&k.as_str())) {
+        return Ok(filter.clone());
+    }
+    let mut conditions: Vec<Value> = fields
+        .iter()
+        .map(|(key, value)| serde_json::json!({ "equals": { "key": key, "value": value } }))
+        .collect();
+    match conditions.len() {
+        0 => anyhow::bail!("filter must not be empty"),
+        1 => Ok(conditions.remove(0)),
+        _ => Ok(serde_json::json!({ "andAll": conditions })),
+    }
+}
+
+/// A `Document` for one Bedrock retrieval result.
+fn kb_document(result: &Value) -> Document {
+    let location = &result["location"];
+    let location_type = location["type"].as_str().unwrap_or_default();
+    // Each location type keeps its address under a different key.
+    let source = [
+        "/s3Location/uri",
+        "/webLocation/url",
+        "/confluenceLocation/url",
+        "/salesforceLocation/url",
+        "/sharePointLocation/url",
+        "/customDocumentLocation/id",
+    ]
+    .iter()
+    .find_map(|pointer| location.pointer(pointer).and_then(|v| v.as_str()));
+
+    let mut document = Document::new(result["content"]["text"].as_str().unwrap_or_default());
+    if let Some(metadata) = result["metadata"].as_object() {
+        document
+            .metadata
+            .extend(metadata.iter().map(|(k, v)| (k.clone(), v.clone())));
     }
+    document
+        .with_metadata("score", result["score"].clone())
+        .with_metadata("source", source.map_or(Value::Null, Value::from))
+        .with_metadata("location_type", Value::from(location_type))
 }
@@ -0,0 +1,264 @@
+//! Polite crawling: robots.txt rules and per-host rate limits.
+//!
+//! The native scraping tools (`ScrapeWebsiteTool`, `WebsiteSearchTool`) check
+//! a `RobotsCache` before fetching and wait on a `HostRateLimiter` so that
+//! requests to the same host are spaced out. Both are meant to be wrapped in
+//! an `Arc` and handed to several tools, so a crawl and one-off scrapes of the
+//! same site coordinate.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// User agent sent by the native scraping tools.
+pub(crate) const USER_AGENT: &str = "Mozilla/5.0 (compatible; CrewAI/1.0)";
+
+/// Product token matched against `User-agent:` lines in robots.txt.
+const ROBOTS_TOKEN: &str = "crewai";
+
+/// Returned (wrapped in `anyhow::Error`) when robots.txt forbids fetching a
+/// URL; callers can `downcast_ref::<RobotsDisallowed>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{url} is disallowed by robots.txt")]
+pub struct RobotsDisallowed {
+    pub url: String,
+}
+
+// ── RobotsCache ──────────────────────────────────────────────────────────────
+
+/// robots.txt rules fetched once per origin and kept for the cache's lifetime.
+///
+/// A missing or unreadable robots.txt allows everything.
+#[derive(Debug, Default)]
+pub struct RobotsCache {
+    rules: Mutex<HashMap<String, Arc<RobotsRules>>>,
+}
+
+impl RobotsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether `url` may be fetched, downloading the host's robots.txt with
+    /// `client` on first use.
+    pub fn is_allowed(&self, client: &reqwest::blocking::Client, url: &reqwest::Url) -> bool {
+        let origin = url.origin().ascii_serialization();
+        let cached = self.rules.lock().unwrap().get(&origin).cloned();
+        let rules = match cached {
+            Some(rules) => rules,
+            None => {
+                // Fetched outside the lock; a concurrent miss just fetches twice.
+                let rules = Arc::new(RobotsRules::fetch(client, &origin));
+                self.rules
+                    .lock()
+                    .unwrap()
+                    .entry(origin)
+                    .or_insert(rules)
+                    .clone()
+            }
+        };
+        let mut path = url.path().to_string();
+        if let Some(query) = url.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+        rules.allows(&path)
+    }
+
+    /// Number of hosts whose robots.txt has been fetched.
+    pub fn len(&self) -> usize {
+        self.rules.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The allow/disallow rules that apply to us, from one robots.txt.
+#[derive(Debug, Default)]
+struct RobotsRules {
+    /// `(allow, pattern)` pairs.
+    rules: Vec<(bool, String)>,
+}
+
+impl RobotsRules {
+    fn fetch(client: &reqwest::blocking::Client, origin: &str) -> Self {
+        let url = format!("{}/robots.txt", origin);
+        match client.get(&url).send() {
+            Ok(resp) if resp.status().is_success() => {
+                Self::parse(&resp.text().unwrap_or_default())
+            }
+            Ok(resp) => {
+                log::debug!("robots: {} returned {}; allowing all", url, resp.status());
+                Self::default()
+            }
+            Err(e) => {
+                log::debug!("robots: failed to fetch {}: {}; allowing all", url, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Keep the rules of the group naming our token, else the `*` group.
+    fn parse(text: &str) -> Self {
+        let mut ours = Vec::new();
+        let mut wildcard = Vec::new();
+        let mut agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        let mut named_us = false;
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim().to_ascii_lowercase(), value.trim());
+            match key.as_str() {
+                "user-agent" => {
+                    // A user-agent line after rules starts a new group.
+                    if in_rules {
+                        agents.clear();
+                        in_rules = false;
+                    }
+                    agents.push(value.to_ascii_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty Disallow allows everything; it adds no rule.
+                    if value.is_empty() {
+                        continue;
+                    }
+                    let rule = (key == "allow", value.to_string());
+                    if agents.iter().any(|a| a.contains(ROBOTS_TOKEN)) {
+                        named_us = true;
+                        ours.push(rule);
+                    } else if agents.iter().any(|a| a == "*") {
+                        wildcard.push(rule);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            rules: if named_us { ours } else { wildcard },
+        }
+    }
+
+    /// The longest matching rule wins; `Allow` wins a tie.
+    fn allows(&self, path: &str) -> bool {
+        let best = self
+            .rules
+            .iter()
+            .filter(|(_, pattern)| pattern_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow));
+        match best {
+            Some((allow, _)) => *allow,
+            None => true,
+        }
+    }
+}
+
+/// robots.txt path matching: a prefix match where `*` matches any run of
+/// characters and a trailing `$` anchors the end.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = path.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        let last = i + 1 == parts.len();
+        if last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+// ── HostRateLimiter ──────────────────────────────────────────────────────────
+
+/// Enforces a minimum delay between requests to the same host.
+///
+/// Slots are reserved under a lock and slept for outside it, so threads
+/// sharing a limiter queue up per host without blocking other hosts.
+#[derive(Debug)]
+pub struct HostRateLimiter {
+    min_delay: Duration,
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl HostRateLimiter {
+    pub fn new(min_delay: Duration) -> Self {
+        Self {
+            min_delay,
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn min_delay(&self) -> Duration {
+        self.min_delay
+    }
+
+    /// Block until a request to `url`'s host is allowed. The first request
+    /// to a host never waits.
+    pub fn wait(&self, url: &reqwest::Url) {
+        let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+        let now = Instant::now();
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = next_slot.get(&host).copied().unwrap_or(now).max(now);
+            next_slot.insert(host, slot + self.min_delay);
+            slot
+        };
+        if slot > now {
+            std::thread::sleep(slot - now);
+        }
+    }
+}
+
+// ── CrawlGuard ───────────────────────────────────────────────────────────────
+
+/// The checks a tool runs before each request it makes.
+pub(crate) struct CrawlGuard<'a> {
+    /// `None` when robots.txt is ignored.
+    pub robots: Option<&'a RobotsCache>,
+    pub limiter: Option<&'a HostRateLimiter>,
+}
+
+impl CrawlGuard<'_> {
+    /// No robots.txt check and no rate limit.
+    #[cfg_attr(not(feature = "web_scraping"), allow(dead_code))]
+    pub(crate) const NONE: CrawlGuard<'static> = CrawlGuard {
+        robots: None,
+        limiter: None,
+    };
+
+    /// Refuse URLs robots.txt disallows, then wait for the host's rate limit.
+    pub(crate) fn admit(
+        &self,
+        client: &reqwest::blocking::Client,
+        url: &reqwest::Url,
+    ) -> Result<(), RobotsDisallowed> {
+        if let Some(robots) = self.robots {
+            if !robots.is_allowed(client, url) {
+                return Err(RobotsDisallowed {
+                    url: url.to_string(),
+                });
+            }
+        }
+        if let Some(limiter) = self.limiter {
+            limiter.wait(url);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+//! Credit budgets for metered scraping APIs.
+//!
+//! Services like Scrapfly, Spider, Firecrawl and Apify bill per request or per
+//! page. A single runaway crawl can burn through a month's allowance, so tools
+//! that talk to them accept a `CreditBudget`: a cap plus a shared counter of
+//! credits consumed so far. Cloning a budget shares the counter, which lets
+//! several tool instances draw from the same allowance.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// A credit cap with a running total shared between clones.
+#[derive(Debug, Clone)]
+pub struct CreditBudget {
+    limit: u64,
+    consumed: Arc<AtomicU64>,
+}
+
+impl CreditBudget {
+    /// Create a budget allowing `limit` credits.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            consumed: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// The configured credit cap.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Credits consumed so far, across every tool sharing this budget.
+    pub fn consumed(&self) -> u64 {
+        self.consumed.load(Ordering::Relaxed)
+    }
+
+    /// Credits left before the cap is reached.
+    pub fn remaining(&self) -> u64 {
+        self.limit.saturating_sub(self.consumed())
+    }
+
+    /// Whether no further requests should be issued.
+    pub fn is_exhausted(&self) -> bool {
+        self.consumed() >= self.limit
+    }
+
+    /// Record `credits` as consumed. Returns the new running total.
+    ///
+    /// Charges are never refused: the service has already billed them by the
+    /// time the response arrives, so the total may end up above the limit.
+    pub fn charge(&self, credits: u64) -> u64 {
+        self.consumed.fetch_add(credits, Ordering::Relaxed) + credits
+    }
+}
+
+/// Read a non-negative credit count from `value` at the given JSON pointer.
+///
+/// Accepts integers, floats (rounded up, since partial credits are still
+/// billed) and numeric strings. Returns `None` if the field is absent.
+#[cfg_attr(not(feature = "web_scraping"), allow(dead_code))]
+pub(crate) fn usage_at(value: &serde_json::Value, pointer: &str) -> Option<u64> {
+    let field = value.pointer(pointer)?;
+    if let Some(n) = field.as_u64() {
+        return Some(n);
+    }
+    if let Some(f) = field.as_f64() {
+        return Some(f.max(0.0).ceil() as u64);
+    }
+    field.as_str().and_then(|s| s.trim().parse::<f64>().ok()).map(|f| f.max(0.0).ceil() as u64)
+}
@@ -0,0 +1,140 @@
+//! Extra headers and cookies sent by the native scraping tools.
+//!
+//! Internal sites often need an auth cookie or token header. Those values are
+//! secrets, so `CustomHeaders` never prints them: `Debug` shows header names
+//! with sensitive values redacted, and errors about a malformed header name
+//! the header but not its value.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Substrings marking a header whose value must not be shown.
+const SENSITIVE_HEADERS: &[&str] =
+    &["auth", "cookie", "token", "key", "secret", "session", "password"];
+
+/// Headers and cookies to attach to every request a tool makes.
+#[derive(Clone, Default)]
+pub struct CustomHeaders {
+    headers: Vec<(String, String)>,
+    cookies: Vec<(String, String)>,
+}
+
+impl CustomHeaders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty() && self.cookies.is_empty()
+    }
+
+    /// Set headers, replacing any existing value for the same name
+    /// (compared case-insensitively).
+    pub fn set_headers(&mut self, headers: impl IntoIterator<Item = (String, String)>) {
+        for (name, value) in headers {
+            self.headers.retain(|(n, _)| !n.eq_ignore_ascii_case(&name));
+            self.headers.push((name, value));
+        }
+    }
+
+    /// Set cookies, replacing any existing cookie with the same name.
+    pub fn set_cookies(&mut self, cookies: impl IntoIterator<Item = (String, String)>) {
+        for (name, value) in cookies {
+            self.cookies.retain(|(n, _)| *n != name);
+            self.cookies.push((name, value));
+        }
+    }
+
+    /// These headers with the runtime `headers` and `cookies` arguments
+    /// merged over them. `headers` is an object of name → value; `cookies`
+    /// is an object or a list of `[name, value]` pairs.
+    pub(crate) fn merged(&self, args: &HashMap<String, Value>) -> Result<Self, anyhow::Error> {
+        let mut merged = self.clone();
+        if let Some(headers) = args.get("headers") {
+            let map = headers
+                .as_object()
+                .ok_or_else(|| anyhow::anyhow!("'headers' must be an object of name → value"))?;
+            merged.set_headers(map.iter().map(|(k, v)| (k.clone(), string_value(v))));
+        }
+        match args.get("cookies") {
+            None | Some(Value::Null) => {}
+            Some(Value::Object(map)) => {
+                merged.set_cookies(map.iter().map(|(k, v)| (k.clone(), string_value(v))));
+            }
+            Some(Value::Array(pairs)) => {
+                let mut cookies = Vec::with_capacity(pairs.len());
+                for pair in pairs {
+                    match pair.as_array().map(Vec::as_slice) {
+                        Some([name, value]) if name.is_string() => {
+                            cookies.push((string_value(name), string_value(value)));
+                        }
+                        _ => anyhow::bail!("'cookies' entries must be [name, value] pairs"),
+                    }
+                }
+                merged.set_cookies(cookies);
+            }
+            Some(_) => anyhow::bail!("'cookies' must be an object or a list of [name, value] pairs"),
+        }
+        Ok(merged)
+    }
+
+    /// Attach the headers and a `Cookie` header to `request`. Malformed
+    /// headers are reported by name only.
+    pub(crate) fn apply(
+        &self,
+        mut request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::RequestBuilder, anyhow::Error> {
+        for (name, value) in &self.headers {
+            let header = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| anyhow::anyhow!("Invalid header name '{}'", name))?;
+            let mut value = reqwest::header::HeaderValue::from_str(value)
+                .map_err(|_| anyhow::anyhow!("Invalid value for header '{}'", name))?;
+            value.set_sensitive(is_sensitive(name));
+            request = request.header(header, value);
+        }
+        if !self.cookies.is_empty() {
+            let cookie = self
+                .cookies
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join("; ");
+            let mut value = reqwest::header::HeaderValue::from_str(&cookie)
+                .map_err(|_| anyhow::anyhow!("Invalid cookie value"))?;
+            value.set_sensitive(true);
+            request = request.header(reqwest::header::COOKIE, value);
+        }
+        Ok(request)
+    }
+}
+
+impl std::fmt::Debug for CustomHeaders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let headers: Vec<(&str, &str)> = self
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let shown = if is_sensitive(name) { "<redacted>" } else { value.as_str() };
+                (name.as_str(), shown)
+            })
+            .collect();
+        let cookies: Vec<&str> = self.cookies.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("CustomHeaders")
+            .field("headers", &headers)
+            .field("cookies", &cookies)
+            .finish()
+    }
+}
+
+fn is_sensitive(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_HEADERS.iter().any(|s| name.contains(s))
+}
+
+fn string_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -5,15 +5,126 @@
 //! in `crewai_tools`.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::builder::{Missing, Set};
+use crate::core::{content_id, CompletionService, EmbeddingService};
+#[cfg(feature = "rag")]
+use crate::core::{Chunk, VectorStore};
+#[cfg(feature = "rag")]
+use crate::rag::{filter::QueryFilter, index::SearchHit};
+
+mod pool;
+mod snowflake;
+mod sql;
+
+pub use pool::{ConnectionPool, PoolTimeout, PooledConnection};
+
+/// The query vector for a search: the `query_vector` argument if given,
+/// otherwise the `query` text embedded with `embedder`.
+fn query_vector(
+    args: &HashMap<String, Value>,
+    embedder: Option<&Arc<dyn EmbeddingService>>,
+) -> Result<Vec<f32>, anyhow::Error> {
+    if let Some(vector) = args.get("query_vector") {
+        return vector
+            .as_array()
+            .and_then(|values| values.iter().map(|v| v.as_f64().map(|x| x as f32)).collect())
+            .ok_or_else(|| anyhow::anyhow!("'query_vector' must be an array of numbers"));
+    }
+    let query = args
+        .get("query")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required argument: query or query_vector"))?;
+    let embedder = embedder.ok_or_else(|| {
+        anyhow::anyhow!(
+            "A text query needs an embedder; pass query_vector or configure with_embedder"
+        )
+    })?;
+    embedder.embed(query)
+}
+
+/// Outcome of an `ingest` call on a vector database tool.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IngestReport {
+    /// Documents written.
+    pub inserted: usize,
+    /// Documents rejected, either on their own or with a failed batch.
+    pub failed: usize,
+    /// One message per rejected document or failed batch.
+    pub errors: Vec<String>,
+}
+
+impl IngestReport {
+    fn fail(&mut self, count: usize, message: impl Into<String>) {
+        self.failed += count;
+        self.errors.push(message.into());
+    }
+}
+
+/// A document to ingest: its text, embedding and metadata.
+type IngestDocument = (String, Vec<f32>, Value);
+
+/// The vector dimension of `documents` (that of the first one) and the
+/// documents that have it, with their positions in `documents`; the rest are
+/// recorded as failures in `report`.
+fn uniform_dimension(
+    documents: Vec<IngestDocument>,
+    report: &mut IngestReport,
+) -> Result<(usize, Vec<(usize, IngestDocument)>), anyhow::Error> {
+    let dimension = documents.first().map_or(0, |(_, vector, _)| vector.len());
+    if dimension == 0 {
+        anyhow::bail!("Cannot ingest documents with empty vectors");
+    }
+    let mut kept = Vec::with_capacity(documents.len());
+    for (i, document) in documents.into_iter().enumerate() {
+        if document.1.len() == dimension {
+            kept.push((i, document));
+        } else {
+            report.fail(
+                1,
+                format!(
+                    "document {}: vector has {} dimensions, expected {}",
+                    i,
+                    document.1.len(),
+                    dimension
+                ),
+            );
+        }
+    }
+    Ok((dimension, kept))
+}
+
 // ── QdrantVectorSearchTool ───────────────────────────────────────────────────
 
+/// Errors from Qdrant that need different fixes.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<QdrantError>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum QdrantError {
+    /// The server could not be reached.
+    #[error("Could not connect to Qdrant at {url}: {message}")]
+    Connection { url: String, message: String },
+    /// The API key is missing or wrong.
+    #[error("Qdrant rejected the API key (HTTP {status})")]
+    Unauthorized { status: u16 },
+    /// The collection does not exist.
+    #[error("Qdrant collection '{collection}' not found")]
+    CollectionNotFound { collection: String },
+    /// Any other error response.
+    #[error("Qdrant search failed (HTTP {status}): {message}")]
+    Api { status: u16, message: String },
+}
+
 /// Search a Qdrant vector database for semantically similar documents.
 ///
+/// Text queries are embedded with the configured [`EmbeddingService`];
+/// callers that already have a vector pass `query_vector` instead.
+///
 /// Corresponds to Python `QdrantVectorSearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct QdrantVectorSearchTool {
     /// Qdrant server URL.
     pub qdrant_url: Option<String>,
@@ -23,6 +134,24 @@ pub struct QdrantVectorSearchTool {
     pub collection_name: String,
     /// Number of results to return.
     pub top_k: usize,
+    /// Points sent per upsert request by `ingest`.
+    pub batch_size: usize,
+    /// Embedder for text queries (not serialized).
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+}
+
+impl std::fmt::Debug for QdrantVectorSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QdrantVectorSearchTool")
+            .field("qdrant_url", &self.qdrant_url)
+            .field("api_key", &self.api_key)
+            .field("collection_name", &self.collection_name)
+            .field("top_k", &self.top_k)
+            .field("batch_size", &self.batch_size)
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl QdrantVectorSearchTool {
@@ -32,6 +161,8 @@ impl QdrantVectorSearchTool {
             api_key: None,
             collection_name: collection_name.into(),
             top_k: 5,
+            batch_size: 100,
+            embedder: None,
         }
     }
 
@@ -50,19 +181,503 @@ impl QdrantVectorSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "QdrantVectorSearchTool: not yet implemented - requires Qdrant client integration"
-        )
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Embed text queries with this service.
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Start a builder; `build()` is available once `collection_name` is set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::QdrantVectorSearchTool;
+    ///
+    /// let tool = QdrantVectorSearchTool::builder()
+    ///     .collection_name("docs")
+    ///     .qdrant_url("http://localhost:6333")
+    ///     .top_k(10)
+    ///     .build();
+    /// ```
+    pub fn builder() -> QdrantVectorSearchToolBuilder<Missing> {
+        QdrantVectorSearchToolBuilder {
+            collection_name: Missing,
+            qdrant_url: None,
+            api_key: None,
+            top_k: 5,
+        }
+    }
+
+    /// Search the collection for the nearest points.
+    ///
+    /// Returns `{collection, count, results: [{id, score, payload}]}`. Failures
+    /// to connect, authenticate or find the collection are reported as
+    /// [`QdrantError`] variants.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query_vector` - Precomputed query embedding.
+    /// * `query` - Query text, embedded with the configured embedder.
+    /// * `filter` - Qdrant payload filter, e.g. `{"must": [...]}`.
+    /// * `top_k` - Override the configured number of results.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let vector = query_vector(&args, self.embedder.as_ref())?;
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.top_k);
+
+        let mut body = serde_json::json!({
+            "vector": vector,
+            "limit": top_k,
+            "with_payload": true,
+        });
+        if let Some(filter) = args.get("filter").filter(|f| !f.is_null()) {
+            body["filter"] = filter.clone();
+        }
+
+        let results: Vec<Value> = self
+            .search_points(&body)?
+            .iter()
+            .map(|hit| {
+                serde_json::json!({
+                    "id": hit["id"],
+                    "score": hit["score"],
+                    "payload": hit["payload"],
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "collection": self.collection_name,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
+
+    /// POST a search request body to the collection, returning the raw hits.
+    fn search_points(&self, body: &Value) -> Result<Vec<Value>, anyhow::Error> {
+        let mut payload = self.post("points/search", body)?;
+        match payload["result"].take() {
+            Value::Array(hits) => Ok(hits),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// POST `body` to `path` under the collection, returning the JSON reply.
+    fn post(&self, path: &str, body: &Value) -> Result<Value, anyhow::Error> {
+        let (base_url, api_key) = self.endpoint();
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()?;
+        let base_url = base_url.trim_end_matches('/');
+        let mut request = client
+            .post(format!(
+                "{}/collections/{}/{}",
+                base_url, self.collection_name, path
+            ))
+            .json(body);
+        if let Some(key) = &api_key {
+            request = request.header("api-key", key);
+        }
+        let resp = request.send().map_err(|e| QdrantError::Connection {
+            url: base_url.to_string(),
+            message: e.to_string(),
+        })?;
+
+        let status = resp.status();
+        let payload: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = payload
+                .pointer("/status/error")
+                .and_then(|v| v.as_str())
+                .unwrap_or("no details")
+                .to_string();
+            return Err(match status.as_u16() {
+                401 | 403 => QdrantError::Unauthorized {
+                    status: status.as_u16(),
+                },
+                404 => QdrantError::CollectionNotFound {
+                    collection: self.collection_name.clone(),
+                },
+                code => QdrantError::Api {
+                    status: code,
+                    message,
+                },
+            }
+            .into());
+        }
+        Ok(payload)
+    }
+
+    /// Upsert `documents` as `(text, vector, metadata)` points, `batch_size`
+    /// per request, creating the collection (cosine distance, sized to the
+    /// first vector) if it does not exist.
+    ///
+    /// Each point's payload is `{text, metadata}`. Point IDs are derived
+    /// from the text, so ingesting the same text again updates its point.
+    /// A failed batch counts all its points as failed and does not stop
+    /// later batches.
+    pub fn ingest(
+        &self,
+        documents: Vec<(String, Vec<f32>, Value)>,
+    ) -> Result<IngestReport, anyhow::Error> {
+        let mut report = IngestReport::default();
+        if documents.is_empty() {
+            return Ok(report);
+        }
+        let (dimension, documents) = uniform_dimension(documents, &mut report)?;
+
+        let (base_url, api_key) = self.endpoint();
+        let base_url = base_url.trim_end_matches('/');
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let collection_url = format!("{}/collections/{}", base_url, self.collection_name);
+        let send = |request: reqwest::blocking::RequestBuilder| {
+            let request = match &api_key {
+                Some(key) => request.header("api-key", key),
+                None => request,
+            };
+            request.send().map_err(|e| QdrantError::Connection {
+                url: base_url.to_string(),
+                message: e.to_string(),
+            })
+        };
+        let api_error = |status: reqwest::StatusCode, body: &Value| -> anyhow::Error {
+            match status.as_u16() {
+                401 | 403 => QdrantError::Unauthorized {
+                    status: status.as_u16(),
+                }
+                .into(),
+                code => QdrantError::Api {
+                    status: code,
+                    message: body
+                        .pointer("/status/error")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("no details")
+                        .to_string(),
+                }
+                .into(),
+            }
+        };
+
+        let resp = send(client.get(&collection_url))?;
+        let status = resp.status();
+        let body: Value = resp.json().unwrap_or(Value::Null);
+        if status.as_u16() == 404 {
+            let resp = send(client.put(&collection_url).json(&serde_json::json!({
+                "vectors": {"size": dimension, "distance": "Cosine"},
+            })))?;
+            let status = resp.status();
+            if !status.is_success() {
+                return Err(api_error(status, &resp.json().unwrap_or(Value::Null)));
+            }
+        } else if !status.is_success() {
+            return Err(api_error(status, &body));
+        } else if let Some(size) = body.pointer("/result/config/params/vectors/size") {
+            if size.as_u64() != Some(dimension as u64) {
+                anyhow::bail!(
+                    "Qdrant collection '{}' holds {}-dimensional vectors, not {}",
+                    self.collection_name,
+                    size,
+                    dimension
+                );
+            }
+        }
+
+        for batch in documents.chunks(self.batch_size.max(1)) {
+            let points: Vec<Value> = batch
+                .iter()
+                .map(|(_, (text, vector, metadata))| {
+                    serde_json::json!({
+                        "id": content_id(text),
+                        "vector": vector,
+                        "payload": {"text": text, "metadata": metadata},
+                    })
+                })
+                .collect();
+            let outcome = send(
+                client
+                    .put(format!("{}/points?wait=true", collection_url))
+                    .json(&serde_json::json!({ "points": points })),
+            )
+            .map_err(anyhow::Error::from)
+            .and_then(|resp| {
+                let status = resp.status();
+                if status.is_success() {
+                    Ok(())
+                } else {
+                    Err(api_error(status, &resp.json().unwrap_or(Value::Null)))
+                }
+            });
+            match outcome {
+                Ok(()) => report.inserted += batch.len(),
+                Err(e) => report.fail(batch.len(), e.to_string()),
+            }
+        }
+        Ok(report)
+    }
+
+    /// The server URL and API key, falling back to `QDRANT_URL` and
+    /// `QDRANT_API_KEY`.
+    fn endpoint(&self) -> (String, Option<String>) {
+        let base_url = self
+            .qdrant_url
+            .clone()
+            .or_else(|| std::env::var("QDRANT_URL").ok())
+            .unwrap_or_else(|| "http://localhost:6333".to_string());
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("QDRANT_API_KEY").ok());
+        (base_url, api_key)
+    }
+}
+
+/// How many more hits than asked for a [`VectorStore`] search fetches from
+/// Qdrant when part of the filter must be applied client-side.
+#[cfg(feature = "rag")]
+const QDRANT_OVERFETCH: usize = 4;
+
+/// Stores chunks as points with payload `{text, metadata}`.
+///
+/// Metadata equality conditions in a [`QueryFilter`] run in Qdrant; source
+/// globs, substring matches and date ranges are applied to an over-fetched
+/// result, so a very selective filter can return fewer than `top_k` hits.
+#[cfg(feature = "rag")]
+impl VectorStore for QdrantVectorSearchTool {
+    fn add(
+        &self,
+        chunks: Vec<Chunk>,
+        embeddings: Vec<Vec<f32>>,
+    ) -> Result<Vec<String>, anyhow::Error> {
+        if chunks.len() != embeddings.len() {
+            anyhow::bail!(
+                "QdrantVectorSearchTool: {} embeddings for {} chunks",
+                embeddings.len(),
+                chunks.len()
+            );
+        }
+        let ids = chunks.iter().map(|c| content_id(&c.content)).collect();
+        let total = chunks.len();
+        let documents = chunks
+            .into_iter()
+            .zip(embeddings)
+            .map(|(chunk, embedding)| {
+                let metadata = Value::Object(chunk.metadata.into_iter().collect());
+                (chunk.content, embedding, metadata)
+            })
+            .collect();
+        let report = self.ingest(documents)?;
+        if report.failed > 0 {
+            anyhow::bail!(
+                "Qdrant rejected {} of {} chunks: {}",
+                report.failed,
+                total,
+                report.errors.join("; ")
+            );
+        }
+        Ok(ids)
+    }
+
+    fn search(
+        &self,
+        query: &[f32],
+        top_k: usize,
+        filter: Option<&QueryFilter>,
+    ) -> Result<Vec<SearchHit>, anyhow::Error> {
+        let filter = filter.filter(|f| !f.is_empty());
+        let (native, exact) = match filter {
+            Some(f) => qdrant_filter(f),
+            None => (None, true),
+        };
+        let limit = if exact {
+            top_k
+        } else {
+            top_k.saturating_mul(QDRANT_OVERFETCH)
+        };
+        let mut body = serde_json::json!({
+            "vector": query,
+            "limit": limit,
+            "with_payload": true,
+        });
+        if let Some(native) = native {
+            body["filter"] = native;
+        }
+
+        let mut hits: Vec<SearchHit> = self
+            .search_points(&body)?
+            .into_iter()
+            .map(|mut hit| SearchHit {
+                id: match &hit["id"] {
+                    Value::String(id) => Some(id.clone()),
+                    Value::Null => None,
+                    other => Some(other.to_string()),
+                },
+                content: hit["payload"]["text"].as_str().unwrap_or_default().to_string(),
+                metadata: match hit["payload"]["metadata"].take() {
+                    Value::Object(map) => map.into_iter().collect(),
+                    _ => HashMap::new(),
+                },
+                score: hit["score"].as_f64().unwrap_or(0.0) as f32,
+            })
+            .collect();
+        if let (Some(f), false) = (filter, exact) {
+            hits.retain(|hit| f.matches(&hit.metadata));
+        }
+        hits.truncate(top_k);
+        Ok(hits)
+    }
+
+    fn delete(&self, ids: &[String]) -> Result<usize, anyhow::Error> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        self.post("points/delete?wait=true", &serde_json::json!({ "points": ids }))?;
+        Ok(ids.len())
+    }
+
+    /// Looks the IDs up without payloads or vectors; a missing collection
+    /// holds none of them.
+    fn contains(&self, ids: &[String]) -> Result<Vec<bool>, anyhow::Error> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let body = serde_json::json!({ "ids": ids, "with_payload": false, "with_vector": false });
+        let response = match self.post("points", &body) {
+            Ok(response) => response,
+            Err(e) => match e.downcast_ref::<QdrantError>() {
+                Some(QdrantError::CollectionNotFound { .. }) => return Ok(vec![false; ids.len()]),
+                _ => return Err(e),
+            },
+        };
+        let found: std::collections::HashSet<String> = response["result"]
+            .as_array()
+            .map(|points| {
+                points
+                    .iter()
+                    .filter_map(|p| match &p["id"] {
+                        Value::String(s) => Some(s.clone()),
+                        Value::Number(n) => Some(n.to_string()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(ids.iter().map(|id| found.contains(id)).collect())
+    }
+
+    fn store_name(&self) -> &str {
+        "QdrantVectorSearchTool"
+    }
+}
+
+/// The part of `filter` Qdrant can evaluate, as a Qdrant filter, and
+/// whether that is the whole of it.
+#[cfg(feature = "rag")]
+fn qdrant_filter(filter: &QueryFilter) -> (Option<Value>, bool) {
+    use crate::rag::filter::ConditionOp;
+
+    let mut must = Vec::new();
+    let mut exact = filter.include_sources.is_empty()
+        && filter.exclude_sources.is_empty()
+        && filter.date_range.is_none();
+    for condition in &filter.conditions {
+        match (condition.op, &condition.value) {
+            // A match on an array field matches any element, which is
+            // `Contains` for arrays; string `Contains` is a substring test.
+            (ConditionOp::Contains, Value::String(_))
+            | (_, Value::Null | Value::Array(_) | Value::Object(_)) => exact = false,
+            (_, value) => must.push(serde_json::json!({
+                "key": format!("metadata.{}", condition.key),
+                "match": {"value": value},
+            })),
+        }
+    }
+    let native = (!must.is_empty()).then(|| serde_json::json!({ "must": must }));
+    (native, exact)
+}
+
+/// Builder for [`QdrantVectorSearchTool`]; `C` tracks whether the
+/// collection name has been supplied.
+#[derive(Debug, Clone)]
+pub struct QdrantVectorSearchToolBuilder<C> {
+    collection_name: C,
+    qdrant_url: Option<String>,
+    api_key: Option<String>,
+    top_k: usize,
+}
+
+impl<C> QdrantVectorSearchToolBuilder<C> {
+    pub fn collection_name(self, name: impl Into<String>) -> QdrantVectorSearchToolBuilder<Set<String>> {
+        QdrantVectorSearchToolBuilder {
+            collection_name: Set(name.into()),
+            qdrant_url: self.qdrant_url,
+            api_key: self.api_key,
+            top_k: self.top_k,
+        }
+    }
+
+    pub fn qdrant_url(mut self, url: impl Into<String>) -> Self {
+        self.qdrant_url = Some(url.into());
+        self
+    }
+
+    pub fn api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+}
+
+impl QdrantVectorSearchToolBuilder<Set<String>> {
+    pub fn build(self) -> QdrantVectorSearchTool {
+        QdrantVectorSearchTool {
+            qdrant_url: self.qdrant_url,
+            api_key: self.api_key,
+            collection_name: self.collection_name.0,
+            top_k: self.top_k,
+            batch_size: 100,
+            embedder: None,
+        }
     }
 }
 
 // ── MongoDbVectorSearchTool ──────────────────────────────────────────────────
 
+/// Errors from MongoDB that need different fixes.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<MongoDbError>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MongoDbError {
+    /// The connection string is invalid or no server could be reached.
+    #[error("Could not connect to MongoDB: {0}")]
+    Connection(String),
+    /// The Atlas Vector Search index does not exist on the collection.
+    #[error("Vector search index '{index}' not found on {collection}")]
+    IndexNotFound { index: String, collection: String },
+    /// The aggregation itself failed.
+    #[error("MongoDB vector search failed: {0}")]
+    Query(String),
+}
+
 /// Search a MongoDB Atlas vector index for similar documents.
 ///
+/// Runs a `$vectorSearch` aggregation; text queries are embedded with the
+/// configured [`EmbeddingService`].
+///
 /// Corresponds to Python `MongoDBVectorSearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct MongoDbVectorSearchTool {
     /// MongoDB connection string.
     pub connection_string: Option<String>,
@@ -72,8 +687,27 @@ pub struct MongoDbVectorSearchTool {
     pub collection: String,
     /// Vector index name.
     pub index_name: String,
+    /// Document field holding the embeddings.
+    pub vector_path: String,
     /// Number of results to return.
     pub top_k: usize,
+    /// Embedder for text queries (not serialized).
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+}
+
+impl std::fmt::Debug for MongoDbVectorSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MongoDbVectorSearchTool")
+            .field("connection_string", &self.connection_string)
+            .field("database", &self.database)
+            .field("collection", &self.collection)
+            .field("index_name", &self.index_name)
+            .field("vector_path", &self.vector_path)
+            .field("top_k", &self.top_k)
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl MongoDbVectorSearchTool {
@@ -83,7 +717,9 @@ impl MongoDbVectorSearchTool {
             database: database.into(),
             collection: collection.into(),
             index_name: "vector_index".to_string(),
+            vector_path: "embedding".to_string(),
             top_k: 5,
+            embedder: None,
         }
     }
 
@@ -97,15 +733,257 @@ impl MongoDbVectorSearchTool {
         self
     }
 
+    pub fn with_vector_path(mut self, path: impl Into<String>) -> Self {
+        self.vector_path = path.into();
+        self
+    }
+
     pub fn with_top_k(mut self, k: usize) -> Self {
         self.top_k = k;
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "MongoDbVectorSearchTool: not yet implemented - requires MongoDB driver integration"
-        )
+    /// Embed text queries with this service.
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Start a builder; `build()` is available once both `database` and
+    /// `collection` are set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::MongoDbVectorSearchTool;
+    ///
+    /// let tool = MongoDbVectorSearchTool::builder()
+    ///     .database("kb")
+    ///     .collection("articles")
+    ///     .index_name("articles_vec")
+    ///     .build();
+    /// ```
+    pub fn builder() -> MongoDbVectorSearchToolBuilder<Missing, Missing> {
+        MongoDbVectorSearchToolBuilder {
+            database: Missing,
+            collection: Missing,
+            connection_string: None,
+            index_name: "vector_index".to_string(),
+            vector_path: "embedding".to_string(),
+            top_k: 5,
+        }
+    }
+
+    /// Run a `$vectorSearch` aggregation.
+    ///
+    /// `numCandidates` is `top_k * 10` (capped at Atlas's 10 000). Returns
+    /// `{database, collection, count, results}`; each result is the matched
+    /// document with a `score` field, and without the embedding unless a
+    /// projection asks for it.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query_vector` - Precomputed query embedding.
+    /// * `query` - Query text, embedded with the configured embedder.
+    /// * `filter` - Pre-filter document on indexed filter fields.
+    /// * `projection` - `$project` document limiting the returned fields.
+    /// * `top_k` - Override the configured number of results.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use mongodb::bson::{doc, Bson, Document};
+
+        let pipeline = self.pipeline(&args)?;
+        let uri = self
+            .connection_string
+            .clone()
+            .or_else(|| std::env::var("MONGODB_URI").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing MONGODB_URI"))?;
+
+        let client = mongodb::sync::Client::with_uri_str(&uri)
+            .map_err(|e| MongoDbError::Connection(e.to_string()))?;
+        let collection = client
+            .database(&self.database)
+            .collection::<Document>(&self.collection);
+        let results: Vec<Value> = collection
+            .aggregate(pipeline)
+            .run()
+            .and_then(|cursor| cursor.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| self.classify(e))?
+            .into_iter()
+            .map(|d| Bson::Document(d).into_relaxed_extjson())
+            .collect();
+
+        // Atlas answers a search on a missing index with no hits rather
+        // than an error, so an empty result is worth a second look.
+        if results.is_empty() {
+            let indexes = collection
+                .aggregate(vec![doc! { "$listSearchIndexes": { "name": self.index_name.as_str() } }])
+                .run()
+                .and_then(|cursor| cursor.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| self.classify(e))?;
+            if indexes.is_empty() {
+                return Err(self.index_not_found().into());
+            }
+        }
+
+        Ok(serde_json::json!({
+            "database": self.database,
+            "collection": self.collection,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
+
+    /// The aggregation pipeline `run` sends for `args`: `$vectorSearch`,
+    /// the score as a `score` field, then the projection.
+    ///
+    /// Takes the same arguments as [`run`](Self::run) and does not connect.
+    pub fn pipeline(
+        &self,
+        args: &HashMap<String, Value>,
+    ) -> Result<Vec<mongodb::bson::Document>, anyhow::Error> {
+        use mongodb::bson::{self, doc, Bson, Document};
+
+        let vector = query_vector(args, self.embedder.as_ref())?;
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.top_k);
+
+        let to_document = |name: &str, value: &Value| -> Result<Document, anyhow::Error> {
+            bson::to_document(value)
+                .map_err(|e| anyhow::anyhow!("'{}' must be a document: {}", name, e))
+        };
+        let mut search = doc! {
+            "index": self.index_name.as_str(),
+            "path": self.vector_path.as_str(),
+            "queryVector": vector.iter().map(|&x| Bson::Double(x as f64)).collect::<Vec<_>>(),
+            "numCandidates": ((top_k * 10).min(10_000) as i64),
+            "limit": (top_k as i64),
+        };
+        if let Some(filter) = args.get("filter").filter(|f| !f.is_null()) {
+            search.insert("filter", to_document("filter", filter)?);
+        }
+        let projection = match args.get("projection").filter(|p| !p.is_null()) {
+            Some(projection) => {
+                let mut projection = to_document("projection", projection)?;
+                // An inclusion projection would otherwise drop the score.
+                let includes = projection
+                    .values()
+                    .any(|v| matches!(v, Bson::Int32(1) | Bson::Int64(1) | Bson::Boolean(true)));
+                if includes {
+                    projection.insert("score", 1);
+                }
+                projection
+            }
+            None => {
+                let mut projection = Document::new();
+                projection.insert(self.vector_path.clone(), 0);
+                projection
+            }
+        };
+        Ok(vec![
+            doc! { "$vectorSearch": search },
+            doc! { "$set": { "score": { "$meta": "vectorSearchScore" } } },
+            doc! { "$project": projection },
+        ])
+    }
+
+    fn index_not_found(&self) -> MongoDbError {
+        MongoDbError::IndexNotFound {
+            index: self.index_name.clone(),
+            collection: format!("{}.{}", self.database, self.collection),
+        }
+    }
+
+    /// Sort a driver error into connection, missing index or query failure.
+    fn classify(&self, error: mongodb::error::Error) -> MongoDbError {
+        use mongodb::error::ErrorKind;
+
+        match error.kind.as_ref() {
+            ErrorKind::ServerSelection { .. }
+            | ErrorKind::Io(_)
+            | ErrorKind::DnsResolve { .. }
+            | ErrorKind::Authentication { .. }
+            | ErrorKind::InvalidArgument { .. } => MongoDbError::Connection(error.to_string()),
+            _ => {
+                let message = error.to_string();
+                let lowered = message.to_ascii_lowercase();
+                if lowered.contains("index") && lowered.contains("not found") {
+                    self.index_not_found()
+                } else {
+                    MongoDbError::Query(message)
+                }
+            }
+        }
+    }
+}
+
+/// Builder for [`MongoDbVectorSearchTool`]; `D` and `C` track whether the
+/// database and collection have been supplied.
+#[derive(Debug, Clone)]
+pub struct MongoDbVectorSearchToolBuilder<D, C> {
+    database: D,
+    collection: C,
+    connection_string: Option<String>,
+    index_name: String,
+    vector_path: String,
+    top_k: usize,
+}
+
+impl<D, C> MongoDbVectorSearchToolBuilder<D, C> {
+    pub fn database(self, database: impl Into<String>) -> MongoDbVectorSearchToolBuilder<Set<String>, C> {
+        MongoDbVectorSearchToolBuilder {
+            database: Set(database.into()),
+            collection: self.collection,
+            connection_string: self.connection_string,
+            index_name: self.index_name,
+            vector_path: self.vector_path,
+            top_k: self.top_k,
+        }
+    }
+
+    pub fn collection(self, collection: impl Into<String>) -> MongoDbVectorSearchToolBuilder<D, Set<String>> {
+        MongoDbVectorSearchToolBuilder {
+            database: self.database,
+            collection: Set(collection.into()),
+            connection_string: self.connection_string,
+            index_name: self.index_name,
+            vector_path: self.vector_path,
+            top_k: self.top_k,
+        }
+    }
+
+    pub fn connection_string(mut self, conn: impl Into<String>) -> Self {
+        self.connection_string = Some(conn.into());
+        self
+    }
+
+    pub fn index_name(mut self, name: impl Into<String>) -> Self {
+        self.index_name = name.into();
+        self
+    }
+
+    pub fn vector_path(mut self, path: impl Into<String>) -> Self {
+        self.vector_path = path.into();
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+}
+
+impl MongoDbVectorSearchToolBuilder<Set<String>, Set<String>> {
+    pub fn build(self) -> MongoDbVectorSearchTool {
+        MongoDbVectorSearchTool {
+            connection_string: self.connection_string,
+            database: self.database.0,
+            collection: self.collection.0,
+            index_name: self.index_name,
+            vector_path: self.vector_path,
+            top_k: self.top_k,
+            embedder: None,
+        }
     }
 }
 
@@ -113,8 +991,13 @@ impl MongoDbVectorSearchTool {
 
 /// Search a Weaviate vector database for similar objects.
 ///
+/// Runs a GraphQL `Get` query against `class_name`: `nearVector` when only a
+/// vector is available, `hybrid` (BM25 plus vector) when there is query text.
+/// Weaviate only returns the properties a query names, so list them with
+/// [`with_properties`](Self::with_properties).
+///
 /// Corresponds to Python `WeaviateVectorSearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WeaviateVectorSearchTool {
     /// Weaviate server URL.
     pub weaviate_url: Option<String>,
@@ -124,6 +1007,28 @@ pub struct WeaviateVectorSearchTool {
     pub class_name: String,
     /// Number of results to return.
     pub top_k: usize,
+    /// Object properties to return with each hit.
+    pub properties: Vec<String>,
+    /// Objects sent per batch request by `ingest`.
+    pub batch_size: usize,
+    /// Embedder for text queries (not serialized). Without one, hybrid
+    /// queries rely on the class's own vectorizer.
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+}
+
+impl std::fmt::Debug for WeaviateVectorSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeaviateVectorSearchTool")
+            .field("weaviate_url", &self.weaviate_url)
+            .field("api_key", &self.api_key)
+            .field("class_name", &self.class_name)
+            .field("top_k", &self.top_k)
+            .field("properties", &self.properties)
+            .field("batch_size", &self.batch_size)
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl WeaviateVectorSearchTool {
@@ -133,6 +1038,9 @@ impl WeaviateVectorSearchTool {
             api_key: None,
             class_name: class_name.into(),
             top_k: 5,
+            properties: Vec::new(),
+            batch_size: 100,
+            embedder: None,
         }
     }
 
@@ -151,10 +1059,806 @@ impl WeaviateVectorSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "WeaviateVectorSearchTool: not yet implemented - requires Weaviate client integration"
-        )
+    pub fn with_properties(mut self, properties: Vec<String>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Embed text queries with this service.
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Start a builder; `build()` is available once `class_name` is set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::WeaviateVectorSearchTool;
+    ///
+    /// let tool = WeaviateVectorSearchTool::builder()
+    ///     .class_name("Article")
+    ///     .weaviate_url("http://localhost:8080")
+    ///     .properties(vec!["title".into(), "body".into()])
+    ///     .build();
+    /// ```
+    pub fn builder() -> WeaviateVectorSearchToolBuilder<Missing> {
+        WeaviateVectorSearchToolBuilder {
+            class_name: Missing,
+            weaviate_url: None,
+            api_key: None,
+            top_k: 5,
+            properties: Vec::new(),
+            batch_size: 100,
+        }
+    }
+
+    /// Search the class.
+    ///
+    /// Returns `{class_name, search, count, results}`, where each result has
+    /// the configured properties plus `_additional: {id, score}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - Query text; triggers a hybrid search.
+    /// * `query_vector` - Precomputed query embedding.
+    /// * `where` - Weaviate where filter as JSON, e.g.
+    ///   `{"path": ["lang"], "operator": "Equal", "valueText": "en"}`.
+    /// * `alpha` - Hybrid weighting, 0 = pure BM25, 1 = pure vector.
+    /// * `top_k` - Override the configured number of results.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let text = args.get("query").and_then(|v| v.as_str());
+        let vector = match (args.contains_key("query_vector"), text, &self.embedder) {
+            (true, _, _) | (false, Some(_), Some(_)) => {
+                Some(query_vector(&args, self.embedder.as_ref())?)
+            }
+            (false, Some(_), None) => None,
+            (false, None, _) => {
+                anyhow::bail!("Missing required argument: query or query_vector")
+            }
+        };
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.top_k);
+
+        for name in std::iter::once(&self.class_name).chain(&self.properties) {
+            if !is_graphql_name(name) {
+                anyhow::bail!("'{}' is not a valid Weaviate class or property name", name);
+            }
+        }
+
+        let mut arguments = vec![format!("limit: {}", top_k)];
+        let (search, additional) = match text {
+            Some(text) => {
+                let mut hybrid = vec![format!("query: {}", Value::from(text))];
+                if let Some(vector) = &vector {
+                    hybrid.push(format!("vector: {}", serde_json::to_string(vector)?));
+                }
+                if let Some(alpha) = args.get("alpha").and_then(|v| v.as_f64()) {
+                    hybrid.push(format!("alpha: {}", alpha));
+                }
+                arguments.push(format!("hybrid: {{{}}}", hybrid.join(", ")));
+                ("hybrid", "id score")
+            }
+            None => {
+                let vector = vector.unwrap_or_default();
+                arguments.push(format!(
+                    "nearVector: {{vector: {}}}",
+                    serde_json::to_string(&vector)?
+                ));
+                ("nearVector", "id distance certainty")
+            }
+        };
+        if let Some(filter) = args.get("where").filter(|f| !f.is_null()) {
+            arguments.push(format!("where: {}", graphql_literal(filter, None)));
+        }
+        let query = format!(
+            "{{ Get {{ {}({}) {{ {} _additional {{ {} }} }} }} }}",
+            self.class_name,
+            arguments.join(", "),
+            self.properties.join(" "),
+            additional
+        );
+
+        let (base_url, api_key) = self.endpoint();
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()?;
+        let mut request = client
+            .post(format!("{}/v1/graphql", base_url.trim_end_matches('/')))
+            .json(&serde_json::json!({ "query": query }));
+        if let Some(key) = &api_key {
+            request = request.bearer_auth(key);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let body: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            anyhow::bail!("Weaviate query failed (HTTP {}): {}", status, body);
+        }
+        if let Some(errors) = body["errors"].as_array().filter(|e| !e.is_empty()) {
+            let messages: Vec<&str> =
+                errors.iter().filter_map(|e| e["message"].as_str()).collect();
+            anyhow::bail!("Weaviate query failed: {}", messages.join("; "));
+        }
+
+        let results: Vec<Value> = body
+            .pointer(&format!("/data/Get/{}", self.class_name))
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut hit| {
+                // Hybrid scores arrive as strings; nearVector has certainty.
+                let extra = &mut hit["_additional"];
+                let score = match &extra["score"] {
+                    Value::String(s) => s.parse::<f64>().ok(),
+                    other => other.as_f64(),
+                }
+                .or_else(|| extra["certainty"].as_f64());
+                extra["score"] = score.map(Value::from).unwrap_or(Value::Null);
+                hit
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "class_name": self.class_name,
+            "search": search,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
+
+    /// Insert `documents` as `(text, vector, metadata)` objects through the
+    /// batch API, `batch_size` per request, creating the class (no
+    /// vectorizer, cosine distance) if it does not exist. Weaviate sizes the
+    /// class's vectors from the first object inserted.
+    ///
+    /// Each object's properties are its metadata (an object whose keys must
+    /// be valid property names) plus the text under `content`. Objects the
+    /// server rejects are counted individually; a failed request counts its
+    /// whole batch.
+    pub fn ingest(
+        &self,
+        documents: Vec<(String, Vec<f32>, Value)>,
+    ) -> Result<IngestReport, anyhow::Error> {
+        let mut report = IngestReport::default();
+        if documents.is_empty() {
+            return Ok(report);
+        }
+        if !is_graphql_name(&self.class_name) {
+            anyhow::bail!("'{}' is not a valid Weaviate class name", self.class_name);
+        }
+        let (_, documents) = uniform_dimension(documents, &mut report)?;
+
+        let (base_url, api_key) = self.endpoint();
+        let base_url = base_url.trim_end_matches('/');
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let send = |request: reqwest::blocking::RequestBuilder| {
+            let request = match &api_key {
+                Some(key) => request.bearer_auth(key),
+                None => request,
+            };
+            request.send()
+        };
+
+        let resp = send(client.get(format!("{}/v1/schema/{}", base_url, self.class_name)))?;
+        match resp.status().as_u16() {
+            404 => {
+                let resp = send(client.post(format!("{}/v1/schema", base_url)).json(
+                    &serde_json::json!({
+                        "class": self.class_name,
+                        "vectorizer": "none",
+                        "vectorIndexConfig": {"distance": "cosine"},
+                    }),
+                ))?;
+                let status = resp.status();
+                if !status.is_success() {
+                    let body = resp.text().unwrap_or_default();
+                    anyhow::bail!("Creating Weaviate class failed (HTTP {}): {}", status, body);
+                }
+            }
+            code if !(200..300).contains(&code) => {
+                let body = resp.text().unwrap_or_default();
+                anyhow::bail!("Weaviate schema lookup failed (HTTP {}): {}", code, body);
+            }
+            _ => {}
+        }
+
+        for batch in documents.chunks(self.batch_size.max(1)) {
+            let mut objects = Vec::with_capacity(batch.len());
+            let mut positions = Vec::with_capacity(batch.len());
+            for (i, (text, vector, metadata)) in batch {
+                let mut properties = match metadata {
+                    Value::Object(map) => map.clone(),
+                    Value::Null => serde_json::Map::new(),
+                    _ => {
+                        report.fail(1, format!("document {}: metadata must be an object", i));
+                        continue;
+                    }
+                };
+                if let Some(bad) = properties.keys().find(|k| !is_graphql_name(k)) {
+                    report.fail(
+                        1,
+                        format!("document {}: '{}' is not a valid property name", i, bad),
+                    );
+                    continue;
+                }
+                properties.insert("content".to_string(), Value::from(text.as_str()));
+                objects.push(serde_json::json!({
+                    "class": self.class_name,
+                    "properties": properties,
+                    "vector": vector,
+                }));
+                positions.push(*i);
+            }
+            if objects.is_empty() {
+                continue;
+            }
+
+            let sent = objects.len();
+            let resp = match send(
+                client
+                    .post(format!("{}/v1/batch/objects", base_url))
+                    .json(&serde_json::json!({ "objects": objects })),
+            ) {
+                Ok(resp) => resp,
+                Err(e) => {
+                    report.fail(sent, format!("batch request failed: {}", e));
+                    continue;
+                }
+            };
+            let status = resp.status();
+            let body: Value = resp.json().unwrap_or(Value::Null);
+            let Some(results) = body.as_array().filter(|_| status.is_success()) else {
+                report.fail(sent, format!("batch request failed (HTTP {}): {}", status, body));
+                continue;
+            };
+            for (result, position) in results.iter().zip(&positions) {
+                let errors = result
+                    .pointer("/result/errors/error")
+                    .and_then(|e| e.as_array())
+                    .filter(|e| !e.is_empty());
+                match errors {
+                    Some(errors) => {
+                        let messages: Vec<&str> =
+                            errors.iter().filter_map(|e| e["message"].as_str()).collect();
+                        report.fail(1, format!("document {}: {}", position, messages.join("; ")));
+                    }
+                    None => report.inserted += 1,
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// The server URL and API key, falling back to `WEAVIATE_URL` and
+    /// `WEAVIATE_API_KEY`.
+    fn endpoint(&self) -> (String, Option<String>) {
+        let base_url = self
+            .weaviate_url
+            .clone()
+            .or_else(|| std::env::var("WEAVIATE_URL").ok())
+            .unwrap_or_else(|| "http://localhost:8080".to_string());
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("WEAVIATE_API_KEY").ok());
+        (base_url, api_key)
+    }
+}
+
+/// Whether `name` can be spliced into a GraphQL query as an identifier.
+fn is_graphql_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Render JSON as a GraphQL input literal: unquoted keys, and `operator`
+/// values as bare enum names.
+fn graphql_literal(value: &Value, key: Option<&str>) -> String {
+    match value {
+        Value::Object(map) => {
+            let fields: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", k, graphql_literal(v, Some(k))))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(|v| graphql_literal(v, None)).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::String(s) if key == Some("operator") && is_graphql_name(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Builder for [`WeaviateVectorSearchTool`]; `C` tracks whether the class
+/// name has been supplied.
+#[derive(Debug, Clone)]
+pub struct WeaviateVectorSearchToolBuilder<C> {
+    class_name: C,
+    weaviate_url: Option<String>,
+    api_key: Option<String>,
+    top_k: usize,
+    properties: Vec<String>,
+    batch_size: usize,
+}
+
+impl<C> WeaviateVectorSearchToolBuilder<C> {
+    pub fn class_name(self, name: impl Into<String>) -> WeaviateVectorSearchToolBuilder<Set<String>> {
+        WeaviateVectorSearchToolBuilder {
+            class_name: Set(name.into()),
+            weaviate_url: self.weaviate_url,
+            api_key: self.api_key,
+            top_k: self.top_k,
+            properties: self.properties,
+            batch_size: self.batch_size,
+        }
+    }
+
+    pub fn weaviate_url(mut self, url: impl Into<String>) -> Self {
+        self.weaviate_url = Some(url.into());
+        self
+    }
+
+    pub fn api_key(mut self, key: impl Into<String>) -> Self {
+        self.api_key = Some(key.into());
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+
+    pub fn properties(mut self, properties: Vec<String>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+impl WeaviateVectorSearchToolBuilder<Set<String>> {
+    pub fn build(self) -> WeaviateVectorSearchTool {
+        WeaviateVectorSearchTool {
+            weaviate_url: self.weaviate_url,
+            api_key: self.api_key,
+            class_name: self.class_name.0,
+            top_k: self.top_k,
+            properties: self.properties,
+            batch_size: self.batch_size,
+            embedder: None,
+        }
+    }
+}
+
+// ── PgVectorSearchTool ───────────────────────────────────────────────────────
+
+/// Approximate-nearest-neighbour index types offered by pgvector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgVectorIndex {
+    /// Hierarchical navigable small world graph; better recall, slower builds.
+    Hnsw,
+    /// Inverted file with `lists` clusters; build it after loading data.
+    IvfFlat { lists: u32 },
+}
+
+/// Search a Postgres table with the pgvector extension by cosine distance.
+///
+/// Table and column names are quoted, so they match case-sensitively;
+/// `schema.table` names are supported.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PgVectorSearchTool {
+    /// Postgres connection string. Falls back to `DATABASE_URL`.
+    pub connection_string: Option<String>,
+    /// Table to search.
+    pub table: String,
+    /// `vector` column holding the embeddings.
+    pub vector_column: String,
+    /// Column returned as each result's content.
+    pub content_column: String,
+    /// Optional `jsonb` column returned with results and matched by `filter`.
+    pub metadata_column: Option<String>,
+    /// Number of results to return.
+    pub top_k: usize,
+    /// Rows inserted per transaction by `ingest`.
+    pub batch_size: usize,
+    /// Embedder for text queries (not serialized).
+    #[serde(skip)]
+    pub embedder: Option<Arc<dyn EmbeddingService>>,
+    /// Connections reused across runs (not serialized).
+    #[serde(skip)]
+    pub pool: Option<Arc<ConnectionPool>>,
+}
+
+impl std::fmt::Debug for PgVectorSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PgVectorSearchTool")
+            .field("connection_string", &self.connection_string)
+            .field("table", &self.table)
+            .field("vector_column", &self.vector_column)
+            .field("content_column", &self.content_column)
+            .field("metadata_column", &self.metadata_column)
+            .field("top_k", &self.top_k)
+            .field("batch_size", &self.batch_size)
+            .field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()))
+            .field("pool", &self.pool)
+            .finish()
+    }
+}
+
+impl PgVectorSearchTool {
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            connection_string: None,
+            table: table.into(),
+            vector_column: "embedding".to_string(),
+            content_column: "content".to_string(),
+            metadata_column: None,
+            top_k: 5,
+            batch_size: 100,
+            embedder: None,
+            pool: None,
+        }
+    }
+
+    pub fn with_connection_string(mut self, conn: impl Into<String>) -> Self {
+        self.connection_string = Some(conn.into());
+        self
+    }
+
+    pub fn with_vector_column(mut self, column: impl Into<String>) -> Self {
+        self.vector_column = column.into();
+        self
+    }
+
+    pub fn with_content_column(mut self, column: impl Into<String>) -> Self {
+        self.content_column = column.into();
+        self
+    }
+
+    pub fn with_metadata_column(mut self, column: impl Into<String>) -> Self {
+        self.metadata_column = Some(column.into());
+        self
+    }
+
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn with_embedder(mut self, embedder: Arc<dyn EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Reuse connections from `pool` instead of connecting on every run.
+    pub fn with_pool(mut self, pool: Arc<ConnectionPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Start a builder; `build()` is available once `table` is set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::PgVectorSearchTool;
+    ///
+    /// let tool = PgVectorSearchTool::builder()
+    ///     .table("documents")
+    ///     .connection_string("postgres://localhost/kb")
+    ///     .metadata_column("meta")
+    ///     .top_k(8)
+    ///     .build();
+    /// ```
+    pub fn builder() -> PgVectorSearchToolBuilder<Missing> {
+        PgVectorSearchToolBuilder {
+            table: Missing,
+            connection_string: None,
+            vector_column: "embedding".to_string(),
+            content_column: "content".to_string(),
+            metadata_column: None,
+            top_k: 5,
+            batch_size: 100,
+        }
+    }
+
+    /// Find the rows nearest to the query.
+    ///
+    /// Returns `{table, count, results: [{content, metadata, distance, score}]}`,
+    /// nearest first, where `score` is `1 - distance`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query_vector` - Precomputed query embedding.
+    /// * `query` - Query text, embedded with the configured embedder.
+    /// * `filter` - JSON object the metadata column must contain (`@>`).
+    /// * `top_k` - Override the configured number of results.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let top_k = args
+            .get("top_k")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.top_k);
+        let filter = args.get("filter").filter(|f| !f.is_null());
+        if let Some(filter) = filter {
+            if !filter.is_object() {
+                anyhow::bail!("'filter' must be a JSON object");
+            }
+            if self.metadata_column.is_none() {
+                anyhow::bail!("'filter' needs a metadata column; configure with_metadata_column");
+            }
+        }
+        let vector = pgvector_literal(&query_vector(&args, self.embedder.as_ref())?);
+
+        let table = sql::quote_ident(&self.table)?;
+        let vector_column = sql::quote_ident(&self.vector_column)?;
+        let content_column = sql::quote_ident(&self.content_column)?;
+        let metadata = match &self.metadata_column {
+            Some(column) => format!("{}::text", sql::quote_ident(column)?),
+            None => "NULL::text".to_string(),
+        };
+        let limit = top_k as i64;
+        let filter_json = filter.map(|f| f.to_string());
+        let mut params: Vec<&(dyn postgres::types::ToSql + Sync)> = vec![&vector, &limit];
+        let mut where_clause = String::new();
+        if let (Some(filter_json), Some(column)) = (&filter_json, &self.metadata_column) {
+            where_clause = format!("WHERE {} @> $3::jsonb", sql::quote_ident(column)?);
+            params.push(filter_json);
+        }
+        let query = format!(
+            "SELECT {content}::text, {metadata}, ({vector} <=> $1::text::vector)::float8 \
+             FROM {table} {where_clause} \
+             ORDER BY {vector} <=> $1::text::vector LIMIT $2",
+            content = content_column,
+            metadata = metadata,
+            vector = vector_column,
+            table = table,
+            where_clause = where_clause,
+        );
+
+        let mut client = sql::postgres_client(self.pool.as_deref(), &self.connection_string()?)?;
+        let rows = client.query(query.as_str(), &params)?;
+        let results: Vec<Value> = rows
+            .iter()
+            .map(|row| {
+                let distance: f64 = row.get(2);
+                let metadata = row
+                    .get::<_, Option<String>>(1)
+                    .and_then(|m| serde_json::from_str(&m).ok())
+                    .unwrap_or(Value::Null);
+                serde_json::json!({
+                    "content": row.get::<_, Option<String>>(0),
+                    "metadata": metadata,
+                    "distance": distance,
+                    "score": 1.0 - distance,
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "table": self.table,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
+
+    /// Insert `documents` as `(text, vector, metadata)` rows, `batch_size`
+    /// per transaction, creating the table if it does not exist with a
+    /// `vector` column sized to the first vector.
+    ///
+    /// Metadata goes to the metadata column and is dropped when none is
+    /// configured. A failed batch is rolled back and counts all its rows as
+    /// failed; later batches still run.
+    pub fn ingest(
+        &self,
+        documents: Vec<(String, Vec<f32>, Value)>,
+    ) -> Result<IngestReport, anyhow::Error> {
+        let mut report = IngestReport::default();
+        if documents.is_empty() {
+            return Ok(report);
+        }
+        let (dimension, documents) = uniform_dimension(documents, &mut report)?;
+
+        let table = sql::quote_ident(&self.table)?;
+        let vector_column = sql::quote_ident(&self.vector_column)?;
+        let content_column = sql::quote_ident(&self.content_column)?;
+        let metadata_column = self
+            .metadata_column
+            .as_deref()
+            .map(sql::quote_ident)
+            .transpose()?;
+        let mut client = sql::postgres_client(self.pool.as_deref(), &self.connection_string()?)?;
+
+        let metadata_definition = metadata_column
+            .as_ref()
+            .map(|column| format!("{} jsonb, ", column))
+            .unwrap_or_default();
+        client.batch_execute(&format!(
+            "CREATE EXTENSION IF NOT EXISTS vector; \
+             CREATE TABLE IF NOT EXISTS {table} ( \
+                 id bigserial PRIMARY KEY, {content} text, {metadata}{vector} vector({dimension}))",
+            table = table,
+            content = content_column,
+            metadata = metadata_definition,
+            vector = vector_column,
+            dimension = dimension,
+        ))?;
+
+        let insert = match &metadata_column {
+            Some(metadata) => format!(
+                "INSERT INTO {} ({}, {}, {}) VALUES ($1, $2::text::vector, $3::text::jsonb)",
+                table, content_column, vector_column, metadata
+            ),
+            None => format!(
+                "INSERT INTO {} ({}, {}) VALUES ($1, $2::text::vector)",
+                table, content_column, vector_column
+            ),
+        };
+        for batch in documents.chunks(self.batch_size.max(1)) {
+            let outcome = (|| -> Result<(), anyhow::Error> {
+                let mut tx = client.transaction()?;
+                let statement = tx.prepare(&insert)?;
+                for (_, (text, vector, metadata)) in batch {
+                    let vector = pgvector_literal(vector);
+                    let metadata = metadata.to_string();
+                    match metadata_column {
+                        Some(_) => tx.execute(&statement, &[text, &vector, &metadata])?,
+                        None => tx.execute(&statement, &[text, &vector])?,
+                    };
+                }
+                tx.commit()?;
+                Ok(())
+            })();
+            match outcome {
+                Ok(()) => report.inserted += batch.len(),
+                Err(e) => report.fail(batch.len(), e.to_string()),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Create a cosine-distance `index` on the vector column unless the
+    /// column already has an HNSW or IVFFlat index. Returns whether an index
+    /// was created.
+    pub fn ensure_index(&self, index: PgVectorIndex) -> Result<bool, anyhow::Error> {
+        let table = sql::quote_ident(&self.table)?;
+        let vector_column = sql::quote_ident(&self.vector_column)?;
+        let mut client = sql::postgres_client(self.pool.as_deref(), &self.connection_string()?)?;
+
+        let existing = client.query_one(
+            "SELECT EXISTS ( \
+                 SELECT 1 FROM pg_index i \
+                 JOIN pg_class c ON c.oid = i.indexrelid \
+                 JOIN pg_am am ON am.oid = c.relam \
+                 JOIN pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = ANY(i.indkey) \
+                 WHERE i.indrelid = $1::text::regclass AND a.attname = $2 \
+                   AND am.amname IN ('hnsw', 'ivfflat'))",
+            &[&table, &self.vector_column],
+        )?;
+        if existing.get::<_, bool>(0) {
+            return Ok(false);
+        }
+
+        let using = match index {
+            PgVectorIndex::Hnsw => format!("hnsw ({} vector_cosine_ops)", vector_column),
+            PgVectorIndex::IvfFlat { lists } => format!(
+                "ivfflat ({} vector_cosine_ops) WITH (lists = {})",
+                vector_column, lists
+            ),
+        };
+        client.batch_execute(&format!("CREATE INDEX ON {} USING {}", table, using))?;
+        Ok(true)
+    }
+
+    fn connection_string(&self) -> Result<String, anyhow::Error> {
+        self.connection_string
+            .clone()
+            .or_else(|| std::env::var("DATABASE_URL").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!("No connection string provided and DATABASE_URL not set")
+            })
+    }
+}
+
+/// `vector` in pgvector's text form, `[1,2,3]`.
+fn pgvector_literal(vector: &[f32]) -> String {
+    let values: Vec<String> = vector.iter().map(|x| x.to_string()).collect();
+    format!("[{}]", values.join(","))
+}
+
+/// Builder for [`PgVectorSearchTool`]; `T` tracks whether the table has
+/// been supplied.
+#[derive(Debug, Clone)]
+pub struct PgVectorSearchToolBuilder<T> {
+    table: T,
+    connection_string: Option<String>,
+    vector_column: String,
+    content_column: String,
+    metadata_column: Option<String>,
+    top_k: usize,
+    batch_size: usize,
+}
+
+impl<T> PgVectorSearchToolBuilder<T> {
+    pub fn table(self, table: impl Into<String>) -> PgVectorSearchToolBuilder<Set<String>> {
+        PgVectorSearchToolBuilder {
+            table: Set(table.into()),
+            connection_string: self.connection_string,
+            vector_column: self.vector_column,
+            content_column: self.content_column,
+            metadata_column: self.metadata_column,
+            top_k: self.top_k,
+            batch_size: self.batch_size,
+        }
+    }
+
+    pub fn connection_string(mut self, conn: impl Into<String>) -> Self {
+        self.connection_string = Some(conn.into());
+        self
+    }
+
+    pub fn vector_column(mut self, column: impl Into<String>) -> Self {
+        self.vector_column = column.into();
+        self
+    }
+
+    pub fn content_column(mut self, column: impl Into<String>) -> Self {
+        self.content_column = column.into();
+        self
+    }
+
+    pub fn metadata_column(mut self, column: impl Into<String>) -> Self {
+        self.metadata_column = Some(column.into());
+        self
+    }
+
+    pub fn top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+}
+
+impl PgVectorSearchToolBuilder<Set<String>> {
+    pub fn build(self) -> PgVectorSearchTool {
+        PgVectorSearchTool {
+            connection_string: self.connection_string,
+            table: self.table.0,
+            vector_column: self.vector_column,
+            content_column: self.content_column,
+            metadata_column: self.metadata_column,
+            top_k: self.top_k,
+            batch_size: self.batch_size,
+            embedder: None,
+            pool: None,
+        }
     }
 }
 
@@ -203,6 +1907,29 @@ impl CouchbaseFtsVectorSearchTool {
         self
     }
 
+    /// Start a builder; `build()` is available once both `bucket` and
+    /// `index_name` are set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::CouchbaseFtsVectorSearchTool;
+    ///
+    /// let tool = CouchbaseFtsVectorSearchTool::builder()
+    ///     .bucket("travel")
+    ///     .index_name("hotels_vec")
+    ///     .scope("inventory")
+    ///     .build();
+    /// ```
+    pub fn builder() -> CouchbaseFtsVectorSearchToolBuilder<Missing, Missing> {
+        CouchbaseFtsVectorSearchToolBuilder {
+            bucket: Missing,
+            index_name: Missing,
+            connection_string: None,
+            scope: None,
+            top_k: 5,
+        }
+    }
+
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!(
             "CouchbaseFtsVectorSearchTool: not yet implemented - requires Couchbase SDK integration"
@@ -210,6 +1937,66 @@ impl CouchbaseFtsVectorSearchTool {
     }
 }
 
+/// Builder for [`CouchbaseFtsVectorSearchTool`]; `B` and `I` track whether
+/// the bucket and index name have been supplied.
+#[derive(Debug, Clone)]
+pub struct CouchbaseFtsVectorSearchToolBuilder<B, I> {
+    bucket: B,
+    index_name: I,
+    connection_string: Option<String>,
+    scope: Option<String>,
+    top_k: usize,
+}
+
+impl<B, I> CouchbaseFtsVectorSearchToolBuilder<B, I> {
+    pub fn bucket(self, bucket: impl Into<String>) -> CouchbaseFtsVectorSearchToolBuilder<Set<String>, I> {
+        CouchbaseFtsVectorSearchToolBuilder {
+            bucket: Set(bucket.into()),
+            index_name: self.index_name,
+            connection_string: self.connection_string,
+            scope: self.scope,
+            top_k: self.top_k,
+        }
+    }
+
+    pub fn index_name(self, name: impl Into<String>) -> CouchbaseFtsVectorSearchToolBuilder<B, Set<String>> {
+        CouchbaseFtsVectorSearchToolBuilder {
+            bucket: self.bucket,
+            index_name: Set(name.into()),
+            connection_string: self.connection_string,
+            scope: self.scope,
+            top_k: self.top_k,
+        }
+    }
+
+    pub fn connection_string(mut self, conn: impl Into<String>) -> Self {
+        self.connection_string = Some(conn.into());
+        self
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    pub fn top_k(mut self, k: usize) -> Self {
+        self.top_k = k;
+        self
+    }
+}
+
+impl CouchbaseFtsVectorSearchToolBuilder<Set<String>, Set<String>> {
+    pub fn build(self) -> CouchbaseFtsVectorSearchTool {
+        CouchbaseFtsVectorSearchTool {
+            connection_string: self.connection_string,
+            bucket: self.bucket.0,
+            scope: self.scope,
+            index_name: self.index_name.0,
+            top_k: self.top_k,
+        }
+    }
+}
+
 // ── SingleStoreSearchTool ────────────────────────────────────────────────────
 
 /// Search a SingleStore database using vector similarity or full-text search.
@@ -225,6 +2012,9 @@ pub struct SingleStoreSearchTool {
     pub table: Option<String>,
     /// Number of results to return.
     pub top_k: usize,
+    /// Connections reused across runs (not serialized).
+    #[serde(skip)]
+    pub pool: Option<Arc<ConnectionPool>>,
 }
 
 impl SingleStoreSearchTool {
@@ -234,6 +2024,7 @@ impl SingleStoreSearchTool {
             database: None,
             table: None,
             top_k: 5,
+            pool: None,
         }
     }
 
@@ -257,6 +2048,12 @@ impl SingleStoreSearchTool {
         self
     }
 
+    /// Reuse connections from `pool` instead of connecting on every run.
+    pub fn with_pool(mut self, pool: Arc<ConnectionPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!(
             "SingleStoreSearchTool: not yet implemented - requires SingleStore driver integration"
@@ -272,23 +2069,92 @@ impl Default for SingleStoreSearchTool {
 
 // ── SnowflakeSearchTool ──────────────────────────────────────────────────────
 
+/// Errors from the Snowflake SQL API that need different fixes.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<SnowflakeError>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SnowflakeError {
+    /// The account host could not be reached.
+    #[error("Could not connect to Snowflake: {0}")]
+    Connection(String),
+    /// Login failed or the token was rejected.
+    #[error("Snowflake authentication failed (HTTP {status}): {message}")]
+    Auth { status: u16, message: String },
+    /// The statement failed to compile or run.
+    #[error("Snowflake statement failed ({code}, SQL state {sql_state}): {message}")]
+    Statement {
+        code: String,
+        sql_state: String,
+        message: String,
+    },
+    /// The statement was still running when the tool's timeout passed.
+    #[error("Snowflake statement {statement_handle} did not finish in time")]
+    Timeout { statement_handle: String },
+    /// Any other error response.
+    #[error("Snowflake request failed (HTTP {status}): {message}")]
+    Api { status: u16, message: String },
+}
+
 /// Query a Snowflake data warehouse using natural language or SQL.
 ///
+/// Statements go through the Snowflake SQL REST API. Authentication uses,
+/// in order of preference, a key pair (`private_key_path`), a programmatic
+/// access token, or a password exchanged for a session token at login.
+/// Unset settings resolve from `SNOWFLAKE_ACCOUNT`, `SNOWFLAKE_USER`,
+/// `SNOWFLAKE_PASSWORD`, `SNOWFLAKE_TOKEN`, `SNOWFLAKE_PRIVATE_KEY_PATH`,
+/// `SNOWFLAKE_WAREHOUSE`, `SNOWFLAKE_DATABASE`, `SNOWFLAKE_SCHEMA` and
+/// `SNOWFLAKE_ROLE`. Secrets are never serialized and `Debug` redacts them.
+///
 /// Corresponds to Python `SnowflakeSearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SnowflakeSearchTool {
     /// Snowflake account identifier.
     pub account: Option<String>,
     /// Snowflake username.
     pub username: Option<String>,
-    /// Snowflake password.
+    /// Snowflake password (never serialized).
+    #[serde(default, skip_serializing)]
     pub password: Option<String>,
+    /// Programmatic access token (never serialized).
+    #[serde(default, skip_serializing)]
+    pub token: Option<String>,
+    /// Path to a PEM-encoded RSA private key for key-pair authentication.
+    pub private_key_path: Option<String>,
     /// Warehouse name.
     pub warehouse: Option<String>,
     /// Database name.
     pub database: Option<String>,
     /// Schema name.
     pub schema: Option<String>,
+    /// Role name.
+    pub role: Option<String>,
+    /// API host override; defaults to `https://<account>.snowflakecomputing.com`.
+    pub base_url: Option<String>,
+    /// How long to wait for a statement to finish.
+    pub timeout: std::time::Duration,
+    /// Model that turns `question` into SQL (not serialized).
+    #[serde(skip)]
+    pub llm: Option<Arc<dyn CompletionService>>,
+}
+
+impl std::fmt::Debug for SnowflakeSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |secret: &Option<String>| secret.as_ref().map(|_| "<redacted>");
+        f.debug_struct("SnowflakeSearchTool")
+            .field("account", &self.account)
+            .field("username", &self.username)
+            .field("password", &redacted(&self.password))
+            .field("token", &redacted(&self.token))
+            .field("private_key_path", &self.private_key_path)
+            .field("warehouse", &self.warehouse)
+            .field("database", &self.database)
+            .field("schema", &self.schema)
+            .field("role", &self.role)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("llm", &self.llm.as_ref().map(|l| l.model_name().to_string()))
+            .finish()
+    }
 }
 
 impl SnowflakeSearchTool {
@@ -297,9 +2163,15 @@ impl SnowflakeSearchTool {
             account: None,
             username: None,
             password: None,
+            token: None,
+            private_key_path: None,
             warehouse: None,
             database: None,
             schema: None,
+            role: None,
+            base_url: None,
+            timeout: std::time::Duration::from_secs(60),
+            llm: None,
         }
     }
 
@@ -318,6 +2190,16 @@ impl SnowflakeSearchTool {
         self
     }
 
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_private_key_path(mut self, path: impl Into<String>) -> Self {
+        self.private_key_path = Some(path.into());
+        self
+    }
+
     pub fn with_warehouse(mut self, warehouse: impl Into<String>) -> Self {
         self.warehouse = Some(warehouse.into());
         self
@@ -333,10 +2215,147 @@ impl SnowflakeSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "SnowflakeSearchTool: not yet implemented - requires Snowflake driver integration"
-        )
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.role = Some(role.into());
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the model used to turn a `question` into SQL.
+    pub fn with_llm(mut self, llm: Arc<dyn CompletionService>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Run a query and return its rows with native JSON types.
+    ///
+    /// Returns `{sql, columns, row_count, rows}` where each row is an object
+    /// keyed by column name. Failures are reported as [`SnowflakeError`]
+    /// variants.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - SQL to run as written.
+    /// * `question` - Natural-language question; the configured LLM writes a
+    ///   single `SELECT` for it from the current schema.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args.get("query").and_then(|v| v.as_str());
+        let question = args.get("question").and_then(|v| v.as_str());
+        if query.is_none() && question.is_none() {
+            anyhow::bail!("Missing required argument: query or question");
+        }
+
+        let setting = |value: &Option<String>, var: &str| {
+            value.clone().or_else(|| std::env::var(var).ok())
+        };
+        let account = setting(&self.account, "SNOWFLAKE_ACCOUNT")
+            .ok_or_else(|| anyhow::anyhow!("No account provided and SNOWFLAKE_ACCOUNT not set"))?;
+        let user = setting(&self.username, "SNOWFLAKE_USER")
+            .ok_or_else(|| anyhow::anyhow!("No username provided and SNOWFLAKE_USER not set"))?;
+        let warehouse = setting(&self.warehouse, "SNOWFLAKE_WAREHOUSE");
+        let database = setting(&self.database, "SNOWFLAKE_DATABASE");
+        let schema = setting(&self.schema, "SNOWFLAKE_SCHEMA");
+        let role = setting(&self.role, "SNOWFLAKE_ROLE");
+
+        let credentials = if let Some(path) =
+            setting(&self.private_key_path, "SNOWFLAKE_PRIVATE_KEY_PATH")
+        {
+            let private_key_pem = std::fs::read_to_string(&path).map_err(|e| {
+                anyhow::anyhow!("Failed to read Snowflake private key {}: {}", path, e)
+            })?;
+            snowflake::Credentials::KeyPair { private_key_pem }
+        } else if let Some(token) = setting(&self.token, "SNOWFLAKE_TOKEN") {
+            snowflake::Credentials::Pat(token)
+        } else if let Some(password) = setting(&self.password, "SNOWFLAKE_PASSWORD") {
+            snowflake::Credentials::Password(password)
+        } else {
+            anyhow::bail!(
+                "No Snowflake credentials: set a private key path, token or password \
+                 (or SNOWFLAKE_PRIVATE_KEY_PATH, SNOWFLAKE_TOKEN, SNOWFLAKE_PASSWORD)"
+            );
+        };
+
+        let base_url = self
+            .base_url
+            .clone()
+            .unwrap_or_else(|| format!("https://{}.snowflakecomputing.com", account));
+        let session = snowflake::Session::connect(
+            &base_url,
+            &account,
+            &user,
+            &credentials,
+            &[
+                ("warehouse", &warehouse),
+                ("databaseName", &database),
+                ("schemaName", &schema),
+                ("roleName", &role),
+            ],
+        )?;
+
+        let timeout = self.timeout;
+        let statement = |sql: &str| {
+            let mut body = serde_json::json!({
+                "statement": sql,
+                "timeout": self.timeout.as_secs(),
+            });
+            for (key, value) in [
+                ("warehouse", &warehouse),
+                ("database", &database),
+                ("schema", &schema),
+                ("role", &role),
+            ] {
+                if let Some(value) = value {
+                    body[key] = Value::from(value.as_str());
+                }
+            }
+            session.execute(&body, timeout)
+        };
+
+        let sql = match (query, question) {
+            (Some(query), _) => query.to_string(),
+            (None, question) => {
+                let llm = self.llm.as_ref().ok_or_else(|| {
+                    anyhow::anyhow!("No LLM configured; use with_llm or pass query")
+                })?;
+                let (_, rows) = statement(
+                    "SELECT table_name, column_name, data_type \
+                     FROM information_schema.columns \
+                     WHERE table_schema = CURRENT_SCHEMA() \
+                     ORDER BY table_name, ordinal_position",
+                )?;
+                let tables: sql::Schema = rows
+                    .into_iter()
+                    .map(|row| {
+                        let text = |i: usize| {
+                            row.get(i).and_then(|v| v.as_str()).unwrap_or_default().to_string()
+                        };
+                        (text(0), text(1), text(2))
+                    })
+                    .collect();
+                let prompt = sql::prompt("Snowflake", &tables, question.unwrap_or_default());
+                sql::read_only_select(&sql::extract_sql(&llm.complete(&prompt)?))?
+            }
+        };
+
+        let (columns, rows) = statement(&sql)?;
+        let rows: Vec<Value> = rows
+            .into_iter()
+            .map(|row| Value::Object(columns.iter().cloned().zip(row).collect()))
+            .collect();
+        Ok(serde_json::json!({
+            "sql": sql,
+            "columns": columns,
+            "row_count": rows.len(),
+            "rows": rows,
+        }))
     }
 }
 
@@ -402,15 +2421,42 @@ impl Default for DatabricksQueryTool {
 
 /// Convert natural language questions to SQL queries and execute them.
 ///
+/// The schema of the configured tables (or every table when `tables` is
+/// empty) is sent to the LLM along with the question. The generated SQL must
+/// be a single `SELECT`; anything else is refused before it reaches the
+/// database, and Postgres queries additionally run in a read-only
+/// transaction while SQLite databases are opened read-only.
+///
 /// Corresponds to Python `NL2SQLTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Nl2SqlTool {
-    /// Database connection string.
+    /// Database connection string. Falls back to `DATABASE_URL`.
     pub connection_string: Option<String>,
-    /// Database dialect (e.g., "postgresql", "mysql", "sqlite").
+    /// Database dialect: "postgresql" or "sqlite".
     pub dialect: String,
     /// List of tables to include in schema context.
     pub tables: Vec<String>,
+    /// Maximum number of rows returned.
+    pub max_rows: usize,
+    /// Model that writes the SQL (not serialized).
+    #[serde(skip)]
+    pub llm: Option<Arc<dyn CompletionService>>,
+    /// Connections reused across runs (not serialized).
+    #[serde(skip)]
+    pub pool: Option<Arc<ConnectionPool>>,
+}
+
+impl std::fmt::Debug for Nl2SqlTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Nl2SqlTool")
+            .field("connection_string", &self.connection_string)
+            .field("dialect", &self.dialect)
+            .field("tables", &self.tables)
+            .field("max_rows", &self.max_rows)
+            .field("llm", &self.llm.as_ref().map(|l| l.model_name().to_string()))
+            .field("pool", &self.pool)
+            .finish()
+    }
 }
 
 impl Nl2SqlTool {
@@ -419,6 +2465,9 @@ impl Nl2SqlTool {
             connection_string: None,
             dialect: "postgresql".to_string(),
             tables: Vec::new(),
+            max_rows: 100,
+            llm: None,
+            pool: None,
         }
     }
 
@@ -437,10 +2486,100 @@ impl Nl2SqlTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "Nl2SqlTool: not yet implemented - requires LLM SQL generation and database driver integration"
-        )
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Set the model that turns questions into SQL. Any
+    /// `Fn(&str) -> Result<String>` closure works.
+    pub fn with_llm(mut self, llm: Arc<dyn CompletionService>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Reuse connections from `pool` instead of connecting on every run.
+    pub fn with_pool(mut self, pool: Arc<ConnectionPool>) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Answer a question with a read-only SQL query.
+    ///
+    /// # Arguments (in `args`)
+    /// - `question` (string, required unless `sql_query` is given): what to ask
+    /// - `sql_query` (string, optional): run this SQL instead of generating it;
+    ///   it is validated the same way
+    ///
+    /// Returns `{question, sql, dialect, columns, rows, row_count, truncated}`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let dialect = sql::Dialect::parse(&self.dialect)?;
+        let question = args.get("question").and_then(|v| v.as_str());
+        let given_sql = args.get("sql_query").and_then(|v| v.as_str());
+        if question.is_none() && given_sql.is_none() {
+            anyhow::bail!("Missing required argument: question");
+        }
+        let conn_str = self
+            .connection_string
+            .clone()
+            .or_else(|| std::env::var("DATABASE_URL").ok())
+            .ok_or_else(|| {
+                anyhow::anyhow!("No connection string provided and DATABASE_URL not set")
+            })?;
+
+        let (query, rows) = match dialect {
+            sql::Dialect::Postgres => {
+                let mut client = sql::postgres_client(self.pool.as_deref(), &conn_str)?;
+                let query = match given_sql {
+                    Some(query) => sql::read_only_select(query)?,
+                    None => {
+                        let schema = sql::postgres_schema(&mut client, &self.tables)?;
+                        self.generate(dialect, &schema, question.unwrap_or_default())?
+                    }
+                };
+                let rows = sql::postgres_rows(&mut client, &query, self.max_rows)?;
+                (query, rows)
+            }
+            sql::Dialect::Sqlite => {
+                let conn = sql::sqlite_connection(self.pool.as_deref(), &conn_str, false)?;
+                let query = match given_sql {
+                    Some(query) => sql::read_only_select(query)?,
+                    None => {
+                        let schema = sql::sqlite_schema(&conn, &self.tables)?;
+                        self.generate(dialect, &schema, question.unwrap_or_default())?
+                    }
+                };
+                let rows = sql::sqlite_rows(&conn, &query, &[], self.max_rows)?;
+                (query, rows)
+            }
+        };
+
+        Ok(serde_json::json!({
+            "question": question,
+            "sql": query,
+            "dialect": self.dialect,
+            "columns": rows.columns,
+            "row_count": rows.rows.len(),
+            "rows": rows.rows,
+            "truncated": rows.truncated,
+        }))
+    }
+
+    /// Ask the LLM for a query and validate what comes back.
+    fn generate(
+        &self,
+        dialect: sql::Dialect,
+        schema: &sql::Schema,
+        question: &str,
+    ) -> Result<String, anyhow::Error> {
+        let llm = self.llm.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("No LLM configured; use with_llm or pass sql_query")
+        })?;
+        if schema.is_empty() {
+            anyhow::bail!("No tables found to query");
+        }
+        let reply = llm.complete(&sql::prompt(dialect.name(), schema, question))?;
+        sql::read_only_select(&sql::extract_sql(&reply))
     }
 }
 
@@ -449,3 +2588,219 @@ impl Default for Nl2SqlTool {
         Self::new()
     }
 }
+
+// ── SqliteSearchTool ─────────────────────────────────────────────────────────
+
+/// Query, inspect and text-search a local SQLite database file.
+///
+/// The file is opened read-only unless writes are enabled with
+/// [`with_allow_writes`](Self::with_allow_writes); even then, only the
+/// `query` action runs caller-supplied SQL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SqliteSearchTool {
+    /// Path to the database file.
+    pub file_path: String,
+    /// Whether `query` may modify the database.
+    pub allow_writes: bool,
+    /// Maximum number of rows returned.
+    pub max_rows: usize,
+}
+
+impl SqliteSearchTool {
+    pub fn new(file_path: impl Into<String>) -> Self {
+        Self {
+            file_path: file_path.into(),
+            allow_writes: false,
+            max_rows: 100,
+        }
+    }
+
+    pub fn with_allow_writes(mut self, allow_writes: bool) -> Self {
+        self.allow_writes = allow_writes;
+        self
+    }
+
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Start a builder; `build()` is available once `file_path` is set.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use crewai_tools::SqliteSearchTool;
+    ///
+    /// let tool = SqliteSearchTool::builder()
+    ///     .file_path("inventory.db")
+    ///     .max_rows(50)
+    ///     .build();
+    /// ```
+    pub fn builder() -> SqliteSearchToolBuilder<Missing> {
+        SqliteSearchToolBuilder {
+            file_path: Missing,
+            allow_writes: false,
+            max_rows: 100,
+        }
+    }
+
+    /// Run one of the tool's actions.
+    ///
+    /// Row-returning actions return `{columns, rows, row_count, truncated}`
+    /// with each row an object keyed by column name; a write returns
+    /// `{rows_affected}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `action` - `query` (default), `list_tables`, `describe_table` or
+    ///   `search`.
+    /// * `sql_query` - SQL for `query`; a single statement.
+    /// * `table` - Table for `describe_table` and `search`.
+    /// * `search_query` - Text that `search` looks for (case-insensitive
+    ///   substring match).
+    /// * `columns` - Columns `search` looks in; defaults to the table's
+    ///   text columns.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let arg = |name: &str| args.get(name).and_then(|v| v.as_str());
+        let action = arg("action").unwrap_or("query");
+        let table = || {
+            arg("table").ok_or_else(|| anyhow::anyhow!("Missing required argument: table"))
+        };
+        let conn = sql::sqlite_open(&self.file_path, self.allow_writes)?;
+
+        let rows = match action {
+            "query" => {
+                let query = arg("sql_query")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required argument: sql_query"))?;
+                if !conn.prepare(query)?.readonly() {
+                    if !self.allow_writes {
+                        anyhow::bail!(
+                            "Refusing to run SQL: the database is read-only; \
+                             enable with_allow_writes to modify it"
+                        );
+                    }
+                    let rows_affected = conn.execute(query, [])?;
+                    return Ok(serde_json::json!({ "rows_affected": rows_affected }));
+                }
+                sql::sqlite_rows(&conn, query, &[], self.max_rows)?
+            }
+            "list_tables" => sql::sqlite_rows(
+                &conn,
+                "SELECT name, type FROM sqlite_master \
+                 WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' ORDER BY name",
+                &[],
+                self.max_rows,
+            )?,
+            "describe_table" => {
+                let table = table()?;
+                let rows = sql::sqlite_rows(
+                    &conn,
+                    "SELECT name, type, \"notnull\" AS not_null, dflt_value AS default_value, pk \
+                     FROM pragma_table_info(?1)",
+                    &[&table],
+                    usize::MAX,
+                )?;
+                if rows.rows.is_empty() {
+                    anyhow::bail!("Table '{}' not found", table);
+                }
+                rows
+            }
+            "search" => {
+                let table = table()?;
+                let term = arg("search_query")
+                    .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+                let columns: Vec<String> = match args.get("columns").and_then(|v| v.as_array()) {
+                    Some(columns) => columns
+                        .iter()
+                        .filter_map(|c| c.as_str().map(str::to_string))
+                        .collect(),
+                    None => text_columns(&conn, table)?,
+                };
+                if columns.is_empty() {
+                    anyhow::bail!("Table '{}' has no text columns to search", table);
+                }
+                let conditions = columns
+                    .iter()
+                    .map(|c| Ok(format!("{} LIKE ?1 ESCAPE '\\'", sql::quote_ident(c)?)))
+                    .collect::<Result<Vec<_>, anyhow::Error>>()?
+                    .join(" OR ");
+                let pattern = format!(
+                    "%{}%",
+                    term.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+                );
+                let query = format!(
+                    "SELECT * FROM {} WHERE {}",
+                    sql::quote_ident(table)?,
+                    conditions
+                );
+                sql::sqlite_rows(&conn, &query, &[&pattern], self.max_rows)?
+            }
+            other => anyhow::bail!(
+                "Unknown action '{}': expected query, list_tables, describe_table or search",
+                other
+            ),
+        };
+
+        Ok(serde_json::json!({
+            "columns": rows.columns,
+            "row_count": rows.rows.len(),
+            "rows": rows.rows,
+            "truncated": rows.truncated,
+        }))
+    }
+}
+
+/// Builder for [`SqliteSearchTool`]; `F` tracks whether the database file
+/// has been supplied.
+#[derive(Debug, Clone)]
+pub struct SqliteSearchToolBuilder<F> {
+    file_path: F,
+    allow_writes: bool,
+    max_rows: usize,
+}
+
+impl<F> SqliteSearchToolBuilder<F> {
+    pub fn file_path(self, path: impl Into<String>) -> SqliteSearchToolBuilder<Set<String>> {
+        SqliteSearchToolBuilder {
+            file_path: Set(path.into()),
+            allow_writes: self.allow_writes,
+            max_rows: self.max_rows,
+        }
+    }
+
+    pub fn allow_writes(mut self, allow_writes: bool) -> Self {
+        self.allow_writes = allow_writes;
+        self
+    }
+
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+}
+
+impl SqliteSearchToolBuilder<Set<String>> {
+    pub fn build(self) -> SqliteSearchTool {
+        SqliteSearchTool {
+            file_path: self.file_path.0,
+            allow_writes: self.allow_writes,
+            max_rows: self.max_rows,
+        }
+    }
+}
+
+/// Columns of `table` with text affinity (declared type containing CHAR,
+/// CLOB or TEXT, per SQLite's affinity rules).
+fn text_columns(conn: &rusqlite::Connection, table: &str) -> Result<Vec<String>, anyhow::Error> {
+    let schema = sql::sqlite_schema(conn, &[table.to_string()])?;
+    if schema.is_empty() {
+        anyhow::bail!("Table '{}' not found", table);
+    }
+    Ok(schema
+        .into_iter()
+        .filter(|(_, _, ty)| {
+            let ty = ty.to_ascii_uppercase();
+            ty.contains("CHAR") || ty.contains("CLOB") || ty.contains("TEXT")
+        })
+        .map(|(_, column, _)| column)
+        .collect())
+}
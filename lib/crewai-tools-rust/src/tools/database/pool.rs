@@ -0,0 +1,254 @@
+//! Connection reuse across database tool runs.
+//!
+//! A `ConnectionPool` keeps idle connections per connection string so that
+//! repeated `run()` calls skip the connect/authenticate round trips. Share
+//! one pool (in an `Arc`) between tools talking to the same database.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Returned (wrapped in `anyhow::Error`) when every connection for a key
+/// stayed busy for the pool's acquire timeout; callers can
+/// `downcast_ref::<PoolTimeout>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("No database connection became available within {timeout:?}")]
+pub struct PoolTimeout {
+    pub timeout: Duration,
+}
+
+/// Connections for one key.
+#[derive(Default)]
+struct Slot {
+    /// Idle connections with the time they were returned, oldest first.
+    idle: Vec<(Box<dyn Any + Send>, Instant)>,
+    /// Connections checked out or being opened.
+    in_use: usize,
+}
+
+/// Idle connections shared between database tools, keyed by connection
+/// string.
+///
+/// At most `max_connections` connections exist per key; once they are all
+/// checked out, `acquire` blocks until one is returned or the acquire
+/// timeout passes. Connections idle for longer than the idle timeout are
+/// closed instead of reused.
+pub struct ConnectionPool {
+    max_connections: usize,
+    idle_timeout: Duration,
+    acquire_timeout: Duration,
+    slots: Mutex<HashMap<String, Slot>>,
+    returned: Condvar,
+}
+
+impl ConnectionPool {
+    pub fn new() -> Self {
+        Self {
+            max_connections: 10,
+            idle_timeout: Duration::from_secs(300),
+            acquire_timeout: Duration::from_secs(30),
+            slots: Mutex::new(HashMap::new()),
+            returned: Condvar::new(),
+        }
+    }
+
+    /// Maximum connections per connection string (at least 1).
+    pub fn with_max_connections(mut self, max: usize) -> Self {
+        self.max_connections = max.max(1);
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// How long `acquire` waits for a busy pool before failing with
+    /// [`PoolTimeout`].
+    pub fn with_acquire_timeout(mut self, timeout: Duration) -> Self {
+        self.acquire_timeout = timeout;
+        self
+    }
+
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    /// Idle connections across all keys.
+    pub fn idle_count(&self) -> usize {
+        self.slots.lock().unwrap().values().map(|s| s.idle.len()).sum()
+    }
+
+    /// Checked-out connections across all keys.
+    pub fn in_use_count(&self) -> usize {
+        self.slots.lock().unwrap().values().map(|s| s.in_use).sum()
+    }
+
+    /// Check out an idle connection of type `C` for `key`, or open one with
+    /// `connect` if the key is below its limit. Blocks while the key is at
+    /// its limit, up to the acquire timeout.
+    pub fn acquire<C, F>(
+        &self,
+        key: &str,
+        connect: F,
+    ) -> Result<PooledConnection<'_, C>, anyhow::Error>
+    where
+        C: Send + 'static,
+        F: FnOnce() -> Result<C, anyhow::Error>,
+    {
+        let deadline = Instant::now() + self.acquire_timeout;
+        let mut slots = self.slots.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            let slot = slots.entry(key.to_string()).or_default();
+            let idle_timeout = self.idle_timeout;
+            slot.idle.retain(|(_, since)| now.duration_since(*since) < idle_timeout);
+
+            if let Some(pos) = slot.idle.iter().rposition(|(conn, _)| conn.is::<C>()) {
+                let (conn, _) = slot.idle.remove(pos);
+                slot.in_use += 1;
+                let conn = *conn.downcast::<C>().expect("checked by is::<C>()");
+                return Ok(PooledConnection::pooled(self, key, conn));
+            }
+            if slot.in_use + slot.idle.len() >= self.max_connections && !slot.idle.is_empty() {
+                // Idle connections of another type make room for this one.
+                slot.idle.remove(0);
+            }
+            if slot.in_use + slot.idle.len() < self.max_connections {
+                slot.in_use += 1;
+                break;
+            }
+
+            let remaining = deadline.saturating_duration_since(now);
+            if remaining.is_zero() {
+                return Err(PoolTimeout {
+                    timeout: self.acquire_timeout,
+                }
+                .into());
+            }
+            slots = self.returned.wait_timeout(slots, remaining).unwrap().0;
+        }
+        drop(slots);
+
+        // Connect outside the lock; the slot is already reserved.
+        match connect() {
+            Ok(conn) => Ok(PooledConnection::pooled(self, key, conn)),
+            Err(e) => {
+                self.release(key, None);
+                Err(e)
+            }
+        }
+    }
+
+    /// Give back a checked-out slot, keeping `conn` for reuse if given.
+    fn release(&self, key: &str, conn: Option<Box<dyn Any + Send>>) {
+        let mut slots = self.slots.lock().unwrap();
+        if let Some(slot) = slots.get_mut(key) {
+            slot.in_use = slot.in_use.saturating_sub(1);
+            if let Some(conn) = conn {
+                slot.idle.push((conn, Instant::now()));
+            }
+        }
+        drop(slots);
+        self.returned.notify_one();
+    }
+}
+
+impl Default for ConnectionPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ConnectionPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Keys are connection strings, which may hold passwords; omit them.
+        f.debug_struct("ConnectionPool")
+            .field("max_connections", &self.max_connections)
+            .field("idle_timeout", &self.idle_timeout)
+            .field("acquire_timeout", &self.acquire_timeout)
+            .field("idle", &self.idle_count())
+            .field("in_use", &self.in_use_count())
+            .finish()
+    }
+}
+
+/// A connection checked out of a [`ConnectionPool`] (or opened without
+/// one). Returned to the pool when dropped.
+pub struct PooledConnection<'a, C: Send + 'static> {
+    conn: Option<C>,
+    /// The pool and key to return to; `None` for an unpooled connection.
+    home: Option<(&'a ConnectionPool, String)>,
+}
+
+impl<'a, C: Send + 'static> PooledConnection<'a, C> {
+    fn pooled(pool: &'a ConnectionPool, key: &str, conn: C) -> Self {
+        Self {
+            conn: Some(conn),
+            home: Some((pool, key.to_string())),
+        }
+    }
+
+    /// Wrap a connection that does not belong to a pool.
+    pub fn unpooled(conn: C) -> Self {
+        Self {
+            conn: Some(conn),
+            home: None,
+        }
+    }
+
+    /// Close the connection instead of returning it, e.g. after it broke.
+    pub fn discard(mut self) {
+        if let Some((pool, key)) = self.home.take() {
+            self.conn = None;
+            pool.release(&key, None);
+        }
+    }
+}
+
+impl<C: Send + 'static> Deref for PooledConnection<'_, C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        self.conn.as_ref().expect("connection present until drop")
+    }
+}
+
+impl<C: Send + 'static> DerefMut for PooledConnection<'_, C> {
+    fn deref_mut(&mut self) -> &mut C {
+        self.conn.as_mut().expect("connection present until drop")
+    }
+}
+
+impl<C: Send + 'static> Drop for PooledConnection<'_, C> {
+    fn drop(&mut self) {
+        if let Some((pool, key)) = self.home.take() {
+            // A panic mid-use may leave the connection in a bad state.
+            let conn = self
+                .conn
+                .take()
+                .filter(|_| !std::thread::panicking())
+                .map(|c| Box::new(c) as Box<dyn Any + Send>);
+            pool.release(&key, conn);
+        }
+    }
+}
+
+/// Check out a connection for `key` from `pool`, or open an unpooled one
+/// when there is no pool.
+pub(super) fn checkout<'a, C, F>(
+    pool: Option<&'a ConnectionPool>,
+    key: &str,
+    connect: F,
+) -> Result<PooledConnection<'a, C>, anyhow::Error>
+where
+    C: Send + 'static,
+    F: FnOnce() -> Result<C, anyhow::Error>,
+{
+    match pool {
+        Some(pool) => pool.acquire(key, connect),
+        None => connect().map(PooledConnection::unpooled),
+    }
+}
@@ -0,0 +1,281 @@
+//! Snowflake SQL REST API client for `SnowflakeSearchTool`: authentication,
+//! statement execution with polling, partition fetching and typed rows.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::Engine;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::SnowflakeError;
+
+const CLIENT_USER_AGENT: &str = concat!("crewai-tools/", env!("CARGO_PKG_VERSION"));
+
+/// How a session proves who it is.
+pub(super) enum Credentials {
+    /// Key-pair authentication with a PEM-encoded RSA private key.
+    KeyPair { private_key_pem: String },
+    /// A programmatic access token.
+    Pat(String),
+    /// Username and password, exchanged for a session token at login.
+    Password(String),
+}
+
+/// An authenticated connection to one Snowflake account.
+pub(super) struct Session {
+    client: reqwest::blocking::Client,
+    base_url: String,
+    authorization: String,
+    token_type: Option<&'static str>,
+}
+
+impl Session {
+    pub(super) fn connect(
+        base_url: &str,
+        account: &str,
+        user: &str,
+        credentials: &Credentials,
+        context: &[(&str, &Option<String>)],
+    ) -> Result<Self, anyhow::Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()?;
+        let base_url = base_url.trim_end_matches('/').to_string();
+        let (authorization, token_type) = match credentials {
+            Credentials::KeyPair { private_key_pem } => (
+                format!("Bearer {}", key_pair_jwt(account, user, private_key_pem)?),
+                Some("KEYPAIR_JWT"),
+            ),
+            Credentials::Pat(token) => {
+                (format!("Bearer {}", token), Some("PROGRAMMATIC_ACCESS_TOKEN"))
+            }
+            Credentials::Password(password) => {
+                let token = login(&client, &base_url, account, user, password, context)?;
+                (format!("Snowflake Token=\"{}\"", token), None)
+            }
+        };
+        Ok(Self {
+            client,
+            base_url,
+            authorization,
+            token_type,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Authorization", &self.authorization)
+            .header("Accept", "application/json")
+            .header("User-Agent", CLIENT_USER_AGENT);
+        if let Some(token_type) = self.token_type {
+            request = request.header("X-Snowflake-Authorization-Token-Type", token_type);
+        }
+        request
+    }
+
+    /// Run `body` (a SQL API statement request), wait for it to finish and
+    /// return the column names and typed rows of every partition.
+    pub(super) fn execute(
+        &self,
+        body: &Value,
+        timeout: Duration,
+    ) -> Result<(Vec<String>, Vec<Vec<Value>>), anyhow::Error> {
+        let deadline = Instant::now() + timeout;
+        let mut payload =
+            send(self.request(reqwest::Method::POST, "/api/v2/statements").json(body))?;
+        let mut delay = Duration::from_millis(250);
+        while payload.status == 202 {
+            let handle = statement_handle(&payload.body)?;
+            if Instant::now() + delay > deadline {
+                return Err(SnowflakeError::Timeout { statement_handle: handle }.into());
+            }
+            std::thread::sleep(delay);
+            delay = (delay * 2).min(Duration::from_secs(2));
+            payload = send(
+                self.request(reqwest::Method::GET, &format!("/api/v2/statements/{}", handle)),
+            )?;
+        }
+
+        let meta = &payload.body["resultSetMetaData"];
+        let row_type = meta["rowType"].as_array().cloned().unwrap_or_default();
+        let columns = row_type
+            .iter()
+            .map(|c| c["name"].as_str().unwrap_or_default().to_string())
+            .collect();
+        let mut rows = typed_rows(&payload.body["data"], &row_type);
+
+        let partitions = meta["partitionInfo"].as_array().map_or(0, Vec::len);
+        if partitions > 1 {
+            let handle = statement_handle(&payload.body)?;
+            for partition in 1..partitions {
+                let page = send(
+                    self.request(reqwest::Method::GET, &format!("/api/v2/statements/{}", handle))
+                        .query(&[("partition", partition)]),
+                )?;
+                rows.extend(typed_rows(&page.body["data"], &row_type));
+            }
+        }
+        Ok((columns, rows))
+    }
+}
+
+struct Payload {
+    status: u16,
+    body: Value,
+}
+
+/// Send a SQL API request and map error responses to [`SnowflakeError`].
+fn send(request: reqwest::blocking::RequestBuilder) -> Result<Payload, anyhow::Error> {
+    let resp = request
+        .send()
+        .map_err(|e| SnowflakeError::Connection(e.to_string()))?;
+    let status = resp.status().as_u16();
+    let body: Value = resp.json().unwrap_or(Value::Null);
+    let message = body["message"].as_str().unwrap_or("no details").to_string();
+    match status {
+        200 | 202 => Ok(Payload { status, body }),
+        401 | 403 => Err(SnowflakeError::Auth { status, message }.into()),
+        422 => Err(SnowflakeError::Statement {
+            code: body["code"].as_str().unwrap_or_default().to_string(),
+            sql_state: body["sqlState"].as_str().unwrap_or_default().to_string(),
+            message,
+        }
+        .into()),
+        _ => Err(SnowflakeError::Api { status, message }.into()),
+    }
+}
+
+fn statement_handle(body: &Value) -> Result<String, anyhow::Error> {
+    body["statementHandle"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("Snowflake response is missing statementHandle"))
+}
+
+/// Exchange a username and password for a session token.
+fn login(
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    account: &str,
+    user: &str,
+    password: &str,
+    context: &[(&str, &Option<String>)],
+) -> Result<String, anyhow::Error> {
+    let params: Vec<(&str, &str)> = context
+        .iter()
+        .filter_map(|(name, value)| value.as_deref().map(|v| (*name, v)))
+        .collect();
+    let body = serde_json::json!({
+        "data": {
+            "ACCOUNT_NAME": account_locator(account),
+            "LOGIN_NAME": user,
+            "PASSWORD": password,
+            "CLIENT_APP_ID": "crewai-tools",
+            "CLIENT_APP_VERSION": env!("CARGO_PKG_VERSION"),
+        }
+    });
+    let resp = client
+        .post(format!("{}/session/v1/login-request", base_url))
+        .query(&params)
+        .header("Accept", "application/json")
+        .json(&body)
+        .send()
+        .map_err(|e| SnowflakeError::Connection(e.to_string()))?;
+    let status = resp.status().as_u16();
+    let payload: Value = resp.json().unwrap_or(Value::Null);
+    match payload.pointer("/data/token").and_then(|t| t.as_str()) {
+        Some(token) if payload["success"].as_bool() == Some(true) => Ok(token.to_string()),
+        _ => Err(SnowflakeError::Auth {
+            status,
+            message: payload["message"].as_str().unwrap_or("login failed").to_string(),
+        }
+        .into()),
+    }
+}
+
+/// The account part of an identifier, upper-cased, without any region or
+/// cloud suffix (`xy12345.us-east-1` → `XY12345`).
+fn account_locator(account: &str) -> String {
+    account.split('.').next().unwrap_or(account).to_ascii_uppercase()
+}
+
+/// A key-pair JWT valid for one hour, signed RS256 with `private_key_pem`
+/// (PKCS#8 or PKCS#1).
+fn key_pair_jwt(account: &str, user: &str, private_key_pem: &str) -> Result<String, anyhow::Error> {
+    use rsa::pkcs1::DecodeRsaPrivateKey;
+    use rsa::pkcs8::{DecodePrivateKey, EncodePublicKey};
+    use rsa::signature::{SignatureEncoding, Signer};
+
+    let key = rsa::RsaPrivateKey::from_pkcs8_pem(private_key_pem)
+        .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(private_key_pem))
+        .map_err(|_| anyhow::anyhow!("Snowflake private key is not a valid RSA PEM key"))?;
+    let public_der = key.to_public_key().to_public_key_der()?;
+    let fingerprint = STANDARD.encode(Sha256::digest(public_der.as_bytes()));
+
+    let qualified_user = format!("{}.{}", account_locator(account), user.to_ascii_uppercase());
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let header = serde_json::json!({"alg": "RS256", "typ": "JWT"});
+    let claims = serde_json::json!({
+        "iss": format!("{}.SHA256:{}", qualified_user, fingerprint),
+        "sub": qualified_user,
+        "iat": now,
+        "exp": now + 3600,
+    });
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(header.to_string()),
+        URL_SAFE_NO_PAD.encode(claims.to_string())
+    );
+    let signer = rsa::pkcs1v15::SigningKey::<Sha256>::new(key);
+    let signature = signer.sign(signing_input.as_bytes()).to_bytes();
+    Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// Convert the SQL API's all-string `data` array using each column's type.
+fn typed_rows(data: &Value, row_type: &[Value]) -> Vec<Vec<Value>> {
+    data.as_array()
+        .map(|rows| {
+            rows.iter()
+                .filter_map(|row| row.as_array())
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(i, cell)| typed_value(cell, row_type.get(i)))
+                        .collect()
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn typed_value(cell: &Value, column: Option<&Value>) -> Value {
+    let Some(text) = cell.as_str() else {
+        return cell.clone();
+    };
+    let (kind, scale) = match column {
+        Some(c) => (
+            c["type"].as_str().unwrap_or_default().to_ascii_lowercase(),
+            c["scale"].as_i64().unwrap_or(0),
+        ),
+        None => return cell.clone(),
+    };
+    let converted = match kind.as_str() {
+        "fixed" if scale == 0 => text.parse::<i64>().ok().map(Value::from),
+        "fixed" | "real" => text
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        "boolean" => match text {
+            "true" | "1" => Some(Value::Bool(true)),
+            "false" | "0" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        "variant" | "object" | "array" => serde_json::from_str(text).ok(),
+        _ => None,
+    };
+    converted.unwrap_or_else(|| cell.clone())
+}
@@ -0,0 +1,389 @@
+//! SQL plumbing for `Nl2SqlTool`: read-only validation, schema
+//! introspection and row fetching for Postgres and SQLite.
+
+use serde_json::Value;
+
+use super::pool::{self, ConnectionPool, PooledConnection};
+
+/// Keywords that can change data, schema or session state. Any of them
+/// outside a string literal or comment gets a query refused.
+const FORBIDDEN_KEYWORDS: &[&str] = &[
+    "ALTER", "ATTACH", "CALL", "COMMENT", "COPY", "CREATE", "DELETE", "DETACH", "DO", "DROP",
+    "EXEC", "EXECUTE", "GRANT", "INSERT", "INTO", "LOCK", "MERGE", "PRAGMA", "REINDEX", "RESET",
+    "REVOKE", "SET", "TRUNCATE", "UPDATE", "UPSERT", "VACUUM",
+];
+
+/// A supported database dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Dialect {
+    Postgres,
+    Sqlite,
+}
+
+impl Dialect {
+    pub(super) fn parse(name: &str) -> Result<Self, anyhow::Error> {
+        match name.to_ascii_lowercase().as_str() {
+            "postgresql" | "postgres" | "pg" => Ok(Self::Postgres),
+            "sqlite" | "sqlite3" => Ok(Self::Sqlite),
+            other => anyhow::bail!(
+                "Unsupported dialect '{}': expected 'postgresql' or 'sqlite'",
+                other
+            ),
+        }
+    }
+
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Self::Postgres => "PostgreSQL",
+            Self::Sqlite => "SQLite",
+        }
+    }
+}
+
+/// Check that `sql` is exactly one `SELECT` (or `WITH … SELECT`) statement
+/// and return it without a trailing semicolon.
+pub(super) fn read_only_select(sql: &str) -> Result<String, anyhow::Error> {
+    let code = strip_literals(sql);
+    let trimmed_code = code.trim_end().trim_end_matches(';');
+    if trimmed_code.contains(';') {
+        anyhow::bail!("Refusing to run SQL: only a single statement is allowed");
+    }
+    let words: Vec<String> = trimmed_code
+        .split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_ascii_uppercase())
+        .collect();
+    match words.first().map(String::as_str) {
+        Some("SELECT") | Some("WITH") => {}
+        _ => anyhow::bail!("Refusing to run SQL: only SELECT queries are allowed"),
+    }
+    if let Some(word) = words.iter().find(|w| FORBIDDEN_KEYWORDS.contains(&w.as_str())) {
+        anyhow::bail!("Refusing to run SQL: '{}' is not allowed in a read-only query", word);
+    }
+    // `code` and `sql` have the same byte offsets, so this keeps literals intact.
+    Ok(sql[..trimmed_code.len()].trim().to_string())
+}
+
+/// `sql` with the contents of string literals and quoted identifiers, and
+/// whole comments, blanked out (byte for byte, so offsets still line up).
+fn strip_literals(sql: &str) -> String {
+    let bytes = sql.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    let blank = |out: &mut Vec<u8>, from: usize, to: usize| {
+        for b in &mut out[from..to.min(bytes.len())] {
+            if *b != b'\n' {
+                *b = b' ';
+            }
+        }
+    };
+    while i < bytes.len() {
+        let end = match bytes[i] {
+            b'\'' | b'"' | b'`' => {
+                let quote = bytes[i];
+                let mut j = i + 1;
+                while j < bytes.len() {
+                    if bytes[j] == quote {
+                        // A doubled quote is an escaped quote.
+                        if bytes.get(j + 1) == Some(&quote) {
+                            j += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    j += 1;
+                }
+                // Keep the quotes so a literal ending the query isn't trimmed away.
+                blank(&mut out, i + 1, j);
+                i = j + 1;
+                continue;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                sql[i..].find('\n').map_or(bytes.len(), |n| i + n)
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                sql[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 2)
+            }
+            b'$' => match dollar_tag(&sql[i..]) {
+                Some(tag) => sql[i + tag.len()..]
+                    .find(tag)
+                    .map_or(bytes.len(), |n| i + tag.len() + n + tag.len()),
+                None => {
+                    i += 1;
+                    continue;
+                }
+            },
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        blank(&mut out, i, end);
+        i = end;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// The opening tag of a Postgres dollar-quoted string (`$$` or `$tag$`).
+fn dollar_tag(s: &str) -> Option<&str> {
+    let close = s[1..].find('$')? + 2;
+    let tag = &s[..close];
+    tag[1..close - 1]
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        .then_some(tag)
+        .filter(|t| !t[1..t.len() - 1].starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Pull the SQL out of a model reply, dropping Markdown code fences.
+pub(super) fn extract_sql(reply: &str) -> String {
+    let reply = reply.trim();
+    match reply.find("```") {
+        Some(start) => {
+            let body = &reply[start + 3..];
+            let body = body.strip_prefix("sql").unwrap_or(body);
+            body.split("```").next().unwrap_or(body).trim().to_string()
+        }
+        None => reply.to_string(),
+    }
+}
+
+/// `(table, column, type)` triples for `tables` (or every user table when
+/// empty), in table then column order.
+pub(super) type Schema = Vec<(String, String, String)>;
+
+/// Render a schema as `CREATE TABLE`-like text for the prompt.
+fn describe_schema(schema: &Schema) -> String {
+    let mut out = String::new();
+    let mut current: Option<&str> = None;
+    for (table, column, ty) in schema {
+        if current != Some(table.as_str()) {
+            if current.is_some() {
+                out.push_str("\n);\n");
+            }
+            out.push_str(&format!("TABLE {} (\n", table));
+            current = Some(table);
+        } else {
+            out.push_str(",\n");
+        }
+        out.push_str(&format!("  {} {}", column, ty));
+    }
+    if current.is_some() {
+        out.push_str("\n);\n");
+    }
+    out
+}
+
+/// The prompt asking an LLM to answer `question` with one `SELECT`.
+pub(super) fn prompt(dialect: &str, schema: &Schema, question: &str) -> String {
+    format!(
+        "You write {dialect} queries. Given this schema:\n\n{schema}\n\
+         Write a single read-only SELECT statement answering the question below. \
+         Reply with the SQL only.\n\nQuestion: {question}",
+        dialect = dialect,
+        schema = describe_schema(schema),
+        question = question,
+    )
+}
+
+/// Rows of a query result, at most `max_rows`, plus whether more existed.
+pub(super) struct Rows {
+    pub columns: Vec<String>,
+    pub rows: Vec<Value>,
+    pub truncated: bool,
+}
+
+// ── Postgres ─────────────────────────────────────────────────────────────────
+
+fn postgres_connect(conn_str: &str) -> Result<postgres::Client, anyhow::Error> {
+    postgres::Client::connect(conn_str, postgres::NoTls)
+        .map_err(|e| anyhow::anyhow!("Failed to connect to Postgres: {}", e))
+}
+
+/// A Postgres client from `pool` (or a fresh one without a pool). Pooled
+/// clients whose connection has dropped are replaced.
+pub(super) fn postgres_client<'a>(
+    pool: Option<&'a ConnectionPool>,
+    conn_str: &str,
+) -> Result<PooledConnection<'a, postgres::Client>, anyhow::Error> {
+    let key = format!("postgres:{}", conn_str);
+    let client = pool::checkout(pool, &key, || postgres_connect(conn_str))?;
+    if !client.is_closed() {
+        return Ok(client);
+    }
+    client.discard();
+    pool::checkout(pool, &key, || postgres_connect(conn_str))
+}
+
+/// Quote a possibly schema-qualified identifier (`public.docs` →
+/// `"public"."docs"`).
+pub(super) fn quote_ident(name: &str) -> Result<String, anyhow::Error> {
+    if name.is_empty() || name.split('.').any(str::is_empty) {
+        anyhow::bail!("Invalid SQL identifier '{}'", name);
+    }
+    Ok(name
+        .split('.')
+        .map(|part| format!("\"{}\"", part.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join("."))
+}
+
+pub(super) fn postgres_schema(
+    client: &mut postgres::Client,
+    tables: &[String],
+) -> Result<Schema, anyhow::Error> {
+    let rows = client.query(
+        "SELECT table_name::text, column_name::text, data_type::text \
+         FROM information_schema.columns \
+         WHERE table_schema NOT IN ('pg_catalog', 'information_schema') \
+           AND (cardinality($1::text[]) = 0 OR table_name = ANY($1)) \
+         ORDER BY table_schema, table_name, ordinal_position",
+        &[&tables],
+    )?;
+    Ok(rows
+        .iter()
+        .map(|row| (row.get(0), row.get(1), row.get(2)))
+        .collect())
+}
+
+/// Run `sql` in a read-only transaction. Rows come back through
+/// `row_to_json`, so every Postgres type maps to its natural JSON form.
+pub(super) fn postgres_rows(
+    client: &mut postgres::Client,
+    sql: &str,
+    max_rows: usize,
+) -> Result<Rows, anyhow::Error> {
+    let mut tx = client.build_transaction().read_only(true).start()?;
+    let columns = tx
+        .prepare(sql)?
+        .columns()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect();
+    let wrapped = format!(
+        "SELECT row_to_json(q)::text FROM ({}) AS q LIMIT {}",
+        sql,
+        max_rows + 1
+    );
+    let mut rows = Vec::new();
+    for row in tx.query(wrapped.as_str(), &[])? {
+        let json: String = row.get(0);
+        rows.push(serde_json::from_str::<Value>(&json)?);
+    }
+    tx.rollback()?;
+    let truncated = rows.len() > max_rows;
+    rows.truncate(max_rows);
+    Ok(Rows {
+        columns,
+        rows,
+        truncated,
+    })
+}
+
+// ── SQLite ───────────────────────────────────────────────────────────────────
+
+pub(super) fn sqlite_schema(
+    conn: &rusqlite::Connection,
+    tables: &[String],
+) -> Result<Schema, anyhow::Error> {
+    let names: Vec<String> = if tables.is_empty() {
+        let mut stmt = conn.prepare(
+            "SELECT name FROM sqlite_master \
+             WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )?;
+        let names = stmt.query_map([], |row| row.get(0))?;
+        names.collect::<Result<_, _>>()?
+    } else {
+        tables.to_vec()
+    };
+    let mut schema = Vec::new();
+    for table in names {
+        let mut stmt = conn.prepare("SELECT name, type FROM pragma_table_info(?1)")?;
+        let columns = stmt.query_map([&table], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        for column in columns {
+            let (name, ty) = column?;
+            schema.push((table.clone(), name, ty));
+        }
+    }
+    Ok(schema)
+}
+
+/// A SQLite connection from `pool` (or a fresh one without a pool), opened
+/// as [`sqlite_open`] does. Read-only and writable connections to the same
+/// file are pooled separately.
+pub(super) fn sqlite_connection<'a>(
+    pool: Option<&'a ConnectionPool>,
+    path: &str,
+    writable: bool,
+) -> Result<PooledConnection<'a, rusqlite::Connection>, anyhow::Error> {
+    let mode = if writable { "rw" } else { "ro" };
+    pool::checkout(pool, &format!("sqlite-{}:{}", mode, path), || sqlite_open(path, writable))
+}
+
+/// Open a SQLite database file (a `sqlite://` prefix is allowed), read-only
+/// unless `writable`.
+pub(super) fn sqlite_open(
+    path: &str,
+    writable: bool,
+) -> Result<rusqlite::Connection, anyhow::Error> {
+    use rusqlite::OpenFlags;
+
+    let path = path
+        .strip_prefix("sqlite://")
+        .or_else(|| path.strip_prefix("sqlite:"))
+        .unwrap_or(path);
+    let mode = if writable {
+        OpenFlags::SQLITE_OPEN_READ_WRITE
+    } else {
+        OpenFlags::SQLITE_OPEN_READ_ONLY
+    };
+    rusqlite::Connection::open_with_flags(path, mode | OpenFlags::SQLITE_OPEN_URI)
+        .map_err(|e| anyhow::anyhow!("Failed to open SQLite database {}: {}", path, e))
+}
+
+/// Run a read-only statement with `params` and collect up to `max_rows`
+/// rows as JSON objects.
+pub(super) fn sqlite_rows(
+    conn: &rusqlite::Connection,
+    sql: &str,
+    params: &[&dyn rusqlite::ToSql],
+    max_rows: usize,
+) -> Result<Rows, anyhow::Error> {
+    use rusqlite::types::ValueRef;
+
+    let mut stmt = conn.prepare(sql)?;
+    if !stmt.readonly() {
+        anyhow::bail!("Refusing to run SQL: the statement is not read-only");
+    }
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+    let mut rows = Vec::new();
+    let mut truncated = false;
+    let mut cursor = stmt.query(params)?;
+    while let Some(row) = cursor.next()? {
+        if rows.len() == max_rows {
+            truncated = true;
+            break;
+        }
+        let mut object = serde_json::Map::new();
+        for (i, name) in columns.iter().enumerate() {
+            let value = match row.get_ref(i)? {
+                ValueRef::Null => Value::Null,
+                ValueRef::Integer(n) => Value::from(n),
+                ValueRef::Real(x) => Value::from(x),
+                ValueRef::Text(t) => Value::from(String::from_utf8_lossy(t).into_owned()),
+                ValueRef::Blob(b) => {
+                    Value::from(b.iter().map(|byte| format!("{:02x}", byte)).collect::<String>())
+                }
+            };
+            object.insert(name.clone(), value);
+        }
+        rows.push(Value::Object(object));
+    }
+    Ok(Rows {
+        columns,
+        rows,
+        truncated,
+    })
+}
@@ -0,0 +1,389 @@
+//! Archive formats for `FileCompressorTool` and `FileDecompressorTool`: zip
+//! and (optionally compressed) tar.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+/// A supported archive format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Format {
+    Zip,
+    Tar,
+    TarGz,
+    TarBz2,
+}
+
+impl Format {
+    pub(super) fn parse(name: &str) -> Result<Self, anyhow::Error> {
+        match name.trim_start_matches('.').to_ascii_lowercase().as_str() {
+            "zip" => Ok(Self::Zip),
+            "tar" => Ok(Self::Tar),
+            "tar.gz" | "tgz" => Ok(Self::TarGz),
+            "tar.bz2" | "tbz2" => Ok(Self::TarBz2),
+            other => anyhow::bail!(
+                "Unsupported archive format '{}': expected zip, tar, tar.gz or tar.bz2",
+                other
+            ),
+        }
+    }
+
+    /// Infer the format from an archive's file name.
+    pub(super) fn detect(path: &Path) -> Result<Self, anyhow::Error> {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        match [".tar.gz", ".tgz", ".tar.bz2", ".tbz2", ".zip", ".tar"]
+            .iter()
+            .find(|ext| name.ends_with(*ext))
+        {
+            Some(ext) => Self::parse(ext),
+            None => anyhow::bail!(
+                "Cannot tell the archive format of '{}'; pass format explicitly",
+                path.display()
+            ),
+        }
+    }
+
+    /// `name` without this format's extension (or a shorthand like `.tgz`).
+    pub(super) fn strip_extension<'a>(&self, name: &'a str) -> &'a str {
+        let lower = name.to_ascii_lowercase();
+        let extensions: &[&str] = match self {
+            Self::Zip => &[".zip"],
+            Self::Tar => &[".tar"],
+            Self::TarGz => &[".tar.gz", ".tgz"],
+            Self::TarBz2 => &[".tar.bz2", ".tbz2"],
+        };
+        extensions
+            .iter()
+            .find(|ext| lower.ends_with(*ext) && lower.len() > ext.len())
+            .map_or(name, |ext| &name[..name.len() - ext.len()])
+    }
+
+    pub(super) fn extension(&self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::Tar => "tar",
+            Self::TarGz => "tar.gz",
+            Self::TarBz2 => "tar.bz2",
+        }
+    }
+}
+
+/// A file or directory to store, by its path inside the archive.
+pub(super) struct Entry {
+    pub source: PathBuf,
+    pub name: String,
+    pub is_dir: bool,
+}
+
+/// What `collect` found under an input path.
+pub(super) struct Collected {
+    pub entries: Vec<Entry>,
+    /// Total size of the files.
+    pub size: u64,
+    /// Paths left out, with the reason.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// Walk `input` (a file or directory) in sorted order. Names are relative
+/// to a directory input and are the file name for a file input. Symlinks
+/// to files are stored as their target's content; symlinks to directories
+/// and broken symlinks are skipped so cycles cannot recurse forever.
+/// `exclude` (the archive being written) is never collected.
+pub(super) fn collect(input: &Path, exclude: &Path) -> Result<Collected, anyhow::Error> {
+    let mut collected = Collected {
+        entries: Vec::new(),
+        size: 0,
+        skipped: Vec::new(),
+    };
+    let meta = std::fs::metadata(input)
+        .map_err(|e| anyhow::anyhow!("Cannot read '{}': {}", input.display(), e))?;
+    if meta.is_file() {
+        let name = input
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+        collected.size = meta.len();
+        collected.entries.push(Entry {
+            source: input.to_path_buf(),
+            name,
+            is_dir: false,
+        });
+        return Ok(collected);
+    }
+    walk(input, "", exclude, &mut collected)?;
+    Ok(collected)
+}
+
+fn walk(
+    dir: &Path,
+    prefix: &str,
+    exclude: &Path,
+    collected: &mut Collected,
+) -> Result<(), anyhow::Error> {
+    let mut children: Vec<_> = std::fs::read_dir(dir)?.collect::<Result<_, _>>()?;
+    children.sort_by_key(|entry| entry.file_name());
+    for child in children {
+        let path = child.path();
+        if path == exclude {
+            continue;
+        }
+        let name = format!("{}{}", prefix, child.file_name().to_string_lossy());
+        let link = child.file_type()?.is_symlink();
+        let meta = match std::fs::metadata(&path) {
+            Ok(meta) => meta,
+            Err(e) => {
+                log::warn!("Skipping {}: {}", path.display(), e);
+                collected.skipped.push((path, format!("unreadable: {}", e)));
+                continue;
+            }
+        };
+        if meta.is_dir() {
+            if link {
+                log::warn!("Skipping symlinked directory {}", path.display());
+                collected.skipped.push((path, "symlink to a directory".to_string()));
+                continue;
+            }
+            collected.entries.push(Entry {
+                source: path.clone(),
+                name: format!("{}/", name),
+                is_dir: true,
+            });
+            walk(&path, &format!("{}/", name), exclude, collected)?;
+        } else if meta.is_file() {
+            collected.size += meta.len();
+            collected.entries.push(Entry {
+                source: path,
+                name,
+                is_dir: false,
+            });
+        } else {
+            collected.skipped.push((path, "not a regular file".to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Write `entries` to a new archive at `output`.
+pub(super) fn write(
+    format: Format,
+    output: &Path,
+    entries: &[Entry],
+) -> Result<(), anyhow::Error> {
+    let file = File::create(output)
+        .map_err(|e| anyhow::anyhow!("Cannot create '{}': {}", output.display(), e))?;
+    match format {
+        Format::Zip => {
+            let mut zip = zip::ZipWriter::new(file);
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated)
+                .large_file(true);
+            for entry in entries {
+                if entry.is_dir {
+                    zip.add_directory(entry.name.as_str(), options)?;
+                } else {
+                    zip.start_file(entry.name.as_str(), options)?;
+                    std::io::copy(&mut File::open(&entry.source)?, &mut zip)?;
+                }
+            }
+            zip.finish()?;
+        }
+        Format::Tar => write_tar(file, entries)?.flush()?,
+        Format::TarGz => {
+            let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            write_tar(encoder, entries)?.finish()?;
+        }
+        Format::TarBz2 => {
+            let encoder = bzip2::write::BzEncoder::new(file, bzip2::Compression::default());
+            write_tar(encoder, entries)?.finish()?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a tar stream into `sink` and hand the sink back for finishing.
+fn write_tar<W: Write>(sink: W, entries: &[Entry]) -> Result<W, anyhow::Error> {
+    let mut tar = tar::Builder::new(sink);
+    for entry in entries {
+        let name = entry.name.trim_end_matches('/');
+        if entry.is_dir {
+            tar.append_dir(name, &entry.source)?;
+        } else {
+            // Opening the path reads through a symlink to its target.
+            tar.append_file(name, &mut File::open(&entry.source)?)?;
+        }
+    }
+    Ok(tar.into_inner()?)
+}
+
+/// What `extract` wrote under the target directory.
+pub(super) struct Extracted {
+    /// Extracted file paths, relative to the target directory.
+    pub files: Vec<String>,
+    /// Total bytes written.
+    pub size: u64,
+    /// Entries left out (links and special files), with the reason.
+    pub skipped: Vec<(String, String)>,
+}
+
+/// Unpack `archive` into `target`, writing at most `max_size` bytes.
+///
+/// Entries whose path would land outside `target` (absolute paths or `..`
+/// components) fail the whole extraction, as do encrypted zip entries and
+/// archives that expand past `max_size`. On failure, every file written so
+/// far is removed again. Symlinks and hard links are skipped, never created.
+pub(super) fn extract(
+    format: Format,
+    archive: &Path,
+    target: &Path,
+    max_size: u64,
+    overwrite: bool,
+) -> Result<Extracted, anyhow::Error> {
+    let file = File::open(archive)
+        .map_err(|e| anyhow::anyhow!("Cannot open '{}': {}", archive.display(), e))?;
+    let mut sink = Sink {
+        target,
+        max_size,
+        overwrite,
+        written: Vec::new(),
+        extracted: Extracted {
+            files: Vec::new(),
+            size: 0,
+            skipped: Vec::new(),
+        },
+    };
+    let result = match format {
+        Format::Zip => extract_zip(file, &mut sink),
+        Format::Tar => extract_tar(file, &mut sink),
+        Format::TarGz => extract_tar(flate2::read::GzDecoder::new(file), &mut sink),
+        Format::TarBz2 => extract_tar(bzip2::read::BzDecoder::new(file), &mut sink),
+    };
+    match result {
+        Ok(()) => Ok(sink.extracted),
+        Err(e) => {
+            for path in sink.written.iter().rev() {
+                let _ = std::fs::remove_file(path);
+            }
+            Err(e)
+        }
+    }
+}
+
+fn extract_zip(file: File, sink: &mut Sink) -> Result<(), anyhow::Error> {
+    let mut zip = zip::ZipArchive::new(file)?;
+    for i in 0..zip.len() {
+        if zip.by_index_raw(i)?.encrypted() {
+            anyhow::bail!(
+                "Archive is password-protected ('{}' is encrypted); \
+                 password-protected zips are not supported",
+                zip.by_index_raw(i)?.name()
+            );
+        }
+        let mut entry = zip.by_index(i)?;
+        let name = entry.name().to_string();
+        let path = entry.enclosed_name().ok_or_else(|| escape_error(&name))?;
+        // Zip has no symlink flag of its own; Unix tools record it in the mode.
+        if entry.unix_mode().is_some_and(|mode| mode & 0o170000 == 0o120000) {
+            sink.skip(&name, "symlink");
+        } else if entry.is_dir() {
+            sink.dir(&path)?;
+        } else {
+            sink.file(&path, &mut entry)?;
+        }
+    }
+    Ok(())
+}
+
+fn extract_tar<R: Read>(reader: R, sink: &mut Sink) -> Result<(), anyhow::Error> {
+    let mut tar = tar::Archive::new(reader);
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let path = enclosed(&entry.path()?).ok_or_else(|| escape_error(&name))?;
+        match entry.header().entry_type() {
+            tar::EntryType::Regular | tar::EntryType::Continuous => sink.file(&path, &mut entry)?,
+            tar::EntryType::Directory => sink.dir(&path)?,
+            tar::EntryType::Symlink => sink.skip(&name, "symlink"),
+            tar::EntryType::Link => sink.skip(&name, "hard link"),
+            // Long-name and PAX headers are consumed by the tar crate.
+            _ => sink.skip(&name, "not a regular file"),
+        }
+    }
+    Ok(())
+}
+
+/// `path` as a relative path with only normal components, or `None` if it
+/// is absolute or climbs out with `..`.
+fn enclosed(path: &Path) -> Option<PathBuf> {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => out.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(out)
+}
+
+fn escape_error(name: &str) -> anyhow::Error {
+    anyhow::anyhow!("Refusing to extract '{}': its path escapes the target directory", name)
+}
+
+/// Writes entries under the target directory and keeps the running tally.
+struct Sink<'a> {
+    target: &'a Path,
+    max_size: u64,
+    overwrite: bool,
+    /// Files created so far, removed again if extraction fails.
+    written: Vec<PathBuf>,
+    extracted: Extracted,
+}
+
+impl Sink<'_> {
+    fn skip(&mut self, name: &str, reason: &str) {
+        log::warn!("Skipping archive entry {}: {}", name, reason);
+        self.extracted.skipped.push((name.to_string(), reason.to_string()));
+    }
+
+    fn dir(&mut self, path: &Path) -> Result<(), anyhow::Error> {
+        std::fs::create_dir_all(self.target.join(path))?;
+        Ok(())
+    }
+
+    fn file(&mut self, path: &Path, reader: &mut dyn Read) -> Result<(), anyhow::Error> {
+        if path.as_os_str().is_empty() {
+            return Ok(());
+        }
+        let dest = self.target.join(path);
+        if let Ok(meta) = std::fs::symlink_metadata(&dest) {
+            if !self.overwrite {
+                anyhow::bail!("'{}' already exists; pass overwrite to replace it", dest.display());
+            }
+            // Replace a link rather than writing through it to wherever it
+            // points.
+            if meta.file_type().is_symlink() {
+                std::fs::remove_file(&dest)?;
+            }
+        }
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut out = File::create(&dest)
+            .map_err(|e| anyhow::anyhow!("Cannot create '{}': {}", dest.display(), e))?;
+        self.written.push(dest);
+
+        // Copy one byte past the budget so an oversized entry is caught
+        // without trusting the size the archive declares.
+        let budget = self.max_size - self.extracted.size;
+        let copied = std::io::copy(&mut reader.take(budget.saturating_add(1)), &mut out)?;
+        if copied > budget {
+            anyhow::bail!("Archive expands beyond the {} byte extraction limit", self.max_size);
+        }
+        self.extracted.size += copied;
+        self.extracted.files.push(path.to_string_lossy().replace('\\', "/"));
+        Ok(())
+    }
+}
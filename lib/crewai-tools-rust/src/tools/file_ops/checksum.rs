@@ -0,0 +1,92 @@
+//! Streaming file hashes for `FileChecksumTool`.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use sha2::Digest;
+
+/// A supported hash algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Algorithm {
+    Sha256,
+    Md5,
+    Blake3,
+}
+
+impl Algorithm {
+    pub(super) fn parse(name: &str) -> Result<Self, anyhow::Error> {
+        match name.to_ascii_lowercase().replace('-', "").as_str() {
+            "sha256" => Ok(Self::Sha256),
+            "md5" => Ok(Self::Md5),
+            "blake3" => Ok(Self::Blake3),
+            _ => anyhow::bail!(
+                "Unsupported algorithm '{}': expected sha256, md5 or blake3",
+                name
+            ),
+        }
+    }
+
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Md5 => "md5",
+            Self::Blake3 => "blake3",
+        }
+    }
+}
+
+enum Hasher {
+    Sha256(sha2::Sha256),
+    Md5(md5::Md5),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    fn new(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            Algorithm::Md5 => Self::Md5(md5::Md5::new()),
+            Algorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Sha256(h) => h.update(bytes),
+            Self::Md5(h) => h.update(bytes),
+            Self::Blake3(h) => {
+                h.update(bytes);
+            }
+        }
+    }
+
+    fn finish(self) -> String {
+        match self {
+            Self::Sha256(h) => format!("{:x}", h.finalize()),
+            Self::Md5(h) => format!("{:x}", h.finalize()),
+            Self::Blake3(h) => h.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+/// Hash `path` in fixed-size chunks, returning the lowercase hex digest
+/// and the number of bytes read.
+pub(super) fn hash_file(algorithm: Algorithm, path: &Path) -> Result<(String, u64), anyhow::Error> {
+    let mut file = File::open(path)
+        .map_err(|e| anyhow::anyhow!("Failed to open '{}': {}", path.display(), e))?;
+    let mut hasher = Hasher::new(algorithm);
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut total = 0u64;
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        total += read as u64;
+    }
+    Ok((hasher.finish(), total))
+}
@@ -5,23 +5,58 @@
 //! in `crewai_tools`.
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+mod archive;
+mod checksum;
 
 // ── FileReadTool ─────────────────────────────────────────────────────────────
 
 /// Read the contents of a file from the local filesystem.
 ///
+/// The file is streamed line by line, so a line range or tail of a large
+/// log is read without loading the rest. Content is capped at `max_bytes`.
+/// Passing any of `start_line`, `end_line`, `tail_lines` or `max_bytes`, or
+/// hitting the cap, switches the result from the bare content string to an
+/// object carrying `content`, `total_lines`, `truncated` and `bytes` (the
+/// file's size).
+///
+/// Non-UTF-8 files are an error by default. The `lossy` encoding replaces
+/// invalid sequences instead, and the `base64` encoding returns the whole
+/// file as `{content, encoding, mime_type, bytes}` for binary consumers
+/// such as images and PDFs; files over `max_base64_bytes` are refused.
+///
 /// Corresponds to Python `FileReadTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileReadTool {
     /// Path to the file to read (can also be provided at runtime).
     pub file_path: Option<String>,
+    /// Most bytes of content returned by one read.
+    #[serde(default = "default_max_read_bytes")]
+    pub max_bytes: usize,
+    /// Largest file the `base64` encoding will read.
+    #[serde(default = "default_max_base64_bytes")]
+    pub max_base64_bytes: u64,
+}
+
+fn default_max_read_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_max_base64_bytes() -> u64 {
+    10 * 1024 * 1024
 }
 
 impl FileReadTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            max_bytes: default_max_read_bytes(),
+            max_base64_bytes: default_max_base64_bytes(),
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -29,21 +64,176 @@ impl FileReadTool {
         self
     }
 
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    pub fn with_max_base64_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_base64_bytes = max_bytes;
+        self
+    }
+
     /// Read the contents of a file.
     ///
     /// # Arguments (in `args`)
     /// * `file_path` - Path to the file to read.
+    /// * `start_line` - First line to return (1-based).
+    /// * `end_line` - Last line to return (inclusive).
+    /// * `tail_lines` - Return only the last N lines; cannot be combined
+    ///   with `start_line` / `end_line`.
+    /// * `max_bytes` - Override the configured content cap for this call.
+    /// * `encoding` - "utf-8" (default), "lossy" or "base64". Line options
+    ///   do not apply to `base64`.
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use std::io::BufRead;
+
         let path = args
             .get("file_path")
             .and_then(|v| v.as_str())
             .or(self.file_path.as_deref())
             .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+        let number = |name: &str| args.get(name).and_then(|v| v.as_u64()).map(|n| n as usize);
+        let start_line = number("start_line");
+        let end_line = number("end_line");
+        let tail_lines = number("tail_lines");
+        let max_bytes = number("max_bytes").unwrap_or(self.max_bytes);
+        let options = start_line.is_some()
+            || end_line.is_some()
+            || tail_lines.is_some()
+            || args.contains_key("max_bytes");
+        let lossy = match args.get("encoding").and_then(|v| v.as_str()) {
+            None => false,
+            Some(e) if e.eq_ignore_ascii_case("utf-8") || e.eq_ignore_ascii_case("utf8") => false,
+            Some(e) if e.eq_ignore_ascii_case("lossy") => true,
+            Some(e) if e.eq_ignore_ascii_case("base64") => {
+                if options {
+                    anyhow::bail!("Line and size options cannot be combined with base64 encoding");
+                }
+                return self.read_base64(path);
+            }
+            Some(other) => anyhow::bail!(
+                "Unsupported encoding '{}': expected utf-8, lossy or base64",
+                other
+            ),
+        };
 
-        let content = std::fs::read_to_string(path)
+        if start_line == Some(0) {
+            anyhow::bail!("start_line is 1-based");
+        }
+        let start_line = start_line.unwrap_or(1);
+        if end_line.is_some_and(|end| end < start_line) {
+            anyhow::bail!("end_line must not be before start_line");
+        }
+        if tail_lines.is_some() && (start_line > 1 || end_line.is_some()) {
+            anyhow::bail!("tail_lines cannot be combined with start_line or end_line");
+        }
+
+        let file = std::fs::File::open(path)
             .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?;
+        let bytes = file.metadata()?.len();
+        let mut reader = std::io::BufReader::new(file);
+
+        let mut total_lines = 0;
+        let mut kept: std::collections::VecDeque<String> = std::collections::VecDeque::new();
+        let mut kept_bytes = 0;
+        let mut truncated = false;
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            if reader
+                .read_until(b'\n', &mut line)
+                .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?
+                == 0
+            {
+                break;
+            }
+            total_lines += 1;
+            let before_end = match end_line {
+                Some(end) => total_lines <= end,
+                None => true,
+            };
+            let wanted = tail_lines.is_some() || (total_lines >= start_line && before_end);
+            // Past the cap, later lines are only counted.
+            if !wanted || (tail_lines.is_none() && kept_bytes >= max_bytes) {
+                truncated |= wanted;
+                continue;
+            }
+            let text = match String::from_utf8(std::mem::take(&mut line)) {
+                Ok(text) => text,
+                Err(e) if lossy => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+                Err(_) => anyhow::bail!(
+                    "Failed to read file '{}': not valid UTF-8 (use encoding \"lossy\" or \"base64\")",
+                    path
+                ),
+            };
+            kept_bytes += text.len();
+            kept.push_back(text);
+            if let Some(n) = tail_lines {
+                if kept.len() > n {
+                    kept_bytes -= kept.pop_front().map_or(0, |first| first.len());
+                }
+                // Drop leading lines that are no longer needed to fill
+                // `max_bytes`.
+                while kept.front().is_some_and(|first| kept_bytes - first.len() >= max_bytes) {
+                    kept_bytes -= kept.pop_front().map_or(0, |first| first.len());
+                    truncated = true;
+                }
+            }
+        }
+
+        let mut content: String = kept.into_iter().collect();
+        if content.len() > max_bytes {
+            truncated = true;
+            content = if tail_lines.is_some() {
+                let mut cut = content.len() - max_bytes;
+                while !content.is_char_boundary(cut) {
+                    cut += 1;
+                }
+                content[cut..].to_string()
+            } else {
+                let mut cut = max_bytes;
+                while !content.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                content.truncate(cut);
+                content
+            };
+        }
 
-        Ok(Value::String(content))
+        if !options && !truncated {
+            return Ok(Value::String(content));
+        }
+        Ok(serde_json::json!({
+            "content": content,
+            "total_lines": total_lines,
+            "truncated": truncated,
+            "bytes": bytes,
+        }))
+    }
+
+    fn read_base64(&self, path: &str) -> Result<Value, anyhow::Error> {
+        use base64::Engine;
+
+        let size = std::fs::metadata(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?
+            .len();
+        if size > self.max_base64_bytes {
+            anyhow::bail!(
+                "File '{}' is {} bytes, over the {} byte limit for base64 reads",
+                path,
+                size,
+                self.max_base64_bytes
+            );
+        }
+        let data = std::fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?;
+        Ok(serde_json::json!({
+            "content": base64::engine::general_purpose::STANDARD.encode(&data),
+            "encoding": "base64",
+            "mime_type": crate::tools::mime::detect(Path::new(path), &data),
+            "bytes": data.len(),
+        }))
     }
 }
 
@@ -57,6 +247,19 @@ impl Default for FileReadTool {
 
 /// Write content to a file on the local filesystem.
 ///
+/// With `verify` set, the file is read back after writing and its SHA-256
+/// compared with that of the intended content; a mismatch is an error. With
+/// `checksum` set, the SHA-256 is reported without re-reading. Either option
+/// switches the result from a message string to an object carrying `path`,
+/// `bytes` and `sha256`.
+///
+/// New files and overwrites are written to a temporary file in the same
+/// directory and renamed into place, so a crash never leaves half-written
+/// output; `append` adds to the end of the file instead. With a sandbox
+/// root set, relative paths resolve against it and any path that escapes
+/// it once `..` and symlinks are resolved is rejected before anything is
+/// created.
+///
 /// Corresponds to Python `FileWriterTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileWriterTool {
@@ -66,6 +269,18 @@ pub struct FileWriterTool {
     pub filename: Option<String>,
     /// Whether to overwrite existing files.
     pub overwrite: bool,
+    /// Re-read the file after writing and compare checksums.
+    #[serde(default)]
+    pub verify: bool,
+    /// Include the SHA-256 of the written content in the result.
+    #[serde(default)]
+    pub checksum: bool,
+    /// Append to the file instead of replacing it.
+    #[serde(default)]
+    pub append: bool,
+    /// Directory that every write must stay inside.
+    #[serde(default)]
+    pub sandbox_root: Option<String>,
 }
 
 impl FileWriterTool {
@@ -74,6 +289,10 @@ impl FileWriterTool {
             directory: None,
             filename: None,
             overwrite: false,
+            verify: false,
+            checksum: false,
+            append: false,
+            sandbox_root: None,
         }
     }
 
@@ -92,121 +311,435 @@ impl FileWriterTool {
         self
     }
 
+    pub fn with_verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    pub fn with_append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Reject writes that resolve to anywhere outside `root`.
+    pub fn with_sandbox_root(mut self, root: impl Into<String>) -> Self {
+        self.sandbox_root = Some(root.into());
+        self
+    }
+
     /// Write content to a file.
     ///
     /// # Arguments (in `args`)
     /// * `content` - The content to write.
     /// * `filename` - The filename (optional if set on struct).
     /// * `directory` - The directory (optional if set on struct).
+    /// * `verify` - Override the struct's `verify` setting for this call.
+    /// * `append` - Override the struct's `append` setting for this call.
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         let content = args
             .get("content")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing required argument: content"))?;
 
-        let filename = args
-            .get("filename")
-            .and_then(|v| v.as_str())
-            .map(String::from)
-            .or_else(|| self.filename.clone())
-            .ok_or_else(|| anyhow::anyhow!("Missing required argument: filename"))?;
-
-        let directory = args
-            .get("directory")
-            .and_then(|v| v.as_str())
-            .map(String::from)
-            .or_else(|| self.directory.clone())
-            .unwrap_or_else(|| ".".to_string());
+        let file_path = resolve_output_path(
+            &args,
+            self.filename.as_deref(),
+            self.directory.as_deref(),
+            self.sandbox_root.as_deref(),
+        )?;
+        let verify = args.get("verify").and_then(|v| v.as_bool()).unwrap_or(self.verify);
+        let mode = if args.get("append").and_then(|v| v.as_bool()).unwrap_or(self.append) {
+            WriteMode::Append
+        } else if self.overwrite {
+            WriteMode::Overwrite
+        } else {
+            WriteMode::CreateNew
+        };
 
-        let dir_path = std::path::Path::new(&directory);
-        if !dir_path.exists() {
-            std::fs::create_dir_all(dir_path)
-                .map_err(|e| anyhow::anyhow!("Failed to create directory '{}': {}", directory, e))?;
+        let report = write_output(&file_path, content.as_bytes(), mode, verify)?;
+        if verify || self.checksum {
+            Ok(report.to_value())
+        } else {
+            Ok(Value::String(report.message()))
         }
+    }
+}
 
-        let file_path = dir_path.join(&filename);
+impl Default for FileWriterTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        if file_path.exists() && !self.overwrite {
-            anyhow::bail!(
-                "File '{}' already exists and overwrite is disabled",
-                file_path.display()
-            );
+// ── StructuredFileWriterTool ─────────────────────────────────────────────────
+
+/// Write structured data (JSON or JSON Lines) to a file.
+///
+/// Supports the same `verify`, `checksum` and sandbox root options as
+/// `FileWriterTool`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredFileWriterTool {
+    /// Directory where files will be written.
+    pub directory: Option<String>,
+    /// Default filename to use if not provided at runtime.
+    pub filename: Option<String>,
+    /// Whether to overwrite existing files.
+    pub overwrite: bool,
+    /// Pretty-print JSON output.
+    pub pretty: bool,
+    /// Re-read the file after writing and compare checksums.
+    #[serde(default)]
+    pub verify: bool,
+    /// Include the SHA-256 of the written content in the result.
+    #[serde(default)]
+    pub checksum: bool,
+    /// Directory that every write must stay inside.
+    #[serde(default)]
+    pub sandbox_root: Option<String>,
+}
+
+impl StructuredFileWriterTool {
+    pub fn new() -> Self {
+        Self {
+            directory: None,
+            filename: None,
+            overwrite: false,
+            pretty: true,
+            verify: false,
+            checksum: false,
+            sandbox_root: None,
         }
+    }
+
+    pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
+        self.directory = Some(dir.into());
+        self
+    }
+
+    pub fn with_filename(mut self, name: impl Into<String>) -> Self {
+        self.filename = Some(name.into());
+        self
+    }
+
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
 
-        std::fs::write(&file_path, content)
-            .map_err(|e| anyhow::anyhow!("Failed to write file '{}': {}", file_path.display(), e))?;
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    pub fn with_verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Reject writes that resolve to anywhere outside `root`.
+    pub fn with_sandbox_root(mut self, root: impl Into<String>) -> Self {
+        self.sandbox_root = Some(root.into());
+        self
+    }
+
+    /// Serialize `data` and write it to a file.
+    ///
+    /// # Arguments (in `args`)
+    /// * `data` - The JSON value to write.
+    /// * `format` - `"json"` (default) or `"jsonl"`; `jsonl` requires an array.
+    /// * `filename` - The filename (optional if set on struct).
+    /// * `directory` - The directory (optional if set on struct).
+    /// * `verify` - Override the struct's `verify` setting for this call.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let data = args
+            .get("data")
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: data"))?;
+        let format = args.get("format").and_then(|v| v.as_str()).unwrap_or("json");
+
+        let content = match format {
+            "json" if self.pretty => serde_json::to_string_pretty(data)? + "\n",
+            "json" => serde_json::to_string(data)? + "\n",
+            "jsonl" => {
+                let items = data
+                    .as_array()
+                    .ok_or_else(|| anyhow::anyhow!("format 'jsonl' requires `data` to be an array"))?;
+                let mut out = String::new();
+                for item in items {
+                    out.push_str(&serde_json::to_string(item)?);
+                    out.push('\n');
+                }
+                out
+            }
+            other => anyhow::bail!("Unsupported format '{}': expected 'json' or 'jsonl'", other),
+        };
 
-        Ok(Value::String(format!(
-            "Successfully wrote to {}",
-            file_path.display()
-        )))
+        let file_path = resolve_output_path(
+            &args,
+            self.filename.as_deref(),
+            self.directory.as_deref(),
+            self.sandbox_root.as_deref(),
+        )?;
+        let verify = args.get("verify").and_then(|v| v.as_bool()).unwrap_or(self.verify);
+        let mode = if self.overwrite { WriteMode::Overwrite } else { WriteMode::CreateNew };
+
+        let report = write_output(&file_path, content.as_bytes(), mode, verify)?;
+        if verify || self.checksum {
+            Ok(report.to_value())
+        } else {
+            Ok(Value::String(report.message()))
+        }
     }
 }
 
-impl Default for FileWriterTool {
+impl Default for StructuredFileWriterTool {
     fn default() -> Self {
         Self::new()
     }
 }
 
-// ── FileCompressorTool ───────────────────────────────────────────────────────
+// ── CsvWriterTool ────────────────────────────────────────────────────────────
 
-/// Compress files or directories into archive formats (zip, tar.gz, etc.).
+/// Write an array of objects as CSV.
 ///
-/// Corresponds to Python `FileCompressorTool` in `crewai_tools`.
+/// The header is the given `headers`, or else every key in the order it
+/// first appears across the rows; when headers are given, a row with any
+/// other key is an error. Missing keys become empty cells, nested arrays and
+/// objects are written as JSON, and quoting is left to the CSV writer.
+/// Appending to a non-empty file reuses that file's header and rejects rows
+/// that do not fit it. Directory creation, overwrite and the sandbox root
+/// behave as in `FileWriterTool`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileCompressorTool {
-    /// Compression format: "zip", "tar.gz", "tar.bz2".
-    pub format: String,
-    /// Output path for the compressed file.
-    pub output_path: Option<String>,
+pub struct CsvWriterTool {
+    /// Directory where files will be written.
+    pub directory: Option<String>,
+    /// Default filename to use if not provided at runtime.
+    pub filename: Option<String>,
+    /// Whether to overwrite existing files.
+    pub overwrite: bool,
+    /// Append rows to an existing file instead of replacing it.
+    #[serde(default)]
+    pub append: bool,
+    /// Field delimiter.
+    #[serde(default = "default_csv_delimiter")]
+    pub delimiter: char,
+    /// Directory that every write must stay inside.
+    #[serde(default)]
+    pub sandbox_root: Option<String>,
 }
 
-impl FileCompressorTool {
+fn default_csv_delimiter() -> char {
+    ','
+}
+
+impl CsvWriterTool {
     pub fn new() -> Self {
         Self {
-            format: "zip".to_string(),
-            output_path: None,
+            directory: None,
+            filename: None,
+            overwrite: false,
+            append: false,
+            delimiter: default_csv_delimiter(),
+            sandbox_root: None,
         }
     }
 
-    pub fn with_format(mut self, format: impl Into<String>) -> Self {
-        self.format = format.into();
+    pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
+        self.directory = Some(dir.into());
         self
     }
 
-    pub fn with_output_path(mut self, path: impl Into<String>) -> Self {
-        self.output_path = Some(path.into());
+    pub fn with_filename(mut self, name: impl Into<String>) -> Self {
+        self.filename = Some(name.into());
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "FileCompressorTool: not yet implemented - requires archive/compression integration"
-        )
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    pub fn with_append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Reject writes that resolve to anywhere outside `root`.
+    pub fn with_sandbox_root(mut self, root: impl Into<String>) -> Self {
+        self.sandbox_root = Some(root.into());
+        self
+    }
+
+    /// Write `rows` to a CSV file.
+    ///
+    /// Returns `{message, path, rows, headers, bytes, appended}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `rows` - Array of objects, one per row.
+    /// * `headers` - Column order; keys outside it are rejected.
+    /// * `filename` - The filename (optional if set on struct).
+    /// * `directory` - The directory (optional if set on struct).
+    /// * `append` - Override the struct's `append` setting for this call.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let rows = args
+            .get("rows")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: rows (an array of objects)"))?;
+        let rows: Vec<&serde_json::Map<String, Value>> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.as_object()
+                    .ok_or_else(|| anyhow::anyhow!("Row {} is not an object", i))
+            })
+            .collect::<Result<_, _>>()?;
+        if !self.delimiter.is_ascii() {
+            anyhow::bail!("CSV delimiter must be an ASCII character");
+        }
+
+        let file_path = resolve_output_path(
+            &args,
+            self.filename.as_deref(),
+            self.directory.as_deref(),
+            self.sandbox_root.as_deref(),
+        )?;
+        let append = args.get("append").and_then(|v| v.as_bool()).unwrap_or(self.append);
+        let existing = if append { self.existing_headers(&file_path)? } else { None };
+
+        let given: Option<Vec<String>> = args.get("headers").and_then(|v| v.as_array()).map(|h| {
+            h.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        });
+        let headers = match (&existing, given) {
+            (Some(existing), Some(given)) if *existing != given => anyhow::bail!(
+                "headers {:?} do not match the existing file's header {:?}",
+                given,
+                existing
+            ),
+            (Some(existing), _) => existing.clone(),
+            (None, Some(given)) => given,
+            (None, None) => {
+                let mut derived: Vec<String> = Vec::new();
+                for key in rows.iter().flat_map(|row| row.keys()) {
+                    if !derived.contains(key) {
+                        derived.push(key.clone());
+                    }
+                }
+                derived
+            }
+        };
+        for (i, row) in rows.iter().enumerate() {
+            if let Some(key) = row.keys().find(|key| !headers.contains(key)) {
+                anyhow::bail!("Row {} has column '{}', which is not in the header {:?}", i, key, headers);
+            }
+        }
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter as u8)
+            .from_writer(Vec::new());
+        if existing.is_none() {
+            writer.write_record(&headers)?;
+        }
+        for row in &rows {
+            writer.write_record(headers.iter().map(|h| csv_cell(row.get(h))))?;
+        }
+        let bytes = writer.into_inner().map_err(|e| anyhow::anyhow!("Failed to encode CSV: {}", e))?;
+
+        let mode = if append {
+            WriteMode::Append
+        } else if self.overwrite {
+            WriteMode::Overwrite
+        } else {
+            WriteMode::CreateNew
+        };
+        let report = write_output(&file_path, &bytes, mode, false)?;
+        Ok(serde_json::json!({
+            "message": report.message(),
+            "path": file_path.display().to_string(),
+            "rows": rows.len(),
+            "headers": headers,
+            "bytes": report.bytes,
+            "appended": report.appended,
+        }))
+    }
+
+    /// The header of an existing, non-empty CSV file.
+    fn existing_headers(&self, path: &Path) -> Result<Option<Vec<String>>, anyhow::Error> {
+        if !std::fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(None);
+        }
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter as u8)
+            .from_path(path)?;
+        Ok(Some(reader.headers()?.iter().map(str::to_string).collect()))
     }
 }
 
-impl Default for FileCompressorTool {
+impl Default for CsvWriterTool {
     fn default() -> Self {
         Self::new()
     }
 }
 
-// ── DirectoryReadTool ────────────────────────────────────────────────────────
+/// A JSON value as CSV cell text.
+fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
 
-/// List and read the contents of a directory on the local filesystem.
+// ── JsonWriterTool ───────────────────────────────────────────────────────────
+
+/// Write a JSON document, or merge into an existing one.
 ///
-/// Corresponds to Python `DirectoryReadTool` in `crewai_tools`.
+/// Mode `write` replaces the file (subject to `overwrite`). Mode `merge`
+/// deep-merges `data` into the existing object: nested objects merge key by
+/// key and everything else is replaced. Mode `append` adds `data` to the
+/// existing array, one element per item when `data` is itself an array. A
+/// missing file is simply written in every mode. Directory creation,
+/// overwrite and the sandbox root behave as in `FileWriterTool`; rewrites
+/// are atomic.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DirectoryReadTool {
-    /// Path to the directory to read.
+pub struct JsonWriterTool {
+    /// Directory where files will be written.
     pub directory: Option<String>,
+    /// Default filename to use if not provided at runtime.
+    pub filename: Option<String>,
+    /// Whether to overwrite existing files in `write` mode.
+    pub overwrite: bool,
+    /// Pretty-print the output.
+    pub pretty: bool,
+    /// Directory that every write must stay inside.
+    #[serde(default)]
+    pub sandbox_root: Option<String>,
 }
 
-impl DirectoryReadTool {
+impl JsonWriterTool {
     pub fn new() -> Self {
-        Self { directory: None }
+        Self {
+            directory: None,
+            filename: None,
+            overwrite: false,
+            pretty: true,
+            sandbox_root: None,
+        }
     }
 
     pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
@@ -214,40 +747,1573 @@ impl DirectoryReadTool {
         self
     }
 
-    /// List the contents of a directory.
+    pub fn with_filename(mut self, name: impl Into<String>) -> Self {
+        self.filename = Some(name.into());
+        self
+    }
+
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Reject writes that resolve to anywhere outside `root`.
+    pub fn with_sandbox_root(mut self, root: impl Into<String>) -> Self {
+        self.sandbox_root = Some(root.into());
+        self
+    }
+
+    /// Write or merge `data` into a JSON file.
+    ///
+    /// Returns `{message, path, mode, bytes}`.
     ///
     /// # Arguments (in `args`)
-    /// * `directory` - Path to the directory to list.
+    /// * `data` - The JSON value to write.
+    /// * `mode` - `"write"` (default), `"merge"` or `"append"`.
+    /// * `pretty` - Override the struct's `pretty` setting for this call.
+    /// * `filename` - The filename (optional if set on struct).
+    /// * `directory` - The directory (optional if set on struct).
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        let dir = args
-            .get("directory")
-            .and_then(|v| v.as_str())
-            .or(self.directory.as_deref())
-            .ok_or_else(|| anyhow::anyhow!("Missing required argument: directory"))?;
+        let data = args
+            .get("data")
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: data"))?;
+        let mode = args.get("mode").and_then(|v| v.as_str()).unwrap_or("write");
+        let pretty = args.get("pretty").and_then(|v| v.as_bool()).unwrap_or(self.pretty);
+        let file_path = resolve_output_path(
+            &args,
+            self.filename.as_deref(),
+            self.directory.as_deref(),
+            self.sandbox_root.as_deref(),
+        )?;
 
-        let entries: Vec<String> = std::fs::read_dir(dir)
-            .map_err(|e| anyhow::anyhow!("Failed to read directory '{}': {}", dir, e))?
-            .filter_map(|entry| {
-                entry.ok().map(|e| {
-                    let path = e.path();
-                    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
-                    if path.is_dir() {
-                        format!("{}/", name)
-                    } else {
-                        name
-                    }
-                })
-            })
-            .collect();
+        let existing = match mode {
+            "write" => None,
+            "merge" | "append" if file_path.exists() => {
+                let text = std::fs::read_to_string(&file_path)
+                    .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", file_path.display(), e))?;
+                let value: Value = serde_json::from_str(&text).map_err(|e| {
+                    anyhow::anyhow!("'{}' is not valid JSON: {}", file_path.display(), e)
+                })?;
+                Some(value)
+            }
+            "merge" | "append" => None,
+            other => anyhow::bail!("Unknown mode '{}': expected 'write', 'merge' or 'append'", other),
+        };
+        let (document, write_mode) = match existing {
+            None if mode == "write" && !self.overwrite => (data.clone(), WriteMode::CreateNew),
+            None => (data.clone(), WriteMode::Overwrite),
+            Some(mut document) if mode == "merge" => {
+                if !document.is_object() || !data.is_object() {
+                    anyhow::bail!("merge mode needs both the file and data to be JSON objects");
+                }
+                deep_merge(&mut document, data);
+                (document, WriteMode::Overwrite)
+            }
+            Some(mut document) => {
+                let items = document
+                    .as_array_mut()
+                    .ok_or_else(|| anyhow::anyhow!("append mode needs the file to hold a JSON array"))?;
+                match data {
+                    Value::Array(more) => items.extend(more.iter().cloned()),
+                    other => items.push(other.clone()),
+                }
+                (document, WriteMode::Overwrite)
+            }
+        };
 
+        let content = if pretty {
+            serde_json::to_string_pretty(&document)? + "\n"
+        } else {
+            serde_json::to_string(&document)? + "\n"
+        };
+        let report = write_output(&file_path, content.as_bytes(), write_mode, false)?;
         Ok(serde_json::json!({
-            "directory": dir,
-            "entries": entries,
-            "count": entries.len(),
+            "message": report.message(),
+            "path": file_path.display().to_string(),
+            "mode": mode,
+            "bytes": report.bytes,
         }))
     }
 }
 
+impl Default for JsonWriterTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Merge `patch` into `target`: objects merge recursively, anything else
+/// replaces what was there.
+fn deep_merge(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                deep_merge(target.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => *target = patch.clone(),
+    }
+}
+
+// ── Write helpers ────────────────────────────────────────────────────────────
+
+/// Outcome of a write: where it went, how much, and its SHA-256.
+#[derive(Debug, Clone)]
+struct WriteReport {
+    path: PathBuf,
+    bytes: usize,
+    sha256: String,
+    verified: bool,
+    appended: bool,
+}
+
+impl WriteReport {
+    fn message(&self) -> String {
+        let verb = if self.appended { "appended" } else { "wrote" };
+        format!("Successfully {} to {}", verb, self.path.display())
+    }
+
+    fn to_value(&self) -> Value {
+        serde_json::json!({
+            "message": self.message(),
+            "path": self.path.display().to_string(),
+            "bytes": self.bytes,
+            "sha256": self.sha256,
+            "verified": self.verified,
+            "appended": self.appended,
+        })
+    }
+}
+
+/// How `write_output` treats an existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WriteMode {
+    /// Fail if the file exists.
+    CreateNew,
+    /// Replace the file atomically.
+    Overwrite,
+    /// Add to the end of the file, creating it if needed.
+    Append,
+}
+
+/// Hex-encoded SHA-256 of `bytes`.
+fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Join the `filename`/`directory` args (or their struct defaults) into a
+/// path, creating its directory if needed. With a sandbox root, relative
+/// paths resolve against the root and the path is checked by
+/// [`sandboxed`] before anything is created.
+fn resolve_output_path(
+    args: &HashMap<String, Value>,
+    default_filename: Option<&str>,
+    default_directory: Option<&str>,
+    sandbox_root: Option<&str>,
+) -> Result<PathBuf, anyhow::Error> {
+    let filename = args
+        .get("filename")
+        .and_then(|v| v.as_str())
+        .or(default_filename)
+        .ok_or_else(|| anyhow::anyhow!("Missing required argument: filename"))?;
+
+    let directory = args
+        .get("directory")
+        .and_then(|v| v.as_str())
+        .or(default_directory)
+        .unwrap_or(".");
+
+    let path = Path::new(directory).join(filename);
+    let path = match sandbox_root {
+        Some(root) => sandboxed(Path::new(root), &path)?,
+        None => path,
+    };
+    if let Some(dir_path) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        if !dir_path.exists() {
+            std::fs::create_dir_all(dir_path).map_err(|e| {
+                anyhow::anyhow!("Failed to create directory '{}': {}", dir_path.display(), e)
+            })?;
+        }
+    }
+    Ok(path)
+}
+
+/// Resolve `path` (relative to `root` unless absolute) to a real path and
+/// fail unless it lies inside `root`.
+///
+/// The deepest existing ancestor is canonicalized, so `..` and symlinks
+/// there are resolved before the check; the not-yet-existing remainder may
+/// only hold plain names.
+fn sandboxed(root: &Path, path: &Path) -> Result<PathBuf, anyhow::Error> {
+    use std::path::Component;
+
+    let root = root
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("Sandbox root '{}' is not accessible: {}", root.display(), e))?;
+    let escape = || {
+        anyhow::anyhow!(
+            "Path '{}' escapes the sandbox root '{}'",
+            path.display(),
+            root.display()
+        )
+    };
+
+    let mut existing = root.join(path);
+    let mut missing = Vec::new();
+    while std::fs::symlink_metadata(&existing).is_err() {
+        match existing.components().next_back() {
+            Some(Component::Normal(name)) => missing.push(name.to_os_string()),
+            _ => return Err(escape()),
+        }
+        existing.pop();
+    }
+    // A dangling symlink fails here rather than being written through.
+    let mut resolved = existing.canonicalize().map_err(|_| escape())?;
+    resolved.extend(missing.iter().rev());
+    if !resolved.starts_with(&root) {
+        return Err(escape());
+    }
+    Ok(resolved)
+}
+
+/// Write `bytes` to `path` according to `mode`, optionally re-reading to
+/// verify the checksum of what was written.
+fn write_output(path: &Path, bytes: &[u8], mode: WriteMode, verify: bool) -> Result<WriteReport, anyhow::Error> {
+    let write_error = |e: std::io::Error| anyhow::anyhow!("Failed to write file '{}': {}", path.display(), e);
+    let offset = match mode {
+        WriteMode::Append => {
+            use std::io::Write;
+
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(write_error)?;
+            let offset = file.metadata().map_err(write_error)?.len();
+            file.write_all(bytes).map_err(write_error)?;
+            offset
+        }
+        WriteMode::CreateNew | WriteMode::Overwrite => {
+            write_atomic(path, bytes, mode == WriteMode::Overwrite).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::AlreadyExists {
+                    anyhow::anyhow!(
+                        "File '{}' already exists and overwrite is disabled",
+                        path.display()
+                    )
+                } else {
+                    write_error(e)
+                }
+            })?;
+            0
+        }
+    };
+
+    let expected = sha256_hex(bytes);
+    if verify {
+        verify_checksum(path, &expected, offset)?;
+    }
+
+    Ok(WriteReport {
+        path: path.to_path_buf(),
+        bytes: bytes.len(),
+        sha256: expected,
+        verified: verify,
+        appended: mode == WriteMode::Append,
+    })
+}
+
+/// Write `bytes` to a temporary file beside `path` and move it into place,
+/// keeping the existing file's permissions. A symlinked `path` is resolved
+/// first so the link itself survives.
+///
+/// With `replace` the temporary file is renamed over `path`. Without it the
+/// file is published with a hard link, which fails with `AlreadyExists`
+/// rather than clobbering a file created since the caller last looked.
+fn write_atomic(path: &Path, bytes: &[u8], replace: bool) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_TEMP: AtomicUsize = AtomicUsize::new(0);
+
+    let target = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let temp = target.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        std::process::id(),
+        NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = (|| {
+        let mut file = std::fs::File::create(&temp)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        if !replace {
+            return std::fs::hard_link(&temp, &target);
+        }
+        if let Ok(meta) = std::fs::metadata(&target) {
+            std::fs::set_permissions(&temp, meta.permissions())?;
+        }
+        std::fs::rename(&temp, &target)
+    })();
+    // After a rename the temporary name is already gone.
+    if result.is_err() || !replace {
+        let _ = std::fs::remove_file(&temp);
+    }
+    result
+}
+
+/// Re-read `path` from byte `offset` on and check the SHA-256 of that part
+/// against `expected`.
+///
+/// Catches another process clobbering the file between write and verify.
+fn verify_checksum(path: &Path, expected: &str, offset: u64) -> Result<(), anyhow::Error> {
+    let on_disk = std::fs::read(path)
+        .map_err(|e| anyhow::anyhow!("Failed to re-read '{}' for verification: {}", path.display(), e))?;
+    let written = on_disk.get(offset as usize..).unwrap_or_default();
+    let actual = sha256_hex(written);
+    if actual != expected {
+        anyhow::bail!(
+            "Verification failed for '{}': expected sha256 {}, found {} ({} bytes on disk)",
+            path.display(),
+            expected,
+            actual,
+            written.len()
+        );
+    }
+    Ok(())
+}
+
+// ── FileCompressorTool ───────────────────────────────────────────────────────
+
+/// Compress files or directories into archive formats (zip, tar.gz, etc.).
+///
+/// Directories are archived recursively with paths relative to the
+/// directory. Symlinks to files are stored as their target's content;
+/// symlinks to directories are skipped (and reported) rather than followed.
+///
+/// Corresponds to Python `FileCompressorTool` in `crewai_tools`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCompressorTool {
+    /// Compression format: "zip", "tar", "tar.gz", "tar.bz2".
+    pub format: String,
+    /// Output path for the compressed file.
+    pub output_path: Option<String>,
+    /// Whether to replace an existing archive at the output path.
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+impl FileCompressorTool {
+    pub fn new() -> Self {
+        Self {
+            format: "zip".to_string(),
+            output_path: None,
+            overwrite: false,
+        }
+    }
+
+    pub fn with_format(mut self, format: impl Into<String>) -> Self {
+        self.format = format.into();
+        self
+    }
+
+    pub fn with_output_path(mut self, path: impl Into<String>) -> Self {
+        self.output_path = Some(path.into());
+        self
+    }
+
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Compress a file or directory.
+    ///
+    /// Returns `{archive_path, format, files, original_size, compressed_size,
+    /// skipped: [{path, reason}]}`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `input_path` - File or directory to compress.
+    /// * `output_path` - Archive to write; defaults to `<input>.<format>`
+    ///   next to the input.
+    /// * `format` - Override the configured format.
+    /// * `overwrite` - Override the configured overwrite setting.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let format = archive::Format::parse(
+            args.get("format")
+                .and_then(|v| v.as_str())
+                .unwrap_or(&self.format),
+        )?;
+        let input = args
+            .get("input_path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: input_path"))?;
+        let overwrite = args
+            .get("overwrite")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(self.overwrite);
+        let input = Path::new(input)
+            .canonicalize()
+            .map_err(|e| anyhow::anyhow!("Input path '{}' not found: {}", input, e))?;
+
+        let output = match args
+            .get("output_path")
+            .and_then(|v| v.as_str())
+            .or(self.output_path.as_deref())
+        {
+            Some(path) => PathBuf::from(path),
+            None => {
+                let name = input
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "archive".to_string());
+                input.with_file_name(format!("{}.{}", name, format.extension()))
+            }
+        };
+        if output.exists() && !overwrite {
+            anyhow::bail!("'{}' already exists; pass overwrite to replace it", output.display());
+        }
+        if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Compare against the canonical form so an archive written inside
+        // the input directory is not added to itself.
+        let exclude = match (output.parent(), output.file_name()) {
+            (Some(parent), Some(name)) => Path::new(parent)
+                .canonicalize()
+                .map(|p| p.join(name))
+                .unwrap_or_else(|_| output.clone()),
+            _ => output.clone(),
+        };
+
+        let collected = archive::collect(&input, &exclude)?;
+        archive::write(format, &output, &collected.entries)?;
+        let compressed_size = std::fs::metadata(&output)?.len();
+
+        let skipped: Vec<Value> = collected
+            .skipped
+            .iter()
+            .map(|(path, reason)| {
+                serde_json::json!({ "path": path.display().to_string(), "reason": reason })
+            })
+            .collect();
+        Ok(serde_json::json!({
+            "archive_path": output.display().to_string(),
+            "format": format.extension(),
+            "files": collected.entries.iter().filter(|e| !e.is_dir).count(),
+            "original_size": collected.size,
+            "compressed_size": compressed_size,
+            "skipped": skipped,
+        }))
+    }
+}
+
+impl Default for FileCompressorTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── FileDecompressorTool ─────────────────────────────────────────────────────
+
+/// Extract zip and tar archives (plain, gzip or bzip2) into a directory.
+///
+/// Entries whose paths would escape the target directory ("zip-slip")
+/// abort the extraction, and so does an archive that expands beyond
+/// `max_total_size` bytes; either way the files written so far are removed.
+/// Symlinks and hard links inside the archive are skipped, never created.
+/// Password-protected zips are rejected with an error saying so.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileDecompressorTool {
+    /// Upper bound on the total bytes written by one extraction.
+    pub max_total_size: u64,
+    /// Whether existing files in the target directory may be replaced.
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+impl FileDecompressorTool {
+    pub fn new() -> Self {
+        Self {
+            max_total_size: 1024 * 1024 * 1024,
+            overwrite: false,
+        }
+    }
+
+    pub fn with_max_total_size(mut self, bytes: u64) -> Self {
+        self.max_total_size = bytes;
+        self
+    }
+
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Extract an archive.
+    ///
+    /// Returns `{output_dir, format, files, file_count, total_size,
+    /// skipped: [{path, reason}]}`, with `files` relative to `output_dir`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `archive_path` - Archive to extract.
+    /// * `output_dir` - Directory to extract into; defaults to the archive
+    ///   path without its extension.
+    /// * `format` - "zip", "tar", "tar.gz" or "tar.bz2"; inferred from the
+    ///   archive's extension when omitted.
+    /// * `overwrite` - Override the configured overwrite setting.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let archive_path = args
+            .get("archive_path")
+            .and_then(|v| v.as_str())
+            .map(PathBuf::from)
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: archive_path"))?;
+        let format = match args.get("format").and_then(|v| v.as_str()) {
+            Some(name) => archive::Format::parse(name)?,
+            None => archive::Format::detect(&archive_path)?,
+        };
+        let overwrite = args
+            .get("overwrite")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(self.overwrite);
+        if !archive_path.is_file() {
+            anyhow::bail!("Archive '{}' not found", archive_path.display());
+        }
+
+        let output_dir = match args.get("output_dir").and_then(|v| v.as_str()) {
+            Some(dir) => PathBuf::from(dir),
+            None => {
+                let name = archive_path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let stem = format.strip_extension(&name);
+                if stem == name {
+                    anyhow::bail!("Missing required argument: output_dir");
+                }
+                archive_path.with_file_name(stem)
+            }
+        };
+        std::fs::create_dir_all(&output_dir)
+            .map_err(|e| anyhow::anyhow!("Cannot create '{}': {}", output_dir.display(), e))?;
+
+        let extracted = archive::extract(
+            format,
+            &archive_path,
+            &output_dir,
+            self.max_total_size,
+            overwrite,
+        )?;
+        let skipped: Vec<Value> = extracted
+            .skipped
+            .iter()
+            .map(|(path, reason)| serde_json::json!({ "path": path, "reason": reason }))
+            .collect();
+        Ok(serde_json::json!({
+            "output_dir": output_dir.display().to_string(),
+            "format": format.extension(),
+            "file_count": extracted.files.len(),
+            "files": extracted.files,
+            "total_size": extracted.size,
+            "skipped": skipped,
+        }))
+    }
+}
+
+impl Default for FileDecompressorTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── FileManageTool ───────────────────────────────────────────────────────────
+
+/// Move, copy and delete files and directories.
+///
+/// `move` renames, falling back to copy-and-delete across filesystems;
+/// `copy` copies a file or a whole directory tree; `delete` removes a file,
+/// an empty directory, or with `recursive` a directory and everything in
+/// it. Existing destinations are only replaced with `overwrite`, and never
+/// when they are directories. With `dry_run` nothing is touched and the
+/// result lists what would be.
+///
+/// A sandbox root confines every path as it does for `FileWriterTool`.
+/// Symlinks are moved and deleted as links, also when a move has to copy
+/// across filesystems; copying one copies what it points to, and symlinks
+/// inside a copied directory are skipped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileManageTool {
+    /// Directory that every path must stay inside.
+    #[serde(default)]
+    pub sandbox_root: Option<String>,
+    /// Whether an existing destination file may be replaced.
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+impl FileManageTool {
+    pub fn new() -> Self {
+        Self {
+            sandbox_root: None,
+            overwrite: false,
+        }
+    }
+
+    /// Reject any source or destination outside `root`.
+    pub fn with_sandbox_root(mut self, root: impl Into<String>) -> Self {
+        self.sandbox_root = Some(root.into());
+        self
+    }
+
+    pub fn with_overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Move, copy or delete a path.
+    ///
+    /// Returns `{operation, dry_run, affected, ...}` where `affected` lists
+    /// every path created or removed, plus `source`/`destination` for move
+    /// and copy, `path` for delete and `skipped` for directory copies.
+    ///
+    /// # Arguments (in `args`)
+    /// * `operation` - `"move"`, `"copy"` or `"delete"`.
+    /// * `source` - Path to move or copy.
+    /// * `destination` - Target path; an existing directory receives the
+    ///   source under its own name.
+    /// * `path` - Path to delete.
+    /// * `recursive` - Allow deleting a non-empty directory.
+    /// * `dry_run` - Report what would happen without changing anything.
+    /// * `overwrite` - Override the configured overwrite setting.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let arg = |name: &str| {
+            args.get(name)
+                .and_then(|v| v.as_str())
+                .map(Path::new)
+                .ok_or_else(|| anyhow::anyhow!("Missing required argument: {}", name))
+        };
+        let flag = |name: &str| args.get(name).and_then(|v| v.as_bool());
+        let operation = args
+            .get("operation")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: operation"))?;
+        let dry_run = flag("dry_run").unwrap_or(false);
+
+        match operation {
+            "delete" => {
+                let path = self.entry_path(arg("path")?)?;
+                self.delete(&path, flag("recursive").unwrap_or(false), dry_run)
+            }
+            "move" | "copy" => {
+                let copy = operation == "copy";
+                let source = arg("source")?;
+                // Copying reads through a symlink, so its target must be
+                // inside the sandbox too; moving only relocates the link.
+                let source = match (&self.sandbox_root, copy) {
+                    (Some(root), true) => sandboxed(Path::new(root), source)?,
+                    _ => self.entry_path(source)?,
+                };
+                let meta = if copy {
+                    std::fs::metadata(&source)
+                } else {
+                    std::fs::symlink_metadata(&source)
+                }
+                .map_err(|e| anyhow::anyhow!("Cannot access '{}': {}", source.display(), e))?;
+
+                let mut destination = self.entry_path(arg("destination")?)?;
+                if destination.is_dir() {
+                    let name = source.file_name().unwrap_or_default();
+                    destination = self.entry_path(&destination.join(name))?;
+                }
+                if meta.is_dir() && destination.starts_with(&source) {
+                    anyhow::bail!("Cannot {} '{}' into itself", operation, source.display());
+                }
+                if let Ok(existing) = std::fs::symlink_metadata(&destination) {
+                    let overwrite = flag("overwrite").unwrap_or(self.overwrite);
+                    if !overwrite || existing.is_dir() {
+                        anyhow::bail!("Destination '{}' already exists", destination.display());
+                    }
+                }
+
+                let (affected, skipped) = if copy {
+                    copy_entry(&source, &destination, meta.is_dir(), dry_run, false)?
+                } else {
+                    if !dry_run {
+                        move_entry(&source, &destination, meta.is_dir())?;
+                    }
+                    (vec![source.clone(), destination.clone()], Vec::new())
+                };
+                Ok(serde_json::json!({
+                    "operation": operation,
+                    "dry_run": dry_run,
+                    "source": source.display().to_string(),
+                    "destination": destination.display().to_string(),
+                    "affected": display_paths(&affected),
+                    "skipped": display_paths(&skipped),
+                }))
+            }
+            other => anyhow::bail!("Unknown operation '{}': expected 'move', 'copy' or 'delete'", other),
+        }
+    }
+
+    fn delete(&self, path: &Path, recursive: bool, dry_run: bool) -> Result<Value, anyhow::Error> {
+        let meta = std::fs::symlink_metadata(path)
+            .map_err(|e| anyhow::anyhow!("Cannot access '{}': {}", path.display(), e))?;
+        let affected = if meta.is_dir() {
+            let mut contents = Vec::new();
+            tree(path, &mut contents)?;
+            if !contents.is_empty() && !recursive {
+                anyhow::bail!(
+                    "Directory '{}' is not empty; pass recursive to delete it with its contents",
+                    path.display()
+                );
+            }
+            contents.push(path.to_path_buf());
+            contents
+        } else {
+            vec![path.to_path_buf()]
+        };
+        if !dry_run {
+            let removed = match (meta.is_dir(), recursive) {
+                (true, true) => std::fs::remove_dir_all(path),
+                (true, false) => std::fs::remove_dir(path),
+                (false, _) => std::fs::remove_file(path),
+            };
+            removed.map_err(|e| anyhow::anyhow!("Failed to delete '{}': {}", path.display(), e))?;
+        }
+        Ok(serde_json::json!({
+            "operation": "delete",
+            "dry_run": dry_run,
+            "path": path.display().to_string(),
+            "affected": display_paths(&affected),
+        }))
+    }
+
+    /// `path` with its directory resolved (and checked against the sandbox)
+    /// but its last component kept as given, so a symlink names the link.
+    fn entry_path(&self, path: &Path) -> Result<PathBuf, anyhow::Error> {
+        let Some(std::path::Component::Normal(name)) = path.components().next_back() else {
+            anyhow::bail!("'{}' does not name a file or directory", path.display());
+        };
+        let parent = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let parent = match &self.sandbox_root {
+            Some(root) => sandboxed(Path::new(root), parent)?,
+            None => parent.canonicalize().unwrap_or_else(|_| parent.to_path_buf()),
+        };
+        Ok(parent.join(name))
+    }
+}
+
+impl Default for FileManageTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn display_paths(paths: &[PathBuf]) -> Vec<String> {
+    paths.iter().map(|p| p.display().to_string()).collect()
+}
+
+/// Append everything under `dir` (not `dir` itself), children before their
+/// parents, without following symlinks.
+fn tree(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), anyhow::Error> {
+    let mut children: Vec<_> = std::fs::read_dir(dir)?.collect::<Result<_, _>>()?;
+    children.sort_by_key(|entry| entry.file_name());
+    for child in children {
+        if child.file_type()?.is_dir() {
+            tree(&child.path(), out)?;
+        }
+        out.push(child.path());
+    }
+    Ok(())
+}
+
+/// Copy a file or directory tree, returning the paths created and the
+/// symlinks skipped. Symlinks inside the tree are recreated with
+/// `keep_links` and skipped otherwise. Nothing is written on a dry run.
+fn copy_entry(
+    source: &Path,
+    destination: &Path,
+    is_dir: bool,
+    dry_run: bool,
+    keep_links: bool,
+) -> Result<(Vec<PathBuf>, Vec<PathBuf>), anyhow::Error> {
+    let copy_error = |e: std::io::Error| {
+        anyhow::anyhow!(
+            "Failed to copy '{}' to '{}': {}",
+            source.display(),
+            destination.display(),
+            e
+        )
+    };
+    if !is_dir {
+        if !dry_run {
+            std::fs::copy(source, destination).map_err(copy_error)?;
+        }
+        return Ok((vec![destination.to_path_buf()], Vec::new()));
+    }
+
+    let mut created = vec![destination.to_path_buf()];
+    let mut skipped = Vec::new();
+    if !dry_run {
+        std::fs::create_dir(destination).map_err(copy_error)?;
+    }
+    let mut children: Vec<_> = std::fs::read_dir(source)?.collect::<Result<_, _>>()?;
+    children.sort_by_key(|entry| entry.file_name());
+    for child in children {
+        let file_type = child.file_type()?;
+        let target = destination.join(child.file_name());
+        if file_type.is_symlink() && keep_links {
+            if !dry_run {
+                copy_symlink(&child.path(), &target).map_err(copy_error)?;
+            }
+            created.push(target);
+        } else if file_type.is_symlink() {
+            skipped.push(child.path());
+        } else {
+            let (more, more_skipped) =
+                copy_entry(&child.path(), &target, file_type.is_dir(), dry_run, keep_links)?;
+            created.extend(more);
+            skipped.extend(more_skipped);
+        }
+    }
+    Ok((created, skipped))
+}
+
+/// Create a symlink at `destination` pointing where the one at `source`
+/// does.
+fn copy_symlink(source: &Path, destination: &Path) -> std::io::Result<()> {
+    let target = std::fs::read_link(source)?;
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, destination)
+    }
+    #[cfg(windows)]
+    {
+        // Windows links are typed; a dangling one becomes a file link.
+        if std::fs::metadata(source).is_ok_and(|m| m.is_dir()) {
+            std::os::windows::fs::symlink_dir(target, destination)
+        } else {
+            std::os::windows::fs::symlink_file(target, destination)
+        }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (target, destination);
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// Rename `source` to `destination`, copying and deleting instead when
+/// they are on different filesystems. The copy recreates symlinks, and
+/// `source` is only removed once it is complete.
+fn move_entry(source: &Path, destination: &Path, is_dir: bool) -> Result<(), anyhow::Error> {
+    let move_error = |e: std::io::Error| {
+        anyhow::anyhow!(
+            "Failed to move '{}' to '{}': {}",
+            source.display(),
+            destination.display(),
+            e
+        )
+    };
+    match std::fs::rename(source, destination) {
+        Ok(()) => Ok(()),
+        Err(e) if crosses_devices(&e) => {
+            let is_link = std::fs::symlink_metadata(source)
+                .map_err(move_error)?
+                .file_type()
+                .is_symlink();
+            if is_link {
+                copy_symlink(source, destination).map_err(move_error)?;
+            } else {
+                copy_entry(source, destination, is_dir, false, true)?;
+            }
+            if is_dir {
+                std::fs::remove_dir_all(source).map_err(move_error)
+            } else {
+                std::fs::remove_file(source).map_err(move_error)
+            }
+        }
+        Err(e) => Err(move_error(e)),
+    }
+}
+
+/// Whether a rename failed only because it crossed filesystems (`EXDEV`,
+/// or `ERROR_NOT_SAME_DEVICE` on Windows).
+fn crosses_devices(e: &std::io::Error) -> bool {
+    let code = if cfg!(windows) { 17 } else { 18 };
+    e.raw_os_error() == Some(code)
+}
+
+// ── FileChecksumTool ─────────────────────────────────────────────────────────
+
+/// Hash files for integrity checks and deduplication.
+///
+/// A file yields one hex digest; a directory yields a manifest mapping each
+/// regular file's path (relative to the directory) to its digest, skipping
+/// symlinks. Passing `expected` verifies instead: a digest for a file, a
+/// manifest for a directory. Files are hashed in chunks, so size does not
+/// matter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChecksumTool {
+    /// Hash algorithm: "sha256", "md5" or "blake3".
+    pub algorithm: String,
+}
+
+impl FileChecksumTool {
+    pub fn new() -> Self {
+        Self {
+            algorithm: "sha256".to_string(),
+        }
+    }
+
+    pub fn with_algorithm(mut self, algorithm: impl Into<String>) -> Self {
+        self.algorithm = algorithm.into();
+        self
+    }
+
+    /// Hash a file or directory, or verify it against `expected`.
+    ///
+    /// For a file returns `{path, algorithm, hash, bytes}`; for a directory
+    /// `{path, algorithm, files, manifest}`. With `expected`, adds `matches`
+    /// and, for a directory, `mismatched: [{path, expected, actual}]`,
+    /// `missing` and `unexpected`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `path` - File or directory to hash.
+    /// * `algorithm` - Override the configured algorithm.
+    /// * `expected` - Hex digest (file) or `{path: digest}` manifest
+    ///   (directory) to verify against.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let path = args
+            .get("path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: path"))?;
+        let algorithm = checksum::Algorithm::parse(
+            args.get("algorithm")
+                .and_then(|v| v.as_str())
+                .unwrap_or(&self.algorithm),
+        )?;
+        let expected = args.get("expected");
+        let root = Path::new(path);
+        let meta = std::fs::metadata(root)
+            .map_err(|e| anyhow::anyhow!("Cannot access '{}': {}", path, e))?;
+
+        if !meta.is_dir() {
+            let (hash, bytes) = checksum::hash_file(algorithm, root)?;
+            let mut result = serde_json::json!({
+                "path": path,
+                "algorithm": algorithm.name(),
+                "hash": hash,
+                "bytes": bytes,
+            });
+            if let Some(expected) = expected {
+                let expected = expected
+                    .as_str()
+                    .ok_or_else(|| anyhow::anyhow!("expected must be a hex digest for a file"))?;
+                result["expected"] = Value::from(expected);
+                result["matches"] = Value::Bool(expected.trim().eq_ignore_ascii_case(&hash));
+            }
+            return Ok(result);
+        }
+
+        let mut paths = Vec::new();
+        tree(root, &mut paths)?;
+        let mut manifest = serde_json::Map::new();
+        for file in paths {
+            if !std::fs::symlink_metadata(&file)?.is_file() {
+                continue;
+            }
+            let relative = file
+                .strip_prefix(root)
+                .unwrap_or(&file)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let (hash, _) = checksum::hash_file(algorithm, &file)?;
+            manifest.insert(relative, Value::String(hash));
+        }
+        let mut result = serde_json::json!({
+            "path": path,
+            "algorithm": algorithm.name(),
+            "files": manifest.len(),
+        });
+        if let Some(expected) = expected {
+            let expected = expected.as_object().ok_or_else(|| {
+                anyhow::anyhow!("expected must be a {{path: hash}} manifest for a directory")
+            })?;
+            let mut mismatched = Vec::new();
+            let mut missing = Vec::new();
+            for (file, want) in expected {
+                let want = want.as_str().unwrap_or_default();
+                match manifest.get(file).and_then(|v| v.as_str()) {
+                    None => missing.push(file.clone()),
+                    Some(actual) if !want.trim().eq_ignore_ascii_case(actual) => {
+                        mismatched.push(serde_json::json!({
+                            "path": file,
+                            "expected": want,
+                            "actual": actual,
+                        }));
+                    }
+                    Some(_) => {}
+                }
+            }
+            let unexpected: Vec<&String> =
+                manifest.keys().filter(|file| !expected.contains_key(*file)).collect();
+            result["matches"] =
+                Value::Bool(mismatched.is_empty() && missing.is_empty() && unexpected.is_empty());
+            result["mismatched"] = Value::from(mismatched);
+            result["missing"] = serde_json::json!(missing);
+            result["unexpected"] = serde_json::json!(unexpected);
+        }
+        result["manifest"] = Value::Object(manifest);
+        Ok(result)
+    }
+}
+
+impl Default for FileChecksumTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── DirectoryReadTool ────────────────────────────────────────────────────────
+
+/// List and read the contents of a directory on the local filesystem.
+///
+/// The listing reports each entry's path relative to the directory, whether
+/// it is a directory, its size and its modification time (Unix seconds),
+/// in sorted order. It can recurse and filter by glob; entries matching the
+/// `ignore` patterns (`.git` and `node_modules` by default) are left out
+/// and not descended into, and at most `max_entries` are returned.
+///
+/// In `summarize` mode the tool walks the whole tree instead and returns
+/// aggregate statistics rather than a listing: file count and size per
+/// extension, the largest and most recently modified files, and directory
+/// counts per depth. The walk skips hidden entries and paths matched by the
+/// root `.gitignore`, and stops early (reporting `partial: true`) when the
+/// file-count or time budget runs out.
+///
+/// Corresponds to Python `DirectoryReadTool` in `crewai_tools`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryReadTool {
+    /// Path to the directory to read.
+    pub directory: Option<String>,
+    /// Deepest level to descend to in `summarize` mode (root = 0).
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    /// Skip hidden entries and `.gitignore` matches in `summarize` mode.
+    #[serde(default = "default_true")]
+    pub respect_ignore: bool,
+    /// How many entries the largest / most recent lists hold.
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+    /// Stop the walk after this many files.
+    #[serde(default = "default_max_files")]
+    pub max_files: usize,
+    /// Stop the walk after this many milliseconds.
+    #[serde(default = "default_time_budget_ms")]
+    pub time_budget_ms: u64,
+    /// Most entries one listing returns.
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    /// Names or globs left out of listings.
+    #[serde(default = "default_list_ignore")]
+    pub ignore: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_top_n() -> usize {
+    10
+}
+
+fn default_max_files() -> usize {
+    200_000
+}
+
+fn default_time_budget_ms() -> u64 {
+    10_000
+}
+
+fn default_max_entries() -> usize {
+    1_000
+}
+
+fn default_list_ignore() -> Vec<String> {
+    vec![".git".to_string(), "node_modules".to_string()]
+}
+
+impl DirectoryReadTool {
+    pub fn new() -> Self {
+        Self {
+            directory: None,
+            max_depth: None,
+            respect_ignore: true,
+            top_n: default_top_n(),
+            max_files: default_max_files(),
+            time_budget_ms: default_time_budget_ms(),
+            max_entries: default_max_entries(),
+            ignore: default_list_ignore(),
+        }
+    }
+
+    pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
+        self.directory = Some(dir.into());
+        self
+    }
+
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    pub fn with_respect_ignore(mut self, respect: bool) -> Self {
+        self.respect_ignore = respect;
+        self
+    }
+
+    pub fn with_top_n(mut self, n: usize) -> Self {
+        self.top_n = n;
+        self
+    }
+
+    pub fn with_max_files(mut self, n: usize) -> Self {
+        self.max_files = n;
+        self
+    }
+
+    pub fn with_time_budget_ms(mut self, ms: u64) -> Self {
+        self.time_budget_ms = ms;
+        self
+    }
+
+    pub fn with_max_entries(mut self, n: usize) -> Self {
+        self.max_entries = n;
+        self
+    }
+
+    /// Replace the default ignore patterns (`.git`, `node_modules`).
+    pub fn with_ignore(mut self, patterns: Vec<String>) -> Self {
+        self.ignore = patterns;
+        self
+    }
+
+    /// List the contents of a directory, or summarize the tree beneath it.
+    ///
+    /// # Arguments (in `args`)
+    /// * `directory` - Path to the directory to list.
+    /// * `mode` - `"list"` (default) or `"summarize"`.
+    /// * `max_depth` - Override the struct's `max_depth` in `summarize` mode.
+    /// * `recursive` - List subdirectories too; implied by a glob with `**`.
+    /// * `glob` - Only list matching entries, e.g. `"**/*.rs"`. A pattern
+    ///   without `/` matches entry names at any depth.
+    /// * `ignore` - Override the struct's ignore patterns.
+    /// * `max_entries` - Override the struct's `max_entries`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let dir = args
+            .get("directory")
+            .and_then(|v| v.as_str())
+            .or(self.directory.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: directory"))?;
+
+        match args.get("mode").and_then(|v| v.as_str()).unwrap_or("list") {
+            "list" => {}
+            "summarize" | "summary" => {
+                let max_depth = args
+                    .get("max_depth")
+                    .and_then(|v| v.as_u64())
+                    .map(|d| d as usize)
+                    .or(self.max_depth);
+                return self.summarize(Path::new(dir), max_depth);
+            }
+            other => anyhow::bail!("Unknown mode '{}': expected 'list' or 'summarize'", other),
+        }
+
+        let glob = args.get("glob").and_then(|v| v.as_str());
+        let recursive = args
+            .get("recursive")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+            || glob.is_some_and(|g| g.contains("**"));
+        let ignore: Vec<String> = match args.get("ignore").and_then(|v| v.as_array()) {
+            Some(patterns) => patterns
+                .iter()
+                .filter_map(|p| p.as_str().map(str::to_string))
+                .collect(),
+            None => self.ignore.clone(),
+        };
+        let max_entries = args
+            .get("max_entries")
+            .and_then(|v| v.as_u64())
+            .map_or(self.max_entries, |n| n as usize);
+
+        let root = Path::new(dir);
+        let mut listing = Listing {
+            root,
+            recursive,
+            glob,
+            ignore: &ignore,
+            max_entries,
+            entries: Vec::new(),
+            truncated: false,
+        };
+        listing
+            .walk(root)
+            .map_err(|e| anyhow::anyhow!("Failed to read directory '{}': {}", dir, e))?;
+
+        Ok(serde_json::json!({
+            "directory": dir,
+            "count": listing.entries.len(),
+            "entries": listing.entries,
+            "truncated": listing.truncated,
+        }))
+    }
+
+    /// Walk the tree under `root` and aggregate statistics.
+    fn summarize(&self, root: &Path, max_depth: Option<usize>) -> Result<Value, anyhow::Error> {
+        use std::cmp::Reverse;
+        use std::collections::{BTreeMap, BinaryHeap};
+
+        if !root.is_dir() {
+            anyhow::bail!("'{}' is not a directory", root.display());
+        }
+        let ignore = if self.respect_ignore {
+            IgnoreRules::load(root)
+        } else {
+            IgnoreRules::default()
+        };
+        let started = std::time::Instant::now();
+        let budget = std::time::Duration::from_millis(self.time_budget_ms);
+
+        let mut by_extension: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+        // Min-heaps capped at `top_n`, so the smallest/oldest is evicted first.
+        let mut largest: BinaryHeap<Reverse<(u64, String)>> = BinaryHeap::new();
+        let mut recent: BinaryHeap<Reverse<(u64, String)>> = BinaryHeap::new();
+        let mut dirs_per_depth: Vec<u64> = Vec::new();
+        let mut files = 0u64;
+        let mut total_bytes = 0u64;
+        let mut unreadable = 0u64;
+        let mut stop_reason: Option<&str> = None;
+
+        let mut stack = vec![(root.to_path_buf(), 0usize)];
+        'walk: while let Some((dir, depth)) = stack.pop() {
+            let entries = match std::fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => {
+                    unreadable += 1;
+                    continue;
+                }
+            };
+            for entry in entries {
+                if files as usize >= self.max_files {
+                    stop_reason = Some("max_files");
+                    break 'walk;
+                }
+                if started.elapsed() >= budget {
+                    stop_reason = Some("time_budget");
+                    break 'walk;
+                }
+                let Ok(entry) = entry else {
+                    unreadable += 1;
+                    continue;
+                };
+                let path = entry.path();
+                let Ok(file_type) = entry.file_type() else {
+                    unreadable += 1;
+                    continue;
+                };
+                let relative = path.strip_prefix(root).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+                if self.respect_ignore && ignore.is_ignored(&relative, file_type.is_dir()) {
+                    continue;
+                }
+
+                if file_type.is_dir() {
+                    let child_depth = depth + 1;
+                    if dirs_per_depth.len() < child_depth {
+                        dirs_per_depth.resize(child_depth, 0);
+                    }
+                    dirs_per_depth[child_depth - 1] += 1;
+                    let within_depth = match max_depth {
+                        Some(max) => child_depth <= max,
+                        None => true,
+                    };
+                    if within_depth {
+                        stack.push((path, child_depth));
+                    }
+                    continue;
+                }
+                if !file_type.is_file() {
+                    continue;
+                }
+                let Ok(meta) = entry.metadata() else {
+                    unreadable += 1;
+                    continue;
+                };
+                let size = meta.len();
+                files += 1;
+                total_bytes += size;
+
+                let ext = path
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase())
+                    .unwrap_or_else(|| "(none)".to_string());
+                let slot = by_extension.entry(ext).or_insert((0, 0));
+                slot.0 += 1;
+                slot.1 += size;
+
+                push_bounded(&mut largest, (size, relative.clone()), self.top_n);
+                if let Some(modified) = meta
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                {
+                    push_bounded(&mut recent, (modified.as_secs(), relative), self.top_n);
+                }
+            }
+        }
+
+        let mut extensions: Vec<(String, (u64, u64))> = by_extension.into_iter().collect();
+        extensions.sort_by(|a, b| b.1 .1.cmp(&a.1 .1).then_with(|| a.0.cmp(&b.0)));
+        let by_extension: Vec<Value> = extensions
+            .into_iter()
+            .map(|(ext, (count, bytes))| serde_json::json!({"extension": ext, "files": count, "bytes": bytes}))
+            .collect();
+        let largest: Vec<Value> = largest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((bytes, path))| serde_json::json!({"path": path, "bytes": bytes}))
+            .collect();
+        let recent: Vec<Value> = recent
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((modified, path))| serde_json::json!({"path": path, "modified": modified}))
+            .collect();
+        let dirs_per_depth: Vec<Value> = dirs_per_depth
+            .iter()
+            .enumerate()
+            .map(|(i, n)| serde_json::json!({"depth": i + 1, "directories": n}))
+            .collect();
+
+        Ok(serde_json::json!({
+            "directory": root.display().to_string(),
+            "mode": "summarize",
+            "files": files,
+            "directories": dirs_per_depth.iter().filter_map(|d| d["directories"].as_u64()).sum::<u64>(),
+            "total_bytes": total_bytes,
+            "by_extension": by_extension,
+            "largest_files": largest,
+            "recently_modified": recent,
+            "directories_per_depth": dirs_per_depth,
+            "unreadable": unreadable,
+            "partial": stop_reason.is_some(),
+            "stop_reason": stop_reason,
+            "elapsed_ms": started.elapsed().as_millis() as u64,
+        }))
+    }
+}
+
+/// State for a `list`-mode walk.
+struct Listing<'a> {
+    root: &'a Path,
+    recursive: bool,
+    glob: Option<&'a str>,
+    ignore: &'a [String],
+    max_entries: usize,
+    entries: Vec<Value>,
+    truncated: bool,
+}
+
+impl Listing<'_> {
+    /// Visit `dir`'s children in name order, descending into each
+    /// directory before moving on, so the output is sorted by path.
+    /// Symlinked directories are listed but not descended into.
+    fn walk(&mut self, dir: &Path) -> std::io::Result<()> {
+        let mut children: Vec<_> = std::fs::read_dir(dir)?.filter_map(Result::ok).collect();
+        children.sort_by_key(|entry| entry.file_name());
+        for child in children {
+            if self.truncated {
+                return Ok(());
+            }
+            let path = child.path();
+            let relative = path
+                .strip_prefix(self.root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if self.ignore.iter().any(|p| glob_match(p, &relative)) {
+                continue;
+            }
+            let meta = std::fs::metadata(&path).or_else(|_| child.metadata());
+            let is_dir = meta.as_ref().is_ok_and(|m| m.is_dir());
+
+            let wanted = match self.glob {
+                Some(glob) => glob_match(glob, &relative),
+                None => true,
+            };
+            if wanted {
+                if self.entries.len() >= self.max_entries {
+                    self.truncated = true;
+                    return Ok(());
+                }
+                let modified = meta
+                    .as_ref()
+                    .ok()
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs());
+                self.entries.push(serde_json::json!({
+                    "path": relative,
+                    "is_dir": is_dir,
+                    "size": meta.as_ref().ok().filter(|m| m.is_file()).map(|m| m.len()),
+                    "modified": modified,
+                }));
+            }
+            let real_dir = child.file_type().is_ok_and(|t| t.is_dir());
+            if self.recursive && real_dir {
+                // An unreadable subdirectory is listed but not expanded.
+                let _ = self.walk(&path);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Match a slash-separated relative path against a glob. `**` spans any
+/// number of directories and `*` / `?` stay within one; a pattern without
+/// `/` is matched against the last path component only.
+fn glob_match(pattern: &str, relative: &str) -> bool {
+    fn segments(pattern: &[&str], path: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((&"**", rest)) => (0..=path.len()).any(|skip| segments(rest, &path[skip..])),
+            Some((first, rest)) => path
+                .split_first()
+                .is_some_and(|(name, tail)| wildcard_match(first, name) && segments(rest, tail)),
+        }
+    }
+
+    let pattern = pattern.trim_start_matches("./").trim_start_matches('/');
+    if !pattern.contains('/') && pattern != "**" {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        return wildcard_match(pattern, name);
+    }
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let path: Vec<&str> = relative.split('/').collect();
+    segments(&pattern, &path)
+}
+
+/// Push onto a min-heap, evicting the smallest entry beyond `cap`.
+fn push_bounded<T: Ord>(heap: &mut std::collections::BinaryHeap<std::cmp::Reverse<T>>, item: T, cap: usize) {
+    if cap == 0 {
+        return;
+    }
+    heap.push(std::cmp::Reverse(item));
+    if heap.len() > cap {
+        heap.pop();
+    }
+}
+
+/// Ignore rules for tree walks: hidden entries, plus the simple patterns of
+/// a root `.gitignore` (`*` and `?` wildcards, trailing `/` for directories,
+/// leading `/` to anchor at the root). Negations are not supported and are
+/// skipped.
+#[derive(Debug, Clone, Default)]
+struct IgnoreRules {
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug, Clone)]
+struct IgnorePattern {
+    glob: String,
+    dir_only: bool,
+    /// Match against the full relative path instead of any path component.
+    anchored: bool,
+}
+
+impl IgnoreRules {
+    fn load(root: &Path) -> Self {
+        let Ok(text) = std::fs::read_to_string(root.join(".gitignore")) else {
+            return Self::default();
+        };
+        let patterns = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#') && !l.starts_with('!'))
+            .map(|line| {
+                let dir_only = line.ends_with('/');
+                let line = line.trim_end_matches('/');
+                let anchored = line.starts_with('/') || line.contains('/');
+                IgnorePattern {
+                    glob: line.trim_start_matches('/').to_string(),
+                    dir_only,
+                    anchored,
+                }
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Whether `relative` (slash-separated, relative to the root) is ignored.
+    fn is_ignored(&self, relative: &str, is_dir: bool) -> bool {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        if name.starts_with('.') {
+            return true;
+        }
+        self.patterns.iter().any(|p| {
+            if p.dir_only && !is_dir {
+                return false;
+            }
+            if p.anchored {
+                wildcard_match(&p.glob, relative)
+            } else {
+                wildcard_match(&p.glob, name)
+            }
+        })
+    }
+}
+
+/// Match `text` against a pattern with `*` (any run, not crossing `/`) and
+/// `?` (one character).
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' && t[ti] != '/' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ti));
+            pi += 1;
+        } else if let Some((sp, st)) = star.filter(|&(_, st)| t[st] != '/') {
+            pi = sp + 1;
+            ti = st + 1;
+            star = Some((sp, st + 1));
+        } else {
+            return false;
+        }
+    }
+    while pi < p.len() && p[pi] == '*' {
+        pi += 1;
+    }
+    pi == p.len()
+}
+
 impl Default for DirectoryReadTool {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,93 @@
+//! MIME type detection for tools that pass files on as base64 (`FileReadTool`,
+//! `VisionTool`).
+
+use std::path::Path;
+
+/// The MIME type of a file, from its leading bytes where they are
+/// conclusive and from its extension otherwise. Zip-based formats (docx,
+/// xlsx, epub, ...) all sniff as zip, so the extension refines those.
+pub(crate) fn detect(path: &Path, bytes: &[u8]) -> &'static str {
+    match sniff(bytes) {
+        Some("application/zip") | None => {
+            by_extension(path).or(sniff(bytes)).unwrap_or("application/octet-stream")
+        }
+        Some(mime) => mime,
+    }
+}
+
+fn sniff(bytes: &[u8]) -> Option<&'static str> {
+    let at = |offset: usize, magic: &[u8]| bytes.get(offset..offset + magic.len()) == Some(magic);
+    let mime = if at(0, b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if at(0, b"\xff\xd8\xff") {
+        "image/jpeg"
+    } else if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        "image/gif"
+    } else if at(0, b"RIFF") && at(8, b"WEBP") {
+        "image/webp"
+    } else if at(0, b"RIFF") && at(8, b"WAVE") {
+        "audio/wav"
+    } else if at(0, b"BM") {
+        "image/bmp"
+    } else if at(0, b"II*\0") || at(0, b"MM\0*") {
+        "image/tiff"
+    } else if at(0, b"\0\0\x01\0") {
+        "image/x-icon"
+    } else if at(0, b"%PDF-") {
+        "application/pdf"
+    } else if at(0, b"PK\x03\x04") || at(0, b"PK\x05\x06") {
+        "application/zip"
+    } else if at(0, b"\x1f\x8b") {
+        "application/gzip"
+    } else if at(0, b"BZh") {
+        "application/x-bzip2"
+    } else if at(0, b"7z\xbc\xaf\x27\x1c") {
+        "application/x-7z-compressed"
+    } else if at(0, b"ID3") || at(0, b"\xff\xfb") {
+        "audio/mpeg"
+    } else if at(0, b"OggS") {
+        "audio/ogg"
+    } else if at(4, b"ftyp") {
+        "video/mp4"
+    } else if at(0, b"\x1a\x45\xdf\xa3") {
+        "video/webm"
+    } else {
+        return None;
+    };
+    Some(mime)
+}
+
+fn by_extension(path: &Path) -> Option<&'static str> {
+    let ext = path.extension()?.to_string_lossy().to_ascii_lowercase();
+    let mime = match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "ico" => "image/x-icon",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "epub" => "application/epub+zip",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "md" => "text/markdown",
+        "txt" | "log" => "text/plain",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        _ => return None,
+    };
+    Some(mime)
+}
@@ -27,10 +27,38 @@ pub mod ai_ml;
 #[cfg(feature = "automation")]
 pub mod automation;
 
-/// Cloud storage tools: S3, Bedrock, and other cloud service integrations.
+/// Cloud storage tools: S3, GCS, Azure Blob, Bedrock, and other cloud service integrations.
 #[cfg(feature = "cloud_storage")]
 pub mod cloud_storage;
 
 /// Browser tools: headless browser automation and web interaction.
 #[cfg(feature = "browser")]
 pub mod browser;
+
+/// Credit budgets shared by metered scraping tools (Scrapfly, Spider, Firecrawl, Apify).
+#[cfg(any(feature = "web_scraping", feature = "automation"))]
+pub mod credit_budget;
+
+/// robots.txt and per-host rate limits for the native scraping tools.
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub mod crawl_policy;
+
+/// Custom headers and cookies for the native scraping tools.
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub mod custom_headers;
+
+/// Serper.dev API key lookup shared by the search and scrape tools.
+#[cfg(any(feature = "search", feature = "web_scraping"))]
+pub(crate) mod serper;
+
+/// Retry with exponential backoff for tools that call rate-limited APIs.
+#[cfg(any(feature = "search", feature = "automation"))]
+pub(crate) mod retry;
+
+/// MIME type detection for tools that hand files on as base64.
+#[cfg(any(feature = "file_ops", feature = "ai_ml"))]
+pub(crate) mod mime;
+
+/// Zapier NLA client shared by `ZapierActionTools` and the `ZapierActionTool` adapter.
+#[cfg(feature = "reqwest")]
+pub(crate) mod zapier;
@@ -0,0 +1,139 @@
+//! Retry with exponential backoff for HTTP calls.
+//!
+//! Search APIs regularly answer 429 under load and occasionally 5xx, and a
+//! single such response should not abort a long crew run. Tools that opt in
+//! keep a `max_retries` / `backoff_base_ms` pair and send through
+//! [`send_with_retry`] (blocking) or [`send_with_retry_async`], which retry
+//! connect errors, timeouts, 429 and 5xx. Any other response, successful or
+//! not, is returned to the caller as-is.
+
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+/// Longest `Retry-After` wait honored, so a misbehaving server cannot stall
+/// the caller indefinitely.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Send the request produced by `build`, retrying transient failures up to
+/// `max_retries` times.
+///
+/// Waits `backoff_base_ms * 2^n` before retry `n` (0-based), or the
+/// server's `Retry-After` seconds when present. `label` names the service
+/// in the final error, which reports how many attempts were made.
+pub(crate) fn send_with_retry(
+    label: &str,
+    max_retries: u32,
+    backoff_base_ms: u64,
+    build: impl Fn() -> reqwest::blocking::RequestBuilder,
+) -> Result<reqwest::blocking::Response, anyhow::Error> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = build().send();
+        let outcome = match &result {
+            Ok(resp) => Outcome::Response(resp.status(), resp.headers()),
+            Err(e) => Outcome::Error(e),
+        };
+        match next_wait(label, attempt, max_retries, backoff_base_ms, outcome)? {
+            Some(wait) => std::thread::sleep(wait),
+            None => return Ok(result?),
+        }
+    }
+}
+
+/// Async counterpart of [`send_with_retry`], with identical retry rules.
+#[cfg_attr(not(feature = "search"), allow(dead_code))]
+pub(crate) async fn send_with_retry_async(
+    label: &str,
+    max_retries: u32,
+    backoff_base_ms: u64,
+    build: impl Fn() -> reqwest::RequestBuilder,
+) -> Result<reqwest::Response, anyhow::Error> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = build().send().await;
+        let outcome = match &result {
+            Ok(resp) => Outcome::Response(resp.status(), resp.headers()),
+            Err(e) => Outcome::Error(e),
+        };
+        match next_wait(label, attempt, max_retries, backoff_base_ms, outcome)? {
+            Some(wait) => tokio::time::sleep(wait).await,
+            None => return Ok(result?),
+        }
+    }
+}
+
+/// The result of one attempt, as far as the retry decision needs it.
+enum Outcome<'a> {
+    Response(StatusCode, &'a HeaderMap),
+    Error(&'a reqwest::Error),
+}
+
+/// Decide what follows attempt number `attempt`: `None` to hand the
+/// response back, `Some(wait)` to retry after `wait`, or the final error
+/// once retries are exhausted or the failure is not transient.
+fn next_wait(
+    label: &str,
+    attempt: u32,
+    max_retries: u32,
+    backoff_base_ms: u64,
+    outcome: Outcome<'_>,
+) -> Result<Option<Duration>, anyhow::Error> {
+    let last = attempt > max_retries;
+    let backoff =
+        Duration::from_millis(backoff_base_ms.saturating_mul(1u64 << (attempt - 1).min(16)));
+    let wait = match outcome {
+        Outcome::Response(status, _) if !is_retryable_status(status) => return Ok(None),
+        Outcome::Response(status, _) if last => anyhow::bail!(
+            "{} request failed after {}: HTTP {}",
+            label,
+            plural_attempts(attempt),
+            status
+        ),
+        Outcome::Response(_, headers) => retry_after(headers).unwrap_or(backoff),
+        Outcome::Error(e) if (e.is_connect() || e.is_timeout()) && !last => backoff,
+        // reqwest keeps "timed out" in the error's source, which the message
+        // below would drop.
+        Outcome::Error(e) if e.is_timeout() => anyhow::bail!(
+            "{} request timed out after {}: {}",
+            label,
+            plural_attempts(attempt),
+            e
+        ),
+        Outcome::Error(e) => anyhow::bail!(
+            "{} request failed after {}: {}",
+            label,
+            plural_attempts(attempt),
+            e
+        ),
+    };
+    log::debug!("{} request attempt {} failed; retrying in {:?}", label, attempt, wait);
+    Ok(Some(wait))
+}
+
+fn plural_attempts(n: u32) -> String {
+    if n == 1 {
+        "1 attempt".to_string()
+    } else {
+        format!("{} attempts", n)
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// The `Retry-After` delay, when given in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let secs = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(Duration::from_secs(secs).min(MAX_RETRY_AFTER))
+}
@@ -0,0 +1,155 @@
+//! An in-memory response cache shared by the web search tools.
+//!
+//! Agents often repeat the same query several times in one crew run. A
+//! `SearchCache` behind an `Arc` can be handed to `SerperDevTool`,
+//! `BraveSearchTool` and `TavilySearchTool` with `with_cache`, so repeats are
+//! answered locally instead of spending API credits. Entries are keyed by
+//! provider, normalized query and request parameters, expire after a TTL,
+//! and the oldest entry is evicted once `max_entries` is reached.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Counters reported by [`SearchCache::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Lookups answered from the cache.
+    pub hits: u64,
+    /// Lookups that went to the provider.
+    pub misses: u64,
+    /// Live entries currently held.
+    pub entries: usize,
+}
+
+/// TTL-bounded cache of successful search responses.
+pub struct SearchCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<String, (Instant, Value)>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl SearchCache {
+    /// Create a cache whose entries live for `ttl`, holding at most
+    /// `max_entries` responses.
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// How long entries stay valid.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// The entry cap.
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Hit and miss counts and the number of live entries.
+    pub fn stats(&self) -> CacheStats {
+        let mut entries = self.entries.lock().unwrap();
+        let ttl = self.ttl;
+        entries.retain(|_, (stored, _)| stored.elapsed() < ttl);
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: entries.len(),
+        }
+    }
+
+    /// Drop every entry. Counters are kept.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Cache key for a query. The query is lowercased with whitespace
+    /// collapsed; `params` holds every other request setting that changes
+    /// the response.
+    pub(crate) fn key(provider: &str, query: &str, params: &Value) -> String {
+        let query = query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        format!("{}\u{0}{}\u{0}{}", provider, query, params)
+    }
+
+    /// Look up `key`, returning the stored response marked `"cached": true`.
+    pub(crate) fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().unwrap();
+        let hit = match entries.get(key) {
+            Some((stored, _)) if stored.elapsed() >= self.ttl => {
+                entries.remove(key);
+                None
+            }
+            Some((_, value)) => Some(value.clone()),
+            None => None,
+        };
+        drop(entries);
+        match hit {
+            Some(value) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(mark_cached(value, true))
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Store `value` under `key` and return it marked `"cached": false`.
+    pub(crate) fn insert(&self, key: String, value: Value) -> Value {
+        if self.max_entries > 0 {
+            let mut entries = self.entries.lock().unwrap();
+            if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+                let ttl = self.ttl;
+                entries.retain(|_, (stored, _)| stored.elapsed() < ttl);
+                if entries.len() >= self.max_entries {
+                    let oldest = entries
+                        .iter()
+                        .min_by_key(|(_, (stored, _))| *stored)
+                        .map(|(k, _)| k.clone());
+                    if let Some(oldest) = oldest {
+                        entries.remove(&oldest);
+                    }
+                }
+            }
+            entries.insert(key, (Instant::now(), value.clone()));
+        }
+        mark_cached(value, false)
+    }
+}
+
+impl Default for SearchCache {
+    /// Fifteen-minute TTL, 1000 entries.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(15 * 60), 1000)
+    }
+}
+
+impl std::fmt::Debug for SearchCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SearchCache")
+            .field("ttl", &self.ttl)
+            .field("max_entries", &self.max_entries)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+fn mark_cached(mut value: Value, cached: bool) -> Value {
+    if let Value::Object(map) = &mut value {
+        map.insert("cached".to_string(), Value::Bool(cached));
+    }
+    value
+}
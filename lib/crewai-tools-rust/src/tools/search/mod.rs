@@ -8,6 +8,154 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+mod cache;
+mod request;
+
+pub use cache::{CacheStats, SearchCache};
+use request::SearchRequest;
+
+use crate::markdown::{atx_heading, code_fence, skip_frontmatter};
+use crate::tabular::looks_like_header;
+use crate::{docx, xpath};
+
+use super::crawl_policy::{CrawlGuard, HostRateLimiter, RobotsCache, USER_AGENT};
+use super::custom_headers::CustomHeaders;
+use super::serper;
+
+pub use crate::youtube::NoTranscript;
+
+// ── SearchResult ─────────────────────────────────────────────────────────────
+
+/// A web search hit in a provider-independent shape.
+///
+/// Produced by the `run_normalized` methods on `BraveSearchTool`,
+/// `SerperDevTool`, `TavilySearchTool` and `ExaSearchTool`. Fields a provider
+/// does not supply are `None`; provider fields with no counterpart here are
+/// kept verbatim in `extra`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Page title.
+    pub title: Option<String>,
+    /// Page URL.
+    pub url: Option<String>,
+    /// Description or excerpt of the page.
+    pub snippet: Option<String>,
+    /// Provider that returned the hit ("brave", "serper", "tavily", "exa").
+    pub source: String,
+    /// Provider relevance score, where the provider reports one.
+    pub score: Option<f64>,
+    /// Publication date or age, as reported by the provider.
+    pub published: Option<String>,
+    /// Remaining provider fields.
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub extra: serde_json::Map<String, Value>,
+}
+
+/// Provider keys read into each `SearchResult` field, in order of preference.
+struct ResultFields {
+    title: &'static [&'static str],
+    url: &'static [&'static str],
+    snippet: &'static [&'static str],
+    score: &'static [&'static str],
+    published: &'static [&'static str],
+}
+
+impl SearchResult {
+    /// Normalize a Brave Web Search response (`web.results`).
+    pub fn from_brave_response(resp: &Value) -> Vec<Self> {
+        const FIELDS: ResultFields = ResultFields {
+            title: &["title"],
+            url: &["url"],
+            snippet: &["description"],
+            score: &[],
+            published: &["page_age", "age"],
+        };
+        Self::from_array(resp.pointer("/web/results"), "brave", &FIELDS)
+    }
+
+    /// Normalize a Serper response. Reads whichever of `organic`, `news`,
+    /// `images`, `places` and `shopping` are present.
+    pub fn from_serper_response(resp: &Value) -> Vec<Self> {
+        const FIELDS: ResultFields = ResultFields {
+            title: &["title"],
+            url: &["link", "website"],
+            snippet: &["snippet", "address"],
+            score: &[],
+            published: &["date"],
+        };
+        ["organic", "news", "images", "places", "shopping"]
+            .iter()
+            .flat_map(|key| Self::from_array(resp.get(*key), "serper", &FIELDS))
+            .collect()
+    }
+
+    /// Normalize a Tavily search response (`results`).
+    pub fn from_tavily_response(resp: &Value) -> Vec<Self> {
+        const FIELDS: ResultFields = ResultFields {
+            title: &["title"],
+            url: &["url"],
+            snippet: &["content"],
+            score: &["score"],
+            published: &["published_date"],
+        };
+        Self::from_array(resp.get("results"), "tavily", &FIELDS)
+    }
+
+    /// Normalize an Exa search response (`results`).
+    pub fn from_exa_response(resp: &Value) -> Vec<Self> {
+        const FIELDS: ResultFields = ResultFields {
+            title: &["title"],
+            url: &["url"],
+            snippet: &["text", "summary"],
+            score: &["score"],
+            published: &["publishedDate"],
+        };
+        Self::from_array(resp.get("results"), "exa", &FIELDS)
+    }
+
+    fn from_array(items: Option<&Value>, source: &str, fields: &ResultFields) -> Vec<Self> {
+        items
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().map(|item| Self::from_item(item, source, fields)).collect())
+            .unwrap_or_default()
+    }
+
+    fn from_item(item: &Value, source: &str, fields: &ResultFields) -> Self {
+        let mut extra = item.as_object().cloned().unwrap_or_default();
+        let text = |v: Value| match v {
+            Value::String(s) => s,
+            other => other.to_string(),
+        };
+        let title = Self::take(&mut extra, fields.title).map(text);
+        let url = Self::take(&mut extra, fields.url).map(text);
+        let snippet = Self::take(&mut extra, fields.snippet).map(text);
+        let published = Self::take(&mut extra, fields.published).map(text);
+        // A score that is not a number stays in `extra` rather than being dropped.
+        let score = fields
+            .score
+            .iter()
+            .find_map(|key| extra.get(*key).and_then(Value::as_f64).map(|score| (*key, score)))
+            .map(|(key, score)| {
+                extra.remove(key);
+                score
+            });
+        Self {
+            title,
+            url,
+            snippet,
+            source: source.to_string(),
+            score,
+            published,
+            extra,
+        }
+    }
+
+    /// Remove and return the first non-null value among `keys`.
+    fn take(extra: &mut serde_json::Map<String, Value>, keys: &[&str]) -> Option<Value> {
+        keys.iter().find_map(|key| extra.remove(*key).filter(|v| !v.is_null()))
+    }
+}
+
 // ── BraveSearchTool ──────────────────────────────────────────────────────────
 
 /// Search the web using the Brave Search API.
@@ -21,6 +169,20 @@ pub struct BraveSearchTool {
     pub max_results: usize,
     /// Country code for localized results.
     pub country: Option<String>,
+    /// Retries after a connect error, timeout, 429 or 5xx response.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further one.
+    pub backoff_base_ms: u64,
+    /// Override of the API endpoint (for proxies and testing).
+    pub base_url: Option<String>,
+    /// Request timeout.
+    pub timeout: std::time::Duration,
+    /// Proxy URL for all requests. When unset, `HTTPS_PROXY`/`HTTP_PROXY`
+    /// from the environment are used.
+    pub proxy: Option<String>,
+    /// Shared response cache (not serialized).
+    #[serde(skip)]
+    pub cache: Option<std::sync::Arc<SearchCache>>,
 }
 
 impl BraveSearchTool {
@@ -29,9 +191,45 @@ impl BraveSearchTool {
             api_key: None,
             max_results: 10,
             country: None,
+            max_retries: 3,
+            backoff_base_ms: 500,
+            base_url: None,
+            timeout: std::time::Duration::from_secs(30),
+            proxy: None,
+            cache: None,
         }
     }
 
+    pub fn with_cache(mut self, cache: std::sync::Arc<SearchCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    pub fn with_max_retries(mut self, n: u32) -> Self {
+        self.max_retries = n;
+        self
+    }
+
+    pub fn with_backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
         self.api_key = Some(key.into());
         self
@@ -52,6 +250,18 @@ impl BraveSearchTool {
     /// # Arguments (in `args`)
     /// * `search_query` - The search query string.
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send()
+    }
+
+    /// Async counterpart of `run`, using reqwest's non-blocking client.
+    pub async fn run_async(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send_async().await
+    }
+
+    fn search_request(
+        &self,
+        args: &HashMap<String, Value>,
+    ) -> Result<SearchRequest, anyhow::Error> {
         let query = args
             .get("search_query")
             .and_then(|v| v.as_str())
@@ -63,20 +273,48 @@ impl BraveSearchTool {
             .or_else(|| std::env::var("BRAVE_API_KEY").ok())
             .ok_or_else(|| anyhow::anyhow!("Missing BRAVE_API_KEY"))?;
 
-        let client = reqwest::blocking::Client::new();
-        let mut request = client
-            .get("https://api.search.brave.com/res/v1/web/search")
-            .header("Accept", "application/json")
-            .header("Accept-Encoding", "gzip")
-            .header("X-Subscription-Token", &api_key)
-            .query(&[("q", query), ("count", &self.max_results.to_string())]);
-
+        let mut params = vec![("q", query.to_string()), ("count", self.max_results.to_string())];
         if let Some(ref country) = self.country {
-            request = request.query(&[("country", country.as_str())]);
+            params.push(("country", country.clone()));
         }
 
-        let resp = request.send()?.json::<Value>()?;
-        Ok(resp)
+        Ok(SearchRequest {
+            label: "Brave Search",
+            method: reqwest::Method::GET,
+            url: self
+                .base_url
+                .clone()
+                .unwrap_or_else(|| "https://api.search.brave.com/res/v1/web/search".to_string()),
+            headers: vec![
+                ("Accept", "application/json".to_string()),
+                ("Accept-Encoding", "gzip".to_string()),
+                ("X-Subscription-Token", api_key),
+            ],
+            query: params,
+            body: None,
+            timeout: self.timeout,
+            proxy: self.proxy.clone(),
+            max_retries: self.max_retries,
+            backoff_base_ms: self.backoff_base_ms,
+            fail_on_error_status: false,
+            cache: self.cache.clone().map(|cache| {
+                let params = serde_json::json!({
+                    "count": self.max_results,
+                    "country": self.country,
+                });
+                (cache, SearchCache::key("brave", query, &params))
+            }),
+        })
+    }
+
+    /// Run a query and normalize the response into `SearchResult`s.
+    ///
+    /// Takes the same `args` as `run`.
+    pub fn run_normalized(
+        &self,
+        args: HashMap<String, Value>,
+    ) -> Result<Vec<SearchResult>, anyhow::Error> {
+        Ok(SearchResult::from_brave_response(&self.run(args)?))
     }
 }
 
@@ -88,6 +326,96 @@ impl Default for BraveSearchTool {
 
 // ── SerperDevTool ────────────────────────────────────────────────────────────
 
+/// Serper endpoint to query.
+///
+/// Deserializing an unrecognized name logs a warning and falls back to
+/// `Search`, rather than sending the query to an endpoint that was never
+/// intended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SerperSearchType {
+    /// Web results (`/search`).
+    #[default]
+    Search,
+    /// News articles (`/news`).
+    News,
+    /// Images (`/images`).
+    Images,
+    /// Local businesses and places (`/places`).
+    Places,
+    /// Google Scholar papers (`/scholar`).
+    Scholar,
+    /// Shopping listings (`/shopping`).
+    Shopping,
+}
+
+impl SerperSearchType {
+    /// The endpoint path segment, which is also the serialized name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::News => "news",
+            Self::Images => "images",
+            Self::Places => "places",
+            Self::Scholar => "scholar",
+            Self::Shopping => "shopping",
+        }
+    }
+}
+
+impl From<&str> for SerperSearchType {
+    fn from(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "search" => Self::Search,
+            "news" => Self::News,
+            "images" => Self::Images,
+            "places" => Self::Places,
+            "scholar" => Self::Scholar,
+            "shopping" => Self::Shopping,
+            other => {
+                log::warn!("Unknown Serper search type '{}'; using 'search'", other);
+                Self::Search
+            }
+        }
+    }
+}
+
+impl From<String> for SerperSearchType {
+    fn from(name: String) -> Self {
+        Self::from(name.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SerperSearchType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?))
+    }
+}
+
+/// Recency filter for Serper news searches, sent as Google's `tbs` parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SerperDateRange {
+    PastHour,
+    PastDay,
+    PastWeek,
+    PastMonth,
+    PastYear,
+}
+
+impl SerperDateRange {
+    /// The `tbs` value for this range.
+    pub fn tbs(&self) -> &'static str {
+        match self {
+            Self::PastHour => "qdr:h",
+            Self::PastDay => "qdr:d",
+            Self::PastWeek => "qdr:w",
+            Self::PastMonth => "qdr:m",
+            Self::PastYear => "qdr:y",
+        }
+    }
+}
+
 /// Search the web using the Serper.dev Google Search API.
 ///
 /// Corresponds to Python `SerperDevTool` in `crewai_tools`.
@@ -95,37 +423,104 @@ impl Default for BraveSearchTool {
 pub struct SerperDevTool {
     /// Serper.dev API key.
     pub api_key: Option<String>,
-    /// Search type (e.g., "search", "news", "images").
-    pub search_type: String,
+    /// Endpoint to query.
+    pub search_type: SerperSearchType,
     /// Maximum number of results.
     pub max_results: usize,
     /// Country code.
     pub country: Option<String>,
     /// Language code.
     pub language: Option<String>,
+    /// Recency filter; only sent for `News` searches.
+    pub date_range: Option<SerperDateRange>,
+    /// Location to search around (e.g. "Berlin, Germany"); only sent for
+    /// `Places` searches.
+    pub location: Option<String>,
+    /// Retries after a connect error, timeout, 429 or 5xx response.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further one.
+    pub backoff_base_ms: u64,
+    /// Override of the API base URL (for proxies and testing).
+    pub base_url: Option<String>,
+    /// Request timeout.
+    pub timeout: std::time::Duration,
+    /// Proxy URL for all requests. When unset, `HTTPS_PROXY`/`HTTP_PROXY`
+    /// from the environment are used.
+    pub proxy: Option<String>,
+    /// Shared response cache (not serialized).
+    #[serde(skip)]
+    pub cache: Option<std::sync::Arc<SearchCache>>,
 }
 
 impl SerperDevTool {
     pub fn new() -> Self {
         Self {
             api_key: None,
-            search_type: "search".to_string(),
+            search_type: SerperSearchType::Search,
             max_results: 10,
             country: None,
             language: None,
+            date_range: None,
+            location: None,
+            max_retries: 3,
+            backoff_base_ms: 500,
+            base_url: None,
+            timeout: std::time::Duration::from_secs(30),
+            proxy: None,
+            cache: None,
         }
     }
 
+    pub fn with_cache(mut self, cache: std::sync::Arc<SearchCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    pub fn with_max_retries(mut self, n: u32) -> Self {
+        self.max_retries = n;
+        self
+    }
+
+    pub fn with_backoff_base_ms(mut self, ms: u64) -> Self {
+        self.backoff_base_ms = ms;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
         self.api_key = Some(key.into());
         self
     }
 
-    pub fn with_search_type(mut self, search_type: impl Into<String>) -> Self {
+    pub fn with_search_type(mut self, search_type: impl Into<SerperSearchType>) -> Self {
         self.search_type = search_type.into();
         self
     }
 
+    pub fn with_date_range(mut self, range: SerperDateRange) -> Self {
+        self.date_range = Some(range);
+        self
+    }
+
+    pub fn with_location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
     pub fn with_max_results(mut self, n: usize) -> Self {
         self.max_results = n;
         self
@@ -146,16 +541,24 @@ impl SerperDevTool {
     /// # Arguments (in `args`)
     /// * `search_query` - The search query string.
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send()
+    }
+
+    /// Async counterpart of `run`, using reqwest's non-blocking client.
+    pub async fn run_async(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send_async().await
+    }
+
+    fn search_request(
+        &self,
+        args: &HashMap<String, Value>,
+    ) -> Result<SearchRequest, anyhow::Error> {
         let query = args
             .get("search_query")
             .and_then(|v| v.as_str())
             .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
 
-        let api_key = self
-            .api_key
-            .clone()
-            .or_else(|| std::env::var("SERPER_API_KEY").ok())
-            .ok_or_else(|| anyhow::anyhow!("Missing SERPER_API_KEY"))?;
+        let api_key = serper::api_key(self.api_key.as_deref())?;
 
         let mut body = serde_json::json!({
             "q": query,
@@ -167,24 +570,54 @@ impl SerperDevTool {
         if let Some(ref lang) = self.language {
             body["hl"] = Value::String(lang.clone());
         }
+        match (self.search_type, &self.date_range, &self.location) {
+            (SerperSearchType::News, Some(range), _) => {
+                body["tbs"] = Value::from(range.tbs());
+            }
+            (SerperSearchType::Places, _, Some(location)) => {
+                body["location"] = Value::String(location.clone());
+            }
+            _ => {}
+        }
 
-        let endpoint = match self.search_type.as_str() {
-            "news" => "https://google.serper.dev/news",
-            "images" => "https://google.serper.dev/images",
-            "places" => "https://google.serper.dev/places",
-            _ => "https://google.serper.dev/search",
-        };
-
-        let client = reqwest::blocking::Client::new();
-        let resp = client
-            .post(endpoint)
-            .header("X-API-KEY", &api_key)
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()?
-            .json::<Value>()?;
-
-        Ok(resp)
+        let path = self.search_type.as_str();
+        let cache = self.cache.clone().map(|cache| {
+            let params = serde_json::json!({
+                "type": path,
+                "num": self.max_results,
+                "gl": self.country,
+                "hl": self.language,
+                "tbs": body.get("tbs"),
+                "location": body.get("location"),
+            });
+            (cache, SearchCache::key("serper", query, &params))
+        });
+        let base_url = self.base_url.as_deref().unwrap_or("https://google.serper.dev");
+
+        Ok(SearchRequest {
+            label: "Serper",
+            method: reqwest::Method::POST,
+            url: format!("{}/{}", base_url.trim_end_matches('/'), path),
+            headers: serper::headers(api_key),
+            query: Vec::new(),
+            body: Some(body),
+            timeout: self.timeout,
+            proxy: self.proxy.clone(),
+            max_retries: self.max_retries,
+            backoff_base_ms: self.backoff_base_ms,
+            fail_on_error_status: false,
+            cache,
+        })
+    }
+
+    /// Run a query and normalize the response into `SearchResult`s.
+    ///
+    /// Takes the same `args` as `run`.
+    pub fn run_normalized(
+        &self,
+        args: HashMap<String, Value>,
+    ) -> Result<Vec<SearchResult>, anyhow::Error> {
+        Ok(SearchResult::from_serper_response(&self.run(args)?))
     }
 }
 
@@ -207,6 +640,16 @@ pub struct TavilySearchTool {
     pub search_depth: String,
     /// Maximum number of results.
     pub max_results: usize,
+    /// Request timeout.
+    pub timeout: std::time::Duration,
+    /// Proxy URL for all requests. When unset, `HTTPS_PROXY`/`HTTP_PROXY`
+    /// from the environment are used.
+    pub proxy: Option<String>,
+    /// Override of the API endpoint (for proxies and testing).
+    pub base_url: Option<String>,
+    /// Shared response cache (not serialized).
+    #[serde(skip)]
+    pub cache: Option<std::sync::Arc<SearchCache>>,
 }
 
 impl TavilySearchTool {
@@ -215,9 +658,33 @@ impl TavilySearchTool {
             api_key: None,
             search_depth: "basic".to_string(),
             max_results: 10,
+            timeout: std::time::Duration::from_secs(30),
+            proxy: None,
+            base_url: None,
+            cache: None,
         }
     }
 
+    pub fn with_cache(mut self, cache: std::sync::Arc<SearchCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
         self.api_key = Some(key.into());
         self
@@ -233,8 +700,72 @@ impl TavilySearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("TavilySearchTool: not yet implemented - requires Tavily API integration")
+    /// Run a Tavily search query.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - The search query string.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send()
+    }
+
+    /// Async counterpart of `run`, using reqwest's non-blocking client.
+    pub async fn run_async(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send_async().await
+    }
+
+    fn search_request(
+        &self,
+        args: &HashMap<String, Value>,
+    ) -> Result<SearchRequest, anyhow::Error> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("TAVILY_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing TAVILY_API_KEY"))?;
+
+        Ok(SearchRequest {
+            label: "Tavily",
+            method: reqwest::Method::POST,
+            url: self
+                .base_url
+                .clone()
+                .unwrap_or_else(|| "https://api.tavily.com/search".to_string()),
+            headers: Vec::new(),
+            query: Vec::new(),
+            body: Some(serde_json::json!({
+                "api_key": api_key,
+                "query": query,
+                "search_depth": self.search_depth,
+                "max_results": self.max_results,
+            })),
+            timeout: self.timeout,
+            proxy: self.proxy.clone(),
+            max_retries: 0,
+            backoff_base_ms: 0,
+            fail_on_error_status: true,
+            cache: self.cache.clone().map(|cache| {
+                let params = serde_json::json!({
+                    "search_depth": self.search_depth,
+                    "max_results": self.max_results,
+                });
+                (cache, SearchCache::key("tavily", query, &params))
+            }),
+        })
+    }
+
+    /// Run a query and normalize the response into `SearchResult`s.
+    ///
+    /// Takes the same `args` as `run`.
+    pub fn run_normalized(
+        &self,
+        args: HashMap<String, Value>,
+    ) -> Result<Vec<SearchResult>, anyhow::Error> {
+        Ok(SearchResult::from_tavily_response(&self.run(args)?))
     }
 }
 
@@ -257,6 +788,13 @@ pub struct ExaSearchTool {
     pub max_results: usize,
     /// Whether to include page contents in results.
     pub include_contents: bool,
+    /// Request timeout.
+    pub timeout: std::time::Duration,
+    /// Proxy URL for all requests. When unset, `HTTPS_PROXY`/`HTTP_PROXY`
+    /// from the environment are used.
+    pub proxy: Option<String>,
+    /// Override of the API endpoint (for proxies and testing).
+    pub base_url: Option<String>,
 }
 
 impl ExaSearchTool {
@@ -265,9 +803,27 @@ impl ExaSearchTool {
             api_key: None,
             max_results: 10,
             include_contents: true,
+            timeout: std::time::Duration::from_secs(30),
+            proxy: None,
+            base_url: None,
         }
     }
 
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_api_key(mut self, key: impl Into<String>) -> Self {
         self.api_key = Some(key.into());
         self
@@ -283,8 +839,69 @@ impl ExaSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("ExaSearchTool: not yet implemented - requires EXA API integration")
+    /// Run an EXA search query.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - The search query string.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send()
+    }
+
+    /// Async counterpart of `run`, using reqwest's non-blocking client.
+    pub async fn run_async(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        self.search_request(&args)?.send_async().await
+    }
+
+    fn search_request(
+        &self,
+        args: &HashMap<String, Value>,
+    ) -> Result<SearchRequest, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("EXA_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing EXA_API_KEY"))?;
+
+        let mut body = serde_json::json!({
+            "query": query,
+            "numResults": self.max_results,
+        });
+        if self.include_contents {
+            body["contents"] = serde_json::json!({ "text": true });
+        }
+
+        Ok(SearchRequest {
+            label: "EXA",
+            method: reqwest::Method::POST,
+            url: self
+                .base_url
+                .clone()
+                .unwrap_or_else(|| "https://api.exa.ai/search".to_string()),
+            headers: vec![("x-api-key", api_key)],
+            query: Vec::new(),
+            body: Some(body),
+            timeout: self.timeout,
+            proxy: self.proxy.clone(),
+            max_retries: 0,
+            backoff_base_ms: 0,
+            fail_on_error_status: true,
+            cache: None,
+        })
+    }
+
+    /// Run a query and normalize the response into `SearchResult`s.
+    ///
+    /// Takes the same `args` as `run`.
+    pub fn run_normalized(
+        &self,
+        args: HashMap<String, Value>,
+    ) -> Result<Vec<SearchResult>, anyhow::Error> {
+        Ok(SearchResult::from_exa_response(&self.run(args)?))
     }
 }
 
@@ -338,18 +955,33 @@ impl Default for ArxivPaperTool {
 
 // ── CsvSearchTool ────────────────────────────────────────────────────────────
 
-/// Search within CSV files using semantic or keyword search.
+/// Search within CSV files using column-aware keyword or fuzzy matching.
+///
+/// Rows are streamed from disk, so arbitrarily large files can be searched
+/// without loading them into memory. Matching rows are returned as JSON
+/// objects keyed by column name.
 ///
 /// Corresponds to Python `CSVSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CsvSearchTool {
     /// Path to the CSV file.
     pub file_path: Option<String>,
+    /// Maximum number of matching rows to return.
+    pub max_results: usize,
+    /// Whether to fall back to fuzzy (typo-tolerant) matching.
+    pub fuzzy: bool,
+    /// Minimum similarity [0.0, 1.0] for a fuzzy token match.
+    pub fuzzy_threshold: f64,
 }
 
 impl CsvSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            max_results: 20,
+            fuzzy: false,
+            fuzzy_threshold: 0.8,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -357,8 +989,176 @@ impl CsvSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("CsvSearchTool: not yet implemented - requires CSV parsing and search")
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    pub fn with_fuzzy(mut self, fuzzy: bool) -> Self {
+        self.fuzzy = fuzzy;
+        self
+    }
+
+    pub fn with_fuzzy_threshold(mut self, threshold: f64) -> Self {
+        self.fuzzy_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Search the rows of a CSV file.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Text to look for (case-insensitive).
+    /// * `file_path` - Path to the CSV file (optional if set on struct).
+    /// * `columns` - Column names to restrict the search to (optional).
+    /// * `fuzzy` - Override the struct's fuzzy setting (optional).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+        if query.trim().is_empty() {
+            anyhow::bail!("search_query must not be empty");
+        }
+
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+
+        let columns: Option<Vec<String>> = args.get("columns").and_then(|v| match v {
+            Value::Array(items) => Some(
+                items
+                    .iter()
+                    .filter_map(|c| c.as_str().map(String::from))
+                    .collect(),
+            ),
+            Value::String(s) => Some(s.split(',').map(|c| c.trim().to_string()).collect()),
+            _ => None,
+        });
+
+        let fuzzy = args
+            .get("fuzzy")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(self.fuzzy);
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(path)
+            .map_err(|e| anyhow::anyhow!("Failed to open CSV file '{}': {}", path, e))?;
+
+        let mut records = reader.byte_records();
+
+        // Header detection: treat the first row as a header when every cell is
+        // non-empty, non-numeric, and unique. Otherwise synthesize names.
+        let first: Vec<String> = match records.next() {
+            Some(Ok(record)) => record
+                .iter()
+                .map(|f| String::from_utf8_lossy(f).trim().to_string())
+                .collect(),
+            Some(Err(e)) => anyhow::bail!("Failed to read CSV file '{}': {}", path, e),
+            None => {
+                return Ok(serde_json::json!({
+                    "file_path": path,
+                    "query": query,
+                    "columns": [],
+                    "results": [],
+                    "count": 0,
+                }))
+            }
+        };
+        let has_header = looks_like_header(&first);
+        let headers: Vec<String> = if has_header {
+            first.clone()
+        } else {
+            (1..=first.len()).map(|i| format!("column_{}", i)).collect()
+        };
+
+        if let Some(ref cols) = columns {
+            let unknown: Vec<&String> = cols.iter().filter(|c| !headers.contains(c)).collect();
+            if !unknown.is_empty() {
+                anyhow::bail!(
+                    "Unknown column(s) {:?}; available columns: {:?}",
+                    unknown,
+                    headers
+                );
+            }
+        }
+
+        let needle = query.to_lowercase();
+        let mut results = Vec::new();
+        let mut skipped_rows = 0usize;
+        let mut ragged_rows = 0usize;
+
+        let first_row = if has_header { None } else { Some((1usize, first)) };
+        let rest = records.enumerate().filter_map(|(i, record)| match record {
+            Ok(record) => Some((
+                i + 2,
+                record
+                    .iter()
+                    .map(|f| String::from_utf8_lossy(f).into_owned())
+                    .collect::<Vec<String>>(),
+            )),
+            Err(e) => {
+                log::warn!("CsvSearchTool: skipping unreadable row {} in '{}': {}", i + 2, path, e);
+                skipped_rows += 1;
+                None
+            }
+        });
+
+        for (row_number, fields) in first_row.into_iter().chain(rest) {
+            if results.len() >= self.max_results {
+                break;
+            }
+
+            // Fields beyond the header row are kept under positional names
+            // rather than dropped, and the row is counted as ragged.
+            if fields.len() > headers.len() {
+                ragged_rows += 1;
+            }
+            let names: Vec<String> = (0..fields.len())
+                .map(|i| headers.get(i).cloned().unwrap_or_else(|| format!("column_{}", i + 1)))
+                .collect();
+
+            let mut matched_columns = Vec::new();
+            for (header, field) in names.iter().zip(fields.iter()) {
+                if let Some(ref cols) = columns {
+                    if !cols.contains(header) {
+                        continue;
+                    }
+                }
+                let haystack = field.to_lowercase();
+                if haystack.contains(&needle)
+                    || (fuzzy && fuzzy_contains(&haystack, &needle, self.fuzzy_threshold))
+                {
+                    matched_columns.push(header.clone());
+                }
+            }
+
+            if !matched_columns.is_empty() {
+                let row: serde_json::Map<String, Value> = names
+                    .iter()
+                    .zip(fields.into_iter().map(Value::String))
+                    .map(|(h, v)| (h.clone(), v))
+                    .collect();
+                results.push(serde_json::json!({
+                    "row_number": row_number,
+                    "matched_columns": matched_columns,
+                    "row": row,
+                }));
+            }
+        }
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "query": query,
+            "columns": headers,
+            "results": results,
+            "count": results.len(),
+            "skipped_rows": skipped_rows,
+            "ragged_rows": ragged_rows,
+        }))
     }
 }
 
@@ -368,20 +1168,91 @@ impl Default for CsvSearchTool {
     }
 }
 
+/// Whether every token of `needle` has a similar token in `haystack`.
+///
+/// Both inputs are expected to be lowercased already. Similarity is
+/// normalized Levenshtein distance: `1 - distance / max_len`.
+fn fuzzy_contains(haystack: &str, needle: &str, threshold: f64) -> bool {
+    let hay_tokens: Vec<&str> = haystack
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .collect();
+    let mut needle_tokens = needle
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .peekable();
+    if needle_tokens.peek().is_none() {
+        return false;
+    }
+    needle_tokens.all(|n| hay_tokens.iter().any(|h| similarity(h, n) >= threshold))
+}
+
+/// Normalized Levenshtein similarity in [0.0, 1.0].
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    1.0 - prev[b.len()] as f64 / max_len as f64
+}
+
 // ── CodeDocsSearchTool ───────────────────────────────────────────────────────
 
 /// Search through code documentation sites.
 ///
+/// Pages are discovered from the site's `sitemap.xml` when one exists,
+/// otherwise by following links that stay under `docs_url`'s path prefix.
+/// Each page is split into headings, code blocks and prose; matches in code
+/// blocks count extra when the query looks like an identifier (contains
+/// `::` or `_`, or is camelCase). Every result carries the page URL and the
+/// anchor of the nearest preceding heading.
+///
 /// Corresponds to Python `CodeDocsSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeDocsSearchTool {
     /// URL of the documentation site.
     pub docs_url: Option<String>,
+    /// How many links away from `docs_url` to follow when there is no sitemap.
+    pub max_depth: usize,
+    /// Maximum number of pages to fetch.
+    pub max_pages: usize,
+    /// Pause between requests, in milliseconds.
+    pub crawl_delay_ms: u64,
+    /// Characters of context on each side of a prose hit.
+    pub snippet_chars: usize,
+    /// Maximum number of results to return.
+    pub max_results: usize,
 }
 
+/// Weight of a code-block match when the query looks like an identifier.
+const CODE_IDENTIFIER_WEIGHT: f64 = 3.0;
+/// Weight of a heading match.
+const HEADING_WEIGHT: f64 = 2.0;
+
 impl CodeDocsSearchTool {
     pub fn new() -> Self {
-        Self { docs_url: None }
+        Self {
+            docs_url: None,
+            max_depth: 2,
+            max_pages: 50,
+            crawl_delay_ms: 250,
+            snippet_chars: 120,
+            max_results: 10,
+        }
     }
 
     pub fn with_docs_url(mut self, url: impl Into<String>) -> Self {
@@ -389,37 +1260,348 @@ impl CodeDocsSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "CodeDocsSearchTool: not yet implemented - requires documentation scraping and search"
-        )
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
     }
-}
 
-impl Default for CodeDocsSearchTool {
-    fn default() -> Self {
-        Self::new()
+    pub fn with_max_pages(mut self, n: usize) -> Self {
+        self.max_pages = n;
+        self
     }
-}
 
-// ── DirectorySearchTool ──────────────────────────────────────────────────────
-
-/// Search through files in a directory using semantic search.
-///
-/// Corresponds to Python `DirectorySearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DirectorySearchTool {
-    /// Path to the directory to search.
-    pub directory: Option<String>,
-}
+    pub fn with_crawl_delay_ms(mut self, ms: u64) -> Self {
+        self.crawl_delay_ms = ms;
+        self
+    }
 
-impl DirectorySearchTool {
-    pub fn new() -> Self {
-        Self { directory: None }
+    pub fn with_snippet_chars(mut self, n: usize) -> Self {
+        self.snippet_chars = n;
+        self
     }
 
-    pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
-        self.directory = Some(dir.into());
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Search a documentation site.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - The text or identifier to look for.
+    /// * `docs_url` - Root URL of the docs (optional if set on struct).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let docs_url = args
+            .get("docs_url")
+            .and_then(|v| v.as_str())
+            .or(self.docs_url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: docs_url"))?;
+
+        let root = reqwest::Url::parse(docs_url)
+            .map_err(|e| anyhow::anyhow!("Invalid docs_url '{}': {}", docs_url, e))?;
+        let prefix = docs_path_prefix(&root);
+        let in_scope = |u: &reqwest::Url| u.origin() == root.origin() && u.path().starts_with(&prefix);
+
+        let sitemap_urls = sitemap_urls(&root, &in_scope, self.max_pages);
+        let discovery = if sitemap_urls.is_empty() { "links" } else { "sitemap" };
+        let (pages, skipped) = if sitemap_urls.is_empty() {
+            crawl_site(vec![root.clone()], &in_scope, self.max_depth, self.max_pages, self.crawl_delay_ms)?
+        } else {
+            crawl_site(sitemap_urls, &in_scope, 0, self.max_pages, self.crawl_delay_ms)?
+        };
+
+        let needle = query.to_lowercase();
+        let identifier = looks_like_identifier(query);
+        let mut scored: Vec<(f64, Value)> = Vec::new();
+        for page in &pages {
+            for section in doc_sections(&page.html) {
+                let lowered = section.text.to_lowercase();
+                let count = lowered.matches(&needle).count();
+                if count == 0 {
+                    continue;
+                }
+                let weight = match section.kind {
+                    "code" if identifier => CODE_IDENTIFIER_WEIGHT,
+                    "heading" => HEADING_WEIGHT,
+                    _ => 1.0,
+                };
+                let snippet = if section.kind == "code" {
+                    section.text.clone()
+                } else {
+                    find_snippets(&section.text, &needle, self.snippet_chars)
+                        .into_iter()
+                        .next()
+                        .unwrap_or_default()
+                };
+                let url = match &section.anchor {
+                    Some(anchor) => format!("{}#{}", page.url, anchor),
+                    None => page.url.clone(),
+                };
+                scored.push((
+                    count as f64 * weight,
+                    serde_json::json!({
+                        "url": url,
+                        "page_url": page.url,
+                        "anchor": section.anchor,
+                        "heading": section.heading,
+                        "kind": section.kind,
+                        "language": section.language,
+                        "matches": count,
+                        "snippet": snippet,
+                    }),
+                ));
+            }
+        }
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        let total = scored.len();
+        let results: Vec<Value> = scored
+            .into_iter()
+            .take(self.max_results)
+            .map(|(score, mut v)| {
+                v["score"] = serde_json::json!(score);
+                v
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "docs_url": docs_url,
+            "query": query,
+            "identifier_query": identifier,
+            "discovery": discovery,
+            "pages_crawled": pages.len(),
+            "pages_skipped": skipped,
+            "count": results.len(),
+            "truncated": total > results.len(),
+            "results": results,
+        }))
+    }
+}
+
+impl Default for CodeDocsSearchTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A heading, code block or run of prose from a documentation page.
+#[derive(Debug, Clone)]
+struct DocSection {
+    /// `"heading"`, `"code"` or `"prose"`.
+    kind: &'static str,
+    text: String,
+    /// Title of the nearest heading at or before this section.
+    heading: Option<String>,
+    /// Fragment identifier of that heading.
+    anchor: Option<String>,
+    /// Language of a code block, from its class.
+    language: Option<String>,
+}
+
+/// Whether a query is probably a code identifier rather than prose.
+fn looks_like_identifier(query: &str) -> bool {
+    let q = query.trim();
+    if q.contains(char::is_whitespace) {
+        return false;
+    }
+    let camel = q
+        .chars()
+        .zip(q.chars().skip(1))
+        .any(|(a, b)| a.is_lowercase() && b.is_uppercase());
+    q.contains("::") || q.contains('_') || camel
+}
+
+/// The directory part of `url`'s path, which crawled pages must stay under.
+fn docs_path_prefix(url: &reqwest::Url) -> String {
+    let path = url.path();
+    match path.rfind('/') {
+        Some(i) => path[..=i].to_string(),
+        None => "/".to_string(),
+    }
+}
+
+/// Page URLs listed in the site's sitemap, trying `sitemap.xml` under the
+/// docs prefix and then at the site root. Sitemap indexes are followed one
+/// level deep. Returns an empty list when there is no usable sitemap.
+fn sitemap_urls(
+    root: &reqwest::Url,
+    in_scope: &dyn Fn(&reqwest::Url) -> bool,
+    limit: usize,
+) -> Vec<reqwest::Url> {
+    let Ok(client) = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent("Mozilla/5.0 (compatible; CrewAI/1.0)")
+        .build()
+    else {
+        return Vec::new();
+    };
+    let Ok(loc_re) = regex::Regex::new(r"(?is)<loc>\s*(.*?)\s*</loc>") else {
+        return Vec::new();
+    };
+    let fetch_locs = |url: &reqwest::Url| -> Vec<String> {
+        match client.get(url.clone()).send() {
+            Ok(r) if r.status().is_success() => r
+                .text()
+                .map(|body| loc_re.captures_iter(&body).map(|c| c[1].replace("&amp;", "&")).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    };
+
+    let candidates = [
+        root.join(&format!("{}sitemap.xml", docs_path_prefix(root))),
+        root.join("/sitemap.xml"),
+    ];
+    for candidate in candidates.into_iter().flatten() {
+        let mut urls = Vec::new();
+        for loc in fetch_locs(&candidate) {
+            let Ok(url) = reqwest::Url::parse(&loc) else {
+                continue;
+            };
+            if url.path().ends_with(".xml") {
+                // A sitemap index: expand nested sitemaps.
+                for nested in fetch_locs(&url) {
+                    if let Ok(u) = reqwest::Url::parse(&nested) {
+                        urls.push(u);
+                    }
+                }
+            } else {
+                urls.push(url);
+            }
+            if urls.len() >= limit * 4 {
+                break;
+            }
+        }
+        urls.retain(|u| in_scope(u));
+        urls.truncate(limit);
+        if !urls.is_empty() {
+            return urls;
+        }
+    }
+    Vec::new()
+}
+
+/// Split a documentation page into headings, code blocks and prose, in
+/// document order.
+fn doc_sections(html: &str) -> Vec<DocSection> {
+    let chrome = regex::Regex::new(r"(?is)<(nav|header|footer|aside)\b[^>]*>.*?</(nav|header|footer|aside)>").unwrap();
+    let structure =
+        regex::Regex::new(r"(?is)<h([1-6])\b([^>]*)>(.*?)</h[1-6]>|<pre\b([^>]*)>(.*?)</pre>").unwrap();
+    let id_attr = regex::Regex::new(r#"(?i)\b(?:id|name)\s*=\s*["']([^"']+)["']"#).unwrap();
+    let self_href = regex::Regex::new(r#"(?i)href\s*=\s*["']#([^"']+)["']"#).unwrap();
+    let lang_class = regex::Regex::new(r#"(?i)(?:language|lang|highlight-source)-([a-z0-9_+-]+)"#).unwrap();
+    let tags = regex::Regex::new(r"<[^>]+>").unwrap();
+
+    let body = chrome.replace_all(html, " ");
+    let mut sections = Vec::new();
+    let mut heading: Option<String> = None;
+    let mut anchor: Option<String> = None;
+    let mut last = 0;
+
+    let push_prose = |raw: &str, heading: &Option<String>, anchor: &Option<String>, out: &mut Vec<DocSection>| {
+        let text = html_to_text(raw);
+        if !text.is_empty() {
+            out.push(DocSection {
+                kind: "prose",
+                text,
+                heading: heading.clone(),
+                anchor: anchor.clone(),
+                language: None,
+            });
+        }
+    };
+
+    for cap in structure.captures_iter(&body) {
+        let whole = cap.get(0).unwrap();
+        push_prose(&body[last..whole.start()], &heading, &anchor, &mut sections);
+        last = whole.end();
+
+        if let Some(inner) = cap.get(3) {
+            let title = html_to_text(inner.as_str());
+            if title.is_empty() {
+                continue;
+            }
+            let attrs = cap.get(2).map_or("", |m| m.as_str());
+            anchor = id_attr
+                .captures(attrs)
+                .or_else(|| id_attr.captures(inner.as_str()))
+                .or_else(|| self_href.captures(inner.as_str()))
+                .map(|c| c[1].to_string())
+                .or_else(|| Some(slugify(&title)));
+            heading = Some(title.clone());
+            sections.push(DocSection {
+                kind: "heading",
+                text: title,
+                heading: heading.clone(),
+                anchor: anchor.clone(),
+                language: None,
+            });
+        } else if let Some(code) = cap.get(5) {
+            // Keep line breaks in code; only tags and entities are removed.
+            let text = decode_basic_entities(&tags.replace_all(code.as_str(), ""));
+            if text.trim().is_empty() {
+                continue;
+            }
+            let attrs = format!("{} {}", cap.get(4).map_or("", |m| m.as_str()), code.as_str());
+            sections.push(DocSection {
+                kind: "code",
+                text: text.trim_matches('\n').to_string(),
+                heading: heading.clone(),
+                anchor: anchor.clone(),
+                language: lang_class.captures(&attrs).map(|c| c[1].to_lowercase()),
+            });
+        }
+    }
+    push_prose(&body[last..], &heading, &anchor, &mut sections);
+    sections
+}
+
+/// GitHub-style heading slug: lowercase, spaces to hyphens, punctuation dropped.
+fn slugify(title: &str) -> String {
+    title
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() => Some(c.to_ascii_lowercase()),
+            ' ' | '-' => Some('-'),
+            '_' => Some('_'),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Decode the handful of entities that matter in text and code.
+fn decode_basic_entities(s: &str) -> String {
+    s.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+// ── DirectorySearchTool ──────────────────────────────────────────────────────
+
+/// Search through files in a directory using semantic search.
+///
+/// Corresponds to Python `DirectorySearchTool` in `crewai_tools`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectorySearchTool {
+    /// Path to the directory to search.
+    pub directory: Option<String>,
+}
+
+impl DirectorySearchTool {
+    pub fn new() -> Self {
+        Self { directory: None }
+    }
+
+    pub fn with_directory(mut self, dir: impl Into<String>) -> Self {
+        self.directory = Some(dir.into());
         self
     }
 
@@ -440,16 +1622,25 @@ impl Default for DirectorySearchTool {
 
 /// Search within DOCX (Microsoft Word) files.
 ///
+/// A `.docx` is a zip archive whose body lives in `word/document.xml`. The
+/// tool extracts paragraph and table-cell text in document order and tracks
+/// the most recent heading so each match can say which section it is in.
+///
 /// Corresponds to Python `DOCXSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocxSearchTool {
     /// Path to the DOCX file.
     pub file_path: Option<String>,
+    /// Maximum number of matches to return.
+    pub max_results: usize,
 }
 
 impl DocxSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            max_results: 50,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -457,8 +1648,58 @@ impl DocxSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("DocxSearchTool: not yet implemented - requires DOCX parsing and search")
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Search a DOCX file for paragraphs containing a query.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Case-insensitive text to look for.
+    /// * `file_path` - Path to the DOCX file (optional if set on struct).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+
+        let xml = crate::docx::read(path)?.document;
+        let blocks = docx_blocks(&xml).map_err(|e| anyhow::anyhow!("Malformed DOCX '{}': {}", path, e))?;
+
+        let needle = query.to_lowercase();
+        let matches: Vec<&DocxBlock> = blocks
+            .iter()
+            .filter(|b| b.text.to_lowercase().contains(&needle))
+            .collect();
+        let results: Vec<Value> = matches
+            .iter()
+            .take(self.max_results)
+            .map(|b| {
+                serde_json::json!({
+                    "index": b.index,
+                    "kind": b.kind,
+                    "text": b.text,
+                    "heading": b.heading,
+                    "heading_level": b.heading_level,
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "query": query,
+            "paragraphs": blocks.len(),
+            "count": results.len(),
+            "truncated": matches.len() > results.len(),
+            "results": results,
+        }))
     }
 }
 
@@ -468,10 +1709,84 @@ impl Default for DocxSearchTool {
     }
 }
 
+/// A paragraph or table cell extracted from a DOCX body.
+#[derive(Debug, Clone)]
+struct DocxBlock {
+    /// Position in document order, counting paragraphs and table cells.
+    index: usize,
+    /// `"paragraph"`, `"heading"` or `"table_cell"`.
+    kind: &'static str,
+    text: String,
+    /// Text of the nearest preceding heading, if any.
+    heading: Option<String>,
+    heading_level: Option<u32>,
+}
+
+/// Split a WordprocessingML body into paragraphs and table cells.
+fn docx_blocks(xml: &str) -> Result<Vec<DocxBlock>, roxmltree::Error> {
+    let doc = roxmltree::Document::parse(xml)?;
+    let mut blocks = Vec::new();
+    let mut heading: Option<(String, u32)> = None;
+
+    let Some(body) = doc.descendants().find(|n| docx::is_element_named(n, "body")) else {
+        return Ok(blocks);
+    };
+
+    for child in body.children().filter(|n| n.is_element()) {
+        match child.tag_name().name() {
+            "p" => {
+                let text = docx::paragraph_text(&child);
+                if text.trim().is_empty() {
+                    continue;
+                }
+                let level = docx::heading_level(&child);
+                if let Some(level) = level {
+                    heading = Some((text.trim().to_string(), level));
+                }
+                blocks.push(DocxBlock {
+                    index: blocks.len(),
+                    kind: if level.is_some() { "heading" } else { "paragraph" },
+                    text,
+                    heading: heading.as_ref().map(|(t, _)| t.clone()),
+                    heading_level: heading.as_ref().map(|(_, l)| *l),
+                });
+            }
+            "tbl" => {
+                for cell in child.descendants().filter(|n| docx::is_element_named(n, "tc")) {
+                    let text: Vec<String> = cell
+                        .children()
+                        .filter(|n| docx::is_element_named(n, "p"))
+                        .map(|p| docx::paragraph_text(&p))
+                        .filter(|t| !t.trim().is_empty())
+                        .collect();
+                    if text.is_empty() {
+                        continue;
+                    }
+                    blocks.push(DocxBlock {
+                        index: blocks.len(),
+                        kind: "table_cell",
+                        text: text.join("\n"),
+                        heading: heading.as_ref().map(|(t, _)| t.clone()),
+                        heading_level: heading.as_ref().map(|(_, l)| *l),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(blocks)
+}
+
 // ── GithubSearchTool ─────────────────────────────────────────────────────────
 
 /// Search within GitHub repositories (code, issues, PRs, etc.).
 ///
+/// Code, issues and pull requests go through the REST search endpoints;
+/// discussions through the GraphQL API, which requires a token. Results of
+/// every content type are merged into one list. When a rate limit is hit
+/// the types searched so far are still returned, with the limit and its
+/// reset time reported under `rate_limit`.
+///
 /// Corresponds to Python `GithubSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GithubSearchTool {
@@ -481,6 +1796,48 @@ pub struct GithubSearchTool {
     pub repository: Option<String>,
     /// Content types to search: "code", "issues", "pulls", "discussions".
     pub content_types: Vec<String>,
+    /// Maximum number of results per content type.
+    #[serde(default = "default_github_max_results")]
+    pub max_results: usize,
+    /// Override of the API endpoint (for GitHub Enterprise and testing).
+    pub base_url: Option<String>,
+}
+
+fn default_github_max_results() -> usize {
+    30
+}
+
+const GITHUB_API: &str = "https://api.github.com";
+
+/// Rate-limit state from GitHub's `x-ratelimit-*` headers.
+#[derive(Debug, Clone, Default, Serialize)]
+struct GithubRateLimit {
+    resource: Option<String>,
+    limit: Option<u64>,
+    remaining: Option<u64>,
+    /// Unix time at which the window resets.
+    reset: Option<u64>,
+    exhausted: bool,
+}
+
+impl GithubRateLimit {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+        let num = |name: &str| get(name).and_then(|v| v.parse().ok());
+        Self {
+            resource: get("x-ratelimit-resource").map(String::from),
+            limit: num("x-ratelimit-limit"),
+            remaining: num("x-ratelimit-remaining"),
+            reset: num("x-ratelimit-reset"),
+            exhausted: false,
+        }
+    }
+}
+
+/// One page of results, or the rate limit that stopped us.
+enum GithubPage {
+    Ok(Value),
+    RateLimited,
 }
 
 impl GithubSearchTool {
@@ -489,6 +1846,8 @@ impl GithubSearchTool {
             github_token: None,
             repository: None,
             content_types: vec!["code".to_string()],
+            max_results: default_github_max_results(),
+            base_url: None,
         }
     }
 
@@ -507,8 +1866,199 @@ impl GithubSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("GithubSearchTool: not yet implemented - requires GitHub API integration")
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    fn api_base(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(GITHUB_API).trim_end_matches('/')
+    }
+
+    /// Search a repository.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - The search terms (GitHub search qualifiers allowed).
+    /// * `repository` - "owner/repo" (optional if set on struct).
+    /// * `content_types` - Array overriding the struct's content types.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let repository = args
+            .get("repository")
+            .and_then(|v| v.as_str())
+            .or(self.repository.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: repository"))?;
+
+        let content_types: Vec<String> = match args.get("content_types").and_then(|v| v.as_array()) {
+            Some(types) => types.iter().filter_map(|t| t.as_str().map(String::from)).collect(),
+            None => self.content_types.clone(),
+        };
+
+        let token = self
+            .github_token
+            .clone()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok());
+
+        for content_type in &content_types {
+            match content_type.as_str() {
+                "code" | "issues" | "pulls" => {}
+                "discussions" if token.is_none() => {
+                    anyhow::bail!("Searching discussions uses the GraphQL API and requires GITHUB_TOKEN")
+                }
+                "discussions" => {}
+                other => anyhow::bail!(
+                    "Unsupported content type '{}': expected code, issues, pulls or discussions",
+                    other
+                ),
+            }
+        }
+        if token.is_none() && content_types.iter().any(|t| t == "code") {
+            anyhow::bail!("GitHub code search requires authentication: set GITHUB_TOKEN");
+        }
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .user_agent("crewai-tools")
+            .build()?;
+
+        let mut results = Vec::new();
+        let mut rate_limit = GithubRateLimit::default();
+        let mut searched = Vec::new();
+        for content_type in &content_types {
+            if rate_limit.exhausted {
+                break;
+            }
+            let found = match content_type.as_str() {
+                "discussions" => {
+                    let token = token.as_deref().unwrap_or_default();
+                    self.search_discussions(&client, token, query, repository, &mut rate_limit)?
+                }
+                other => {
+                    self.search_rest(&client, token.as_deref(), other, query, repository, &mut rate_limit)?
+                }
+            };
+            results.extend(found);
+            searched.push(content_type.clone());
+        }
+
+        Ok(serde_json::json!({
+            "repository": repository,
+            "query": query,
+            "content_types": searched,
+            "count": results.len(),
+            "results": results,
+            "rate_limit": rate_limit,
+        }))
+    }
+
+    /// Page through a REST search endpoint up to `max_results` items.
+    fn search_rest(
+        &self,
+        client: &reqwest::blocking::Client,
+        token: Option<&str>,
+        content_type: &str,
+        query: &str,
+        repository: &str,
+        rate_limit: &mut GithubRateLimit,
+    ) -> Result<Vec<Value>, anyhow::Error> {
+        let (endpoint, q) = match content_type {
+            "code" => ("code", format!("{} repo:{}", query, repository)),
+            "issues" => ("issues", format!("{} repo:{} is:issue", query, repository)),
+            _ => ("issues", format!("{} repo:{} is:pr", query, repository)),
+        };
+        let per_page = self.max_results.clamp(1, 100);
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        while items.len() < self.max_results {
+            let request = client
+                .get(format!("{}/search/{}", self.api_base(), endpoint))
+                .query(&[("q", q.as_str()), ("per_page", &per_page.to_string()), ("page", &page.to_string())])
+                .header(reqwest::header::ACCEPT, "application/vnd.github.text-match+json");
+            let body = match github_send(request, token, rate_limit)? {
+                GithubPage::Ok(body) => body,
+                GithubPage::RateLimited => break,
+            };
+            let batch = body["items"].as_array().cloned().unwrap_or_default();
+            let batch_len = batch.len();
+            for item in batch {
+                if items.len() >= self.max_results {
+                    break;
+                }
+                items.push(github_item(content_type, &item));
+            }
+            let total = body["total_count"].as_u64().unwrap_or(0) as usize;
+            // The search API serves at most 1000 results.
+            if batch_len < per_page || page * per_page >= total.min(1000) {
+                break;
+            }
+            page += 1;
+        }
+        Ok(items)
+    }
+
+    /// Search discussions through the GraphQL API.
+    fn search_discussions(
+        &self,
+        client: &reqwest::blocking::Client,
+        token: &str,
+        query: &str,
+        repository: &str,
+        rate_limit: &mut GithubRateLimit,
+    ) -> Result<Vec<Value>, anyhow::Error> {
+        const GRAPHQL: &str = "query($q: String!, $first: Int!, $after: String) {
+            search(query: $q, type: DISCUSSION, first: $first, after: $after) {
+                pageInfo { hasNextPage endCursor }
+                nodes { ... on Discussion { title url number bodyText } }
+            }
+        }";
+        let q = format!("{} repo:{}", query, repository);
+        let mut items = Vec::new();
+        let mut after: Option<String> = None;
+
+        while items.len() < self.max_results {
+            let first = (self.max_results - items.len()).min(100);
+            let request = client.post(format!("{}/graphql", self.api_base())).json(&serde_json::json!({
+                "query": GRAPHQL,
+                "variables": {"q": q, "first": first, "after": after},
+            }));
+            let body = match github_send(request, Some(token), rate_limit)? {
+                GithubPage::Ok(body) => body,
+                GithubPage::RateLimited => break,
+            };
+            if let Some(errors) = body["errors"].as_array().filter(|e| !e.is_empty()) {
+                anyhow::bail!("GitHub GraphQL search failed: {}", Value::Array(errors.clone()));
+            }
+            let search = &body["data"]["search"];
+            for node in search["nodes"].as_array().into_iter().flatten() {
+                if node["url"].is_null() {
+                    continue;
+                }
+                items.push(serde_json::json!({
+                    "type": "discussion",
+                    "title": node["title"],
+                    "number": node["number"],
+                    "url": node["url"],
+                    "snippet": truncate_chars(node["bodyText"].as_str().unwrap_or_default(), 200),
+                }));
+            }
+            match search["pageInfo"]["endCursor"].as_str() {
+                Some(cursor) if search["pageInfo"]["hasNextPage"].as_bool() == Some(true) => {
+                    after = Some(cursor.to_string());
+                }
+                _ => break,
+            }
+        }
+        Ok(items)
     }
 }
 
@@ -518,6 +2068,73 @@ impl Default for GithubSearchTool {
     }
 }
 
+/// Send a GitHub API request, recording rate-limit headers. A 403/429 with
+/// no remaining quota is reported as `RateLimited` rather than an error.
+fn github_send(
+    mut request: reqwest::blocking::RequestBuilder,
+    token: Option<&str>,
+    rate_limit: &mut GithubRateLimit,
+) -> Result<GithubPage, anyhow::Error> {
+    request = request.header("X-GitHub-Api-Version", "2022-11-28");
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send()?;
+    let status = response.status();
+    *rate_limit = GithubRateLimit::from_headers(response.headers());
+
+    let limited = matches!(status.as_u16(), 403 | 429)
+        && (rate_limit.remaining == Some(0) || response.headers().contains_key("retry-after"));
+    if limited {
+        rate_limit.exhausted = true;
+        log::warn!(
+            "GitHub rate limit exhausted ({}); resets at {:?}",
+            rate_limit.resource.as_deref().unwrap_or("unknown"),
+            rate_limit.reset
+        );
+        return Ok(GithubPage::RateLimited);
+    }
+    if !status.is_success() {
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("GitHub API error ({}): {}", status, body);
+    }
+    Ok(GithubPage::Ok(response.json()?))
+}
+
+/// Normalize a REST search item into `{type, title, path, url, snippet}`.
+fn github_item(content_type: &str, item: &Value) -> Value {
+    let fragment = item["text_matches"]
+        .as_array()
+        .and_then(|m| m.first())
+        .and_then(|m| m["fragment"].as_str())
+        .map(String::from);
+    match content_type {
+        "code" => serde_json::json!({
+            "type": "code",
+            "title": item["name"],
+            "path": item["path"],
+            "url": item["html_url"],
+            "snippet": fragment,
+        }),
+        _ => serde_json::json!({
+            "type": if content_type == "pulls" { "pull_request" } else { "issue" },
+            "title": item["title"],
+            "number": item["number"],
+            "state": item["state"],
+            "url": item["html_url"],
+            "snippet": fragment.unwrap_or_else(|| truncate_chars(item["body"].as_str().unwrap_or_default(), 200)),
+        }),
+    }
+}
+
+/// The first `max` characters of `s`, with an ellipsis if cut.
+fn truncate_chars(s: &str, max: usize) -> String {
+    match s.char_indices().nth(max) {
+        Some((i, _)) => format!("{}…", &s[..i]),
+        None => s.to_string(),
+    }
+}
+
 // ── JsonSearchTool ───────────────────────────────────────────────────────────
 
 /// Search within JSON files using semantic or keyword search.
@@ -554,16 +2171,26 @@ impl Default for JsonSearchTool {
 
 /// Search within MDX (Markdown with JSX) files.
 ///
+/// YAML frontmatter, ESM `import`/`export` lines and JSX component tags are
+/// stripped before searching; the markdown between tags is kept. Each match
+/// records the heading hierarchy it falls under, and matches inside fenced
+/// code blocks are flagged as code.
+///
 /// Corresponds to Python `MDXSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MdxSearchTool {
     /// Path to the MDX file or directory.
     pub file_path: Option<String>,
+    /// Maximum number of matches to return across all files.
+    pub max_results: usize,
 }
 
 impl MdxSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            max_results: 50,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -571,8 +2198,78 @@ impl MdxSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("MdxSearchTool: not yet implemented - requires MDX parsing and search")
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Search MDX content for a query.
+    ///
+    /// When `file_path` is a directory, every `.mdx` and `.md` file beneath
+    /// it is searched, skipping hidden directories.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Case-insensitive text to look for.
+    /// * `file_path` - Path to an MDX file or directory (optional if set on struct).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+
+        let root = std::path::Path::new(path);
+        let files = if root.is_dir() {
+            let mut files = Vec::new();
+            collect_markdown_files(root, &mut files)
+                .map_err(|e| anyhow::anyhow!("Failed to read directory '{}': {}", path, e))?;
+            files.sort();
+            files
+        } else {
+            vec![root.to_path_buf()]
+        };
+
+        let needle = query.to_lowercase();
+        let mut results = Vec::new();
+        let mut total = 0usize;
+        for file in &files {
+            let source = std::fs::read_to_string(file)
+                .map_err(|e| anyhow::anyhow!("Failed to read '{}': {}", file.display(), e))?;
+            for block in mdx_blocks(&source) {
+                if !block.text.to_lowercase().contains(&needle) {
+                    continue;
+                }
+                total += 1;
+                if results.len() >= self.max_results {
+                    continue;
+                }
+                results.push(serde_json::json!({
+                    "file_path": file.display().to_string(),
+                    "line": block.line,
+                    "text": block.text,
+                    "metadata": {
+                        "heading_path": block.headings.join(" > "),
+                        "headings": block.headings,
+                        "is_code": block.is_code,
+                        "code_language": block.code_language,
+                    },
+                }));
+            }
+        }
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "query": query,
+            "files_searched": files.len(),
+            "count": results.len(),
+            "truncated": total > results.len(),
+            "results": results,
+        }))
     }
 }
 
@@ -582,20 +2279,185 @@ impl Default for MdxSearchTool {
     }
 }
 
+/// A paragraph, heading or code fence from an MDX document.
+#[derive(Debug, Clone)]
+struct MdxBlock {
+    /// 1-based line on which the block starts.
+    line: usize,
+    text: String,
+    /// Enclosing headings, outermost first. A heading block includes itself.
+    headings: Vec<String>,
+    is_code: bool,
+    code_language: Option<String>,
+}
+
+/// Recursively gather `.mdx` and `.md` files, skipping hidden directories.
+fn collect_markdown_files(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if path.is_dir() {
+            if !hidden {
+                collect_markdown_files(&path, out)?;
+            }
+        } else if matches!(path.extension().and_then(|e| e.to_str()), Some("mdx" | "md")) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Split MDX source into searchable blocks.
+fn mdx_blocks(source: &str) -> Vec<MdxBlock> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut i = skip_frontmatter(&lines);
+
+    let mut blocks = Vec::new();
+    // (level, title) of every open heading.
+    let mut headings: Vec<(usize, String)> = Vec::new();
+    let heading_titles = |h: &[(usize, String)]| h.iter().map(|(_, t)| t.clone()).collect::<Vec<_>>();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut paragraph_line = 0;
+    let mut in_tag = JsxState::default();
+
+    let flush = |paragraph: &mut Vec<String>, line: usize, headings: &[(usize, String)], blocks: &mut Vec<MdxBlock>| {
+        let text = paragraph.join("\n").trim().to_string();
+        paragraph.clear();
+        if !text.is_empty() {
+            blocks.push(MdxBlock {
+                line,
+                text,
+                headings: heading_titles(headings),
+                is_code: false,
+                code_language: None,
+            });
+        }
+    };
+
+    while i < lines.len() {
+        let raw = lines[i];
+        let trimmed = raw.trim_start();
+
+        if let Some(fence) = code_fence(trimmed) {
+            flush(&mut paragraph, paragraph_line, &headings, &mut blocks);
+            let lang = trimmed[fence.len()..].trim();
+            let start = i + 1;
+            let mut end = start;
+            while end < lines.len() && !lines[end].trim_start().starts_with(fence) {
+                end += 1;
+            }
+            blocks.push(MdxBlock {
+                line: i + 1,
+                text: lines[start..end].join("\n"),
+                headings: heading_titles(&headings),
+                is_code: true,
+                code_language: (!lang.is_empty()).then(|| lang.to_string()),
+            });
+            i = end + 1;
+            continue;
+        }
+
+        if !in_tag.open && (trimmed.starts_with("import ") || trimmed.starts_with("export ")) {
+            flush(&mut paragraph, paragraph_line, &headings, &mut blocks);
+            i += 1;
+            continue;
+        }
+
+        let line = strip_jsx(raw, &mut in_tag);
+        let content = line.trim();
+
+        if let Some((level, title)) = atx_heading(content) {
+            flush(&mut paragraph, paragraph_line, &headings, &mut blocks);
+            headings.retain(|(l, _)| *l < level);
+            headings.push((level, title.clone()));
+            blocks.push(MdxBlock {
+                line: i + 1,
+                text: title,
+                headings: heading_titles(&headings),
+                is_code: false,
+                code_language: None,
+            });
+        } else if content.is_empty() {
+            flush(&mut paragraph, paragraph_line, &headings, &mut blocks);
+        } else {
+            if paragraph.is_empty() {
+                paragraph_line = i + 1;
+            }
+            paragraph.push(content.to_string());
+        }
+        i += 1;
+    }
+    flush(&mut paragraph, paragraph_line, &headings, &mut blocks);
+    blocks
+}
+
+/// Whether we are inside a JSX tag that spans lines, and how deep in `{}`.
+#[derive(Debug, Default)]
+struct JsxState {
+    open: bool,
+    braces: usize,
+}
+
+/// Remove JSX component tags (`<Tabs>`, `</Tabs>`, `<Note type="x" />`,
+/// fragments) from a line, keeping the text between them. Lowercase HTML
+/// tags are left alone since they are valid markdown.
+fn strip_jsx(line: &str, state: &mut JsxState) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if state.open {
+            match c {
+                '{' => state.braces += 1,
+                '}' => state.braces = state.braces.saturating_sub(1),
+                '>' if state.braces == 0 => state.open = false,
+                _ => {}
+            }
+            continue;
+        }
+        if c == '<' {
+            let mut lookahead = chars.clone();
+            if lookahead.peek() == Some(&'/') {
+                lookahead.next();
+            }
+            if matches!(lookahead.peek(), Some(n) if n.is_ascii_uppercase() || *n == '>') {
+                state.open = true;
+                state.braces = 0;
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
 // ── PdfSearchTool ────────────────────────────────────────────────────────────
 
-/// Search within PDF documents.
+/// Search within PDF documents, reporting matches by page.
+///
+/// Pages are extracted and searched one at a time, so large PDFs never
+/// have their full text held in memory.
 ///
 /// Corresponds to Python `PDFSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PdfSearchTool {
     /// Path to the PDF file.
     pub file_path: Option<String>,
+    /// Characters of context on each side of a hit.
+    pub snippet_chars: usize,
+    /// Maximum number of hits to return across all pages.
+    pub max_results: usize,
 }
 
 impl PdfSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            snippet_chars: 120,
+            max_results: 50,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -603,8 +2465,89 @@ impl PdfSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("PdfSearchTool: not yet implemented - requires PDF parsing and search")
+    pub fn with_snippet_chars(mut self, n: usize) -> Self {
+        self.snippet_chars = n;
+        self
+    }
+
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Search a PDF for a keyword.
+    ///
+    /// Fails with an error pointing at `OcrTool` when no page has any
+    /// extractable text (a scanned or image-only PDF).
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Case-insensitive text to look for.
+    /// * `file_path` - Path to the PDF file (optional if set on struct).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+
+        let pdf = crate::pdf::PdfPages::open(path)?;
+        let needle = query.to_lowercase();
+
+        let mut pages: Vec<Value> = Vec::new();
+        let mut total_hits = 0usize;
+        let mut returned = 0usize;
+        let mut pages_with_text = 0usize;
+        let mut pages_with_images = 0usize;
+
+        for page in pdf.page_numbers() {
+            let text = pdf.page_text(page);
+            if text.trim().is_empty() {
+                if pdf.page_has_images(page) {
+                    pages_with_images += 1;
+                }
+                continue;
+            }
+            pages_with_text += 1;
+
+            let hits = find_snippets(&text, &needle, self.snippet_chars);
+            if hits.is_empty() {
+                continue;
+            }
+            total_hits += hits.len();
+            let room = self.max_results.saturating_sub(returned);
+            if room == 0 {
+                continue;
+            }
+            let snippets: Vec<Value> = hits.into_iter().take(room).map(Value::String).collect();
+            returned += snippets.len();
+            pages.push(serde_json::json!({
+                "page": page,
+                "matches": snippets.len(),
+                "snippets": snippets,
+            }));
+        }
+
+        if pages_with_text == 0 && pdf.page_count() > 0 {
+            anyhow::bail!(
+                "PDF '{}' has no extractable text{} - it is probably scanned; use OcrTool to extract its text first",
+                path,
+                if pages_with_images > 0 { " (pages contain only images)" } else { "" }
+            );
+        }
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "query": query,
+            "total_pages": pdf.page_count(),
+            "total_matches": total_hits,
+            "truncated": total_hits > returned,
+            "pages": pages,
+        }))
     }
 }
 
@@ -614,20 +2557,69 @@ impl Default for PdfSearchTool {
     }
 }
 
+/// Case-insensitive occurrences of `needle` (already lowercased) in `text`,
+/// each with up to `context` characters on either side and whitespace collapsed.
+fn find_snippets(text: &str, needle: &str, context: usize) -> Vec<String> {
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    // Lowercase per char so offsets map back to the original text even when
+    // lowercasing changes a character's length.
+    let chars: Vec<char> = text.chars().collect();
+    let lowered: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let needle: Vec<char> = needle.chars().collect();
+
+    let mut snippets = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= lowered.len() {
+        if lowered[i..i + needle.len()] == needle[..] {
+            let start = i.saturating_sub(context);
+            let end = (i + needle.len() + context).min(chars.len());
+            let raw: String = chars[start..end].iter().collect();
+            let mut snippet = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+            if start > 0 {
+                snippet.insert(0, '…');
+            }
+            if end < chars.len() {
+                snippet.push('…');
+            }
+            snippets.push(snippet);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    snippets
+}
+
 // ── TxtSearchTool ────────────────────────────────────────────────────────────
 
-/// Search within plain text files.
+/// Search within plain text files, returning matching lines with context.
+///
+/// The file is read line by line, so large logs are searched without being
+/// loaded into memory; only a rolling window of `context_lines` is kept.
 ///
 /// Corresponds to Python `TXTSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TxtSearchTool {
     /// Path to the text file.
     pub file_path: Option<String>,
+    /// Number of lines of context to include before and after each match.
+    pub context_lines: usize,
+    /// Maximum number of matches to return.
+    pub max_results: usize,
 }
 
 impl TxtSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            context_lines: 2,
+            max_results: 50,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -635,8 +2627,136 @@ impl TxtSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("TxtSearchTool: not yet implemented - requires text file search")
+    pub fn with_context_lines(mut self, n: usize) -> Self {
+        self.context_lines = n;
+        self
+    }
+
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Search a text file for matching lines.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Text (or regex, see below) to look for.
+    /// * `file_path` - Path to the text file (optional if set on struct).
+    /// * `regex` - Treat `search_query` as a regular expression (default: false).
+    /// * `case_sensitive` - Match case exactly (default: false).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        use std::collections::VecDeque;
+        use std::io::BufRead;
+
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+
+        let use_regex = args.get("regex").and_then(|v| v.as_bool()).unwrap_or(false);
+        let case_sensitive = args
+            .get("case_sensitive")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let pattern = if use_regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        let matcher = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(!case_sensitive)
+            .build()
+            .map_err(|e| anyhow::anyhow!("Invalid regex '{}': {}", query, e))?;
+
+        let file = std::fs::File::open(path)
+            .map_err(|e| anyhow::anyhow!("Failed to open file '{}': {}", path, e))?;
+        let mut reader = std::io::BufReader::new(file);
+
+        let ctx = self.context_lines;
+        let mut before: VecDeque<(usize, String)> = VecDeque::with_capacity(ctx + 1);
+        // Matches still collecting their trailing context: (result, lines still needed).
+        let mut open: Vec<(serde_json::Map<String, Value>, usize)> = Vec::new();
+        let mut results: Vec<Value> = Vec::new();
+        let mut total_matches = 0usize;
+        let mut buf = Vec::new();
+        let mut line_number = 0usize;
+
+        loop {
+            buf.clear();
+            let read = reader
+                .read_until(b'\n', &mut buf)
+                .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?;
+            if read == 0 {
+                break;
+            }
+            line_number += 1;
+            let line = String::from_utf8_lossy(&buf)
+                .trim_end_matches(['\n', '\r'])
+                .to_string();
+
+            // Feed this line as trailing context to earlier matches.
+            for (result, needed) in open.iter_mut() {
+                if let Some(Value::Array(after)) = result.get_mut("context_after") {
+                    after.push(serde_json::json!({ "line_number": line_number, "text": line }));
+                }
+                *needed -= 1;
+            }
+            let (done, still_open): (Vec<_>, Vec<_>) = open.into_iter().partition(|(_, n)| *n == 0);
+            results.extend(done.into_iter().map(|(r, _)| Value::Object(r)));
+            open = still_open;
+
+            if matcher.is_match(&line) {
+                total_matches += 1;
+                if results.len() + open.len() < self.max_results {
+                    let mut result = serde_json::Map::new();
+                    result.insert("line_number".into(), serde_json::json!(line_number));
+                    result.insert("line".into(), Value::String(line.clone()));
+                    result.insert(
+                        "context_before".into(),
+                        Value::Array(
+                            before
+                                .iter()
+                                .map(|(n, t)| serde_json::json!({ "line_number": n, "text": t }))
+                                .collect(),
+                        ),
+                    );
+                    result.insert("context_after".into(), Value::Array(Vec::new()));
+                    if ctx == 0 {
+                        results.push(Value::Object(result));
+                    } else {
+                        open.push((result, ctx));
+                    }
+                } else if open.is_empty() {
+                    // Result cap reached and all context collected; stop
+                    // reading the rest of the file.
+                    break;
+                }
+            }
+
+            if ctx > 0 {
+                if before.len() == ctx {
+                    before.pop_front();
+                }
+                before.push_back((line_number, line));
+            }
+        }
+        results.extend(open.into_iter().map(|(r, _)| Value::Object(r)));
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "query": query,
+            "regex": use_regex,
+            "results": results,
+            "count": results.len(),
+            "truncated": total_matches > results.len(),
+        }))
     }
 }
 
@@ -648,18 +2768,27 @@ impl Default for TxtSearchTool {
 
 // ── XmlSearchTool ────────────────────────────────────────────────────────────
 
-/// Search within XML files.
+/// Search within XML files, by XPath and/or by text.
+///
+/// Element names match on local name, so namespace prefixes in either the
+/// document or the XPath can be ignored. See the `xpath` module for the
+/// supported expression subset.
 ///
 /// Corresponds to Python `XMLSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct XmlSearchTool {
     /// Path to the XML file.
     pub file_path: Option<String>,
+    /// Maximum number of matches to return.
+    pub max_results: usize,
 }
 
 impl XmlSearchTool {
     pub fn new() -> Self {
-        Self { file_path: None }
+        Self {
+            file_path: None,
+            max_results: 50,
+        }
     }
 
     pub fn with_file_path(mut self, path: impl Into<String>) -> Self {
@@ -667,8 +2796,132 @@ impl XmlSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("XmlSearchTool: not yet implemented - requires XML parsing and search")
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    /// Extract or search content in an XML file.
+    ///
+    /// At least one of `xpath` and `search_query` is required. With both, the
+    /// text search is restricted to the nodes the XPath selects.
+    ///
+    /// # Arguments (in `args`)
+    /// * `file_path` - Path to the XML file (optional if set on struct).
+    /// * `xpath` - XPath expression selecting elements, attributes or text.
+    /// * `search_query` - Case-insensitive text to find in element text and
+    ///   attribute values.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .or(self.file_path.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: file_path"))?;
+        let xpath_expr = args.get("xpath").and_then(|v| v.as_str());
+        let query = args.get("search_query").and_then(|v| v.as_str());
+        if xpath_expr.is_none() && query.is_none() {
+            anyhow::bail!("Missing required argument: xpath or search_query");
+        }
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read file '{}': {}", path, e))?;
+        let doc = roxmltree::Document::parse_with_options(
+            &text,
+            roxmltree::ParsingOptions {
+                allow_dtd: true,
+                ..Default::default()
+            },
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to parse XML '{}': {}", path, e))?;
+
+        let hits = match xpath_expr {
+            Some(expr) => xpath::select(&doc, expr)?,
+            None => vec![xpath::XPathHit::Element(doc.root_element())],
+        };
+
+        let needle = query.map(|q| q.to_lowercase());
+        // Nested selections (e.g. `//*`) would otherwise search a subtree twice.
+        let mut searched = std::collections::HashSet::new();
+        let mut results: Vec<Value> = Vec::new();
+        let mut total = 0usize;
+        let mut push = |result: Value| {
+            total += 1;
+            if results.len() < self.max_results {
+                results.push(result);
+            }
+        };
+
+        for hit in &hits {
+            match (hit, needle.as_deref()) {
+                (xpath::XPathHit::Element(node), None) => push(serde_json::json!({
+                    "path": xpath::element_path(node),
+                    "text": xpath::text_content(node).trim(),
+                })),
+                (xpath::XPathHit::Attribute { element, name, value }, None) => push(serde_json::json!({
+                    "path": format!("{}/@{}", xpath::element_path(element), name),
+                    "text": value,
+                })),
+                (xpath::XPathHit::Text(node), None) => push(serde_json::json!({
+                    "path": format!("{}/text()", node.parent().map(|p| xpath::element_path(&p)).unwrap_or_default()),
+                    "text": node.text().unwrap_or("").trim(),
+                })),
+                (xpath::XPathHit::Element(node), Some(needle)) => {
+                    // Search the element and everything beneath it: direct
+                    // text of each element, then each attribute value.
+                    for el in node.descendants().filter(|n| n.is_element()) {
+                        if !searched.insert(el.id()) {
+                            continue;
+                        }
+                        let own_text: String = el
+                            .children()
+                            .filter(|c| c.is_text())
+                            .filter_map(|c| c.text())
+                            .collect();
+                        let own_text = own_text.trim();
+                        if own_text.to_lowercase().contains(needle) {
+                            push(serde_json::json!({
+                                "path": xpath::element_path(&el),
+                                "text": own_text,
+                            }));
+                        }
+                        for attr in el.attributes() {
+                            if attr.value().to_lowercase().contains(needle) {
+                                push(serde_json::json!({
+                                    "path": format!("{}/@{}", xpath::element_path(&el), attr.name()),
+                                    "text": attr.value(),
+                                }));
+                            }
+                        }
+                    }
+                }
+                (xpath::XPathHit::Attribute { element, name, value }, Some(needle)) => {
+                    if value.to_lowercase().contains(needle) {
+                        push(serde_json::json!({
+                            "path": format!("{}/@{}", xpath::element_path(element), name),
+                            "text": value,
+                        }));
+                    }
+                }
+                (xpath::XPathHit::Text(node), Some(needle)) => {
+                    let t = node.text().unwrap_or("").trim();
+                    if t.to_lowercase().contains(needle) {
+                        push(serde_json::json!({
+                            "path": format!("{}/text()", node.parent().map(|p| xpath::element_path(&p)).unwrap_or_default()),
+                            "text": t,
+                        }));
+                    }
+                }
+            }
+        }
+
+        Ok(serde_json::json!({
+            "file_path": path,
+            "xpath": xpath_expr,
+            "query": query,
+            "count": results.len(),
+            "truncated": total > results.len(),
+            "results": results,
+        }))
     }
 }
 
@@ -682,16 +2935,90 @@ impl Default for XmlSearchTool {
 
 /// Search within the content of a specific website.
 ///
+/// Crawls same-origin links breadth-first from `website_url` up to
+/// `max_depth` links away and at most `max_pages` pages, pausing
+/// `crawl_delay_ms` between requests and skipping non-HTML responses. Pages
+/// are ranked by how often the query occurs in their text.
+///
+/// With the `rag` feature and an embedder set via `with_embedder`, pages are
+/// instead split with `WebChunker` and chunks ranked by embedding similarity.
+///
 /// Corresponds to Python `WebsiteSearchTool` in `crewai_tools`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WebsiteSearchTool {
     /// URL of the website to search.
     pub website_url: Option<String>,
+    /// How many links away from `website_url` to follow (0 = start page only).
+    pub max_depth: usize,
+    /// Maximum number of pages to fetch.
+    pub max_pages: usize,
+    /// Pause between requests, in milliseconds.
+    pub crawl_delay_ms: u64,
+    /// Characters of context on each side of a hit.
+    pub snippet_chars: usize,
+    /// Maximum number of results to return.
+    pub max_results: usize,
+    /// Crawl pages even when robots.txt disallows them.
+    pub ignore_robots: bool,
+    /// Per-host rate limit shared with other tools. Without one, each crawl
+    /// spaces requests by `crawl_delay_ms`.
+    #[serde(skip)]
+    pub rate_limiter: Option<std::sync::Arc<HostRateLimiter>>,
+    /// robots.txt rules fetched so far; clones of the tool share it.
+    #[serde(skip)]
+    pub robots: std::sync::Arc<RobotsCache>,
+    /// Extra headers and cookies (not serialized; values may be secrets).
+    #[serde(skip)]
+    pub request_headers: CustomHeaders,
+    /// Embedder for semantic ranking (not serialized).
+    #[cfg(feature = "rag")]
+    #[serde(skip)]
+    pub embedder: Option<std::sync::Arc<dyn crate::core::EmbeddingService>>,
+}
+
+impl std::fmt::Debug for WebsiteSearchTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("WebsiteSearchTool");
+        d.field("website_url", &self.website_url)
+            .field("max_depth", &self.max_depth)
+            .field("max_pages", &self.max_pages)
+            .field("crawl_delay_ms", &self.crawl_delay_ms)
+            .field("snippet_chars", &self.snippet_chars)
+            .field("max_results", &self.max_results)
+            .field("ignore_robots", &self.ignore_robots)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("request_headers", &self.request_headers);
+        #[cfg(feature = "rag")]
+        d.field("embedder", &self.embedder.as_ref().map(|e| e.model_name().to_string()));
+        d.finish()
+    }
+}
+
+/// A fetched HTML page.
+#[derive(Debug, Clone)]
+struct CrawledPage {
+    url: String,
+    /// Raw markup, for tools that need page structure.
+    html: String,
+    text: String,
 }
 
 impl WebsiteSearchTool {
     pub fn new() -> Self {
-        Self { website_url: None }
+        Self {
+            website_url: None,
+            max_depth: 1,
+            max_pages: 20,
+            crawl_delay_ms: 500,
+            snippet_chars: 120,
+            max_results: 10,
+            ignore_robots: false,
+            rate_limiter: None,
+            robots: std::sync::Arc::default(),
+            request_headers: CustomHeaders::new(),
+            #[cfg(feature = "rag")]
+            embedder: None,
+        }
     }
 
     pub fn with_website_url(mut self, url: impl Into<String>) -> Self {
@@ -699,11 +3026,207 @@ impl WebsiteSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "WebsiteSearchTool: not yet implemented - requires website scraping and semantic search"
+    pub fn with_max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    pub fn with_max_pages(mut self, n: usize) -> Self {
+        self.max_pages = n;
+        self
+    }
+
+    pub fn with_crawl_delay_ms(mut self, ms: u64) -> Self {
+        self.crawl_delay_ms = ms;
+        self
+    }
+
+    pub fn with_snippet_chars(mut self, n: usize) -> Self {
+        self.snippet_chars = n;
+        self
+    }
+
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    pub fn with_ignore_robots(mut self, ignore: bool) -> Self {
+        self.ignore_robots = ignore;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, limiter: std::sync::Arc<HostRateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Share robots.txt lookups with other tools.
+    pub fn with_robots_cache(mut self, robots: std::sync::Arc<RobotsCache>) -> Self {
+        self.robots = robots;
+        self
+    }
+
+    /// Send these headers with every page request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.request_headers.set_headers(headers);
+        self
+    }
+
+    /// Send these cookies with every page request.
+    pub fn with_cookies(mut self, cookies: Vec<(String, String)>) -> Self {
+        self.request_headers.set_cookies(cookies);
+        self
+    }
+
+    /// Rank chunks by embedding similarity instead of keyword counts.
+    #[cfg(feature = "rag")]
+    pub fn with_embedder(mut self, embedder: std::sync::Arc<dyn crate::core::EmbeddingService>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Crawl the site and search it.
+    ///
+    /// Pages robots.txt disallows are not fetched; they are listed under
+    /// `pages_blocked` as `{url, reason}` records.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - The text to look for.
+    /// * `website_url` - Start URL (optional if set on struct).
+    /// * `headers` - Object of extra headers, merged over the configured ones.
+    /// * `cookies` - Object or `[name, value]` list, merged likewise.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let url = args
+            .get("website_url")
+            .and_then(|v| v.as_str())
+            .or(self.website_url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: website_url"))?;
+
+        let headers = self.request_headers.merged(&args)?;
+        let crawl = self.crawl(url, &headers)?;
+        let pages = &crawl.pages;
+
+        #[cfg(feature = "rag")]
+        if let Some(embedder) = &self.embedder {
+            return self.rank_semantic(url, query, &crawl, embedder.clone());
+        }
+
+        let needle = query.to_lowercase();
+        let mut ranked: Vec<(usize, Value)> = pages
+            .iter()
+            .filter_map(|page| {
+                let snippets = find_snippets(&page.text, &needle, self.snippet_chars);
+                if snippets.is_empty() {
+                    return None;
+                }
+                let count = snippets.len();
+                Some((
+                    count,
+                    serde_json::json!({
+                        "url": page.url,
+                        "matches": count,
+                        "snippets": snippets.into_iter().take(5).collect::<Vec<_>>(),
+                    }),
+                ))
+            })
+            .collect();
+        // Stable sort keeps crawl order among pages with equal counts.
+        ranked.sort_by_key(|r| std::cmp::Reverse(r.0));
+        let results: Vec<Value> = ranked.into_iter().take(self.max_results).map(|(_, v)| v).collect();
+
+        Ok(serde_json::json!({
+            "website_url": url,
+            "query": query,
+            "ranking": "keyword",
+            "pages_crawled": pages.len(),
+            "pages_skipped": crawl.skipped,
+            "pages_blocked": crawl.blocked,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
+
+    /// Breadth-first same-origin crawl, honouring robots.txt and the rate
+    /// limiter.
+    fn crawl(&self, start: &str, headers: &CustomHeaders) -> Result<CrawlOutcome, anyhow::Error> {
+        let start_url = reqwest::Url::parse(start)
+            .map_err(|e| anyhow::anyhow!("Invalid website_url '{}': {}", start, e))?;
+        let origin = start_url.origin();
+        let local_limiter;
+        let limiter = match &self.rate_limiter {
+            Some(shared) => shared.as_ref(),
+            None => {
+                local_limiter =
+                    HostRateLimiter::new(std::time::Duration::from_millis(self.crawl_delay_ms));
+                &local_limiter
+            }
+        };
+        let guard = CrawlGuard {
+            robots: (!self.ignore_robots).then_some(self.robots.as_ref()),
+            limiter: Some(limiter),
+        };
+        crawl_site_guarded(
+            vec![start_url],
+            &|link| link.origin() == origin,
+            self.max_depth,
+            self.max_pages,
+            &guard,
+            headers,
         )
     }
+
+    /// Chunk pages with `WebChunker` and rank chunks against the query embedding.
+    #[cfg(feature = "rag")]
+    fn rank_semantic(
+        &self,
+        url: &str,
+        query: &str,
+        crawl: &CrawlOutcome,
+        embedder: std::sync::Arc<dyn crate::core::EmbeddingService>,
+    ) -> Result<Value, anyhow::Error> {
+        use crate::rag::chunkers::WebChunker;
+        use crate::core::Document;
+        use crate::rag::pipeline::RagPipeline;
+
+        let pipeline = RagPipeline::new(Box::new(WebChunker::new()), embedder)
+            .with_top_k(self.max_results);
+        let documents: Vec<Document> = crawl
+            .pages
+            .iter()
+            .map(|p| Document::new(p.html.clone()).with_metadata("source", Value::String(p.url.clone())))
+            .collect();
+        let chunks = pipeline.add_documents(&documents)?;
+        let result = pipeline.query(query, None, None)?;
+        let results: Vec<Value> = result
+            .hits
+            .into_iter()
+            .map(|hit| {
+                serde_json::json!({
+                    "url": hit.metadata.get("source").cloned().unwrap_or(Value::Null),
+                    "score": hit.score,
+                    "snippet": hit.content,
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "website_url": url,
+            "query": query,
+            "ranking": "semantic",
+            "pages_crawled": crawl.pages.len(),
+            "pages_skipped": crawl.skipped,
+            "pages_blocked": crawl.blocked,
+            "chunks_indexed": chunks,
+            "count": results.len(),
+            "results": results,
+        }))
+    }
 }
 
 impl Default for WebsiteSearchTool {
@@ -712,6 +3235,148 @@ impl Default for WebsiteSearchTool {
     }
 }
 
+/// Breadth-first crawl from `seeds`, following links for which `in_scope`
+/// holds up to `max_depth` hops, fetching at most `max_pages` HTML pages and
+/// pausing `delay_ms` between requests.
+///
+/// Returns the pages fetched and the number of responses skipped for an
+/// error status or a non-HTML content type. A network failure on the very
+/// first request is returned as an error; later ones are skipped.
+fn crawl_site(
+    seeds: Vec<reqwest::Url>,
+    in_scope: &dyn Fn(&reqwest::Url) -> bool,
+    max_depth: usize,
+    max_pages: usize,
+    delay_ms: u64,
+) -> Result<(Vec<CrawledPage>, usize), anyhow::Error> {
+    let limiter = HostRateLimiter::new(std::time::Duration::from_millis(delay_ms));
+    let guard = CrawlGuard {
+        robots: None,
+        limiter: Some(&limiter),
+    };
+    let crawl = crawl_site_guarded(
+        seeds,
+        in_scope,
+        max_depth,
+        max_pages,
+        &guard,
+        &CustomHeaders::new(),
+    )?;
+    Ok((crawl.pages, crawl.skipped))
+}
+
+/// Pages gathered by `crawl_site_guarded`.
+struct CrawlOutcome {
+    pages: Vec<CrawledPage>,
+    /// Responses skipped for a non-HTML content type or error.
+    skipped: usize,
+    /// `{url, reason}` for each URL robots.txt kept us from fetching.
+    blocked: Vec<Value>,
+}
+
+/// `crawl_site` with the robots.txt check and rate limit of `guard` applied
+/// before every request, and `headers` sent with every page request.
+fn crawl_site_guarded(
+    seeds: Vec<reqwest::Url>,
+    in_scope: &dyn Fn(&reqwest::Url) -> bool,
+    max_depth: usize,
+    max_pages: usize,
+    guard: &CrawlGuard,
+    headers: &CustomHeaders,
+) -> Result<CrawlOutcome, anyhow::Error> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent(USER_AGENT)
+        .build()?;
+    let href_re = regex::Regex::new(r#"(?i)<a\s[^>]*href\s*=\s*["']([^"']+)["']"#)?;
+
+    let mut seen: std::collections::HashSet<String> = seeds.iter().map(page_key).collect();
+    let mut queue: std::collections::VecDeque<(reqwest::Url, usize)> =
+        seeds.into_iter().map(|u| (u, 0)).collect();
+    let mut pages = Vec::new();
+    let mut skipped = 0;
+    let mut blocked = Vec::new();
+    let mut first = true;
+
+    while let Some((page_url, depth)) = queue.pop_front() {
+        if pages.len() >= max_pages {
+            break;
+        }
+        if let Err(e) = guard.admit(&client, &page_url) {
+            blocked.push(serde_json::json!({ "url": e.url, "reason": e.to_string() }));
+            continue;
+        }
+
+        let response = match headers.apply(client.get(page_url.clone()))?.send() {
+            Ok(r) if r.status().is_success() => r,
+            Ok(r) => {
+                log::debug!("crawl: {} returned {}", page_url, r.status());
+                skipped += 1;
+                first = false;
+                continue;
+            }
+            Err(e) if first => anyhow::bail!("Failed to fetch '{}': {}", page_url, e),
+            Err(e) => {
+                log::debug!("crawl: failed to fetch {}: {}", page_url, e);
+                skipped += 1;
+                continue;
+            }
+        };
+        first = false;
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| ct.contains("text/html") || ct.contains("application/xhtml"));
+        if !is_html {
+            skipped += 1;
+            continue;
+        }
+        let html = response.text()?;
+
+        if depth < max_depth {
+            for cap in href_re.captures_iter(&html) {
+                let Ok(link) = page_url.join(&cap[1]) else {
+                    continue;
+                };
+                if !matches!(link.scheme(), "http" | "https") || !in_scope(&link) {
+                    continue;
+                }
+                if seen.insert(page_key(&link)) {
+                    queue.push_back((link, depth + 1));
+                }
+            }
+        }
+
+        let text = html_to_text(&html);
+        pages.push(CrawledPage {
+            url: page_url.to_string(),
+            html,
+            text,
+        });
+    }
+    Ok(CrawlOutcome {
+        pages,
+        skipped,
+        blocked,
+    })
+}
+
+/// Crawl-dedup key: the URL without its fragment.
+fn page_key(url: &reqwest::Url) -> String {
+    let mut url = url.clone();
+    url.set_fragment(None);
+    url.to_string()
+}
+
+/// Strip scripts, styles and tags from HTML and collapse whitespace.
+fn html_to_text(html: &str) -> String {
+    let blocks = regex::Regex::new(r"(?is)<(script|style|noscript)[^>]*>.*?</(script|style|noscript)>").unwrap();
+    let tags = regex::Regex::new(r"<[^>]+>").unwrap();
+    let text = decode_basic_entities(&tags.replace_all(&blocks.replace_all(html, " "), " "));
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 // ── YoutubeChannelSearchTool ─────────────────────────────────────────────────
 
 /// Search within a YouTube channel's videos and transcripts.
@@ -750,16 +3415,43 @@ impl Default for YoutubeChannelSearchTool {
 
 /// Search within a specific YouTube video's transcript.
 ///
+/// `video_url` may be a full URL (`watch?v=`, `youtu.be/`, `/shorts/`,
+/// `/embed/`) or a bare 11-character video ID. Each result links to the
+/// first matching caption cue (`watch?v=ID&t=123s`) and lists every
+/// matching cue with its timestamp. Videos without captions fail with
+/// [`NoTranscript`].
+///
+/// The transcript is segmented by the video's chapters when it has any, so
+/// each match cites where it falls, e.g. `Chapter: Pricing deep-dive (14:05)`.
+/// Videos without chapters are segmented into `window_seconds` windows.
+///
 /// Corresponds to Python `YoutubeVideoSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YoutubeVideoSearchTool {
     /// YouTube video URL or ID.
     pub video_url: Option<String>,
+    /// Preferred transcript language.
+    pub language: String,
+    /// Section length used when the video has no chapters.
+    pub window_seconds: u32,
+    /// Characters of context on each side of a hit.
+    pub snippet_chars: usize,
+    /// Maximum number of sections to return.
+    pub max_results: usize,
+    /// Override of where watch pages are fetched from (for proxies and testing).
+    pub base_url: Option<String>,
 }
 
 impl YoutubeVideoSearchTool {
     pub fn new() -> Self {
-        Self { video_url: None }
+        Self {
+            video_url: None,
+            language: "en".to_string(),
+            window_seconds: 60,
+            snippet_chars: 120,
+            max_results: 10,
+            base_url: None,
+        }
     }
 
     pub fn with_video_url(mut self, url: impl Into<String>) -> Self {
@@ -767,10 +3459,110 @@ impl YoutubeVideoSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "YoutubeVideoSearchTool: not yet implemented - requires YouTube transcript API integration"
-        )
+    pub fn with_language(mut self, lang: impl Into<String>) -> Self {
+        self.language = lang.into();
+        self
+    }
+
+    pub fn with_window_seconds(mut self, seconds: u32) -> Self {
+        self.window_seconds = seconds;
+        self
+    }
+
+    pub fn with_snippet_chars(mut self, n: usize) -> Self {
+        self.snippet_chars = n;
+        self
+    }
+
+    pub fn with_max_results(mut self, n: usize) -> Self {
+        self.max_results = n;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Search a video's transcript.
+    ///
+    /// # Arguments (in `args`)
+    /// * `search_query` - Case-insensitive text to look for.
+    /// * `video_url` - Video URL or ID (optional if set on struct).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("search_query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: search_query"))?;
+
+        let video_url = args
+            .get("video_url")
+            .and_then(|v| v.as_str())
+            .or(self.video_url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: video_url"))?;
+
+        let video = crate::youtube::fetch_transcript_from(
+            self.base_url.as_deref().unwrap_or(crate::youtube::YOUTUBE_URL),
+            video_url,
+            &self.language,
+        )?;
+        let needle = query.to_lowercase();
+        let sections = crate::youtube::sections(&video, self.window_seconds);
+        let results: Vec<Value> = sections
+            .iter()
+            .filter_map(|section| {
+                let snippets = find_snippets(&section.text, &needle, self.snippet_chars);
+                if snippets.is_empty() {
+                    return None;
+                }
+                // Individual cues containing the query, for precise deep links.
+                // A phrase split across cues only shows up in `snippets`.
+                let hits: Vec<Value> = video
+                    .segments
+                    .iter()
+                    .filter(|seg| {
+                        let at = seg.start as u32;
+                        at >= section.start
+                            && (at < section.end || section.end <= section.start)
+                            && seg.text.to_lowercase().contains(&needle)
+                    })
+                    .map(|seg| {
+                        let at = seg.start as u32;
+                        serde_json::json!({
+                            "timestamp": crate::youtube::format_timestamp(at),
+                            "start_seconds": at,
+                            "url": crate::youtube::deep_link(&video.video_id, at),
+                            "text": seg.text,
+                        })
+                    })
+                    .collect();
+                let link_at = hits
+                    .first()
+                    .and_then(|h| h["start_seconds"].as_u64())
+                    .map_or(section.start, |s| s as u32);
+                Some(serde_json::json!({
+                    "citation": section.citation(),
+                    "chapter": section.chapter,
+                    "start_seconds": section.start,
+                    "end_seconds": section.end,
+                    "url": crate::youtube::deep_link(&video.video_id, link_at),
+                    "matches": snippets.len(),
+                    "snippets": snippets,
+                    "hits": hits,
+                }))
+            })
+            .take(self.max_results)
+            .collect();
+
+        Ok(serde_json::json!({
+            "video_id": video.video_id,
+            "title": video.title,
+            "query": query,
+            "segmentation": if video.chapters.is_empty() { "window" } else { "chapter" },
+            "chapters": video.chapters.len(),
+            "count": results.len(),
+            "results": results,
+        }))
     }
 }
 
@@ -791,6 +3583,10 @@ pub struct MySqlSearchTool {
     pub connection_string: Option<String>,
     /// Database name.
     pub database: Option<String>,
+    /// Connections reused across runs (not serialized).
+    #[cfg(feature = "database")]
+    #[serde(skip)]
+    pub pool: Option<std::sync::Arc<crate::tools::database::ConnectionPool>>,
 }
 
 impl MySqlSearchTool {
@@ -798,6 +3594,8 @@ impl MySqlSearchTool {
         Self {
             connection_string: None,
             database: None,
+            #[cfg(feature = "database")]
+            pool: None,
         }
     }
 
@@ -811,6 +3609,16 @@ impl MySqlSearchTool {
         self
     }
 
+    /// Reuse connections from `pool` instead of connecting on every run.
+    #[cfg(feature = "database")]
+    pub fn with_pool(
+        mut self,
+        pool: std::sync::Arc<crate::tools::database::ConnectionPool>,
+    ) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
     pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         anyhow::bail!("MySqlSearchTool: not yet implemented - requires MySQL driver integration")
     }
@@ -824,7 +3632,12 @@ impl Default for MySqlSearchTool {
 
 // ── LinkupSearchTool ─────────────────────────────────────────────────────────
 
-/// Search using the Linkup API for enriched link previews and metadata.
+/// Search the web using the Linkup `/v1/search` API.
+///
+/// `depth` is `"standard"` (fast) or `"deep"` (iterative, often 30+
+/// seconds), and `output_type` is `"searchResults"` (a list of
+/// `{name, url, content}` entries) or `"sourcedAnswer"` (a synthesized
+/// answer plus the sources it cites). Both can be overridden per call.
 ///
 /// Corresponds to Python `LinkupSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -833,6 +3646,16 @@ pub struct LinkupSearchTool {
     pub api_key: Option<String>,
     /// Maximum number of results.
     pub max_results: usize,
+    /// Default search depth: "standard" or "deep".
+    pub depth: String,
+    /// Default output type: "searchResults" or "sourcedAnswer".
+    pub output_type: String,
+    /// Request timeout for standard searches.
+    pub timeout: std::time::Duration,
+    /// Request timeout for deep searches.
+    pub deep_timeout: std::time::Duration,
+    /// Override of the API endpoint (for proxies and testing).
+    pub base_url: Option<String>,
 }
 
 impl LinkupSearchTool {
@@ -840,6 +3663,11 @@ impl LinkupSearchTool {
         Self {
             api_key: None,
             max_results: 10,
+            depth: "standard".to_string(),
+            output_type: "searchResults".to_string(),
+            timeout: std::time::Duration::from_secs(30),
+            deep_timeout: std::time::Duration::from_secs(120),
+            base_url: None,
         }
     }
 
@@ -853,11 +3681,158 @@ impl LinkupSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("LinkupSearchTool: not yet implemented - requires Linkup API integration")
+    pub fn with_depth(mut self, depth: impl Into<String>) -> Self {
+        self.depth = depth.into();
+        self
+    }
+
+    pub fn with_output_type(mut self, output_type: impl Into<String>) -> Self {
+        self.output_type = output_type.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_deep_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.deep_timeout = timeout;
+        self
+    }
+
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Run a Linkup search.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - The search query string.
+    /// * `depth` - Optional "standard" or "deep" (defaults to `self.depth`).
+    /// * `output_type` - Optional "searchResults" or "sourcedAnswer"
+    ///   (defaults to `self.output_type`).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+        let depth = args
+            .get("depth")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.depth);
+        let output_type = args
+            .get("output_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.output_type);
+
+        let timeout = match depth {
+            "standard" => self.timeout,
+            "deep" => self.deep_timeout,
+            other => anyhow::bail!("Invalid depth '{}': expected 'standard' or 'deep'", other),
+        };
+        if output_type != "searchResults" && output_type != "sourcedAnswer" {
+            anyhow::bail!(
+                "Invalid output_type '{}': expected 'searchResults' or 'sourcedAnswer'",
+                output_type
+            );
+        }
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("LINKUP_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing LINKUP_API_KEY"))?;
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(timeout)
+            .build()?;
+        let base_url = self.base_url.as_deref().unwrap_or("https://api.linkup.so");
+        let resp = client
+            .post(format!("{}/v1/search", base_url.trim_end_matches('/')))
+            .bearer_auth(&api_key)
+            .json(&serde_json::json!({
+                "q": query,
+                "depth": depth,
+                "outputType": output_type,
+            }))
+            .send()
+            .map_err(|e| {
+                if e.is_timeout() {
+                    anyhow::anyhow!(
+                        "Linkup {} search timed out after {:?}",
+                        depth,
+                        timeout
+                    )
+                } else {
+                    e.into()
+                }
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            // Gateways in front of the API may answer with non-JSON bodies.
+            let text = resp.text().unwrap_or_default();
+            let body: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+            let message = body
+                .pointer("/error/message")
+                .or_else(|| body.get("message"))
+                .and_then(|m| m.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| match text.trim() {
+                    "" => "request failed".to_string(),
+                    raw => truncate_chars(raw, 200),
+                });
+            anyhow::bail!("Linkup API error ({}): {}", status, message);
+        }
+        let body: Value = resp.json()?;
+
+        if output_type == "sourcedAnswer" {
+            let sources: Vec<Value> = body
+                .get("sources")
+                .and_then(|s| s.as_array())
+                .map(|sources| sources.iter().map(linkup_entry).collect())
+                .unwrap_or_default();
+            return Ok(serde_json::json!({
+                "query": query,
+                "depth": depth,
+                "answer": body.get("answer").and_then(|a| a.as_str()).unwrap_or_default(),
+                "sources": sources,
+            }));
+        }
+
+        let results: Vec<Value> = body
+            .get("results")
+            .and_then(|r| r.as_array())
+            .map(|results| {
+                results
+                    .iter()
+                    .take(self.max_results)
+                    .map(linkup_entry)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(serde_json::json!({
+            "query": query,
+            "depth": depth,
+            "total_results": results.len(),
+            "results": results,
+        }))
     }
 }
 
+/// Normalize a Linkup result or source to `{name, url, content}`. Sources
+/// carry their excerpt in `snippet`, results in `content`.
+fn linkup_entry(item: &Value) -> Value {
+    let field = |key: &str| item.get(key).and_then(Value::as_str);
+    serde_json::json!({
+        "name": field("name").unwrap_or_default(),
+        "url": field("url").unwrap_or_default(),
+        "content": field("content").or_else(|| field("snippet")).unwrap_or_default(),
+    })
+}
+
 impl Default for LinkupSearchTool {
     fn default() -> Self {
         Self::new()
@@ -868,17 +3843,41 @@ impl Default for LinkupSearchTool {
 
 /// Execute multiple search queries in parallel across different search tools.
 ///
+/// Each (query, provider) pair runs on a pool of at most `max_concurrency`
+/// worker threads. A provider failing, or not finishing before the
+/// `timeout` wall-clock deadline, is reported on that query only; the
+/// call as a whole still succeeds.
+///
 /// Corresponds to Python `ParallelSearchTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParallelSearchTool {
     /// Maximum concurrency for parallel searches.
     pub max_concurrency: usize,
+    /// Providers used for queries that do not name their own
+    /// ("serper", "brave", "tavily").
+    pub providers: Vec<String>,
+    /// Wall-clock limit for the whole call.
+    pub timeout: std::time::Duration,
+    /// Configured Serper backend.
+    pub serper: SerperDevTool,
+    /// Configured Brave backend.
+    pub brave: BraveSearchTool,
+    /// Configured Tavily backend.
+    pub tavily: TavilySearchTool,
 }
 
+/// Search backends `ParallelSearchTool` can dispatch to.
+const PARALLEL_PROVIDERS: &[&str] = &["serper", "brave", "tavily"];
+
 impl ParallelSearchTool {
     pub fn new() -> Self {
         Self {
             max_concurrency: 5,
+            providers: vec!["serper".to_string()],
+            timeout: std::time::Duration::from_secs(60),
+            serper: SerperDevTool::new(),
+            brave: BraveSearchTool::new(),
+            tavily: TavilySearchTool::new(),
         }
     }
 
@@ -887,10 +3886,176 @@ impl ParallelSearchTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "ParallelSearchTool: not yet implemented - requires parallel search orchestration"
-        )
+    pub fn with_providers(mut self, providers: Vec<String>) -> Self {
+        self.providers = providers;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_serper(mut self, tool: SerperDevTool) -> Self {
+        self.serper = tool;
+        self
+    }
+
+    pub fn with_brave(mut self, tool: BraveSearchTool) -> Self {
+        self.brave = tool;
+        self
+    }
+
+    pub fn with_tavily(mut self, tool: TavilySearchTool) -> Self {
+        self.tavily = tool;
+        self
+    }
+
+    /// Run several searches concurrently.
+    ///
+    /// # Arguments (in `args`)
+    /// * `queries` - Array of query strings.
+    /// * `providers` - Optional array parallel to `queries`; each entry is a
+    ///   provider name or an array of names for that query. Missing or null
+    ///   entries use `self.providers`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let queries: Vec<String> = args
+            .get("queries")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: queries"))?
+            .iter()
+            .map(|q| {
+                q.as_str()
+                    .map(str::to_string)
+                    .ok_or_else(|| anyhow::anyhow!("queries must be an array of strings"))
+            })
+            .collect::<Result<_, _>>()?;
+        if queries.is_empty() {
+            anyhow::bail!("queries must not be empty");
+        }
+        let per_query = args.get("providers").and_then(|v| v.as_array());
+
+        let mut tasks: Vec<(String, String)> = Vec::new();
+        for (i, query) in queries.iter().enumerate() {
+            let providers: Vec<String> = match per_query.and_then(|p| p.get(i)) {
+                Some(Value::String(name)) => vec![name.clone()],
+                Some(Value::Array(names)) => names
+                    .iter()
+                    .filter_map(|n| n.as_str().map(str::to_string))
+                    .collect(),
+                _ => self.providers.clone(),
+            };
+            for provider in providers {
+                let provider = provider.to_lowercase();
+                if !PARALLEL_PROVIDERS.contains(&provider.as_str()) {
+                    anyhow::bail!(
+                        "Unknown provider '{}': expected one of {}",
+                        provider,
+                        PARALLEL_PROVIDERS.join(", ")
+                    );
+                }
+                let task = (query.clone(), provider);
+                if !tasks.contains(&task) {
+                    tasks.push(task);
+                }
+            }
+        }
+
+        let started = std::time::Instant::now();
+        let deadline = started + self.timeout;
+        let tasks = std::sync::Arc::new(tasks);
+        let next = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        // Workers are detached so a slow provider cannot hold the call past
+        // the deadline; results arriving after it are dropped.
+        for _ in 0..self.max_concurrency.max(1).min(tasks.len()) {
+            let (tool, tasks, next, tx) = (self.clone(), tasks.clone(), next.clone(), tx.clone());
+            std::thread::spawn(move || loop {
+                let i = next.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                if i >= tasks.len() || std::time::Instant::now() >= deadline {
+                    break;
+                }
+                let (query, provider) = &tasks[i];
+                let result = tool.search_one(provider, query);
+                if tx.send((i, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut outcomes: Vec<Option<Result<Value, String>>> = vec![None; tasks.len()];
+        let mut received = 0;
+        while received < tasks.len() {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            match rx.recv_timeout(remaining) {
+                Ok((i, result)) => {
+                    outcomes[i] = Some(result.map_err(|e| e.to_string()));
+                    received += 1;
+                }
+                Err(_) => break,
+            }
+        }
+        let timed_out = received < tasks.len();
+
+        let mut results = serde_json::Map::new();
+        let (mut succeeded, mut failed) = (0, 0);
+        for ((query, provider), outcome) in tasks.iter().zip(outcomes) {
+            let entry = results.entry(query.clone()).or_insert_with(|| {
+                serde_json::json!({ "status": "ok", "providers": {} })
+            });
+            let provider_result = match outcome {
+                Some(Ok(data)) => {
+                    succeeded += 1;
+                    serde_json::json!({ "status": "ok", "data": data })
+                }
+                Some(Err(error)) => {
+                    failed += 1;
+                    serde_json::json!({ "status": "error", "error": error })
+                }
+                None => {
+                    failed += 1;
+                    serde_json::json!({
+                        "status": "error",
+                        "error": format!("timed out after {:?}", self.timeout),
+                    })
+                }
+            };
+            entry["providers"][provider.as_str()] = provider_result;
+        }
+        for entry in results.values_mut() {
+            let statuses: Vec<&str> = entry["providers"]
+                .as_object()
+                .map(|p| p.values().filter_map(|r| r["status"].as_str()).collect())
+                .unwrap_or_default();
+            let ok = statuses.iter().filter(|s| **s == "ok").count();
+            entry["status"] = Value::from(match ok {
+                0 => "error",
+                n if n == statuses.len() => "ok",
+                _ => "partial",
+            });
+        }
+
+        Ok(serde_json::json!({
+            "results": results,
+            "succeeded": succeeded,
+            "failed": failed,
+            "timed_out": timed_out,
+            "elapsed_ms": started.elapsed().as_millis() as u64,
+        }))
+    }
+
+    fn search_one(&self, provider: &str, query: &str) -> Result<Value, anyhow::Error> {
+        let mut args = HashMap::new();
+        args.insert("search_query".to_string(), Value::from(query));
+        args.insert("query".to_string(), Value::from(query));
+        match provider {
+            "serper" => self.serper.run(args),
+            "brave" => self.brave.run(args),
+            "tavily" => self.tavily.run(args),
+            other => anyhow::bail!("Unknown provider '{}'", other),
+        }
     }
 }
 
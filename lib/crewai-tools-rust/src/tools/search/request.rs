@@ -0,0 +1,125 @@
+//! Transport shared by the blocking and async paths of the web search tools.
+//!
+//! `BraveSearchTool`, `SerperDevTool`, `TavilySearchTool` and `ExaSearchTool`
+//! each turn their settings and `args` into a [`SearchRequest`] once, then
+//! send it with either [`SearchRequest::send`] (reqwest's blocking client)
+//! or [`SearchRequest::send_async`] (its async client). Caching, retries and
+//! status handling live here so both paths behave the same.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+
+use super::SearchCache;
+use crate::tools::retry;
+
+/// A fully described search API call.
+pub(super) struct SearchRequest {
+    /// Service name used in error messages.
+    pub label: &'static str,
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(&'static str, String)>,
+    pub query: Vec<(&'static str, String)>,
+    pub body: Option<Value>,
+    pub timeout: Duration,
+    pub proxy: Option<String>,
+    pub max_retries: u32,
+    pub backoff_base_ms: u64,
+    /// Turn non-2xx responses into errors instead of returning their body.
+    pub fail_on_error_status: bool,
+    /// Cache to consult and fill, with this request's key.
+    pub cache: Option<(Arc<SearchCache>, String)>,
+}
+
+impl SearchRequest {
+    /// Send with the blocking client.
+    pub fn send(&self) -> Result<Value, anyhow::Error> {
+        if let Some(hit) = self.cached() {
+            return Ok(hit);
+        }
+        let mut builder = reqwest::blocking::Client::builder().timeout(self.timeout);
+        if let Some(proxy) = self.proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build()?;
+        let build = || {
+            let mut request = client
+                .request(self.method.clone(), &self.url)
+                .query(&self.query);
+            for (name, value) in &self.headers {
+                request = request.header(*name, value);
+            }
+            match &self.body {
+                Some(body) => request.json(body),
+                None => request,
+            }
+        };
+        let resp =
+            retry::send_with_retry(self.label, self.max_retries, self.backoff_base_ms, build)?;
+        let status = self.check_status(resp.status())?;
+        self.finish(status, resp.json::<Value>()?)
+    }
+
+    /// Send with the async client.
+    pub async fn send_async(&self) -> Result<Value, anyhow::Error> {
+        if let Some(hit) = self.cached() {
+            return Ok(hit);
+        }
+        let mut builder = reqwest::Client::builder().timeout(self.timeout);
+        if let Some(proxy) = self.proxy()? {
+            builder = builder.proxy(proxy);
+        }
+        let client = builder.build()?;
+        let build = || {
+            let mut request = client
+                .request(self.method.clone(), &self.url)
+                .query(&self.query);
+            for (name, value) in &self.headers {
+                request = request.header(*name, value);
+            }
+            match &self.body {
+                Some(body) => request.json(body),
+                None => request,
+            }
+        };
+        let resp =
+            retry::send_with_retry_async(self.label, self.max_retries, self.backoff_base_ms, build)
+                .await?;
+        let status = self.check_status(resp.status())?;
+        self.finish(status, resp.json::<Value>().await?)
+    }
+
+    /// An explicit proxy applies to every scheme. Without one, reqwest's
+    /// system proxy handling reads `HTTPS_PROXY`/`HTTP_PROXY` from the
+    /// environment.
+    fn proxy(&self) -> Result<Option<reqwest::Proxy>, anyhow::Error> {
+        Ok(match &self.proxy {
+            Some(proxy) => Some(reqwest::Proxy::all(proxy)?),
+            None => None,
+        })
+    }
+
+    fn cached(&self) -> Option<Value> {
+        let (cache, key) = self.cache.as_ref()?;
+        cache.get(key)
+    }
+
+    fn check_status(&self, status: StatusCode) -> Result<StatusCode, anyhow::Error> {
+        if self.fail_on_error_status && !status.is_success() {
+            anyhow::bail!("{} request failed: HTTP {}", self.label, status);
+        }
+        Ok(status)
+    }
+
+    /// Store a successful response in the cache, if any. Error responses
+    /// pass through uncached.
+    fn finish(&self, status: StatusCode, resp: Value) -> Result<Value, anyhow::Error> {
+        Ok(match &self.cache {
+            Some((cache, key)) if status.is_success() => cache.insert(key.clone(), resp),
+            _ => resp,
+        })
+    }
+}
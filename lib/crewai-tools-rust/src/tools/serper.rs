@@ -0,0 +1,18 @@
+//! Serper.dev credentials shared by the search and scrape tools.
+//!
+//! `SerperDevTool` (google.serper.dev) and `SerperScrapeWebsiteTool`
+//! (scrape.serper.dev) use the same key, looked up the same way and sent in
+//! the same header.
+
+/// Resolve the API key: the configured one, else `SERPER_API_KEY`.
+pub(crate) fn api_key(configured: Option<&str>) -> Result<String, anyhow::Error> {
+    configured
+        .map(String::from)
+        .or_else(|| std::env::var("SERPER_API_KEY").ok())
+        .ok_or_else(|| anyhow::anyhow!("Missing SERPER_API_KEY"))
+}
+
+/// Headers authenticating a Serper request.
+pub(crate) fn headers(api_key: String) -> Vec<(&'static str, String)> {
+    vec![("X-API-KEY", api_key)]
+}
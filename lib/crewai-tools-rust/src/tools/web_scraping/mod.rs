@@ -5,23 +5,115 @@
 //! to a Python tool class in `crewai_tools`.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::credit_budget::{usage_at, CreditBudget};
+use super::crawl_policy::{CrawlGuard, HostRateLimiter, RobotsCache, USER_AGENT};
+use super::custom_headers::CustomHeaders;
+use super::serper;
+
+// ── OutputFormat ─────────────────────────────────────────────────────────────
+
+/// How the native scraping tools render page content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Readable text with no markup.
+    Text,
+    /// Markdown with headings, links, lists, tables and code blocks.
+    #[default]
+    Markdown,
+    /// The HTML as served.
+    Html,
+}
+
+impl OutputFormat {
+    /// Parse an `output_format` argument.
+    pub fn parse(s: &str) -> Result<Self, anyhow::Error> {
+        match s.to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            other => anyhow::bail!(
+                "Unknown output_format '{}': expected 'text', 'markdown' or 'html'",
+                other
+            ),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Markdown => "markdown",
+            Self::Html => "html",
+        }
+    }
+
+    /// The configured format, unless `args` carries an `output_format`.
+    fn from_args(args: &HashMap<String, Value>, configured: Self) -> Result<Self, anyhow::Error> {
+        match args.get("output_format").and_then(|v| v.as_str()) {
+            Some(s) => Self::parse(s),
+            None => Ok(configured),
+        }
+    }
+}
+
 // ── ScrapeWebsiteTool ────────────────────────────────────────────────────────
 
 /// Scrape the full content of a website page.
 ///
+/// Follows redirects, decodes the body using the charset from the
+/// `Content-Type` header or the page's `<meta>` tags, and renders the page
+/// in `output_format`. Scripts, styles, navigation, sidebars and footers are
+/// dropped from text and Markdown output.
+///
+/// robots.txt is honoured unless `ignore_robots` is set, and an optional
+/// [`HostRateLimiter`] spaces out requests to the same host.
+///
 /// Corresponds to Python `ScrapeWebsiteTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScrapeWebsiteTool {
     /// URL of the website to scrape (can also be provided at runtime).
     pub website_url: Option<String>,
+    /// How to render the page (default Markdown).
+    pub output_format: OutputFormat,
+    /// Fetch pages even when robots.txt disallows them.
+    pub ignore_robots: bool,
+    /// Per-host rate limit, typically shared with other tools.
+    #[serde(skip)]
+    pub rate_limiter: Option<Arc<HostRateLimiter>>,
+    /// robots.txt rules fetched so far; clones of the tool share it.
+    #[serde(skip)]
+    pub robots: Arc<RobotsCache>,
+    /// Extra headers and cookies (not serialized; values may be secrets).
+    #[serde(skip)]
+    pub request_headers: CustomHeaders,
 }
 
 impl ScrapeWebsiteTool {
     pub fn new() -> Self {
-        Self { website_url: None }
+        Self {
+            website_url: None,
+            output_format: OutputFormat::Markdown,
+            ignore_robots: false,
+            rate_limiter: None,
+            robots: Arc::default(),
+            request_headers: CustomHeaders::new(),
+        }
+    }
+
+    /// Send these headers with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.request_headers.set_headers(headers);
+        self
+    }
+
+    /// Send these cookies with every request.
+    pub fn with_cookies(mut self, cookies: Vec<(String, String)>) -> Self {
+        self.request_headers.set_cookies(cookies);
+        self
     }
 
     pub fn with_website_url(mut self, url: impl Into<String>) -> Self {
@@ -29,39 +121,160 @@ impl ScrapeWebsiteTool {
         self
     }
 
-    /// Scrape the full text content of a website.
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    pub fn with_ignore_robots(mut self, ignore: bool) -> Self {
+        self.ignore_robots = ignore;
+        self
+    }
+
+    pub fn with_rate_limiter(mut self, limiter: Arc<HostRateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Share robots.txt lookups with other tools.
+    pub fn with_robots_cache(mut self, robots: Arc<RobotsCache>) -> Self {
+        self.robots = robots;
+        self
+    }
+
+    /// Scrape the readable content of a web page.
+    ///
+    /// Returns `{url, title, text, output_format, status_code}`, where `url`
+    /// is the final URL after redirects and `text` is the page rendered in
+    /// `output_format`. A URL disallowed by robots.txt fails with
+    /// [`RobotsDisallowed`](super::crawl_policy::RobotsDisallowed).
     ///
     /// # Arguments (in `args`)
     /// * `website_url` - The URL to scrape.
+    /// * `output_format` - `"text"`, `"markdown"` or `"html"` (overrides the
+    ///   configured format).
+    /// * `headers` - Object of extra headers, merged over the configured ones.
+    /// * `cookies` - Object or `[name, value]` list, merged likewise.
     pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
         let url = args
             .get("website_url")
             .and_then(|v| v.as_str())
             .or(self.website_url.as_deref())
             .ok_or_else(|| anyhow::anyhow!("Missing required argument: website_url"))?;
+        let format = OutputFormat::from_args(&args, self.output_format)?;
+        let headers = self.request_headers.merged(&args)?;
+
+        let guard = CrawlGuard {
+            robots: (!self.ignore_robots).then_some(self.robots.as_ref()),
+            limiter: self.rate_limiter.as_deref(),
+        };
+        let page = fetch_html(url, &guard, &headers)?;
+        let body = page.body.as_str();
+        let text = match format {
+            _ if !page.is_html => body.trim().to_string(),
+            OutputFormat::Text => crate::html::page_to_text(body),
+            OutputFormat::Markdown => crate::html::page_to_markdown(body),
+            OutputFormat::Html => body.to_string(),
+        };
+
+        Ok(serde_json::json!({
+            "url": page.url,
+            "title": if page.is_html { crate::html::html_title(body) } else { None },
+            "text": text,
+            "output_format": format.as_str(),
+            "status_code": page.status_code,
+        }))
+    }
+}
 
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .user_agent("Mozilla/5.0 (compatible; CrewAI/1.0)")
-            .build()?;
+/// A fetched page, decoded to text.
+struct FetchedPage {
+    /// Final URL after redirects.
+    url: String,
+    status_code: u16,
+    body: String,
+    /// `false` for `text/plain` responses.
+    is_html: bool,
+}
 
-        let body = client.get(url).send()?.text()?;
+/// GET `url` and decode it, refusing content that is not HTML or plain text.
+fn fetch_html(
+    url: &str,
+    guard: &CrawlGuard,
+    headers: &CustomHeaders,
+) -> Result<FetchedPage, anyhow::Error> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| anyhow::anyhow!("Invalid URL '{}': {}", url, e))?;
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent(USER_AGENT)
+        .build()?;
+    guard.admit(&client, &parsed)?;
+
+    let resp = headers.apply(client.get(parsed))?.send()?;
+    let final_url = resp.url().to_string();
+    let status_code = resp.status().as_u16();
+    let content_type = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_string();
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    let is_html = match mime.as_str() {
+        // Servers that omit the header almost always serve HTML.
+        "" | "text/html" | "application/xhtml+xml" => true,
+        "text/plain" => false,
+        other => anyhow::bail!(
+            "Cannot scrape {}: content type '{}' is not HTML",
+            final_url,
+            other
+        ),
+    };
+
+    let bytes = resp.bytes()?;
+    let charset = header_charset(&content_type).or_else(|| meta_charset(&bytes));
+    let encoding = charset
+        .as_deref()
+        .and_then(|label| encoding_rs::Encoding::for_label(label.as_bytes()))
+        .unwrap_or(encoding_rs::UTF_8);
+    let (body, _, _) = encoding.decode(&bytes);
+
+    Ok(FetchedPage {
+        url: final_url,
+        status_code,
+        body: body.into_owned(),
+        is_html,
+    })
+}
 
-        // Strip HTML tags for plain text (basic regex approach)
-        let re_tags = regex::Regex::new(r"<script[^>]*>[\s\S]*?</script>")
-            .unwrap_or_else(|_| regex::Regex::new(r"<[^>]+>").unwrap());
-        let no_scripts = re_tags.replace_all(&body, " ");
-        let re_style = regex::Regex::new(r"<style[^>]*>[\s\S]*?</style>")
-            .unwrap_or_else(|_| regex::Regex::new(r"<[^>]+>").unwrap());
-        let no_styles = re_style.replace_all(&no_scripts, " ");
-        let re_html = regex::Regex::new(r"<[^>]+>").unwrap();
-        let text = re_html.replace_all(&no_styles, " ");
-        // Collapse whitespace
-        let re_ws = regex::Regex::new(r"\s+").unwrap();
-        let clean = re_ws.replace_all(&text, " ").trim().to_string();
+/// The `charset` parameter of a `Content-Type` header.
+fn header_charset(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
 
-        Ok(Value::String(clean))
-    }
+/// The charset declared by `<meta charset=…>` or
+/// `<meta http-equiv="Content-Type" content="…; charset=…">` within the first
+/// 1024 bytes, as browsers do.
+fn meta_charset(bytes: &[u8]) -> Option<String> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]).to_ascii_lowercase();
+    let at = head.find("charset=")? + "charset=".len();
+    let value: String = head[at..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+        .collect();
+    (!value.is_empty()).then_some(value)
 }
 
 impl Default for ScrapeWebsiteTool {
@@ -74,6 +287,10 @@ impl Default for ScrapeWebsiteTool {
 
 /// Scrape a specific HTML element from a website using CSS selectors.
 ///
+/// Every element matching the selector is returned with its text, inner
+/// HTML and link attributes. No matches is an empty list, not an error, so
+/// agents can retry with a different selector.
+///
 /// Corresponds to Python `ScrapeElementFromWebsiteTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScrapeElementFromWebsiteTool {
@@ -81,6 +298,11 @@ pub struct ScrapeElementFromWebsiteTool {
     pub website_url: Option<String>,
     /// CSS selector for the element to extract.
     pub css_selector: Option<String>,
+    /// How to render each match's `text` (default plain text).
+    pub output_format: OutputFormat,
+    /// Extra headers and cookies (not serialized; values may be secrets).
+    #[serde(skip)]
+    pub request_headers: CustomHeaders,
 }
 
 impl ScrapeElementFromWebsiteTool {
@@ -88,9 +310,28 @@ impl ScrapeElementFromWebsiteTool {
         Self {
             website_url: None,
             css_selector: None,
+            output_format: OutputFormat::Text,
+            request_headers: CustomHeaders::new(),
         }
     }
 
+    pub fn with_output_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    /// Send these headers with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.request_headers.set_headers(headers);
+        self
+    }
+
+    /// Send these cookies with every request.
+    pub fn with_cookies(mut self, cookies: Vec<(String, String)>) -> Self {
+        self.request_headers.set_cookies(cookies);
+        self
+    }
+
     pub fn with_website_url(mut self, url: impl Into<String>) -> Self {
         self.website_url = Some(url.into());
         self
@@ -101,10 +342,82 @@ impl ScrapeElementFromWebsiteTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "ScrapeElementFromWebsiteTool: not yet implemented - requires CSS selector-based scraping"
-        )
+    /// Extract the elements matching a CSS selector.
+    ///
+    /// Returns `{url, css_selector, output_format, count, matches}`; each
+    /// match has `text` (rendered in `output_format`), the inner `html` and
+    /// whichever of `href`, `src`, `alt` and `title` it carries, with
+    /// `href`/`src` resolved against the page URL.
+    ///
+    /// # Arguments (in `args`)
+    /// * `website_url` - The URL to scrape.
+    /// * `css_selector` - Selector to match (overrides the configured one).
+    /// * `output_format` - `"text"`, `"markdown"` or `"html"` (overrides the
+    ///   configured format).
+    /// * `headers` - Object of extra headers, merged over the configured ones.
+    /// * `cookies` - Object or `[name, value]` list, merged likewise.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("website_url")
+            .and_then(|v| v.as_str())
+            .or(self.website_url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: website_url"))?;
+        let css_selector = args
+            .get("css_selector")
+            .and_then(|v| v.as_str())
+            .or(self.css_selector.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: css_selector"))?;
+        let format = OutputFormat::from_args(&args, self.output_format)?;
+        let headers = self.request_headers.merged(&args)?;
+
+        let selector = scraper::Selector::parse(css_selector)
+            .map_err(|e| anyhow::anyhow!("Invalid CSS selector '{}': {}", css_selector, e))?;
+
+        let page = fetch_html(url, &CrawlGuard::NONE, &headers)?;
+        let base = reqwest::Url::parse(&page.url).ok();
+        let document = scraper::Html::parse_document(&page.body);
+
+        let matches: Vec<Value> = document
+            .select(&selector)
+            .map(|element| {
+                let text = match format {
+                    OutputFormat::Text => element
+                        .text()
+                        .collect::<String>()
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    OutputFormat::Markdown => crate::html::html_to_markdown(&element.html()),
+                    OutputFormat::Html => element.html(),
+                };
+                let mut entry = serde_json::json!({
+                    "text": text,
+                    "html": element.inner_html(),
+                });
+                for name in ["href", "src", "alt", "title"] {
+                    let Some(value) = element.value().attr(name) else {
+                        continue;
+                    };
+                    let value = match (name, &base) {
+                        ("href" | "src", Some(base)) => base
+                            .join(value)
+                            .map(|u| u.to_string())
+                            .unwrap_or_else(|_| value.to_string()),
+                        _ => value.to_string(),
+                    };
+                    entry[name] = Value::String(value);
+                }
+                entry
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "url": page.url,
+            "css_selector": css_selector,
+            "output_format": format.as_str(),
+            "count": matches.len(),
+            "matches": matches,
+        }))
     }
 }
 
@@ -118,6 +431,10 @@ impl Default for ScrapeElementFromWebsiteTool {
 
 /// Crawl an entire website using the Firecrawl API.
 ///
+/// Crawls run as asynchronous Firecrawl jobs: the tool starts one, polls it
+/// every `poll_interval_ms`, and gives up waiting after `timeout`,
+/// returning whatever pages were scraped by then.
+///
 /// Corresponds to Python `FirecrawlCrawlWebsiteTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FirecrawlCrawlWebsiteTool {
@@ -127,6 +444,16 @@ pub struct FirecrawlCrawlWebsiteTool {
     pub url: Option<String>,
     /// Maximum number of pages to crawl.
     pub max_pages: usize,
+    /// Delay between job status checks, in milliseconds.
+    pub poll_interval_ms: u64,
+    /// How long to wait for the crawl to finish.
+    pub timeout: std::time::Duration,
+    /// Optional credit cap. Firecrawl bills one credit per crawled page.
+    #[serde(skip)]
+    pub credit_budget: Option<CreditBudget>,
+    /// API root; defaults to `https://api.firecrawl.dev/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl FirecrawlCrawlWebsiteTool {
@@ -135,6 +462,10 @@ impl FirecrawlCrawlWebsiteTool {
             api_key: None,
             url: None,
             max_pages: 100,
+            poll_interval_ms: 2000,
+            timeout: std::time::Duration::from_secs(600),
+            credit_budget: None,
+            base_url: None,
         }
     }
 
@@ -143,6 +474,11 @@ impl FirecrawlCrawlWebsiteTool {
         self
     }
 
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
         self
@@ -153,10 +489,175 @@ impl FirecrawlCrawlWebsiteTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "FirecrawlCrawlWebsiteTool: not yet implemented - requires Firecrawl API integration"
-        )
+    pub fn with_poll_interval_ms(mut self, ms: u64) -> Self {
+        self.poll_interval_ms = ms;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Cap the credits this tool may consume.
+    pub fn with_credit_budget(mut self, credits: u64) -> Self {
+        self.credit_budget = Some(CreditBudget::new(credits));
+        self
+    }
+
+    /// Draw from a budget shared with other tool instances.
+    pub fn with_shared_credit_budget(mut self, budget: CreditBudget) -> Self {
+        self.credit_budget = Some(budget);
+        self
+    }
+
+    /// Running total of credits consumed against the budget (0 without one).
+    pub fn credits_used(&self) -> u64 {
+        self.credit_budget.as_ref().map_or(0, |b| b.consumed())
+    }
+
+    /// Crawl a website and return the markdown of every page reached.
+    ///
+    /// The crawl is polled until it completes, and the result's `status` is
+    /// `"completed"`. If the credit budget runs out while it is still running,
+    /// the crawl is cancelled and the pages scraped so far are returned with
+    /// `budget_exhausted: true` and status `"cancelled"`. If `timeout`
+    /// passes first, the pages so far are returned with status `"timeout"`;
+    /// the job keeps running on Firecrawl's side under the returned
+    /// `crawl_id`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - The URL to start crawling from (optional if set on struct).
+    /// * `max_pages` - Override the page limit for this crawl.
+    /// * `include_paths` - Optional array of path regexes to restrict the crawl to.
+    /// * `exclude_paths` - Optional array of path regexes to skip.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .or(self.url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+
+        let firecrawl = Firecrawl::connect(self.api_key.as_deref(), self.base_url.as_deref())?;
+
+        let max_pages = args
+            .get("max_pages")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.max_pages);
+
+        let budget = self.credit_budget.as_ref();
+        if budget.is_some_and(|b| b.is_exhausted()) {
+            return Ok(serde_json::json!({
+                "url": url,
+                "status": "cancelled",
+                "pages": [],
+                "count": 0,
+                "credits_used": 0,
+                "budget_exhausted": true,
+            }));
+        }
+        let limit = match budget {
+            Some(b) => max_pages.min(b.remaining() as usize),
+            None => max_pages,
+        };
+
+        let mut request = serde_json::json!({
+            "url": url,
+            "limit": limit,
+            "scrapeOptions": { "formats": ["markdown"] },
+        });
+        for (arg, field) in [("include_paths", "includePaths"), ("exclude_paths", "excludePaths")] {
+            if let Some(paths) = args.get(arg).and_then(|v| v.as_array()) {
+                request[field] = Value::Array(paths.clone());
+            }
+        }
+
+        let deadline = std::time::Instant::now() + self.timeout;
+        let poll_interval = std::time::Duration::from_millis(self.poll_interval_ms);
+        let started = firecrawl.post("crawl", &request)?;
+        let crawl_id = started
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Firecrawl did not return a crawl id: {}", started))?;
+        let status_url = format!("{}/crawl/{}", firecrawl.api, crawl_id);
+
+        let mut charged = 0u64;
+        let mut budget_exhausted = false;
+        let mut outcome = "completed";
+        let status = loop {
+            let status = firecrawl.get(&status_url)?;
+
+            // `creditsUsed` is cumulative for the crawl; charge only the delta.
+            if let Some(used) = usage_at(&status, "/creditsUsed") {
+                if used > charged {
+                    if let Some(b) = budget {
+                        b.charge(used - charged);
+                    }
+                    charged = used;
+                }
+            }
+
+            match status.get("status").and_then(|v| v.as_str()) {
+                Some("completed") => break status,
+                Some("failed") | Some("cancelled") => {
+                    anyhow::bail!("Firecrawl crawl {} ended with status: {}", crawl_id, status["status"])
+                }
+                _ => {}
+            }
+
+            if budget.is_some_and(|b| b.is_exhausted()) {
+                budget_exhausted = true;
+                outcome = "cancelled";
+                if let Err(e) = firecrawl.delete(&status_url) {
+                    log::warn!("Failed to cancel Firecrawl crawl {}: {}", crawl_id, e);
+                }
+                break status;
+            }
+
+            if std::time::Instant::now() + poll_interval >= deadline {
+                log::warn!(
+                    "Firecrawl crawl {} still running after {:?}; returning partial results",
+                    crawl_id,
+                    self.timeout
+                );
+                outcome = "timeout";
+                break status;
+            }
+            std::thread::sleep(poll_interval);
+        };
+
+        // Results are paginated; follow `next` until every page is collected.
+        let mut pages: Vec<Value> = Vec::new();
+        let mut current = status;
+        loop {
+            if let Some(Value::Array(data)) = current.get("data") {
+                pages.extend(data.iter().map(|page| {
+                    serde_json::json!({
+                        "url": page.pointer("/metadata/sourceURL").cloned().unwrap_or(Value::Null),
+                        "title": page.pointer("/metadata/title").cloned().unwrap_or(Value::Null),
+                        "markdown": page.get("markdown").cloned().unwrap_or(Value::Null),
+                    })
+                }));
+            }
+            match current.get("next").and_then(|v| v.as_str()) {
+                Some(next) if pages.len() < limit => {
+                    current = firecrawl.get(next)?;
+                }
+                _ => break,
+            }
+        }
+        pages.truncate(limit);
+
+        Ok(serde_json::json!({
+            "url": url,
+            "crawl_id": crawl_id,
+            "status": outcome,
+            "count": pages.len(),
+            "pages": pages,
+            "credits_used": charged,
+            "budget_exhausted": budget_exhausted,
+        }))
     }
 }
 
@@ -177,13 +678,23 @@ pub struct FirecrawlScrapeWebsiteTool {
     pub api_key: Option<String>,
     /// URL to scrape.
     pub url: Option<String>,
+    /// Output formats to request ("markdown", "html", "links").
+    pub formats: Vec<String>,
+    /// API root; defaults to `https://api.firecrawl.dev/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
+/// Formats `FirecrawlScrapeWebsiteTool` can request.
+const FIRECRAWL_FORMATS: &[&str] = &["markdown", "html", "links"];
+
 impl FirecrawlScrapeWebsiteTool {
     pub fn new() -> Self {
         Self {
             api_key: None,
             url: None,
+            formats: vec!["markdown".to_string()],
+            base_url: None,
         }
     }
 
@@ -192,15 +703,132 @@ impl FirecrawlScrapeWebsiteTool {
         self
     }
 
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "FirecrawlScrapeWebsiteTool: not yet implemented - requires Firecrawl API integration"
-        )
+    pub fn with_formats(mut self, formats: Vec<String>) -> Self {
+        self.formats = formats;
+        self
+    }
+
+    /// Scrape one page through Firecrawl's `/v1/scrape` endpoint.
+    ///
+    /// Returns the requested formats alongside `url`, `title`,
+    /// `status_code` and the full Firecrawl `metadata`.
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - The URL to scrape (optional if set on struct).
+    /// * `formats` - Optional array of "markdown", "html" and "links".
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .or(self.url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+
+        let formats: Vec<String> = match args.get("formats").and_then(|v| v.as_array()) {
+            Some(formats) => formats
+                .iter()
+                .filter_map(|f| f.as_str().map(str::to_string))
+                .collect(),
+            None => self.formats.clone(),
+        };
+        if formats.is_empty() {
+            anyhow::bail!("formats must name at least one of {}", FIRECRAWL_FORMATS.join(", "));
+        }
+        if let Some(unknown) = formats.iter().find(|f| !FIRECRAWL_FORMATS.contains(&f.as_str())) {
+            anyhow::bail!(
+                "Unsupported Firecrawl format '{}': expected one of {}",
+                unknown,
+                FIRECRAWL_FORMATS.join(", ")
+            );
+        }
+
+        let firecrawl = Firecrawl::connect(self.api_key.as_deref(), self.base_url.as_deref())?;
+        let request = serde_json::json!({ "url": url, "formats": formats });
+        let body = firecrawl.post("scrape", &request)?;
+
+        let data = body.get("data").cloned().unwrap_or(Value::Null);
+        let metadata = data.get("metadata").cloned().unwrap_or(Value::Null);
+        let mut result = serde_json::json!({
+            "url": metadata.get("sourceURL").and_then(|v| v.as_str()).unwrap_or(url),
+            "title": metadata.get("title").cloned().unwrap_or(Value::Null),
+            "status_code": metadata.get("statusCode").cloned().unwrap_or(Value::Null),
+        });
+        for format in &formats {
+            result[format.as_str()] = data.get(format.as_str()).cloned().unwrap_or(Value::Null);
+        }
+        result["metadata"] = metadata;
+        Ok(result)
+    }
+}
+
+/// Base URL of the Firecrawl v1 API.
+const FIRECRAWL_API: &str = "https://api.firecrawl.dev/v1";
+
+/// An authenticated Firecrawl API client shared by the Firecrawl tools.
+struct Firecrawl {
+    client: reqwest::blocking::Client,
+    api_key: String,
+    /// API root, without a trailing slash.
+    api: String,
+}
+
+impl Firecrawl {
+    /// Use `api_key`, falling back to `FIRECRAWL_API_KEY`, against `base_url`
+    /// (default [`FIRECRAWL_API`]).
+    fn connect(api_key: Option<&str>, base_url: Option<&str>) -> Result<Self, anyhow::Error> {
+        let api_key = api_key
+            .map(str::to_string)
+            .or_else(|| std::env::var("FIRECRAWL_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing FIRECRAWL_API_KEY"))?;
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let api = base_url.unwrap_or(FIRECRAWL_API).trim_end_matches('/').to_string();
+        Ok(Self { client, api_key, api })
+    }
+
+    /// POST `body` to the v1 endpoint `path` (e.g. `"scrape"`).
+    fn post(&self, path: &str, body: &Value) -> Result<Value, anyhow::Error> {
+        let url = format!("{}/{}", self.api, path);
+        Self::body(self.client.post(url).bearer_auth(&self.api_key).json(body).send()?)
+    }
+
+    /// GET an absolute URL, such as a crawl status or `next` page link.
+    fn get(&self, url: &str) -> Result<Value, anyhow::Error> {
+        Self::body(self.client.get(url).bearer_auth(&self.api_key).send()?)
+    }
+
+    /// DELETE an absolute URL, such as a running crawl job.
+    fn delete(&self, url: &str) -> Result<(), anyhow::Error> {
+        Self::body(self.client.delete(url).bearer_auth(&self.api_key).send()?).map(|_| ())
+    }
+
+    /// Parse a response, turning Firecrawl's `{"success": false, "error": …}`
+    /// envelope (sent with both 2xx and error statuses) into an `Err`.
+    fn body(resp: reqwest::blocking::Response) -> Result<Value, anyhow::Error> {
+        let status = resp.status();
+        let body: Value = match resp.json() {
+            Ok(body) => body,
+            Err(e) if status.is_success() => return Err(e.into()),
+            Err(_) => anyhow::bail!("Firecrawl request failed: HTTP {}", status),
+        };
+        if body.get("success") == Some(&Value::Bool(false)) || !status.is_success() {
+            let message = body
+                .get("error")
+                .and_then(|e| e.as_str())
+                .unwrap_or("unknown error");
+            anyhow::bail!("Firecrawl request failed (HTTP {}): {}", status, message);
+        }
+        Ok(body)
     }
 }
 
@@ -221,6 +849,11 @@ pub struct FirecrawlSearchTool {
     pub api_key: Option<String>,
     /// Maximum number of results.
     pub max_results: usize,
+    /// Whether to scrape each hit and include its markdown.
+    pub scrape_results: bool,
+    /// API root; defaults to `https://api.firecrawl.dev/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl FirecrawlSearchTool {
@@ -228,6 +861,8 @@ impl FirecrawlSearchTool {
         Self {
             api_key: None,
             max_results: 10,
+            scrape_results: false,
+            base_url: None,
         }
     }
 
@@ -236,15 +871,80 @@ impl FirecrawlSearchTool {
         self
     }
 
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_max_results(mut self, n: usize) -> Self {
         self.max_results = n;
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "FirecrawlSearchTool: not yet implemented - requires Firecrawl API integration"
-        )
+    pub fn with_scrape_results(mut self, scrape: bool) -> Self {
+        self.scrape_results = scrape;
+        self
+    }
+
+    /// Search the web through Firecrawl's `/v1/search` endpoint.
+    ///
+    /// Returns `{query, count, results}` with each result normalized to
+    /// `{title, url, description}`, plus `markdown` when results are scraped.
+    ///
+    /// # Arguments (in `args`)
+    /// * `query` - The search query string.
+    /// * `scrape_results` - Optional override of `self.scrape_results`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: query"))?;
+        let scrape = args
+            .get("scrape_results")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(self.scrape_results);
+
+        let firecrawl = Firecrawl::connect(self.api_key.as_deref(), self.base_url.as_deref())?;
+        let mut request = serde_json::json!({
+            "query": query,
+            "limit": self.max_results,
+        });
+        if scrape {
+            request["scrapeOptions"] = serde_json::json!({ "formats": ["markdown"] });
+        }
+        let body = firecrawl.post("search", &request)?;
+
+        let results: Vec<Value> = body
+            .get("data")
+            .and_then(|d| d.as_array())
+            .map(|hits| {
+                hits.iter()
+                    .map(|hit| {
+                        let field = |key: &str| {
+                            hit.get(key)
+                                .or_else(|| hit.pointer(&format!("/metadata/{}", key)))
+                                .cloned()
+                                .unwrap_or(Value::Null)
+                        };
+                        let mut result = serde_json::json!({
+                            "title": field("title"),
+                            "url": field("url"),
+                            "description": field("description"),
+                        });
+                        if scrape {
+                            result["markdown"] = field("markdown");
+                        }
+                        result
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(serde_json::json!({
+            "query": query,
+            "count": results.len(),
+            "results": results,
+        }))
     }
 }
 
@@ -292,6 +992,9 @@ impl Default for JinaScrapeWebsiteTool {
 
 /// Scrape JavaScript-rendered websites using Selenium WebDriver.
 ///
+/// Talks the W3C WebDriver protocol directly to a running driver or Selenium
+/// server (chromedriver, `selenium/standalone-chrome`, ...).
+///
 /// Corresponds to Python `SeleniumScrapingTool` in `crewai_tools`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeleniumScrapingTool {
@@ -299,8 +1002,14 @@ pub struct SeleniumScrapingTool {
     pub website_url: Option<String>,
     /// CSS selector for element extraction.
     pub css_selector: Option<String>,
-    /// Wait timeout in seconds for page load.
-    pub wait_timeout: u64,
+    /// How long to wait for the page or selector.
+    pub wait_timeout: std::time::Duration,
+    /// WebDriver endpoint (default `http://localhost:4444`).
+    pub webdriver_url: String,
+    /// Run Chrome headless.
+    pub headless: bool,
+    /// Also capture a PNG screenshot, returned base64-encoded.
+    pub screenshot: bool,
 }
 
 impl SeleniumScrapingTool {
@@ -308,7 +1017,10 @@ impl SeleniumScrapingTool {
         Self {
             website_url: None,
             css_selector: None,
-            wait_timeout: 10,
+            wait_timeout: std::time::Duration::from_secs(10),
+            webdriver_url: "http://localhost:4444".to_string(),
+            headless: true,
+            screenshot: false,
         }
     }
 
@@ -322,16 +1034,220 @@ impl SeleniumScrapingTool {
         self
     }
 
-    pub fn with_wait_timeout(mut self, timeout: u64) -> Self {
+    pub fn with_wait_timeout(mut self, timeout: std::time::Duration) -> Self {
         self.wait_timeout = timeout;
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "SeleniumScrapingTool: not yet implemented - requires Selenium WebDriver integration"
+    pub fn with_webdriver_url(mut self, url: impl Into<String>) -> Self {
+        self.webdriver_url = url.into();
+        self
+    }
+
+    pub fn with_headless(mut self, headless: bool) -> Self {
+        self.headless = headless;
+        self
+    }
+
+    pub fn with_screenshot(mut self, screenshot: bool) -> Self {
+        self.screenshot = screenshot;
+        self
+    }
+
+    /// Chrome capabilities requested when opening a session.
+    fn capabilities(&self) -> Value {
+        let mut chrome_args = vec!["--no-sandbox", "--disable-dev-shm-usage"];
+        if self.headless {
+            chrome_args.extend(["--headless=new", "--disable-gpu", "--window-size=1920,1080"]);
+        }
+        serde_json::json!({
+            "capabilities": {
+                "alwaysMatch": {
+                    "browserName": "chrome",
+                    "goog:chromeOptions": { "args": chrome_args },
+                }
+            }
+        })
+    }
+
+    /// Load the page in a real browser and extract the rendered content.
+    ///
+    /// Waits up to `wait_timeout` for `css_selector` to match, or for
+    /// `document.readyState` to reach `"complete"` when there is no selector.
+    /// The browser session is closed whether or not extraction succeeds.
+    ///
+    /// Returns `{url, css_selector, count, matches: [{text, html}], text}`,
+    /// plus `screenshot` (base64 PNG) when enabled.
+    ///
+    /// # Arguments (in `args`)
+    /// * `website_url` - The URL to load (optional if set on struct).
+    /// * `css_selector` - Override the configured selector.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("website_url")
+            .and_then(|v| v.as_str())
+            .or(self.website_url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: website_url"))?;
+        let selector = args
+            .get("css_selector")
+            .and_then(|v| v.as_str())
+            .or(self.css_selector.as_deref());
+
+        let session = WebDriverSession::open(&self.webdriver_url, &self.capabilities())?;
+        session.post("url", serde_json::json!({ "url": url }))?;
+
+        let deadline = std::time::Instant::now() + self.wait_timeout;
+        let elements = loop {
+            let ready = match selector {
+                Some(css) => {
+                    let found = session.find_all(css)?;
+                    (!found.is_empty()).then_some(found)
+                }
+                None => {
+                    if session.execute("return document.readyState;")? == "complete" {
+                        Some(session.find_all("html")?)
+                    } else {
+                        None
+                    }
+                }
+            };
+            if let Some(found) = ready {
+                break found;
+            }
+            if std::time::Instant::now() >= deadline {
+                match selector {
+                    Some(css) => anyhow::bail!(
+                        "Timed out after {:?} waiting for selector '{}' on {}",
+                        self.wait_timeout,
+                        css,
+                        url
+                    ),
+                    None => anyhow::bail!(
+                        "Timed out after {:?} waiting for {} to finish loading",
+                        self.wait_timeout,
+                        url
+                    ),
+                }
+            }
+            std::thread::sleep(std::time::Duration::from_millis(250));
+        };
+
+        let mut matches = Vec::with_capacity(elements.len());
+        for element in &elements {
+            let text = session.get(&format!("element/{}/text", element))?;
+            let html = session.get(&format!("element/{}/property/outerHTML", element))?;
+            matches.push(serde_json::json!({ "text": text, "html": html }));
+        }
+        let text = matches
+            .iter()
+            .filter_map(|m| m["text"].as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let mut result = serde_json::json!({
+            "url": url,
+            "css_selector": selector,
+            "count": matches.len(),
+            "matches": matches,
+            "text": text,
+        });
+        if self.screenshot {
+            result["screenshot"] = session.get("screenshot")?;
+        }
+        Ok(result)
+    }
+}
+
+/// Element reference key defined by the W3C WebDriver spec.
+const WEBDRIVER_ELEMENT: &str = "element-6066-11e4-a52e-4f735466cecc";
+
+/// An open WebDriver session, deleted when dropped.
+struct WebDriverSession {
+    client: reqwest::blocking::Client,
+    base: String,
+}
+
+impl WebDriverSession {
+    fn open(webdriver_url: &str, capabilities: &Value) -> Result<Self, anyhow::Error> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let root = webdriver_url.trim_end_matches('/');
+        let resp = client
+            .post(format!("{}/session", root))
+            .json(capabilities)
+            .send()
+            .map_err(|e| anyhow::anyhow!("Could not reach WebDriver at {}: {}", root, e))?;
+        let value = Self::value(resp)?;
+        let id = value
+            .get("sessionId")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("WebDriver did not return a session id"))?;
+        Ok(Self {
+            client,
+            base: format!("{}/session/{}", root, id),
+        })
+    }
+
+    /// Unwrap the `value` of a WebDriver response, surfacing protocol errors.
+    fn value(resp: reqwest::blocking::Response) -> Result<Value, anyhow::Error> {
+        let status = resp.status();
+        let mut body: Value = resp.json()?;
+        let value = body["value"].take();
+        if !status.is_success() {
+            anyhow::bail!(
+                "WebDriver error (HTTP {}): {}: {}",
+                status,
+                value["error"].as_str().unwrap_or("unknown error"),
+                value["message"].as_str().unwrap_or("")
+            );
+        }
+        Ok(value)
+    }
+
+    fn get(&self, path: &str) -> Result<Value, anyhow::Error> {
+        Self::value(self.client.get(format!("{}/{}", self.base, path)).send()?)
+    }
+
+    fn post(&self, path: &str, body: Value) -> Result<Value, anyhow::Error> {
+        Self::value(
+            self.client
+                .post(format!("{}/{}", self.base, path))
+                .json(&body)
+                .send()?,
         )
     }
+
+    fn execute(&self, script: &str) -> Result<Value, anyhow::Error> {
+        self.post(
+            "execute/sync",
+            serde_json::json!({ "script": script, "args": [] }),
+        )
+    }
+
+    /// Element ids matching a CSS selector (empty when nothing matches).
+    fn find_all(&self, css: &str) -> Result<Vec<String>, anyhow::Error> {
+        let found = self.post(
+            "elements",
+            serde_json::json!({ "using": "css selector", "value": css }),
+        )?;
+        Ok(found
+            .as_array()
+            .map(|list| {
+                list.iter()
+                    .filter_map(|e| e[WEBDRIVER_ELEMENT].as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+impl Drop for WebDriverSession {
+    fn drop(&mut self) {
+        if let Err(e) = self.client.delete(&self.base).send() {
+            log::warn!("Failed to close WebDriver session {}: {}", self.base, e);
+        }
+    }
 }
 
 impl Default for SeleniumScrapingTool {
@@ -342,6 +1258,22 @@ impl Default for SeleniumScrapingTool {
 
 // ── ScrapflyScrapeWebsiteTool ────────────────────────────────────────────────
 
+/// Returned (wrapped in `anyhow::Error`) when Scrapfly throttles a request;
+/// callers can `downcast_ref::<ScrapflyThrottled>()` and wait
+/// `retry_after_secs` before trying again.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Scrapfly throttled the request ({code}): {message}{}", retry_hint(.retry_after_secs))]
+pub struct ScrapflyThrottled {
+    pub code: String,
+    pub message: String,
+    pub retry_after_secs: Option<u64>,
+}
+
+fn retry_hint(secs: &Option<u64>) -> String {
+    secs.map(|s| format!("; retry after {}s", s))
+        .unwrap_or_default()
+}
+
 /// Scrape websites using the Scrapfly API with anti-bot bypass.
 ///
 /// Corresponds to Python `ScrapflyScrapeWebsiteTool` in `crewai_tools`.
@@ -353,6 +1285,12 @@ pub struct ScrapflyScrapeWebsiteTool {
     pub url: Option<String>,
     /// Whether to enable anti-scraping protection bypass.
     pub anti_scraping: bool,
+    /// Optional credit cap, charged from Scrapfly's reported API cost.
+    #[serde(skip)]
+    pub credit_budget: Option<CreditBudget>,
+    /// API root; defaults to `https://api.scrapfly.io`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl ScrapflyScrapeWebsiteTool {
@@ -361,6 +1299,8 @@ impl ScrapflyScrapeWebsiteTool {
             api_key: None,
             url: None,
             anti_scraping: true,
+            credit_budget: None,
+            base_url: None,
         }
     }
 
@@ -369,6 +1309,11 @@ impl ScrapflyScrapeWebsiteTool {
         self
     }
 
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
         self
@@ -379,10 +1324,157 @@ impl ScrapflyScrapeWebsiteTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "ScrapflyScrapeWebsiteTool: not yet implemented - requires Scrapfly API integration"
-        )
+    /// Cap the credits this tool may consume.
+    pub fn with_credit_budget(mut self, credits: u64) -> Self {
+        self.credit_budget = Some(CreditBudget::new(credits));
+        self
+    }
+
+    /// Draw from a budget shared with other tool instances.
+    pub fn with_shared_credit_budget(mut self, budget: CreditBudget) -> Self {
+        self.credit_budget = Some(budget);
+        self
+    }
+
+    /// Running total of credits consumed against the budget (0 without one).
+    pub fn credits_used(&self) -> u64 {
+        self.credit_budget.as_ref().map_or(0, |b| b.consumed())
+    }
+
+    /// Scrape one or more URLs through Scrapfly.
+    ///
+    /// URLs are scraped in order. Once the credit budget is spent no further
+    /// requests are made and the results so far are returned with
+    /// `budget_exhausted: true`. Each result carries the API credits it cost
+    /// and Scrapfly's cost breakdown. Throttled requests fail with
+    /// [`ScrapflyThrottled`].
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - A single URL to scrape (optional if set on struct).
+    /// * `urls` - A list of URLs to scrape instead of `url`.
+    /// * `format` - `"markdown"` (default) or `"raw"` HTML.
+    /// * `render_js` - Render the page in a headless browser (default false).
+    /// * `country` - Proxy country code, e.g. `"us"`.
+    /// * `proxy_pool` - e.g. `"public_datacenter_pool"` or
+    ///   `"public_residential_pool"`.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let urls: Vec<String> = match args.get("urls").and_then(|v| v.as_array()) {
+            Some(list) => list
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect(),
+            None => vec![args
+                .get("url")
+                .and_then(|v| v.as_str())
+                .or(self.url.as_deref())
+                .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?
+                .to_string()],
+        };
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("SCRAPFLY_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing SCRAPFLY_API_KEY"))?;
+
+        let format = args
+            .get("format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("markdown");
+        if format != "markdown" && format != "raw" {
+            anyhow::bail!("Unknown format '{}': expected 'markdown' or 'raw'", format);
+        }
+        let render_js = args
+            .get("render_js")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let mut params: Vec<(&str, String)> = vec![
+            ("key", api_key),
+            ("format", format.to_string()),
+            ("asp", self.anti_scraping.to_string()),
+            ("render_js", render_js.to_string()),
+        ];
+        for name in ["country", "proxy_pool"] {
+            if let Some(value) = args.get(name).and_then(|v| v.as_str()) {
+                params.push((name, value.to_string()));
+            }
+        }
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(150))
+            .build()?;
+        let api = self.base_url.as_deref().unwrap_or("https://api.scrapfly.io");
+        let endpoint = format!("{}/scrape", api.trim_end_matches('/'));
+
+        let budget = self.credit_budget.as_ref();
+        let mut results = Vec::new();
+        let mut credits_used = 0u64;
+        let mut budget_exhausted = false;
+
+        for url in &urls {
+            if budget.is_some_and(|b| b.is_exhausted()) {
+                budget_exhausted = true;
+                break;
+            }
+
+            let resp = client
+                .get(&endpoint)
+                .query(&params)
+                .query(&[("url", url)])
+                .send()?;
+            let header = |name: &str| {
+                resp.headers()
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+            };
+            let header_cost = header("X-Scrapfly-Api-Cost");
+            let retry_after = header("Retry-After");
+            let status = resp.status();
+            let body = resp.json::<Value>().unwrap_or(Value::Null);
+            if !status.is_success() {
+                let field = |key: &str| body.get(key).and_then(Value::as_str);
+                let code = field("code").unwrap_or_default().to_string();
+                let message = field("message").unwrap_or("no details").to_string();
+                if status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                    || code.starts_with("ERR::THROTTLE")
+                {
+                    let retry_after_secs =
+                        retry_after.or_else(|| body.get("retry_delay").and_then(Value::as_u64));
+                    return Err(ScrapflyThrottled { code, message, retry_after_secs }.into());
+                }
+                anyhow::bail!(
+                    "Scrapfly request for {} failed (HTTP {}): {}",
+                    url,
+                    status,
+                    message
+                );
+            }
+
+            let cost = header_cost
+                .or_else(|| usage_at(&body, "/context/cost/total"))
+                .unwrap_or(1);
+            credits_used += cost;
+            if let Some(b) = budget {
+                b.charge(cost);
+            }
+
+            results.push(serde_json::json!({
+                "url": url,
+                "status_code": body.pointer("/result/status_code").cloned().unwrap_or(Value::Null),
+                "content": body.pointer("/result/content").cloned().unwrap_or(Value::Null),
+                "format": format,
+                "cost": cost,
+                "cost_details": body.pointer("/context/cost").cloned().unwrap_or(Value::Null),
+            }));
+        }
+
+        Ok(serde_json::json!({
+            "count": results.len(),
+            "results": results,
+            "credits_used": credits_used,
+            "budget_exhausted": budget_exhausted,
+        }))
     }
 }
 
@@ -447,6 +1539,9 @@ pub struct SerperScrapeWebsiteTool {
     pub api_key: Option<String>,
     /// URL to scrape.
     pub url: Option<String>,
+    /// Override of the API endpoint (for proxies and testing).
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl SerperScrapeWebsiteTool {
@@ -454,6 +1549,7 @@ impl SerperScrapeWebsiteTool {
         Self {
             api_key: None,
             url: None,
+            base_url: None,
         }
     }
 
@@ -467,10 +1563,72 @@ impl SerperScrapeWebsiteTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!(
-            "SerperScrapeWebsiteTool: not yet implemented - requires Serper.dev API integration"
-        )
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Scrape a page through Serper.
+    ///
+    /// Returns `{url, text, markdown, title, description, metadata, jsonld}`.
+    /// `markdown` is null unless requested; `jsonld` lists every JSON-LD
+    /// block Serper found on the page.
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - The URL to scrape (optional if set on struct).
+    /// * `include_markdown` - Also return the page as markdown (default true).
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .or(self.url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+        let include_markdown = args
+            .get("include_markdown")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let api_key = serper::api_key(self.api_key.as_deref())?;
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()?;
+        let endpoint = self.base_url.as_deref().unwrap_or("https://scrape.serper.dev");
+        let mut request = client.post(endpoint).json(&serde_json::json!({
+            "url": url,
+            "includeMarkdown": include_markdown,
+        }));
+        for (name, value) in serper::headers(api_key) {
+            request = request.header(name, value);
+        }
+        let resp = request.send()?;
+        let status = resp.status();
+        let body: Value = resp.json().unwrap_or(Value::Null);
+        if !status.is_success() {
+            let message = body
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no details");
+            anyhow::bail!("Serper scrape failed (HTTP {}): {}", status, message);
+        }
+
+        let metadata = body.get("metadata").cloned().unwrap_or(Value::Null);
+        let meta = |key: &str| metadata.get(key).cloned().unwrap_or(Value::Null);
+        let (title, description) = (meta("title"), meta("description"));
+        let jsonld = match body.get("jsonld") {
+            Some(Value::Array(blocks)) => blocks.clone(),
+            Some(Value::Null) | None => Vec::new(),
+            Some(block) => vec![block.clone()],
+        };
+
+        Ok(serde_json::json!({
+            "url": url,
+            "text": body.get("text").cloned().unwrap_or(Value::Null),
+            "markdown": body.get("markdown").cloned().unwrap_or(Value::Null),
+            "title": title,
+            "description": description,
+            "metadata": metadata,
+            "jsonld": jsonld,
+        }))
     }
 }
 
@@ -482,6 +1640,19 @@ impl Default for SerperScrapeWebsiteTool {
 
 // ── SpiderTool ───────────────────────────────────────────────────────────────
 
+/// Errors from the Spider API that callers may want to tell apart.
+///
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<SpiderError>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SpiderError {
+    /// The account is out of credits. Retrying will not help.
+    #[error("Spider quota exceeded: {0}")]
+    QuotaExceeded(String),
+    /// Any other non-success response.
+    #[error("Spider request failed (HTTP {status}): {message}")]
+    Api { status: u16, message: String },
+}
+
 /// Web crawling and scraping tool using the Spider API.
 ///
 /// Corresponds to Python `SpiderTool` in `crewai_tools`.
@@ -493,6 +1664,14 @@ pub struct SpiderTool {
     pub url: Option<String>,
     /// Maximum depth for crawling.
     pub max_depth: usize,
+    /// Maximum number of pages to crawl.
+    pub max_pages: usize,
+    /// Optional page budget. Every page Spider returns counts as one credit.
+    #[serde(skip)]
+    pub credit_budget: Option<CreditBudget>,
+    /// API root; defaults to `https://api.spider.cloud`.
+    #[serde(default)]
+    pub base_url: Option<String>,
 }
 
 impl SpiderTool {
@@ -501,6 +1680,9 @@ impl SpiderTool {
             api_key: None,
             url: None,
             max_depth: 3,
+            max_pages: 50,
+            credit_budget: None,
+            base_url: None,
         }
     }
 
@@ -509,6 +1691,11 @@ impl SpiderTool {
         self
     }
 
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
         self.url = Some(url.into());
         self
@@ -519,8 +1706,170 @@ impl SpiderTool {
         self
     }
 
-    pub fn run(&self, _args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
-        anyhow::bail!("SpiderTool: not yet implemented - requires Spider API integration")
+    pub fn with_max_pages(mut self, n: usize) -> Self {
+        self.max_pages = n;
+        self
+    }
+
+    /// Cap the pages this tool may consume.
+    pub fn with_credit_budget(mut self, credits: u64) -> Self {
+        self.credit_budget = Some(CreditBudget::new(credits));
+        self
+    }
+
+    /// Draw from a budget shared with other tool instances.
+    pub fn with_shared_credit_budget(mut self, budget: CreditBudget) -> Self {
+        self.credit_budget = Some(budget);
+        self
+    }
+
+    /// Running total of credits consumed against the budget (0 without one).
+    pub fn credits_used(&self) -> u64 {
+        self.credit_budget.as_ref().map_or(0, |b| b.consumed())
+    }
+
+    /// Crawl a website with Spider and return each page as markdown.
+    ///
+    /// The crawl limit is capped at the remaining budget. If the budget is
+    /// already spent no request is made; if Spider returns more pages than
+    /// the budget allows, the extra pages are dropped. Either way the result
+    /// carries `budget_exhausted: true`.
+    ///
+    /// Pages are streamed as JSON Lines and collected as they arrive, so a
+    /// crawl that hits the budget stops reading early. A response saying the
+    /// account is out of credits (HTTP 402) fails with
+    /// [`SpiderError::QuotaExceeded`].
+    ///
+    /// # Arguments (in `args`)
+    /// * `url` - The URL to crawl (optional if set on struct).
+    /// * `mode` - `"crawl"` (default) or `"scrape"` for a single page.
+    /// * `limit` - Override `max_pages` for this crawl.
+    /// * `return_format` - `"markdown"` (default) or `"raw"` HTML.
+    pub fn run(&self, args: HashMap<String, Value>) -> Result<Value, anyhow::Error> {
+        let url = args
+            .get("url")
+            .and_then(|v| v.as_str())
+            .or(self.url.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Missing required argument: url"))?;
+
+        let api_key = self
+            .api_key
+            .clone()
+            .or_else(|| std::env::var("SPIDER_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing SPIDER_API_KEY"))?;
+
+        let mode = args.get("mode").and_then(|v| v.as_str()).unwrap_or("crawl");
+        let max_pages = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|n| n as usize)
+            .unwrap_or(self.max_pages);
+        let (endpoint, requested) = match mode {
+            "scrape" => ("scrape", 1),
+            "crawl" => ("crawl", max_pages),
+            other => anyhow::bail!("Unknown mode '{}': expected 'crawl' or 'scrape'", other),
+        };
+        let return_format = args
+            .get("return_format")
+            .and_then(|v| v.as_str())
+            .unwrap_or("markdown");
+        if return_format != "markdown" && return_format != "raw" {
+            anyhow::bail!(
+                "Unknown return_format '{}': expected 'markdown' or 'raw'",
+                return_format
+            );
+        }
+
+        let budget = self.credit_budget.as_ref();
+        let limit = match budget {
+            Some(b) => requested.min(b.remaining() as usize),
+            None => requested,
+        };
+        if limit == 0 {
+            return Ok(serde_json::json!({
+                "url": url,
+                "pages": [],
+                "count": 0,
+                "credits_used": 0,
+                "budget_exhausted": budget.is_some(),
+            }));
+        }
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(300))
+            .build()?;
+        let api = self.base_url.as_deref().unwrap_or("https://api.spider.cloud");
+        let resp = client
+            .post(format!("{}/{}", api.trim_end_matches('/'), endpoint))
+            .bearer_auth(&api_key)
+            .header("Content-Type", "application/jsonl")
+            .json(&serde_json::json!({
+                "url": url,
+                "limit": limit,
+                "depth": self.max_depth,
+                "return_format": return_format,
+            }))
+            .send()?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().unwrap_or_default();
+            let message = serde_json::from_str::<Value>(&body)
+                .ok()
+                .and_then(|v| v.get("error").and_then(|e| e.as_str()).map(String::from))
+                .unwrap_or(body);
+            return Err(if status == reqwest::StatusCode::PAYMENT_REQUIRED {
+                SpiderError::QuotaExceeded(message).into()
+            } else {
+                SpiderError::Api { status: status.as_u16(), message }.into()
+            });
+        }
+
+        // Another tool sharing the budget may spend credits while this crawl
+        // runs, so the cap is re-checked for every page.
+        let mut pages: Vec<Value> = Vec::new();
+        let mut budget_exhausted = false;
+        for line in std::io::BufRead::lines(std::io::BufReader::new(resp)) {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            // A non-streaming response arrives as one JSON array.
+            let items = match serde_json::from_str::<Value>(&line)? {
+                Value::Array(items) => items,
+                item => vec![item],
+            };
+            for page in items {
+                if pages.len() >= limit {
+                    break;
+                }
+                if budget.is_some_and(|b| b.remaining() == 0) {
+                    budget_exhausted = true;
+                    break;
+                }
+                if let Some(b) = budget {
+                    b.charge(1);
+                }
+                pages.push(serde_json::json!({
+                    "url": page.get("url").cloned().unwrap_or(Value::Null),
+                    "status": page.get("status").cloned().unwrap_or(Value::Null),
+                    "content": page.get("content").cloned().unwrap_or(Value::Null),
+                }));
+            }
+            if budget_exhausted || pages.len() >= limit {
+                break;
+            }
+        }
+        let allowed = pages.len();
+        let budget_exhausted = budget_exhausted || budget.is_some_and(|b| b.is_exhausted());
+
+        Ok(serde_json::json!({
+            "url": url,
+            "count": pages.len(),
+            "pages": pages,
+            "credits_used": allowed,
+            "budget_exhausted": budget_exhausted,
+        }))
     }
 }
 
@@ -0,0 +1,131 @@
+//! Zapier Natural Language Actions (NLA) client shared by
+//! `ZapierActionTools` and the `ZapierActionTool` adapter.
+//!
+//! NLA exposes the actions a user has enabled in their Zapier account.
+//! Each one takes plain-language `instructions` plus optional field
+//! overrides; with `preview_only` Zapier resolves the fields and returns
+//! a review URL without running anything.
+
+use serde_json::Value;
+
+const ZAPIER_NLA_API: &str = "https://nla.zapier.com/api/v1";
+
+pub(crate) struct Client {
+    http: reqwest::blocking::Client,
+    base: String,
+    api_key: String,
+}
+
+impl Client {
+    /// Connect with the configured key, else `ZAPIER_NLA_API_KEY`.
+    pub(crate) fn connect(
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+    ) -> Result<Self, anyhow::Error> {
+        let api_key = api_key
+            .map(String::from)
+            .or_else(|| std::env::var("ZAPIER_NLA_API_KEY").ok())
+            .ok_or_else(|| anyhow::anyhow!("Missing ZAPIER_NLA_API_KEY"))?;
+        Ok(Self {
+            http: reqwest::blocking::Client::builder()
+                .timeout(std::time::Duration::from_secs(120))
+                .build()?,
+            base: base_url.unwrap_or(ZAPIER_NLA_API).trim_end_matches('/').to_string(),
+            api_key,
+        })
+    }
+
+    /// Every action exposed to this key, as `{id, description, params}`.
+    #[cfg_attr(not(feature = "automation"), allow(dead_code))]
+    pub(crate) fn actions(&self) -> Result<Vec<Value>, anyhow::Error> {
+        let resp = self
+            .http
+            .get(format!("{}/exposed/", self.base))
+            .header("X-API-Key", &self.api_key)
+            .send()?;
+        let payload = checked(resp)?;
+        Ok(payload["results"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|action| {
+                serde_json::json!({
+                    "id": action["id"],
+                    "description": action["description"],
+                    "params": action["params"],
+                })
+            })
+            .collect())
+    }
+
+    /// Run (or with `preview`, only resolve) an action.
+    ///
+    /// Returns `{action_id, status, review_url, result, input_params}`;
+    /// an execution Zapier reports as failed is an error that includes the
+    /// review URL.
+    pub(crate) fn execute(
+        &self,
+        action_id: &str,
+        instructions: &str,
+        params: &serde_json::Map<String, Value>,
+        preview: bool,
+    ) -> Result<Value, anyhow::Error> {
+        let mut body = params.clone();
+        body.insert("instructions".to_string(), Value::from(instructions));
+        let mut request = self
+            .http
+            .post(format!("{}/exposed/{}/execute/", self.base, action_id))
+            .header("X-API-Key", &self.api_key)
+            .json(&body);
+        if preview {
+            request = request.query(&[("preview_only", "true")]);
+        }
+        let payload = checked(request.send()?)?;
+        if payload["status"] == "error" {
+            anyhow::bail!(
+                "Zapier action {} failed: {} (review: {})",
+                action_id,
+                payload["error"].as_str().unwrap_or("no details"),
+                payload["review_url"].as_str().unwrap_or("none")
+            );
+        }
+        Ok(serde_json::json!({
+            "action_id": action_id,
+            "status": payload["status"],
+            "review_url": payload["review_url"],
+            "result": payload["result"],
+            "input_params": payload["input_params"],
+        }))
+    }
+}
+
+/// Arguments of an execute call: `(instructions, params, preview)`.
+pub(crate) fn execute_args(
+    args: &std::collections::HashMap<String, Value>,
+) -> Result<(&str, serde_json::Map<String, Value>, bool), anyhow::Error> {
+    let instructions = args
+        .get("instructions")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Missing required argument: instructions"))?;
+    let params = match args.get("params") {
+        Some(Value::Object(params)) => params.clone(),
+        Some(Value::Null) | None => serde_json::Map::new(),
+        Some(_) => anyhow::bail!("'params' must be an object of field overrides"),
+    };
+    let preview = args.get("preview").and_then(|v| v.as_bool()).unwrap_or(false);
+    Ok((instructions, params, preview))
+}
+
+fn checked(resp: reqwest::blocking::Response) -> Result<Value, anyhow::Error> {
+    let status = resp.status();
+    let payload: Value = resp.json().unwrap_or(Value::Null);
+    if !status.is_success() {
+        let message = payload
+            .get("detail")
+            .or_else(|| payload.get("error"))
+            .and_then(|m| m.as_str())
+            .unwrap_or("no details");
+        anyhow::bail!("Zapier NLA request failed with status {}: {}", status.as_u16(), message);
+    }
+    Ok(payload)
+}
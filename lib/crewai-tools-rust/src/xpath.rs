@@ -0,0 +1,409 @@
+//! A small XPath subset evaluator over `roxmltree` documents.
+//!
+//! Supports what agents typically ask for when extracting from XML:
+//!
+//! - absolute and relative location paths: `/a/b`, `a/b`, `//b`, `a//b`
+//! - name tests: `name`, `prefix:name`, `*`, plus `.` and `..`
+//! - trailing `@attr`, `@*` and `text()` steps (without predicates)
+//! - predicates: `[3]`, `[last()]`, `[@attr]`, `[@attr='v']`, `[child='v']`,
+//!   `[contains(., 'v')]`, `[contains(@attr, 'v')]`, `[local-name()='v']`
+//!
+//! Namespace prefixes are not resolved. Every name test matches on local name,
+//! so `//dc:title` and `//title` both find `<dc:title>` regardless of which
+//! URI the prefix is bound to.
+
+use roxmltree::{Document, Node};
+
+/// One node selected by an XPath expression.
+#[derive(Debug, Clone)]
+pub(crate) enum XPathHit<'a, 'input> {
+    Element(Node<'a, 'input>),
+    Attribute {
+        element: Node<'a, 'input>,
+        name: String,
+        value: String,
+    },
+    Text(Node<'a, 'input>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Axis {
+    Child,
+    Descendant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NodeTest {
+    Name(String),
+    AnyElement,
+    SelfNode,
+    Parent,
+    Attribute(String),
+    AnyAttribute,
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Predicate {
+    Position(usize),
+    Last,
+    HasAttribute(String),
+    AttributeEquals(String, String),
+    ChildEquals(String, String),
+    TextEquals(String),
+    TextContains(String),
+    AttributeContains(String, String),
+    LocalName(String),
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Predicate>,
+}
+
+/// Evaluate `expr` against `doc`, returning hits in document order.
+pub(crate) fn select<'a, 'input>(
+    doc: &'a Document<'input>,
+    expr: &str,
+) -> Result<Vec<XPathHit<'a, 'input>>, anyhow::Error> {
+    let steps = parse(expr)?;
+
+    let mut context: Vec<Node<'a, 'input>> = vec![doc.root()];
+    for (i, step) in steps.iter().enumerate() {
+        let is_last = i + 1 == steps.len();
+        match step.test {
+            NodeTest::Attribute(_) | NodeTest::AnyAttribute | NodeTest::Text => {
+                if !is_last {
+                    anyhow::bail!("XPath '{}': @attribute and text() must be the last step", expr);
+                }
+                if !step.predicates.is_empty() {
+                    anyhow::bail!("XPath '{}': predicates on @attribute and text() are not supported", expr);
+                }
+                return Ok(terminal_hits(&context, step));
+            }
+            _ => {}
+        }
+
+        let mut next: Vec<Node<'a, 'input>> = Vec::new();
+        for node in &context {
+            let candidates: Vec<Node> = match (&step.test, step.axis) {
+                (NodeTest::SelfNode, _) => vec![*node],
+                (NodeTest::Parent, _) => node.parent().into_iter().collect(),
+                (_, Axis::Child) => node.children().filter(|n| n.is_element()).collect(),
+                (_, Axis::Descendant) => node
+                    .descendants()
+                    .filter(|n| n.is_element() && n != node)
+                    .collect(),
+            };
+            let matched: Vec<Node> = candidates
+                .into_iter()
+                .filter(|n| name_matches(&step.test, n))
+                .collect();
+
+            // Positions are relative to siblings, so `//book[2]` is the second
+            // book under each parent, not the second book in the document.
+            let mut by_parent: Vec<(Option<roxmltree::NodeId>, Vec<Node>)> = Vec::new();
+            for n in matched {
+                let parent = n.parent().map(|p| p.id());
+                match by_parent.iter_mut().find(|(p, _)| *p == parent) {
+                    Some((_, group)) => group.push(n),
+                    None => by_parent.push((parent, vec![n])),
+                }
+            }
+            for (_, group) in by_parent {
+                next.extend(apply_predicates(group, &step.predicates));
+            }
+        }
+        next.sort_by_key(|n| n.id().get());
+        next.dedup_by_key(|n| n.id());
+        context = next;
+    }
+
+    Ok(context.into_iter().map(XPathHit::Element).collect())
+}
+
+fn terminal_hits<'a, 'input>(context: &[Node<'a, 'input>], step: &Step) -> Vec<XPathHit<'a, 'input>> {
+    let elements: Vec<Node> = match step.axis {
+        Axis::Child => context.to_vec(),
+        Axis::Descendant => {
+            let mut all: Vec<Node> = context
+                .iter()
+                .flat_map(|n| n.descendants().filter(|d| d.is_element()))
+                .collect();
+            all.sort_by_key(|n| n.id().get());
+            all.dedup_by_key(|n| n.id());
+            all
+        }
+    };
+
+    let mut hits = Vec::new();
+    for element in elements {
+        match &step.test {
+            NodeTest::Attribute(name) => {
+                for attr in element.attributes().filter(|a| a.name() == local(name)) {
+                    hits.push(XPathHit::Attribute {
+                        element,
+                        name: attr.name().to_string(),
+                        value: attr.value().to_string(),
+                    });
+                }
+            }
+            NodeTest::AnyAttribute => {
+                for attr in element.attributes() {
+                    hits.push(XPathHit::Attribute {
+                        element,
+                        name: attr.name().to_string(),
+                        value: attr.value().to_string(),
+                    });
+                }
+            }
+            NodeTest::Text => {
+                hits.extend(
+                    element
+                        .children()
+                        .filter(|c| c.is_text() && !c.text().unwrap_or("").trim().is_empty())
+                        .map(XPathHit::Text),
+                );
+            }
+            _ => unreachable!("terminal_hits called with an element test"),
+        }
+    }
+    hits
+}
+
+fn name_matches(test: &NodeTest, node: &Node) -> bool {
+    match test {
+        NodeTest::Name(name) => node.is_element() && node.tag_name().name() == local(name),
+        NodeTest::AnyElement => node.is_element(),
+        NodeTest::SelfNode | NodeTest::Parent => true,
+        _ => false,
+    }
+}
+
+fn apply_predicates<'a, 'input>(
+    mut nodes: Vec<Node<'a, 'input>>,
+    predicates: &[Predicate],
+) -> Vec<Node<'a, 'input>> {
+    for predicate in predicates {
+        nodes = match predicate {
+            Predicate::Position(n) => nodes.get(n.wrapping_sub(1)).copied().into_iter().collect(),
+            Predicate::Last => nodes.last().copied().into_iter().collect(),
+            _ => nodes.into_iter().filter(|n| predicate_holds(predicate, n)).collect(),
+        };
+    }
+    nodes
+}
+
+fn predicate_holds(predicate: &Predicate, node: &Node) -> bool {
+    match predicate {
+        Predicate::HasAttribute(name) => attribute(node, name).is_some(),
+        Predicate::AttributeEquals(name, value) => attribute(node, name) == Some(value.as_str()),
+        Predicate::AttributeContains(name, value) => {
+            attribute(node, name).is_some_and(|a| a.contains(value.as_str()))
+        }
+        Predicate::ChildEquals(name, value) => node
+            .children()
+            .filter(|c| c.is_element() && c.tag_name().name() == local(name))
+            .any(|c| text_content(&c).trim() == value),
+        Predicate::TextEquals(value) => text_content(node).trim() == value,
+        Predicate::TextContains(value) => text_content(node).contains(value.as_str()),
+        Predicate::LocalName(name) => node.tag_name().name() == name,
+        Predicate::Position(_) | Predicate::Last => true,
+    }
+}
+
+fn attribute<'a>(node: &Node<'a, '_>, name: &str) -> Option<&'a str> {
+    let name = local(name);
+    node.attributes().find(|a| a.name() == name).map(|a| a.value())
+}
+
+/// Strip a namespace prefix: `dc:title` → `title`.
+fn local(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Concatenated text of `node` and all its descendants.
+pub(crate) fn text_content(node: &Node) -> String {
+    node.descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect()
+}
+
+/// The element's location as an XPath, e.g. `/catalog/book[3]/title`.
+///
+/// A position is only added when the element has same-named siblings.
+pub(crate) fn element_path(node: &Node) -> String {
+    let mut segments = Vec::new();
+    let mut current = Some(*node);
+    while let Some(n) = current {
+        if n.is_element() {
+            let name = n.tag_name().name();
+            let same_named = |s: &Node| s.is_element() && s.tag_name().name() == name;
+            let siblings: Vec<Node> = n
+                .parent()
+                .map(|p| p.children().filter(same_named).collect())
+                .unwrap_or_default();
+            if siblings.len() > 1 {
+                let position = siblings.iter().position(|s| *s == n).unwrap_or(0) + 1;
+                segments.push(format!("{}[{}]", name, position));
+            } else {
+                segments.push(name.to_string());
+            }
+        }
+        current = n.parent();
+    }
+    segments.reverse();
+    format!("/{}", segments.join("/"))
+}
+
+// ── Parsing ──────────────────────────────────────────────────────────────────
+
+fn parse(expr: &str) -> Result<Vec<Step>, anyhow::Error> {
+    let expr = expr.trim();
+    if expr.is_empty() {
+        anyhow::bail!("Empty XPath expression");
+    }
+
+    let mut steps = Vec::new();
+    let mut rest = expr;
+    // A relative path is evaluated from the root, so `book/title` behaves
+    // like `//book/title` would for a top-level search.
+    let mut axis = if rest.starts_with('/') { Axis::Child } else { Axis::Descendant };
+
+    loop {
+        if let Some(r) = rest.strip_prefix("//") {
+            axis = Axis::Descendant;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('/') {
+            rest = r;
+        }
+        if rest.is_empty() {
+            break;
+        }
+
+        let end = step_end(rest);
+        steps.push(parse_step(&rest[..end], axis, expr)?);
+        rest = &rest[end..];
+        axis = Axis::Child;
+    }
+
+    if steps.is_empty() {
+        anyhow::bail!("XPath '{}' selects nothing", expr);
+    }
+    Ok(steps)
+}
+
+/// Byte offset of the `/` ending the current step, skipping brackets and quotes.
+fn step_end(s: &str) -> usize {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'') | (None, '"') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, '/') if depth == 0 => return i,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+fn parse_step(raw: &str, axis: Axis, expr: &str) -> Result<Step, anyhow::Error> {
+    let (name, predicate_src) = match raw.find('[') {
+        Some(i) => (raw[..i].trim(), &raw[i..]),
+        None => (raw.trim(), ""),
+    };
+
+    let test = match name {
+        "*" => NodeTest::AnyElement,
+        "." => NodeTest::SelfNode,
+        ".." => NodeTest::Parent,
+        "text()" => NodeTest::Text,
+        "@*" => NodeTest::AnyAttribute,
+        n if n.starts_with('@') => NodeTest::Attribute(n[1..].to_string()),
+        n if !n.is_empty() && n.chars().all(|c| c.is_alphanumeric() || "_-.:".contains(c)) => {
+            NodeTest::Name(n.to_string())
+        }
+        _ => anyhow::bail!("XPath '{}': unsupported step '{}'", expr, raw),
+    };
+
+    let mut predicates = Vec::new();
+    let mut rest = predicate_src.trim();
+    while !rest.is_empty() {
+        if !rest.starts_with('[') {
+            anyhow::bail!("XPath '{}': malformed predicate in '{}'", expr, raw);
+        }
+        let close = predicate_close(rest)
+            .ok_or_else(|| anyhow::anyhow!("XPath '{}': unclosed predicate in '{}'", expr, raw))?;
+        predicates.push(parse_predicate(rest[1..close].trim(), expr)?);
+        rest = rest[close + 1..].trim_start();
+    }
+
+    Ok(Step {
+        axis,
+        test,
+        predicates,
+    })
+}
+
+fn predicate_close(s: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices().skip(1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'') | (None, '"') => quote = Some(c),
+            (None, ']') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_predicate(p: &str, expr: &str) -> Result<Predicate, anyhow::Error> {
+    if let Ok(n) = p.parse::<usize>() {
+        return Ok(Predicate::Position(n));
+    }
+    if p == "last()" {
+        return Ok(Predicate::Last);
+    }
+    if let Some(args) = p.strip_prefix("contains(").and_then(|r| r.strip_suffix(')')) {
+        let (target, value) = args
+            .split_once(',')
+            .ok_or_else(|| anyhow::anyhow!("XPath '{}': contains() needs two arguments", expr))?;
+        let value = unquote(value.trim(), expr)?;
+        return match target.trim() {
+            "." | "text()" => Ok(Predicate::TextContains(value)),
+            t if t.starts_with('@') => Ok(Predicate::AttributeContains(t[1..].to_string(), value)),
+            t => anyhow::bail!("XPath '{}': unsupported contains() target '{}'", expr, t),
+        };
+    }
+    if let Some((lhs, rhs)) = p.split_once('=') {
+        let lhs = lhs.trim();
+        let value = unquote(rhs.trim(), expr)?;
+        return match lhs {
+            "local-name()" => Ok(Predicate::LocalName(value)),
+            l if l.starts_with('@') => Ok(Predicate::AttributeEquals(l[1..].to_string(), value)),
+            "." | "text()" => Ok(Predicate::TextEquals(value)),
+            l => Ok(Predicate::ChildEquals(l.to_string(), value)),
+        };
+    }
+    if let Some(name) = p.strip_prefix('@') {
+        return Ok(Predicate::HasAttribute(name.to_string()));
+    }
+    anyhow::bail!("XPath '{}': unsupported predicate '[{}]'", expr, p)
+}
+
+fn unquote(s: &str, expr: &str) -> Result<String, anyhow::Error> {
+    let quoted = (s.starts_with('\'') && s.ends_with('\'')) || (s.starts_with('"') && s.ends_with('"'));
+    if s.len() < 2 || !quoted {
+        anyhow::bail!("XPath '{}': expected a quoted string, got {}", expr, s);
+    }
+    Ok(s[1..s.len() - 1].to_string())
+}
@@ -0,0 +1,430 @@
+//! YouTube transcript fetching and chapter-aware segmentation, shared by
+//! `YoutubeVideoSearchTool` and the RAG `YoutubeVideoLoader`.
+//!
+//! Chapters come from the player's chapter markers when present, otherwise
+//! from timestamp lines in the description ("0:00 Intro", "12:30 – Pricing").
+//! Transcripts with chapters are segmented by chapter; the rest fall back to
+//! fixed time windows.
+
+use serde_json::Value;
+
+/// The video exists but has no captions track to build a transcript from.
+///
+/// Returned (wrapped in `anyhow::Error`) by `YoutubeVideoSearchTool` and
+/// `YoutubeVideoLoader`; callers can `downcast_ref::<NoTranscript>()`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("No transcript available for YouTube video {video_id}")]
+pub struct NoTranscript {
+    pub video_id: String,
+}
+
+/// One caption cue.
+#[derive(Debug, Clone)]
+pub(crate) struct TranscriptSegment {
+    /// Start time in seconds.
+    pub start: f64,
+    pub text: String,
+}
+
+/// A creator-defined chapter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Chapter {
+    pub title: String,
+    /// Start time in whole seconds.
+    pub start: u32,
+}
+
+/// A fetched video with its transcript and chapters.
+#[derive(Debug, Clone)]
+pub(crate) struct VideoTranscript {
+    pub video_id: String,
+    pub title: String,
+    /// Channel name.
+    #[cfg_attr(not(feature = "rag"), allow(dead_code))]
+    pub channel: String,
+    /// Language code of the caption track used, e.g. `en` or `de-DE`.
+    #[cfg_attr(not(feature = "rag"), allow(dead_code))]
+    pub language: String,
+    /// Whether the track is YouTube's speech recognition rather than
+    /// creator-provided captions.
+    #[cfg_attr(not(feature = "rag"), allow(dead_code))]
+    pub auto_generated: bool,
+    pub segments: Vec<TranscriptSegment>,
+    pub chapters: Vec<Chapter>,
+    /// Video length in seconds, if reported.
+    pub length: Option<u32>,
+}
+
+/// A span of transcript: one chapter, or one time window.
+#[derive(Debug, Clone)]
+pub(crate) struct TranscriptSection {
+    /// Chapter title; `None` for time-window sections.
+    pub chapter: Option<String>,
+    pub start: u32,
+    pub end: u32,
+    pub text: String,
+}
+
+impl TranscriptSection {
+    /// Human-readable location, e.g. `Chapter: Pricing deep-dive (14:05)`
+    /// or `14:05–15:05`.
+    pub(crate) fn citation(&self) -> String {
+        match &self.chapter {
+            Some(title) => format!("Chapter: {} ({})", title, format_timestamp(self.start)),
+            None => format!("{}–{}", format_timestamp(self.start), format_timestamp(self.end)),
+        }
+    }
+}
+
+/// Extract the 11-character video ID from a URL or bare ID.
+pub(crate) fn video_id(url_or_id: &str) -> Option<String> {
+    let s = url_or_id.trim();
+    let candidate = if let Some(pos) = s.find("?v=").or_else(|| s.find("&v=")) {
+        &s[pos + 3..]
+    } else if let Some(pos) = s.find("youtu.be/") {
+        &s[pos + 9..]
+    } else if let Some(pos) = s.find("/shorts/").or_else(|| s.find("/embed/")).or_else(|| s.find("/live/")) {
+        let rest = &s[pos + 1..];
+        &rest[rest.find('/').map_or(rest.len(), |i| i + 1)..]
+    } else {
+        s
+    };
+    let id: String = candidate
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    (id.len() == 11).then_some(id)
+}
+
+/// Where watch pages are fetched from unless a caller points elsewhere.
+pub(crate) const YOUTUBE_URL: &str = "https://www.youtube.com";
+
+/// Fetch a video's transcript in `language` (falling back to any available
+/// track) along with its chapters, from the watch page served at `base_url`
+/// (normally [`YOUTUBE_URL`]).
+pub(crate) fn fetch_transcript_from(
+    base_url: &str,
+    video: &str,
+    language: &str,
+) -> Result<VideoTranscript, anyhow::Error> {
+    let video_id = video_id(video)
+        .ok_or_else(|| anyhow::anyhow!("Could not find a YouTube video ID in '{}'", video))?;
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .user_agent("Mozilla/5.0 (compatible; CrewAI/1.0)")
+        .build()?;
+
+    let html = client
+        .get(format!("{}/watch?v={}", base_url.trim_end_matches('/'), video_id))
+        .header(reqwest::header::ACCEPT_LANGUAGE, language)
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    let player = embedded_json(&html, "ytInitialPlayerResponse")
+        .ok_or_else(|| anyhow::anyhow!("Could not read the player response for video {}", video_id))?;
+    let details = &player["videoDetails"];
+    let title = details["title"].as_str().unwrap_or_default().to_string();
+    let channel = details["author"].as_str().unwrap_or_default().to_string();
+    let description = details["shortDescription"].as_str().unwrap_or_default();
+    let length = details["lengthSeconds"].as_str().and_then(|s| s.parse().ok());
+
+    let tracks = player
+        .pointer("/captions/playerCaptionsTracklistRenderer/captionTracks")
+        .and_then(|v| v.as_array())
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| NoTranscript { video_id: video_id.clone() })?;
+    let track = pick_caption_track(tracks, language);
+    let track_language = track["languageCode"].as_str().unwrap_or(language).to_string();
+    let auto_generated = track["kind"].as_str() == Some("asr");
+    let base_url = track["baseUrl"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Caption track for video {} has no URL", video_id))?;
+    let captions: Value = client
+        .get(format!("{}&fmt=json3", base_url))
+        .send()?
+        .error_for_status()?
+        .json()?;
+    let segments = parse_json3(&captions);
+    if segments.is_empty() {
+        return Err(NoTranscript { video_id }.into());
+    }
+
+    let mut chapters = embedded_json(&html, "ytInitialData")
+        .map(|data| player_chapters(&data))
+        .unwrap_or_default();
+    if chapters.is_empty() {
+        chapters = parse_description_chapters(description);
+    }
+
+    Ok(VideoTranscript {
+        video_id,
+        title,
+        channel,
+        language: track_language,
+        auto_generated,
+        segments,
+        chapters,
+        length,
+    })
+}
+
+/// Prefer a manual track in `language`, then an auto-generated one, then
+/// whatever comes first.
+fn pick_caption_track<'a>(tracks: &'a [Value], language: &str) -> &'a Value {
+    let lang_matches = |t: &&Value| {
+        t["languageCode"]
+            .as_str()
+            .is_some_and(|code| language_matches(code, language))
+    };
+    tracks
+        .iter()
+        .filter(lang_matches)
+        .find(|t| t["kind"].as_str() != Some("asr"))
+        .or_else(|| tracks.iter().find(lang_matches))
+        .unwrap_or(&tracks[0])
+}
+
+/// Parse the JSON object assigned to `var_name` in a watch page.
+fn embedded_json(html: &str, var_name: &str) -> Option<Value> {
+    let start = html.find(&format!("{} = ", var_name))? + var_name.len() + 3;
+    serde_json::Deserializer::from_str(&html[start..])
+        .into_iter::<Value>()
+        .next()?
+        .ok()
+}
+
+/// Caption cues from a `fmt=json3` timed-text response.
+fn parse_json3(captions: &Value) -> Vec<TranscriptSegment> {
+    let Some(events) = captions["events"].as_array() else {
+        return Vec::new();
+    };
+    events
+        .iter()
+        .filter_map(|event| {
+            let segs = event["segs"].as_array()?;
+            let text: String = segs.iter().filter_map(|s| s["utf8"].as_str()).collect();
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            if text.is_empty() {
+                return None;
+            }
+            Some(TranscriptSegment {
+                start: event["tStartMs"].as_f64().unwrap_or(0.0) / 1000.0,
+                text,
+            })
+        })
+        .collect()
+}
+
+/// Chapters from the player bar's chapter markers in `ytInitialData`.
+fn player_chapters(data: &Value) -> Vec<Chapter> {
+    fn walk(v: &Value, out: &mut Vec<Chapter>) {
+        match v {
+            Value::Object(map) => {
+                if let Some(ch) = map.get("chapterRenderer") {
+                    let title = ch
+                        .pointer("/title/simpleText")
+                        .and_then(|t| t.as_str())
+                        .unwrap_or_default()
+                        .trim();
+                    if let Some(ms) = ch["timeRangeStartMillis"].as_u64() {
+                        if !title.is_empty() {
+                            out.push(Chapter {
+                                title: title.to_string(),
+                                start: (ms / 1000) as u32,
+                            });
+                        }
+                    }
+                    return;
+                }
+                for child in map.values() {
+                    walk(child, out);
+                }
+            }
+            Value::Array(items) => {
+                for child in items {
+                    walk(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut chapters = Vec::new();
+    walk(data, &mut chapters);
+    chapters.sort_by_key(|c| c.start);
+    chapters.dedup_by_key(|c| c.start);
+    chapters
+}
+
+/// Parse chapter lines from a video description.
+///
+/// Accepts `h:mm:ss` and `mm:ss` timestamps before or after the title,
+/// optionally bracketed and separated by hyphens, en/em dashes, colons,
+/// pipes or bullets, and several `timestamp title` pairs on one line.
+/// Returns an empty list unless at least two timestamps are found in
+/// strictly ascending order, which filters out descriptions that merely
+/// mention a time.
+pub(crate) fn parse_description_chapters(description: &str) -> Vec<Chapter> {
+    let mut chapters = Vec::new();
+    for line in description.lines() {
+        let stamps = find_timestamps(line);
+        if stamps.is_empty() {
+            continue;
+        }
+        for (i, &(start, end, seconds)) in stamps.iter().enumerate() {
+            let next = stamps.get(i + 1).map_or(line.len(), |s| s.0);
+            let mut title = clean_chapter_title(&line[end..next]);
+            // "Intro - 0:00": a lone timestamp trailing its title.
+            if title.is_empty() && stamps.len() == 1 {
+                title = clean_chapter_title(&line[..start]);
+            }
+            if !title.is_empty() {
+                chapters.push(Chapter { title, start: seconds });
+            }
+        }
+    }
+
+    let ascending = chapters.windows(2).all(|w| w[0].start < w[1].start);
+    if chapters.len() < 2 || !ascending {
+        return Vec::new();
+    }
+    chapters
+}
+
+/// Trim separators, brackets and whitespace from around a chapter title.
+fn clean_chapter_title(s: &str) -> String {
+    const SEPARATORS: &[char] = &[
+        '-', '–', '—', '―', ':', '|', '•', '·', '*', '>', '[', ']', '(', ')', '.', ',',
+    ];
+    s.trim_matches(|c: char| c.is_whitespace() || SEPARATORS.contains(&c))
+        .to_string()
+}
+
+/// Every `h:mm:ss` / `mm:ss` / `m:ss` timestamp in `line`, as
+/// `(byte_start, byte_end, seconds)`.
+fn find_timestamps(line: &str) -> Vec<(usize, usize, u32)> {
+    let bytes = line.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let preceded_by_word = i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b':');
+        if !bytes[i].is_ascii_digit() || preceded_by_word {
+            i += 1;
+            continue;
+        }
+        // Greedily take digit groups separated by single colons.
+        let mut end = i;
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b':') {
+            end += 1;
+        }
+        let mut candidate = &line[i..end];
+        candidate = candidate.trim_end_matches(':');
+        let followed_by_word = bytes
+            .get(i + candidate.len())
+            .is_some_and(|b| b.is_ascii_alphanumeric());
+        match parse_timestamp(candidate) {
+            Some(seconds) if !followed_by_word => {
+                found.push((i, i + candidate.len(), seconds));
+            }
+            _ => {}
+        }
+        i = end.max(i + 1);
+    }
+    found
+}
+
+/// Parse `h:mm:ss`, `mm:ss` or `m:ss` into seconds.
+pub(crate) fn parse_timestamp(s: &str) -> Option<u32> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if !(2..=3).contains(&parts.len()) || parts.iter().any(|p| p.is_empty() || p.len() > 2) {
+        return None;
+    }
+    let nums: Vec<u32> = parts.iter().map(|p| p.parse().ok()).collect::<Option<_>>()?;
+    // Every field after the first is zero-padded and below 60.
+    if parts[1..].iter().any(|p| p.len() != 2) || nums[1..].iter().any(|n| *n >= 60) {
+        return None;
+    }
+    Some(match nums[..] {
+        [m, s] => m * 60 + s,
+        [h, m, s] => h * 3600 + m * 60 + s,
+        _ => return None,
+    })
+}
+
+/// Link to `video_id` starting at `seconds`.
+pub(crate) fn deep_link(video_id: &str, seconds: u32) -> String {
+    format!("https://www.youtube.com/watch?v={}&t={}s", video_id, seconds)
+}
+
+/// Format seconds as `m:ss` or `h:mm:ss`.
+pub(crate) fn format_timestamp(seconds: u32) -> String {
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
+    }
+}
+
+/// Whether a caption track in `code` satisfies a request for `language`
+/// (`en` accepts `en-GB`).
+pub(crate) fn language_matches(code: &str, language: &str) -> bool {
+    code == language || code.split('-').next() == Some(language)
+}
+
+/// Split a transcript into sections: one per chapter when chapters exist,
+/// otherwise consecutive windows of `window_seconds`.
+pub(crate) fn sections(video: &VideoTranscript, window_seconds: u32) -> Vec<TranscriptSection> {
+    let last_cue = video.segments.last().map_or(0, |s| s.start.ceil() as u32);
+    let total = video.length.unwrap_or(0).max(last_cue);
+
+    let mut sections: Vec<TranscriptSection> = if video.chapters.is_empty() {
+        let window = window_seconds.max(1);
+        (0..=total / window)
+            .map(|i| TranscriptSection {
+                chapter: None,
+                start: i * window,
+                end: ((i + 1) * window).min(total.max(i * window)),
+                text: String::new(),
+            })
+            .collect()
+    } else {
+        let mut out = Vec::new();
+        // Speech before a first chapter that does not start at 0:00.
+        if video.chapters[0].start > 0 {
+            out.push(TranscriptSection {
+                chapter: None,
+                start: 0,
+                end: video.chapters[0].start,
+                text: String::new(),
+            });
+        }
+        for (i, chapter) in video.chapters.iter().enumerate() {
+            let end = video.chapters.get(i + 1).map_or(total.max(chapter.start), |c| c.start);
+            out.push(TranscriptSection {
+                chapter: Some(chapter.title.clone()),
+                start: chapter.start,
+                end,
+                text: String::new(),
+            });
+        }
+        out
+    };
+
+    for segment in &video.segments {
+        let at = segment.start as u32;
+        let idx = sections
+            .iter()
+            .rposition(|s| s.start <= at)
+            .unwrap_or(0);
+        if let Some(section) = sections.get_mut(idx) {
+            if !section.text.is_empty() {
+                section.text.push(' ');
+            }
+            section.text.push_str(&segment.text);
+        }
+    }
+    sections.retain(|s| !s.text.is_empty());
+    sections
+}
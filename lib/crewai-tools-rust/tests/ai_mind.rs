@@ -0,0 +1,84 @@
+//! AiMindTool against a scripted Minds API server.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::{body_recording_mock_server, recording_mock_server};
+use crewai_tools::AiMindTool;
+use serde_json::{json, Value};
+
+const ANSWER: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"choices": [{"message": {"role": "assistant", "content": "Revenue grew 12%."}}]}"#,
+);
+
+const NOT_FOUND: common::Scripted = ("404 Not Found", "", r#"{"detail": "Mind not found"}"#);
+
+fn query(q: &str) -> HashMap<String, Value> {
+    HashMap::from([("query".to_string(), Value::from(q))])
+}
+
+#[test]
+fn question_is_sent_as_a_chat_completion_for_the_mind() {
+    let (url, _, requests) = recording_mock_server(vec![ANSWER]);
+
+    let result = AiMindTool::new()
+        .with_api_endpoint(&url)
+        .with_api_key("mdb-key")
+        .with_mind_name("sales_mind")
+        .run(query("How did revenue change?"))
+        .unwrap();
+
+    assert_eq!(result, json!({"answer": "Revenue grew 12%.", "mind": "sales_mind"}));
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /api/v1/chat/completions "), "{}", head);
+    assert!(head.contains("authorization: bearer mdb-key"), "{}", head);
+}
+
+#[test]
+fn unknown_mind_is_reported_by_name() {
+    let (url, _, _) = recording_mock_server(vec![NOT_FOUND]);
+
+    let err = AiMindTool::new()
+        .with_api_endpoint(url)
+        .with_api_key("k")
+        .with_mind_name("sales_mnd")
+        .run(query("hi"))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("AI Mind 'sales_mnd' not found"), "{}", err);
+}
+
+#[test]
+fn missing_mind_is_created_over_its_datasources() {
+    let created = ("200 OK", "", "{}");
+    let (url, bodies) = body_recording_mock_server(vec![NOT_FOUND, created, created, ANSWER]);
+
+    let result = AiMindTool::new()
+        .with_api_endpoint(url)
+        .with_api_key("k")
+        .with_mind_name("sales_mind")
+        .with_create_if_missing(true)
+        .with_datasource(json!({
+            "name": "sales_db",
+            "engine": "postgres",
+            "description": "Order history",
+            "connection_data": {"host": "db", "database": "sales"},
+            "tables": ["orders"],
+        }))
+        .run(query("How did revenue change?"))
+        .unwrap();
+
+    assert_eq!(result["answer"], "Revenue grew 12%.");
+    let bodies = bodies.lock().unwrap();
+    let datasource: Value = serde_json::from_str(&bodies[1]).unwrap();
+    assert_eq!(datasource["engine"], "postgres");
+    let mind: Value = serde_json::from_str(&bodies[2]).unwrap();
+    assert_eq!(mind, json!({"name": "sales_mind", "datasources": ["sales_db"]}));
+    let chat: Value = serde_json::from_str(&bodies[3]).unwrap();
+    assert_eq!(chat["model"], "sales_mind");
+}
@@ -0,0 +1,95 @@
+//! ApifyActorsTool against a scripted Apify API.
+
+#![cfg(feature = "automation")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use common::recording_mock_server;
+use crewai_tools::ApifyActorsTool;
+use serde_json::{json, Value};
+
+const STARTED: common::Scripted = (
+    "201 Created",
+    "",
+    r#"{"data": {"id": "run-1", "status": "RUNNING", "defaultDatasetId": "ds-9"}}"#,
+);
+
+const SUCCEEDED: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"data": {"id": "run-1", "status": "SUCCEEDED", "defaultDatasetId": "ds-9"}}"#,
+);
+
+fn input() -> HashMap<String, Value> {
+    HashMap::from([(
+        "run_input".to_string(),
+        json!({"startUrls": [{"url": "https://example.com"}]}),
+    )])
+}
+
+fn tool(url: &str) -> ApifyActorsTool {
+    ApifyActorsTool::new()
+        .with_base_url(url)
+        .with_api_token("apify-tok")
+        .with_actor_id("apify/web-scraper")
+}
+
+#[test]
+fn run_is_awaited_and_dataset_items_returned() {
+    let items = ("200 OK", "", r#"[{"url": "https://example.com"}, {"url": "https://example.com/a"}]"#);
+    let (url, _, requests) = recording_mock_server(vec![STARTED, SUCCEEDED, items]);
+
+    let result = tool(&url)
+        .with_memory_mbytes(1024)
+        .with_run_timeout(Duration::from_secs(120))
+        .with_max_items(2)
+        .run(input())
+        .unwrap();
+
+    assert_eq!(result["run_id"], "run-1");
+    assert_eq!(result["status"], "SUCCEEDED");
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["items"][1]["url"], "https://example.com/a");
+
+    let requests = requests.lock().unwrap();
+    let start = &requests[0];
+    assert!(start.starts_with("post /v2/acts/apify~web-scraper/runs?"), "{}", start);
+    for param in ["memory=1024", "timeout=120", "maxitems=2", "waitforfinish=60"] {
+        assert!(start.contains(param), "missing {} in {}", param, start);
+    }
+    assert!(start.contains("authorization: bearer apify-tok"), "{}", start);
+    assert!(requests[1].starts_with("get /v2/actor-runs/run-1?waitforfinish="), "{}", requests[1]);
+    assert!(requests[2].starts_with("get /v2/datasets/ds-9/items?clean=true&limit=2 "), "{}", requests[2]);
+}
+
+#[test]
+fn failed_run_reports_status_message_and_log_url() {
+    let failed = (
+        "201 Created",
+        "",
+        r#"{"data": {"id": "run-2", "status": "FAILED", "statusMessage": "Out of memory"}}"#,
+    );
+    let (url, _, _) = recording_mock_server(vec![failed]);
+
+    let err = tool(&url).run(input()).unwrap_err().to_string();
+
+    assert!(err.contains("FAILED: Out of memory"), "{}", err);
+    assert!(err.contains(&format!("{}/v2/actor-runs/run-2/log", url)), "{}", err);
+}
+
+#[test]
+fn budget_limits_the_dataset_fetch() {
+    let items = ("200 OK", "", r#"[{"n": 1}, {"n": 2}, {"n": 3}]"#);
+    let (url, _, requests) = recording_mock_server(vec![SUCCEEDED, items]);
+    let tool = tool(&url).with_credit_budget(2);
+
+    let result = tool.run(input()).unwrap();
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["budget_exhausted"], true);
+    assert_eq!(tool.credits_used(), 2);
+    assert!(requests.lock().unwrap()[1].contains("limit=2"));
+}
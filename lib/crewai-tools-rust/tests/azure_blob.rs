@@ -0,0 +1,233 @@
+//! AzureBlobReaderTool and AzureBlobWriterTool against a scripted Blob
+//! service endpoint.
+
+#![cfg(feature = "cloud_storage")]
+
+mod common;
+
+use common::{args, mock_server, recording_mock_server};
+use crewai_tools::{AzureBlobError, AzureBlobReaderTool, AzureBlobWriterTool};
+use serde_json::json;
+
+const PAGE_1: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/xml\r\n",
+    r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ContainerName="reports">
+  <Prefix>logs/</Prefix><Delimiter>/</Delimiter>
+  <Blobs>
+    <Blob><Name>logs/a.log</Name><Properties>
+      <Last-Modified>Wed, 01 May 2024 10:00:00 GMT</Last-Modified>
+      <Content-Length>120</Content-Length>
+    </Properties></Blob>
+    <BlobPrefix><Name>logs/2024/</Name></BlobPrefix>
+  </Blobs>
+  <NextMarker>marker-2</NextMarker>
+</EnumerationResults>"#,
+);
+
+const PAGE_2: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/xml\r\n",
+    r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ContainerName="reports">
+  <Blobs>
+    <Blob><Name>logs/b.log</Name><Properties>
+      <Last-Modified>Thu, 02 May 2024 10:00:00 GMT</Last-Modified>
+      <Content-Length>80</Content-Length>
+    </Properties></Blob>
+  </Blobs>
+  <NextMarker />
+</EnumerationResults>"#,
+);
+
+fn reader(url: &str) -> AzureBlobReaderTool {
+    AzureBlobReaderTool::new()
+        .with_endpoint_url(url)
+        .with_container("reports")
+        .with_account_name("acme")
+        .with_account_key("c2VjcmV0LWtleQ==")
+}
+
+#[test]
+fn reads_text_blobs_with_a_shared_key_signature() {
+    let (url, _, heads) =
+        recording_mock_server(vec![("200 OK", "Content-Type: text/plain\r\n", "hello world")]);
+
+    let result = reader(&url).run(args(&[("key", json!("2024/q1 summary.txt"))])).unwrap();
+
+    assert_eq!(result["container"], "reports");
+    assert_eq!(result["key"], "2024/q1 summary.txt");
+    assert_eq!(result["content"], "hello world");
+    assert_eq!(result["size"], 11);
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("get /reports/2024/q1%20summary.txt "), "{}", head);
+    assert!(head.contains("authorization: sharedkey acme:"), "{}", head);
+    assert!(head.contains("x-ms-version: 2021-08-06"), "{}", head);
+    assert!(head.contains("x-ms-date: "), "{}", head);
+}
+
+#[test]
+fn sas_token_from_a_connection_string_goes_in_the_query() {
+    let (url, _, heads) =
+        recording_mock_server(vec![("200 OK", "Content-Type: image/png\r\n", "\u{89}PNG")]);
+    let connection_string = format!(
+        "BlobEndpoint={}/;SharedAccessSignature=sv=2022-11-02&sp=r&sig=abc%3D",
+        url
+    );
+
+    let result = AzureBlobReaderTool::new()
+        .with_connection_string(connection_string)
+        .run(args(&[("file_path", json!("az://reports/logo.png"))]))
+        .unwrap();
+
+    assert!(result["content_base64"].as_str().is_some_and(|b| !b.is_empty()));
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("get /reports/logo.png?sv=2022-11-02&sp=r&sig=abc%3d "), "{}", head);
+    assert!(!head.contains("authorization:"), "{}", head);
+}
+
+#[test]
+fn range_reads_report_the_full_size() {
+    let reply = (
+        "206 Partial Content",
+        "Content-Type: text/plain\r\nContent-Range: bytes 0-4/5000\r\n",
+        "hello",
+    );
+    let (url, _, heads) = recording_mock_server(vec![reply]);
+
+    let result = reader(&url)
+        .run(args(&[("key", json!("big.log")), ("range", json!("0-4"))]))
+        .unwrap();
+
+    assert_eq!(result["content"], "hello");
+    assert_eq!(result["total_size"], 5000);
+    assert!(heads.lock().unwrap()[0].contains("x-ms-range: bytes=0-4"));
+}
+
+#[test]
+fn list_follows_markers_and_groups_prefixes() {
+    let (url, _, heads) = recording_mock_server(vec![PAGE_1, PAGE_2]);
+
+    let result = reader(&url)
+        .run(args(&[("prefix", json!("logs/")), ("delimiter", json!("/"))]))
+        .unwrap();
+
+    assert_eq!(
+        result["objects"],
+        json!([
+            {"key": "logs/a.log", "size": 120, "last_modified": "2024-05-01T10:00:00Z"},
+            {"key": "logs/b.log", "size": 80, "last_modified": "2024-05-02T10:00:00Z"},
+        ])
+    );
+    assert_eq!(result["common_prefixes"], json!(["logs/2024/"]));
+    assert_eq!(result["truncated"], false);
+    let heads = heads.lock().unwrap();
+    assert!(
+        heads[0].starts_with("get /reports?restype=container&comp=list&prefix=logs%2f"),
+        "{}",
+        heads[0]
+    );
+    assert!(heads[1].contains("marker=marker-2"), "{}", heads[1]);
+}
+
+#[test]
+fn missing_container_and_rejected_key_are_distinct_errors() {
+    let missing = (
+        "404 The specified container does not exist.",
+        "x-ms-error-code: ContainerNotFound\r\nContent-Type: application/xml\r\n",
+        r#"<?xml version="1.0" encoding="utf-8"?><Error><Code>ContainerNotFound</Code>
+<Message>The specified container does not exist.
+RequestId:1</Message></Error>"#,
+    );
+    let forbidden = (
+        "403 Server failed to authenticate the request.",
+        "x-ms-error-code: AuthenticationFailed\r\nContent-Type: application/xml\r\n",
+        r#"<?xml version="1.0" encoding="utf-8"?><Error><Code>AuthenticationFailed</Code>
+<Message>Server failed to authenticate the request.
+RequestId:2</Message></Error>"#,
+    );
+    let (url, _) = mock_server(vec![missing, forbidden]);
+
+    let err = reader(&url).run(args(&[("key", json!("a.txt"))])).unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<AzureBlobError>(),
+            Some(AzureBlobError::ContainerNotFound { container, .. }) if container == "reports"
+        ),
+        "{:?}",
+        err
+    );
+
+    let err = reader(&url).run(args(&[("key", json!("a.txt"))])).unwrap_err();
+    match err.downcast_ref::<AzureBlobError>() {
+        Some(AzureBlobError::AccessDenied { code, message, .. }) => {
+            assert_eq!(code.as_deref(), Some("AuthenticationFailed"));
+            assert_eq!(message, "Server failed to authenticate the request.");
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn missing_blob_is_reported() {
+    let reply = ("404 Not Found", "x-ms-error-code: BlobNotFound\r\n", "");
+    let (url, _) = mock_server(vec![reply]);
+
+    let err = reader(&url).run(args(&[("key", json!("gone.txt"))])).unwrap_err();
+
+    assert!(err.to_string().contains("az://reports/gone.txt not found"), "{}", err);
+}
+
+#[test]
+fn writes_block_blobs_with_content_type() {
+    let reply = ("201 Created", "ETag: \"0x8DC6A\"\r\n", "");
+    let (url, _, heads) = recording_mock_server(vec![reply]);
+
+    let result = AzureBlobWriterTool::new()
+        .with_endpoint_url(&url)
+        .with_account_name("acme")
+        .with_account_key("c2VjcmV0LWtleQ==")
+        .run(args(&[
+            ("file_path", json!("az://reports/out/summary.md")),
+            ("content", json!("# Summary")),
+            ("content_type", json!("text/markdown")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["etag"], "0x8DC6A");
+    assert_eq!(result["size"], 9);
+    assert_eq!(result["url"], format!("{}/reports/out/summary.md", url));
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("put /reports/out/summary.md "), "{}", head);
+    assert!(head.contains("x-ms-blob-type: blockblob"), "{}", head);
+    assert!(head.contains("content-type: text/markdown"), "{}", head);
+    assert!(!head.contains("if-none-match"), "{}", head);
+}
+
+#[test]
+fn if_not_exists_refuses_to_overwrite() {
+    let reply = (
+        "409 The specified blob already exists.",
+        "x-ms-error-code: BlobAlreadyExists\r\n",
+        "",
+    );
+    let (url, _, heads) = recording_mock_server(vec![reply]);
+
+    let err = AzureBlobWriterTool::new()
+        .with_endpoint_url(&url)
+        .with_container("reports")
+        .with_account_name("acme")
+        .with_sas_token("?sv=2022-11-02&sp=cw&sig=abc")
+        .with_if_not_exists(true)
+        .run(args(&[("key", json!("a.txt")), ("content", json!("x"))]))
+        .unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<AzureBlobError>(),
+        Some(AzureBlobError::AlreadyExists { .. })
+    ));
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("put /reports/a.txt?sv=2022-11-02&sp=cw&sig=abc "), "{}", head);
+    assert!(head.contains("if-none-match: *"), "{}", head);
+}
@@ -0,0 +1,148 @@
+//! BedrockKbRetrieverTool against a scripted bedrock-agent-runtime endpoint.
+
+#![cfg(feature = "cloud_storage")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{BedrockKbRetrieverTool, Document};
+use serde_json::{json, Value};
+
+const RESULTS: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"retrievalResults": [
+        {"content": {"text": "Refunds are issued within 14 days.", "type": "TEXT"},
+         "location": {"type": "S3", "s3Location": {"uri": "s3://kb-docs/policies/refunds.pdf"}},
+         "metadata": {"x-amz-bedrock-kb-source-uri": "s3://kb-docs/policies/refunds.pdf", "year": 2024},
+         "score": 0.87},
+        {"content": {"text": "Shipping takes 3-5 business days."},
+         "location": {"type": "WEB", "webLocation": {"url": "https://example.com/shipping"}},
+         "score": 0.61}
+    ]}"#,
+);
+
+const THROTTLED: common::Scripted = (
+    "429 Too Many Requests",
+    "x-amzn-ErrorType: ThrottlingException:http://internal.amazon.com/coral/\r\n",
+    r#"{"message": "Rate exceeded"}"#,
+);
+
+fn tool(url: &str) -> BedrockKbRetrieverTool {
+    BedrockKbRetrieverTool::new("KB123")
+        .with_region("eu-west-1")
+        .with_endpoint_url(url)
+        .with_credentials("AKIDEXAMPLE", "wJalrXUtnFEMI")
+        .with_backoff_base_ms(1)
+}
+
+#[test]
+fn returns_chunks_with_score_and_source() {
+    let (url, _, heads) = recording_mock_server(vec![RESULTS]);
+    let result = tool(&url).run(args(&[("query", json!("refund policy"))])).unwrap();
+
+    let results = result["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["content"], "Refunds are issued within 14 days.");
+    assert_eq!(results[0]["score"], 0.87);
+    assert_eq!(results[0]["source"], "s3://kb-docs/policies/refunds.pdf");
+    assert_eq!(results[0]["metadata"]["year"], 2024);
+    assert_eq!(results[1]["source"], "https://example.com/shipping");
+    assert_eq!(results[1]["metadata"]["location_type"], "WEB");
+
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("post /knowledgebases/kb123/retrieve "), "{}", head);
+    assert!(head.contains("/eu-west-1/bedrock/aws4_request"), "{}", head);
+}
+
+#[test]
+fn results_deserialize_as_documents() {
+    let (url, _) = mock_server(vec![RESULTS, RESULTS]);
+    let result = tool(&url).run(args(&[("query", json!("refund policy"))])).unwrap();
+    let documents: Vec<Document> = serde_json::from_value(result["results"].clone()).unwrap();
+    assert_eq!(documents[0].content, "Refunds are issued within 14 days.");
+    assert_eq!(documents[0].metadata["score"], 0.87);
+
+    let retrieved = tool(&url).retrieve("refund policy", None).unwrap();
+    assert_eq!(retrieved[1].metadata["source"], "https://example.com/shipping");
+}
+
+#[test]
+fn sends_top_k_and_metadata_filter() {
+    let (url, bodies) = body_recording_mock_server(vec![RESULTS, RESULTS, RESULTS]);
+    let kb = tool(&url).with_top_k(3);
+    kb.run(args(&[("query", json!("refunds")), ("filter", json!({"year": 2024}))]))
+        .unwrap();
+    kb.run(args(&[
+        ("query", json!("refunds")),
+        ("filter", json!({"region": "EU", "year": 2024})),
+        ("top_k", json!(7)),
+    ]))
+    .unwrap();
+    let native = json!({"startsWith": {"key": "team", "value": "ops"}});
+    kb.run(args(&[("query", json!("refunds")), ("filter", native.clone())]))
+        .unwrap();
+
+    let bodies: Vec<Value> = bodies
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|b| serde_json::from_str(b).unwrap())
+        .collect();
+    assert_eq!(bodies[0]["retrievalQuery"]["text"], "refunds");
+    let search = &bodies[0]["retrievalConfiguration"]["vectorSearchConfiguration"];
+    assert_eq!(search["numberOfResults"], 3);
+    assert_eq!(search["filter"], json!({"equals": {"key": "year", "value": 2024}}));
+
+    let search = &bodies[1]["retrievalConfiguration"]["vectorSearchConfiguration"];
+    assert_eq!(search["numberOfResults"], 7);
+    assert_eq!(
+        search["filter"],
+        json!({"andAll": [
+            {"equals": {"key": "region", "value": "EU"}},
+            {"equals": {"key": "year", "value": 2024}}
+        ]})
+    );
+    assert_eq!(bodies[2]["retrievalConfiguration"]["vectorSearchConfiguration"]["filter"], native);
+}
+
+#[test]
+fn throttling_is_retried() {
+    let (url, hits) = mock_server(vec![THROTTLED, THROTTLED, RESULTS]);
+    let result = tool(&url).run(args(&[("query", json!("refunds"))])).unwrap();
+    assert_eq!(result["results"].as_array().unwrap().len(), 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn throttling_gives_up_after_max_retries() {
+    let (url, hits) = mock_server(vec![THROTTLED, THROTTLED, RESULTS]);
+    let err = tool(&url)
+        .with_max_retries(1)
+        .run(args(&[("query", json!("refunds"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("ThrottlingException"), "{}", err);
+    assert!(err.to_string().contains("Rate exceeded"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn other_errors_are_not_retried() {
+    let (url, hits) = mock_server(vec![(
+        "404 Not Found",
+        "x-amzn-ErrorType: ResourceNotFoundException\r\n",
+        r#"{"message": "Knowledge base KB123 not found"}"#,
+    )]);
+    let err = tool(&url).run(args(&[("query", json!("refunds"))])).unwrap_err();
+    assert!(err.to_string().contains("ResourceNotFoundException"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn requires_a_query() {
+    let err = tool("http://localhost").run(HashMap::new()).unwrap_err();
+    assert!(err.to_string().contains("query"), "{}", err);
+}
@@ -0,0 +1,188 @@
+//! BrowserbaseLoadTool against a scripted sessions API and a fake
+//! DevTools browser.
+
+#![cfg(feature = "browser")]
+
+mod common;
+
+use std::net::TcpListener;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::BrowserbaseLoadTool;
+use serde_json::{json, Value};
+use tungstenite::Message;
+
+fn tool(url: &str) -> BrowserbaseLoadTool {
+    BrowserbaseLoadTool::new()
+        .with_base_url(url)
+        .with_api_key("bb_test")
+        .with_project_id("proj-1")
+        .with_timeout(Duration::from_secs(10))
+}
+
+/// Serve one DevTools connection that answers the commands the tool sends
+/// and reports network idle after navigating. Returns its URL and the
+/// methods it received.
+fn fake_browser() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}/devtools/browser/b1", listener.local_addr().unwrap());
+    let methods = Arc::new(Mutex::new(Vec::new()));
+    let log = methods.clone();
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut socket = tungstenite::accept(stream).unwrap();
+        while let Ok(Message::Text(text)) = socket.read() {
+            let command: Value = serde_json::from_str(&text).unwrap();
+            let method = command["method"].as_str().unwrap().to_string();
+            log.lock().unwrap().push(method.clone());
+            let result = match method.as_str() {
+                "Target.getTargets" => json!({"targetInfos": [{"type": "page", "targetId": "T1"}]}),
+                "Target.attachToTarget" => json!({"sessionId": "S1"}),
+                "Page.navigate" => json!({"frameId": "F1"}),
+                "Page.captureScreenshot" => json!({"data": "iVBORw0KGgo="}),
+                "Runtime.evaluate" => {
+                    let value = match command["params"]["expression"].as_str().unwrap() {
+                        "location.href" => json!("https://example.com/"),
+                        "document.title" => json!("Example Domain"),
+                        "document.documentElement.outerHTML" => json!("<html><h1>Hi</h1></html>"),
+                        e if e.starts_with("!!document.querySelector") => json!(true),
+                        _ => json!("Example Domain\nThis domain is for examples."),
+                    };
+                    json!({"result": {"type": "string", "value": value}})
+                }
+                _ => json!({}),
+            };
+            let reply = json!({"id": command["id"], "result": result, "sessionId": "S1"});
+            socket.send(Message::Text(reply.to_string())).unwrap();
+            if method == "Page.navigate" {
+                for name in ["load", "networkIdle"] {
+                    let event = json!({
+                        "method": "Page.lifecycleEvent",
+                        "params": {"frameId": "F1", "name": name},
+                        "sessionId": "S1",
+                    });
+                    socket.send(Message::Text(event.to_string())).unwrap();
+                }
+            }
+        }
+    });
+    (url, methods)
+}
+
+fn session_reply(connect_url: &str) -> common::Scripted {
+    let body = json!({"id": "sess-1", "connectUrl": connect_url}).to_string();
+    ("201 Created", "", Box::leak(body.into_boxed_str()))
+}
+
+#[test]
+fn loads_page_text_and_releases_the_session() {
+    let (browser, methods) = fake_browser();
+    let (url, _, heads) =
+        recording_mock_server(vec![session_reply(&browser), ("200 OK", "", "{}")]);
+
+    let result = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap();
+
+    assert_eq!(result["content"], "Example Domain\nThis domain is for examples.");
+    assert_eq!(result["title"], "Example Domain");
+    assert_eq!(result["url"], "https://example.com/");
+    assert_eq!(result["session_id"], "sess-1");
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /v1/sessions "), "{}", heads[0]);
+    assert!(heads[0].contains("x-bb-api-key: bb_test"), "{}", heads[0]);
+    assert!(heads[1].starts_with("post /v1/sessions/sess-1 "), "{}", heads[1]);
+    let methods = methods.lock().unwrap();
+    assert!(methods.contains(&"Page.navigate".to_string()), "{:?}", methods);
+    assert!(!methods.contains(&"Network.setBlockedURLs".to_string()), "{:?}", methods);
+}
+
+#[test]
+fn html_and_screenshot_outputs() {
+    let (browser, _) = fake_browser();
+    let (url, _) = mock_server(vec![session_reply(&browser), ("200 OK", "", "{}")]);
+    let result = tool(&url)
+        .run(args(&[("url", json!("https://example.com")), ("output", json!("html"))]))
+        .unwrap();
+    assert_eq!(result["content"], "<html><h1>Hi</h1></html>");
+
+    let (browser, _) = fake_browser();
+    let (url, _) = mock_server(vec![session_reply(&browser), ("200 OK", "", "{}")]);
+    let result = tool(&url)
+        .run(args(&[
+            ("url", json!("https://example.com")),
+            ("output", json!("screenshot")),
+            ("wait_for_selector", json!("h1")),
+        ]))
+        .unwrap();
+    assert_eq!(result["output"], "screenshot");
+    assert_eq!(result["content"], "iVBORw0KGgo=");
+}
+
+#[test]
+fn text_only_blocks_images() {
+    let (browser, methods) = fake_browser();
+    let (url, _) = mock_server(vec![session_reply(&browser), ("200 OK", "", "{}")]);
+
+    tool(&url)
+        .with_text_only(true)
+        .run(args(&[("url", json!("https://example.com"))]))
+        .unwrap();
+
+    assert!(methods.lock().unwrap().contains(&"Network.setBlockedURLs".to_string()));
+}
+
+#[test]
+fn session_is_released_when_the_browser_is_unreachable() {
+    let (url, bodies) = body_recording_mock_server(vec![
+        session_reply("ws://127.0.0.1:1/devtools/browser/b1"),
+        ("200 OK", "", "{}"),
+    ]);
+
+    let err = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap_err();
+
+    assert!(err.to_string().contains("remote browser"), "{}", err);
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies.len(), 2);
+    let release: Value = serde_json::from_str(&bodies[1]).unwrap();
+    assert_eq!(release, json!({"projectId": "proj-1", "status": "REQUEST_RELEASE"}));
+}
+
+#[test]
+fn quota_errors_are_explained() {
+    let reply = (
+        "429 Too Many Requests",
+        "",
+        r#"{"statusCode": 429, "message": "You've exceeded your max concurrent sessions limit"}"#,
+    );
+    let (url, hits) = mock_server(vec![reply]);
+
+    let err = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("quota reached"), "{}", message);
+    assert!(message.contains("max concurrent sessions"), "{}", message);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn bad_output_is_rejected_before_creating_a_session() {
+    let (url, hits) = mock_server(vec![]);
+    let err = tool(&url)
+        .run(args(&[("url", json!("https://example.com")), ("output", json!("pdf"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("expected text, html or screenshot"), "{}", err);
+    let err = tool(&url)
+        .with_text_only(true)
+        .run(args(&[("url", json!("https://example.com")), ("output", json!("screenshot"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("text_only"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn debug_redacts_the_api_key() {
+    let debug = format!("{:?}", tool("http://localhost"));
+    assert!(!debug.contains("bb_test"), "{}", debug);
+}
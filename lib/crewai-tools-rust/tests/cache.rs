@@ -0,0 +1,69 @@
+//! Response caching shared between search tools.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::{mock_server, OK};
+use crewai_tools::{CacheStats, SearchCache, SerperDevTool};
+use serde_json::Value;
+
+fn query_args(query: &str) -> HashMap<String, Value> {
+    HashMap::from([("search_query".to_string(), Value::from(query))])
+}
+
+#[test]
+fn repeated_query_is_served_from_cache() {
+    let (url, hits) = mock_server(vec![OK, OK]);
+    let cache = Arc::new(SearchCache::new(Duration::from_secs(60), 10));
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_cache(cache.clone());
+
+    let first = tool.run(query_args("Rust  async")).unwrap();
+    let second = tool.run(query_args("rust async")).unwrap();
+
+    assert_eq!(first["cached"], false);
+    assert_eq!(second["cached"], true);
+    assert_eq!(second["organic"], first["organic"]);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 1, entries: 1 });
+}
+
+#[test]
+fn different_params_do_not_share_entries() {
+    let (url, hits) = mock_server(vec![OK, OK]);
+    let cache = Arc::new(SearchCache::default());
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_cache(cache.clone());
+
+    tool.run(query_args("rust")).unwrap();
+    tool.clone().with_max_results(3).run(query_args("rust")).unwrap();
+
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert_eq!(cache.stats().entries, 2);
+}
+
+#[test]
+fn expired_entries_are_refetched() {
+    let (url, hits) = mock_server(vec![OK, OK]);
+    let cache = Arc::new(SearchCache::new(Duration::ZERO, 10));
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_cache(cache);
+
+    tool.run(query_args("rust")).unwrap();
+    let second = tool.run(query_args("rust")).unwrap();
+
+    assert_eq!(second["cached"], false);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
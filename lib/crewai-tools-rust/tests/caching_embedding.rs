@@ -0,0 +1,122 @@
+//! CachingEmbeddingService partial-batch misses, persistence across
+//! instances and per-model isolation.
+
+#![cfg(feature = "rag")]
+
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::{CachingEmbeddingService, EmbeddingService};
+
+/// Embeds a text as `[len, seed]` and records every batch it is sent.
+struct Recording {
+    model: &'static str,
+    seed: f32,
+    batches: Mutex<Vec<Vec<String>>>,
+}
+
+impl Recording {
+    fn new(model: &'static str, seed: f32) -> Arc<Self> {
+        Arc::new(Self {
+            model,
+            seed,
+            batches: Mutex::new(Vec::new()),
+        })
+    }
+
+    fn batches(&self) -> Vec<Vec<String>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+impl EmbeddingService for Recording {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        Ok(self.embed_batch(&[text])?.remove(0))
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        self.batches
+            .lock()
+            .unwrap()
+            .push(texts.iter().map(|t| t.to_string()).collect());
+        Ok(texts.iter().map(|t| vec![t.len() as f32, self.seed]).collect())
+    }
+
+    fn model_name(&self) -> &str {
+        self.model
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+}
+
+fn cache_path(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir()
+        .join(format!("crewai-embed-cache-{}-{}.sqlite", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn only_misses_reach_the_inner_service() {
+    let inner = Recording::new("m1", 1.0);
+    let cache = CachingEmbeddingService::new(inner.clone());
+
+    assert_eq!(cache.embed_batch(&["a", "bb"]).unwrap(), [vec![1.0, 1.0], vec![2.0, 1.0]]);
+    let vectors = cache.embed_batch(&["bb", "ccc", "a", "ccc"]).unwrap();
+
+    assert_eq!(vectors, [vec![2.0, 1.0], vec![3.0, 1.0], vec![1.0, 1.0], vec![3.0, 1.0]]);
+    assert_eq!(inner.batches(), [vec!["a", "bb"], vec!["ccc"]]);
+    assert_eq!(cache.misses(), 3);
+    assert_eq!(cache.hits(), 3);
+    assert_eq!(cache.model_name(), "m1");
+    assert_eq!(cache.dimensions(), 2);
+}
+
+#[test]
+fn disk_cache_survives_a_new_instance() {
+    let path = cache_path("persist");
+    let first = Recording::new("m1", 1.0);
+    CachingEmbeddingService::open(first.clone(), &path)
+        .unwrap()
+        .embed_batch(&["alpha", "beta"])
+        .unwrap();
+
+    let second = Recording::new("m1", 9.0);
+    let cache = CachingEmbeddingService::open(second.clone(), &path).unwrap();
+    let vectors = cache.embed_batch(&["beta", "gamma"]).unwrap();
+
+    // "beta" comes back exactly as first stored; only "gamma" is embedded.
+    assert_eq!(vectors, [vec![4.0, 1.0], vec![5.0, 9.0]]);
+    assert_eq!(second.batches(), [vec!["gamma"]]);
+    assert_eq!((cache.hits(), cache.misses()), (1, 1));
+}
+
+#[test]
+fn other_models_never_share_entries() {
+    let path = cache_path("models");
+    let small = Recording::new("small", 1.0);
+    CachingEmbeddingService::open(small, &path).unwrap().embed("text").unwrap();
+
+    let large = Recording::new("large", 2.0);
+    let cache = CachingEmbeddingService::open(large.clone(), &path).unwrap();
+
+    assert_eq!(cache.embed("text").unwrap(), [4.0, 2.0]);
+    assert_eq!(large.batches(), [vec!["text"]]);
+    assert_eq!(cache.hits(), 0);
+}
+
+#[test]
+fn memory_layer_evicts_least_recently_used() {
+    let inner = Recording::new("m1", 1.0);
+    let cache = CachingEmbeddingService::new(inner.clone()).with_memory_capacity(2);
+
+    cache.embed_batch(&["a", "b"]).unwrap();
+    cache.embed("a").unwrap();
+    cache.embed("c").unwrap();
+    // "b" was least recently used when "c" arrived.
+    cache.embed_batch(&["a", "b"]).unwrap();
+
+    assert_eq!(inner.batches(), [vec!["a", "b"], vec!["c"], vec!["b"]]);
+    assert_eq!(cache.memory_capacity(), 2);
+}
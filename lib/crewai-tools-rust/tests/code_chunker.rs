@@ -0,0 +1,190 @@
+//! CodeChunker item boundaries for Rust, Python, TypeScript and Go.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{BaseChunker, Chunk, CodeChunker, Document};
+use serde_json::json;
+
+fn source(path: &str, content: &str) -> Document {
+    Document::new(content).with_metadata("path", json!(path))
+}
+
+fn names(chunks: &[Chunk]) -> Vec<String> {
+    chunks
+        .iter()
+        .map(|c| c.metadata.get("item_name").and_then(|n| n.as_str()).unwrap_or("-").to_string())
+        .collect()
+}
+
+const RUST: &str = r#"//! Module docs.
+
+use std::fmt;
+
+/// A point.
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let brace = '{';
+        write!(f, "{{{}}} {}", self.x, brace)
+    }
+}
+
+/// Adds one.
+pub(crate) fn add_one<'a>(x: &'a i32) -> i32 {
+    // a } in a comment
+    let s = "}";
+    x + 1 + s.len() as i32
+}
+
+const LIMIT: usize = 3;
+"#;
+
+#[test]
+fn rust_items_keep_their_doc_comments_and_attributes() {
+    let chunks = CodeChunker::new().chunk(&source("src/point.rs", RUST)).unwrap();
+
+    assert_eq!(names(&chunks), ["-", "Point", "impl fmt::Display for Point", "add_one", "LIMIT"]);
+    assert_eq!(chunks[0].content, "//! Module docs.\n\nuse std::fmt;");
+    assert!(chunks[1].content.starts_with("/// A point.\n#[derive(Debug)]\npub struct Point {"));
+    assert_eq!(chunks[1].metadata["item_kind"], json!("struct"));
+    assert_eq!(chunks[1].metadata["start_line"], json!(5));
+    assert_eq!(chunks[1].metadata["end_line"], json!(9));
+    assert_eq!(chunks[2].metadata["item_kind"], json!("impl"));
+    assert!(chunks[2].content.ends_with("    }\n}"));
+    assert!(chunks[3].content.starts_with("/// Adds one."));
+    assert!(chunks[3].content.ends_with("s.len() as i32\n}"));
+    assert_eq!(chunks[3].metadata["code_language"], json!("rust"));
+    assert_eq!(chunks[3].metadata["path"], json!("src/point.rs"));
+}
+
+const PYTHON: &str = r#"import os
+
+@cache
+def load(path):
+    """Read a file.
+
+Keeps going after a zero-indent docstring line.
+"""
+    return open(path).read()
+
+
+class Store:
+    # Comment inside.
+    def get(self, key):
+        return key
+
+print(load(os.getcwd()))
+"#;
+
+#[test]
+fn python_items_follow_indentation_and_keep_decorators() {
+    let chunks = CodeChunker::new().chunk(&source("app/store.py", PYTHON)).unwrap();
+
+    assert_eq!(names(&chunks), ["-", "load", "Store", "-"]);
+    assert!(chunks[1].content.starts_with("@cache\ndef load(path):"));
+    assert!(chunks[1].content.ends_with("return open(path).read()"));
+    assert_eq!(chunks[1].metadata["item_kind"], json!("function"));
+    assert_eq!(chunks[2].metadata["item_kind"], json!("class"));
+    assert_eq!(chunks[2].metadata["start_line"], json!(12));
+    assert_eq!(chunks[2].metadata["end_line"], json!(15));
+    assert_eq!(chunks[3].content, "print(load(os.getcwd()))");
+}
+
+const TYPESCRIPT: &str = r#"import { x } from "./x";
+
+const LIMIT = 5;
+
+/** Greets. */
+export async function greet(name: string): Promise<string> {
+  return `hi ${name} }`;
+}
+
+export const handler = (req: Request) => {
+  return greet('}');
+};
+
+export interface Options {
+  verbose: boolean;
+}
+"#;
+
+#[test]
+fn typescript_items_ignore_braces_in_strings() {
+    let chunks = CodeChunker::new().chunk(&source("web/greet.ts", TYPESCRIPT)).unwrap();
+
+    assert_eq!(names(&chunks), ["-", "greet", "handler", "Options"]);
+    assert_eq!(chunks[0].content, "import { x } from \"./x\";\n\nconst LIMIT = 5;");
+    assert!(chunks[1].content.starts_with("/** Greets. */\nexport async function greet"));
+    assert_eq!(chunks[2].metadata["item_kind"], json!("variable"));
+    assert_eq!(chunks[3].metadata["item_kind"], json!("interface"));
+    assert_eq!(chunks[3].metadata["code_language"], json!("typescript"));
+}
+
+const GO: &str = r#"package store
+
+import (
+	"fmt"
+)
+
+// Store holds values.
+type Store struct {
+	items map[string]string
+}
+
+// Get returns a value.
+func (s *Store) Get(key string) string {
+	return fmt.Sprint(s.items[key], "{")
+}
+
+func New() *Store {
+	return &Store{items: map[string]string{}}
+}
+"#;
+
+#[test]
+fn go_methods_and_types_are_items() {
+    let chunks = CodeChunker::new().chunk(&source("store.go", GO)).unwrap();
+
+    assert_eq!(names(&chunks), ["-", "Store", "Get", "New"]);
+    assert_eq!(chunks[1].metadata["item_kind"], json!("struct"));
+    assert_eq!(chunks[2].metadata["item_kind"], json!("method"));
+    assert!(chunks[2].content.starts_with("// Get returns a value.\nfunc (s *Store) Get"));
+}
+
+#[test]
+fn oversized_items_fall_back_to_line_windows() {
+    let body: String = (0..25).map(|i| format!("    let v{} = {};\n", i, i)).collect();
+    let content = format!("fn big() {{\n{}}}\n", body);
+    let chunks = CodeChunker::new()
+        .with_max_lines(10)
+        .with_language("rust")
+        .chunk(&Document::new(content))
+        .unwrap();
+
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks.iter().all(|c| c.metadata["item_name"] == json!("big")));
+    assert_eq!(chunks[1].metadata["part"], json!(2));
+    assert_eq!(chunks[1].metadata["parts"], json!(3));
+    assert_eq!(chunks[1].metadata["start_line"], json!(11));
+    assert_eq!(chunks[2].metadata["end_line"], json!(27));
+}
+
+#[test]
+fn unknown_languages_use_line_windows() {
+    let content: String = (0..12).map(|i| format!("line {}\n", i)).collect();
+    let chunks = CodeChunker::new()
+        .with_max_lines(5)
+        .chunk(&source("notes.txt", &content))
+        .unwrap();
+
+    assert_eq!(chunks.len(), 3);
+    assert!(!chunks[0].metadata.contains_key("item_name"));
+    assert!(!chunks[0].metadata.contains_key("code_language"));
+
+    let err = CodeChunker::new().with_language("cobol").chunk(&Document::new("x")).unwrap_err();
+    assert!(err.to_string().contains("unsupported language"), "{}", err);
+}
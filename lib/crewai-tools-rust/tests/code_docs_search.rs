@@ -0,0 +1,110 @@
+//! CodeDocsSearchTool same-site link crawling and code-block results against
+//! a mock docs site without a sitemap.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::{args, recording_mock_server, Scripted};
+use crewai_tools::CodeDocsSearchTool;
+use serde_json::{json, Value};
+
+const HTML: &str = "Content-Type: text/html\r\n";
+const NOT_FOUND: Scripted = ("404 Not Found", "", "{}");
+
+const INDEX: Scripted = (
+    "200 OK",
+    HTML,
+    r##"<html><body><nav><a href="/docs/nav.html">parse_config in the nav</a></nav>
+       <h1>Guide</h1><p>Start with <a href="intro.html">the intro</a>.</p>
+       <a href="/docs/api/other.html">API</a> <a href="/blog/">Blog</a>
+       <a href="http://elsewhere.example/docs/guide/x.html">Mirror</a></body></html>"##,
+);
+const INTRO: Scripted = (
+    "200 OK",
+    HTML,
+    r##"<html><body><h2 id="setup">Setup</h2>
+       <p>Call parse_config once at startup.</p>
+       <pre><code class="language-rust">let cfg = parse_config(&amp;path)?;
+println!("{:?}", cfg);</code></pre>
+       <h2>Next steps</h2><p>See <a href="deep.html">more</a>.</p></body></html>"##,
+);
+const DEEP: Scripted = ("200 OK", HTML, "<html><body><p>parse_config options.</p></body></html>");
+
+/// Run the tool from `/docs/guide/index.html`, returning the result and the
+/// paths requested.
+fn search(script: Vec<Scripted>, tool: CodeDocsSearchTool, query: &str) -> (Value, Vec<String>) {
+    let (base, _, requests) = recording_mock_server(script);
+    let result = tool
+        .with_docs_url(format!("{}/docs/guide/index.html", base))
+        .with_crawl_delay_ms(0)
+        .run(args(&[("search_query", json!(query))]))
+        .unwrap();
+    let paths = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.split_whitespace().nth(1).unwrap().to_string())
+        .collect();
+    (result, paths)
+}
+
+#[test]
+fn links_are_followed_only_under_the_docs_prefix_and_within_depth() {
+    let script = vec![NOT_FOUND, NOT_FOUND, INDEX, INTRO];
+
+    let (result, paths) = search(script, CodeDocsSearchTool::new().with_max_depth(1), "parse_config");
+
+    assert_eq!(
+        paths,
+        ["/docs/guide/sitemap.xml", "/sitemap.xml", "/docs/guide/index.html", "/docs/guide/intro.html"]
+    );
+    assert_eq!(result["discovery"], "links");
+    assert_eq!(result["pages_crawled"], 2);
+}
+
+#[test]
+fn max_pages_limits_the_crawl() {
+    let script = vec![NOT_FOUND, NOT_FOUND, INDEX, INTRO, DEEP];
+
+    let (result, paths) = search(script, CodeDocsSearchTool::new().with_max_pages(1), "guide");
+
+    assert_eq!(paths.len(), 3);
+    assert_eq!(result["pages_crawled"], 1);
+}
+
+#[test]
+fn identifier_matches_in_code_blocks_rank_first_with_their_anchor() {
+    let script = vec![NOT_FOUND, NOT_FOUND, INDEX, INTRO, DEEP];
+
+    let (result, paths) = search(script, CodeDocsSearchTool::new(), "parse_config");
+
+    assert_eq!(paths.last().unwrap(), "/docs/guide/deep.html");
+    assert_eq!(result["identifier_query"], true);
+    let top = &result["results"][0];
+    assert_eq!(top["kind"], "code");
+    assert_eq!(top["language"], "rust");
+    assert_eq!(top["heading"], "Setup");
+    assert_eq!(top["anchor"], "setup");
+    assert!(top["url"].as_str().unwrap().ends_with("/docs/guide/intro.html#setup"));
+    assert_eq!(top["snippet"], "let cfg = parse_config(&path)?;\nprintln!(\"{:?}\", cfg);");
+    assert_eq!(top["score"], 3.0);
+
+    // Prose matches follow; navigation chrome is never searched.
+    let kinds: Vec<&str> =
+        result["results"].as_array().unwrap().iter().map(|r| r["kind"].as_str().unwrap()).collect();
+    assert_eq!(kinds, ["code", "prose", "prose"]);
+}
+
+#[test]
+fn prose_queries_do_not_boost_code() {
+    let script = vec![NOT_FOUND, NOT_FOUND, INDEX, INTRO, DEEP];
+
+    let (result, _) = search(script, CodeDocsSearchTool::new(), "next steps");
+
+    assert_eq!(result["identifier_query"], false);
+    let top = &result["results"][0];
+    assert_eq!(top["kind"], "heading");
+    assert_eq!(top["anchor"], "next-steps");
+    assert_eq!(top["score"], 2.0);
+}
@@ -0,0 +1,106 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+/// A scripted response: status line, extra headers, body.
+pub type Scripted = (&'static str, &'static str, &'static str);
+
+pub const UNAVAILABLE: Scripted = ("503 Service Unavailable", "", "{}");
+pub const OK: Scripted = ("200 OK", "", r#"{"organic":[{"title":"t"}]}"#);
+
+/// Serve `script` one response per connection, then stop. Returns the base
+/// URL and a counter of requests received.
+pub fn mock_server(script: Vec<Scripted>) -> (String, Arc<AtomicUsize>) {
+    let (url, hits, _) = recording_mock_server(script);
+    (url, hits)
+}
+
+/// `mock_server` that also keeps the request line and headers of every
+/// request, lowercased.
+pub fn recording_mock_server(
+    script: Vec<Scripted>,
+) -> (String, Arc<AtomicUsize>, Arc<Mutex<Vec<String>>>) {
+    let (url, hits, requests, _) = serve(script);
+    (url, hits, requests)
+}
+
+/// `mock_server` that keeps the body of every request, as sent.
+pub fn body_recording_mock_server(script: Vec<Scripted>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let (url, _, _, bodies) = serve(script);
+    (url, bodies)
+}
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+fn serve(script: Vec<Scripted>) -> (String, Arc<AtomicUsize>, Log, Log) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let log = requests.clone();
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let body_log = bodies.clone();
+    std::thread::spawn(move || {
+        for (status, headers, body) in script {
+            let Ok((stream, _)) = listener.accept() else {
+                return;
+            };
+            let mut reader = BufReader::new(stream);
+            let mut content_length = 0;
+            let mut head = String::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line.to_ascii_lowercase());
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            let _ = reader.read_exact(&mut request_body);
+            log.lock().unwrap().push(head);
+            body_log
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&request_body).into_owned());
+            counter.fetch_add(1, Ordering::SeqCst);
+            // Scripted headers may replace the default content type.
+            let content_type = if headers.to_ascii_lowercase().contains("content-type:") {
+                ""
+            } else {
+                "Content-Type: application/json\r\n"
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                status,
+                content_type,
+                body.len(),
+                headers,
+                body
+            );
+            let _ = reader.get_mut().write_all(response.as_bytes());
+        }
+    });
+    (url, hits, requests, bodies)
+}
+
+/// Tool arguments from `(name, value)` pairs.
+pub fn args(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect()
+}
@@ -0,0 +1,120 @@
+//! Connection reuse through `ConnectionPool`.
+
+#![cfg(feature = "database")]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crewai_tools::{ConnectionPool, Nl2SqlTool, PoolTimeout};
+use serde_json::Value;
+
+#[test]
+fn idle_connections_are_reused() {
+    let pool = ConnectionPool::new();
+    let mut opened = 0;
+
+    for _ in 0..3 {
+        let conn = pool
+            .acquire("db", || {
+                opened += 1;
+                Ok(opened)
+            })
+            .unwrap();
+        assert_eq!(*conn, 1);
+    }
+
+    assert_eq!(opened, 1);
+    assert_eq!(pool.idle_count(), 1);
+    assert_eq!(pool.in_use_count(), 0);
+}
+
+#[test]
+fn keys_are_pooled_separately() {
+    let pool = ConnectionPool::new();
+
+    let a = pool.acquire("a", || Ok("a")).unwrap();
+    let b = pool.acquire("b", || Ok("b")).unwrap();
+
+    assert_eq!((*a, *b), ("a", "b"));
+    assert_eq!(pool.in_use_count(), 2);
+}
+
+#[test]
+fn exhausted_pool_times_out() {
+    let pool = ConnectionPool::new()
+        .with_max_connections(1)
+        .with_acquire_timeout(Duration::from_millis(100));
+    let _held = pool.acquire("db", || Ok(1u8)).unwrap();
+
+    let started = Instant::now();
+    let err = pool.acquire("db", || Ok(2u8)).err().unwrap();
+
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    assert!(err.downcast_ref::<PoolTimeout>().is_some(), "{}", err);
+}
+
+#[test]
+fn exhausted_pool_waits_for_a_return() {
+    let pool = Arc::new(ConnectionPool::new().with_max_connections(1));
+    let held = pool.acquire("db", || Ok(7u8)).unwrap();
+
+    let waiter = {
+        let pool = pool.clone();
+        std::thread::spawn(move || {
+            let conn = pool.acquire("db", || Ok(0u8)).unwrap();
+            *conn
+        })
+    };
+    std::thread::sleep(Duration::from_millis(50));
+    drop(held);
+
+    assert_eq!(waiter.join().unwrap(), 7);
+}
+
+#[test]
+fn expired_idle_connections_are_replaced() {
+    let pool = ConnectionPool::new().with_idle_timeout(Duration::from_millis(10));
+    drop(pool.acquire("db", || Ok(1u8)).unwrap());
+    std::thread::sleep(Duration::from_millis(30));
+
+    let conn = pool.acquire("db", || Ok(2u8)).unwrap();
+
+    assert_eq!(*conn, 2);
+}
+
+#[test]
+fn failed_connects_free_their_slot() {
+    let pool = ConnectionPool::new().with_max_connections(1);
+
+    let err = pool
+        .acquire::<u8, _>("db", || Err(anyhow::anyhow!("refused")))
+        .err()
+        .unwrap();
+
+    assert_eq!(err.to_string(), "refused");
+    assert_eq!(pool.in_use_count(), 0);
+    assert!(pool.acquire("db", || Ok(1u8)).is_ok());
+}
+
+#[test]
+fn nl2sql_reuses_pooled_sqlite_connections() {
+    let path = std::env::temp_dir().join(format!("crewai-pool-{}.sqlite", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    rusqlite::Connection::open(&path)
+        .unwrap()
+        .execute_batch("CREATE TABLE t (n INTEGER); INSERT INTO t VALUES (1);")
+        .unwrap();
+    let pool = Arc::new(ConnectionPool::new());
+    let tool = Nl2SqlTool::new()
+        .with_dialect("sqlite")
+        .with_connection_string(path.to_string_lossy())
+        .with_pool(pool.clone());
+    let args = HashMap::from([("sql_query".to_string(), Value::from("SELECT n FROM t"))]);
+
+    tool.run(args.clone()).unwrap();
+    tool.run(args).unwrap();
+
+    assert_eq!(pool.idle_count(), 1);
+    assert_eq!(pool.in_use_count(), 0);
+}
@@ -0,0 +1,72 @@
+//! robots.txt handling and per-host rate limiting of the native scraping
+//! tools.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use common::mock_server;
+use crewai_tools::{HostRateLimiter, RobotsDisallowed, ScrapeWebsiteTool};
+use serde_json::Value;
+
+const ROBOTS: &str = "User-agent: *\nDisallow: /private\nAllow: /private/ok$\n";
+
+fn url_args(url: String) -> HashMap<String, Value> {
+    HashMap::from([("website_url".to_string(), Value::from(url))])
+}
+
+#[test]
+fn disallowed_url_is_refused_without_fetching() {
+    let (base, hits) = mock_server(vec![("200 OK", "", ROBOTS)]);
+    let tool = ScrapeWebsiteTool::new();
+
+    let err = tool.run(url_args(format!("{}/private/page", base))).unwrap_err();
+    let disallowed = err.downcast_ref::<RobotsDisallowed>().unwrap();
+    assert!(disallowed.url.ends_with("/private/page"));
+    // Only robots.txt was requested.
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn robots_txt_is_cached_per_host() {
+    let (base, hits) = mock_server(vec![("200 OK", "", ROBOTS)]);
+    let tool = ScrapeWebsiteTool::new();
+
+    for path in ["/private/a", "/private/b"] {
+        let err = tool.run(url_args(format!("{}{}", base, path))).unwrap_err();
+        assert!(err.downcast_ref::<RobotsDisallowed>().is_some());
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(tool.robots.len(), 1);
+}
+
+#[test]
+fn ignore_robots_skips_the_check() {
+    let (base, hits) = mock_server(vec![("200 OK", "", "{}")]);
+    let tool = ScrapeWebsiteTool::new().with_ignore_robots(true);
+
+    // The mock answers with JSON, which the tool refuses to scrape; what
+    // matters is that the page itself was requested.
+    let err = tool.run(url_args(format!("{}/private/page", base))).unwrap_err();
+    assert!(err.downcast_ref::<RobotsDisallowed>().is_none());
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert!(tool.robots.is_empty());
+}
+
+#[test]
+fn rate_limiter_spaces_requests_per_host() {
+    let limiter = HostRateLimiter::new(Duration::from_millis(100));
+    let a = reqwest::Url::parse("http://a.example/").unwrap();
+    let b = reqwest::Url::parse("http://b.example/").unwrap();
+
+    let start = Instant::now();
+    limiter.wait(&a);
+    limiter.wait(&b);
+    assert!(start.elapsed() < Duration::from_millis(100));
+    limiter.wait(&a);
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
@@ -0,0 +1,112 @@
+//! GenerateCrewaiAutomationTool with scripted LLM replies.
+
+#![cfg(feature = "automation")]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::GenerateCrewaiAutomationTool;
+use serde_json::{json, Value};
+
+const YAML_CREW: &str = "```yaml
+agents:
+  researcher:
+    role: Market researcher
+    goal: Find solar adoption data
+    backstory: Ten years of energy analysis.
+  writer:
+    role: Writer
+    goal: Summarize findings
+    backstory: Former trade journalist.
+tasks:
+  research_task:
+    description: Collect 2024 adoption figures.
+    expected_output: A table of figures by region.
+    agent: researcher
+  report_task:
+    description: Write a one-page brief.
+    expected_output: A markdown brief.
+    agent: writer
+    context: [research_task]
+crew:
+  process: sequential
+```";
+
+/// An LLM that returns `replies` in order and records its prompts.
+fn scripted(replies: Vec<&'static str>) -> (GenerateCrewaiAutomationTool, Arc<Mutex<Vec<String>>>) {
+    let prompts = Arc::new(Mutex::new(Vec::new()));
+    let log = prompts.clone();
+    let replies = Mutex::new(replies.into_iter());
+    let llm = move |prompt: &str| -> Result<String, anyhow::Error> {
+        log.lock().unwrap().push(prompt.to_string());
+        Ok(replies.lock().unwrap().next().expect("unexpected LLM call").to_string())
+    };
+    (GenerateCrewaiAutomationTool::new().with_llm(Arc::new(llm)), prompts)
+}
+
+fn describe(format: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        ("description".to_string(), json!("Research solar adoption and write a brief")),
+        ("output_format".to_string(), json!(format)),
+    ])
+}
+
+#[test]
+fn valid_yaml_is_returned_in_declaration_order() {
+    let (tool, prompts) = scripted(vec![YAML_CREW]);
+
+    let result = tool.run(describe("yaml")).unwrap();
+
+    assert_eq!(result["repaired"], false);
+    assert_eq!(result["agents"], json!(["researcher", "writer"]));
+    assert_eq!(result["tasks"], json!(["research_task", "report_task"]));
+    let definition = result["definition"].as_str().unwrap();
+    assert!(definition.starts_with("agents:\n  researcher:\n"), "{}", definition);
+    assert!(definition.find("research_task").unwrap() < definition.find("report_task").unwrap());
+    assert!(prompts.lock().unwrap()[0].contains("Research solar adoption"));
+}
+
+#[test]
+fn undefined_agent_gets_one_repair_attempt() {
+    let bad = r#"{"agents": {"researcher": {"role": "r", "goal": "g", "backstory": "b"}},
+                  "tasks": {"t1": {"description": "d", "expected_output": "o", "agent": "analyst"}}}"#;
+    let good = r#"{"agents": {"researcher": {"role": "r", "goal": "g", "backstory": "b"}},
+                   "tasks": {"t1": {"description": "d", "expected_output": "o", "agent": "researcher"}}}"#;
+    let (tool, prompts) = scripted(vec![bad, good]);
+
+    let result = tool.run(describe("json")).unwrap();
+
+    assert_eq!(result["repaired"], true);
+    let definition: Value = serde_json::from_str(result["definition"].as_str().unwrap()).unwrap();
+    assert_eq!(definition["tasks"]["t1"]["agent"], "researcher");
+    assert_eq!(definition["crew"]["process"], "sequential");
+    let repair = &prompts.lock().unwrap()[1];
+    assert!(repair.contains("task 't1' references undefined agent 'analyst'"), "{}", repair);
+}
+
+#[test]
+fn output_still_invalid_after_repair_is_an_error() {
+    let (tool, _) = scripted(vec!["not: [valid", "agents: {}\ntasks: {}"]);
+
+    let err = tool.run(describe("yaml")).unwrap_err().to_string();
+
+    assert!(err.contains("after a repair attempt"), "{}", err);
+    assert!(err.contains("no agents are defined"), "{}", err);
+}
+
+#[test]
+fn python_is_rendered_from_the_validated_definition() {
+    let (tool, prompts) = scripted(vec![
+        r#"{"agents": {"researcher": {"role": "Analyst \"lead\"", "goal": "g", "backstory": "b"}},
+            "tasks": {"t1": {"description": "d", "expected_output": "o", "agent": "researcher"}}}"#,
+    ]);
+
+    let result = tool.run(describe("python")).unwrap();
+
+    let code = result["definition"].as_str().unwrap();
+    assert!(code.starts_with("from crewai import Agent, Crew, Process, Task\n"), "{}", code);
+    assert!(code.contains(r#"role="Analyst \"lead\"","#), "{}", code);
+    assert!(code.contains("    agent=researcher,\n"), "{}", code);
+    assert!(code.contains("process=Process.sequential"), "{}", code);
+    assert!(prompts.lock().unwrap()[0].contains("only a JSON document"));
+}
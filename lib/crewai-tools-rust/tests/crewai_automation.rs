@@ -0,0 +1,101 @@
+//! InvokeCrewaiAutomationTool against a scripted crewAI Enterprise crew.
+
+#![cfg(feature = "automation")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use common::{body_recording_mock_server, recording_mock_server};
+use crewai_tools::InvokeCrewaiAutomationTool;
+use serde_json::{json, Value};
+
+const KICKOFF: common::Scripted = ("200 OK", "", r#"{"kickoff_id": "k-42"}"#);
+const RUNNING: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"state": "RUNNING", "last_step": {"agent": "Researcher"}}"#,
+);
+const SUCCESS: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"state": "SUCCESS", "result": "Final report"}"#,
+);
+
+fn tool(url: &str) -> InvokeCrewaiAutomationTool {
+    InvokeCrewaiAutomationTool::new()
+        .with_crew_api_url(url)
+        .with_crew_bearer_token("crew-tok")
+        .with_poll_interval_ms(1)
+        .with_backoff_base_ms(1)
+}
+
+fn inputs() -> HashMap<String, Value> {
+    HashMap::from([("inputs".to_string(), json!({"topic": "solar"}))])
+}
+
+#[test]
+fn kickoff_is_polled_through_blips_until_success() {
+    let (url, _, requests) = recording_mock_server(vec![
+        KICKOFF,
+        common::UNAVAILABLE,
+        RUNNING,
+        SUCCESS,
+    ]);
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let log = seen.clone();
+
+    let result = tool(&url)
+        .with_progress(move |status| log.lock().unwrap().push(status["last_step"]["agent"].clone()))
+        .run(inputs())
+        .unwrap();
+
+    assert_eq!(result, json!({"kickoff_id": "k-42", "state": "SUCCESS", "result": "Final report"}));
+    assert_eq!(*seen.lock().unwrap(), vec![json!("Researcher")]);
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("post /kickoff "), "{}", requests[0]);
+    assert!(requests[0].contains("authorization: bearer crew-tok"));
+    assert!(requests[1].starts_with("get /status/k-42 "), "{}", requests[1]);
+    assert_eq!(requests.len(), 4);
+}
+
+#[test]
+fn inputs_are_sent_in_the_kickoff_body() {
+    let (url, bodies) = body_recording_mock_server(vec![KICKOFF, SUCCESS]);
+
+    tool(&url).run(inputs()).unwrap();
+
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body, json!({"inputs": {"topic": "solar"}}));
+}
+
+#[test]
+fn failed_kickoff_returns_the_error_detail() {
+    let failed = (
+        "200 OK",
+        "",
+        r#"{"state": "FAILED", "error": "Tool 'SerperDevTool' raised: quota exceeded"}"#,
+    );
+    let (url, _, _) = recording_mock_server(vec![failed]);
+
+    let err = tool(&url)
+        .run(HashMap::from([("kickoff_id".to_string(), json!("k-7"))]))
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Crew kickoff k-7 failed: Tool 'SerperDevTool' raised: quota exceeded"
+    );
+}
+
+#[test]
+fn timeout_names_the_kickoff_to_resume() {
+    let (url, _, _) = recording_mock_server(vec![KICKOFF, RUNNING]);
+
+    let err = tool(&url).with_timeout(Duration::ZERO).run(inputs()).unwrap_err();
+
+    assert!(err.to_string().contains("k-42 still RUNNING"), "{}", err);
+    assert!(err.to_string().contains("pass kickoff_id"), "{}", err);
+}
@@ -0,0 +1,172 @@
+//! CronScheduleTool: scheduling, listing, cancelling and running due jobs
+//! against a JSON store in a temporary directory.
+
+#![cfg(feature = "automation")]
+
+mod common;
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use common::args;
+use crewai_tools::CronScheduleTool;
+use serde_json::{json, Value};
+
+fn store(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("crewai-cron-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir.join("jobs.json")
+}
+
+/// Every `(tool, args)` the executor was called with.
+type Calls = Arc<Mutex<Vec<(String, Value)>>>;
+
+/// A tool whose executor records `(tool, args)` for every job it runs.
+fn recording_tool(path: PathBuf) -> (CronScheduleTool, Calls) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let log = calls.clone();
+    let tool = CronScheduleTool::new().with_store_path(path).with_executor(move |tool, args| {
+        log.lock().unwrap().push((tool.to_string(), args.clone()));
+        Ok(json!({"done": true}))
+    });
+    (tool, calls)
+}
+
+fn jobs(tool: &CronScheduleTool) -> Vec<Value> {
+    let listed = tool.run(args(&[("action", json!("list"))])).unwrap();
+    listed["jobs"].as_array().unwrap().clone()
+}
+
+#[test]
+fn one_shot_job_runs_once_and_is_removed() {
+    let (tool, calls) = recording_tool(store("once"));
+    let scheduled = tool
+        .run(args(&[
+            ("schedule", json!("2020-01-01T09:00:00+02:00")),
+            ("tool", json!("ticket_check")),
+            ("args", json!({"ticket": 42})),
+        ]))
+        .unwrap();
+    assert_eq!(scheduled["scheduled"]["next_run"], "2020-01-01T07:00:00Z");
+
+    let reports = tool.run_due().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0]["ok"], true);
+    assert_eq!(reports[0]["result"], json!({"done": true}));
+    assert_eq!(
+        calls.lock().unwrap().as_slice(),
+        &[("ticket_check".to_string(), json!({"ticket": 42}))]
+    );
+    assert!(jobs(&tool).is_empty());
+    assert!(tool.run_due().unwrap().is_empty());
+}
+
+#[test]
+fn delayed_job_waits_until_due() {
+    let (tool, calls) = recording_tool(store("delay"));
+    tool.run(args(&[("delay_seconds", json!(7200)), ("tool", json!("ticket_check"))]))
+        .unwrap();
+
+    assert!(tool.run_due().unwrap().is_empty());
+    let later = SystemTime::now() + Duration::from_secs(7201);
+    assert_eq!(tool.run_due_at(later).unwrap().len(), 1);
+    assert_eq!(calls.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn missed_recurring_runs_fire_once() {
+    let (tool, calls) = recording_tool(store("missed"));
+    tool.run(args(&[("schedule", json!("@hourly")), ("tool", json!("poll"))]))
+        .unwrap();
+
+    // Down for a day: 24 hourly runs were missed.
+    let after_downtime = SystemTime::now() + Duration::from_secs(24 * 3600 + 60);
+    assert_eq!(tool.run_due_at(after_downtime).unwrap().len(), 1);
+    assert_eq!(calls.lock().unwrap().len(), 1);
+    assert!(tool.run_due_at(after_downtime).unwrap().is_empty());
+
+    let job = &jobs(&tool)[0];
+    assert_eq!(job["runs"], 1);
+    assert!(job["next_run"].as_str().unwrap().ends_with(":00:00Z"), "{}", job);
+    assert!(job["next_run"].as_str() > job["last_run"].as_str(), "{}", job);
+}
+
+#[test]
+fn cron_fields_constrain_next_run() {
+    let (tool, _) = recording_tool(store("fields"));
+    let scheduled = tool
+        .run(args(&[("schedule", json!("*/15 9-17 * * 1-5")), ("tool", json!("poll"))]))
+        .unwrap();
+    let next = scheduled["scheduled"]["next_run"].as_str().unwrap().to_string();
+    let hour: u32 = next[11..13].parse().unwrap();
+    let minute: u32 = next[14..16].parse().unwrap();
+    assert!((9..=17).contains(&hour), "{}", next);
+    assert_eq!(minute % 15, 0, "{}", next);
+}
+
+#[test]
+fn list_and_cancel() {
+    let (tool, _) = recording_tool(store("cancel"));
+    for name in ["a", "b"] {
+        tool.run(args(&[("schedule", json!("0 9 * * *")), ("tool", json!(name))]))
+            .unwrap();
+    }
+    let listed = jobs(&tool);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0]["id"], "job-1");
+
+    let cancelled = tool
+        .run(args(&[("action", json!("cancel")), ("job_id", json!("job-1"))]))
+        .unwrap();
+    assert_eq!(cancelled["cancelled"]["tool"], "a");
+    let remaining = jobs(&tool);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0]["id"], "job-2");
+
+    let err = tool
+        .run(args(&[("action", json!("cancel")), ("job_id", json!("job-1"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("No scheduled job"), "{}", err);
+}
+
+#[test]
+fn executor_failures_are_recorded_and_job_stays_scheduled() {
+    let tool = CronScheduleTool::new()
+        .with_store_path(store("failure"))
+        .with_executor(|_, _| anyhow::bail!("crew unavailable"));
+    tool.run(args(&[("schedule", json!("@daily")), ("tool", json!("report"))]))
+        .unwrap();
+
+    let reports = tool
+        .run_due_at(SystemTime::now() + Duration::from_secs(2 * 86400))
+        .unwrap();
+    assert_eq!(reports[0]["ok"], false);
+    assert_eq!(reports[0]["error"], "crew unavailable");
+    let job = &jobs(&tool)[0];
+    assert_eq!(job["last_error"], "crew unavailable");
+    assert_eq!(job["runs"], 1);
+}
+
+#[test]
+fn rejects_bad_schedules() {
+    let (tool, _) = recording_tool(store("invalid"));
+    for (schedule, expected) in [
+        ("tomorrow", "expected 5 fields"),
+        ("61 * * * *", "not a number from 0 to 59"),
+        ("0 0 31 2 *", "never fires"),
+    ] {
+        let err = tool
+            .run(args(&[("schedule", json!(schedule)), ("tool", json!("poll"))]))
+            .unwrap_err();
+        assert!(err.to_string().contains(expected), "{}: {}", schedule, err);
+    }
+    assert!(jobs(&tool).is_empty());
+}
+
+#[test]
+fn run_due_requires_an_executor() {
+    let tool = CronScheduleTool::new().with_store_path(store("no-executor"));
+    let err = tool.run_due().unwrap_err();
+    assert!(err.to_string().contains("with_executor"), "{}", err);
+}
@@ -0,0 +1,97 @@
+//! CsvLoader row documents, modes and ragged input.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{BaseLoader, CsvLoadMode, CsvLoader, MetadataCondition};
+use serde_json::json;
+
+/// A temp CSV file holding `contents`.
+fn csv_file(name: &str, contents: &str) -> String {
+    let path = std::env::temp_dir().join(format!("crewai-csv-{}-{}.csv", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+#[test]
+fn one_document_per_row_with_column_metadata() {
+    let path = csv_file("rows", "name,team,goals\nAda,Red,12\nGrace,Blue,9\n");
+
+    let documents = CsvLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "name: Ada\nteam: Red\ngoals: 12");
+    let metadata = &documents[1].metadata;
+    assert_eq!(metadata["source"], json!(path));
+    assert_eq!(metadata["row_index"], json!(1));
+    assert_eq!(metadata["name"], json!("Grace"));
+    assert_eq!(metadata["goals"], json!("9"));
+    assert!(MetadataCondition::equals("team", json!("Blue")).matches(metadata));
+}
+
+#[test]
+fn content_column_picks_the_text() {
+    let path = csv_file("content", "id,body\n1,First note\n2,Second note\n");
+
+    let documents = CsvLoader::new(&path).with_content_column("body").load().unwrap();
+
+    assert_eq!(documents[0].content, "First note");
+    assert_eq!(documents[0].metadata["id"], json!("1"));
+
+    let err = CsvLoader::new(&path).with_content_column("text").load().unwrap_err();
+    assert!(err.to_string().contains("unknown content column 'text'"), "{}", err);
+}
+
+#[test]
+fn combined_mode_emits_one_document() {
+    let path = csv_file("combined", "city,country\nLyon,France\nKyoto,Japan\n");
+
+    let documents = CsvLoader::new(&path).with_mode(CsvLoadMode::Combined).load().unwrap();
+
+    assert_eq!(documents.len(), 1);
+    assert_eq!(
+        documents[0].content,
+        "city: Lyon\ncountry: France\n\ncity: Kyoto\ncountry: Japan"
+    );
+    assert_eq!(documents[0].metadata["row_count"], json!(2));
+    assert_eq!(documents[0].metadata["columns"], json!(["city", "country"]));
+}
+
+#[test]
+fn headerless_files_get_numbered_columns() {
+    let path = csv_file("headerless", "1;3.5\n2;4.0\n");
+
+    let documents = CsvLoader::new(&path).with_delimiter(b';').load().unwrap();
+
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "column_1: 1\ncolumn_2: 3.5");
+    assert_eq!(documents[0].metadata["row_index"], json!(0));
+
+    let forced = CsvLoader::new(&path)
+        .with_delimiter(b';')
+        .with_has_header(true)
+        .load()
+        .unwrap();
+    assert_eq!(forced.len(), 1);
+    assert_eq!(forced[0].content, "1: 2\n3.5: 4.0");
+}
+
+#[test]
+fn ragged_rows_are_kept() {
+    let path = csv_file("ragged", "a,b\n1\n2,3,4\n");
+
+    let documents = CsvLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "a: 1");
+    assert!(!documents[0].metadata.contains_key("b"));
+    assert_eq!(documents[1].content, "a: 2\nb: 3\ncolumn_3: 4");
+}
+
+#[test]
+fn empty_and_missing_files() {
+    let path = csv_file("empty", "");
+    assert!(CsvLoader::new(&path).load().unwrap().is_empty());
+
+    let err = CsvLoader::new("/nonexistent/data.csv").load().unwrap_err();
+    assert!(err.to_string().contains("Failed to open CSV file"), "{}", err);
+}
@@ -0,0 +1,72 @@
+//! CsvSearchTool header detection, query validation and ragged rows.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::args;
+use crewai_tools::CsvSearchTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+/// Search `contents` for `query`, returning the tool's JSON output.
+fn search(contents: &str, query: &str) -> Result<Value, anyhow::Error> {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("data.csv");
+    std::fs::write(&path, contents).unwrap();
+    CsvSearchTool::new()
+        .with_file_path(path.to_string_lossy())
+        .run(args(&[("search_query", json!(query))]))
+}
+
+#[test]
+fn a_textual_first_row_is_the_header() {
+    let result = search("name,city\nAda,London\nGrace,Arlington\n", "london").unwrap();
+
+    assert_eq!(result["columns"], json!(["name", "city"]));
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["row_number"], 2);
+    assert_eq!(result["results"][0]["matched_columns"], json!(["city"]));
+    assert_eq!(result["results"][0]["row"], json!({"name": "Ada", "city": "London"}));
+}
+
+#[test]
+fn numeric_empty_or_repeated_first_rows_are_data() {
+    for contents in ["id,42\nx,43\n", "name,\nAda,London\n", "a,a\nAda,London\n"] {
+        let result = search(contents, "4").unwrap_or_else(|e| panic!("{}: {}", contents, e));
+        assert_eq!(result["columns"], json!(["column_1", "column_2"]), "{}", contents);
+    }
+
+    let result = search("Ada,1815\nGrace,1906\n", "ada").unwrap();
+    assert_eq!(result["results"][0]["row_number"], 1);
+    assert_eq!(result["results"][0]["row"], json!({"column_1": "Ada", "column_2": "1815"}));
+}
+
+#[test]
+fn empty_search_query_is_rejected() {
+    for query in ["", "   "] {
+        let err = search("name\nAda\n", query).unwrap_err();
+        assert!(err.to_string().contains("search_query must not be empty"), "{}", err);
+    }
+}
+
+#[test]
+fn extra_fields_in_ragged_rows_are_kept_and_searched() {
+    let result = search("name,city\nAda,London,mathematician\nGrace,Arlington\n", "math").unwrap();
+
+    assert_eq!(result["ragged_rows"], 1);
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["matched_columns"], json!(["column_3"]));
+    assert_eq!(
+        result["results"][0]["row"],
+        json!({"name": "Ada", "city": "London", "column_3": "mathematician"})
+    );
+}
+
+#[test]
+fn short_rows_omit_the_missing_columns() {
+    let result = search("name,city\nAda\n", "ada").unwrap();
+
+    assert_eq!(result["ragged_rows"], 0);
+    assert_eq!(result["results"][0]["row"], json!({"name": "Ada"}));
+}
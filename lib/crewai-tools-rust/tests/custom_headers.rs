@@ -0,0 +1,64 @@
+//! Custom headers and cookies on the native scraping tools.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::recording_mock_server;
+use crewai_tools::ScrapeWebsiteTool;
+use serde_json::{json, Value};
+
+fn tool() -> ScrapeWebsiteTool {
+    ScrapeWebsiteTool::new()
+        .with_ignore_robots(true)
+        .with_headers(HashMap::from([
+            ("X-Team".to_string(), "data".to_string()),
+            ("Authorization".to_string(), "Bearer s3cret".to_string()),
+        ]))
+        .with_cookies(vec![("session".to_string(), "abc123".to_string())])
+}
+
+#[test]
+fn configured_and_runtime_headers_reach_the_server() {
+    let (url, _, requests) = recording_mock_server(vec![("200 OK", "", "{}")]);
+    let args: HashMap<String, Value> = HashMap::from([
+        ("website_url".to_string(), Value::from(url)),
+        ("headers".to_string(), json!({ "x-team": "platform" })),
+        ("cookies".to_string(), json!([["theme", "dark"]])),
+    ]);
+
+    // The mock serves JSON, which the tool refuses to scrape; the request
+    // itself is what matters here.
+    let err = tool().run(args).unwrap_err();
+    assert!(!err.to_string().contains("s3cret"));
+
+    let requests = requests.lock().unwrap();
+    let head = &requests[0];
+    assert!(head.contains("x-team: platform\r\n"), "{}", head);
+    assert!(!head.contains("x-team: data"));
+    assert!(head.contains("authorization: bearer s3cret\r\n"));
+    assert!(head.contains("cookie: session=abc123; theme=dark\r\n"));
+}
+
+#[test]
+fn debug_output_redacts_secrets() {
+    let debug = format!("{:?}", tool());
+    assert!(debug.contains("X-Team"));
+    assert!(debug.contains("session"));
+    assert!(!debug.contains("s3cret"));
+    assert!(!debug.contains("abc123"));
+}
+
+#[test]
+fn malformed_header_errors_name_only_the_header() {
+    let bad = ScrapeWebsiteTool::new().with_ignore_robots(true).with_headers(HashMap::from([(
+        "X-Token".to_string(),
+        "line\nbreak-s3cret".to_string(),
+    )]));
+    let args = HashMap::from([("website_url".to_string(), Value::from("http://127.0.0.1:9/"))]);
+    let err = bad.run(args).unwrap_err().to_string();
+    assert!(err.contains("X-Token"));
+    assert!(!err.contains("s3cret"));
+}
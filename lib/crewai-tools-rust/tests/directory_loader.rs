@@ -0,0 +1,115 @@
+//! DirectoryLoader dispatch, filtering and per-file error collection.
+
+#![cfg(feature = "rag")]
+
+use std::path::{Path, PathBuf};
+
+use crewai_tools::{BaseLoader, DirectoryLoader};
+use serde_json::json;
+
+/// A fresh temp directory holding `files` (relative path, contents).
+fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("crewai-dirload-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    for (path, contents) in files {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+    root
+}
+
+fn sources(documents: &[crewai_tools::Document], root: &Path) -> Vec<String> {
+    documents
+        .iter()
+        .map(|d| {
+            let source = d.metadata["source"].as_str().unwrap();
+            Path::new(source).strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/")
+        })
+        .collect()
+}
+
+#[test]
+fn dispatches_by_extension_and_skips_hidden_and_ignored() {
+    let root = tree(
+        "dispatch",
+        &[
+            ("notes.txt", "plain notes"),
+            ("people.csv", "name,role\nAda,engineer\n"),
+            ("feed/news.xml", "<catalog><item>x</item></catalog>"),
+            ("image.bin", "\u{0}\u{1}"),
+            (".env", "SECRET=1"),
+            (".cache/cached.txt", "stale"),
+            ("target/debug/out.txt", "build output"),
+            (".git/HEAD", "ref: refs/heads/main"),
+        ],
+    );
+
+    let report = DirectoryLoader::new(root.to_string_lossy()).load_report().unwrap();
+
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    assert_eq!(sources(&report.documents, &root), ["notes.txt", "people.csv", "feed/news.xml"]);
+    assert_eq!(report.documents[1].metadata["role"], json!("engineer"));
+}
+
+#[test]
+fn recursion_extensions_and_ignore_list_are_configurable() {
+    let root = tree(
+        "config",
+        &[("a.txt", "top"), ("b.md", "# md"), ("sub/c.txt", "nested"), ("target/d.txt", "kept")],
+    );
+    let dir = root.to_string_lossy().into_owned();
+
+    let flat = DirectoryLoader::new(&dir).with_recursive(false).load().unwrap();
+    assert_eq!(sources(&flat, &root), ["a.txt", "b.md"]);
+
+    let txt_only = DirectoryLoader::new(&dir)
+        .with_extensions(vec![".TXT".to_string()])
+        .with_ignore(Vec::new())
+        .load()
+        .unwrap();
+    assert_eq!(sources(&txt_only, &root), ["a.txt", "sub/c.txt", "target/d.txt"]);
+
+    let hidden = tree("hidden", &[(".notes.txt", "hidden")]);
+    let loaded = DirectoryLoader::new(hidden.to_string_lossy())
+        .with_include_hidden(true)
+        .load()
+        .unwrap();
+    assert_eq!(loaded.len(), 1);
+}
+
+#[test]
+fn failing_files_are_collected_not_fatal() {
+    let root = tree("errors", &[("good.txt", "fine"), ("broken.pdf", "not a pdf")]);
+    let dir = root.to_string_lossy().into_owned();
+
+    let report = DirectoryLoader::new(&dir).load_report().unwrap();
+    assert_eq!(sources(&report.documents, &root), ["good.txt"]);
+    assert_eq!(report.errors.len(), 1);
+    assert!(report.errors[0].path.ends_with("broken.pdf"), "{:?}", report.errors);
+
+    let documents = DirectoryLoader::new(&dir).load().unwrap();
+    assert_eq!(documents.len(), 1);
+
+    let err = DirectoryLoader::new(&dir).with_strict(true).load().unwrap_err();
+    assert!(err.to_string().contains("1 file(s) failed to load"), "{}", err);
+    assert!(err.to_string().contains("broken.pdf"), "{}", err);
+}
+
+#[test]
+fn requested_extensions_without_a_loader_are_reported() {
+    let root = tree("noloader", &[("page.html", "<p>hi</p>")]);
+
+    let report = DirectoryLoader::new(root.to_string_lossy())
+        .with_extensions(vec!["html".to_string()])
+        .load_report()
+        .unwrap();
+
+    assert_eq!(report.errors[0].error, "no loader for .html files");
+}
+
+#[test]
+fn missing_directory_is_an_error() {
+    let err = DirectoryLoader::new("/nonexistent/corpus").load().unwrap_err();
+    assert!(err.to_string().contains("is not a directory"), "{}", err);
+}
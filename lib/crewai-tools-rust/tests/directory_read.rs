@@ -0,0 +1,263 @@
+//! DirectoryReadTool listings: recursion, globs, ignores and the entry cap;
+//! and `summarize` statistics over a generated tree.
+
+#![cfg(feature = "file_ops")]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+use crewai_tools::DirectoryReadTool;
+use serde_json::{json, Value};
+
+/// A small project tree:
+///
+/// ```text
+/// Cargo.toml
+/// node_modules/pkg/index.js
+/// src/lib.rs
+/// src/util/mod.rs
+/// .git/HEAD
+/// ```
+fn project(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("crewai-dirread-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    for dir in ["src/util", "node_modules/pkg", ".git"] {
+        std::fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    std::fs::write(root.join("Cargo.toml"), "[package]\n").unwrap();
+    std::fs::write(root.join("src/lib.rs"), "mod util;\n").unwrap();
+    std::fs::write(root.join("src/util/mod.rs"), "").unwrap();
+    std::fs::write(root.join("node_modules/pkg/index.js"), "").unwrap();
+    std::fs::write(root.join(".git/HEAD"), "ref").unwrap();
+    root
+}
+
+fn list(root: &Path, options: &[(&str, Value)]) -> Value {
+    let mut args: HashMap<String, Value> = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    args.insert("directory".into(), json!(root.to_string_lossy()));
+    DirectoryReadTool::new().run(args).unwrap()
+}
+
+fn paths(result: &Value) -> Vec<&str> {
+    result["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["path"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn top_level_listing_is_sorted_with_metadata() {
+    let root = project("top");
+
+    let result = list(&root, &[]);
+
+    assert_eq!(paths(&result), ["Cargo.toml", "src"]);
+    assert_eq!(result["entries"][0]["is_dir"], false);
+    assert_eq!(result["entries"][0]["size"], 10);
+    assert!(result["entries"][0]["modified"].as_u64().is_some());
+    assert_eq!(result["entries"][1]["is_dir"], true);
+    assert_eq!(result["truncated"], false);
+}
+
+#[test]
+fn recursive_listing_skips_default_ignores() {
+    let root = project("recursive");
+
+    let result = list(&root, &[("recursive", json!(true))]);
+
+    assert_eq!(
+        paths(&result),
+        ["Cargo.toml", "src", "src/lib.rs", "src/util", "src/util/mod.rs"]
+    );
+}
+
+#[test]
+fn glob_with_double_star_recurses() {
+    let root = project("glob");
+
+    let result = list(&root, &[("glob", json!("**/*.rs"))]);
+
+    assert_eq!(paths(&result), ["src/lib.rs", "src/util/mod.rs"]);
+}
+
+#[test]
+fn ignore_patterns_can_be_overridden() {
+    let root = project("ignore");
+
+    let result = list(
+        &root,
+        &[("recursive", json!(true)), ("ignore", json!(["src"])), ("glob", json!("*.js"))],
+    );
+
+    assert_eq!(paths(&result), ["node_modules/pkg/index.js"]);
+}
+
+#[test]
+fn max_entries_truncates() {
+    let root = project("cap");
+
+    let result = list(&root, &[("recursive", json!(true)), ("max_entries", json!(2))]);
+
+    assert_eq!(paths(&result), ["Cargo.toml", "src"]);
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["truncated"], true);
+}
+
+/// Modification time given to generated files unless stated otherwise.
+const OLD: u64 = 1_600_000_000;
+
+fn file(root: &Path, relative: &str, size: usize, modified: u64) {
+    let path = root.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "x".repeat(size)).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(UNIX_EPOCH + Duration::from_secs(modified))
+        .unwrap();
+}
+
+/// A generated tree of known composition:
+///
+/// ```text
+/// README                       10 bytes
+/// big.bin                    5000 bytes
+/// d{0,1,2}/mod.toml            20 bytes each
+/// d{a}/s{b}/{a,b}.rs          100 bytes each (12 files)
+/// d{a}/s{b}/notes.md           50 bytes each (6 files)
+/// d{a}/s{b}/debug.log          ignored by .gitignore
+/// d{a}/s{b}/leaf/data.json    200, 210, .. 250 bytes
+/// target/, .cache/             ignored
+/// ```
+///
+/// Directories: 3 at depth 1, 6 at depth 2, 6 at depth 3. `README` and
+/// `d2/s1/notes.md` are the most recently modified files.
+fn generated_tree(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("crewai-dirsummary-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join(".gitignore"), "target/\n*.log\n").unwrap();
+    file(&root, "README", 10, OLD + 100);
+    file(&root, "big.bin", 5000, OLD);
+    file(&root, "target/debug/app", 9000, OLD);
+    file(&root, ".cache/blob.bin", 9000, OLD);
+    for a in 0..3 {
+        file(&root, &format!("d{}/mod.toml", a), 20, OLD);
+        for b in 0..2 {
+            let dir = format!("d{}/s{}", a, b);
+            file(&root, &format!("{}/a.rs", dir), 100, OLD);
+            file(&root, &format!("{}/b.rs", dir), 100, OLD);
+            let notes_modified = if (a, b) == (2, 1) { OLD + 200 } else { OLD };
+            file(&root, &format!("{}/notes.md", dir), 50, notes_modified);
+            file(&root, &format!("{}/debug.log", dir), 700, OLD);
+            file(&root, &format!("{}/leaf/data.json", dir), 200 + (a * 2 + b) * 10, OLD);
+        }
+    }
+    root
+}
+
+fn summarize(tool: DirectoryReadTool, root: &Path, options: &[(&str, Value)]) -> Value {
+    let mut args: HashMap<String, Value> = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    args.insert("directory".into(), json!(root.to_string_lossy()));
+    args.insert("mode".into(), json!("summarize"));
+    tool.run(args).unwrap()
+}
+
+#[test]
+fn summary_aggregates_the_generated_tree() {
+    let root = generated_tree("full");
+
+    let result = summarize(DirectoryReadTool::new().with_top_n(3), &root, &[]);
+
+    assert_eq!(result["mode"], "summarize");
+    assert!(result.get("entries").is_none());
+    assert_eq!(result["files"], 29);
+    assert_eq!(result["total_bytes"], 7920);
+    assert_eq!(
+        result["by_extension"],
+        json!([
+            {"extension": "bin", "files": 1, "bytes": 5000},
+            {"extension": "json", "files": 6, "bytes": 1350},
+            {"extension": "rs", "files": 12, "bytes": 1200},
+            {"extension": "md", "files": 6, "bytes": 300},
+            {"extension": "toml", "files": 3, "bytes": 60},
+            {"extension": "(none)", "files": 1, "bytes": 10},
+        ])
+    );
+    assert_eq!(
+        result["largest_files"],
+        json!([
+            {"path": "big.bin", "bytes": 5000},
+            {"path": "d2/s1/leaf/data.json", "bytes": 250},
+            {"path": "d2/s0/leaf/data.json", "bytes": 240},
+        ])
+    );
+    assert_eq!(result["recently_modified"][0], json!({"path": "d2/s1/notes.md", "modified": OLD + 200}));
+    assert_eq!(result["recently_modified"][1], json!({"path": "README", "modified": OLD + 100}));
+    assert_eq!(result["recently_modified"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        result["directories_per_depth"],
+        json!([
+            {"depth": 1, "directories": 3},
+            {"depth": 2, "directories": 6},
+            {"depth": 3, "directories": 6},
+        ])
+    );
+    assert_eq!(result["directories"], 15);
+    assert_eq!(result["partial"], false);
+    assert_eq!(result["stop_reason"], Value::Null);
+}
+
+#[test]
+fn summary_respects_the_depth_limit() {
+    let root = generated_tree("depth");
+
+    let result = summarize(DirectoryReadTool::new(), &root, &[("max_depth", json!(1))]);
+
+    // Root files plus d*/mod.toml; depth-2 directories are counted but not entered.
+    assert_eq!(result["files"], 5);
+    assert_eq!(result["total_bytes"], 5070);
+    assert_eq!(
+        result["directories_per_depth"],
+        json!([{"depth": 1, "directories": 3}, {"depth": 2, "directories": 6}])
+    );
+    assert_eq!(result["partial"], false);
+}
+
+#[test]
+fn summary_can_include_ignored_entries() {
+    let root = generated_tree("unignored");
+
+    let result = summarize(DirectoryReadTool::new().with_respect_ignore(false), &root, &[]);
+
+    // .gitignore, target/debug/app, .cache/blob.bin and six debug.log files.
+    assert_eq!(result["files"], 29 + 9);
+    assert_eq!(result["largest_files"][0]["bytes"], 9000);
+}
+
+#[test]
+fn summary_stops_at_the_file_budget_and_reports_partial() {
+    let root = generated_tree("budget");
+
+    let result = summarize(DirectoryReadTool::new().with_max_files(10), &root, &[]);
+
+    assert_eq!(result["files"], 10);
+    assert_eq!(result["partial"], true);
+    assert_eq!(result["stop_reason"], "max_files");
+
+    let result = summarize(DirectoryReadTool::new().with_time_budget_ms(0), &root, &[]);
+
+    assert_eq!(result["files"], 0);
+    assert_eq!(result["partial"], true);
+    assert_eq!(result["stop_reason"], "time_budget");
+}
@@ -0,0 +1,126 @@
+//! DocxLoader Markdown rendering, heading splits and image metadata.
+
+#![cfg(feature = "rag")]
+
+use std::io::Write;
+
+use crewai_tools::{BaseLoader, DocxLoader};
+use serde_json::json;
+
+const BODY: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+    xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+    xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+  <w:body>
+    <w:p><w:pPr><w:pStyle w:val="Title"/></w:pPr><w:r><w:t>Field Guide</w:t></w:r></w:p>
+    <w:p><w:r><w:t>Read this first.</w:t></w:r></w:p>
+    <w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>Birds</w:t></w:r></w:p>
+    <w:p><w:r><w:t>Common species:</w:t></w:r></w:p>
+    <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/></w:numPr></w:pPr><w:r><w:t>Robin</w:t></w:r></w:p>
+    <w:p><w:r><w:drawing><a:graphic><a:graphicData>
+      <a:blip r:embed="rId7"/>
+    </a:graphicData></a:graphic></w:drawing></w:r></w:p>
+    <w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr><w:r><w:t>Sizes</w:t></w:r></w:p>
+    <w:tbl>
+      <w:tr>
+        <w:tc><w:p><w:r><w:t>Bird</w:t></w:r></w:p></w:tc>
+        <w:tc><w:p><w:r><w:t>Length | cm</w:t></w:r></w:p></w:tc>
+      </w:tr>
+      <w:tr>
+        <w:tc><w:p><w:r><w:t>Robin</w:t></w:r></w:p></w:tc>
+        <w:tc><w:p><w:r><w:t>14</w:t></w:r></w:p></w:tc>
+      </w:tr>
+      <w:tr>
+        <w:tc>
+          <w:tcPr><w:gridSpan w:val="2"/></w:tcPr>
+          <w:p><w:r><w:t>Average</w:t></w:r></w:p>
+        </w:tc>
+      </w:tr>
+    </w:tbl>
+    <w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>Trees</w:t></w:r></w:p>
+    <w:sdt><w:sdtContent>
+      <w:p><w:r><w:t>Oak and ash.</w:t></w:r></w:p>
+    </w:sdtContent></w:sdt>
+    <w:sectPr/>
+  </w:body>
+</w:document>"#;
+
+const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId7"
+      Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image"
+      Target="media/image1.png"/>
+</Relationships>"#;
+
+/// A temp `.docx` holding `entries`.
+fn docx_file(name: &str, entries: &[(&str, &str)]) -> String {
+    let path =
+        std::env::temp_dir().join(format!("crewai-docx-{}-{}.docx", name, std::process::id()));
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+    for (entry, data) in entries {
+        zip.start_file(*entry, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(data.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn guide() -> String {
+    docx_file("guide", &[("word/document.xml", BODY), ("word/_rels/document.xml.rels", RELS)])
+}
+
+#[test]
+fn whole_file_renders_markdown() {
+    let path = guide();
+
+    let documents = DocxLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 1);
+    let document = &documents[0];
+    assert_eq!(
+        document.content,
+        "# Field Guide\n\nRead this first.\n\n# Birds\n\nCommon species:\n\n- Robin\n\n\
+         ## Sizes\n\n| Bird | Length \\| cm |\n| --- | --- |\n| Robin | 14 |\n| Average |  |\n\n\
+         # Trees\n\nOak and ash."
+    );
+    assert_eq!(document.metadata["source"], json!(path));
+    assert_eq!(
+        document.metadata["headings"],
+        json!(["Field Guide", "Birds", "Sizes", "Trees"])
+    );
+    assert_eq!(document.metadata["image_count"], json!(1));
+    assert_eq!(document.metadata["images"], json!(["image1.png"]));
+}
+
+#[test]
+fn split_by_heading_yields_one_document_per_top_level_heading() {
+    let path = guide();
+
+    let documents = DocxLoader::new(&path).with_split_by_heading(true).load().unwrap();
+
+    assert_eq!(documents.len(), 3);
+    assert_eq!(documents[0].content, "# Field Guide\n\nRead this first.");
+    assert!(!documents[0].metadata.contains_key("heading"));
+
+    let birds = &documents[1];
+    assert_eq!(birds.metadata["heading"], json!("Birds"));
+    assert_eq!(birds.metadata["heading_level"], json!(1));
+    assert!(birds.content.starts_with("# Birds\n\n"), "{}", birds.content);
+    assert!(birds.content.contains("| Robin | 14 |"), "{}", birds.content);
+    assert_eq!(birds.metadata["images"], json!(["image1.png"]));
+
+    let trees = &documents[2];
+    assert_eq!(trees.metadata["heading"], json!("Trees"));
+    assert_eq!(trees.content, "# Trees\n\nOak and ash.");
+    assert_eq!(trees.metadata["image_count"], json!(0));
+}
+
+#[test]
+fn rejects_files_that_are_not_docx() {
+    let path = docx_file("notdocx", &[("readme.txt", "hello")]);
+    let err = DocxLoader::new(&path).load().unwrap_err();
+    assert!(err.to_string().contains("missing word/document.xml"), "{}", err);
+
+    let err = DocxLoader::new("/nonexistent/report.docx").load().unwrap_err();
+    assert!(err.to_string().contains("Failed to open file"), "{}", err);
+}
@@ -0,0 +1,121 @@
+//! DocxSearchTool paragraph and table-cell extraction and matching.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::io::Write;
+
+use common::args;
+use crewai_tools::DocxSearchTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+const BODY: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t>Quarterly report.</w:t></w:r></w:p>
+    <w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>Revenue</w:t></w:r></w:p>
+    <w:p><w:r><w:t xml:space="preserve">Revenue grew </w:t></w:r><w:r><w:t>in every region.</w:t></w:r></w:p>
+    <w:p/>
+    <w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr><w:r><w:t>By region</w:t></w:r></w:p>
+    <w:tbl>
+      <w:tr>
+        <w:tc><w:p><w:r><w:t>Region</w:t></w:r></w:p></w:tc>
+        <w:tc><w:p><w:r><w:t>Growth</w:t></w:r></w:p></w:tc>
+      </w:tr>
+      <w:tr>
+        <w:tc><w:p><w:r><w:t>North</w:t></w:r></w:p><w:p><w:r><w:t>incl. islands</w:t></w:r></w:p></w:tc>
+        <w:tc><w:p/></w:tc>
+      </w:tr>
+    </w:tbl>
+    <w:sectPr/>
+  </w:body>
+</w:document>"#;
+
+/// Write a `.docx` holding `BODY` into `dir`.
+fn report(dir: &TempDir) -> String {
+    let path = dir.path().join("report.docx");
+    let mut zip = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+    zip.start_file("word/document.xml", zip::write::SimpleFileOptions::default()).unwrap();
+    zip.write_all(BODY.as_bytes()).unwrap();
+    zip.finish().unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn search(tool: DocxSearchTool, query: &str) -> Value {
+    let dir = TempDir::new().unwrap();
+    let path = report(&dir);
+    tool.with_file_path(path).run(args(&[("search_query", json!(query))])).unwrap()
+}
+
+#[test]
+fn paragraphs_and_cells_are_extracted_in_document_order() {
+    // A query every non-empty block contains.
+    let result = search(DocxSearchTool::new(), "");
+
+    assert_eq!(result["paragraphs"], 7);
+    let blocks: Vec<(&str, &str)> = result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| (b["kind"].as_str().unwrap(), b["text"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        blocks,
+        [
+            ("paragraph", "Quarterly report."),
+            ("heading", "Revenue"),
+            ("paragraph", "Revenue grew in every region."),
+            ("heading", "By region"),
+            ("table_cell", "Region"),
+            ("table_cell", "Growth"),
+            ("table_cell", "North\nincl. islands"),
+        ]
+    );
+}
+
+#[test]
+fn matches_carry_their_nearest_heading() {
+    let result = search(DocxSearchTool::new(), "REGION");
+
+    let hits: Vec<(u64, Value, Value)> = result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| (b["index"].as_u64().unwrap(), b["heading"].clone(), b["heading_level"].clone()))
+        .collect();
+    assert_eq!(
+        hits,
+        [
+            (2, json!("Revenue"), json!(1)),
+            (3, json!("By region"), json!(2)),
+            (4, json!("By region"), json!(2)),
+        ]
+    );
+    assert_eq!(search(DocxSearchTool::new(), "report")["results"][0]["heading"], Value::Null);
+}
+
+#[test]
+fn matches_in_multi_paragraph_cells_and_truncation() {
+    let result = search(DocxSearchTool::new(), "islands");
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["kind"], "table_cell");
+
+    let result = search(DocxSearchTool::new().with_max_results(1), "region");
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["truncated"], true);
+}
+
+#[test]
+fn non_docx_files_are_rejected() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("plain.docx");
+    std::fs::write(&path, "not a zip").unwrap();
+
+    let result = DocxSearchTool::new()
+        .with_file_path(path.to_string_lossy())
+        .run(args(&[("search_query", json!("x"))]));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,84 @@
+//! EmbeddingTool over a deterministic in-process embedder.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use std::sync::Arc;
+
+use common::args;
+use crewai_tools::{EmbeddingService, EmbeddingTool};
+use serde_json::json;
+
+/// Embeds a text as its counts of the letters a, b and c.
+struct LetterCounts;
+
+impl EmbeddingService for LetterCounts {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        Ok(['a', 'b', 'c']
+            .iter()
+            .map(|l| text.chars().filter(|c| c == l).count() as f32)
+            .collect())
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        texts.iter().map(|t| self.embed(t)).collect()
+    }
+
+    fn model_name(&self) -> &str {
+        "letter-counts"
+    }
+
+    fn dimensions(&self) -> usize {
+        3
+    }
+}
+
+fn tool() -> EmbeddingTool {
+    EmbeddingTool::new().with_embedder(Arc::new(LetterCounts))
+}
+
+#[test]
+fn texts_are_embedded_with_model_and_dimensions() {
+    let result = tool().run(args(&[("texts", json!(["abc", "aab"]))])).unwrap();
+
+    assert_eq!(
+        result,
+        json!({
+            "model": "letter-counts",
+            "dimensions": 3,
+            "vectors": [[1.0, 1.0, 1.0], [2.0, 1.0, 0.0]],
+        })
+    );
+}
+
+#[test]
+fn similarity_mode_returns_only_the_cosine() {
+    let same = tool()
+        .run(args(&[
+            ("mode", json!("similarity")),
+            ("text_a", json!("ab")),
+            ("text_b", json!("aabb")),
+        ]))
+        .unwrap();
+    assert!((same["similarity"].as_f64().unwrap() - 1.0).abs() < 1e-6);
+    assert!(same.get("vectors").is_none());
+
+    let orthogonal = tool()
+        .run(args(&[("mode", json!("similarity")), ("texts", json!(["aa", "cc"]))]))
+        .unwrap();
+    assert_eq!(orthogonal["similarity"], 0.0);
+}
+
+#[test]
+fn missing_embedder_and_bad_arguments_are_errors() {
+    let err = EmbeddingTool::new()
+        .run(args(&[("text", json!("a"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("with_embedder"), "{}", err);
+
+    let err = tool()
+        .run(args(&[("mode", json!("similarity")), ("texts", json!(["a"]))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("two texts"), "{}", err);
+}
@@ -0,0 +1,223 @@
+//! EnterpriseActionTool argument validation and response drift detection
+//! against fixture responses that no longer match their schema.
+
+mod common;
+
+use std::collections::HashMap;
+
+use crewai_tools::EnterpriseActionTool;
+use serde_json::{json, Value};
+
+/// An action definition as served by the enterprise schema endpoint.
+fn definition() -> Value {
+    json!({
+        "name": "crm_list_contacts",
+        "parameters": {
+            "type": "object",
+            "properties": {
+                "limit": {"type": "integer"},
+                "segment": {"type": "string", "enum": ["all", "vip"]}
+            },
+            "required": ["segment"],
+            "additionalProperties": false
+        },
+        "response": {
+            "type": "object",
+            "properties": {
+                "contacts": {"type": "array", "items": {"$ref": "#/definitions/contact"}},
+                "total": {"type": "integer"},
+                "next_cursor": {"type": "string", "nullable": true},
+                "region": {"type": "string", "enum": ["eu", "us"]}
+            },
+            "required": ["contacts", "total"],
+            "definitions": {
+                "contact": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "integer"},
+                        "name": {"type": "string"},
+                        "score": {"type": "number"},
+                        "active": {"type": "boolean"},
+                        "tags": {"type": "array", "items": {"type": "string"}}
+                    },
+                    "required": ["id", "name"]
+                }
+            }
+        }
+    })
+}
+
+/// A response from after the API drifted: numbers and booleans arrive as
+/// strings, `tags` is a bare string, `name` is gone and `region` has a new
+/// value.
+const DRIFTED: &str = r#"{
+    "contacts": [
+        {"id": "42", "score": "7.5", "active": "true", "tags": "vip"},
+        {"id": 43, "name": "Ada", "score": "n/a", "active": false, "tags": ["new"]}
+    ],
+    "total": "2",
+    "next_cursor": null,
+    "region": "apac"
+}"#;
+
+fn tool() -> EnterpriseActionTool {
+    EnterpriseActionTool::new("crm_list_contacts").with_action_schema(&definition())
+}
+
+fn paths(result: &Value, key: &str) -> Vec<String> {
+    let mut paths: Vec<String> = result[key]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["path"].as_str().unwrap().to_string())
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn drifted_response_is_returned_with_its_violations() {
+    let drifted: Value = serde_json::from_str(DRIFTED).unwrap();
+
+    let result = tool().check_response(drifted.clone());
+
+    assert_eq!(result["data"], drifted);
+    assert_eq!(
+        paths(&result, "schema_violations"),
+        [
+            "/contacts/0/active",
+            "/contacts/0/id",
+            "/contacts/0/name",
+            "/contacts/0/score",
+            "/contacts/0/tags",
+            "/contacts/1/score",
+            "/region",
+            "/total",
+        ]
+    );
+    let messages: HashMap<String, String> = result["schema_violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| (v["path"].as_str().unwrap().to_string(), v["message"].as_str().unwrap().to_string()))
+        .collect();
+    assert_eq!(messages["/contacts/0/id"], "expected integer, found string");
+    assert_eq!(messages["/contacts/0/tags"], "expected array, found string");
+    assert_eq!(messages["/contacts/0/name"], "required property is missing");
+    assert!(messages["/region"].contains("\"apac\" is not one of"), "{}", messages["/region"]);
+    assert_eq!(result["coercions"], json!([]));
+}
+
+#[test]
+fn coercion_fixes_obvious_mismatches_and_reports_the_rest() {
+    let drifted: Value = serde_json::from_str(DRIFTED).unwrap();
+
+    let result = tool().with_coerce(true).check_response(drifted);
+
+    let data = &result["data"];
+    assert_eq!(data["total"], 2);
+    assert_eq!(data["contacts"][0]["id"], 42);
+    assert_eq!(data["contacts"][0]["score"], 7.5);
+    assert_eq!(data["contacts"][0]["active"], true);
+    assert_eq!(data["contacts"][0]["tags"], json!(["vip"]));
+    // Not an obvious number: left alone and still reported.
+    assert_eq!(data["contacts"][1]["score"], "n/a");
+
+    assert_eq!(
+        paths(&result, "coercions"),
+        ["/contacts/0/active", "/contacts/0/id", "/contacts/0/score", "/contacts/0/tags", "/total"]
+    );
+    let total = result["coercions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["path"] == "/total")
+        .unwrap();
+    assert_eq!((&total["from"], &total["to"]), (&json!("2"), &json!(2)));
+
+    assert_eq!(
+        paths(&result, "schema_violations"),
+        ["/contacts/0/name", "/contacts/1/score", "/region"]
+    );
+}
+
+#[test]
+fn matching_response_has_no_violations_or_coercions() {
+    let response = json!({
+        "contacts": [{"id": 1, "name": "Ada", "score": 3, "tags": []}],
+        "total": 1,
+        "next_cursor": null
+    });
+
+    let result = tool().with_coerce(true).check_response(response.clone());
+
+    assert_eq!(result["data"], response);
+    assert_eq!(result["schema_violations"], json!([]));
+    assert_eq!(result["coercions"], json!([]));
+}
+
+#[test]
+fn without_a_response_schema_data_passes_through() {
+    let drifted: Value = serde_json::from_str(DRIFTED).unwrap();
+
+    let result = EnterpriseActionTool::new("crm_list_contacts")
+        .with_coerce(true)
+        .check_response(drifted.clone());
+
+    assert_eq!(result["data"], drifted);
+    assert_eq!(result["schema_violations"], json!([]));
+}
+
+#[test]
+fn invalid_arguments_list_every_violation() {
+    let args = HashMap::from([
+        ("limit".to_string(), json!("ten")),
+        ("segment".to_string(), json!("gold")),
+        ("extra".to_string(), json!(true)),
+    ]);
+
+    let err = tool().validate_args(&args).unwrap_err().to_string();
+
+    assert!(err.starts_with("Invalid arguments for action 'crm_list_contacts'"), "{}", err);
+    assert!(err.contains("/limit: expected integer, found string"), "{}", err);
+    assert!(err.contains("/segment: \"gold\" is not one of"), "{}", err);
+    assert!(err.contains("/extra: property is not allowed by the schema"), "{}", err);
+    assert!(tool().validate_args(&HashMap::from([("segment".to_string(), json!("vip"))])).is_ok());
+}
+
+#[cfg(feature = "reqwest")]
+mod run {
+    use super::*;
+    use crate::common::{body_recording_mock_server, mock_server};
+
+    #[test]
+    fn run_returns_coerced_data_and_remaining_violations() {
+        let (url, bodies) = body_recording_mock_server(vec![("200 OK", "", DRIFTED)]);
+        let tool = tool().with_api_endpoint(url).with_coerce(true);
+
+        let result = tool
+            .run(HashMap::from([("segment".to_string(), json!("vip"))]))
+            .unwrap();
+
+        assert_eq!(result["data"]["total"], 2);
+        assert_eq!(
+            paths(&result, "schema_violations"),
+            ["/contacts/0/name", "/contacts/1/score", "/region"]
+        );
+        let request: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+        assert_eq!(request, json!({"action": "crm_list_contacts", "parameters": {"segment": "vip"}}));
+    }
+
+    #[test]
+    fn invalid_arguments_fail_before_any_request() {
+        let (url, hits) = mock_server(vec![("200 OK", "", DRIFTED)]);
+        let tool = tool().with_api_endpoint(url);
+
+        let err = tool
+            .run(HashMap::from([("segment".to_string(), json!("gold"))]))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("/segment"), "{}", err);
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,303 @@
+//! Feature-flag smoke tests.
+//!
+//! Each module below compiles only when its feature is enabled and checks
+//! that the feature's re-exports are reachable from the crate root and
+//! constructible. `every_feature_builds_without_warnings_in_isolation`
+//! runs `cargo check --all-targets` for each feature on its own, so code
+//! only one feature uses can't leave dead-code or unused-import warnings
+//! behind in the others. It is `#[ignore]`d as it rebuilds the crate once
+//! per feature; run it with `cargo test --test features -- --ignored`. It
+//! builds into `target/feature-matrix` to stay clear of the build lock held
+//! by the outer `cargo test`.
+
+use serde::Serialize;
+
+/// Serialize a tool and check it produced a JSON object.
+#[allow(dead_code)]
+fn assert_tool<T: Serialize + std::fmt::Debug>(tool: T) {
+    let value = serde_json::to_value(&tool).expect("tool should serialize");
+    assert!(value.is_object(), "{:?} did not serialize to an object", tool);
+}
+
+/// Every feature flag declared in Cargo.toml, except the `all` umbrella.
+const FEATURES: &[&str] = &[
+    "search",
+    "web_scraping",
+    "database",
+    "file_ops",
+    "ai_ml",
+    "automation",
+    "cloud_storage",
+    "browser",
+    "rag",
+];
+
+#[test]
+fn feature_list_matches_manifest() {
+    let manifest = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")).unwrap();
+    let features = manifest
+        .split("[features]")
+        .nth(1)
+        .expect("manifest has a [features] table");
+    for line in features.lines().skip(1) {
+        if line.starts_with('[') {
+            break;
+        }
+        let Some((name, _)) = line.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        if name == "default" || name == "all" || name.is_empty() {
+            continue;
+        }
+        assert!(FEATURES.contains(&name), "feature '{}' is missing from the test matrix", name);
+    }
+}
+
+#[test]
+#[ignore = "rebuilds the crate once per feature"]
+fn every_feature_builds_without_warnings_in_isolation() {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+    let target_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/target/feature-matrix");
+    let mut failures = Vec::new();
+    let matrix = FEATURES.iter().map(|f| vec!["--no-default-features", "--features", f]);
+    let extra = [vec!["--no-default-features"], vec!["--features", "all"]];
+    for args in matrix.chain(extra) {
+        let output = std::process::Command::new(&cargo)
+            .args(["check", "--quiet", "--all-targets", "--manifest-path", manifest])
+            .args(&args)
+            .env("CARGO_TARGET_DIR", target_dir)
+            .output()
+            .expect("failed to run cargo");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // Diagnostics in this crate point at relative paths; the path
+        // dependencies' own warnings are not this test's concern.
+        let warned = stderr
+            .lines()
+            .any(|line| line.trim_start().strip_prefix("--> ").is_some_and(|at| !at.starts_with('/')));
+        if !output.status.success() || warned {
+            failures.push(format!("{}:\n{}", args.join(" "), stderr));
+        }
+    }
+    assert!(failures.is_empty(), "cargo check failed or warned for:\n{}", failures.join("\n"));
+}
+
+mod always {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn adapters_and_core() {
+        assert_eq!(McpServerAdapter::new("srv").name(), "srv");
+        assert_eq!(EnterpriseActionTool::new("act").name(), "EnterpriseActionTool");
+        let built = EnterpriseActionTool::builder().coerce(true).action_name("act").build();
+        assert_eq!((built.action_name.as_str(), built.coerce), ("act", true));
+        assert_eq!(ZapierActionTool::new().name(), "ZapierActionTool");
+        assert_tool(RagAdapter::new("pipeline"));
+        assert_tool(LanceDbAdapter::new("./db"));
+        let doc = Document::new("hello");
+        assert_eq!(doc.content, "hello");
+        let _: Option<&dyn EmbeddingService> = None;
+    }
+}
+
+#[cfg(feature = "search")]
+mod search {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn search_tools_construct() {
+        assert_tool(ArxivPaperTool::new());
+        assert_tool(BraveSearchTool::new());
+        assert_tool(CodeDocsSearchTool::new());
+        assert_tool(CsvSearchTool::new());
+        assert_tool(DirectorySearchTool::new());
+        assert_tool(DocxSearchTool::new());
+        assert_tool(ExaSearchTool::new());
+        assert_tool(GithubSearchTool::new());
+        assert_tool(JsonSearchTool::new());
+        assert_tool(LinkupSearchTool::new());
+        assert_tool(MdxSearchTool::new());
+        assert_tool(MySqlSearchTool::new());
+        assert_tool(ParallelSearchTool::new());
+        assert_tool(PdfSearchTool::new());
+        assert_tool(SerperDevTool::new());
+        assert_tool(TavilySearchTool::new());
+        assert_tool(TxtSearchTool::new());
+        assert_tool(WebsiteSearchTool::new());
+        assert_tool(XmlSearchTool::new());
+        assert_tool(YoutubeChannelSearchTool::new());
+        assert_tool(YoutubeVideoSearchTool::new());
+    }
+}
+
+#[cfg(feature = "web_scraping")]
+mod web_scraping {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn web_scraping_tools_construct() {
+        assert_tool(FirecrawlCrawlWebsiteTool::new());
+        assert_tool(FirecrawlScrapeWebsiteTool::new());
+        assert_tool(FirecrawlSearchTool::new());
+        assert_tool(JinaScrapeWebsiteTool::new());
+        assert_tool(ScrapeElementFromWebsiteTool::new());
+        assert_tool(ScrapeWebsiteTool::new());
+        assert_tool(ScrapegraphScrapeTool::new());
+        assert_tool(ScrapflyScrapeWebsiteTool::new());
+        assert_tool(SeleniumScrapingTool::new());
+        assert_tool(SerperScrapeWebsiteTool::new());
+        assert_tool(SpiderTool::new());
+        assert_eq!(CreditBudget::new(10).remaining(), 10);
+    }
+}
+
+#[cfg(feature = "database")]
+mod database {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn database_tools_construct() {
+        assert_tool(CouchbaseFtsVectorSearchTool::new("bucket", "index"));
+        assert_tool(DatabricksQueryTool::new());
+        assert_tool(MongoDbVectorSearchTool::new("db", "coll"));
+        assert_tool(Nl2SqlTool::new());
+        assert_tool(PgVectorSearchTool::new("documents"));
+        assert_tool(QdrantVectorSearchTool::new("coll"));
+        assert_tool(SingleStoreSearchTool::new());
+        assert_tool(SnowflakeSearchTool::new());
+        assert_tool(SqliteSearchTool::new("state.db"));
+        assert_tool(WeaviateVectorSearchTool::new("Class"));
+    }
+
+    #[test]
+    fn typed_builders_match_constructors() {
+        let built = QdrantVectorSearchTool::builder().collection_name("coll").top_k(3).build();
+        assert_eq!(built.collection_name, "coll");
+        assert_eq!(built.top_k, 3);
+        let built = MongoDbVectorSearchTool::builder().collection("c").database("d").build();
+        assert_eq!((built.database.as_str(), built.collection.as_str()), ("d", "c"));
+        let built = WeaviateVectorSearchTool::builder().batch_size(0).class_name("Class").build();
+        assert_eq!((built.class_name.as_str(), built.batch_size), ("Class", 1));
+        let built = PgVectorSearchTool::builder().table("documents").build();
+        assert_eq!(serde_json::to_value(&built).unwrap(), serde_json::to_value(PgVectorSearchTool::new("documents")).unwrap());
+        let built = CouchbaseFtsVectorSearchTool::builder().index_name("index").bucket("bucket").build();
+        assert_eq!((built.bucket.as_str(), built.index_name.as_str()), ("bucket", "index"));
+        let built = SqliteSearchTool::builder().file_path("state.db").max_rows(7).build();
+        assert_eq!((built.file_path.as_str(), built.max_rows, built.allow_writes), ("state.db", 7, false));
+    }
+}
+
+#[cfg(feature = "file_ops")]
+mod file_ops {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn file_ops_tools_construct() {
+        assert_tool(CsvWriterTool::new());
+        assert_tool(DirectoryReadTool::new());
+        assert_tool(FileChecksumTool::new());
+        assert_tool(FileCompressorTool::new());
+        assert_tool(FileDecompressorTool::new());
+        assert_tool(FileManageTool::new());
+        assert_tool(FileReadTool::new());
+        assert_tool(FileWriterTool::new());
+        assert_tool(JsonWriterTool::new());
+        assert_tool(StructuredFileWriterTool::new());
+    }
+}
+
+#[cfg(feature = "ai_ml")]
+mod ai_ml {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn ai_ml_tools_construct() {
+        assert_tool(AiMindTool::new());
+        assert_tool(DalleTool::new());
+        assert_tool(EmbeddingTool::new());
+        assert_tool(LlamaIndexTool::new("name", "description"));
+        assert_tool(ModerationTool::new());
+        assert_tool(OcrTool::new());
+        assert_tool(RagTool::new());
+        assert_tool(TranscriptionTool::new());
+        assert_tool(VisionTool::new());
+    }
+}
+
+#[cfg(feature = "automation")]
+mod automation {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn automation_tools_construct() {
+        assert_tool(ApifyActorsTool::new());
+        assert_tool(ComposioTool::new());
+        assert_tool(CronScheduleTool::new());
+        assert_tool(GenerateCrewaiAutomationTool::new());
+        assert_tool(InvokeCrewaiAutomationTool::new());
+        assert_tool(MergeAgentHandlerTool::new());
+        assert_tool(WebhookTool::new());
+        assert_tool(ZapierActionTools::new());
+    }
+}
+
+#[cfg(feature = "cloud_storage")]
+mod cloud_storage {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn cloud_storage_tools_construct() {
+        assert_tool(AzureBlobReaderTool::new());
+        assert_tool(AzureBlobWriterTool::new());
+        assert_tool(BedrockInvokeAgentTool::new("agent", "alias"));
+        assert_tool(BedrockInvokeAgentTool::builder().agent_alias_id("alias").agent_id("agent").build());
+        assert_tool(BedrockKbRetrieverTool::new("kb"));
+        let built = BedrockKbRetrieverTool::builder().knowledge_base_id("kb").build();
+        assert_eq!(serde_json::to_value(&built).unwrap(), serde_json::to_value(BedrockKbRetrieverTool::new("kb")).unwrap());
+        assert_tool(GcsReaderTool::new());
+        assert_tool(GcsWriterTool::new());
+        assert_tool(S3ReaderTool::new());
+        assert_tool(S3WriterTool::new());
+    }
+}
+
+#[cfg(feature = "browser")]
+mod browser {
+    use super::assert_tool;
+    use crewai_tools::*;
+
+    #[test]
+    fn browser_tools_construct() {
+        assert_tool(BrowserbaseLoadTool::new());
+        assert_tool(HyperbrowserLoadTool::new());
+        assert_tool(MultiOnTool::new());
+        assert_tool(PlaywrightBrowserTool::new());
+        assert_tool(StagehandTool::new());
+    }
+}
+
+#[cfg(feature = "rag")]
+mod rag {
+    use crewai_tools::*;
+
+    #[test]
+    fn rag_types_are_reachable() {
+        let chunker: Box<dyn BaseChunker> = Box::new(WebChunker::new());
+        let chunks = chunker.chunk(&Document::new("one two three")).unwrap();
+        assert!(!chunks.is_empty());
+        let _ = TextChunker::new();
+        let _ = QueryFilter::default();
+        let _ = InMemoryVectorIndex::new();
+        assert!(html_to_markdown("<p>hi</p>").contains("hi"));
+        let _: fn(&str) -> Option<DetectedLanguage> = detect_language;
+    }
+}
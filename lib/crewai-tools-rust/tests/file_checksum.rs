@@ -0,0 +1,99 @@
+//! FileChecksumTool digests, directory manifests and verification.
+
+#![cfg(feature = "file_ops")]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crewai_tools::FileChecksumTool;
+use serde_json::{json, Value};
+
+const HELLO_SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+const HELLO_MD5: &str = "5d41402abc4b2a76b9719d911017c592";
+const HELLO_BLAKE3: &str = "ea8f163db38682925e4491c5e58d4bb3506ef8c14eb78a86e908c5624a67200f";
+
+fn temp_dir(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("crewai-checksum-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    root
+}
+
+fn hash(path: &Path, options: &[(&str, Value)]) -> Value {
+    let mut args: HashMap<String, Value> = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    args.insert("path".into(), json!(path.to_string_lossy()));
+    FileChecksumTool::new().run(args).unwrap()
+}
+
+#[test]
+fn file_digests_match_known_values() {
+    let dir = temp_dir("file");
+    let file = dir.join("hello.txt");
+    std::fs::write(&file, "hello").unwrap();
+
+    assert_eq!(hash(&file, &[])["hash"], HELLO_SHA256);
+    assert_eq!(hash(&file, &[("algorithm", json!("md5"))])["hash"], HELLO_MD5);
+    assert_eq!(hash(&file, &[("algorithm", json!("blake3"))])["hash"], HELLO_BLAKE3);
+    assert_eq!(hash(&file, &[])["bytes"], 5);
+}
+
+#[test]
+fn large_files_hash_across_chunks() {
+    let dir = temp_dir("large");
+    let file = dir.join("big.bin");
+    let data = vec![7u8; 200 * 1024 + 3];
+    std::fs::write(&file, &data).unwrap();
+
+    let result = hash(&file, &[("algorithm", json!("blake3"))]);
+
+    assert_eq!(result["hash"], blake3::hash(&data).to_hex().as_str());
+    assert_eq!(result["bytes"], data.len());
+}
+
+#[test]
+fn verify_file_reports_match_case_insensitively() {
+    let dir = temp_dir("verify-file");
+    let file = dir.join("hello.txt");
+    std::fs::write(&file, "hello").unwrap();
+
+    let ok = hash(&file, &[("expected", json!(HELLO_SHA256.to_uppercase()))]);
+    assert_eq!(ok["matches"], true);
+    let bad = hash(&file, &[("expected", json!(HELLO_MD5))]);
+    assert_eq!(bad["matches"], false);
+}
+
+#[test]
+fn directory_manifest_and_verification() {
+    let dir = temp_dir("manifest");
+    std::fs::create_dir_all(dir.join("sub")).unwrap();
+    std::fs::write(dir.join("a.txt"), "hello").unwrap();
+    std::fs::write(dir.join("sub/b.txt"), "world").unwrap();
+
+    let result = hash(&dir, &[]);
+    assert_eq!(result["files"], 2);
+    assert_eq!(result["manifest"]["a.txt"], HELLO_SHA256);
+
+    let mut expected = result["manifest"].clone();
+    expected["sub/b.txt"] = json!(HELLO_SHA256);
+    expected["gone.txt"] = json!(HELLO_SHA256);
+    std::fs::write(dir.join("new.txt"), "").unwrap();
+
+    let report = hash(&dir, &[("expected", expected)]);
+    assert_eq!(report["matches"], false);
+    assert_eq!(report["mismatched"][0]["path"], "sub/b.txt");
+    assert_eq!(report["missing"], json!(["gone.txt"]));
+    assert_eq!(report["unexpected"], json!(["new.txt"]));
+}
+
+#[test]
+fn unknown_algorithm_is_an_error() {
+    let dir = temp_dir("algo");
+    let err = FileChecksumTool::new()
+        .with_algorithm("crc32")
+        .run(HashMap::from([("path".to_string(), json!(dir.to_string_lossy()))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("Unsupported algorithm"), "{}", err);
+}
@@ -0,0 +1,119 @@
+//! FileCompressorTool writing real archives into a temp directory.
+
+#![cfg(feature = "file_ops")]
+
+mod common;
+
+use std::fs::File;
+use std::path::Path;
+
+use common::args;
+use crewai_tools::FileCompressorTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+/// A fresh directory holding `src/` with a nested file and an empty
+/// subdirectory.
+fn fixture() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("src/nested")).unwrap();
+    std::fs::create_dir_all(root.join("src/empty")).unwrap();
+    std::fs::write(root.join("src/a.txt"), "hello ".repeat(100)).unwrap();
+    std::fs::write(root.join("src/nested/b.txt"), "world").unwrap();
+    dir
+}
+
+fn input(root: &Path) -> Value {
+    json!(root.join("src").to_string_lossy())
+}
+
+#[test]
+fn zip_preserves_relative_paths() {
+    let tmp = fixture();
+    let root = tmp.path();
+
+    let result = FileCompressorTool::new()
+        .run(args(&[("input_path", input(root))]))
+        .unwrap();
+
+    let archive_path = root.join("src.zip");
+    assert_eq!(result["archive_path"], json!(archive_path.to_string_lossy()));
+    assert_eq!(result["files"], 2);
+    assert_eq!(result["original_size"], 605);
+    assert!(result["compressed_size"].as_u64().unwrap() < 605);
+
+    let mut zip = zip::ZipArchive::new(File::open(&archive_path).unwrap()).unwrap();
+    let mut names: Vec<String> = zip.file_names().map(str::to_string).collect();
+    names.sort();
+    assert_eq!(names, ["a.txt", "empty/", "nested/", "nested/b.txt"]);
+    let mut b = String::new();
+    std::io::Read::read_to_string(&mut zip.by_name("nested/b.txt").unwrap(), &mut b).unwrap();
+    assert_eq!(b, "world");
+}
+
+#[test]
+fn tar_gz_round_trips() {
+    let tmp = fixture();
+    let root = tmp.path();
+    let output = root.join("out/archive.tar.gz");
+
+    FileCompressorTool::new()
+        .with_format("tar.gz")
+        .run(args(&[
+            ("input_path", input(root)),
+            ("output_path", json!(output.to_string_lossy())),
+        ]))
+        .unwrap();
+
+    let decoder = flate2::read::GzDecoder::new(File::open(&output).unwrap());
+    let mut names: Vec<String> = tar::Archive::new(decoder)
+        .entries()
+        .unwrap()
+        .map(|e| e.unwrap().path().unwrap().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["a.txt", "empty", "nested", "nested/b.txt"]);
+}
+
+#[test]
+fn unsupported_format_fails_before_touching_files() {
+    let tmp = fixture();
+    let root = tmp.path();
+
+    let err = FileCompressorTool::new()
+        .with_format("rar")
+        .run(args(&[("input_path", input(root))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Unsupported archive format"), "{}", err);
+    assert!(!root.join("src.rar").exists());
+}
+
+#[test]
+fn existing_archive_needs_overwrite() {
+    let tmp = fixture();
+    let root = tmp.path();
+    std::fs::write(root.join("src.zip"), "old").unwrap();
+    let tool = FileCompressorTool::new();
+
+    assert!(tool.run(args(&[("input_path", input(root))])).is_err());
+    tool.run(args(&[("input_path", input(root)), ("overwrite", json!(true))]))
+        .unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_directories_are_skipped_not_followed() {
+    let tmp = fixture();
+    let root = tmp.path();
+    std::os::unix::fs::symlink(root.join("src"), root.join("src/nested/loop")).unwrap();
+    std::os::unix::fs::symlink(root.join("src/a.txt"), root.join("src/link.txt")).unwrap();
+
+    let result = FileCompressorTool::new()
+        .run(args(&[("input_path", input(root))]))
+        .unwrap();
+
+    assert_eq!(result["files"], 3);
+    assert_eq!(result["skipped"][0]["reason"], "symlink to a directory");
+}
@@ -0,0 +1,181 @@
+//! FileDecompressorTool against archives built in a temp directory,
+//! including hostile ones.
+
+#![cfg(feature = "file_ops")]
+
+mod common;
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use common::args;
+use crewai_tools::FileDecompressorTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+fn path_arg(path: &Path) -> Value {
+    json!(path.to_string_lossy())
+}
+
+fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+    let mut zip = zip::ZipWriter::new(File::create(path).unwrap());
+    for (name, data) in entries {
+        zip.start_file(*name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(data).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[test]
+fn zip_extracts_next_to_the_archive_by_default() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("bundle.zip");
+    write_zip(&archive, &[("a.txt", b"hello"), ("docs/b.txt", b"world!")]);
+
+    let result = FileDecompressorTool::new()
+        .run(args(&[("archive_path", path_arg(&archive))]))
+        .unwrap();
+
+    let out = root.join("bundle");
+    assert_eq!(result["output_dir"], path_arg(&out));
+    assert_eq!(result["files"], json!(["a.txt", "docs/b.txt"]));
+    assert_eq!(result["file_count"], 2);
+    assert_eq!(result["total_size"], 11);
+    assert_eq!(std::fs::read_to_string(out.join("docs/b.txt")).unwrap(), "world!");
+}
+
+#[test]
+fn tar_gz_format_is_inferred_from_tgz() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("bundle.tgz");
+    let encoder = flate2::write::GzEncoder::new(
+        File::create(&archive).unwrap(),
+        flate2::Compression::default(),
+    );
+    let mut tar = tar::Builder::new(encoder);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(3);
+    header.set_mode(0o644);
+    tar.append_data(&mut header, "nested/c.txt", &b"abc"[..])
+        .unwrap();
+    tar.into_inner().unwrap().finish().unwrap();
+
+    let result = FileDecompressorTool::new()
+        .run(args(&[("archive_path", path_arg(&archive))]))
+        .unwrap();
+
+    assert_eq!(result["format"], "tar.gz");
+    assert_eq!(
+        std::fs::read_to_string(root.join("bundle/nested/c.txt")).unwrap(),
+        "abc"
+    );
+}
+
+#[test]
+fn zip_slip_entries_are_refused() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("evil.zip");
+    write_zip(&archive, &[("ok.txt", b"fine"), ("../escaped.txt", b"pwned")]);
+
+    let err = FileDecompressorTool::new()
+        .run(args(&[
+            ("archive_path", path_arg(&archive)),
+            ("output_dir", path_arg(&root.join("out"))),
+        ]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("escapes the target directory"), "{}", err);
+    assert!(!root.join("escaped.txt").exists());
+    // Files written before the bad entry are cleaned up.
+    assert!(!root.join("out/ok.txt").exists());
+}
+
+#[test]
+fn tar_parent_dir_entries_are_refused() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("evil.tar");
+    let mut tar = tar::Builder::new(File::create(&archive).unwrap());
+    // The builder refuses `..` itself, so write the name into the header.
+    let mut header = tar::Header::new_old();
+    let name = b"../escaped.txt";
+    header.as_old_mut().name[..name.len()].copy_from_slice(name);
+    header.set_size(5);
+    header.set_mode(0o644);
+    header.set_cksum();
+    tar.append(&header, &b"pwned"[..]).unwrap();
+    tar.finish().unwrap();
+
+    let err = FileDecompressorTool::new()
+        .run(args(&[
+            ("archive_path", path_arg(&archive)),
+            ("output_dir", path_arg(&root.join("out"))),
+        ]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("escapes the target directory"), "{}", err);
+    assert!(!root.join("escaped.txt").exists());
+}
+
+#[test]
+fn extraction_stops_at_the_size_limit() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("bomb.zip");
+    let zeros = vec![0u8; 4096];
+    write_zip(&archive, &[("first.bin", &zeros), ("second.bin", &zeros)]);
+
+    let err = FileDecompressorTool::new()
+        .with_max_total_size(6000)
+        .run(args(&[("archive_path", path_arg(&archive))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("6000 byte extraction limit"), "{}", err);
+    assert!(!root.join("bomb/first.bin").exists());
+    assert!(!root.join("bomb/second.bin").exists());
+}
+
+#[test]
+fn password_protected_zip_gives_a_clear_error() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("secret.zip");
+    write_zip(&archive, &[("secret.txt", b"hidden")]);
+    // Set the "encrypted" general-purpose flag in the local and central
+    // headers, which is all a reader has to go on.
+    let mut bytes = std::fs::read(&archive).unwrap();
+    for (signature, flag_offset) in [(b"PK\x03\x04", 6), (b"PK\x01\x02", 8)] {
+        let at = bytes.windows(4).position(|w| w == signature).unwrap();
+        bytes[at + flag_offset] |= 1;
+    }
+    std::fs::write(&archive, bytes).unwrap();
+
+    let err = FileDecompressorTool::new()
+        .run(args(&[("archive_path", path_arg(&archive))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("password-protected"), "{}", err);
+}
+
+#[test]
+fn existing_files_need_overwrite() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    let archive = root.join("bundle.zip");
+    write_zip(&archive, &[("a.txt", b"new")]);
+    std::fs::create_dir_all(root.join("bundle")).unwrap();
+    std::fs::write(root.join("bundle/a.txt"), "old").unwrap();
+    let tool = FileDecompressorTool::new();
+
+    assert!(tool.run(args(&[("archive_path", path_arg(&archive))])).is_err());
+    assert_eq!(std::fs::read_to_string(root.join("bundle/a.txt")).unwrap(), "old");
+
+    tool.run(args(&[("archive_path", path_arg(&archive)), ("overwrite", json!(true))]))
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(root.join("bundle/a.txt")).unwrap(), "new");
+}
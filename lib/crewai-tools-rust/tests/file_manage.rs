@@ -0,0 +1,216 @@
+//! FileManageTool move, copy and delete, with dry runs and the sandbox.
+
+#![cfg(feature = "file_ops")]
+
+mod common;
+
+use std::path::Path;
+
+use common::args;
+use crewai_tools::FileManageTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+fn p(path: &Path) -> Value {
+    json!(path.to_string_lossy())
+}
+
+#[test]
+fn move_into_an_existing_directory_keeps_the_name() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    std::fs::write(dir.join("report.txt"), "done").unwrap();
+    std::fs::create_dir(dir.join("results")).unwrap();
+
+    let result = FileManageTool::new()
+        .run(args(&[
+            ("operation", json!("move")),
+            ("source", p(&dir.join("report.txt"))),
+            ("destination", p(&dir.join("results"))),
+        ]))
+        .unwrap();
+
+    let moved = dir.join("results/report.txt");
+    assert_eq!(result["destination"], p(&moved));
+    assert_eq!(std::fs::read_to_string(moved).unwrap(), "done");
+    assert!(!dir.join("report.txt").exists());
+}
+
+#[test]
+fn copy_directory_recursively_and_refuse_existing_destination() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    std::fs::create_dir_all(dir.join("src/nested")).unwrap();
+    std::fs::write(dir.join("src/a.txt"), "a").unwrap();
+    std::fs::write(dir.join("src/nested/b.txt"), "b").unwrap();
+    let tool = FileManageTool::new();
+    let copy = args(&[
+        ("operation", json!("copy")),
+        ("source", p(&dir.join("src"))),
+        ("destination", p(&dir.join("dst"))),
+    ]);
+
+    let result = tool.run(copy.clone()).unwrap();
+
+    assert_eq!(result["affected"].as_array().unwrap().len(), 4);
+    assert_eq!(std::fs::read_to_string(dir.join("dst/nested/b.txt")).unwrap(), "b");
+    assert!(dir.join("src/a.txt").exists());
+    // `dst` now exists, so the second copy lands in `dst/src`.
+    tool.run(copy).unwrap();
+    assert!(dir.join("dst/src/nested/b.txt").exists());
+}
+
+#[test]
+fn delete_requires_recursive_for_non_empty_directories() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    std::fs::create_dir_all(dir.join("tmp/sub")).unwrap();
+    std::fs::write(dir.join("tmp/sub/x"), "x").unwrap();
+    let tool = FileManageTool::new();
+
+    let err = tool
+        .run(args(&[("operation", json!("delete")), ("path", p(&dir.join("tmp")))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not empty"), "{}", err);
+
+    let result = tool
+        .run(args(&[
+            ("operation", json!("delete")),
+            ("path", p(&dir.join("tmp"))),
+            ("recursive", json!(true)),
+        ]))
+        .unwrap();
+    assert_eq!(result["affected"].as_array().unwrap().len(), 3);
+    assert!(!dir.join("tmp").exists());
+}
+
+#[test]
+fn dry_run_reports_without_changing_anything() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    std::fs::write(dir.join("keep.txt"), "x").unwrap();
+
+    let result = FileManageTool::new()
+        .run(args(&[
+            ("operation", json!("delete")),
+            ("path", p(&dir.join("keep.txt"))),
+            ("dry_run", json!(true)),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["dry_run"], true);
+    assert_eq!(result["affected"], json!([dir.join("keep.txt").to_string_lossy()]));
+    assert!(dir.join("keep.txt").exists());
+}
+
+#[test]
+fn sandbox_rejects_paths_outside_the_root() {
+    let tmp = TempDir::new().unwrap();
+    let base = tmp.path().canonicalize().unwrap();
+    let root = base.join("root");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("inside.txt"), "x").unwrap();
+    std::fs::write(base.join("outside.txt"), "x").unwrap();
+    let tool = FileManageTool::new().with_sandbox_root(root.to_string_lossy());
+
+    let err = tool
+        .run(args(&[("operation", json!("delete")), ("path", json!("../outside.txt"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("escapes the sandbox root"), "{}", err);
+    assert!(base.join("outside.txt").exists());
+
+    assert!(tool
+        .run(args(&[
+            ("operation", json!("move")),
+            ("source", json!("inside.txt")),
+            ("destination", json!("../stolen.txt")),
+        ]))
+        .is_err());
+    assert!(root.join("inside.txt").exists());
+
+    // The root itself cannot be deleted through the sandbox.
+    assert!(tool
+        .run(args(&[
+            ("operation", json!("delete")),
+            ("path", json!(".")),
+            ("recursive", json!(true)),
+        ]))
+        .is_err());
+
+    tool.run(args(&[
+        ("operation", json!("move")),
+        ("source", json!("inside.txt")),
+        ("destination", json!("renamed.txt")),
+    ]))
+    .unwrap();
+    assert!(root.join("renamed.txt").exists());
+}
+
+/// Two temporary directories on different filesystems, if the machine has
+/// a tmpfs at /dev/shm apart from the default temp dir.
+#[cfg(unix)]
+fn dirs_on_two_filesystems() -> Option<(TempDir, TempDir)> {
+    use std::os::unix::fs::MetadataExt;
+
+    let here = TempDir::new().ok()?;
+    let there = TempDir::new_in("/dev/shm").ok()?;
+    let dev = |dir: &TempDir| std::fs::metadata(dir.path()).map(|m| m.dev()).ok();
+    (dev(&here)? != dev(&there)?).then_some((here, there))
+}
+
+#[cfg(unix)]
+#[test]
+fn move_across_filesystems_keeps_symlinks() {
+    let Some((src, dst)) = dirs_on_two_filesystems() else {
+        eprintln!("skipped: no second filesystem to move across");
+        return;
+    };
+    let tree = src.path().join("tree");
+    std::fs::create_dir_all(tree.join("sub")).unwrap();
+    std::fs::write(tree.join("sub/data.txt"), "data").unwrap();
+    std::os::unix::fs::symlink("sub/data.txt", tree.join("link")).unwrap();
+    std::os::unix::fs::symlink("/nowhere", tree.join("dangling")).unwrap();
+    std::os::unix::fs::symlink("tree/sub", src.path().join("top-link")).unwrap();
+
+    let tool = FileManageTool::new();
+    for name in ["tree", "top-link"] {
+        tool.run(args(&[
+            ("operation", json!("move")),
+            ("source", p(&src.path().join(name))),
+            ("destination", p(&dst.path().join(name))),
+        ]))
+        .unwrap();
+    }
+
+    let moved = dst.path().join("tree");
+    assert_eq!(std::fs::read_to_string(moved.join("sub/data.txt")).unwrap(), "data");
+    assert_eq!(std::fs::read_link(moved.join("link")).unwrap(), Path::new("sub/data.txt"));
+    assert_eq!(std::fs::read_link(moved.join("dangling")).unwrap(), Path::new("/nowhere"));
+    assert_eq!(
+        std::fs::read_link(dst.path().join("top-link")).unwrap(),
+        Path::new("tree/sub")
+    );
+    assert!(std::fs::symlink_metadata(src.path().join("tree")).is_err());
+    assert!(std::fs::symlink_metadata(src.path().join("top-link")).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn copy_still_skips_symlinks_inside_directories() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+    std::fs::create_dir(dir.join("tree")).unwrap();
+    std::fs::write(dir.join("tree/data.txt"), "data").unwrap();
+    std::os::unix::fs::symlink("data.txt", dir.join("tree/link")).unwrap();
+
+    let result = FileManageTool::new()
+        .run(args(&[
+            ("operation", json!("copy")),
+            ("source", p(&dir.join("tree"))),
+            ("destination", p(&dir.join("copy"))),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["skipped"], json!([dir.join("tree/link").to_string_lossy()]));
+    assert!(std::fs::symlink_metadata(dir.join("copy/link")).is_err());
+}
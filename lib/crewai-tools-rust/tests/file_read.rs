@@ -0,0 +1,150 @@
+//! FileReadTool line ranges, tail mode and the content cap.
+
+#![cfg(feature = "file_ops")]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crewai_tools::FileReadTool;
+use serde_json::{json, Value};
+
+/// A temp file holding `line 1\n` .. `line {n}\n`.
+fn numbered_file(name: &str, n: usize) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("crewai-read-{}-{}.log", name, std::process::id()));
+    let content: String = (1..=n).map(|i| format!("line {}\n", i)).collect();
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn read(path: &Path, options: &[(&str, Value)]) -> Result<Value, anyhow::Error> {
+    let mut args: HashMap<String, Value> = options
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    args.insert("file_path".into(), json!(path.to_string_lossy()));
+    FileReadTool::new().run(args)
+}
+
+#[test]
+fn no_options_returns_the_bare_string() {
+    let path = numbered_file("plain", 3);
+    assert_eq!(read(&path, &[]).unwrap(), json!("line 1\nline 2\nline 3\n"));
+}
+
+#[test]
+fn line_range_is_inclusive_and_counts_every_line() {
+    let path = numbered_file("range", 10);
+
+    let result = read(&path, &[("start_line", json!(3)), ("end_line", json!(5))]).unwrap();
+
+    assert_eq!(result["content"], "line 3\nline 4\nline 5\n");
+    assert_eq!(result["total_lines"], 10);
+    assert_eq!(result["truncated"], false);
+    assert_eq!(result["bytes"], std::fs::metadata(&path).unwrap().len());
+}
+
+#[test]
+fn tail_returns_the_last_lines() {
+    let path = numbered_file("tail", 10);
+
+    let result = read(&path, &[("tail_lines", json!(2))]).unwrap();
+
+    assert_eq!(result["content"], "line 9\nline 10\n");
+    assert_eq!(result["total_lines"], 10);
+    assert_eq!(result["truncated"], false);
+}
+
+#[test]
+fn max_bytes_truncates_and_says_so() {
+    let path = numbered_file("cap", 100);
+
+    let head = read(&path, &[("max_bytes", json!(10))]).unwrap();
+    assert_eq!(head["content"], "line 1\nlin");
+    assert_eq!(head["truncated"], true);
+    assert_eq!(head["total_lines"], 100);
+
+    let tail = read(&path, &[("tail_lines", json!(5)), ("max_bytes", json!(10))]).unwrap();
+    assert_eq!(tail["content"], "\nline 100\n");
+    assert_eq!(tail["truncated"], true);
+}
+
+#[test]
+fn oversized_file_switches_to_an_object_without_options() {
+    let path = numbered_file("big", 10);
+
+    let result = FileReadTool::new()
+        .with_max_bytes(7)
+        .with_file_path(path.to_string_lossy())
+        .run(HashMap::new())
+        .unwrap();
+
+    assert_eq!(result["content"], "line 1\n");
+    assert_eq!(result["truncated"], true);
+}
+
+#[test]
+fn conflicting_options_are_rejected() {
+    let path = numbered_file("conflict", 3);
+
+    assert!(read(&path, &[("start_line", json!(0))]).is_err());
+    assert!(read(&path, &[("start_line", json!(3)), ("end_line", json!(2))]).is_err());
+    assert!(read(&path, &[("tail_lines", json!(1)), ("start_line", json!(2))]).is_err());
+}
+
+fn binary_file(name: &str, bytes: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("crewai-read-{}-{}", std::process::id(), name));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn base64_reports_the_sniffed_mime_type() {
+    use base64::Engine;
+
+    // PNG magic under a misleading extension: the bytes win.
+    let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+    let path = binary_file("image.jpg", png);
+
+    let result = read(&path, &[("encoding", json!("base64"))]).unwrap();
+
+    assert_eq!(result["encoding"], "base64");
+    assert_eq!(result["mime_type"], "image/png");
+    assert_eq!(result["bytes"], png.len());
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(result["content"].as_str().unwrap())
+        .unwrap();
+    assert_eq!(decoded, png);
+}
+
+#[test]
+fn base64_falls_back_to_the_extension() {
+    let path = binary_file("notes.csv", b"a,b\n1,2\n");
+
+    let result = read(&path, &[("encoding", json!("base64"))]).unwrap();
+
+    assert_eq!(result["mime_type"], "text/csv");
+}
+
+#[test]
+fn base64_refuses_files_over_the_limit() {
+    let path = binary_file("big.bin", &[0u8; 64]);
+
+    let err = FileReadTool::new()
+        .with_max_base64_bytes(32)
+        .with_file_path(path.to_string_lossy())
+        .run(HashMap::from([("encoding".to_string(), json!("base64"))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("over the 32 byte limit"), "{}", err);
+}
+
+#[test]
+fn invalid_utf8_errors_unless_lossy() {
+    let path = binary_file("latin1.txt", b"caf\xe9\n");
+
+    let err = read(&path, &[]).unwrap_err();
+    assert!(err.to_string().contains("not valid UTF-8"), "{}", err);
+
+    let result = read(&path, &[("encoding", json!("lossy"))]).unwrap();
+    assert_eq!(result, json!("caf\u{fffd}\n"));
+}
@@ -0,0 +1,238 @@
+//! FileWriterTool append mode, atomic overwrites, the sandbox root, and
+//! write-and-verify checksums (shared with StructuredFileWriterTool).
+
+#![cfg(feature = "file_ops")]
+
+mod common;
+
+use common::args;
+use crewai_tools::{FileWriterTool, StructuredFileWriterTool};
+use serde_json::json;
+use tempfile::TempDir;
+
+/// SHA-256 of `hello world`.
+const HELLO_SHA256: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+#[test]
+fn verify_reports_bytes_and_the_sha256_of_the_content() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let report = FileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_verify(true)
+        .run(args(&[("filename", json!("out.txt")), ("content", json!("hello world"))]))
+        .unwrap();
+
+    assert_eq!(report["verified"], true);
+    assert_eq!(report["bytes"], 11);
+    assert_eq!(report["sha256"], HELLO_SHA256);
+    assert_eq!(report["path"], dir.join("out.txt").display().to_string());
+    assert_eq!(std::fs::read_to_string(dir.join("out.txt")).unwrap(), "hello world");
+}
+
+#[test]
+fn checksum_is_reported_without_verifying() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let tool = FileWriterTool::new().with_directory(dir.to_string_lossy());
+
+    let plain = tool
+        .run(args(&[("filename", json!("a.txt")), ("content", json!("hello world"))]))
+        .unwrap();
+    assert!(plain.is_string(), "{}", plain);
+
+    let report = tool
+        .with_checksum(true)
+        .run(args(&[("filename", json!("b.txt")), ("content", json!("hello world"))]))
+        .unwrap();
+    assert_eq!(report["sha256"], HELLO_SHA256);
+    assert_eq!(report["bytes"], 11);
+    assert_eq!(report["verified"], false);
+}
+
+#[cfg(unix)]
+#[test]
+fn verify_mismatch_reports_both_hashes() {
+    // Appends through a link to /dev/null "succeed" but nothing reads back,
+    // standing in for another process clobbering the file.
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::os::unix::fs::symlink("/dev/null", dir.join("sink.log")).unwrap();
+
+    let err = FileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_append(true)
+        .with_verify(true)
+        .run(args(&[("filename", json!("sink.log")), ("content", json!("hello world"))]))
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("Verification failed"), "{}", err);
+    assert!(err.contains(&format!("expected sha256 {}", HELLO_SHA256)), "{}", err);
+    let empty = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+    assert!(err.contains(&format!("found {}", empty)), "{}", err);
+}
+
+#[test]
+fn structured_writer_checksums_the_serialized_output() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let report = StructuredFileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_pretty(false)
+        .with_verify(true)
+        .run(args(&[("filename", json!("data.json")), ("data", json!({"a": 1}))]))
+        .unwrap();
+
+    // SHA-256 of `{"a":1}\n`, exactly what landed on disk.
+    assert_eq!(report["sha256"], "e346432021b04179518d9614f3560ccd71354a4ee101ddcb893d6959a9d6301c");
+    assert_eq!(report["bytes"], 8);
+    assert_eq!(report["verified"], true);
+    assert_eq!(std::fs::read_to_string(dir.join("data.json")).unwrap(), "{\"a\":1}\n");
+}
+
+#[test]
+fn append_adds_to_the_end_and_verifies_only_the_new_part() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let tool = FileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_append(true);
+
+    tool.run(args(&[("filename", json!("log.txt")), ("content", json!("one\n"))]))
+        .unwrap();
+    let report = tool
+        .run(args(&[
+            ("filename", json!("log.txt")),
+            ("content", json!("two\n")),
+            ("verify", json!(true)),
+        ]))
+        .unwrap();
+
+    assert_eq!(report["appended"], true);
+    assert_eq!(report["bytes"], 4);
+    assert_eq!(std::fs::read_to_string(dir.join("log.txt")).unwrap(), "one\ntwo\n");
+}
+
+#[test]
+fn overwrite_replaces_the_file_without_leaving_temp_files() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::fs::write(dir.join("out.txt"), "old contents").unwrap();
+
+    FileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_overwrite(true)
+        .run(args(&[("filename", json!("out.txt")), ("content", json!("new"))]))
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(dir.join("out.txt")).unwrap(), "new");
+    let entries: Vec<_> = std::fs::read_dir(dir).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn existing_file_without_overwrite_is_left_alone() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::fs::write(dir.join("out.txt"), "keep").unwrap();
+
+    let result = FileWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .run(args(&[("filename", json!("out.txt")), ("content", json!("new"))]));
+
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(dir.join("out.txt")).unwrap(), "keep");
+}
+
+#[test]
+fn concurrent_writers_without_overwrite_create_the_file_once() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().to_string_lossy().into_owned();
+
+    let results: Vec<_> = std::thread::scope(|scope| {
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let dir = dir.clone();
+                scope.spawn(move || {
+                    FileWriterTool::new().with_directory(dir).run(args(&[
+                        ("filename", json!("out.txt")),
+                        ("content", json!(format!("writer {}", i))),
+                    ]))
+                })
+            })
+            .collect();
+        writers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    let winners: Vec<usize> = (0..8).filter(|&i| results[i].is_ok()).collect();
+    assert_eq!(winners.len(), 1, "{:?}", results);
+    for result in results.iter().filter(|r| r.is_err()) {
+        let err = result.as_ref().unwrap_err().to_string();
+        assert!(err.contains("already exists and overwrite is disabled"), "{}", err);
+    }
+    let on_disk = std::fs::read_to_string(tmp.path().join("out.txt")).unwrap();
+    assert_eq!(on_disk, format!("writer {}", winners[0]));
+    // No temporary files are left behind by the losers.
+    assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn sandbox_resolves_relative_paths_inside_the_root() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path();
+    // `..` is only resolvable through directories that already exist.
+    std::fs::create_dir_all(root.join("reports/q1")).unwrap();
+    FileWriterTool::new()
+        .with_sandbox_root(root.to_string_lossy())
+        .run(args(&[
+            ("filename", json!("reports/q1/../q2.txt")),
+            ("content", json!("ok")),
+        ]))
+        .unwrap();
+
+    assert_eq!(std::fs::read_to_string(root.join("reports/q2.txt")).unwrap(), "ok");
+}
+
+#[test]
+fn sandbox_rejects_parent_dir_escapes() {
+    let tmp = TempDir::new().unwrap();
+    let base = tmp.path();
+    let root = base.join("root");
+    std::fs::create_dir_all(&root).unwrap();
+
+    let err = FileWriterTool::new()
+        .with_sandbox_root(root.to_string_lossy())
+        .run(args(&[("filename", json!("../evil.txt")), ("content", json!("x"))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("escapes the sandbox root"), "{}", err);
+    assert!(!base.join("evil.txt").exists());
+
+    let outside = base.join("abs.txt");
+    assert!(FileWriterTool::new()
+        .with_sandbox_root(root.to_string_lossy())
+        .run(args(&[("filename", json!(outside.to_string_lossy())), ("content", json!("x"))]))
+        .is_err());
+    assert!(!outside.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn sandbox_rejects_symlink_escapes() {
+    let tmp = TempDir::new().unwrap();
+    let base = tmp.path();
+    let root = base.join("root");
+    let outside = base.join("outside");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::create_dir_all(&outside).unwrap();
+    std::os::unix::fs::symlink(&outside, root.join("link")).unwrap();
+
+    let err = FileWriterTool::new()
+        .with_sandbox_root(root.to_string_lossy())
+        .run(args(&[("filename", json!("link/new/evil.txt")), ("content", json!("x"))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("escapes the sandbox root"), "{}", err);
+    assert!(!outside.join("new").exists());
+}
@@ -0,0 +1,112 @@
+//! FirecrawlCrawlWebsiteTool credit budgets against a scripted Firecrawl API.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::{body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{CreditBudget, FirecrawlCrawlWebsiteTool};
+use serde_json::Value;
+
+const STARTED: common::Scripted = ("200 OK", "", r#"{"success": true, "id": "crawl-1"}"#);
+const CANCELLED: common::Scripted = ("200 OK", "", r#"{"success": true, "status": "cancelled"}"#);
+
+fn tool(url: &str) -> FirecrawlCrawlWebsiteTool {
+    FirecrawlCrawlWebsiteTool::new()
+        .with_base_url(url)
+        .with_api_key("fc-test")
+        .with_url("https://example.com")
+        .with_poll_interval_ms(0)
+}
+
+#[test]
+fn crossing_the_budget_mid_crawl_cancels_the_job() {
+    let first_poll = (
+        "200 OK",
+        "",
+        r##"{"status": "scraping", "creditsUsed": 3, "data": [
+            {"markdown": "# A", "metadata": {"sourceURL": "https://example.com/a", "title": "A"}}
+        ]}"##,
+    );
+    let second_poll = (
+        "200 OK",
+        "",
+        r##"{"status": "scraping", "creditsUsed": 6, "data": [
+            {"markdown": "# A", "metadata": {"sourceURL": "https://example.com/a", "title": "A"}},
+            {"markdown": "# B", "metadata": {"sourceURL": "https://example.com/b", "title": "B"}}
+        ]}"##,
+    );
+    let (url, hits, requests) =
+        recording_mock_server(vec![STARTED, first_poll, second_poll, CANCELLED]);
+    let tool = tool(&url).with_credit_budget(5);
+
+    let result = tool.run(HashMap::new()).unwrap();
+
+    assert_eq!(result["status"], "cancelled");
+    assert_eq!(result["budget_exhausted"], true);
+    assert_eq!(result["crawl_id"], "crawl-1");
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["pages"][1]["url"], "https://example.com/b");
+    // The whole reported usage is charged, even past the cap.
+    assert_eq!(result["credits_used"], 6);
+    assert_eq!(tool.credits_used(), 6);
+
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 4);
+    let requests = requests.lock().unwrap();
+    assert!(requests[1].starts_with("get /crawl/crawl-1 "), "{}", requests[1]);
+    assert!(requests[3].starts_with("delete /crawl/crawl-1 "), "{}", requests[3]);
+}
+
+#[test]
+fn crawl_limit_is_capped_by_the_remaining_budget() {
+    let done = ("200 OK", "", r#"{"status": "completed", "creditsUsed": 1, "data": []}"#);
+    let (url, bodies) = body_recording_mock_server(vec![STARTED, done]);
+    let budget = CreditBudget::new(4);
+    budget.charge(1);
+    let tool = tool(&url).with_max_pages(10).with_shared_credit_budget(budget.clone());
+
+    let result = tool.run(HashMap::new()).unwrap();
+
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["budget_exhausted"], false);
+    let request: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(request["limit"], 3);
+    assert_eq!(budget.consumed(), 2);
+}
+
+#[test]
+fn cumulative_usage_is_charged_once_and_rounded_up() {
+    let polls = vec![
+        STARTED,
+        ("200 OK", "", r#"{"status": "scraping", "creditsUsed": "1.5", "data": []}"#),
+        ("200 OK", "", r#"{"status": "scraping", "creditsUsed": 2, "data": []}"#),
+        ("200 OK", "", r#"{"status": "completed", "creditsUsed": 2.2, "data": []}"#),
+    ];
+    let (url, _) = mock_server(polls);
+    let tool = tool(&url).with_credit_budget(10);
+
+    let result = tool.run(HashMap::new()).unwrap();
+
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["credits_used"], 3);
+    assert_eq!(tool.credits_used(), 3);
+}
+
+#[test]
+fn shared_budget_spent_by_another_tool_stops_before_any_request() {
+    let (url, hits) = mock_server(vec![STARTED]);
+    let budget = CreditBudget::new(5);
+    budget.charge(5);
+
+    let result = tool(&url)
+        .with_shared_credit_budget(budget)
+        .run(HashMap::new())
+        .unwrap();
+
+    assert_eq!(result["status"], "cancelled");
+    assert_eq!(result["budget_exhausted"], true);
+    assert_eq!(result["count"], 0);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
@@ -0,0 +1,146 @@
+//! FirecrawlScrapeWebsiteTool requests and response mapping against a
+//! scripted Firecrawl API.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::FirecrawlScrapeWebsiteTool;
+use serde_json::{json, Value};
+
+const SCRAPED: common::Scripted = (
+    "200 OK",
+    "",
+    r##"{"success": true, "data": {
+        "markdown": "# Docs",
+        "html": "<h1>Docs</h1>",
+        "links": ["https://example.com/a"],
+        "metadata": {"title": "Docs", "statusCode": 200, "sourceURL": "https://example.com/docs", "language": "en"}
+    }}"##,
+);
+
+fn tool(url: &str) -> FirecrawlScrapeWebsiteTool {
+    FirecrawlScrapeWebsiteTool::new()
+        .with_base_url(url)
+        .with_api_key("fc-test")
+        .with_url("https://example.com/docs")
+}
+
+#[test]
+fn request_names_the_url_and_formats() {
+    let (url, _, requests) = recording_mock_server(vec![SCRAPED]);
+    tool(&url).run(args(&[])).unwrap();
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /scrape "), "{}", head);
+    assert!(
+        head.contains("authorization: bearer fc-test\r\n"),
+        "{}",
+        head
+    );
+
+    let (url, bodies) = body_recording_mock_server(vec![SCRAPED]);
+    tool(&url)
+        .run(args(&[
+            ("url", json!("https://example.com/other")),
+            ("formats", json!(["html", "links"])),
+        ]))
+        .unwrap();
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(
+        body,
+        json!({"url": "https://example.com/other", "formats": ["html", "links"]})
+    );
+}
+
+#[test]
+fn requested_formats_and_metadata_are_returned() {
+    let (url, _) = mock_server(vec![SCRAPED]);
+
+    let out = tool(&url)
+        .run(args(&[("formats", json!(["markdown", "links"]))]))
+        .unwrap();
+    assert_eq!(out["url"], "https://example.com/docs");
+    assert_eq!(out["title"], "Docs");
+    assert_eq!(out["status_code"], 200);
+    assert_eq!(out["markdown"], "# Docs");
+    assert_eq!(out["links"], json!(["https://example.com/a"]));
+    assert!(out.get("html").is_none());
+    assert_eq!(out["metadata"]["language"], "en");
+}
+
+#[test]
+fn missing_fields_become_null() {
+    let (url, _) = mock_server(vec![("200 OK", "", r#"{"success": true, "data": {}}"#)]);
+
+    let out = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(out["url"], "https://example.com/docs");
+    assert_eq!(out["markdown"], Value::Null);
+    assert_eq!(out["title"], Value::Null);
+    assert_eq!(out["metadata"], Value::Null);
+}
+
+#[test]
+fn unknown_or_empty_formats_fail_without_a_request() {
+    let (url, hits) = mock_server(vec![SCRAPED]);
+
+    let err = tool(&url)
+        .run(args(&[("formats", json!(["markdown", "pdf"]))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unsupported Firecrawl format 'pdf': expected one of markdown, html, links"
+    );
+    let err = tool(&url).run(args(&[("formats", json!([]))])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "formats must name at least one of markdown, html, links"
+    );
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
+
+#[test]
+fn firecrawl_errors_are_surfaced() {
+    let (url, _) = mock_server(vec![(
+        "402 Payment Required",
+        "",
+        r#"{"success": false, "error": "Insufficient credits"}"#,
+    )]);
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Firecrawl request failed (HTTP 402 Payment Required): Insufficient credits"
+    );
+
+    // A failure envelope is an error even with a 200.
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "",
+        r#"{"success": false, "error": "blocked"}"#,
+    )]);
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Firecrawl request failed (HTTP 200 OK): blocked"
+    );
+
+    let (url, _) = mock_server(vec![(
+        "502 Bad Gateway",
+        "Content-Type: text/html\r\n",
+        "<h1>502</h1>",
+    )]);
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Firecrawl request failed: HTTP 502 Bad Gateway"
+    );
+}
+
+#[test]
+fn missing_url_is_rejected() {
+    let err = FirecrawlScrapeWebsiteTool::new()
+        .with_api_key("fc-test")
+        .run(args(&[]))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Missing required argument: url");
+}
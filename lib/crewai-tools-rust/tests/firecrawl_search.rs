@@ -0,0 +1,132 @@
+//! FirecrawlSearchTool requests and result normalization against a scripted
+//! Firecrawl API.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::FirecrawlSearchTool;
+use serde_json::{json, Value};
+
+const HITS: common::Scripted = (
+    "200 OK",
+    "",
+    r##"{"success": true, "data": [
+        {"title": "Rust", "url": "https://rust-lang.org", "description": "A language", "markdown": "# Rust"},
+        {"metadata": {"title": "Book", "url": "https://doc.rust-lang.org/book", "description": "The book"}},
+        {"url": "https://example.com"}
+    ]}"##,
+);
+
+fn tool(url: &str) -> FirecrawlSearchTool {
+    FirecrawlSearchTool::new()
+        .with_base_url(url)
+        .with_api_key("fc-test")
+        .with_max_results(3)
+}
+
+#[test]
+fn request_carries_query_and_limit() {
+    let (url, _, requests) = recording_mock_server(vec![HITS]);
+    tool(&url).run(args(&[("query", json!("rust"))])).unwrap();
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /search "), "{}", head);
+    assert!(
+        head.contains("authorization: bearer fc-test\r\n"),
+        "{}",
+        head
+    );
+
+    let (url, bodies) = body_recording_mock_server(vec![HITS]);
+    tool(&url).run(args(&[("query", json!("rust"))])).unwrap();
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body, json!({"query": "rust", "limit": 3}));
+}
+
+#[test]
+fn scraping_requests_markdown_per_hit() {
+    let (url, bodies) = body_recording_mock_server(vec![HITS, HITS]);
+
+    tool(&url)
+        .with_scrape_results(true)
+        .run(args(&[("query", json!("rust"))]))
+        .unwrap();
+    // The runtime flag overrides the configured one.
+    tool(&url)
+        .with_scrape_results(true)
+        .run(args(&[
+            ("query", json!("rust")),
+            ("scrape_results", json!(false)),
+        ]))
+        .unwrap();
+
+    let bodies = bodies.lock().unwrap();
+    let scraped: Value = serde_json::from_str(&bodies[0]).unwrap();
+    assert_eq!(scraped["scrapeOptions"], json!({"formats": ["markdown"]}));
+    let plain: Value = serde_json::from_str(&bodies[1]).unwrap();
+    assert!(plain.get("scrapeOptions").is_none());
+}
+
+#[test]
+fn results_are_normalized() {
+    let (url, _) = mock_server(vec![HITS]);
+
+    let out = tool(&url).run(args(&[("query", json!("rust"))])).unwrap();
+    assert_eq!(out["query"], "rust");
+    assert_eq!(out["count"], 3);
+    assert_eq!(
+        out["results"],
+        json!([
+            {"title": "Rust", "url": "https://rust-lang.org", "description": "A language"},
+            {"title": "Book", "url": "https://doc.rust-lang.org/book", "description": "The book"},
+            {"title": null, "url": "https://example.com", "description": null},
+        ])
+    );
+}
+
+#[test]
+fn scraped_results_include_markdown() {
+    let (url, _) = mock_server(vec![HITS]);
+
+    let out = tool(&url)
+        .run(args(&[
+            ("query", json!("rust")),
+            ("scrape_results", json!(true)),
+        ]))
+        .unwrap();
+    assert_eq!(out["results"][0]["markdown"], "# Rust");
+    assert_eq!(out["results"][1]["markdown"], Value::Null);
+}
+
+#[test]
+fn empty_data_is_no_results() {
+    let (url, _) = mock_server(vec![("200 OK", "", r#"{"success": true, "data": []}"#)]);
+
+    let out = tool(&url).run(args(&[("query", json!("rust"))])).unwrap();
+    assert_eq!(out["count"], 0);
+    assert_eq!(out["results"], json!([]));
+}
+
+#[test]
+fn firecrawl_errors_are_surfaced() {
+    let (url, _) = mock_server(vec![(
+        "401 Unauthorized",
+        "",
+        r#"{"success": false, "error": "Invalid token"}"#,
+    )]);
+
+    let err = tool(&url)
+        .run(args(&[("query", json!("rust"))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Firecrawl request failed (HTTP 401 Unauthorized): Invalid token"
+    );
+}
+
+#[test]
+fn missing_query_is_rejected() {
+    let err = tool("http://127.0.0.1:9").run(args(&[])).unwrap_err();
+    assert_eq!(err.to_string(), "Missing required argument: query");
+}
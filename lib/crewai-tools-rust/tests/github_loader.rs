@@ -0,0 +1,131 @@
+//! GithubLoader listing, filtering and rate-limit handling against a mock API.
+
+#![cfg(feature = "rag")]
+
+mod common;
+
+use common::{mock_server, recording_mock_server};
+use crewai_tools::{BaseLoader, GithubLoader, GithubLoaderError};
+use serde_json::json;
+use std::sync::atomic::Ordering;
+
+const TREE: &str = r#"{"sha":"t1","truncated":false,"tree":[
+  {"path":"src","type":"tree","sha":"d1"},
+  {"path":"src/lib.rs","type":"blob","sha":"a1","size":12},
+  {"path":"README.md","type":"blob","sha":"a2","size":8},
+  {"path":"logo.png","type":"blob","sha":"a3","size":40},
+  {"path":"data.txt","type":"blob","sha":"a4","size":5000},
+  {"path":"notes.txt","type":"blob","sha":"a5","size":4}
+]}"#;
+
+#[test]
+fn loads_one_document_per_text_file() {
+    let (url, hits, heads) = recording_mock_server(vec![
+        ("200 OK", "", TREE),
+        ("200 OK", "Content-Type: text/plain\r\n", "fn main() {}"),
+        ("200 OK", "Content-Type: text/plain\r\n", "# Readme"),
+        ("200 OK", "Content-Type: text/plain\r\n", "bin\0"),
+    ]);
+
+    let documents = GithubLoader::new("octo/repo")
+        .with_github_token("ghp_test")
+        .with_max_file_size(1000)
+        .with_api_url(url)
+        .load()
+        .unwrap();
+
+    // logo.png is binary by extension, data.txt is too large, notes.txt has a NUL.
+    assert_eq!(hits.load(Ordering::SeqCst), 4);
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "fn main() {}");
+    assert_eq!(documents[0].metadata["path"], json!("src/lib.rs"));
+    assert_eq!(documents[0].metadata["sha"], json!("a1"));
+    assert_eq!(documents[0].metadata["size"], json!(12));
+    assert_eq!(
+        documents[0].metadata["source"],
+        json!("https://github.com/octo/repo/blob/main/src/lib.rs")
+    );
+    assert_eq!(documents[1].metadata["path"], json!("README.md"));
+
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("get /repos/octo/repo/git/trees/main?recursive=1 "));
+    assert!(heads[1].starts_with("get /repos/octo/repo/git/blobs/a1 "));
+    assert!(heads[1].contains("accept: application/vnd.github.raw"));
+    assert!(heads.iter().all(|h| h.contains("authorization: bearer ghp_test")));
+}
+
+#[test]
+fn extensions_and_max_files_limit_the_fetch() {
+    let (url, hits, heads) = recording_mock_server(vec![
+        ("200 OK", "", TREE),
+        ("200 OK", "Content-Type: text/plain\r\n", "fn main() {}"),
+    ]);
+
+    let documents = GithubLoader::new("octo/repo")
+        .with_github_token("ghp_test")
+        .with_branch("dev")
+        .with_extensions(vec![".rs".to_string(), "md".to_string()])
+        .with_max_files(1)
+        .with_api_url(url)
+        .load()
+        .unwrap();
+
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0].metadata["branch"], json!("dev"));
+    assert!(heads.lock().unwrap()[0].starts_with("get /repos/octo/repo/git/trees/dev?"));
+}
+
+#[test]
+fn rate_limit_mid_load_returns_the_partial_set() {
+    let (url, _) = mock_server(vec![
+        ("200 OK", "", TREE),
+        ("200 OK", "Content-Type: text/plain\r\n", "fn main() {}"),
+        (
+            "403 Forbidden",
+            "X-RateLimit-Remaining: 0\r\nX-RateLimit-Reset: 1700000000\r\n",
+            r#"{"message":"API rate limit exceeded"}"#,
+        ),
+    ]);
+
+    let err = GithubLoader::new("octo/repo")
+        .with_github_token("ghp_test")
+        .with_max_file_size(1000)
+        .with_api_url(url)
+        .load()
+        .unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("after loading 1 file(s); 2 file(s) were skipped"), "{}", message);
+    assert!(message.contains("1700000000"), "{}", message);
+    match err.downcast::<GithubLoaderError>().unwrap() {
+        GithubLoaderError::RateLimited {
+            documents,
+            skipped,
+            reset,
+        } => {
+            assert_eq!(documents.len(), 1);
+            assert_eq!(documents[0].metadata["path"], json!("src/lib.rs"));
+            assert_eq!(skipped, 2);
+            assert_eq!(reset, Some(1_700_000_000));
+        }
+        other => panic!("unexpected error: {}", other),
+    }
+}
+
+#[test]
+fn missing_branch_is_not_found() {
+    let (url, _) = mock_server(vec![("404 Not Found", "", r#"{"message":"Not Found"}"#)]);
+
+    let err = GithubLoader::new("octo/repo")
+        .with_github_token("ghp_test")
+        .with_branch("nope")
+        .with_api_url(url)
+        .load()
+        .unwrap_err();
+
+    assert!(matches!(
+        err.downcast_ref::<GithubLoaderError>(),
+        Some(GithubLoaderError::NotFound { branch, .. }) if branch == "nope"
+    ));
+}
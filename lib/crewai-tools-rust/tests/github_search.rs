@@ -0,0 +1,203 @@
+//! GithubSearchTool against a mock API: pagination, content types, auth and
+//! rate limits.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server, Scripted};
+use crewai_tools::GithubSearchTool;
+use serde_json::{json, Value};
+
+fn tool(base: &str) -> GithubSearchTool {
+    GithubSearchTool::new().with_base_url(base).with_repository("acme/widgets")
+}
+
+fn query(q: &str) -> std::collections::HashMap<String, Value> {
+    args(&[("search_query", json!(q))])
+}
+
+/// A REST search page of `count` issues numbered from `first`.
+fn issue_page(first: usize, count: usize, total: usize) -> &'static str {
+    let items: Vec<Value> = (first..first + count)
+        .map(|n| {
+            json!({"title": format!("Issue {}", n), "number": n, "state": "open",
+                   "html_url": format!("https://x/{}", n), "body": "b"})
+        })
+        .collect();
+    Box::leak(json!({"total_count": total, "items": items}).to_string().into_boxed_str())
+}
+
+#[test]
+fn rest_results_are_paged_up_to_max_results() {
+    let (base, _, requests) = recording_mock_server(vec![
+        ("200 OK", "", issue_page(1, 100, 130)),
+        ("200 OK", "", issue_page(101, 30, 130)),
+    ]);
+
+    let result = tool(&base)
+        .with_content_types(vec!["issues".into()])
+        .with_max_results(150)
+        .run(query("crash"))
+        .unwrap();
+
+    assert_eq!(result["count"], 130);
+    assert_eq!(result["results"][129]["number"], 130);
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].starts_with("get /search/issues?"), "{}", requests[0]);
+    assert!(requests[0].contains("per_page=100&page=1"), "{}", requests[0]);
+    assert!(requests[1].contains("per_page=100&page=2"), "{}", requests[1]);
+}
+
+#[test]
+fn paging_stops_at_the_reported_total() {
+    let (base, hits) = mock_server(vec![("200 OK", "", issue_page(1, 2, 2))]);
+
+    let result = tool(&base)
+        .with_content_types(vec!["issues".into()])
+        .with_max_results(2)
+        .run(query("x"))
+        .unwrap();
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn content_types_select_endpoints_and_normalize_items() {
+    let pull = r#"{"total_count": 1, "items": [{"title": "Fix it", "number": 7, "state": "closed",
+        "html_url": "https://x/pull/7", "text_matches": [{"fragment": "fixes the crash"}]}]}"#;
+    let (base, _, requests) =
+        recording_mock_server(vec![("200 OK", "", issue_page(1, 1, 1)), ("200 OK", "", pull)]);
+
+    // The argument overrides the configured content types.
+    let result = tool(&base)
+        .run(args(&[("search_query", json!("crash")), ("content_types", json!(["issues", "pulls"]))]))
+        .unwrap();
+
+    assert_eq!(result["content_types"], json!(["issues", "pulls"]));
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].contains("is%3aissue"), "{}", requests[0]);
+    assert!(requests[1].contains("is%3apr"), "{}", requests[1]);
+    assert_eq!(result["results"][0]["type"], "issue");
+    assert_eq!(
+        result["results"][1],
+        json!({"type": "pull_request", "title": "Fix it", "number": 7, "state": "closed",
+               "url": "https://x/pull/7", "snippet": "fixes the crash"})
+    );
+}
+
+#[test]
+fn unsupported_content_types_are_rejected_before_any_request() {
+    let (base, hits) = mock_server(vec![]);
+
+    let err = tool(&base).with_content_types(vec!["wiki".into()]).run(query("x")).unwrap_err();
+
+    assert!(err.to_string().contains("Unsupported content type 'wiki'"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn code_search_sends_the_token_and_requires_one() {
+    let code = r#"{"total_count": 1, "items": [{"name": "lib.rs", "path": "src/lib.rs",
+        "html_url": "https://x/lib.rs"}]}"#;
+    let (base, _, requests) = recording_mock_server(vec![("200 OK", "", code)]);
+
+    let result = tool(&base).with_github_token("secret").run(query("fn main")).unwrap();
+
+    assert_eq!(result["results"][0]["path"], "src/lib.rs");
+    assert!(requests.lock().unwrap()[0].contains("authorization: bearer secret"));
+
+    if std::env::var("GITHUB_TOKEN").is_err() {
+        let err = tool(&base).run(query("fn main")).unwrap_err();
+        assert!(err.to_string().contains("requires authentication"), "{}", err);
+        let err =
+            tool(&base).with_content_types(vec!["discussions".into()]).run(query("x")).unwrap_err();
+        assert!(err.to_string().contains("requires GITHUB_TOKEN"), "{}", err);
+    }
+}
+
+#[test]
+fn other_error_statuses_fail_with_the_body() {
+    let (base, _) = mock_server(vec![("401 Unauthorized", "", r#"{"message": "Bad credentials"}"#)]);
+
+    let err = tool(&base).with_github_token("wrong").run(query("x")).unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("GitHub API error (401"), "{}", message);
+    assert!(message.contains("Bad credentials"), "{}", message);
+}
+
+#[test]
+fn an_exhausted_rate_limit_stops_the_search_and_is_reported() {
+    let limited: Scripted = (
+        "403 Forbidden",
+        "x-ratelimit-resource: search\r\nx-ratelimit-limit: 10\r\nx-ratelimit-remaining: 0\r\nx-ratelimit-reset: 1700000000\r\n",
+        r#"{"message": "API rate limit exceeded"}"#,
+    );
+    let (base, hits) = mock_server(vec![limited]);
+
+    let result = tool(&base)
+        .with_content_types(vec!["issues".into(), "pulls".into()])
+        .run(query("x"))
+        .unwrap();
+
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(result["count"], 0);
+    assert_eq!(result["content_types"], json!(["issues"]));
+    assert_eq!(
+        result["rate_limit"],
+        json!({"resource": "search", "limit": 10, "remaining": 0, "reset": 1700000000u64, "exhausted": true})
+    );
+}
+
+#[test]
+fn a_403_with_quota_left_is_an_error() {
+    let (base, _) = mock_server(vec![("403 Forbidden", "x-ratelimit-remaining: 9\r\n", "{}")]);
+
+    let err = tool(&base).with_content_types(vec!["issues".into()]).run(query("x")).unwrap_err();
+
+    assert!(err.to_string().contains("GitHub API error (403"), "{}", err);
+}
+
+#[test]
+fn discussions_page_through_graphql_cursors() {
+    let first = r#"{"data": {"search": {"pageInfo": {"hasNextPage": true, "endCursor": "c1"},
+        "nodes": [{"title": "Q1", "url": "https://x/d/1", "number": 1, "bodyText": "how?"}, {}]}}}"#;
+    let second = r#"{"data": {"search": {"pageInfo": {"hasNextPage": false, "endCursor": "c2"},
+        "nodes": [{"title": "Q2", "url": "https://x/d/2", "number": 2, "bodyText": "why?"}]}}}"#;
+    let (base, bodies) = body_recording_mock_server(vec![("200 OK", "", first), ("200 OK", "", second)]);
+
+    let result = tool(&base)
+        .with_github_token("secret")
+        .with_content_types(vec!["discussions".into()])
+        .with_max_results(5)
+        .run(query("setup"))
+        .unwrap();
+
+    let titles: Vec<&str> =
+        result["results"].as_array().unwrap().iter().map(|r| r["title"].as_str().unwrap()).collect();
+    assert_eq!(titles, ["Q1", "Q2"]);
+    assert_eq!(result["results"][0]["type"], "discussion");
+    let bodies: Vec<Value> =
+        bodies.lock().unwrap().iter().map(|b| serde_json::from_str(b).unwrap()).collect();
+    assert_eq!(bodies[0]["variables"], json!({"q": "setup repo:acme/widgets", "first": 5, "after": null}));
+    assert_eq!(bodies[1]["variables"]["after"], "c1");
+    assert_eq!(bodies[1]["variables"]["first"], 4);
+}
+
+#[test]
+fn graphql_errors_fail_the_search() {
+    let (base, _) = mock_server(vec![("200 OK", "", r#"{"errors": [{"message": "bad query"}]}"#)]);
+
+    let err = tool(&base)
+        .with_github_token("secret")
+        .with_content_types(vec!["discussions".into()])
+        .run(query("x"))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("GitHub GraphQL search failed"), "{}", err);
+}
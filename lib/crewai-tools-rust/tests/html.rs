@@ -0,0 +1,37 @@
+//! HTML to Markdown/text conversion shared by the scraping tools and the RAG
+//! loaders.
+
+#![cfg(any(feature = "rag", feature = "web_scraping"))]
+
+use crewai_tools::html::{html_to_markdown, page_to_markdown, page_to_text};
+
+#[test]
+fn tables_become_pipe_tables() {
+    let html = "<table>\
+        <tr><th>Plan</th><th>Price</th></tr>\
+        <tr><td>Free</td><td>$0</td></tr>\
+        <tr><td><a href=\"/pro\">Pro</a></td><td>$10 | month</td></tr>\
+        </table>";
+    assert_eq!(
+        html_to_markdown(html),
+        "| Plan | Price |\n| --- | --- |\n| Free | $0 |\n| [Pro](/pro) | $10 \\| month |"
+    );
+}
+
+#[test]
+fn thead_row_leads_and_short_rows_are_padded() {
+    let html = "<table><tbody><tr><td>a</td></tr></tbody>\
+        <thead><tr><td>x</td><td>y</td></tr></thead></table>";
+    assert_eq!(
+        html_to_markdown(html),
+        "| x | y |\n| --- | --- |\n| a |  |"
+    );
+}
+
+#[test]
+fn page_conversions_drop_chrome() {
+    let html = "<nav>Home</nav><h1>Title</h1><p>Body <b>bold</b></p>\
+        <aside>Ads</aside><footer>(c)</footer>";
+    assert_eq!(page_to_markdown(html), "# Title\n\nBody **bold**");
+    assert_eq!(page_to_text(html), "Title\n\nBody bold");
+}
@@ -0,0 +1,158 @@
+//! HyperbrowserLoadTool against a scripted jobs API.
+
+#![cfg(feature = "browser")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::HyperbrowserLoadTool;
+use serde_json::{json, Value};
+
+const STARTED: common::Scripted = ("200 OK", "", r#"{"jobId": "job-1"}"#);
+const RUNNING: common::Scripted = ("200 OK", "", r#"{"status": "running"}"#);
+const COMPLETED: common::Scripted = ("200 OK", "", r#"{"status": "completed"}"#);
+
+fn tool(url: &str) -> HyperbrowserLoadTool {
+    HyperbrowserLoadTool::new()
+        .with_base_url(url)
+        .with_api_key("hb_test")
+        .with_poll_interval_ms(10)
+}
+
+#[test]
+fn scrape_polls_until_the_job_completes() {
+    let job = (
+        "200 OK",
+        "",
+        r##"{"jobId": "job-1", "status": "completed", "data": {
+            "metadata": {"title": "Example Domain", "sourceURL": "https://example.com"},
+            "markdown": "# Example Domain"}}"##,
+    );
+    let (url, _, heads) = recording_mock_server(vec![STARTED, RUNNING, COMPLETED, job]);
+
+    let result = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap();
+
+    assert_eq!(result["operation"], "scrape");
+    assert_eq!(result["job_id"], "job-1");
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["markdown"], "# Example Domain");
+    assert_eq!(result["metadata"]["title"], "Example Domain");
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /api/scrape "), "{}", heads[0]);
+    assert!(heads[0].contains("x-api-key: hb_test"), "{}", heads[0]);
+    assert!(heads[1].starts_with("get /api/scrape/job-1/status "), "{}", heads[1]);
+    assert!(heads[3].starts_with("get /api/scrape/job-1 "), "{}", heads[3]);
+}
+
+#[test]
+fn request_carries_formats_and_params() {
+    let job = ("200 OK", "", r#"{"data": {"metadata": {}, "html": "<h1>Hi</h1>"}}"#);
+    let (url, bodies) = body_recording_mock_server(vec![STARTED, COMPLETED, job]);
+
+    let result = tool(&url)
+        .run(args(&[
+            ("url", json!("https://example.com")),
+            ("formats", json!(["html"])),
+            ("params", json!({"sessionOptions": {"useProxy": true}})),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["html"], "<h1>Hi</h1>");
+    let sent: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(
+        sent,
+        json!({
+            "url": "https://example.com",
+            "scrapeOptions": {"formats": ["html"]},
+            "sessionOptions": {"useProxy": true},
+        })
+    );
+}
+
+#[test]
+fn crawl_collects_every_batch() {
+    let batch_1 = (
+        "200 OK",
+        "",
+        r#"{"status": "completed", "totalPageBatches": 2, "data": [
+            {"url": "https://example.com/", "status": "completed", "metadata": {}, "markdown": "home"}]}"#,
+    );
+    let batch_2 = (
+        "200 OK",
+        "",
+        r#"{"status": "completed", "totalPageBatches": 2, "data": [
+            {"url": "https://example.com/a", "status": "failed", "metadata": {}, "error": "HTTP 404"}]}"#,
+    );
+    let (url, _, heads) = recording_mock_server(vec![STARTED, COMPLETED, batch_1, batch_2]);
+
+    let result = tool(&url)
+        .run(args(&[
+            ("url", json!("https://example.com")),
+            ("operation", json!("crawl")),
+            ("max_pages", json!(5)),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["total_pages"], 2);
+    assert_eq!(result["pages"][0]["markdown"], "home");
+    assert_eq!(result["pages"][1]["error"], "HTTP 404");
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /api/crawl "), "{}", heads[0]);
+    assert!(heads[2].starts_with("get /api/crawl/job-1?page=1 "), "{}", heads[2]);
+    assert!(heads[3].starts_with("get /api/crawl/job-1?page=2 "), "{}", heads[3]);
+}
+
+#[test]
+fn crawl_out_of_time_returns_partial_pages() {
+    let partial = (
+        "200 OK",
+        "",
+        r#"{"status": "running", "totalPageBatches": 1, "data": [
+            {"url": "https://example.com/", "status": "completed", "metadata": {}, "markdown": "home"}]}"#,
+    );
+    let (url, _) = mock_server(vec![STARTED, RUNNING, partial]);
+
+    let result = tool(&url)
+        .with_timeout(Duration::ZERO)
+        .run(args(&[("url", json!("https://example.com")), ("operation", json!("crawl"))]))
+        .unwrap();
+
+    assert_eq!(result["status"], "timed_out");
+    assert_eq!(result["total_pages"], 1);
+    assert_eq!(result["pages"][0]["url"], "https://example.com/");
+}
+
+#[test]
+fn scrape_out_of_time_names_the_job() {
+    let (url, _) = mock_server(vec![STARTED, RUNNING]);
+
+    let err = tool(&url)
+        .with_timeout(Duration::ZERO)
+        .run(args(&[("url", json!("https://example.com"))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("job-1 still running"), "{}", err);
+}
+
+#[test]
+fn failed_jobs_report_their_error() {
+    let failed = ("200 OK", "", r#"{"status": "failed"}"#);
+    let job = ("200 OK", "", r#"{"status": "failed", "error": "Navigation timeout"}"#);
+    let (url, _) = mock_server(vec![STARTED, failed, job]);
+
+    let err = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap_err();
+
+    assert!(err.to_string().contains("Navigation timeout"), "{}", err);
+}
+
+#[test]
+fn rejected_key_is_reported() {
+    let (url, _) = mock_server(vec![("401 Unauthorized", "", r#"{"message": "Invalid API key"}"#)]);
+
+    let err = tool(&url).run(args(&[("url", json!("https://example.com"))])).unwrap_err();
+
+    assert!(err.to_string().contains("rejected the API key"), "{}", err);
+}
@@ -0,0 +1,153 @@
+//! Language detection on English, German, Japanese and mixed-language text,
+//! and the per-chunk stamps and routing built on it.
+
+#![cfg(feature = "rag")]
+
+use std::sync::Arc;
+
+use crewai_tools::{
+    detect_language, BaseChunker, BaseLoader, Document, EmbeddingService, LowConfidencePolicy,
+    RagPipeline, TextLoader, WebChunker,
+};
+
+const EN: &str = "The history of the printing press is one of the most important stories in \
+    the development of modern Europe. It was invented in the fifteenth century, and within a few \
+    decades the new machines were running in every large city. This is why books became cheap \
+    enough for ordinary people to own, and it is also the reason that ideas could spread faster \
+    than the authorities were able to control them.";
+
+const DE: &str = "Die Geschichte der Druckerpresse ist eine der wichtigsten Geschichten in der \
+    Entwicklung des modernen Europa. Sie wurde im fünfzehnten Jahrhundert erfunden, und nach \
+    wenigen Jahrzehnten liefen die neuen Maschinen in jeder großen Stadt. Deshalb wurden Bücher \
+    auch für einfache Leute erschwinglich, und das ist auch der Grund, warum sich Ideen schneller \
+    verbreiten konnten, als die Obrigkeit sie kontrollieren wollte.";
+
+const JA: &str = "活版印刷の歴史は、近代ヨーロッパの発展においてもっとも重要な物語のひとつです。\
+    十五世紀に発明され、数十年のうちに新しい機械はすべての大都市で動いていました。\
+    そのため本は普通の人々にも手の届くものになり、思想は権力者が管理できるよりも速く広まりました。";
+
+/// One short sentence of each language: no language clearly wins.
+const MIXED: &str = "The press was invented in the fifteenth century and it spread. \
+    Die Presse wurde im Jahrhundert erfunden und ist auch nicht teuer. \
+    活版印刷は十五世紀に発明されました。";
+
+#[test]
+fn detects_each_fixture_language_with_high_confidence() {
+    for (text, code) in [(EN, "en"), (DE, "de"), (JA, "ja")] {
+        let detected = detect_language(text).unwrap();
+        assert_eq!(detected.code, code);
+        assert!(detected.confidence >= 0.8, "{}: {}", code, detected.confidence);
+    }
+}
+
+#[test]
+fn han_without_kana_is_chinese_not_japanese() {
+    let detected = detect_language("印刷术的历史是近代欧洲发展中最重要的故事之一").unwrap();
+    assert_eq!(detected.code, "zh");
+}
+
+#[test]
+fn mixed_text_has_lower_confidence_than_any_pure_text() {
+    let mixed = detect_language(MIXED).unwrap();
+    let lowest_pure = [EN, DE, JA]
+        .iter()
+        .map(|t| detect_language(t).unwrap().confidence)
+        .fold(f32::MAX, f32::min);
+
+    assert!(
+        mixed.confidence < lowest_pure,
+        "mixed {} vs pure {}",
+        mixed.confidence,
+        lowest_pure
+    );
+    assert!(mixed.confidence < 0.5, "{}", mixed.confidence);
+}
+
+#[test]
+fn too_little_text_is_not_detected() {
+    assert!(detect_language("").is_none());
+    assert!(detect_language("Hi there").is_none());
+    assert!(detect_language("1234 5678 !!!").is_none());
+}
+
+#[test]
+fn chunks_of_a_mixed_document_carry_their_own_language() {
+    let document = Document::new(format!("{}\n\n{}\n\n{}", EN, DE, JA));
+    let chunker = WebChunker::new().with_chunk_size(450).with_strip_html(false);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    let languages: Vec<&str> = chunks
+        .iter()
+        .map(|c| c.metadata["language"].as_str().unwrap())
+        .collect();
+    assert_eq!(languages, ["en", "de", "ja"]);
+    for chunk in &chunks {
+        let confidence = chunk.metadata["language_confidence"].as_f64().unwrap();
+        assert!(confidence >= 0.8, "{}: {}", chunk.metadata["language"], confidence);
+    }
+}
+
+#[test]
+fn text_loader_stamps_the_document_language() {
+    let dir = std::env::temp_dir().join(format!("crewai_language_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("de.txt");
+    std::fs::write(&path, DE).unwrap();
+
+    let documents = TextLoader::new(path.to_string_lossy()).load().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(documents[0].metadata["language"], "de");
+}
+
+/// Embeds every text as the same vector; routing is all that is under test.
+struct ConstantEmbedder;
+
+impl EmbeddingService for ConstantEmbedder {
+    fn embed(&self, _text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        Ok(vec![1.0, 0.0])
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        texts.iter().map(|t| self.embed(t)).collect()
+    }
+
+    fn model_name(&self) -> &str {
+        "constant"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+}
+
+/// Serves one document per text.
+struct Fixtures(Vec<&'static str>);
+
+impl BaseLoader for Fixtures {
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
+        Ok(self.0.iter().map(|t| Document::new(*t)).collect())
+    }
+
+    fn loader_name(&self) -> &str {
+        "Fixtures"
+    }
+}
+
+#[test]
+fn pipeline_sets_low_confidence_chunks_aside() {
+    let pipeline = RagPipeline::builder()
+        .loader(Fixtures(vec![EN, DE, JA, MIXED]))
+        .chunker(WebChunker::new().with_strip_html(false))
+        .embedder(Arc::new(ConstantEmbedder))
+        .language_threshold(0.8, LowConfidencePolicy::SeparateIndex)
+        .build();
+
+    let summary = pipeline.ingest().unwrap();
+
+    assert_eq!(summary.chunks, 4);
+    assert_eq!(summary.stored, 3);
+    assert_eq!(summary.low_confidence, 1);
+    assert_eq!(pipeline.low_confidence_store().len(), 1);
+}
@@ -0,0 +1,115 @@
+//! LinkupSearchTool request shape, depth and output-type handling, and API
+//! errors against a mock server.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::LinkupSearchTool;
+use serde_json::{json, Value};
+
+const RESULTS: &str = r#"{"results": [
+  {"type": "text", "name": "Rust", "url": "https://rust-lang.org", "content": "A language"},
+  {"type": "text", "name": "Cargo", "url": "https://doc.rust-lang.org/cargo"},
+  {"type": "text", "name": "Crates", "url": "https://crates.io", "content": "Registry"}
+]}"#;
+
+fn tool(base: &str) -> LinkupSearchTool {
+    LinkupSearchTool::new().with_api_key("lk-key").with_base_url(base)
+}
+
+#[test]
+fn posts_query_depth_and_output_type_with_bearer_auth() {
+    let (base, _, requests) = recording_mock_server(vec![("200 OK", "", RESULTS)]);
+
+    tool(&base).run(args(&[("query", json!("rust"))])).unwrap();
+
+    let request = &requests.lock().unwrap()[0];
+    assert!(request.starts_with("post /v1/search "), "{}", request);
+    assert!(request.contains("authorization: bearer lk-key"), "{}", request);
+
+    let (base, bodies) = body_recording_mock_server(vec![("200 OK", "", RESULTS)]);
+    tool(&base).run(args(&[("query", json!("rust"))])).unwrap();
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body, json!({"q": "rust", "depth": "standard", "outputType": "searchResults"}));
+}
+
+#[test]
+fn search_results_are_normalized_and_capped() {
+    let (base, _) = mock_server(vec![("200 OK", "", RESULTS)]);
+
+    let result = tool(&base).with_max_results(2).run(args(&[("query", json!("rust"))])).unwrap();
+
+    assert_eq!(result["depth"], "standard");
+    assert_eq!(result["total_results"], 2);
+    assert_eq!(
+        result["results"],
+        json!([
+            {"name": "Rust", "url": "https://rust-lang.org", "content": "A language"},
+            {"name": "Cargo", "url": "https://doc.rust-lang.org/cargo", "content": ""},
+        ])
+    );
+}
+
+#[test]
+fn per_call_depth_and_sourced_answer_override_the_defaults() {
+    let answer = r#"{"answer": "Rust is a language.", "sources": [
+      {"name": "Rust", "url": "https://rust-lang.org", "snippet": "Fast and safe"}
+    ]}"#;
+    let (base, bodies) = body_recording_mock_server(vec![("200 OK", "", answer)]);
+
+    let result = tool(&base)
+        .run(args(&[
+            ("query", json!("what is rust")),
+            ("depth", json!("deep")),
+            ("output_type", json!("sourcedAnswer")),
+        ]))
+        .unwrap();
+
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["depth"], "deep");
+    assert_eq!(body["outputType"], "sourcedAnswer");
+    assert_eq!(
+        result,
+        json!({
+            "query": "what is rust",
+            "depth": "deep",
+            "answer": "Rust is a language.",
+            "sources": [{"name": "Rust", "url": "https://rust-lang.org", "content": "Fast and safe"}],
+        })
+    );
+}
+
+#[test]
+fn invalid_depth_or_output_type_fails_before_any_request() {
+    let (base, hits) = mock_server(vec![]);
+
+    let err = tool(&base).with_depth("shallow").run(args(&[("query", json!("x"))])).unwrap_err();
+    assert!(err.to_string().contains("Invalid depth 'shallow'"), "{}", err);
+    let err =
+        tool(&base).run(args(&[("query", json!("x")), ("output_type", json!("answer"))])).unwrap_err();
+    assert!(err.to_string().contains("Invalid output_type 'answer'"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn api_errors_carry_the_status_and_message() {
+    let (base, _) = mock_server(vec![
+        ("401 Unauthorized", "", r#"{"error": {"message": "Invalid API key"}}"#),
+        ("429 Too Many Requests", "", r#"{"message": "Slow down"}"#),
+        ("502 Bad Gateway", "Content-Type: text/html\r\n", "<html>Bad gateway</html>"),
+    ]);
+    let tool = tool(&base);
+
+    for expected in [
+        "(401 Unauthorized): Invalid API key",
+        "(429 Too Many Requests): Slow down",
+        "(502 Bad Gateway): <html>Bad gateway</html>",
+    ] {
+        let err = tool.run(args(&[("query", json!("x"))])).unwrap_err();
+        assert_eq!(err.to_string(), format!("Linkup API error {}", expected));
+    }
+}
@@ -0,0 +1,233 @@
+//! PlaywrightBrowserTool against a fake DevTools browser.
+
+#![cfg(feature = "browser")]
+
+mod common;
+
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use common::args;
+use crewai_tools::PlaywrightBrowserTool;
+use serde_json::{json, Value};
+use tungstenite::Message;
+
+/// Serve DevTools connections (one per run) that answer the commands the
+/// tool sends and report the page loaded after navigating. Returns its URL
+/// and every command received.
+fn fake_browser() -> (String, Arc<Mutex<Vec<Value>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}/devtools/browser/b1", listener.local_addr().unwrap());
+    let commands = Arc::new(Mutex::new(Vec::new()));
+    let log = commands.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let log = log.clone();
+            std::thread::spawn(move || {
+                let mut socket = tungstenite::accept(stream.unwrap()).unwrap();
+                while let Ok(Message::Text(text)) = socket.read() {
+                    let command: Value = serde_json::from_str(&text).unwrap();
+                    log.lock().unwrap().push(command.clone());
+                    let method = command["method"].as_str().unwrap();
+                    let result = match method {
+                        "Target.createTarget" => json!({"targetId": "T1"}),
+                        "Target.attachToTarget" => json!({"sessionId": "S1"}),
+                        "Page.navigate" => json!({"frameId": "F1", "loaderId": "L1"}),
+                        "Page.captureScreenshot" => json!({"data": "iVBORw0KGgo="}),
+                        "Runtime.evaluate" => {
+                            let expression = command["params"]["expression"].as_str().unwrap();
+                            json!({"result": {"value": evaluate(expression)}})
+                        }
+                        _ => json!({}),
+                    };
+                    let reply = json!({"id": command["id"], "result": result});
+                    socket.send(Message::Text(reply.to_string())).unwrap();
+                    if method == "Page.navigate" {
+                        // A stale load from the previous page comes first.
+                        let events = [("load", "L0"), ("DOMContentLoaded", "L1"), ("load", "L1")];
+                        for (name, loader) in events {
+                            let event = json!({
+                                "method": "Page.lifecycleEvent",
+                                "params": {"frameId": "F1", "loaderId": loader, "name": name},
+                                "sessionId": "S1",
+                            });
+                            socket.send(Message::Text(event.to_string())).unwrap();
+                        }
+                    }
+                }
+            });
+        }
+    });
+    (url, commands)
+}
+
+fn evaluate(expression: &str) -> Value {
+    match expression {
+        "location.href" => json!("https://example.com/login"),
+        "document.title" => json!("Log in"),
+        "document.documentElement.outerHTML" => json!("<html><form></form></html>"),
+        e if e.contains("\"#missing\"") => Value::Null,
+        e if e.starts_with("!!document.querySelector") => json!(!e.contains("\"#never\"")),
+        e if e.contains("getBoundingClientRect") => json!({"x": 40, "y": 12}),
+        e if e.contains("el.focus()") => json!(true),
+        e if e.contains("el.innerText") => json!("Welcome back"),
+        _ => json!("Log in\nWelcome back"),
+    }
+}
+
+fn methods(commands: &Arc<Mutex<Vec<Value>>>) -> Vec<String> {
+    commands
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|c| c["method"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn runs_commands_and_keeps_the_session_open() {
+    let (browser, commands) = fake_browser();
+    let tool = PlaywrightBrowserTool::new().with_browser_url(&browser);
+
+    let result = tool
+        .run(args(&[(
+            "commands",
+            json!([
+                {"action": "navigate", "url": "https://example.com/login"},
+                {"action": "wait_for_selector", "selector": "form"},
+                {"action": "text"},
+                {"action": "html"},
+                {"action": "screenshot", "full_page": true},
+            ]),
+        )]))
+        .unwrap();
+
+    assert_eq!(result["closed"], false);
+    assert_eq!(result["url"], "https://example.com/login");
+    let results = result["results"].as_array().unwrap();
+    assert_eq!(
+        results[0],
+        json!({"action": "navigate", "url": "https://example.com/login", "title": "Log in"})
+    );
+    assert_eq!(results[2]["content"], "Log in\nWelcome back");
+    assert_eq!(results[3]["content"], "<html><form></form></html>");
+    assert_eq!(results[4]["content"], "iVBORw0KGgo=");
+    let methods = methods(&commands);
+    assert_eq!(methods.iter().filter(|m| *m == "Target.createTarget").count(), 1);
+    assert!(!methods.contains(&"Target.closeTarget".to_string()), "{:?}", methods);
+}
+
+#[test]
+fn session_id_continues_on_the_same_page() {
+    let (browser, commands) = fake_browser();
+    let tool = PlaywrightBrowserTool::new().with_browser_url(&browser);
+    let first = tool
+        .run(args(&[("commands", json!([{"action": "navigate", "url": "https://example.com"}]))]))
+        .unwrap();
+    let session_id = first["session_id"].clone();
+
+    let second = tool
+        .clone()
+        .run(args(&[
+            ("session_id", session_id.clone()),
+            (
+                "commands",
+                json!([
+                    {"action": "fill", "selector": "#user", "value": "ada"},
+                    {"action": "click", "selector": "button[type=submit]"},
+                    {"action": "text", "selector": "h1"},
+                ]),
+            ),
+        ]))
+        .unwrap();
+
+    assert_eq!(second["session_id"], session_id);
+    assert_eq!(second["results"][2]["content"], "Welcome back");
+    let commands = commands.lock().unwrap();
+    let created = commands.iter().filter(|c| c["method"] == "Target.createTarget").count();
+    assert_eq!(created, 1);
+    let attaches: Vec<&Value> =
+        commands.iter().filter(|c| c["method"] == "Target.attachToTarget").collect();
+    assert_eq!(attaches.len(), 2);
+    assert!(attaches.iter().all(|c| c["params"]["targetId"] == "T1"));
+    let typed = commands.iter().find(|c| c["method"] == "Input.insertText").unwrap();
+    assert_eq!(typed["params"]["text"], "ada");
+    let clicks: Vec<&Value> =
+        commands.iter().filter(|c| c["method"] == "Input.dispatchMouseEvent").collect();
+    assert_eq!(clicks.len(), 3);
+    assert_eq!(clicks[1]["params"], json!({
+        "type": "mousePressed", "x": 40, "y": 12, "button": "left", "clickCount": 1,
+    }));
+}
+
+#[test]
+fn close_ends_the_session() {
+    let (browser, commands) = fake_browser();
+    let tool = PlaywrightBrowserTool::new().with_browser_url(&browser);
+    let first = tool
+        .run(args(&[("commands", json!([{"action": "navigate", "url": "https://example.com"}]))]))
+        .unwrap();
+    let session_id = first["session_id"].clone();
+
+    let closed = tool
+        .run(args(&[
+            ("session_id", session_id.clone()),
+            ("commands", json!([{"action": "close"}])),
+        ]))
+        .unwrap();
+    assert_eq!(closed["closed"], true);
+    assert_eq!(closed["url"], Value::Null);
+    assert!(methods(&commands).contains(&"Target.closeTarget".to_string()));
+
+    let err = tool
+        .run(args(&[("session_id", session_id), ("commands", json!([{"action": "text"}]))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("Unknown browser session"), "{}", err);
+}
+
+#[test]
+fn missing_elements_and_selector_timeouts_fail_the_command() {
+    let (browser, _) = fake_browser();
+    let tool = PlaywrightBrowserTool::new().with_browser_url(&browser);
+
+    let err = tool
+        .run(args(&[("commands", json!([{"action": "click", "selector": "#missing"}]))]))
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("command 1 failed"), "{}", message);
+    assert!(message.contains("No element matches selector '#missing'"), "{}", message);
+
+    let err = tool
+        .run(args(&[(
+            "commands",
+            json!([{"action": "wait_for_selector", "selector": "#never", "timeout_ms": 300}]),
+        )]))
+        .unwrap_err();
+    assert!(err.to_string().contains("Timed out waiting for selector '#never'"), "{}", err);
+}
+
+#[test]
+fn commands_are_checked_before_starting_a_browser() {
+    // Nothing listens here, so any connection attempt would fail differently.
+    let tool =
+        PlaywrightBrowserTool::new().with_browser_url("ws://127.0.0.1:1/devtools/browser/x");
+    let cases = [
+        (json!([]), "non-empty array"),
+        (json!([{"action": "scroll"}]), "unknown action 'scroll'"),
+        (json!([{"action": "fill", "selector": "#q"}]), "needs a 'value' string"),
+        (json!([{"action": "close"}, {"action": "text"}]), "must be the last command"),
+    ];
+    for (commands, expected) in cases {
+        let err = tool.run(args(&[("commands", commands)])).unwrap_err();
+        assert!(err.to_string().contains(expected), "{}", err);
+    }
+}
+
+#[test]
+fn reports_a_missing_chromium_binary() {
+    let err = PlaywrightBrowserTool::new()
+        .with_chrome_path("/nonexistent/chromium")
+        .run(args(&[("commands", json!([{"action": "text"}]))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("Could not start Chromium"), "{}", err);
+}
@@ -0,0 +1,109 @@
+//! MarkdownLoader frontmatter, heading sections and code fences.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{BaseLoader, DirectoryLoader, MarkdownLoader, MetadataCondition};
+use serde_json::json;
+
+/// A temp Markdown file holding `contents`.
+fn md_file(name: &str, contents: &str) -> String {
+    let path = std::env::temp_dir().join(format!("crewai-md-{}-{}.md", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+const GUIDE: &str = "---
+title: Install guide
+tags: [setup, cli]
+draft: false
+---
+Intro before any heading.
+
+# Install
+
+Run this:
+
+```sh
+# not a heading
+cargo install crewai
+```
+
+## From source
+
+Clone it.
+
+# Usage
+
+Call `run`.
+";
+
+#[test]
+fn splits_on_top_level_headings_with_frontmatter_metadata() {
+    let path = md_file("guide", GUIDE);
+
+    let documents = MarkdownLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 3);
+    assert_eq!(documents[0].content, "Intro before any heading.");
+    assert!(!documents[0].metadata.contains_key("heading"));
+    assert_eq!(documents[0].metadata["breadcrumb"], json!([]));
+
+    let install = &documents[1];
+    assert!(install.content.starts_with("# Install\n\nRun this:"));
+    assert!(install.content.contains("```sh\n# not a heading\ncargo install crewai\n```"));
+    assert!(install.content.ends_with("## From source\n\nClone it."));
+    assert_eq!(install.metadata["heading"], json!("Install"));
+    assert_eq!(install.metadata["heading_level"], json!(1));
+    assert_eq!(install.metadata["section_index"], json!(1));
+    assert_eq!(install.metadata["source"], json!(path));
+
+    for document in &documents {
+        assert!(!document.content.contains("title: Install guide"));
+        assert_eq!(document.metadata["title"], json!("Install guide"));
+        assert_eq!(document.metadata["tags"], json!(["setup", "cli"]));
+        assert_eq!(document.metadata["draft"], json!(false));
+    }
+    let drafts = MetadataCondition::equals("draft", json!(false));
+    assert!(drafts.matches(&documents[2].metadata));
+}
+
+#[test]
+fn split_level_adds_sections_with_breadcrumbs() {
+    let path = md_file("levels", GUIDE);
+
+    let documents = MarkdownLoader::new(&path).with_split_level(2).load().unwrap();
+
+    assert_eq!(documents.len(), 4);
+    assert_eq!(documents[1].content.lines().last(), Some("```"));
+    let source = &documents[2];
+    assert_eq!(source.content, "## From source\n\nClone it.");
+    assert_eq!(source.metadata["breadcrumb"], json!(["Install", "From source"]));
+    assert_eq!(source.metadata["heading_path"], json!("Install > From source"));
+    assert_eq!(source.metadata["heading_level"], json!(2));
+    assert_eq!(documents[3].metadata["heading_path"], json!("Usage"));
+}
+
+#[test]
+fn default_split_level_is_the_shallowest_heading() {
+    let path = md_file("shallow", "## One\n\nfirst\n\n### Deep\n\nmore\n\n## Two\n\nsecond\n");
+
+    let documents = MarkdownLoader::new(&path).load().unwrap();
+
+    let headings: Vec<_> = documents.iter().map(|d| d.metadata["heading"].clone()).collect();
+    assert_eq!(headings, [json!("One"), json!("Two")]);
+    assert!(!documents[0].metadata.contains_key("title"));
+}
+
+#[test]
+fn directory_loader_dispatches_markdown() {
+    let root = std::env::temp_dir().join(format!("crewai-md-dir-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("a.md"), "# A\n\none\n\n# B\n\ntwo\n").unwrap();
+    std::fs::write(root.join("b.mdx"), "# C\n\nthree\n").unwrap();
+
+    let documents = DirectoryLoader::new(root.to_string_lossy()).load().unwrap();
+
+    let headings: Vec<_> = documents.iter().map(|d| d.metadata["heading"].clone()).collect();
+    assert_eq!(headings, [json!("A"), json!("B"), json!("C")]);
+}
@@ -0,0 +1,124 @@
+//! MdxSearchTool frontmatter, ESM and JSX stripping, heading paths and code blocks.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::args;
+use crewai_tools::MdxSearchTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+const GUIDE: &str = r#"---
+title: Secret frontmatter title
+---
+import { Tabs, Tab } from '@components'
+export const meta = { hidden: true }
+
+# Setup
+
+Install the CLI first.
+
+<Tabs items={["npm", "cargo"]}>
+<Tab value="npm">
+Run the npm installer.
+</Tab>
+</Tabs>
+
+## Linux
+
+Use the <Note kind="tip">static</Note> binary on <b>Linux</b>.
+
+```bash
+curl -sSf install.sh | sh
+```
+
+# Usage
+
+<Callout
+  title={`multi ${"line"}`}
+>
+Pass a config file.
+</Callout>
+"#;
+
+fn search(source: &str, query: &str) -> Value {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("guide.mdx");
+    std::fs::write(&path, source).unwrap();
+    MdxSearchTool::new()
+        .with_file_path(path.to_string_lossy())
+        .run(args(&[("search_query", json!(query))]))
+        .unwrap()
+}
+
+#[test]
+fn frontmatter_esm_and_jsx_attributes_are_not_searched() {
+    for query in ["frontmatter", "components", "hidden", "items", "kind", "multi"] {
+        assert_eq!(search(GUIDE, query)["count"], 0, "{}", query);
+    }
+}
+
+#[test]
+fn text_between_jsx_tags_is_kept() {
+    let result = search(GUIDE, "npm installer");
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["text"], "Run the npm installer.");
+    assert_eq!(result["results"][0]["line"], 13);
+
+    let result = search(GUIDE, "static binary");
+    assert_eq!(result["results"][0]["text"], "Use the static binary on <b>Linux</b>.");
+
+    let result = search(GUIDE, "config");
+    assert_eq!(result["results"][0]["text"], "Pass a config file.");
+    assert_eq!(result["results"][0]["metadata"]["heading_path"], "Usage");
+}
+
+#[test]
+fn matches_report_their_heading_path() {
+    let result = search(GUIDE, "linux");
+
+    let hits: Vec<(&str, &str)> = result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| (r["text"].as_str().unwrap(), r["metadata"]["heading_path"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        hits,
+        [("Linux", "Setup > Linux"), ("Use the static binary on <b>Linux</b>.", "Setup > Linux")]
+    );
+    assert_eq!(result["results"][0]["metadata"]["headings"], json!(["Setup", "Linux"]));
+
+    // A new top-level heading closes the nested one.
+    assert_eq!(search(GUIDE, "usage")["results"][0]["metadata"]["headings"], json!(["Usage"]));
+}
+
+#[test]
+fn matches_in_fenced_code_are_flagged() {
+    let result = search(GUIDE, "curl");
+
+    let metadata = &result["results"][0]["metadata"];
+    assert_eq!(metadata["is_code"], true);
+    assert_eq!(metadata["code_language"], "bash");
+    assert_eq!(metadata["heading_path"], "Setup > Linux");
+    assert_eq!(search(GUIDE, "install the cli")["results"][0]["metadata"]["is_code"], false);
+}
+
+#[test]
+fn directories_are_searched_skipping_hidden_ones() {
+    let dir = TempDir::new().unwrap();
+    std::fs::create_dir_all(dir.path().join("docs")).unwrap();
+    std::fs::create_dir_all(dir.path().join(".cache")).unwrap();
+    std::fs::write(dir.path().join("docs/a.mdx"), "# A\n\nshared term\n").unwrap();
+    std::fs::write(dir.path().join("b.md"), "shared term\n").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), "shared term\n").unwrap();
+    std::fs::write(dir.path().join(".cache/c.mdx"), "shared term\n").unwrap();
+
+    let tool = MdxSearchTool::new().with_file_path(dir.path().to_string_lossy()).with_max_results(1);
+    let result = tool.run(args(&[("search_query", json!("shared"))])).unwrap();
+
+    assert_eq!(result["files_searched"], 2);
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["truncated"], true);
+}
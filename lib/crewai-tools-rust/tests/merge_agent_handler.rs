@@ -0,0 +1,130 @@
+//! MergeAgentHandlerTool strategies.
+
+#![cfg(feature = "automation")]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::{EmbeddingService, MergeAgentHandlerTool};
+use serde_json::{json, Value};
+
+fn outputs(items: &[(&str, &str)]) -> HashMap<String, Value> {
+    let outputs: Vec<Value> = items
+        .iter()
+        .map(|(agent, content)| json!({"agent": agent, "content": content}))
+        .collect();
+    HashMap::from([("outputs".to_string(), Value::from(outputs))])
+}
+
+fn with_strategy(mut args: HashMap<String, Value>, strategy: &str) -> HashMap<String, Value> {
+    args.insert("strategy".to_string(), json!(strategy));
+    args
+}
+
+#[test]
+fn concat_labels_each_section() {
+    let result = MergeAgentHandlerTool::new()
+        .run(outputs(&[("researcher", "Found 3 sources.\n"), ("writer", "Drafted intro.")]))
+        .unwrap();
+
+    assert_eq!(
+        result["result"],
+        "## researcher\n\nFound 3 sources.\n\n## writer\n\nDrafted intro."
+    );
+    assert_eq!(result["agents"], json!(["researcher", "writer"]));
+}
+
+#[test]
+fn vote_groups_normalized_answers_and_tallies() {
+    let result = MergeAgentHandlerTool::new()
+        .with_merge_strategy("vote")
+        .run(outputs(&[("a", "Lyon"), ("b", "  paris. "), ("c", "Paris"), ("d", "\"PARIS\"")]))
+        .unwrap();
+
+    assert_eq!(result["result"], "  paris. ");
+    assert_eq!(result["agents"], json!(["b", "c", "d"]));
+    assert_eq!(result["tie"], false);
+    assert_eq!(result["tally"][0]["votes"], 3);
+    assert_eq!(result["tally"][1], json!({"answer": "Lyon", "agents": ["a"], "votes": 1}));
+}
+
+#[test]
+fn vote_tie_goes_to_the_first_answer_and_is_flagged() {
+    let result = MergeAgentHandlerTool::new()
+        .run(with_strategy(
+            outputs(&[("a", "yes"), ("b", "no"), ("c", "No"), ("d", "Yes")]),
+            "vote",
+        ))
+        .unwrap();
+
+    assert_eq!(result["tie"], true);
+    assert_eq!(result["result"], "yes");
+    assert_eq!(result["agents"], json!(["a", "d"]));
+}
+
+/// Embeds "approve"-like answers together, everything else apart.
+struct Approvals;
+
+impl EmbeddingService for Approvals {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        let approve = ["approve", "lgtm", "ship it"].iter().any(|w| text.to_lowercase().contains(w));
+        Ok(if approve { vec![1.0, 0.0] } else { vec![0.0, 1.0] })
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        texts.iter().map(|t| self.embed(t)).collect()
+    }
+
+    fn model_name(&self) -> &str {
+        "approvals"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+}
+
+#[test]
+fn vote_uses_embeddings_to_match_paraphrases() {
+    let result = MergeAgentHandlerTool::new()
+        .with_embedder(Arc::new(Approvals))
+        .run(with_strategy(
+            outputs(&[("a", "Reject"), ("b", "LGTM"), ("c", "Approve the change"), ("d", "Ship it")]),
+            "vote",
+        ))
+        .unwrap();
+
+    assert_eq!(result["agents"], json!(["b", "c", "d"]));
+    assert_eq!(result["tally"][0]["votes"], 3);
+}
+
+#[test]
+fn summarize_prompts_with_every_agent_and_reports_citations() {
+    let prompts = Arc::new(Mutex::new(Vec::new()));
+    let log = prompts.clone();
+    let llm = move |prompt: &str| -> Result<String, anyhow::Error> {
+        log.lock().unwrap().push(prompt.to_string());
+        Ok("Demand is rising [researcher].".to_string())
+    };
+
+    let result = MergeAgentHandlerTool::new()
+        .with_merge_strategy("summarize")
+        .with_llm(Arc::new(llm))
+        .run(outputs(&[("researcher", "Demand up 12%"), ("critic", "Sample is small")]))
+        .unwrap();
+
+    assert_eq!(result["result"], "Demand is rising [researcher].");
+    assert_eq!(result["cited"], json!(["researcher"]));
+    let prompt = &prompts.lock().unwrap()[0];
+    assert!(prompt.contains("[researcher]\nDemand up 12%") && prompt.contains("[critic]\nSample is small"));
+}
+
+#[test]
+fn empty_outputs_are_rejected_for_every_strategy() {
+    for strategy in ["concat", "vote", "summarize"] {
+        let err = MergeAgentHandlerTool::new()
+            .run(with_strategy(outputs(&[]), strategy))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No outputs to merge", "{}", strategy);
+    }
+}
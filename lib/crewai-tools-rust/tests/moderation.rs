@@ -0,0 +1,82 @@
+//! ModerationTool against a scripted moderations endpoint and a custom
+//! provider.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{args, body_recording_mock_server};
+use crewai_tools::{Moderation, ModerationTool};
+use serde_json::{json, Value};
+
+const RESULTS: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{
+        "model": "omni-moderation-2024-09-26",
+        "results": [
+            {"flagged": false, "categories": {"harassment": false, "violence": false},
+             "category_scores": {"harassment": 0.02, "violence": 0.31}},
+            {"flagged": true, "categories": {"harassment": true, "violence": false},
+             "category_scores": {"harassment": 0.91, "violence": 0.05}}
+        ]
+    }"#,
+);
+
+#[test]
+fn batch_is_sent_in_one_request_and_flags_decide_without_threshold() {
+    let (url, bodies) = body_recording_mock_server(vec![RESULTS]);
+
+    let result = ModerationTool::new()
+        .with_base_url(url)
+        .run(args(&[("input", json!(["a fine report", "a rude reply"]))]))
+        .unwrap();
+
+    assert_eq!(result["allowed"], false);
+    assert_eq!(result["model"], "omni-moderation-2024-09-26");
+    assert_eq!(result["results"][0]["allowed"], true);
+    assert_eq!(result["results"][1]["categories"], json!(["harassment"]));
+    assert_eq!(result["results"][1]["scores"]["harassment"], 0.91);
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["input"], json!(["a fine report", "a rude reply"]));
+}
+
+#[test]
+fn threshold_overrides_the_provider_flag() {
+    let (url, _) = body_recording_mock_server(vec![RESULTS]);
+
+    let result = ModerationTool::new()
+        .with_base_url(url)
+        .with_threshold(0.3)
+        .run(args(&[("input", json!(["one", "two"]))]))
+        .unwrap();
+
+    assert_eq!(result["results"][0]["allowed"], false);
+    assert_eq!(result["results"][0]["flagged"], false);
+    assert_eq!(result["results"][0]["categories"], json!(["violence"]));
+    assert_eq!(result["results"][1]["categories"], json!(["harassment"]));
+}
+
+#[test]
+fn custom_service_is_used_instead_of_openai() {
+    let service = |texts: &[&str]| -> Result<Vec<Moderation>, anyhow::Error> {
+        Ok(texts
+            .iter()
+            .map(|t| Moderation {
+                flagged: t.contains("spam"),
+                categories: Vec::new(),
+                scores: [("spam".to_string(), if t.contains("spam") { 0.9 } else { 0.0 })].into(),
+            })
+            .collect())
+    };
+
+    let result = ModerationTool::new()
+        .with_service(Arc::new(service))
+        .run(args(&[("input", json!("hello")), ("threshold", json!(0.5))]))
+        .unwrap();
+
+    assert_eq!(result["allowed"], true);
+    assert_eq!(result["model"], "custom");
+}
@@ -0,0 +1,158 @@
+//! MongoDbVectorSearchTool: the `$vectorSearch` pipeline it builds and how
+//! connection failures are reported.
+
+#![cfg(feature = "database")]
+
+use std::collections::HashMap;
+
+use crewai_tools::{MongoDbError, MongoDbVectorSearchTool};
+use mongodb::bson::doc;
+use serde_json::{json, Value};
+
+fn tool() -> MongoDbVectorSearchTool {
+    MongoDbVectorSearchTool::new("kb", "articles")
+        .with_index_name("articles_vec")
+        .with_vector_path("vec")
+        .with_top_k(4)
+}
+
+fn vector_args(extra: &[(&str, Value)]) -> HashMap<String, Value> {
+    let mut args = HashMap::from([("query_vector".to_string(), json!([0.5, -1.0]))]);
+    args.extend(extra.iter().map(|(k, v)| (k.to_string(), v.clone())));
+    args
+}
+
+#[test]
+fn pipeline_searches_scores_and_drops_the_embedding() {
+    let pipeline = tool().pipeline(&vector_args(&[])).unwrap();
+
+    assert_eq!(
+        pipeline,
+        vec![
+            doc! { "$vectorSearch": {
+                "index": "articles_vec",
+                "path": "vec",
+                "queryVector": [0.5, -1.0],
+                "numCandidates": 40_i64,
+                "limit": 4_i64,
+            } },
+            doc! { "$set": { "score": { "$meta": "vectorSearchScore" } } },
+            doc! { "$project": { "vec": 0 } },
+        ]
+    );
+}
+
+#[test]
+fn filter_is_passed_to_vector_search() {
+    let filter = json!({"lang": "en", "year": {"$gte": 2020}});
+    let pipeline = tool()
+        .pipeline(&vector_args(&[("filter", filter)]))
+        .unwrap();
+
+    let search = pipeline[0].get_document("$vectorSearch").unwrap();
+    assert_eq!(
+        search.get_document("filter").unwrap(),
+        &doc! { "lang": "en", "year": { "$gte": 2020_i64 } }
+    );
+}
+
+#[test]
+fn top_k_sets_limit_and_candidates_up_to_the_atlas_cap() {
+    let pipeline = tool()
+        .pipeline(&vector_args(&[("top_k", json!(7))]))
+        .unwrap();
+    let search = pipeline[0].get_document("$vectorSearch").unwrap();
+    assert_eq!(search.get_i64("limit").unwrap(), 7);
+    assert_eq!(search.get_i64("numCandidates").unwrap(), 70);
+
+    let pipeline = tool()
+        .pipeline(&vector_args(&[("top_k", json!(2_000))]))
+        .unwrap();
+    let search = pipeline[0].get_document("$vectorSearch").unwrap();
+    assert_eq!(search.get_i64("limit").unwrap(), 2_000);
+    assert_eq!(search.get_i64("numCandidates").unwrap(), 10_000);
+}
+
+#[test]
+fn inclusion_projection_keeps_the_score() {
+    let pipeline = tool()
+        .pipeline(&vector_args(&[(
+            "projection",
+            json!({"title": 1, "_id": 0}),
+        )]))
+        .unwrap();
+    assert_eq!(
+        pipeline[2],
+        doc! { "$project": { "title": 1_i64, "_id": 0_i64, "score": 1 } }
+    );
+
+    // An exclusion projection already keeps every other field.
+    let pipeline = tool()
+        .pipeline(&vector_args(&[("projection", json!({"body": 0}))]))
+        .unwrap();
+    assert_eq!(pipeline[2], doc! { "$project": { "body": 0_i64 } });
+}
+
+#[test]
+fn malformed_arguments_are_rejected() {
+    let err = tool()
+        .pipeline(&vector_args(&[("filter", json!(["lang"]))]))
+        .unwrap_err();
+    assert!(
+        err.to_string().starts_with("'filter' must be a document"),
+        "{}",
+        err
+    );
+
+    let err = tool()
+        .pipeline(&HashMap::from([("query_vector".to_string(), json!("x"))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "'query_vector' must be an array of numbers"
+    );
+
+    let err = tool()
+        .pipeline(&HashMap::from([("query".to_string(), json!("rust"))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "A text query needs an embedder; pass query_vector or configure with_embedder"
+    );
+}
+
+#[test]
+fn unreachable_server_is_a_connection_error() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+    let tool = tool().with_connection_string(format!(
+        "mongodb://127.0.0.1:{}/?serverSelectionTimeoutMS=200&directConnection=true",
+        port
+    ));
+
+    let err = tool.run(vector_args(&[])).unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<MongoDbError>(),
+            Some(MongoDbError::Connection(_))
+        ),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn invalid_connection_string_is_a_connection_error() {
+    let tool = tool().with_connection_string("postgres://not-mongo");
+
+    let err = tool.run(vector_args(&[])).unwrap_err();
+    assert!(
+        matches!(
+            err.downcast_ref::<MongoDbError>(),
+            Some(MongoDbError::Connection(_))
+        ),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,112 @@
+//! MultiOnTool against a scripted browse API.
+
+#![cfg(feature = "browser")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::MultiOnTool;
+use serde_json::{json, Value};
+
+fn tool(url: &str) -> MultiOnTool {
+    MultiOnTool::new().with_base_url(url).with_api_key("mo_test")
+}
+
+const CONTINUING: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"message": "Opened the front page", "status": "CONTINUE",
+        "url": "https://news.ycombinator.com/", "session_id": "mo-1"}"#,
+);
+
+const DONE: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"message": "The top post is 'Show HN: ...'", "status": "DONE",
+        "url": "https://news.ycombinator.com/", "session_id": "mo-1"}"#,
+);
+
+#[test]
+fn loops_until_the_agent_is_done() {
+    let (url, bodies) = body_recording_mock_server(vec![CONTINUING, DONE]);
+
+    let result = tool(&url)
+        .run(args(&[
+            ("cmd", json!("find the top post")),
+            ("url", json!("https://news.ycombinator.com")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["status"], "DONE");
+    assert_eq!(result["message"], "The top post is 'Show HN: ...'");
+    assert_eq!(result["url"], "https://news.ycombinator.com/");
+    assert_eq!(result["session_id"], "mo-1");
+    assert_eq!(result["steps"], 2);
+    let bodies = bodies.lock().unwrap();
+    let first: Value = serde_json::from_str(&bodies[0]).unwrap();
+    assert_eq!(
+        first,
+        json!({"cmd": "find the top post", "local": false, "url": "https://news.ycombinator.com"})
+    );
+    let second: Value = serde_json::from_str(&bodies[1]).unwrap();
+    assert_eq!(second["session_id"], "mo-1");
+    assert!(second.get("url").is_none(), "{}", second);
+}
+
+#[test]
+fn stops_at_max_steps_while_continuing() {
+    let (url, hits) = mock_server(vec![CONTINUING, CONTINUING, DONE]);
+
+    let result = tool(&url)
+        .run(args(&[("cmd", json!("find the top post")), ("max_steps", json!(2))]))
+        .unwrap();
+
+    assert_eq!(result["status"], "CONTINUE");
+    assert_eq!(result["steps"], 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn session_and_local_mode_are_sent() {
+    let (url, _, heads) = recording_mock_server(vec![DONE]);
+    let (url2, bodies) = body_recording_mock_server(vec![DONE]);
+
+    tool(&url).run(args(&[("cmd", json!("summarize"))])).unwrap();
+    tool(&url2)
+        .with_local(true)
+        .run(args(&[("cmd", json!("summarize")), ("session_id", json!("mo-9"))]))
+        .unwrap();
+
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("post /web/browse "), "{}", head);
+    assert!(head.contains("x_multion_api_key: mo_test"), "{}", head);
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["local"], true);
+    assert_eq!(body["session_id"], "mo-9");
+}
+
+#[test]
+fn api_errors_are_reported() {
+    let rejected = ("401 Unauthorized", "", r#"{"detail": "Invalid API key"}"#);
+    let gone = ("404 Not Found", "", r#"{"detail": "Session not found"}"#);
+    let (url, _) = mock_server(vec![rejected, gone]);
+
+    let err = tool(&url).run(args(&[("cmd", json!("go"))])).unwrap_err();
+    assert!(err.to_string().contains("rejected the API key"), "{}", err);
+    let err = tool(&url)
+        .run(args(&[("cmd", json!("go")), ("session_id", json!("mo-old"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("session mo-old is no longer running"), "{}", err);
+}
+
+#[test]
+fn cmd_is_required_and_key_redacted() {
+    let (url, hits) = mock_server(vec![]);
+    let err = tool(&url).run(HashMap::new()).unwrap_err();
+    assert!(err.to_string().contains("cmd"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+    assert!(!format!("{:?}", tool(&url)).contains("mo_test"));
+}
@@ -0,0 +1,126 @@
+//! Nl2SqlTool against a throwaway SQLite database.
+
+#![cfg(feature = "database")]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::Nl2SqlTool;
+use serde_json::{json, Value};
+
+/// A fresh database with an `orders` table, unique per test.
+fn orders_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "crewai-nl2sql-{}-{}.sqlite",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE orders (id INTEGER PRIMARY KEY, customer TEXT, total REAL);
+         INSERT INTO orders (customer, total) VALUES ('ada', 12.5), ('bob', 7.0), ('ada', 3.0);",
+    )
+    .unwrap();
+    path
+}
+
+fn tool(path: &Path, reply: &'static str) -> (Nl2SqlTool, Arc<Mutex<Vec<String>>>) {
+    let prompts = Arc::new(Mutex::new(Vec::new()));
+    let seen = prompts.clone();
+    let llm = move |prompt: &str| {
+        seen.lock().unwrap().push(prompt.to_string());
+        Ok::<_, anyhow::Error>(reply.to_string())
+    };
+    let tool = Nl2SqlTool::new()
+        .with_dialect("sqlite")
+        .with_connection_string(path.to_string_lossy())
+        .with_llm(Arc::new(llm));
+    (tool, prompts)
+}
+
+fn question(q: &str) -> HashMap<String, Value> {
+    HashMap::from([("question".to_string(), Value::from(q))])
+}
+
+fn order_count(path: &Path) -> i64 {
+    let conn = rusqlite::Connection::open(path).unwrap();
+    conn.query_row("SELECT COUNT(*) FROM orders", [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn generated_select_returns_rows_and_sql() {
+    let path = orders_db("select");
+    let (tool, prompts) = tool(
+        &path,
+        "```sql\nSELECT customer, SUM(total) AS spent FROM orders GROUP BY customer ORDER BY customer;\n```",
+    );
+
+    let result = tool.run(question("How much did each customer spend?")).unwrap();
+
+    assert_eq!(
+        result["sql"],
+        "SELECT customer, SUM(total) AS spent FROM orders GROUP BY customer ORDER BY customer"
+    );
+    assert_eq!(result["columns"], json!(["customer", "spent"]));
+    assert_eq!(result["row_count"], 2);
+    assert_eq!(result["rows"][0], json!({"customer": "ada", "spent": 15.5}));
+    assert_eq!(result["truncated"], false);
+
+    let prompts = prompts.lock().unwrap();
+    assert_eq!(prompts.len(), 1);
+    assert!(prompts[0].contains("TABLE orders"), "{}", prompts[0]);
+    assert!(prompts[0].contains("customer TEXT"), "{}", prompts[0]);
+    assert!(prompts[0].contains("How much did each customer spend?"));
+}
+
+#[test]
+fn mutations_from_the_llm_are_refused() {
+    let path = orders_db("delete");
+    let (tool, _) = tool(&path, "DELETE FROM orders");
+
+    let err = tool.run(question("Clear the orders")).unwrap_err();
+
+    assert!(err.to_string().contains("only SELECT"), "{}", err);
+    assert_eq!(order_count(&path), 3);
+}
+
+#[test]
+fn stacked_statements_are_refused() {
+    let path = orders_db("stacked");
+    let (tool, _) = tool(&path, "SELECT * FROM orders; DROP TABLE orders");
+
+    let err = tool.run(question("Show orders")).unwrap_err();
+
+    assert!(err.to_string().contains("single statement"), "{}", err);
+    assert_eq!(order_count(&path), 3);
+}
+
+#[test]
+fn keywords_inside_string_literals_are_allowed() {
+    let path = orders_db("literal");
+    let (tool, _) = tool(&path, "SELECT id FROM orders WHERE customer = 'delete; drop'");
+
+    let result = tool.run(question("Find a customer")).unwrap();
+
+    assert_eq!(result["row_count"], 0);
+}
+
+#[test]
+fn sql_query_skips_the_llm_and_respects_max_rows() {
+    let path = orders_db("max-rows");
+    let (tool, prompts) = tool(&path, "unused");
+    let tool = tool.with_max_rows(2);
+
+    let args = HashMap::from([(
+        "sql_query".to_string(),
+        Value::from("SELECT id FROM orders ORDER BY id"),
+    )]);
+    let result = tool.run(args).unwrap();
+
+    assert_eq!(result["rows"], json!([{"id": 1}, {"id": 2}]));
+    assert_eq!(result["truncated"], true);
+    assert!(prompts.lock().unwrap().is_empty());
+}
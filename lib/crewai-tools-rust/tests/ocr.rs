@@ -0,0 +1,100 @@
+//! OcrTool engine parsing, plus the tesseract backend driven through a
+//! stand-in script so the tests do not need Tesseract installed.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use common::args;
+use crewai_tools::{OcrEngine, OcrTool};
+use serde_json::json;
+
+#[test]
+fn unknown_engines_fail_to_parse_or_deserialize() {
+    assert_eq!("Tesseract".parse::<OcrEngine>().unwrap(), OcrEngine::Tesseract);
+    assert!("cloud_vision".parse::<OcrEngine>().is_err());
+
+    let bad = json!({"engine": "easyocr", "languages": ["eng"]});
+    assert!(serde_json::from_value::<OcrTool>(bad).is_err());
+}
+
+#[test]
+fn missing_binary_gives_an_install_hint() {
+    let image = std::env::temp_dir().join(format!("crewai-ocr-missing-{}.png", std::process::id()));
+    std::fs::write(&image, b"\x89PNG\r\n\x1a\n").unwrap();
+
+    let err = OcrTool::new()
+        .with_tesseract_path("/nonexistent/tesseract")
+        .run(args(&[("image_path", json!(image.to_string_lossy()))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Install Tesseract"), "{}", err);
+}
+
+#[cfg(unix)]
+mod with_stub {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    /// A fake `tesseract` that records its arguments and prints `output`.
+    fn stub(name: &str, output: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("crewai-ocr-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let log = dir.join("args");
+        let script = dir.join("tesseract");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$@\" > '{}'\nprintf '%b' '{}'\n",
+                log.display(),
+                output
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        (script, log)
+    }
+
+    #[test]
+    fn multi_page_text_is_split_on_form_feeds() {
+        let (script, log) = stub("text", "first page\\n\\f second page\\n\\f");
+
+        let result = OcrTool::new()
+            .with_tesseract_path(script.to_string_lossy())
+            .with_languages(vec!["eng".into(), "deu".into()])
+            .run(args(&[("image_base64", json!("data:image/tiff;base64,SUkqAA=="))]))
+            .unwrap();
+
+        assert_eq!(result["pages"][0], json!({"page": 1, "text": "first page"}));
+        assert_eq!(result["pages"][1]["text"], "second page");
+        assert_eq!(result["text"], "first page\u{c}second page");
+        let recorded = std::fs::read_to_string(log).unwrap();
+        assert!(recorded.contains("stdout -l eng+deu"), "{}", recorded);
+    }
+
+    #[test]
+    fn tsv_output_groups_words_into_blocks_with_confidence() {
+        let tsv = "level\\tpage_num\\tblock_num\\tpar_num\\tline_num\\tword_num\\tleft\\ttop\\twidth\\theight\\tconf\\ttext\\n\
+                   5\\t1\\t1\\t1\\t1\\t1\\t0\\t0\\t10\\t10\\t90\\tHello\\n\
+                   5\\t1\\t1\\t1\\t1\\t2\\t0\\t0\\t10\\t10\\t80\\tworld\\n\
+                   5\\t1\\t2\\t1\\t1\\t1\\t0\\t0\\t10\\t10\\t70.5\\tBye\\n";
+        let (script, log) = stub("tsv", tsv);
+        let image = std::env::temp_dir().join(format!("crewai-ocr-tsv-{}.png", std::process::id()));
+        std::fs::write(&image, b"\x89PNG\r\n\x1a\n").unwrap();
+
+        let result = OcrTool::new()
+            .with_tesseract_path(script.to_string_lossy())
+            .run(args(&[
+                ("image_path", json!(image.to_string_lossy())),
+                ("output", json!("tsv")),
+            ]))
+            .unwrap();
+
+        let blocks = &result["pages"][0]["blocks"];
+        assert_eq!(blocks[0], json!({"text": "Hello world", "confidence": 85.0}));
+        assert_eq!(blocks[1], json!({"text": "Bye", "confidence": 70.5}));
+        assert_eq!(result["text"], "Hello world\n\nBye");
+        assert!(std::fs::read_to_string(log).unwrap().trim_end().ends_with("tsv"));
+    }
+}
@@ -0,0 +1,121 @@
+//! OllamaEmbeddingService batching, legacy fallback, dimension caching and
+//! setup hints against a scripted Ollama server.
+
+#![cfg(feature = "rag")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{embedding_service, EmbeddingService, OllamaEmbeddingService};
+
+const BATCH: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"model":"nomic-embed-text","embeddings":[[0.1,0.2,0.3],[0.4,0.5,0.6]]}"#,
+);
+
+#[test]
+fn batches_go_to_the_embed_endpoint() {
+    let (url, hits, heads) = recording_mock_server(vec![BATCH]);
+    let service = OllamaEmbeddingService::new().with_base_url(&url);
+
+    let vectors = service.embed_batch(&["first", "second"]).unwrap();
+
+    assert_eq!(vectors, [vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6]]);
+    // Learnt from the batch, so no probe request.
+    assert_eq!(service.dimensions(), 3);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert!(heads.lock().unwrap()[0].starts_with("post /api/embed "));
+}
+
+#[test]
+fn request_names_the_model_and_inputs() {
+    let (url, bodies) = body_recording_mock_server(vec![BATCH]);
+    let service = OllamaEmbeddingService::new().with_base_url(&url).with_model("mxbai-embed-large");
+
+    service.embed_batch(&["first", "second"]).unwrap();
+
+    let body: serde_json::Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["model"], "mxbai-embed-large");
+    assert_eq!(body["input"], serde_json::json!(["first", "second"]));
+    assert_eq!(service.model_name(), "mxbai-embed-large");
+}
+
+#[test]
+fn dimensions_are_probed_once() {
+    let (url, hits) = mock_server(vec![(
+        "200 OK",
+        "",
+        r#"{"embeddings":[[0.0,1.0,0.0,0.0]]}"#,
+    )]);
+    let service = OllamaEmbeddingService::new().with_base_url(&url);
+
+    assert_eq!(service.dimensions(), 4);
+    assert_eq!(service.dimensions(), 4);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn older_servers_fall_back_to_the_embeddings_endpoint() {
+    let (url, hits, heads) = recording_mock_server(vec![
+        ("404 Not Found", "Content-Type: text/plain\r\n", "404 page not found"),
+        ("200 OK", "", r#"{"embedding":[1.0,0.0]}"#),
+        ("200 OK", "", r#"{"embedding":[0.0,1.0]}"#),
+        ("200 OK", "", r#"{"embedding":[0.5,0.5]}"#),
+    ]);
+    let service = OllamaEmbeddingService::new().with_base_url(&url);
+
+    let vectors = service.embed_batch(&["a", "b"]).unwrap();
+    assert_eq!(vectors, [vec![1.0, 0.0], vec![0.0, 1.0]]);
+    // The fallback sticks, so the next call skips /api/embed.
+    assert_eq!(service.embed("c").unwrap(), [0.5, 0.5]);
+
+    assert_eq!(hits.load(Ordering::SeqCst), 4);
+    let heads = heads.lock().unwrap();
+    assert!(heads[1].starts_with("post /api/embeddings "), "{}", heads[1]);
+    assert!(heads[3].starts_with("post /api/embeddings "), "{}", heads[3]);
+}
+
+#[test]
+fn missing_models_and_servers_explain_the_fix() {
+    let (url, _) = mock_server(vec![(
+        "404 Not Found",
+        "",
+        r#"{"error":"model \"nomic-embed-text\" not found, try pulling it first"}"#,
+    )]);
+    let err = OllamaEmbeddingService::new().with_base_url(&url).embed("x").unwrap_err();
+    assert!(err.to_string().contains("ollama pull nomic-embed-text"), "{}", err);
+
+    let err = OllamaEmbeddingService::new()
+        .with_base_url("http://127.0.0.1:1")
+        .embed("x")
+        .unwrap_err();
+    assert!(err.to_string().contains("ollama serve"), "{}", err);
+}
+
+#[test]
+fn providers_are_chosen_by_name() {
+    let ollama = embedding_service("ollama", None, Some("http://127.0.0.1:1")).unwrap();
+    assert_eq!(ollama.model_name(), "nomic-embed-text");
+    let openai = embedding_service("OpenAI", Some("text-embedding-3-large"), None).unwrap();
+    assert_eq!(openai.dimensions(), 3072);
+
+    let err = embedding_service("cohere", None, None).err().unwrap();
+    assert!(err.to_string().contains("expected openai or ollama"), "{}", err);
+}
+
+#[cfg(feature = "ai_ml")]
+#[test]
+fn rag_tool_switches_to_ollama_with_one_setting() {
+    let (url, _) = mock_server(vec![BATCH]);
+    let tool = crewai_tools::RagTool::new()
+        .with_embedding_provider("ollama")
+        .with_embedding_base_url(&url);
+
+    let embedder = tool.embedder().unwrap();
+
+    assert_eq!(embedder.model_name(), "nomic-embed-text");
+    assert_eq!(embedder.embed_batch(&["a", "b"]).unwrap().len(), 2);
+}
@@ -0,0 +1,159 @@
+//! ParallelSearchTool fan-out across mocked Serper, Brave and Tavily
+//! backends: per-query provider selection, request building, status
+//! aggregation and the wall-clock timeout.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{BraveSearchTool, ParallelSearchTool, SerperDevTool, TavilySearchTool};
+use serde_json::{json, Value};
+
+const SERPER: &str = r#"{"organic": [{"title": "From Serper", "link": "https://s"}]}"#;
+const BRAVE: &str = r#"{"web": {"results": [{"title": "From Brave", "url": "https://b"}]}}"#;
+const TAVILY: &str = r#"{"results": [{"title": "From Tavily", "url": "https://t"}]}"#;
+
+fn serper(base: &str) -> SerperDevTool {
+    SerperDevTool::new().with_api_key("s-key").with_base_url(base).with_max_retries(0)
+}
+
+fn brave(base: &str) -> BraveSearchTool {
+    BraveSearchTool::new().with_api_key("b-key").with_base_url(base).with_max_retries(0)
+}
+
+fn tavily(base: &str) -> TavilySearchTool {
+    TavilySearchTool::new().with_api_key("t-key").with_base_url(base)
+}
+
+#[test]
+fn queries_fan_out_to_their_providers_with_provider_specific_requests() {
+    let (serper_url, serper_bodies) = body_recording_mock_server(vec![("200 OK", "", SERPER)]);
+    let (brave_url, _, brave_requests) =
+        recording_mock_server(vec![("200 OK", "", BRAVE), ("200 OK", "", BRAVE)]);
+    let (tavily_url, _) = mock_server(vec![("200 OK", "", TAVILY)]);
+    let tool = ParallelSearchTool::new()
+        .with_providers(vec!["brave".into(), "tavily".into()])
+        .with_serper(serper(&serper_url))
+        .with_brave(brave(&brave_url))
+        .with_tavily(tavily(&tavily_url));
+
+    let result = tool
+        .run(args(&[
+            ("queries", json!(["rust", "cargo"])),
+            ("providers", json!([["serper", "Brave", "serper"], null])),
+        ]))
+        .unwrap();
+
+    // Duplicate providers collapse and names are case-insensitive.
+    assert_eq!(result["succeeded"], 4);
+    assert_eq!(result["failed"], 0);
+    assert_eq!(result["timed_out"], false);
+    let rust = &result["results"]["rust"];
+    assert_eq!(rust["status"], "ok");
+    let serper_data: Value = serde_json::from_str(SERPER).unwrap();
+    assert_eq!(rust["providers"]["serper"], json!({"status": "ok", "data": serper_data}));
+    assert_eq!(rust["providers"]["brave"]["data"]["web"]["results"][0]["title"], "From Brave");
+    let cargo = &result["results"]["cargo"];
+    assert_eq!(cargo["providers"].as_object().unwrap().keys().collect::<Vec<_>>(), ["brave", "tavily"]);
+    assert_eq!(cargo["providers"]["tavily"]["data"]["results"][0]["title"], "From Tavily");
+
+    let serper_body: Value = serde_json::from_str(&serper_bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(serper_body["q"], "rust");
+    let mut brave_queries: Vec<String> = brave_requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| {
+            assert!(r.contains("x-subscription-token: b-key"), "{}", r);
+            r.split_whitespace().nth(1).unwrap().to_string()
+        })
+        .collect();
+    brave_queries.sort();
+    assert_eq!(brave_queries, ["/?q=cargo&count=10", "/?q=rust&count=10"]);
+}
+
+#[test]
+fn a_failing_provider_marks_only_its_query_partial() {
+    let (serper_url, _) = mock_server(vec![("200 OK", "", SERPER)]);
+    let (tavily_url, tavily_bodies) =
+        body_recording_mock_server(vec![("401 Unauthorized", "", r#"{"detail": "bad key"}"#)]);
+    let tool = ParallelSearchTool::new()
+        .with_providers(vec!["serper".into(), "tavily".into()])
+        .with_serper(serper(&serper_url))
+        .with_tavily(tavily(&tavily_url))
+        .with_max_concurrency(1);
+
+    let result = tool.run(args(&[("queries", json!(["rust"]))])).unwrap();
+
+    let rust = &result["results"]["rust"];
+    assert_eq!(rust["status"], "partial");
+    assert_eq!(rust["providers"]["serper"]["status"], "ok");
+    assert_eq!(rust["providers"]["tavily"]["status"], "error");
+    assert_eq!(rust["providers"]["tavily"]["error"], "Tavily request failed: HTTP 401 Unauthorized");
+    assert_eq!((result["succeeded"].clone(), result["failed"].clone()), (json!(1), json!(1)));
+    let tavily_body: Value = serde_json::from_str(&tavily_bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(
+        tavily_body,
+        json!({"api_key": "t-key", "query": "rust", "search_depth": "basic", "max_results": 10})
+    );
+}
+
+#[test]
+fn a_query_whose_providers_all_fail_is_an_error() {
+    let (tavily_url, _) = mock_server(vec![("500 Internal Server Error", "", "{}")]);
+    let tool = ParallelSearchTool::new()
+        .with_providers(vec!["tavily".into()])
+        .with_tavily(tavily(&tavily_url));
+
+    let result = tool.run(args(&[("queries", json!(["rust"]))])).unwrap();
+
+    assert_eq!(result["results"]["rust"]["status"], "error");
+    assert_eq!(result["failed"], 1);
+}
+
+#[test]
+fn invalid_arguments_fail_before_any_request() {
+    let (base, hits) = mock_server(vec![]);
+    let tool = ParallelSearchTool::new().with_serper(serper(&base));
+
+    for (call, expected) in [
+        (args(&[]), "Missing required argument: queries"),
+        (args(&[("queries", json!([]))]), "queries must not be empty"),
+        (args(&[("queries", json!(["a", 1]))]), "queries must be an array of strings"),
+        (
+            args(&[("queries", json!(["a"])), ("providers", json!(["bing"]))]),
+            "Unknown provider 'bing': expected one of serper, brave, tavily",
+        ),
+    ] {
+        let err = tool.run(call).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn providers_still_running_at_the_deadline_are_reported_as_timed_out() {
+    // Accepts connections but never answers.
+    let silent = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let silent_url = format!("http://{}", silent.local_addr().unwrap());
+    let (serper_url, _) = mock_server(vec![("200 OK", "", SERPER)]);
+    let tool = ParallelSearchTool::new()
+        .with_providers(vec!["serper".into(), "brave".into()])
+        .with_serper(serper(&serper_url))
+        .with_brave(brave(&silent_url))
+        .with_timeout(Duration::from_secs(1));
+
+    let started = std::time::Instant::now();
+    let result = tool.run(args(&[("queries", json!(["rust"]))])).unwrap();
+
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    assert_eq!(result["timed_out"], true);
+    let rust = &result["results"]["rust"];
+    assert_eq!(rust["status"], "partial");
+    assert_eq!(rust["providers"]["serper"]["status"], "ok");
+    assert_eq!(rust["providers"]["brave"], json!({"status": "error", "error": "timed out after 1s"}));
+}
@@ -0,0 +1,152 @@
+//! PdfSearchTool page-number reporting and the no-extractable-text path.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::args;
+use crewai_tools::PdfSearchTool;
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+/// One page of a generated PDF.
+enum Page<'a> {
+    /// Lines of Helvetica text.
+    Text(&'a [&'a str]),
+    /// A single 1x1 gray image and no text, like a scanned page.
+    Image,
+}
+
+/// Write a PDF with `pages` into `dir`.
+fn pdf_file(dir: &TempDir, pages: &[Page]) -> String {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+        "Encoding" => "WinAnsiEncoding",
+    });
+
+    let mut kids = Vec::new();
+    for page in pages {
+        let (operations, resources) = match page {
+            Page::Text(lines) => {
+                let mut ops = vec![
+                    Operation::new("BT", vec![]),
+                    Operation::new("Tf", vec!["F1".into(), 12.into()]),
+                    Operation::new("TL", vec![14.into()]),
+                    Operation::new("Td", vec![72.into(), 720.into()]),
+                ];
+                for line in *lines {
+                    ops.push(Operation::new("Tj", vec![Object::string_literal(*line)]));
+                    ops.push(Operation::new("T*", vec![]));
+                }
+                ops.push(Operation::new("ET", vec![]));
+                (ops, dictionary! { "Font" => dictionary! { "F1" => font_id } })
+            }
+            Page::Image => {
+                let image_id = doc.add_object(Stream::new(
+                    dictionary! {
+                        "Type" => "XObject",
+                        "Subtype" => "Image",
+                        "Width" => 1,
+                        "Height" => 1,
+                        "ColorSpace" => "DeviceGray",
+                        "BitsPerComponent" => 8,
+                    },
+                    vec![0x80],
+                ));
+                let ops = vec![
+                    Operation::new("q", vec![]),
+                    Operation::new("cm", vec![100.into(), 0.into(), 0.into(), 100.into(), 72.into(), 600.into()]),
+                    Operation::new("Do", vec!["Im1".into()]),
+                    Operation::new("Q", vec![]),
+                ];
+                (ops, dictionary! { "XObject" => dictionary! { "Im1" => image_id } })
+            }
+        };
+        let content = Content { operations }.encode().unwrap();
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+        kids.push(Object::Reference(doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+            "Resources" => resources,
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        })));
+    }
+    let count = kids.len() as i64;
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => kids, "Count" => count }),
+    );
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+
+    let path = dir.path().join("document.pdf");
+    doc.save(&path).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn search(tool: PdfSearchTool, pages: &[Page], query: &str) -> Result<Value, anyhow::Error> {
+    let dir = TempDir::new().unwrap();
+    let path = pdf_file(&dir, pages);
+    tool.with_file_path(path).run(args(&[("search_query", json!(query))]))
+}
+
+#[test]
+fn matches_are_reported_by_page_number() {
+    let pages = [
+        Page::Text(&["Introduction to orbits"]),
+        Page::Text(&["Nothing relevant here"]),
+        Page::Image,
+        Page::Text(&["Orbits decay.", "Low orbits decay faster."]),
+    ];
+
+    let result = search(PdfSearchTool::new().with_snippet_chars(6), &pages, "ORBIT").unwrap();
+
+    assert_eq!(result["total_pages"], 4);
+    assert_eq!(result["total_matches"], 3);
+    assert_eq!(result["truncated"], false);
+    let found: Vec<(u64, u64)> = result["pages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| (p["page"].as_u64().unwrap(), p["matches"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(found, [(1, 1), (4, 2)]);
+    let snippet = result["pages"][0]["snippets"][0].as_str().unwrap();
+    assert!(snippet.starts_with('…') && snippet.contains("orbits"), "{}", snippet);
+}
+
+#[test]
+fn hits_beyond_max_results_are_counted_but_not_returned() {
+    let pages = [Page::Text(&["orbit orbit"]), Page::Text(&["orbit"])];
+
+    let result = search(PdfSearchTool::new().with_max_results(2), &pages, "orbit").unwrap();
+
+    assert_eq!(result["total_matches"], 3);
+    assert_eq!(result["truncated"], true);
+    assert_eq!(result["pages"].as_array().unwrap().len(), 1);
+    assert_eq!(result["pages"][0]["matches"], 2);
+}
+
+#[test]
+fn image_only_pdfs_point_at_ocr() {
+    let err = search(PdfSearchTool::new(), &[Page::Image, Page::Image], "orbit").unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("has no extractable text (pages contain only images)"), "{}", message);
+    assert!(message.contains("OcrTool"), "{}", message);
+}
+
+#[test]
+fn blank_pdfs_report_missing_text_without_images() {
+    let err = search(PdfSearchTool::new(), &[Page::Text(&[])], "orbit").unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("has no extractable text - it is probably scanned"), "{}", message);
+}
@@ -0,0 +1,108 @@
+//! PgVectorSearchTool.
+//!
+//! The tests that need a database are `#[ignore]`d; point
+//! `PGVECTOR_TEST_URL` at a Postgres with the pgvector extension available
+//! and run them with:
+//!
+//! ```text
+//! docker run -d -p 5432:5432 -e POSTGRES_PASSWORD=pw pgvector/pgvector:pg16
+//! PGVECTOR_TEST_URL=postgres://postgres:pw@localhost/postgres \
+//!     cargo test --features database --test pgvector -- --ignored
+//! ```
+
+#![cfg(feature = "database")]
+
+use std::collections::HashMap;
+
+use crewai_tools::{PgVectorIndex, PgVectorSearchTool};
+use serde_json::{json, Value};
+
+fn vector_args(vector: &[f32]) -> HashMap<String, Value> {
+    HashMap::from([("query_vector".to_string(), json!(vector))])
+}
+
+#[test]
+fn filter_needs_a_metadata_column() {
+    let tool = PgVectorSearchTool::new("docs").with_connection_string("postgres://unused");
+    let mut args = vector_args(&[1.0, 0.0]);
+    args.insert("filter".to_string(), json!({"lang": "en"}));
+
+    let err = tool.run(args).unwrap_err();
+
+    assert!(err.to_string().contains("with_metadata_column"), "{}", err);
+}
+
+#[test]
+fn text_query_needs_an_embedder() {
+    let tool = PgVectorSearchTool::new("docs").with_connection_string("postgres://unused");
+    let args = HashMap::from([("query".to_string(), Value::from("hello"))]);
+
+    let err = tool.run(args).unwrap_err();
+
+    assert!(err.to_string().contains("embedder"), "{}", err);
+}
+
+/// A fresh `table` with three 3-d documents, or `None` when no test
+/// database is configured.
+fn seeded(table: &str) -> Option<PgVectorSearchTool> {
+    let url = std::env::var("PGVECTOR_TEST_URL").ok()?;
+    let mut client = postgres::Client::connect(&url, postgres::NoTls).unwrap();
+    client
+        .batch_execute(&format!(
+            "CREATE EXTENSION IF NOT EXISTS vector;
+             DROP TABLE IF EXISTS {table};
+             CREATE TABLE {table} (id serial, content text, metadata jsonb, embedding vector(3));
+             INSERT INTO {table} (content, metadata, embedding) VALUES
+                 ('cats', '{{\"lang\": \"en\"}}', '[1,0,0]'),
+                 ('chats', '{{\"lang\": \"fr\"}}', '[0.9,0.1,0]'),
+                 ('dogs', '{{\"lang\": \"en\"}}', '[0,1,0]');",
+            table = table
+        ))
+        .unwrap();
+    Some(
+        PgVectorSearchTool::new(table)
+            .with_connection_string(url)
+            .with_metadata_column("metadata"),
+    )
+}
+
+#[test]
+#[ignore]
+fn returns_nearest_rows_first() {
+    let tool = seeded("crewai_pgvector_nearest").expect("PGVECTOR_TEST_URL not set");
+
+    let result = tool.with_top_k(2).run(vector_args(&[1.0, 0.0, 0.0])).unwrap();
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["results"][0]["content"], "cats");
+    assert_eq!(result["results"][0]["metadata"], json!({"lang": "en"}));
+    assert_eq!(result["results"][1]["content"], "chats");
+    assert!(result["results"][0]["score"].as_f64().unwrap() > 0.99);
+}
+
+#[test]
+#[ignore]
+fn metadata_filter_restricts_results() {
+    let tool = seeded("crewai_pgvector_filter").expect("PGVECTOR_TEST_URL not set");
+    let mut args = vector_args(&[1.0, 0.0, 0.0]);
+    args.insert("filter".to_string(), json!({"lang": "en"}));
+
+    let result = tool.run(args).unwrap();
+
+    let contents: Vec<&str> = result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["content"].as_str().unwrap())
+        .collect();
+    assert_eq!(contents, ["cats", "dogs"]);
+}
+
+#[test]
+#[ignore]
+fn ensure_index_creates_once() {
+    let tool = seeded("crewai_pgvector_index").expect("PGVECTOR_TEST_URL not set");
+
+    assert!(tool.ensure_index(PgVectorIndex::Hnsw).unwrap());
+    assert!(!tool.ensure_index(PgVectorIndex::IvfFlat { lists: 10 }).unwrap());
+}
@@ -0,0 +1,111 @@
+//! QueryFilter matching in InMemoryVectorIndex, and its Lance WHERE clause.
+
+#![cfg(feature = "rag")]
+
+use std::collections::HashMap;
+
+use crewai_tools::{Chunk, DateRange, InMemoryVectorIndex, MetadataCondition, QueryFilter};
+use serde_json::json;
+
+fn index(sources: &[&str]) -> InMemoryVectorIndex {
+    let mut index = InMemoryVectorIndex::new();
+    for (i, source) in sources.iter().enumerate() {
+        index.add(
+            Chunk {
+                content: format!("chunk {}", i),
+                metadata: HashMap::from([("source".to_string(), json!(source))]),
+                index: 0,
+            },
+            vec![1.0, i as f32],
+        );
+    }
+    index
+}
+
+fn sources(index: &InMemoryVectorIndex, filter: &QueryFilter) -> Vec<String> {
+    let mut found: Vec<String> = index
+        .search(&[1.0, 0.0], 10, Some(filter))
+        .into_iter()
+        .map(|h| h.metadata["source"].as_str().unwrap().to_string())
+        .collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn index_applies_include_and_exclude_globs() {
+    let index = index(&["reports/2024/q1.pdf", "reports/2023/q4.pdf", "blog/reddit.com/post"]);
+
+    let filter = QueryFilter::new().include_source("reports/*").exclude_source("*2023*");
+    assert_eq!(sources(&index, &filter), ["reports/2024/q1.pdf"]);
+
+    let filter = QueryFilter::new().include_source("reports/202?/q?.pdf");
+    assert_eq!(sources(&index, &filter), ["reports/2023/q4.pdf", "reports/2024/q1.pdf"]);
+
+    let nothing = QueryFilter::new().include_source("wiki/*");
+    assert!(index.search(&[1.0, 0.0], 10, Some(&nothing)).is_empty());
+}
+
+#[test]
+fn index_treats_percent_and_underscore_in_globs_literally() {
+    let index = index(&["docs/100%_done.md", "docs/100xydone.md", "docs/my_notes.md", "docs/myxnotes.md"]);
+
+    let filter = QueryFilter::new().include_source("docs/100%_done*");
+    assert_eq!(sources(&index, &filter), ["docs/100%_done.md"]);
+
+    let filter = QueryFilter::new().include_source("docs/my_*");
+    assert_eq!(sources(&index, &filter), ["docs/my_notes.md"]);
+}
+
+#[test]
+fn index_combines_conditions_and_date_range() {
+    let mut index = InMemoryVectorIndex::new();
+    for (source, lang, modified) in [("a.md", "en", "2024-03-01"), ("b.md", "de", "2024-06-01"), ("c.md", "en", "2025-01-02")] {
+        index.add(
+            Chunk {
+                content: source.to_string(),
+                metadata: HashMap::from([
+                    ("source".to_string(), json!(source)),
+                    ("language".to_string(), json!(lang)),
+                    ("modified".to_string(), json!(modified)),
+                ]),
+                index: 0,
+            },
+            vec![1.0, 0.0],
+        );
+    }
+
+    let filter = QueryFilter::new()
+        .with_condition(MetadataCondition::equals("language", json!("en")))
+        .with_date_range(DateRange::new("modified").with_to("2024-12-31"));
+    assert_eq!(sources(&index, &filter), ["a.md"]);
+}
+
+#[test]
+fn lance_filter_escapes_like_wildcards_in_globs() {
+    let filter = QueryFilter::new()
+        .include_source("reports/100%_final/*.pdf")
+        .exclude_source("*draft_v?**");
+
+    assert_eq!(
+        filter.to_lance_filter().unwrap(),
+        r"(source LIKE 'reports/100\%\_final/%.pdf' ESCAPE '\') AND source NOT LIKE '%draft\_v_%' ESCAPE '\'"
+    );
+}
+
+#[test]
+fn lance_filter_escapes_contains_and_date_prefix_values() {
+    let filter = QueryFilter::new()
+        .with_condition(MetadataCondition::contains("title", json!("50% off_sale \\ it's")))
+        .with_date_range(DateRange::new("modified").with_from("2024-01").with_to("2024_12"));
+
+    assert_eq!(
+        filter.to_lance_filter().unwrap(),
+        r"title LIKE '%50\% off\_sale \\ it''s%' ESCAPE '\' AND modified >= '2024-01' AND (modified <= '2024_12' OR modified LIKE '2024\_12%' ESCAPE '\')"
+    );
+}
+
+#[test]
+fn empty_filter_has_no_lance_clause() {
+    assert_eq!(QueryFilter::new().to_lance_filter(), None);
+}
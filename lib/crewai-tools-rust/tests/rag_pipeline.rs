@@ -0,0 +1,282 @@
+//! RagPipeline ingest counts, batching, incremental skips and retrieval.
+
+#![cfg(feature = "rag")]
+
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::{
+    BaseChunker, BaseLoader, Chunk, Document, EmbeddingService, InMemoryVectorStore, QueryFilter,
+    RagPipeline,
+};
+use serde_json::json;
+
+/// Serves whatever documents it currently holds.
+#[derive(Clone, Default)]
+struct MemoryLoader {
+    documents: Arc<Mutex<Vec<Document>>>,
+}
+
+impl MemoryLoader {
+    fn with(documents: &[(&str, &str)]) -> Self {
+        let loader = Self::default();
+        for (source, content) in documents {
+            loader.push(source, content);
+        }
+        loader
+    }
+
+    fn push(&self, source: &str, content: &str) {
+        self.documents
+            .lock()
+            .unwrap()
+            .push(Document::new(content).with_metadata("source", json!(source)));
+    }
+}
+
+impl BaseLoader for MemoryLoader {
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
+        Ok(self.documents.lock().unwrap().clone())
+    }
+
+    fn loader_name(&self) -> &str {
+        "MemoryLoader"
+    }
+}
+
+struct FailingLoader;
+
+impl BaseLoader for FailingLoader {
+    fn load(&self) -> Result<Vec<Document>, anyhow::Error> {
+        anyhow::bail!("disk on fire")
+    }
+
+    fn loader_name(&self) -> &str {
+        "FailingLoader"
+    }
+}
+
+/// One chunk per line, carrying the document's metadata.
+struct LineChunker;
+
+impl BaseChunker for LineChunker {
+    fn chunk(&self, document: &Document) -> Result<Vec<Chunk>, anyhow::Error> {
+        Ok(document
+            .content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Chunk {
+                content: line.to_string(),
+                metadata: document.metadata.clone(),
+                index,
+            })
+            .collect())
+    }
+
+    fn chunker_name(&self) -> &str {
+        "LineChunker"
+    }
+}
+
+/// Embeds a text as counts of "rust" and "python", recording each batch.
+#[derive(Default)]
+struct KeywordEmbedder {
+    batches: Mutex<Vec<Vec<String>>>,
+}
+
+impl KeywordEmbedder {
+    fn batches(&self) -> Vec<Vec<String>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+impl EmbeddingService for KeywordEmbedder {
+    fn embed(&self, text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        let text = text.to_lowercase();
+        Ok(vec![text.matches("rust").count() as f32, text.matches("python").count() as f32])
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        self.batches
+            .lock()
+            .unwrap()
+            .push(texts.iter().map(|t| t.to_string()).collect());
+        texts.iter().map(|t| self.embed(t)).collect()
+    }
+
+    fn model_name(&self) -> &str {
+        "keywords"
+    }
+
+    fn dimensions(&self) -> usize {
+        2
+    }
+}
+
+fn corpus() -> (MemoryLoader, MemoryLoader) {
+    (
+        MemoryLoader::with(&[("rust.md", "rust ownership\nrust lifetimes\nrust traits")]),
+        MemoryLoader::with(&[("python.md", "python decorators\npython generators")]),
+    )
+}
+
+#[test]
+fn ingest_embeds_in_batches_and_reports_counts() {
+    let (rust, python) = corpus();
+    let embedder = Arc::new(KeywordEmbedder::default());
+    let pipeline = RagPipeline::builder()
+        .loader(rust)
+        .loader(python)
+        .chunker(LineChunker)
+        .embedder(embedder.clone())
+        .batch_size(2)
+        .build();
+
+    let summary = pipeline.ingest().unwrap();
+
+    assert_eq!(
+        (summary.documents, summary.chunks, summary.stored, summary.skipped),
+        (2, 5, 5, 0)
+    );
+    let sizes: Vec<usize> = embedder.batches().iter().map(Vec::len).collect();
+    assert_eq!(sizes, [2, 2, 1]);
+    assert!(summary.total_time >= summary.load_time);
+}
+
+#[test]
+fn re_ingest_only_embeds_new_content() {
+    let (rust, python) = corpus();
+    let embedder = Arc::new(KeywordEmbedder::default());
+    let store = Arc::new(InMemoryVectorStore::new());
+    let pipeline = RagPipeline::builder()
+        .loader(rust.clone())
+        .loader(python)
+        .chunker(LineChunker)
+        .embedder(embedder.clone())
+        .store(store.clone())
+        .build();
+    pipeline.ingest().unwrap();
+    let first_batches = embedder.batches().len();
+
+    let unchanged = pipeline.ingest().unwrap();
+    assert_eq!((unchanged.stored, unchanged.skipped), (0, 5));
+    assert_eq!(embedder.batches().len(), first_batches);
+
+    rust.push("rust-2.md", "rust ownership\nrust macros");
+    let grown = pipeline.ingest().unwrap();
+
+    assert_eq!((grown.chunks, grown.stored, grown.skipped), (7, 1, 6));
+    assert_eq!(embedder.batches().last().unwrap(), &["rust macros"]);
+    assert_eq!(store.len(), 6);
+}
+
+#[test]
+fn repeated_content_in_one_run_is_embedded_once() {
+    let loader = MemoryLoader::with(&[("a.md", "rust traits"), ("b.md", "rust traits")]);
+    let embedder = Arc::new(KeywordEmbedder::default());
+    let pipeline = RagPipeline::builder()
+        .loader(loader)
+        .chunker(LineChunker)
+        .embedder(embedder.clone())
+        .build();
+
+    let summary = pipeline.ingest().unwrap();
+
+    assert_eq!((summary.stored, summary.skipped), (1, 1));
+    assert_eq!(embedder.batches(), [vec!["rust traits"]]);
+}
+
+#[test]
+fn retrieve_returns_scored_chunks_with_their_source() {
+    let (rust, python) = corpus();
+    let pipeline = RagPipeline::builder()
+        .loaders(vec![Box::new(rust), Box::new(python)])
+        .chunker(LineChunker)
+        .embedder(Arc::new(KeywordEmbedder::default()))
+        .build();
+    pipeline.ingest().unwrap();
+
+    let hits = pipeline.retrieve("python", 2).unwrap();
+
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|h| h.metadata["source"] == json!("python.md")));
+    assert!(hits[0].score > 0.99);
+    assert!(hits[0].id.is_some());
+    assert_eq!(pipeline.store().store_name(), "InMemoryVectorStore");
+}
+
+#[test]
+fn query_filter_scopes_hits_and_is_echoed_back() {
+    let loader = MemoryLoader::with(&[
+        ("notes/100%_done.md", "rust done"),
+        ("notes/100x_done.md", "rust nearly done"),
+        ("notes/my_rust.md", "rust mine"),
+        ("notes/myxrust.md", "rust not mine"),
+    ]);
+    let pipeline = RagPipeline::builder()
+        .loader(loader)
+        .chunker(LineChunker)
+        .embedder(Arc::new(KeywordEmbedder::default()))
+        .build();
+    pipeline.ingest().unwrap();
+    let sources = |filter: &QueryFilter| {
+        let result = pipeline.query("rust", Some(10), Some(filter)).unwrap();
+        assert_eq!(result.filter.as_ref(), Some(filter));
+        let mut sources: Vec<String> = result
+            .hits
+            .iter()
+            .map(|h| h.metadata["source"].as_str().unwrap().to_string())
+            .collect();
+        sources.sort();
+        sources
+    };
+
+    // `%` and `_` in a glob are literal characters, not wildcards.
+    assert_eq!(sources(&QueryFilter::new().include_source("notes/100%_*")), ["notes/100%_done.md"]);
+    assert_eq!(
+        sources(&QueryFilter::new().include_source("notes/*").exclude_source("*/my_*")),
+        ["notes/100%_done.md", "notes/100x_done.md", "notes/myxrust.md"]
+    );
+
+    let none = pipeline
+        .query("rust", None, Some(&QueryFilter::new().include_source("wiki/*")))
+        .unwrap();
+    assert!(none.hits.is_empty());
+}
+
+#[test]
+fn loader_failures_name_the_loader() {
+    let pipeline = RagPipeline::builder()
+        .loader(FailingLoader)
+        .chunker(LineChunker)
+        .embedder(Arc::new(KeywordEmbedder::default()))
+        .build();
+
+    let err = pipeline.ingest().unwrap_err();
+
+    assert!(err.to_string().contains("FailingLoader failed: disk on fire"), "{}", err);
+}
+
+#[cfg(feature = "ai_ml")]
+#[test]
+fn rag_tool_delegates_to_its_pipeline() {
+    use std::collections::HashMap;
+
+    use crewai_tools::RagTool;
+
+    let (rust, python) = corpus();
+    let pipeline = RagPipeline::builder()
+        .loader(rust)
+        .loader(python)
+        .chunker(LineChunker)
+        .embedder(Arc::new(KeywordEmbedder::default()))
+        .build();
+    let tool = RagTool::new().with_top_k(1).with_pipeline(Arc::new(pipeline));
+
+    assert_eq!(tool.ingest().unwrap().stored, 5);
+    let args = HashMap::from([("query".to_string(), json!("rust"))]);
+    let result = tool.run(args).unwrap();
+
+    assert_eq!(result["count"], json!(1));
+    assert_eq!(result["results"][0]["metadata"]["source"], json!("rust.md"));
+    assert!(RagTool::new().ingest().is_err());
+}
@@ -0,0 +1,97 @@
+//! Retry behavior of the HTTP-backed search tools, against a local mock
+//! server that answers from a fixed script of responses.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use common::{mock_server, OK, UNAVAILABLE};
+use crewai_tools::{BraveSearchTool, SerperDevTool};
+use serde_json::Value;
+
+fn query_args(query: &str) -> HashMap<String, Value> {
+    HashMap::from([("search_query".to_string(), Value::from(query))])
+}
+
+#[test]
+fn serper_retries_until_success() {
+    let (url, hits) = mock_server(vec![UNAVAILABLE, UNAVAILABLE, OK]);
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_backoff_base_ms(1);
+
+    let resp = tool.run(query_args("rust")).unwrap();
+    assert_eq!(resp["organic"][0]["title"], "t");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn brave_retries_until_success() {
+    let (url, hits) = mock_server(vec![UNAVAILABLE, UNAVAILABLE, OK]);
+    let tool = BraveSearchTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_backoff_base_ms(1);
+
+    assert!(tool.run(query_args("rust")).is_ok());
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn honors_retry_after_on_429() {
+    let rate_limited = ("429 Too Many Requests", "Retry-After: 0\r\n", "{}");
+    let (url, hits) = mock_server(vec![rate_limited, OK]);
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_backoff_base_ms(60_000);
+
+    let started = std::time::Instant::now();
+    assert!(tool.run(query_args("rust")).is_ok());
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn reports_attempt_count_when_retries_run_out() {
+    let (url, hits) = mock_server(vec![UNAVAILABLE, UNAVAILABLE, UNAVAILABLE]);
+    let tool = BraveSearchTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_max_retries(2)
+        .with_backoff_base_ms(1);
+
+    let err = tool.run(query_args("rust")).unwrap_err().to_string();
+    assert!(err.contains("after 3 attempts"), "unexpected error: {}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn does_not_retry_client_errors() {
+    let (url, hits) = mock_server(vec![("400 Bad Request", "", r#"{"error":"bad"}"#), OK]);
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_backoff_base_ms(1);
+
+    let resp = tool.run(query_args("rust")).unwrap();
+    assert_eq!(resp["error"], "bad");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn async_run_retries_like_blocking_run() {
+    let (url, hits) = mock_server(vec![UNAVAILABLE, UNAVAILABLE, OK]);
+    let tool = SerperDevTool::new()
+        .with_api_key("test")
+        .with_base_url(url)
+        .with_backoff_base_ms(1);
+
+    let resp = tool.run_async(query_args("rust")).await.unwrap();
+    assert_eq!(resp["organic"][0]["title"], "t");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
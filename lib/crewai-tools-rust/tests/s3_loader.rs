@@ -0,0 +1,99 @@
+//! S3Loader listing, dispatch and size guards against a scripted
+//! S3-compatible endpoint.
+
+#![cfg(all(feature = "rag", feature = "cloud_storage"))]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{mock_server, recording_mock_server};
+use crewai_tools::{BaseLoader, S3Error, S3Loader};
+use serde_json::json;
+
+const LISTING: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/xml\r\n",
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>corpus</Name><Prefix>docs/</Prefix><IsTruncated>false</IsTruncated>
+  <Contents><Key>docs/</Key><Size>0</Size></Contents>
+  <Contents><Key>docs/notes.txt</Key><Size>11</Size></Contents>
+  <Contents><Key>docs/logo.png</Key><Size>40</Size></Contents>
+  <Contents><Key>docs/huge.txt</Key><Size>900000</Size></Contents>
+  <Contents><Key>docs/people.csv</Key><Size>24</Size></Contents>
+</ListBucketResult>"#,
+);
+
+fn loader(url: &str) -> S3Loader {
+    S3Loader::new("corpus", "docs/")
+        .with_endpoint_url(url)
+        .with_region("eu-west-1")
+        .with_access_key_id("AKIDEXAMPLE")
+        .with_secret_access_key("wJalrXUtnFEMI")
+        .with_max_object_size(1024)
+        .with_max_concurrency(1)
+}
+
+#[test]
+fn loads_supported_objects_with_s3_sources() {
+    let (url, hits, heads) = recording_mock_server(vec![
+        LISTING,
+        ("200 OK", "Content-Type: text/plain\r\n", "hello world"),
+        ("200 OK", "Content-Type: text/csv\r\n", "name,role\nAda,engineer\n"),
+    ]);
+
+    let documents = loader(&url).load().unwrap();
+
+    // The folder marker, the image and the oversized object are never fetched.
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "hello world");
+    assert_eq!(documents[0].metadata["source"], json!("s3://corpus/docs/notes.txt"));
+    assert_eq!(documents[0].metadata["key"], json!("docs/notes.txt"));
+    assert_eq!(documents[0].metadata["size"], json!(11));
+    assert_eq!(documents[1].metadata["source"], json!("s3://corpus/docs/people.csv"));
+    assert_eq!(documents[1].metadata["name"], json!("Ada"));
+
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("get /corpus/?"), "{}", heads[0]);
+    assert!(heads[0].contains("prefix=docs%2f"), "{}", heads[0]);
+    assert!(heads[1].starts_with("get /corpus/docs/notes.txt "), "{}", heads[1]);
+    assert!(heads.iter().all(|h| h.contains("authorization: aws4-hmac-sha256")));
+}
+
+#[test]
+fn concurrent_downloads_keep_listing_order() {
+    let (url, _) = mock_server(vec![
+        LISTING,
+        // Either object may take either response, so both bodies parse as CSV.
+        ("200 OK", "Content-Type: text/plain\r\n", "name,role\nAda,engineer\n"),
+        ("200 OK", "Content-Type: text/plain\r\n", "name,role\nAda,engineer\n"),
+    ]);
+
+    let documents = loader(&url).with_max_concurrency(2).load().unwrap();
+
+    let sources: Vec<_> = documents.iter().map(|d| d.metadata["source"].clone()).collect();
+    assert_eq!(sources[0], json!("s3://corpus/docs/notes.txt"));
+    assert_eq!(sources.last(), Some(&json!("s3://corpus/docs/people.csv")));
+}
+
+#[test]
+fn object_errors_fail_the_load() {
+    let (url, _) = mock_server(vec![
+        LISTING,
+        (
+            "403 Forbidden",
+            "Content-Type: application/xml\r\n",
+            "<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>",
+        ),
+        ("200 OK", "Content-Type: text/csv\r\n", "name,role\nAda,engineer\n"),
+    ]);
+
+    let err = loader(&url).load().unwrap_err();
+
+    match err.downcast_ref::<S3Error>() {
+        Some(S3Error::AccessDenied { key, .. }) => assert_eq!(key, "docs/notes.txt"),
+        _ => panic!("expected AccessDenied, got {}", err),
+    }
+}
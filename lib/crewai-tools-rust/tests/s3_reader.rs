@@ -0,0 +1,266 @@
+//! S3ReaderTool against a scripted S3-compatible endpoint.
+
+#![cfg(feature = "cloud_storage")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, mock_server, recording_mock_server};
+use crewai_tools::{S3Error, S3ReaderTool};
+use serde_json::json;
+
+const PAGE_1: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/xml\r\n",
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>reports</Name><Prefix>logs/</Prefix><KeyCount>2</KeyCount>
+  <IsTruncated>true</IsTruncated><NextContinuationToken>tok-2</NextContinuationToken>
+  <Contents><Key>logs/a.log</Key><Size>120</Size><LastModified>2024-05-01T10:00:00.000Z</LastModified></Contents>
+  <Contents><Key>logs/b.log</Key><Size>80</Size><LastModified>2024-05-02T10:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+);
+
+const PAGE_2: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/xml\r\n",
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>reports</Name><Prefix>logs/</Prefix><KeyCount>1</KeyCount>
+  <IsTruncated>false</IsTruncated>
+  <Contents><Key>logs/c.log</Key><Size>42</Size><LastModified>2024-05-03T10:00:00.000Z</LastModified></Contents>
+</ListBucketResult>"#,
+);
+
+fn tool(url: &str) -> S3ReaderTool {
+    S3ReaderTool::new()
+        .with_endpoint_url(url)
+        .with_region("eu-west-1")
+        .with_bucket("reports")
+        .with_access_key_id("AKIDEXAMPLE")
+        .with_secret_access_key("wJalrXUtnFEMI")
+}
+
+#[test]
+fn reads_text_objects_with_a_signed_request() {
+    let (url, _, heads) =
+        recording_mock_server(vec![("200 OK", "Content-Type: text/plain\r\n", "hello world")]);
+    let result = tool(&url).run(args(&[("key", json!("2024/q1 summary.txt"))])).unwrap();
+
+    assert_eq!(result["content"], "hello world");
+    assert_eq!(result["size"], 11);
+    assert_eq!(result["content_type"], "text/plain");
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("get /reports/2024/q1%20summary.txt "), "{}", head);
+    assert!(
+        head.contains("authorization: aws4-hmac-sha256 credential=akidexample/"),
+        "{}",
+        head
+    );
+    assert!(
+        head.contains(
+            "/eu-west-1/s3/aws4_request, \
+             signedheaders=host;x-amz-content-sha256;x-amz-date, signature="
+        ),
+        "{}",
+        head
+    );
+    assert!(head.contains("x-amz-content-sha256: e3b0c44298fc1c149afbf4c8996fb924"), "{}", head);
+}
+
+#[test]
+fn binary_objects_come_back_as_base64() {
+    let (url, _, heads) =
+        recording_mock_server(vec![("200 OK", "Content-Type: image/png\r\n", "\u{0}PNG")]);
+    let result = tool(&url)
+        .run(args(&[("file_path", json!("s3://archive/img/logo.png"))]))
+        .unwrap();
+
+    assert_eq!(result["bucket"], "archive");
+    assert_eq!(result["content_base64"], "AFBORw==");
+    assert!(result.get("content").is_none());
+    assert!(heads.lock().unwrap()[0].starts_with("get /archive/img/logo.png "));
+}
+
+#[test]
+fn byte_ranges_sample_large_objects() {
+    let (url, _, heads) = recording_mock_server(vec![(
+        "206 Partial Content",
+        "Content-Type: text/csv\r\nContent-Range: bytes 0-9/5000\r\n",
+        "id,name\n1,",
+    )]);
+    let result = tool(&url)
+        .with_max_bytes(16)
+        .run(args(&[("key", json!("big.csv")), ("range", json!("0-9"))]))
+        .unwrap();
+
+    assert_eq!(result["content"], "id,name\n1,");
+    assert_eq!(result["range"], "bytes=0-9");
+    assert_eq!(result["total_size"], 5000);
+    assert!(heads.lock().unwrap()[0].contains("range: bytes=0-9"));
+}
+
+#[test]
+fn reads_over_max_bytes_are_refused() {
+    let (url, _) =
+        mock_server(vec![("200 OK", "Content-Type: text/plain\r\n", "0123456789abcdefghij")]);
+    let err = tool(&url)
+        .with_max_bytes(8)
+        .run(args(&[("key", json!("big.txt"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("over max_bytes"), "{}", err);
+    assert!(err.to_string().contains("range"), "{}", err);
+}
+
+#[test]
+fn invalid_ranges_are_rejected_before_any_request() {
+    let (url, hits) = mock_server(vec![]);
+    let err = tool(&url)
+        .run(args(&[("key", json!("a.txt")), ("range", json!("10-2"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("Invalid range"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn missing_objects_report_not_found() {
+    let (url, _) = mock_server(vec![(
+        "404 Not Found",
+        "Content-Type: application/xml\r\n",
+        "<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>",
+    )]);
+    let err = tool(&url).run(args(&[("key", json!("gone.txt"))])).unwrap_err();
+    assert!(err.to_string().contains("s3://reports/gone.txt not found"), "{}", err);
+}
+
+#[test]
+fn access_errors_show_the_s3_message() {
+    let (url, _) = mock_server(vec![(
+        "403 Forbidden",
+        "Content-Type: application/xml\r\n",
+        "<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>",
+    )]);
+    let err = tool(&url).run(args(&[("key", json!("secret.txt"))])).unwrap_err();
+    match err.downcast_ref::<S3Error>() {
+        Some(S3Error::AccessDenied { message, .. }) => assert_eq!(message, "Access Denied"),
+        _ => panic!("expected AccessDenied, got {}", err),
+    }
+}
+
+#[test]
+fn lists_keys_across_pages() {
+    let (url, _, heads) = recording_mock_server(vec![PAGE_1, PAGE_2]);
+    let result = tool(&url).run(args(&[("prefix", json!("logs/"))])).unwrap();
+
+    let keys: Vec<&str> = result["objects"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|o| o["key"].as_str().unwrap())
+        .collect();
+    assert_eq!(keys, ["logs/a.log", "logs/b.log", "logs/c.log"]);
+    assert_eq!(result["objects"][0]["size"], 120);
+    assert_eq!(result["truncated"], false);
+    let heads = heads.lock().unwrap();
+    assert!(
+        heads[0].starts_with("get /reports/?list-type=2&max-keys=1000&prefix=logs%2f "),
+        "{}",
+        heads[0]
+    );
+    assert!(heads[1].contains("continuation-token=tok-2"), "{}", heads[1]);
+}
+
+#[test]
+fn listing_stops_at_max_keys_with_a_continuation_token() {
+    let (url, hits) = mock_server(vec![PAGE_1, PAGE_2]);
+    let result = tool(&url)
+        .run(args(&[
+            ("action", json!("list")),
+            ("prefix", json!("logs/")),
+            ("max_keys", json!(2)),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["objects"].as_array().unwrap().len(), 2);
+    assert_eq!(result["truncated"], true);
+    assert_eq!(result["next_continuation_token"], "tok-2");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn presign_returns_a_get_link_without_calling_s3() {
+    let (url, hits) = mock_server(vec![]);
+    let result = tool(&url)
+        .with_session_token("FQoGZXIvYXdzEXAMPLE/token")
+        .run(args(&[
+            ("action", json!("presign")),
+            ("file_path", json!("s3://reports/2024/q1 summary.txt")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["method"], "GET");
+    assert_eq!(result["expires_in"], 900);
+    let link = result["url"].as_str().unwrap();
+    assert!(
+        link.starts_with(&format!(
+            "{}/reports/2024/q1%20summary.txt?X-Amz-Algorithm=AWS4-HMAC-SHA256\
+             &X-Amz-Credential=AKIDEXAMPLE%2F",
+            url
+        )),
+        "{}",
+        link
+    );
+    assert!(link.contains("%2Feu-west-1%2Fs3%2Faws4_request&X-Amz-Date="), "{}", link);
+    assert!(
+        link.contains(
+            "&X-Amz-Expires=900&X-Amz-Security-Token=FQoGZXIvYXdzEXAMPLE%2Ftoken\
+             &X-Amz-SignedHeaders=host&X-Amz-Signature="
+        ),
+        "{}",
+        link
+    );
+    let signature = link.rsplit_once("X-Amz-Signature=").unwrap().1;
+    assert_eq!(signature.len(), 64);
+    let expires_at = result["expires_at"].as_str().unwrap();
+    assert!(expires_at.ends_with('Z'), "{}", expires_at);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn presign_expiry_is_bounded_locally() {
+    for expires_in in [0, 7 * 24 * 3600 + 1] {
+        let err = tool("http://localhost")
+            .run(args(&[
+                ("action", json!("presign")),
+                ("key", json!("a.txt")),
+                ("expires_in", json!(expires_in)),
+            ]))
+            .unwrap_err();
+        assert!(err.to_string().contains("7 days"), "{}", err);
+    }
+    let result = tool("http://localhost")
+        .run(args(&[
+            ("action", json!("presign")),
+            ("key", json!("a.txt")),
+            ("expires_in", json!(7 * 24 * 3600)),
+        ]))
+        .unwrap();
+    assert!(result["url"].as_str().unwrap().contains("X-Amz-Expires=604800"));
+}
+
+#[test]
+fn half_configured_credentials_are_an_error() {
+    let err = S3ReaderTool::new()
+        .with_bucket("reports")
+        .with_access_key_id("AKIDEXAMPLE")
+        .run(args(&[("key", json!("a.txt"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("must be set together"), "{}", err);
+}
+
+#[test]
+fn debug_redacts_the_secret_key() {
+    let debug = format!("{:?}", tool("http://localhost"));
+    assert!(!debug.contains("wJalrXUtnFEMI"), "{}", debug);
+}
@@ -0,0 +1,202 @@
+//! S3WriterTool against a scripted S3-compatible endpoint.
+
+#![cfg(feature = "cloud_storage")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{S3Error, S3WriterTool};
+use serde_json::json;
+
+const ACCESS_DENIED: &str =
+    "<Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>";
+const NO_SUCH_BUCKET: &str = "<Error><Code>NoSuchBucket</Code>\
+     <Message>The specified bucket does not exist</Message></Error>";
+const XML: &str = "Content-Type: application/xml\r\n";
+
+fn tool(url: &str) -> S3WriterTool {
+    S3WriterTool::new()
+        .with_endpoint_url(url)
+        .with_region("eu-west-1")
+        .with_bucket("reports")
+        .with_access_key_id("AKIDEXAMPLE")
+        .with_secret_access_key("wJalrXUtnFEMI")
+}
+
+fn s3_error(err: &anyhow::Error) -> &S3Error {
+    err.downcast_ref::<S3Error>()
+        .unwrap_or_else(|| panic!("expected an S3Error, got {}", err))
+}
+
+#[test]
+fn puts_text_content_and_returns_url_and_etag() {
+    let (url, _, heads) = recording_mock_server(vec![(
+        "200 OK",
+        "ETag: \"9b2cf535f27731c974343645a3985328\"\r\n",
+        "",
+    )]);
+    let result = tool(&url)
+        .with_storage_class("STANDARD_IA")
+        .with_server_side_encryption("AES256")
+        .run(args(&[("key", json!("notes/today.md")), ("content", json!("# Notes"))]))
+        .unwrap();
+
+    assert_eq!(result["etag"], "9b2cf535f27731c974343645a3985328");
+    assert_eq!(result["url"], format!("{}/reports/notes/today.md", url));
+    assert_eq!(result["size"], 7);
+    assert_eq!(result["multipart"], false);
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("put /reports/notes/today.md "), "{}", head);
+    assert!(head.contains("content-type: text/plain; charset=utf-8"), "{}", head);
+    assert!(head.contains("x-amz-storage-class: standard_ia"), "{}", head);
+    assert!(head.contains("x-amz-server-side-encryption: aes256"), "{}", head);
+    assert!(!head.contains("if-none-match"), "{}", head);
+}
+
+#[test]
+fn decodes_base64_content() {
+    let (url, bodies) = body_recording_mock_server(vec![("200 OK", "ETag: \"e1\"\r\n", "")]);
+    tool(&url)
+        .run(args(&[
+            ("file_path", json!("s3://archive/logo.png")),
+            ("content_base64", json!("aGVsbG8=")),
+            ("content_type", json!("image/png")),
+        ]))
+        .unwrap();
+    assert_eq!(bodies.lock().unwrap()[0], "hello");
+}
+
+#[test]
+fn large_content_uses_multipart_upload() {
+    let (url, _, heads) = recording_mock_server(vec![
+        (
+            "200 OK",
+            XML,
+            "<InitiateMultipartUploadResult><Bucket>reports</Bucket><Key>big.txt</Key>\
+             <UploadId>up-1</UploadId></InitiateMultipartUploadResult>",
+        ),
+        ("200 OK", "ETag: \"p1\"\r\n", ""),
+        ("200 OK", "ETag: \"p2\"\r\n", ""),
+        ("200 OK", "ETag: \"p3\"\r\n", ""),
+        (
+            "200 OK",
+            XML,
+            "<CompleteMultipartUploadResult><ETag>\"abc-3\"</ETag></CompleteMultipartUploadResult>",
+        ),
+    ]);
+    let result = tool(&url)
+        .with_multipart_threshold(8)
+        .with_part_size(4)
+        .run(args(&[("key", json!("big.txt")), ("content", json!("0123456789"))]))
+        .unwrap();
+
+    assert_eq!(result["multipart"], true);
+    assert_eq!(result["parts"], 3);
+    assert_eq!(result["etag"], "abc-3");
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /reports/big.txt?uploads= "), "{}", heads[0]);
+    assert!(heads[1].starts_with("put /reports/big.txt?partnumber=1&uploadid=up-1 "));
+    assert!(heads[3].starts_with("put /reports/big.txt?partnumber=3&uploadid=up-1 "));
+    assert!(heads[4].starts_with("post /reports/big.txt?uploadid=up-1 "), "{}", heads[4]);
+}
+
+#[test]
+fn failed_parts_abort_the_upload() {
+    let (url, _, heads) = recording_mock_server(vec![
+        (
+            "200 OK",
+            XML,
+            "<InitiateMultipartUploadResult><UploadId>up-2</UploadId>\
+             </InitiateMultipartUploadResult>",
+        ),
+        ("403 Forbidden", XML, ACCESS_DENIED),
+        ("204 No Content", "", ""),
+    ]);
+    let err = tool(&url)
+        .with_multipart_threshold(2)
+        .with_part_size(2)
+        .run(args(&[("key", json!("big.txt")), ("content", json!("abcd"))]))
+        .unwrap_err();
+
+    assert!(matches!(s3_error(&err), S3Error::AccessDenied { .. }), "{}", err);
+    let heads = heads.lock().unwrap();
+    assert_eq!(heads.len(), 3);
+    assert!(heads[2].starts_with("delete /reports/big.txt?uploadid=up-2 "), "{}", heads[2]);
+}
+
+#[test]
+fn if_not_exists_refuses_to_overwrite() {
+    let (url, _, heads) = recording_mock_server(vec![(
+        "412 Precondition Failed",
+        XML,
+        "<Error><Code>PreconditionFailed</Code><Message>At least one of the pre-conditions \
+         you specified did not hold</Message></Error>",
+    )]);
+    let err = tool(&url)
+        .with_if_not_exists(true)
+        .run(args(&[("key", json!("report.csv")), ("content", json!("a,b"))]))
+        .unwrap_err();
+
+    assert!(matches!(s3_error(&err), S3Error::AlreadyExists { .. }), "{}", err);
+    assert!(heads.lock().unwrap()[0].contains("if-none-match: *"));
+}
+
+#[test]
+fn missing_buckets_and_denied_access_are_distinct() {
+    let (url, _) = mock_server(vec![("404 Not Found", XML, NO_SUCH_BUCKET)]);
+    let err = tool(&url)
+        .run(args(&[("key", json!("a.txt")), ("content", json!("a"))]))
+        .unwrap_err();
+    match s3_error(&err) {
+        S3Error::NoSuchBucket { bucket } => assert_eq!(bucket, "reports"),
+        other => panic!("expected NoSuchBucket, got {:?}", other),
+    }
+
+    let (url, _) = mock_server(vec![("403 Forbidden", "", "")]);
+    let err = tool(&url)
+        .run(args(&[("key", json!("a.txt")), ("content", json!("a"))]))
+        .unwrap_err();
+    assert!(matches!(s3_error(&err), S3Error::AccessDenied { .. }), "{}", err);
+}
+
+#[test]
+fn requires_exactly_one_content_argument() {
+    let (url, hits) = mock_server(vec![]);
+    let err = tool(&url).run(args(&[("key", json!("a.txt"))])).unwrap_err();
+    assert!(err.to_string().contains("content"), "{}", err);
+    let err = tool(&url)
+        .run(args(&[
+            ("key", json!("a.txt")),
+            ("content", json!("a")),
+            ("content_base64", json!("YQ==")),
+        ]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not both"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn presign_returns_a_put_link_pinning_the_content_type() {
+    let (url, hits) = mock_server(vec![]);
+    let result = tool(&url)
+        .run(args(&[
+            ("action", json!("presign")),
+            ("key", json!("uploads/data.csv")),
+            ("content_type", json!("text/csv")),
+            ("expires_in", json!(3600)),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["method"], "PUT");
+    assert_eq!(result["bucket"], "reports");
+    assert_eq!(result["key"], "uploads/data.csv");
+    assert_eq!(result["headers"], json!({"content-type": "text/csv"}));
+    assert_eq!(result["expires_in"], 3600);
+    let link = result["url"].as_str().unwrap();
+    assert!(link.starts_with(&format!("{}/reports/uploads/data.csv?", url)), "{}", link);
+    assert!(link.contains("X-Amz-SignedHeaders=content-type%3Bhost"), "{}", link);
+    assert!(!link.contains("X-Amz-Security-Token"), "{}", link);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,135 @@
+//! ScrapeElementFromWebsiteTool against a local server: selector matching,
+//! attributes, output formats and error handling.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, mock_server};
+use crewai_tools::ScrapeElementFromWebsiteTool;
+use serde_json::{json, Value};
+
+const HTML: &str = "Content-Type: text/html; charset=utf-8\r\n";
+
+const PAGE: &str = "<html><body>\
+    <div class=\"card\"><a href=\"/posts/1\" title=\"First\">One  <b>bold</b></a></div>\
+    <div class=\"card\"><img src=\"img/2.png\" alt=\"Two\"><span>second\n card</span></div>\
+    <div class=\"ad\">Buy now</div>\
+    </body></html>";
+
+fn select(url: &str, selector: &str, format: &str) -> Result<Value, anyhow::Error> {
+    ScrapeElementFromWebsiteTool::new()
+        .with_css_selector("div.ad")
+        .run(args(&[
+            ("website_url", json!(format!("{}/blog/", url))),
+            ("css_selector", json!(selector)),
+            ("output_format", json!(format)),
+        ]))
+}
+
+#[test]
+fn every_match_is_returned_with_text_and_inner_html() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    let out = select(&url, "div.card", "text").unwrap();
+    assert_eq!(out["url"], format!("{}/blog/", url));
+    assert_eq!(out["css_selector"], "div.card");
+    assert_eq!(out["count"], 2);
+    let matches = out["matches"].as_array().unwrap();
+    assert_eq!(matches[0]["text"], "One bold");
+    assert_eq!(
+        matches[0]["html"],
+        "<a href=\"/posts/1\" title=\"First\">One  <b>bold</b></a>"
+    );
+    assert_eq!(matches[1]["text"], "second card");
+}
+
+#[test]
+fn link_attributes_are_resolved_against_the_page_url() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE), ("200 OK", HTML, PAGE)]);
+
+    let link = &select(&url, "a", "text").unwrap()["matches"][0];
+    assert_eq!(link["href"], format!("{}/posts/1", url));
+    assert_eq!(link["title"], "First");
+
+    let image = &select(&url, "img", "text").unwrap()["matches"][0];
+    assert_eq!(image["src"], format!("{}/blog/img/2.png", url));
+    assert_eq!(image["alt"], "Two");
+    assert!(image.get("href").is_none());
+}
+
+#[test]
+fn configured_selector_applies_when_none_is_given() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+    let tool = ScrapeElementFromWebsiteTool::new()
+        .with_website_url(url)
+        .with_css_selector("div.ad");
+
+    let out = tool.run(args(&[])).unwrap();
+    assert_eq!(out["css_selector"], "div.ad");
+    assert_eq!(out["matches"][0]["text"], "Buy now");
+}
+
+#[test]
+fn markdown_and_html_formats_render_the_whole_element() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE), ("200 OK", HTML, PAGE)]);
+
+    let markdown = select(&url, "div.card", "markdown").unwrap();
+    assert_eq!(markdown["output_format"], "markdown");
+    assert_eq!(markdown["matches"][0]["text"], "[One **bold**](/posts/1)");
+
+    let html = select(&url, "div.ad", "html").unwrap();
+    assert_eq!(
+        html["matches"][0]["text"],
+        "<div class=\"ad\">Buy now</div>"
+    );
+    assert_eq!(html["matches"][0]["html"], "Buy now");
+}
+
+#[test]
+fn no_match_is_an_empty_list() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    let out = select(&url, "table", "text").unwrap();
+    assert_eq!(out["count"], 0);
+    assert_eq!(out["matches"], json!([]));
+}
+
+#[test]
+fn invalid_selector_fails_before_fetching() {
+    let (url, hits) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    let err = select(&url, "div[", "text").unwrap_err().to_string();
+    assert!(err.starts_with("Invalid CSS selector 'div['"), "{}", err);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
+
+#[test]
+fn error_pages_are_searched_like_any_other() {
+    let (url, _) = mock_server(vec![("404 Not Found", HTML, "<h1>Not found</h1>")]);
+
+    let out = select(&url, "h1", "text").unwrap();
+    assert_eq!(out["matches"][0]["text"], "Not found");
+}
+
+#[test]
+fn non_html_content_is_refused() {
+    let (url, _) = mock_server(vec![("200 OK", "", "{}")]);
+
+    let err = select(&url, "div", "text").unwrap_err().to_string();
+    assert_eq!(
+        err,
+        format!(
+            "Cannot scrape {}/blog/: content type 'application/json' is not HTML",
+            url
+        )
+    );
+}
+
+#[test]
+fn missing_selector_is_rejected() {
+    let err = ScrapeElementFromWebsiteTool::new()
+        .run(args(&[("website_url", json!("http://127.0.0.1:9/"))]))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Missing required argument: css_selector");
+}
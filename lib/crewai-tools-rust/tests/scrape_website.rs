@@ -0,0 +1,170 @@
+//! ScrapeWebsiteTool against a local server: text extraction, redirects,
+//! charsets and non-2xx or non-HTML responses.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, mock_server, recording_mock_server};
+use crewai_tools::ScrapeWebsiteTool;
+use serde_json::{json, Value};
+
+const HTML: &str = "Content-Type: text/html; charset=utf-8\r\n";
+
+const PAGE: &str = "<html><head><title>Release notes</title>\
+    <style>body { color: red }</style><script>track()</script></head>\
+    <body><nav><a href=\"/\">Home</a></nav>\
+    <h1>Version 2</h1><p>Faster   builds\n and  fewer bugs.</p>\
+    <ul><li>Incremental cache</li><li>New linker</li></ul>\
+    <footer>Copyright</footer></body></html>";
+
+fn scrape(url: String, format: &str) -> Result<Value, anyhow::Error> {
+    ScrapeWebsiteTool::new()
+        .with_ignore_robots(true)
+        .run(args(&[
+            ("website_url", json!(url)),
+            ("output_format", json!(format)),
+        ]))
+}
+
+#[test]
+fn page_is_reduced_to_readable_text() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    let out = scrape(format!("{}/notes", url), "text").unwrap();
+    assert_eq!(out["url"], format!("{}/notes", url));
+    assert_eq!(out["title"], "Release notes");
+    assert_eq!(out["status_code"], 200);
+    assert_eq!(out["output_format"], "text");
+    assert_eq!(
+        out["text"],
+        "Version 2\n\nFaster builds and fewer bugs.\n\nIncremental cache\nNew linker"
+    );
+}
+
+#[test]
+fn markdown_keeps_headings_and_lists() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    let text = scrape(url, "markdown").unwrap()["text"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(text.contains("# Version 2"), "{}", text);
+    assert!(
+        text.contains("- Incremental cache\n- New linker"),
+        "{}",
+        text
+    );
+    for dropped in ["Home", "Copyright", "track()", "color: red"] {
+        assert!(!text.contains(dropped), "{} in {}", dropped, text);
+    }
+}
+
+#[test]
+fn html_format_returns_the_page_unchanged() {
+    let (url, _) = mock_server(vec![("200 OK", HTML, PAGE)]);
+
+    assert_eq!(scrape(url, "html").unwrap()["text"], PAGE);
+}
+
+#[test]
+fn redirects_are_followed_and_reported() {
+    let (url, _, requests) = recording_mock_server(vec![
+        ("301 Moved Permanently", "Location: /new\r\n", ""),
+        ("200 OK", HTML, PAGE),
+    ]);
+
+    let out = scrape(format!("{}/old", url), "text").unwrap();
+    assert_eq!(out["url"], format!("{}/new", url));
+    assert_eq!(out["status_code"], 200);
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("get /old "));
+    assert!(requests[1].starts_with("get /new "));
+}
+
+#[test]
+fn body_is_decoded_with_the_declared_charset() {
+    // "é" is two UTF-8 bytes, which read as windows-1252 are "Ã©".
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "Content-Type: text/html; charset=windows-1252\r\n",
+        "<p>café</p>",
+    )]);
+    assert_eq!(scrape(url, "text").unwrap()["text"], "cafÃ©");
+
+    // Without a header charset the <meta> tag decides.
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "Content-Type: text/html\r\n",
+        "<meta charset=\"iso-8859-1\"><p>café</p>",
+    )]);
+    assert_eq!(scrape(url, "text").unwrap()["text"], "cafÃ©");
+
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "Content-Type: text/html\r\n",
+        "<p>café</p>",
+    )]);
+    assert_eq!(scrape(url, "text").unwrap()["text"], "café");
+}
+
+#[test]
+fn error_statuses_are_scraped_and_reported() {
+    let (url, _) = mock_server(vec![(
+        "404 Not Found",
+        HTML,
+        "<head><title>Missing</title></head><h1>Page not found</h1>",
+    )]);
+
+    let out = scrape(url, "text").unwrap();
+    assert_eq!(out["status_code"], 404);
+    assert_eq!(out["title"], "Missing");
+    assert_eq!(out["text"], "Page not found");
+}
+
+#[test]
+fn plain_text_is_returned_trimmed_without_a_title() {
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "Content-Type: text/plain\r\n",
+        "\n  just text \n",
+    )]);
+
+    let out = scrape(url, "markdown").unwrap();
+    assert_eq!(out["text"], "just text");
+    assert_eq!(out["title"], Value::Null);
+}
+
+#[test]
+fn non_html_content_is_refused_by_type() {
+    for mime in ["application/pdf", "image/png"] {
+        let header: &'static str =
+            Box::leak(format!("Content-Type: {}\r\n", mime).into_boxed_str());
+        let (url, _) = mock_server(vec![("200 OK", header, "%PDF")]);
+
+        let err = scrape(format!("{}/file", url), "text")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            format!(
+                "Cannot scrape {}/file: content type '{}' is not HTML",
+                url, mime
+            )
+        );
+    }
+}
+
+#[test]
+fn missing_or_invalid_url_is_rejected() {
+    let err = ScrapeWebsiteTool::new().run(args(&[])).unwrap_err();
+    assert_eq!(err.to_string(), "Missing required argument: website_url");
+
+    let err = scrape("not a url".to_string(), "text").unwrap_err();
+    assert!(
+        err.to_string().starts_with("Invalid URL 'not a url'"),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,165 @@
+//! ScrapflyScrapeWebsiteTool query parameters, response mapping, credits and
+//! throttling against a scripted Scrapfly API.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, mock_server, recording_mock_server};
+use crewai_tools::{ScrapflyScrapeWebsiteTool, ScrapflyThrottled};
+use serde_json::{json, Value};
+
+const SCRAPED: common::Scripted = (
+    "200 OK",
+    "X-Scrapfly-Api-Cost: 6\r\n",
+    r##"{"result": {"status_code": 200, "content": "# Page"},
+        "context": {"cost": {"total": 5, "details": [{"code": "ASP", "amount": 5}]}}}"##,
+);
+
+fn tool(url: &str) -> ScrapflyScrapeWebsiteTool {
+    ScrapflyScrapeWebsiteTool::new()
+        .with_base_url(url)
+        .with_api_key("sf-test")
+        .with_url("https://example.com/page")
+}
+
+#[test]
+fn options_are_sent_as_query_parameters() {
+    let (url, _, requests) = recording_mock_server(vec![SCRAPED, SCRAPED]);
+
+    tool(&url).run(args(&[])).unwrap();
+    tool(&url)
+        .with_anti_scraping(false)
+        .run(args(&[
+            ("format", json!("raw")),
+            ("render_js", json!(true)),
+            ("country", json!("us")),
+            ("proxy_pool", json!("public_residential_pool")),
+        ]))
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    let line = |i: usize| requests[i].lines().next().unwrap().to_string();
+    assert_eq!(
+        line(0),
+        "get /scrape?key=sf-test&format=markdown&asp=true&render_js=false\
+         &url=https%3a%2f%2fexample.com%2fpage http/1.1"
+    );
+    assert_eq!(
+        line(1),
+        "get /scrape?key=sf-test&format=raw&asp=false&render_js=true&country=us\
+         &proxy_pool=public_residential_pool&url=https%3a%2f%2fexample.com%2fpage http/1.1"
+    );
+}
+
+#[test]
+fn results_carry_content_and_cost() {
+    let (url, _) = mock_server(vec![SCRAPED]);
+
+    let out = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(out["count"], 1);
+    let result = &out["results"][0];
+    assert_eq!(result["url"], "https://example.com/page");
+    assert_eq!(result["status_code"], 200);
+    assert_eq!(result["content"], "# Page");
+    assert_eq!(result["format"], "markdown");
+    // The cost header wins over the body's total.
+    assert_eq!(result["cost"], 6);
+    assert_eq!(result["cost_details"]["details"][0]["code"], "ASP");
+    assert_eq!(out["credits_used"], 6);
+}
+
+#[test]
+fn cost_falls_back_to_the_body_then_one() {
+    let body_cost = ("200 OK", "", r#"{"context": {"cost": {"total": 5}}}"#);
+    let no_cost = ("200 OK", "", r#"{"result": {}}"#);
+    let (url, _) = mock_server(vec![body_cost, no_cost]);
+
+    let out = tool(&url)
+        .run(args(&[(
+            "urls",
+            json!(["https://a.example", "https://b.example"]),
+        )]))
+        .unwrap();
+    assert_eq!(out["results"][0]["cost"], 5);
+    assert_eq!(out["results"][1]["cost"], 1);
+    assert_eq!(out["results"][1]["content"], Value::Null);
+    assert_eq!(out["credits_used"], 6);
+}
+
+#[test]
+fn budget_stops_before_the_next_url() {
+    let (url, hits) = mock_server(vec![SCRAPED, SCRAPED]);
+    let tool = tool(&url).with_credit_budget(4);
+
+    let out = tool
+        .run(args(&[(
+            "urls",
+            json!(["https://a.example", "https://b.example"]),
+        )]))
+        .unwrap();
+    assert_eq!(out["count"], 1);
+    assert_eq!(out["budget_exhausted"], true);
+    assert_eq!(tool.credits_used(), 6);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn throttling_is_a_typed_error() {
+    let (url, _) = mock_server(vec![
+        (
+            "429 Too Many Requests",
+            "Retry-After: 30\r\n",
+            r#"{"code": "ERR::THROTTLE::MAX_REQUEST_RATE_EXCEEDED", "message": "Slow down"}"#,
+        ),
+        (
+            "422 Unprocessable Entity",
+            "",
+            r#"{"code": "ERR::THROTTLE::MAX_CONCURRENT_REQUEST_EXCEEDED", "message": "Busy", "retry_delay": 5}"#,
+        ),
+    ]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    let throttled = err.downcast_ref::<ScrapflyThrottled>().unwrap();
+    assert_eq!(throttled.retry_after_secs, Some(30));
+    assert_eq!(
+        err.to_string(),
+        "Scrapfly throttled the request (ERR::THROTTLE::MAX_REQUEST_RATE_EXCEEDED): \
+         Slow down; retry after 30s"
+    );
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    let throttled = err.downcast_ref::<ScrapflyThrottled>().unwrap();
+    assert_eq!(
+        throttled.code,
+        "ERR::THROTTLE::MAX_CONCURRENT_REQUEST_EXCEEDED"
+    );
+    assert_eq!(throttled.retry_after_secs, Some(5));
+}
+
+#[test]
+fn other_failures_name_the_url() {
+    let (url, _) = mock_server(vec![(
+        "400 Bad Request",
+        "",
+        r#"{"code": "ERR::SCRAPE::BAD_URL", "message": "Invalid URL"}"#,
+    )]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert!(err.downcast_ref::<ScrapflyThrottled>().is_none());
+    assert_eq!(
+        err.to_string(),
+        "Scrapfly request for https://example.com/page failed (HTTP 400 Bad Request): Invalid URL"
+    );
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    let err = tool("http://127.0.0.1:9")
+        .run(args(&[("format", json!("text"))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown format 'text': expected 'markdown' or 'raw'"
+    );
+}
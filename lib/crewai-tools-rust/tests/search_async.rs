@@ -0,0 +1,163 @@
+//! `run_async` sends the same request as `run` and reports the same result,
+//! for every search tool with an async variant.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common::{args, body_recording_mock_server, recording_mock_server, Scripted, OK};
+use crewai_tools::{BraveSearchTool, ExaSearchTool, SearchCache, SerperDevTool, TavilySearchTool};
+use serde_json::{json, Value};
+
+type Outcome = Result<Value, String>;
+
+fn query() -> std::collections::HashMap<String, Value> {
+    args(&[("search_query", json!("rust")), ("query", json!("rust"))])
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Runtime::new().unwrap().block_on(future)
+}
+
+/// Call `search` against a server answering `response`, blocking and then
+/// async, and check both calls sent the same request and got the same result.
+fn assert_parity(
+    response: Scripted,
+    search: impl Fn(&str, bool) -> Result<Value, anyhow::Error>,
+) -> Outcome {
+    let outcome =
+        |async_: bool, url: &str| -> Outcome { search(url, async_).map_err(|e| e.to_string()) };
+
+    let (url, _, heads) = recording_mock_server(vec![response, response]);
+    let blocking = outcome(false, &url);
+    let non_blocking = outcome(true, &url);
+    assert_eq!(blocking, non_blocking);
+    let heads = heads.lock().unwrap();
+    assert_eq!(heads.len(), 2);
+    // The two reqwest clients emit the same headers in a different order.
+    let sorted = |head: &str| {
+        let mut lines: Vec<&str> = head.lines().collect();
+        lines.sort_unstable();
+        lines.join("\n")
+    };
+    assert_eq!(sorted(&heads[0]), sorted(&heads[1]));
+
+    let (url, bodies) = body_recording_mock_server(vec![response, response]);
+    outcome(false, &url).ok();
+    outcome(true, &url).ok();
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies[0], bodies[1]);
+    blocking
+}
+
+fn brave(url: &str, async_: bool) -> Result<Value, anyhow::Error> {
+    let tool = BraveSearchTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_country("de")
+        .with_max_retries(0);
+    if async_ {
+        block_on(tool.run_async(query()))
+    } else {
+        tool.run(query())
+    }
+}
+
+fn serper(url: &str, async_: bool) -> Result<Value, anyhow::Error> {
+    let tool = SerperDevTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_search_type("news")
+        .with_max_retries(0);
+    if async_ {
+        block_on(tool.run_async(query()))
+    } else {
+        tool.run(query())
+    }
+}
+
+fn tavily(url: &str, async_: bool) -> Result<Value, anyhow::Error> {
+    let tool = TavilySearchTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_search_depth("advanced");
+    if async_ {
+        block_on(tool.run_async(query()))
+    } else {
+        tool.run(query())
+    }
+}
+
+fn exa(url: &str, async_: bool) -> Result<Value, anyhow::Error> {
+    let tool = ExaSearchTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_max_results(3);
+    if async_ {
+        block_on(tool.run_async(query()))
+    } else {
+        tool.run(query())
+    }
+}
+
+#[test]
+fn successful_searches_match() {
+    for search in [brave, serper, tavily, exa] {
+        assert_eq!(
+            assert_parity(OK, search),
+            Ok(json!({"organic": [{"title": "t"}]}))
+        );
+    }
+}
+
+#[test]
+fn error_statuses_are_handled_alike() {
+    let unauthorized: Scripted = ("401 Unauthorized", "", r#"{"message": "bad key"}"#);
+
+    // Brave and Serper hand client-error bodies back to the caller.
+    assert_eq!(
+        assert_parity(unauthorized, brave),
+        Ok(json!({"message": "bad key"}))
+    );
+    assert_eq!(
+        assert_parity(unauthorized, serper),
+        Ok(json!({"message": "bad key"}))
+    );
+    assert_eq!(
+        assert_parity(unauthorized, tavily),
+        Err("Tavily request failed: HTTP 401 Unauthorized".to_string())
+    );
+    assert_eq!(
+        assert_parity(unauthorized, exa),
+        Err("EXA request failed: HTTP 401 Unauthorized".to_string())
+    );
+
+    let unavailable: Scripted = ("503 Service Unavailable", "", "{}");
+    assert_eq!(
+        assert_parity(unavailable, serper),
+        Err("Serper request failed after 1 attempt: HTTP 503 Service Unavailable".to_string())
+    );
+}
+
+#[test]
+fn async_and_blocking_calls_share_the_cache() {
+    let (url, _, heads) = recording_mock_server(vec![OK]);
+    let cache = Arc::new(SearchCache::new(Duration::from_secs(60), 16));
+    let tool = SerperDevTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_cache(cache.clone());
+
+    let first = tool.run(query()).unwrap();
+    let second = block_on(tool.run_async(query())).unwrap();
+
+    assert_eq!(first["cached"], false);
+    assert_eq!(second["cached"], true);
+    assert_eq!(first["organic"], second["organic"]);
+    assert_eq!(heads.lock().unwrap().len(), 1);
+    assert_eq!(cache.stats().hits, 1);
+}
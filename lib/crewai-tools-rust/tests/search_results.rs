@@ -0,0 +1,148 @@
+//! Provider responses normalized into `SearchResult`, through
+//! `run_normalized` against mock APIs and the `from_*_response` mappers.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::{args, mock_server};
+use crewai_tools::{BraveSearchTool, SearchResult, SerperDevTool, TavilySearchTool};
+use serde_json::{json, Map, Value};
+
+fn query(q: &str) -> std::collections::HashMap<String, Value> {
+    args(&[("search_query", json!(q)), ("query", json!(q))])
+}
+
+fn extra(value: Value) -> Map<String, Value> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn brave_results_map_description_and_age() {
+    let body = r#"{"type": "search", "web": {"results": [
+      {"title": "Rust", "url": "https://rust-lang.org", "description": "A language",
+       "page_age": "2024-05-01", "age": "May 1, 2024", "language": "en"},
+      {"title": "Bare", "url": "https://bare.example"}
+    ]}}"#;
+    let (url, _) = mock_server(vec![("200 OK", "", body)]);
+
+    let tool = BraveSearchTool::new().with_api_key("k").with_base_url(url);
+
+    let results = tool.run_normalized(query("rust")).unwrap();
+
+    assert_eq!(
+        results,
+        [
+            SearchResult {
+                title: Some("Rust".into()),
+                url: Some("https://rust-lang.org".into()),
+                snippet: Some("A language".into()),
+                source: "brave".into(),
+                score: None,
+                published: Some("2024-05-01".into()),
+                // The unused fallback key stays with the other unknown fields.
+                extra: extra(json!({"age": "May 1, 2024", "language": "en"})),
+            },
+            SearchResult {
+                title: Some("Bare".into()),
+                url: Some("https://bare.example".into()),
+                snippet: None,
+                source: "brave".into(),
+                score: None,
+                published: None,
+                extra: Map::new(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn serper_results_cover_every_result_list() {
+    let body = r#"{"searchParameters": {"q": "rust"},
+      "organic": [{"title": "Rust", "link": "https://rust-lang.org", "snippet": "A language",
+                   "position": 1}],
+      "places": [{"title": "Rust Cafe", "website": "https://cafe.example", "address": "1 Main St",
+                  "rating": 4.5}],
+      "news": [{"title": "Rust 2.0", "link": "https://news.example", "date": "1 day ago"}]}"#;
+    let (url, _) = mock_server(vec![("200 OK", "", body)]);
+
+    let tool = SerperDevTool::new().with_api_key("k").with_base_url(url);
+
+    let results = tool.run_normalized(query("rust")).unwrap();
+
+    let summary: Vec<[Option<&str>; 4]> = results
+        .iter()
+        .map(|r| [r.title.as_deref(), r.url.as_deref(), r.snippet.as_deref(), r.published.as_deref()])
+        .collect();
+    // Lists are read in a fixed order: organic, news, images, places, shopping.
+    assert_eq!(
+        summary,
+        [
+            [Some("Rust"), Some("https://rust-lang.org"), Some("A language"), None],
+            [Some("Rust 2.0"), Some("https://news.example"), None, Some("1 day ago")],
+            [Some("Rust Cafe"), Some("https://cafe.example"), Some("1 Main St"), None],
+        ]
+    );
+    assert!(results.iter().all(|r| r.source == "serper" && r.score.is_none()));
+    assert_eq!(results[0].extra, extra(json!({"position": 1})));
+    assert_eq!(results[2].extra, extra(json!({"rating": 4.5})));
+}
+
+#[test]
+fn tavily_results_keep_their_score() {
+    let body = r#"{"query": "rust", "results": [
+      {"title": "Rust", "url": "https://rust-lang.org", "content": "A language", "score": 0.93,
+       "published_date": "2024-05-01", "raw_content": null}
+    ]}"#;
+    let (url, _) = mock_server(vec![("200 OK", "", body)]);
+
+    let tool = TavilySearchTool::new().with_api_key("k").with_base_url(url);
+
+    let results = tool.run_normalized(query("rust")).unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].snippet.as_deref(), Some("A language"));
+    assert_eq!(results[0].score, Some(0.93));
+    assert_eq!(results[0].published.as_deref(), Some("2024-05-01"));
+    assert_eq!(results[0].extra, extra(json!({"raw_content": null})));
+}
+
+#[test]
+fn exa_results_fall_back_from_text_to_summary() {
+    let response = json!({"results": [
+        {"title": "Rust", "url": "https://rust-lang.org", "text": "Full text", "summary": "Short",
+         "score": 0.5, "publishedDate": "2024-05-01", "id": "abc"},
+        {"title": "Summary only", "url": "https://s.example", "text": null, "summary": "Short"},
+    ]});
+
+    let results = SearchResult::from_exa_response(&response);
+
+    assert_eq!(results[0].snippet.as_deref(), Some("Full text"));
+    assert_eq!(results[0].extra, extra(json!({"summary": "Short", "id": "abc"})));
+    assert_eq!(results[1].snippet.as_deref(), Some("Short"));
+    assert_eq!(results[1].source, "exa");
+}
+
+#[test]
+fn missing_lists_and_non_string_fields_are_tolerated() {
+    assert!(SearchResult::from_brave_response(&json!({"error": "quota"})).is_empty());
+    assert!(SearchResult::from_serper_response(&json!({})).is_empty());
+
+    let results =
+        SearchResult::from_tavily_response(&json!({"results": [{"title": 42, "score": "high"}]}));
+    assert_eq!(results[0].title.as_deref(), Some("42"));
+    assert_eq!(results[0].score, None);
+    assert_eq!(results[0].extra, extra(json!({"score": "high"})));
+    assert_eq!(results[0].url, None);
+}
+
+#[test]
+fn normalized_results_serialize_without_an_empty_extra() {
+    let result = SearchResult::from_brave_response(&json!({"web": {"results": [{"title": "t"}]}}));
+
+    assert_eq!(
+        serde_json::to_value(&result[0]).unwrap(),
+        json!({"title": "t", "url": null, "snippet": null, "source": "brave", "score": null,
+               "published": null})
+    );
+}
@@ -0,0 +1,129 @@
+//! Request timeouts and proxy settings of the HTTP-backed search tools.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use common::{args, recording_mock_server, OK};
+use crewai_tools::{BraveSearchTool, ExaSearchTool, SerperDevTool, TavilySearchTool};
+use serde_json::json;
+
+/// A server that accepts connections but never answers.
+fn silent_server() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    (listener, url)
+}
+
+fn query() -> std::collections::HashMap<String, serde_json::Value> {
+    args(&[("search_query", json!("rust")), ("query", json!("rust"))])
+}
+
+#[test]
+fn default_timeout_is_thirty_seconds() {
+    assert_eq!(BraveSearchTool::new().timeout, Duration::from_secs(30));
+    assert_eq!(SerperDevTool::new().timeout, Duration::from_secs(30));
+    assert_eq!(TavilySearchTool::new().timeout, Duration::from_secs(30));
+    assert_eq!(ExaSearchTool::new().timeout, Duration::from_secs(30));
+}
+
+#[test]
+fn a_hung_upstream_fails_once_the_timeout_elapses() {
+    let (_listener, url) = silent_server();
+    let tool = BraveSearchTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_max_retries(0)
+        .with_timeout(Duration::from_millis(300));
+
+    let started = Instant::now();
+    let err = tool.run(query()).unwrap_err();
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(err.to_string().starts_with("Brave Search request timed out after 1 attempt"), "{}", err);
+}
+
+#[test]
+fn timeouts_are_retried_like_connect_errors() {
+    let (_listener, url) = silent_server();
+    let tool = SerperDevTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_max_retries(1)
+        .with_backoff_base_ms(1)
+        .with_timeout(Duration::from_millis(200));
+
+    let err = tool.run(query()).unwrap_err();
+
+    assert!(err.to_string().starts_with("Serper request timed out after 2 attempts"), "{}", err);
+}
+
+#[tokio::test]
+async fn async_requests_honor_the_timeout() {
+    let (_listener, url) = silent_server();
+    let tool = TavilySearchTool::new()
+        .with_api_key("k")
+        .with_base_url(url)
+        .with_timeout(Duration::from_millis(300));
+
+    let started = Instant::now();
+    let err = tool.run_async(query()).await.unwrap_err();
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(err.to_string().starts_with("Tavily request timed out after 1 attempt"), "{}", err);
+}
+
+#[test]
+fn an_explicit_proxy_receives_every_request() {
+    let (proxy, _, requests) = recording_mock_server(vec![OK, OK]);
+
+    SerperDevTool::new()
+        .with_api_key("k")
+        .with_base_url("http://serper.invalid")
+        .with_proxy(proxy.clone())
+        .run(query())
+        .unwrap();
+    BraveSearchTool::new()
+        .with_api_key("k")
+        .with_base_url("http://brave.invalid/search")
+        .with_proxy(proxy)
+        .run(query())
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("post http://serper.invalid/search "), "{}", requests[0]);
+    assert!(requests[1].starts_with("get http://brave.invalid/search?q=rust"), "{}", requests[1]);
+}
+
+#[test]
+fn an_invalid_proxy_url_is_an_error() {
+    let tool = ExaSearchTool::new().with_api_key("k").with_proxy("::not a url::");
+
+    let err = tool.run(query()).unwrap_err();
+
+    assert!(format!("{:#}", err).contains("relative URL without a base"), "{:#}", err);
+}
+
+#[test]
+fn https_proxy_from_the_environment_is_used_when_none_is_set() {
+    // Only https targets consult HTTPS_PROXY, so the other tests in this
+    // file, which all use http URLs, are unaffected.
+    let (proxy, _, requests) = recording_mock_server(vec![("502 Bad Gateway", "", "")]);
+    for name in ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy", "NO_PROXY", "no_proxy"] {
+        std::env::remove_var(name);
+    }
+    std::env::set_var("HTTPS_PROXY", &proxy);
+
+    let result = TavilySearchTool::new()
+        .with_api_key("k")
+        .with_base_url("https://tavily.invalid/search")
+        .run(query());
+
+    std::env::remove_var("HTTPS_PROXY");
+    assert!(result.is_err());
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("connect tavily.invalid:443 "), "{}", requests[0]);
+}
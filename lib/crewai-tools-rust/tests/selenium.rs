@@ -0,0 +1,201 @@
+//! SeleniumScrapingTool against a scripted WebDriver server: the protocol
+//! exchange, extraction, waiting and error handling.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server, Scripted};
+use crewai_tools::SeleniumScrapingTool;
+use serde_json::{json, Value};
+
+const SESSION: Scripted = (
+    "200 OK",
+    "",
+    r#"{"value": {"sessionId": "s1", "capabilities": {}}}"#,
+);
+const NULL: Scripted = ("200 OK", "", r#"{"value": null}"#);
+const TWO_ELEMENTS: Scripted = (
+    "200 OK",
+    "",
+    r#"{"value": [{"element-6066-11e4-a52e-4f735466cecc": "e1"},
+                  {"element-6066-11e4-a52e-4f735466cecc": "e2"}]}"#,
+);
+const NO_ELEMENTS: Scripted = ("200 OK", "", r#"{"value": []}"#);
+
+fn text(value: &'static str) -> Scripted {
+    (
+        "200 OK",
+        "",
+        Box::leak(json!({ "value": value }).to_string().into_boxed_str()),
+    )
+}
+
+fn tool(url: &str) -> SeleniumScrapingTool {
+    SeleniumScrapingTool::new()
+        .with_webdriver_url(url)
+        .with_website_url("https://example.com/app")
+}
+
+#[test]
+fn selector_matches_are_extracted_and_the_session_closed() {
+    let (url, _, requests) = recording_mock_server(vec![
+        SESSION,
+        NULL,
+        TWO_ELEMENTS,
+        text("First"),
+        text("<li>First</li>"),
+        text("Second"),
+        text("<li>Second</li>"),
+        NULL,
+    ]);
+
+    let out = tool(&url)
+        .run(args(&[("css_selector", json!("li"))]))
+        .unwrap();
+    assert_eq!(out["url"], "https://example.com/app");
+    assert_eq!(out["css_selector"], "li");
+    assert_eq!(out["count"], 2);
+    assert_eq!(
+        out["matches"],
+        json!([
+            {"text": "First", "html": "<li>First</li>"},
+            {"text": "Second", "html": "<li>Second</li>"},
+        ])
+    );
+    assert_eq!(out["text"], "First\n\nSecond");
+    assert!(out.get("screenshot").is_none());
+
+    let lines: Vec<String> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.lines().next().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "post /session http/1.1",
+            "post /session/s1/url http/1.1",
+            "post /session/s1/elements http/1.1",
+            "get /session/s1/element/e1/text http/1.1",
+            "get /session/s1/element/e1/property/outerhtml http/1.1",
+            "get /session/s1/element/e2/text http/1.1",
+            "get /session/s1/element/e2/property/outerhtml http/1.1",
+            "delete /session/s1 http/1.1",
+        ]
+    );
+}
+
+#[test]
+fn requests_carry_capabilities_url_and_selector() {
+    let (url, bodies) = body_recording_mock_server(vec![SESSION, NULL, NO_ELEMENTS, NULL]);
+
+    tool(&url)
+        .with_css_selector("#app")
+        .with_headless(false)
+        .with_wait_timeout(Duration::ZERO)
+        .run(args(&[]))
+        .unwrap_err();
+
+    let bodies = bodies.lock().unwrap();
+    let body = |i: usize| serde_json::from_str::<Value>(&bodies[i]).unwrap();
+    assert_eq!(
+        body(0)["capabilities"]["alwaysMatch"],
+        json!({
+            "browserName": "chrome",
+            "goog:chromeOptions": {"args": ["--no-sandbox", "--disable-dev-shm-usage"]},
+        })
+    );
+    assert_eq!(body(1), json!({"url": "https://example.com/app"}));
+    assert_eq!(body(2), json!({"using": "css selector", "value": "#app"}));
+}
+
+#[test]
+fn headless_adds_chrome_flags() {
+    let (url, bodies) = body_recording_mock_server(vec![(
+        "500 Internal Server Error",
+        "",
+        r#"{"value": {"error": "session not created", "message": "no chrome binary"}}"#,
+    )]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "WebDriver error (HTTP 500 Internal Server Error): session not created: no chrome binary"
+    );
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    let flags = &body["capabilities"]["alwaysMatch"]["goog:chromeOptions"]["args"];
+    assert!(
+        flags.as_array().unwrap().contains(&json!("--headless=new")),
+        "{}",
+        flags
+    );
+}
+
+#[test]
+fn without_a_selector_the_page_load_is_awaited() {
+    let (url, _, requests) = recording_mock_server(vec![
+        SESSION,
+        NULL,
+        text("loading"),
+        text("complete"),
+        (
+            "200 OK",
+            "",
+            r#"{"value": [{"element-6066-11e4-a52e-4f735466cecc": "root"}]}"#,
+        ),
+        text("Loaded"),
+        text("<html>Loaded</html>"),
+        text("iVBORw0KGgo="),
+        NULL,
+    ]);
+
+    let out = tool(&url).with_screenshot(true).run(args(&[])).unwrap();
+    assert_eq!(out["css_selector"], Value::Null);
+    assert_eq!(out["text"], "Loaded");
+    assert_eq!(out["screenshot"], "iVBORw0KGgo=");
+    let requests = requests.lock().unwrap();
+    assert!(requests[2].starts_with("post /session/s1/execute/sync "));
+    assert!(requests[7].starts_with("get /session/s1/screenshot "));
+}
+
+#[test]
+fn missing_selector_times_out_and_still_closes_the_session() {
+    let (url, _, requests) = recording_mock_server(vec![SESSION, NULL, NO_ELEMENTS, NULL]);
+
+    let err = tool(&url)
+        .with_css_selector(".never")
+        .with_wait_timeout(Duration::ZERO)
+        .run(args(&[]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Timed out after 0ns waiting for selector '.never' on https://example.com/app"
+    );
+    assert!(requests.lock().unwrap()[3].starts_with("delete /session/s1 "));
+}
+
+#[test]
+fn unreachable_webdriver_is_named() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let err = tool(&url).run(args(&[])).unwrap_err().to_string();
+    assert!(
+        err.starts_with(&format!("Could not reach WebDriver at {}", url)),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn missing_session_id_is_an_error() {
+    let (url, _) = mock_server(vec![("200 OK", "", r#"{"value": {}}"#)]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(err.to_string(), "WebDriver did not return a session id");
+}
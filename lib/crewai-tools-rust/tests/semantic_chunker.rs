@@ -0,0 +1,152 @@
+//! SemanticChunker breakpoints, size guards and batching, using a keyword
+//! embedder so no embedding API is needed.
+
+#![cfg(feature = "rag")]
+
+use std::sync::{Arc, Mutex};
+
+use crewai_tools::{BaseChunker, Document, EmbeddingService, SemanticChunker};
+use serde_json::json;
+
+/// Embeds a text as counts of a few topic words and records every batch.
+#[derive(Default)]
+struct KeywordEmbedder {
+    batches: Mutex<Vec<Vec<String>>>,
+    short_by_one: bool,
+}
+
+impl EmbeddingService for KeywordEmbedder {
+    fn embed(&self, _text: &str) -> Result<Vec<f32>, anyhow::Error> {
+        panic!("SemanticChunker should only call embed_batch");
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, anyhow::Error> {
+        self.batches
+            .lock()
+            .unwrap()
+            .push(texts.iter().map(|t| t.to_string()).collect());
+        let mut vectors: Vec<Vec<f32>> = texts
+            .iter()
+            .map(|t| {
+                ["cat", "rocket", "bread"]
+                    .iter()
+                    .map(|word| t.matches(word).count() as f32)
+                    .collect()
+            })
+            .collect();
+        if self.short_by_one {
+            vectors.pop();
+        }
+        Ok(vectors)
+    }
+
+    fn model_name(&self) -> &str {
+        "keywords"
+    }
+
+    fn dimensions(&self) -> usize {
+        3
+    }
+}
+
+const TEXT: &str = "The cat sleeps. A cat purrs loudly. Every cat likes fish. \
+    The rocket launches. A rocket needs fuel. That rocket reached orbit. \
+    Fresh bread smells good. The bread needs yeast.";
+
+fn chunker(embedder: &Arc<KeywordEmbedder>) -> SemanticChunker {
+    SemanticChunker::new(embedder.clone())
+        .with_window(0)
+        .with_min_chunk_size(0)
+}
+
+#[test]
+fn splits_where_the_topic_changes() {
+    let embedder = Arc::new(KeywordEmbedder::default());
+    let document = Document::new(TEXT).with_metadata("source", json!("talk.txt"));
+
+    let chunks = chunker(&embedder).chunk(&document).unwrap();
+
+    let contents: Vec<_> = chunks.iter().map(|c| c.content.as_str()).collect();
+    assert_eq!(
+        contents,
+        [
+            "The cat sleeps. A cat purrs loudly. Every cat likes fish.",
+            "The rocket launches. A rocket needs fuel. That rocket reached orbit.",
+            "Fresh bread smells good. The bread needs yeast.",
+        ]
+    );
+    assert_eq!(chunks[2].index, 2);
+    assert_eq!(chunks[1].metadata["source"], json!("talk.txt"));
+
+    let batches = embedder.batches.lock().unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].len(), 8);
+}
+
+#[test]
+fn embeds_in_batches_of_sentence_windows() {
+    let embedder = Arc::new(KeywordEmbedder::default());
+
+    chunker(&embedder)
+        .with_window(1)
+        .with_batch_size(3)
+        .chunk(&Document::new(TEXT))
+        .unwrap();
+
+    let batches = embedder.batches.lock().unwrap();
+    let sizes: Vec<_> = batches.iter().map(Vec::len).collect();
+    assert_eq!(sizes, [3, 3, 2]);
+    assert_eq!(batches[0][0], "The cat sleeps. A cat purrs loudly.");
+    assert_eq!(batches[0][1], "The cat sleeps. A cat purrs loudly. Every cat likes fish.");
+}
+
+#[test]
+fn size_guards_override_breakpoints() {
+    let embedder = Arc::new(KeywordEmbedder::default());
+
+    let merged = chunker(&embedder)
+        .with_min_chunk_size(100)
+        .chunk(&Document::new(TEXT))
+        .unwrap();
+    // The cat chunk is too short to end, and the short bread chunk joins
+    // the one before it.
+    assert_eq!(merged.len(), 1);
+
+    let bounded = chunker(&embedder)
+        .with_min_chunk_size(100)
+        .with_max_chunk_size(150)
+        .chunk(&Document::new(TEXT))
+        .unwrap();
+    assert_eq!(bounded.len(), 2);
+    assert!(bounded[0].content.ends_with("That rocket reached orbit."));
+    assert!(bounded[1].content.starts_with("Fresh bread"), "{}", bounded[1].content);
+
+    let capped = chunker(&embedder)
+        .with_max_chunk_size(40)
+        .chunk(&Document::new(TEXT))
+        .unwrap();
+    assert!(capped.len() > 3);
+    for chunk in &capped {
+        assert!(chunk.content.chars().count() <= 40, "{}", chunk.content);
+    }
+}
+
+#[test]
+fn rejects_bad_settings_and_short_embedding_replies() {
+    let embedder = Arc::new(KeywordEmbedder::default());
+    let document = Document::new(TEXT);
+
+    assert!(chunker(&embedder).with_breakpoint_percentile(120.0).chunk(&document).is_err());
+    assert!(chunker(&embedder)
+        .with_min_chunk_size(500)
+        .with_max_chunk_size(100)
+        .chunk(&document)
+        .is_err());
+
+    let short = Arc::new(KeywordEmbedder {
+        short_by_one: true,
+        ..Default::default()
+    });
+    let err = chunker(&short).chunk(&document).unwrap_err();
+    assert!(err.to_string().contains("returned 7 vectors for 8 sentences"), "{}", err);
+}
@@ -0,0 +1,124 @@
+//! SerperScrapeWebsiteTool requests and response mapping against a scripted
+//! Serper scrape endpoint.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::SerperScrapeWebsiteTool;
+use serde_json::{json, Value};
+
+const SCRAPED: common::Scripted = (
+    "200 OK",
+    "",
+    r##"{"text": "Hello world", "markdown": "# Hello\n\nworld",
+        "metadata": {"title": "Hello", "description": "A greeting", "og:type": "article"},
+        "jsonld": [{"@type": "Article"}, {"@type": "BreadcrumbList"}]}"##,
+);
+
+fn tool(url: &str) -> SerperScrapeWebsiteTool {
+    SerperScrapeWebsiteTool::new()
+        .with_base_url(url)
+        .with_api_key("serper-test")
+        .with_url("https://example.com/post")
+}
+
+#[test]
+fn request_carries_url_markdown_flag_and_key() {
+    let (url, _, requests) = recording_mock_server(vec![SCRAPED]);
+    tool(&url).run(args(&[])).unwrap();
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post / "), "{}", head);
+    assert!(head.contains("x-api-key: serper-test\r\n"), "{}", head);
+
+    let (url, bodies) = body_recording_mock_server(vec![SCRAPED, SCRAPED]);
+    tool(&url).run(args(&[])).unwrap();
+    tool(&url)
+        .run(args(&[
+            ("url", json!("https://example.com/other")),
+            ("include_markdown", json!(false)),
+        ]))
+        .unwrap();
+    let bodies = bodies.lock().unwrap();
+    let body = |i: usize| serde_json::from_str::<Value>(&bodies[i]).unwrap();
+    assert_eq!(
+        body(0),
+        json!({"url": "https://example.com/post", "includeMarkdown": true})
+    );
+    assert_eq!(
+        body(1),
+        json!({"url": "https://example.com/other", "includeMarkdown": false})
+    );
+}
+
+#[test]
+fn text_metadata_and_jsonld_are_returned() {
+    let (url, _) = mock_server(vec![SCRAPED]);
+
+    let out = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(out["url"], "https://example.com/post");
+    assert_eq!(out["text"], "Hello world");
+    assert_eq!(out["markdown"], "# Hello\n\nworld");
+    assert_eq!(out["title"], "Hello");
+    assert_eq!(out["description"], "A greeting");
+    assert_eq!(out["metadata"]["og:type"], "article");
+    assert_eq!(
+        out["jsonld"],
+        json!([{"@type": "Article"}, {"@type": "BreadcrumbList"}])
+    );
+}
+
+#[test]
+fn jsonld_is_always_a_list() {
+    let (url, _) = mock_server(vec![
+        (
+            "200 OK",
+            "",
+            r#"{"text": "t", "jsonld": {"@type": "Product"}}"#,
+        ),
+        ("200 OK", "", r#"{"text": "t", "jsonld": null}"#),
+        ("200 OK", "", r#"{"text": "t"}"#),
+    ]);
+
+    let first = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(first["jsonld"], json!([{"@type": "Product"}]));
+    for _ in 0..2 {
+        let out = tool(&url).run(args(&[])).unwrap();
+        assert_eq!(out["jsonld"], json!([]));
+        assert_eq!(out["markdown"], Value::Null);
+        assert_eq!(out["title"], Value::Null);
+    }
+}
+
+#[test]
+fn failures_carry_status_and_message() {
+    let (url, _) = mock_server(vec![
+        ("403 Forbidden", "", r#"{"message": "Not enough credits"}"#),
+        (
+            "502 Bad Gateway",
+            "Content-Type: text/html\r\n",
+            "<h1>502</h1>",
+        ),
+    ]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Serper scrape failed (HTTP 403 Forbidden): Not enough credits"
+    );
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Serper scrape failed (HTTP 502 Bad Gateway): no details"
+    );
+}
+
+#[test]
+fn missing_url_is_rejected() {
+    let err = SerperScrapeWebsiteTool::new()
+        .with_api_key("serper-test")
+        .run(args(&[]))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Missing required argument: url");
+}
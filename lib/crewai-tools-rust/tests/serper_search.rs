@@ -0,0 +1,88 @@
+//! SerperSearchType parsing and the endpoint and parameters each search
+//! type sends, against a mock Serper API.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, recording_mock_server, OK};
+use crewai_tools::{SerperDateRange, SerperDevTool, SerperSearchType};
+use serde_json::{json, Value};
+
+const TYPES: [(&str, SerperSearchType); 6] = [
+    ("search", SerperSearchType::Search),
+    ("news", SerperSearchType::News),
+    ("images", SerperSearchType::Images),
+    ("places", SerperSearchType::Places),
+    ("scholar", SerperSearchType::Scholar),
+    ("shopping", SerperSearchType::Shopping),
+];
+
+#[test]
+fn names_parse_case_insensitively_and_round_trip() {
+    for (name, search_type) in TYPES {
+        assert_eq!(SerperSearchType::from(name), search_type);
+        assert_eq!(SerperSearchType::from(format!(" {} ", name.to_uppercase())), search_type);
+        assert_eq!(search_type.as_str(), name);
+        assert_eq!(serde_json::to_value(search_type).unwrap(), json!(name));
+        assert_eq!(serde_json::from_value::<SerperSearchType>(json!(name)).unwrap(), search_type);
+    }
+}
+
+#[test]
+fn unknown_names_fall_back_to_search() {
+    for name in ["video", "", "web search"] {
+        assert_eq!(SerperSearchType::from(name), SerperSearchType::Search, "{:?}", name);
+    }
+    // Deserialization goes through the same fallback.
+    let parsed: SerperSearchType = serde_json::from_value(json!("maps")).unwrap();
+    assert_eq!(parsed, SerperSearchType::Search);
+}
+
+#[test]
+fn each_type_posts_to_its_own_endpoint() {
+    for (name, search_type) in TYPES {
+        let (url, _, requests) = recording_mock_server(vec![OK]);
+        let tool = SerperDevTool::new()
+            .with_api_key("k")
+            .with_base_url(format!("{}/", url))
+            .with_search_type(search_type);
+
+        tool.run(args(&[("search_query", json!("rust"))])).unwrap();
+
+        let request = &requests.lock().unwrap()[0];
+        assert!(request.starts_with(&format!("post /{} ", name)), "{}", request);
+        assert!(request.contains("x-api-key: k"), "{}", request);
+    }
+}
+
+/// The JSON body sent for a query with `tool`.
+fn body(tool: SerperDevTool) -> Value {
+    let (url, bodies) = body_recording_mock_server(vec![OK]);
+    tool.with_api_key("k").with_base_url(url).run(args(&[("search_query", json!("rust"))])).unwrap();
+    let body = bodies.lock().unwrap()[0].clone();
+    serde_json::from_str(&body).unwrap()
+}
+
+#[test]
+fn date_range_and_location_are_sent_only_for_their_types() {
+    let news = SerperDevTool::new()
+        .with_search_type("news")
+        .with_date_range(SerperDateRange::PastWeek)
+        .with_location("Berlin");
+    assert_eq!(body(news), json!({"q": "rust", "num": 10, "tbs": "qdr:w"}));
+
+    let places = SerperDevTool::new()
+        .with_search_type("places")
+        .with_date_range(SerperDateRange::PastWeek)
+        .with_location("Berlin");
+    assert_eq!(body(places), json!({"q": "rust", "num": 10, "location": "Berlin"}));
+
+    let web = SerperDevTool::new()
+        .with_date_range(SerperDateRange::PastDay)
+        .with_location("Berlin")
+        .with_country("de")
+        .with_language("en")
+        .with_max_results(3);
+    assert_eq!(body(web), json!({"q": "rust", "num": 3, "gl": "de", "hl": "en"}));
+}
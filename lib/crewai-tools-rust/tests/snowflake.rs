@@ -0,0 +1,135 @@
+//! SnowflakeSearchTool against a scripted SQL API server.
+
+#![cfg(feature = "database")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::recording_mock_server;
+use crewai_tools::{SnowflakeError, SnowflakeSearchTool};
+use serde_json::{json, Value};
+
+const RUNNING: common::Scripted = (
+    "202 Accepted",
+    "",
+    r#"{"statementHandle":"01b2-h","message":"Asynchronous execution in progress."}"#,
+);
+
+const FIRST_PARTITION: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{
+        "statementHandle": "01b2-h",
+        "resultSetMetaData": {
+            "numRows": 3,
+            "format": "jsonv2",
+            "rowType": [
+                {"name": "REGION", "type": "text"},
+                {"name": "ORDERS", "type": "fixed", "scale": 0},
+                {"name": "REVENUE", "type": "fixed", "scale": 2},
+                {"name": "ACTIVE", "type": "boolean"}
+            ],
+            "partitionInfo": [{"rowCount": 2}, {"rowCount": 1}]
+        },
+        "data": [["emea", "42", "1050.25", "true"], ["apac", "7", null, "false"]]
+    }"#,
+);
+
+const SECOND_PARTITION: common::Scripted =
+    ("200 OK", "", r#"{"data": [["amer", "19", "88.10", "true"]]}"#);
+
+fn query(sql: &str) -> HashMap<String, Value> {
+    HashMap::from([("query".to_string(), Value::from(sql))])
+}
+
+fn tool(url: &str) -> SnowflakeSearchTool {
+    SnowflakeSearchTool::new()
+        .with_account("xy12345.us-east-1")
+        .with_username("analyst")
+        .with_warehouse("COMPUTE_WH")
+        .with_base_url(url)
+}
+
+#[test]
+fn polls_fetches_every_partition_and_types_values() {
+    let (url, _, requests) =
+        recording_mock_server(vec![RUNNING, FIRST_PARTITION, SECOND_PARTITION]);
+
+    let result = tool(&url)
+        .with_token("pat-secret")
+        .run(query("SELECT region, orders, revenue, active FROM sales"))
+        .unwrap();
+
+    assert_eq!(result["columns"], json!(["REGION", "ORDERS", "REVENUE", "ACTIVE"]));
+    assert_eq!(result["row_count"], 3);
+    assert_eq!(
+        result["rows"],
+        json!([
+            {"REGION": "emea", "ORDERS": 42, "REVENUE": 1050.25, "ACTIVE": true},
+            {"REGION": "apac", "ORDERS": 7, "REVENUE": null, "ACTIVE": false},
+            {"REGION": "amer", "ORDERS": 19, "REVENUE": 88.1, "ACTIVE": true},
+        ])
+    );
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("post /api/v2/statements "));
+    assert!(requests[0].contains("authorization: bearer pat-secret"));
+    assert!(requests[0]
+        .contains("x-snowflake-authorization-token-type: programmatic_access_token"));
+    assert!(requests[1].starts_with("get /api/v2/statements/01b2-h "));
+    assert!(requests[2].starts_with("get /api/v2/statements/01b2-h?partition=1 "));
+}
+
+#[test]
+fn password_login_uses_the_session_token() {
+    let login = (
+        "200 OK",
+        "",
+        r#"{"success": true, "data": {"token": "sess-tok", "masterToken": "m"}}"#,
+    );
+    let done = (
+        "200 OK",
+        "",
+        r#"{"statementHandle": "h", "resultSetMetaData": {"rowType": [{"name": "N", "type": "fixed", "scale": 0}], "partitionInfo": [{"rowCount": 1}]}, "data": [["1"]]}"#,
+    );
+    let (url, _, requests) = recording_mock_server(vec![login, done]);
+
+    let result = tool(&url).with_password("hunter2").run(query("SELECT 1 AS n")).unwrap();
+
+    assert_eq!(result["rows"], json!([{"N": 1}]));
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("post /session/v1/login-request?warehouse=compute_wh "));
+    assert!(requests[1].contains("authorization: snowflake token=\"sess-tok\""));
+    assert!(!requests[1].contains("x-snowflake-authorization-token-type"));
+}
+
+#[test]
+fn statement_errors_are_typed() {
+    let failed = (
+        "422 Unprocessable Entity",
+        "",
+        r#"{"code": "002003", "sqlState": "42S02", "message": "Table 'NOPE' does not exist"}"#,
+    );
+    let (url, _, _) = recording_mock_server(vec![failed]);
+
+    let err = tool(&url).with_token("t").run(query("SELECT * FROM nope")).unwrap_err();
+
+    match err.downcast_ref::<SnowflakeError>() {
+        Some(SnowflakeError::Statement { sql_state, .. }) => assert_eq!(sql_state, "42S02"),
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn secrets_are_redacted_from_debug_and_serde() {
+    let tool = tool("http://unused").with_password("hunter2").with_token("pat-secret");
+
+    let debug = format!("{:?}", tool);
+    assert!(!debug.contains("hunter2") && !debug.contains("pat-secret"), "{}", debug);
+    assert!(debug.contains("<redacted>"));
+
+    let serialized = serde_json::to_string(&tool).unwrap();
+    assert!(!serialized.contains("hunter2") && !serialized.contains("pat-secret"));
+    assert!(serialized.contains("COMPUTE_WH"));
+}
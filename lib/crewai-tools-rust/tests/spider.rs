@@ -0,0 +1,167 @@
+//! SpiderTool requests, streamed pages and typed errors against a scripted
+//! Spider API.
+
+#![cfg(feature = "web_scraping")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::{SpiderError, SpiderTool};
+use serde_json::{json, Value};
+
+const PAGES: common::Scripted = (
+    "200 OK",
+    "Content-Type: application/jsonl\r\n",
+    "{\"url\": \"https://example.com/\", \"status\": 200, \"content\": \"# Home\"}\n\
+     \n\
+     {\"url\": \"https://example.com/a\", \"status\": 200, \"content\": \"# A\", \"costs\": {}}\n\
+     {\"url\": \"https://example.com/b\", \"status\": 404, \"content\": null}\n",
+);
+
+fn tool(url: &str) -> SpiderTool {
+    SpiderTool::new()
+        .with_base_url(url)
+        .with_api_key("sp-test")
+        .with_url("https://example.com/")
+        .with_max_depth(2)
+}
+
+#[test]
+fn quota_exceeded_is_a_typed_error() {
+    let (url, _) = mock_server(vec![(
+        "402 Payment Required",
+        "",
+        r#"{"error": "Out of credits"}"#,
+    )]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    match err.downcast_ref::<SpiderError>() {
+        Some(SpiderError::QuotaExceeded(message)) => assert_eq!(message, "Out of credits"),
+        other => panic!("expected QuotaExceeded, got {:?}", other),
+    }
+    assert_eq!(err.to_string(), "Spider quota exceeded: Out of credits");
+}
+
+#[test]
+fn other_failures_keep_status_and_message() {
+    let (url, _) = mock_server(vec![
+        ("401 Unauthorized", "", r#"{"error": "Invalid key"}"#),
+        (
+            "500 Internal Server Error",
+            "Content-Type: text/plain\r\n",
+            "boom",
+        ),
+    ]);
+
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<SpiderError>(),
+        Some(SpiderError::Api { status: 401, message }) if message == "Invalid key"
+    ));
+    // Non-JSON bodies are passed through as the message.
+    let err = tool(&url).run(args(&[])).unwrap_err();
+    assert_eq!(err.to_string(), "Spider request failed (HTTP 500): boom");
+}
+
+#[test]
+fn crawl_request_carries_limits_and_format() {
+    let (url, _, requests) = recording_mock_server(vec![PAGES]);
+    tool(&url).with_max_pages(7).run(args(&[])).unwrap();
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /crawl "), "{}", head);
+    assert!(
+        head.contains("authorization: bearer sp-test\r\n"),
+        "{}",
+        head
+    );
+
+    let (url, bodies) = body_recording_mock_server(vec![PAGES]);
+    tool(&url)
+        .run(args(&[
+            ("limit", json!(5)),
+            ("return_format", json!("raw")),
+        ]))
+        .unwrap();
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(
+        body,
+        json!({"url": "https://example.com/", "limit": 5, "depth": 2, "return_format": "raw"})
+    );
+}
+
+#[test]
+fn scrape_mode_asks_for_one_page() {
+    let (url, _, requests) = recording_mock_server(vec![PAGES]);
+
+    let out = tool(&url).run(args(&[("mode", json!("scrape"))])).unwrap();
+    assert!(requests.lock().unwrap()[0].starts_with("post /scrape "));
+    assert_eq!(out["count"], 1);
+    assert_eq!(out["pages"][0]["content"], "# Home");
+}
+
+#[test]
+fn streamed_pages_are_collected() {
+    let (url, _) = mock_server(vec![PAGES]);
+
+    let out = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(out["url"], "https://example.com/");
+    assert_eq!(out["count"], 3);
+    assert_eq!(
+        out["pages"],
+        json!([
+            {"url": "https://example.com/", "status": 200, "content": "# Home"},
+            {"url": "https://example.com/a", "status": 200, "content": "# A"},
+            {"url": "https://example.com/b", "status": 404, "content": null},
+        ])
+    );
+    assert_eq!(out["budget_exhausted"], false);
+}
+
+#[test]
+fn json_array_responses_are_accepted() {
+    let (url, _) = mock_server(vec![(
+        "200 OK",
+        "",
+        r#"[{"url": "https://example.com/", "status": 200, "content": "x"}]"#,
+    )]);
+
+    let out = tool(&url).run(args(&[])).unwrap();
+    assert_eq!(out["count"], 1);
+}
+
+#[test]
+fn budget_caps_the_pages_kept() {
+    let (url, bodies) = body_recording_mock_server(vec![PAGES]);
+    let tool = tool(&url).with_credit_budget(2);
+
+    let out = tool.run(args(&[])).unwrap();
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["limit"], 2);
+    assert_eq!(out["count"], 2);
+    assert_eq!(out["budget_exhausted"], true);
+    assert_eq!(tool.credits_used(), 2);
+
+    // A spent budget makes no request at all.
+    let out = tool.run(args(&[])).unwrap();
+    assert_eq!(out["count"], 0);
+    assert_eq!(out["budget_exhausted"], true);
+}
+
+#[test]
+fn unknown_mode_and_format_are_rejected() {
+    let (url, hits) = mock_server(vec![PAGES]);
+
+    let err = tool(&url).run(args(&[("mode", json!("map"))])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown mode 'map': expected 'crawl' or 'scrape'"
+    );
+    let err = tool(&url)
+        .run(args(&[("return_format", json!("text"))]))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown return_format 'text': expected 'markdown' or 'raw'"
+    );
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
+}
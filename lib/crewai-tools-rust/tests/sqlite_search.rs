@@ -0,0 +1,115 @@
+//! SqliteSearchTool against a throwaway database file.
+
+#![cfg(feature = "database")]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::args;
+use crewai_tools::SqliteSearchTool;
+use serde_json::json;
+
+/// A fresh database with a `notes` table, unique per test.
+fn notes_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "crewai-sqlite-search-{}-{}.sqlite",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE notes (id INTEGER PRIMARY KEY, title TEXT NOT NULL, body VARCHAR(200), stars INTEGER);
+         INSERT INTO notes (title, body, stars) VALUES
+             ('Groceries', 'milk, eggs, 100% rye bread', 1),
+             ('Deploy', 'Roll out the new Bread service', 3),
+             ('Ideas', 'nothing yet', 2);",
+    )
+    .unwrap();
+    path
+}
+
+fn tool(path: &Path) -> SqliteSearchTool {
+    SqliteSearchTool::new(path.to_string_lossy())
+}
+
+#[test]
+fn query_returns_objects_keyed_by_column() {
+    let path = notes_db("query");
+
+    let result = tool(&path)
+        .run(args(&[("sql_query", json!("SELECT title, stars FROM notes ORDER BY stars DESC"))]))
+        .unwrap();
+
+    assert_eq!(result["columns"], json!(["title", "stars"]));
+    assert_eq!(result["rows"][0], json!({"title": "Deploy", "stars": 3}));
+    assert_eq!(result["row_count"], 3);
+}
+
+#[test]
+fn writes_are_refused_unless_enabled() {
+    let path = notes_db("writes");
+    let delete = args(&[("sql_query", json!("DELETE FROM notes WHERE stars = 1"))]);
+
+    let err = tool(&path).run(delete.clone()).unwrap_err();
+    assert!(err.to_string().contains("read-only"), "{}", err);
+
+    let result = tool(&path).with_allow_writes(true).run(delete).unwrap();
+    assert_eq!(result["rows_affected"], 1);
+}
+
+#[test]
+fn list_and_describe_tables() {
+    let path = notes_db("introspect");
+
+    let tables = tool(&path).run(args(&[("action", json!("list_tables"))])).unwrap();
+    assert_eq!(tables["rows"], json!([{"name": "notes", "type": "table"}]));
+
+    let described = tool(&path)
+        .run(args(&[("action", json!("describe_table")), ("table", json!("notes"))]))
+        .unwrap();
+    assert_eq!(described["row_count"], 4);
+    assert_eq!(
+        described["rows"][1],
+        json!({"name": "title", "type": "TEXT", "not_null": 1, "default_value": null, "pk": 0})
+    );
+}
+
+#[test]
+fn search_matches_text_columns_case_insensitively() {
+    let path = notes_db("search");
+
+    let result = tool(&path)
+        .run(args(&[
+            ("action", json!("search")),
+            ("table", json!("notes")),
+            ("search_query", json!("bread")),
+        ]))
+        .unwrap();
+
+    let titles: Vec<&str> = result["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["Groceries", "Deploy"]);
+}
+
+#[test]
+fn search_treats_wildcards_literally() {
+    let path = notes_db("wildcards");
+
+    let result = tool(&path)
+        .run(args(&[
+            ("action", json!("search")),
+            ("table", json!("notes")),
+            ("search_query", json!("100%")),
+            ("columns", json!(["body"])),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["row_count"], 1);
+    assert_eq!(result["rows"][0]["title"], "Groceries");
+}
@@ -0,0 +1,172 @@
+//! StagehandTool against a scripted Stagehand server.
+
+#![cfg(feature = "browser")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::StagehandTool;
+use serde_json::{json, Value};
+
+const STARTED: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"success": true, "data": {"sessionId": "sh-1", "available": true}}"#,
+);
+
+const NAVIGATED: common::Scripted = ("200 OK", "", r#"{"success": true, "data": null}"#);
+
+fn tool(url: &str) -> StagehandTool {
+    StagehandTool::new()
+        .with_server_url(url)
+        .with_api_key("bb_test")
+        .with_project_id("proj-1")
+        .with_model_api_key("sk-model")
+}
+
+#[test]
+fn act_starts_a_session_and_navigates_first() {
+    let acted = (
+        "200 OK",
+        "",
+        r#"{"success": true, "data": {"success": true, "message": "Clicked the login button"}}"#,
+    );
+    let (url, _, heads) = recording_mock_server(vec![STARTED, NAVIGATED, acted]);
+
+    let result = tool(&url)
+        .with_model("anthropic", "claude-3-5-sonnet-latest")
+        .run(args(&[
+            ("command", json!("act")),
+            ("instruction", json!("click the login button")),
+            ("url", json!("https://example.com")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["session_id"], "sh-1");
+    assert_eq!(result["command"], "act");
+    assert_eq!(result["result"]["message"], "Clicked the login button");
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /sessions/start "), "{}", heads[0]);
+    assert!(heads[0].contains("x-bb-api-key: bb_test"), "{}", heads[0]);
+    assert!(heads[0].contains("x-model-api-key: sk-model"), "{}", heads[0]);
+    assert!(heads[1].starts_with("post /sessions/sh-1/navigate "), "{}", heads[1]);
+    assert!(heads[2].starts_with("post /sessions/sh-1/act "), "{}", heads[2]);
+}
+
+#[test]
+fn start_request_carries_model_and_headless() {
+    let observed = ("200 OK", "", r#"{"success": true, "data": []}"#);
+    let (url, bodies) = body_recording_mock_server(vec![STARTED, observed]);
+
+    tool(&url)
+        .with_model("anthropic", "claude-3-5-sonnet-latest")
+        .with_headless(false)
+        .run(args(&[("command", json!("observe"))]))
+        .unwrap();
+
+    let start: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(start["modelName"], "anthropic/claude-3-5-sonnet-latest");
+    assert_eq!(start["localBrowserLaunchOptions"]["headless"], false);
+    assert_eq!(start["browserbaseSessionCreateParams"]["projectId"], "proj-1");
+}
+
+#[test]
+fn session_id_is_reused_across_runs() {
+    let observed = (
+        "200 OK",
+        "",
+        r#"{"success": true, "data": [
+            {"selector": "xpath=/html/body/a", "description": "More information link",
+             "method": "click", "arguments": []}
+        ]}"#,
+    );
+    let (url, _, heads) = recording_mock_server(vec![observed]);
+
+    let result = tool(&url)
+        .run(args(&[
+            ("command", json!("observe")),
+            ("instruction", json!("find links")),
+            ("session_id", json!("sh-7")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["session_id"], "sh-7");
+    assert_eq!(result["result"][0]["method"], "click");
+    let heads = heads.lock().unwrap();
+    assert_eq!(heads.len(), 1);
+    assert!(heads[0].starts_with("post /sessions/sh-7/observe "), "{}", heads[0]);
+}
+
+#[test]
+fn extract_sends_the_schema() {
+    let extracted = ("200 OK", "", r#"{"success": true, "data": {"price": "$19.99"}}"#);
+    let (url, bodies) = body_recording_mock_server(vec![extracted]);
+
+    let result = tool(&url)
+        .run(args(&[
+            ("command", json!("extract")),
+            ("instruction", json!("the product price")),
+            ("schema", json!(r#"{"type": "object", "properties": {"price": {"type": "string"}}}"#)),
+            ("session_id", json!("sh-1")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["result"]["price"], "$19.99");
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["instruction"], "the product price");
+    assert_eq!(body["schemaDefinition"]["properties"]["price"]["type"], "string");
+}
+
+#[test]
+fn close_ends_the_session() {
+    let (url, _, heads) = recording_mock_server(vec![NAVIGATED]);
+
+    let result = tool(&url)
+        .run(args(&[("command", json!("close")), ("session_id", json!("sh-1"))]))
+        .unwrap();
+
+    assert_eq!(result["result"]["closed"], true);
+    assert!(heads.lock().unwrap()[0].starts_with("post /sessions/sh-1/end "));
+}
+
+#[test]
+fn expired_sessions_are_explained() {
+    let gone = ("404 Not Found", "", r#"{"success": false, "message": "Session not found"}"#);
+    let (url, _) = mock_server(vec![gone]);
+
+    let err = tool(&url)
+        .run(args(&[
+            ("command", json!("act")),
+            ("instruction", json!("scroll down")),
+            ("session_id", json!("sh-old")),
+        ]))
+        .unwrap_err();
+
+    let message = err.to_string();
+    assert!(message.contains("session sh-old"), "{}", message);
+    assert!(message.contains("omit session_id"), "{}", message);
+}
+
+#[test]
+fn bad_arguments_are_rejected_before_any_request() {
+    let (url, hits) = mock_server(vec![]);
+
+    let err = tool(&url).run(args(&[("command", json!("act"))])).unwrap_err();
+    assert!(err.to_string().contains("instruction"), "{}", err);
+    let err = tool(&url).run(args(&[("command", json!("scrape"))])).unwrap_err();
+    assert!(err.to_string().contains("expected act, extract, observe or close"), "{}", err);
+    let err = tool(&url)
+        .run(args(&[("command", json!("extract")), ("schema", json!("{not json"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not valid JSON"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn debug_redacts_keys() {
+    let debug = format!("{:?}", tool("http://localhost"));
+    assert!(!debug.contains("bb_test"), "{}", debug);
+    assert!(!debug.contains("sk-model"), "{}", debug);
+}
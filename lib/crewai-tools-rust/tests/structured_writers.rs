@@ -0,0 +1,182 @@
+//! CsvWriterTool, JsonWriterTool and StructuredFileWriterTool against files
+//! in a temp directory.
+
+#![cfg(feature = "file_ops")]
+
+mod common;
+
+use common::args;
+use crewai_tools::{CsvWriterTool, JsonWriterTool, StructuredFileWriterTool};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+#[test]
+fn csv_derives_headers_and_quotes_fields() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let tool = CsvWriterTool::new().with_directory(dir.to_string_lossy());
+
+    let result = tool
+        .run(args(&[
+            ("filename", json!("out.csv")),
+            (
+                "rows",
+                json!([
+                    {"name": "Ada, Countess", "score": 9.5},
+                    {"name": "Bob \"B\"", "tags": ["x", "y"]},
+                ]),
+            ),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["headers"], json!(["name", "score", "tags"]));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("out.csv")).unwrap(),
+        "name,score,tags\n\"Ada, Countess\",9.5,\n\"Bob \"\"B\"\"\",,\"[\"\"x\"\",\"\"y\"\"]\"\n"
+    );
+}
+
+#[test]
+fn csv_rejects_keys_outside_explicit_headers() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+
+    let err = CsvWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .run(args(&[
+            ("filename", json!("out.csv")),
+            ("headers", json!(["id"])),
+            ("rows", json!([{"id": 1, "extra": true}])),
+        ]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("'extra'"), "{}", err);
+    assert!(!dir.join("out.csv").exists());
+}
+
+#[test]
+fn csv_append_reuses_the_existing_header() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::fs::write(dir.join("log.csv"), "b,a\n1,2\n").unwrap();
+    let tool = CsvWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_append(true);
+
+    tool.run(args(&[("filename", json!("log.csv")), ("rows", json!([{"a": 4, "b": 3}]))]))
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(dir.join("log.csv")).unwrap(), "b,a\n1,2\n3,4\n");
+
+    let err = tool
+        .run(args(&[("filename", json!("log.csv")), ("rows", json!([{"c": 5}]))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not in the header"), "{}", err);
+}
+
+#[test]
+fn json_write_respects_overwrite() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let tool = JsonWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .with_pretty(false);
+
+    tool.run(args(&[("filename", json!("a.json")), ("data", json!({"x": 1}))]))
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(dir.join("a.json")).unwrap(), "{\"x\":1}\n");
+    assert!(tool
+        .run(args(&[("filename", json!("a.json")), ("data", json!({"x": 2}))]))
+        .is_err());
+}
+
+#[test]
+fn json_merge_is_deep() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    std::fs::write(dir.join("cfg.json"), r#"{"db": {"host": "a", "port": 1}, "debug": false}"#).unwrap();
+
+    JsonWriterTool::new()
+        .with_directory(dir.to_string_lossy())
+        .run(args(&[
+            ("filename", json!("cfg.json")),
+            ("mode", json!("merge")),
+            ("data", json!({"db": {"port": 2}, "name": "svc"})),
+        ]))
+        .unwrap();
+
+    let merged: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("cfg.json")).unwrap()).unwrap();
+    assert_eq!(
+        merged,
+        json!({"db": {"host": "a", "port": 2}, "debug": false, "name": "svc"})
+    );
+}
+
+#[test]
+fn json_append_extends_arrays() {
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path();
+    let tool = JsonWriterTool::new().with_directory(dir.to_string_lossy());
+    let append = |data: Value| {
+        tool.run(args(&[
+            ("filename", json!("items.json")),
+            ("mode", json!("append")),
+            ("data", data),
+        ]))
+    };
+
+    append(json!([1])).unwrap();
+    append(json!(2)).unwrap();
+    append(json!([3, 4])).unwrap();
+
+    let items: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("items.json")).unwrap()).unwrap();
+    assert_eq!(items, json!([1, 2, 3, 4]));
+
+    std::fs::write(dir.join("obj.json"), "{}").unwrap();
+    assert!(tool
+        .run(args(&[
+            ("filename", json!("obj.json")),
+            ("mode", json!("append")),
+            ("data", json!(1)),
+        ]))
+        .is_err());
+}
+
+#[test]
+fn sandbox_root_confines_every_structured_writer() {
+    let tmp = TempDir::new().unwrap();
+    let base = tmp.path();
+    let root = base.join("root");
+    std::fs::create_dir_all(&root).unwrap();
+    let root_str = root.to_string_lossy();
+    let escape = |filename: &str| {
+        vec![
+            StructuredFileWriterTool::new()
+                .with_sandbox_root(root_str.clone())
+                .run(args(&[("filename", json!(filename)), ("data", json!({"a": 1}))])),
+            CsvWriterTool::new()
+                .with_sandbox_root(root_str.clone())
+                .run(args(&[("filename", json!(filename)), ("rows", json!([{"a": 1}]))])),
+            JsonWriterTool::new()
+                .with_sandbox_root(root_str.clone())
+                .run(args(&[("filename", json!(filename)), ("data", json!({"a": 1}))])),
+        ]
+    };
+
+    let outside = base.join("abs.out");
+    for filename in ["../evil.out", &*outside.to_string_lossy()] {
+        for result in escape(filename) {
+            let err = result.unwrap_err();
+            assert!(err.to_string().contains("escapes the sandbox root"), "{}", err);
+        }
+    }
+    assert!(!base.join("evil.out").exists());
+    assert!(!outside.exists());
+
+    CsvWriterTool::new()
+        .with_sandbox_root(root_str.clone())
+        .run(args(&[("filename", json!("sub/ok.csv")), ("rows", json!([{"a": 1}]))]))
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(root.join("sub/ok.csv")).unwrap(), "a\n1\n");
+}
@@ -0,0 +1,89 @@
+//! TokenChunker sizes, sentence boundaries and overlap in tokens.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{BaseChunker, Document, TokenChunker};
+use serde_json::json;
+
+fn sentences(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("Sentence number {} talks about topic {}.", i, i % 7))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn counts_tokens_with_the_configured_encoding() {
+    let chunker = TokenChunker::new();
+    assert_eq!(chunker.count_tokens("hello world").unwrap(), 2);
+    assert_eq!(chunker.count_tokens("").unwrap(), 0);
+
+    let err = TokenChunker::new().with_encoding("no-such-encoding").count_tokens("x").unwrap_err();
+    assert!(err.to_string().contains("Unknown tokenizer encoding"), "{}", err);
+    assert!(TokenChunker::new().with_encoding("gpt-4").count_tokens("x").is_ok());
+}
+
+#[test]
+fn chunks_stay_within_the_token_budget_on_sentence_boundaries() {
+    let text = sentences(40);
+    let document = Document::new(text.clone()).with_metadata("source", json!("notes.txt"));
+    let chunker = TokenChunker::new().with_chunk_size(50).with_chunk_overlap(0);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    assert!(chunks.len() > 1);
+    for (i, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk.index, i);
+        let tokens = chunker.count_tokens(&chunk.content).unwrap();
+        assert!(tokens <= 50, "chunk {} has {} tokens", i, tokens);
+        assert_eq!(chunk.metadata["token_count"], json!(tokens));
+        assert_eq!(chunk.metadata["encoding"], json!("cl100k_base"));
+        assert_eq!(chunk.metadata["source"], json!("notes.txt"));
+        assert!(chunk.content.starts_with("Sentence number"), "{}", chunk.content);
+        assert!(chunk.content.ends_with('.'), "{}", chunk.content);
+    }
+    let rejoined = chunks.iter().map(|c| c.content.as_str()).collect::<Vec<_>>().join(" ");
+    assert_eq!(rejoined, text);
+}
+
+#[test]
+fn consecutive_chunks_share_trailing_sentences() {
+    let document = Document::new(sentences(30));
+    let chunker = TokenChunker::new().with_chunk_size(60).with_chunk_overlap(15);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    assert!(chunks.len() > 1);
+    for pair in chunks.windows(2) {
+        let last_sentence = pair[0].content.rsplit(". ").next().unwrap();
+        assert!(
+            pair[1].content.starts_with(last_sentence),
+            "{:?} does not start with {:?}",
+            pair[1].content,
+            last_sentence
+        );
+    }
+}
+
+#[test]
+fn oversized_sentences_split_between_words_then_tokens() {
+    let long_sentence = "word ".repeat(120);
+    let long_word = "x".repeat(400);
+    let document = Document::new(format!("Short one. {}. {}", long_sentence.trim(), long_word));
+    let chunker = TokenChunker::new().with_chunk_size(30).with_chunk_overlap(0);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    for chunk in &chunks {
+        assert!(chunker.count_tokens(&chunk.content).unwrap() <= 30, "{}", chunk.content);
+    }
+    assert!(chunks.iter().any(|c| c.content.starts_with("Short one. word")));
+    let xs: usize = chunks.iter().map(|c| c.content.matches('x').count()).sum();
+    assert_eq!(xs, 400);
+}
+
+#[test]
+fn overlap_must_be_smaller_than_the_chunk() {
+    let chunker = TokenChunker::new().with_chunk_size(10).with_chunk_overlap(10);
+    assert!(chunker.chunk(&Document::new("Some text.")).is_err());
+}
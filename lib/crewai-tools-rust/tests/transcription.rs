@@ -0,0 +1,150 @@
+//! TranscriptionTool against a scripted Whisper server, with a stand-in
+//! `ffmpeg` for the chunked path.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, recording_mock_server};
+use crewai_tools::TranscriptionTool;
+use serde_json::json;
+
+fn audio(name: &str, bytes: &[u8]) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("crewai-{}-{}.mp3", name, std::process::id()));
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn small_file_is_uploaded_in_one_request() {
+    let path = audio("transcribe-small", b"ID3fake-audio");
+    let reply = ("200 OK", "", r#"{"text": " Welcome to the weekly sync. "}"#);
+    let (url, _, requests) = recording_mock_server(vec![reply]);
+
+    let result = TranscriptionTool::new()
+        .with_base_url(format!("{}/v1", url))
+        .with_api_key("sk-test")
+        .with_language("en")
+        .run(args(&[("audio_path", json!(path.to_string_lossy()))]))
+        .unwrap();
+
+    assert_eq!(
+        result,
+        json!({"text": "Welcome to the weekly sync.", "model": "whisper-1", "chunks": 1})
+    );
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /v1/audio/transcriptions "), "{}", head);
+    assert!(head.contains("content-type: multipart/form-data"), "{}", head);
+    assert!(head.contains("authorization: bearer sk-test"), "{}", head);
+}
+
+#[test]
+fn form_carries_model_language_and_format() {
+    let path = audio("transcribe-form", b"ID3fake-audio");
+    let reply = ("200 OK", "", r#"{"text": "hola", "language": "spanish", "duration": 1.5, "segments": []}"#);
+    let (url, bodies) = body_recording_mock_server(vec![reply]);
+
+    let result = TranscriptionTool::new()
+        .with_base_url(url)
+        .with_model("whisper-large-v3")
+        .run(args(&[
+            ("audio_path", json!(path.to_string_lossy())),
+            ("language", json!("es")),
+            ("response_format", json!("verbose_json")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["language"], "spanish");
+    assert_eq!(result["duration"], 1.5);
+    let body = &bodies.lock().unwrap()[0];
+    for field in [
+        "name=\"model\"\r\n\r\nwhisper-large-v3",
+        "name=\"language\"\r\n\r\nes",
+        "name=\"response_format\"\r\n\r\nverbose_json",
+        "ID3fake-audio",
+    ] {
+        assert!(body.contains(field), "missing {:?} in {}", field, body);
+    }
+}
+
+#[test]
+fn unknown_response_format_is_rejected() {
+    let err = TranscriptionTool::new()
+        .with_api_key("k")
+        .run(args(&[
+            ("audio_path", json!("/tmp/x.mp3")),
+            ("response_format", json!("srt")),
+        ]))
+        .unwrap_err();
+    assert!(err.to_string().contains("verbose_json"), "{}", err);
+}
+
+#[cfg(unix)]
+#[test]
+fn large_file_is_split_at_silence_and_segments_are_offset() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("crewai-ffmpeg-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let log = dir.join("args");
+    let script = dir.join("ffmpeg");
+    // The analysis pass reports 20s of audio with a pause at 9-11s; the
+    // split pass writes two chunks and the segment list.
+    std::fs::write(
+        &script,
+        format!(
+            r#"#!/bin/sh
+case "$*" in *silencedetect*)
+  printf '  Duration: 00:00:20.00, start: 0.0\n[silencedetect] silence_start: 9\n[silencedetect] silence_end: 11 | silence_duration: 2\n' >&2
+  exit 0;;
+esac
+echo "$@" > '{}'
+while [ $# -gt 1 ]; do [ "$1" = -segment_list ] && list=$2; shift; done
+dir=$(dirname "$1")
+printf 'one' > "$dir/chunk000.mp3"
+printf 'two' > "$dir/chunk001.mp3"
+printf 'chunk000.mp3,0.000000,10.000000\nchunk001.mp3,10.000000,20.000000\n' > "$list"
+"#,
+            log.display()
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let first = (
+        "200 OK",
+        "",
+        r#"{"text": "Hello all.", "language": "english", "duration": 10.0, "segments": [{"start": 0.5, "end": 2.0, "text": " Hello all."}]}"#,
+    );
+    let second = (
+        "200 OK",
+        "",
+        r#"{"text": "Next item.", "language": "english", "duration": 10.0, "segments": [{"start": 65.0, "end": 67.5, "text": " Next item."}]}"#,
+    );
+    let (url, _) = body_recording_mock_server(vec![first, second]);
+    let path = audio("transcribe-large", b"0123456789");
+
+    let result = TranscriptionTool::new()
+        .with_base_url(url)
+        .with_ffmpeg_path(script.to_string_lossy())
+        .with_max_upload_bytes(5)
+        .with_chunk_seconds(15)
+        .run(args(&[
+            ("audio_path", json!(path.to_string_lossy())),
+            ("response_format", json!("verbose_json")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["chunks"], 2);
+    assert_eq!(result["text"], "Hello all. Next item.");
+    assert_eq!(result["duration"], 20.0);
+    assert_eq!(
+        result["segments"],
+        json!([
+            {"start_seconds": 0.5, "end_seconds": 2.0, "timestamp": "0:00", "text": "Hello all."},
+            {"start_seconds": 75.0, "end_seconds": 77.5, "timestamp": "1:15", "text": "Next item."},
+        ])
+    );
+    let recorded = std::fs::read_to_string(log).unwrap();
+    assert!(recorded.contains("-segment_times 10.000"), "{}", recorded);
+}
@@ -0,0 +1,113 @@
+//! TxtSearchTool context windows, lossy decoding and the result cap.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::args;
+use crewai_tools::TxtSearchTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+/// Run `tool` over a file holding `contents`.
+fn search(tool: TxtSearchTool, contents: &[u8], query: &str) -> Value {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("notes.txt");
+    std::fs::write(&path, contents).unwrap();
+    tool.with_file_path(path.to_string_lossy())
+        .run(args(&[("search_query", json!(query))]))
+        .unwrap()
+}
+
+/// The line numbers in a `context_before`/`context_after` array.
+fn numbers(context: &Value) -> Vec<u64> {
+    context.as_array().unwrap().iter().map(|l| l["line_number"].as_u64().unwrap()).collect()
+}
+
+#[test]
+fn context_is_clipped_at_the_start_and_end_of_the_file() {
+    let result = search(TxtSearchTool::new(), b"alpha hit\nb\nc\nd\ne\nomega hit\n", "hit");
+
+    assert_eq!(result["count"], 2);
+    let first = &result["results"][0];
+    assert_eq!(first["line_number"], 1);
+    assert_eq!(numbers(&first["context_before"]), Vec::<u64>::new());
+    assert_eq!(numbers(&first["context_after"]), [2, 3]);
+    let last = &result["results"][1];
+    assert_eq!(last["line_number"], 6);
+    assert_eq!(numbers(&last["context_before"]), [4, 5]);
+    assert_eq!(numbers(&last["context_after"]), Vec::<u64>::new());
+    assert_eq!(result["truncated"], false);
+}
+
+#[test]
+fn overlapping_matches_each_get_their_full_context() {
+    let result = search(TxtSearchTool::new(), b"a\nhit one\nhit two\nb\nc\n", "hit");
+
+    let results = result["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(numbers(&results[0]["context_before"]), [1]);
+    assert_eq!(numbers(&results[0]["context_after"]), [3, 4]);
+    assert_eq!(results[0]["context_after"][0]["text"], "hit two");
+    assert_eq!(numbers(&results[1]["context_before"]), [1, 2]);
+    assert_eq!(numbers(&results[1]["context_after"]), [4, 5]);
+}
+
+#[test]
+fn non_utf8_lines_are_decoded_lossily_and_still_match() {
+    let result = search(TxtSearchTool::new().with_context_lines(1), b"caf\xe9 hit\r\nplain\n", "hit");
+
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["line"], "caf\u{fffd} hit");
+    assert_eq!(result["results"][0]["context_after"][0]["text"], "plain");
+}
+
+#[test]
+fn matches_beyond_the_cap_are_reported_as_truncated() {
+    let tool = TxtSearchTool::new().with_context_lines(0).with_max_results(2);
+
+    let result = search(tool, b"hit 1\nhit 2\nmiss\nhit 3\nhit 4\n", "hit");
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["results"][1]["line"], "hit 2");
+    assert_eq!(result["truncated"], true);
+}
+
+/// Once the cap is reached and every context window is complete, the tool
+/// stops reading: a FIFO whose writer never closes does not block it.
+#[cfg(unix)]
+#[test]
+fn reading_stops_once_the_cap_is_reached() {
+    use std::io::Write;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let dir = TempDir::new().unwrap();
+    let fifo = dir.path().join("stream");
+    assert!(std::process::Command::new("mkfifo").arg(&fifo).status().unwrap().success());
+
+    let (release, held) = mpsc::channel::<()>();
+    let writer_path = fifo.clone();
+    let writer = std::thread::spawn(move || {
+        let mut pipe = std::fs::OpenOptions::new().write(true).open(writer_path).unwrap();
+        pipe.write_all(b"hit 1\nhit 2\nmore\nhit 3\n").unwrap();
+        pipe.flush().unwrap();
+        // Keep the write end open so reading to EOF would block.
+        let _ = held.recv();
+    });
+
+    let (done, result) = mpsc::channel();
+    let path = fifo.to_string_lossy().into_owned();
+    std::thread::spawn(move || {
+        let tool = TxtSearchTool::new().with_file_path(path).with_context_lines(1).with_max_results(2);
+        let _ = done.send(tool.run(args(&[("search_query", json!("hit"))])));
+    });
+
+    let result = result.recv_timeout(Duration::from_secs(10)).expect("tool kept reading").unwrap();
+    release.send(()).unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(numbers(&result["results"][1]["context_after"]), [3]);
+    assert_eq!(result["truncated"], true);
+}
@@ -0,0 +1,96 @@
+//! `ingest` on the vector database tools, against scripted servers.
+
+#![cfg(feature = "database")]
+
+mod common;
+
+use common::recording_mock_server;
+use crewai_tools::{QdrantVectorSearchTool, WeaviateVectorSearchTool};
+use serde_json::{json, Value};
+
+fn documents() -> Vec<(String, Vec<f32>, Value)> {
+    vec![
+        ("alpha".to_string(), vec![1.0, 0.0], json!({"lang": "en"})),
+        ("beta".to_string(), vec![0.0, 1.0], json!({"lang": "en"})),
+        ("gamma".to_string(), vec![0.5, 0.5, 0.5], json!({})),
+        ("delta".to_string(), vec![0.7, 0.7], json!({"lang": "de"})),
+    ]
+}
+
+#[test]
+fn qdrant_creates_the_collection_and_batches_upserts() {
+    let (url, _, requests) = recording_mock_server(vec![
+        ("404 Not Found", "", r#"{"status":{"error":"Not found"}}"#),
+        ("200 OK", "", r#"{"result":true,"status":"ok"}"#),
+        ("200 OK", "", r#"{"result":{"status":"completed"},"status":"ok"}"#),
+        ("500 Internal Server Error", "", r#"{"status":{"error":"disk full"}}"#),
+    ]);
+    let tool = QdrantVectorSearchTool::new("docs")
+        .with_qdrant_url(&url)
+        .with_batch_size(2);
+
+    let report = tool.ingest(documents()).unwrap();
+
+    assert_eq!(report.inserted, 2);
+    assert_eq!(report.failed, 2);
+    assert!(report.errors[0].contains("document 2"), "{:?}", report.errors);
+    assert!(report.errors[1].contains("disk full"), "{:?}", report.errors);
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("get /collections/docs "));
+    assert!(requests[1].starts_with("put /collections/docs "));
+    assert!(requests[2].starts_with("put /collections/docs/points?wait=true "));
+    assert!(requests[3].starts_with("put /collections/docs/points?wait=true "));
+}
+
+#[test]
+fn qdrant_refuses_a_collection_of_another_dimension() {
+    let (url, hits, _) = recording_mock_server(vec![(
+        "200 OK",
+        "",
+        r#"{"result":{"config":{"params":{"vectors":{"size":768,"distance":"Cosine"}}}}}"#,
+    )]);
+    let tool = QdrantVectorSearchTool::new("docs").with_qdrant_url(&url);
+
+    let err = tool.ingest(documents()).unwrap_err();
+
+    assert!(err.to_string().contains("768"), "{}", err);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn weaviate_counts_rejected_objects() {
+    let (url, _, requests) = recording_mock_server(vec![
+        ("200 OK", "", r#"{"class":"Doc"}"#),
+        (
+            "200 OK",
+            "",
+            r#"[
+                {"id": "1", "result": {}},
+                {"id": "2", "result": {"errors": {"error": [{"message": "bad vector"}]}}},
+                {"id": "3", "result": {}}
+            ]"#,
+        ),
+    ]);
+    let tool = WeaviateVectorSearchTool::new("Doc").with_weaviate_url(&url);
+
+    let report = tool.ingest(documents()).unwrap();
+
+    assert_eq!(report.inserted, 2);
+    assert_eq!(report.failed, 2);
+    assert!(report.errors.iter().any(|e| e == "document 1: bad vector"), "{:?}", report.errors);
+
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].starts_with("get /v1/schema/doc "));
+    assert!(requests[1].starts_with("post /v1/batch/objects "));
+}
+
+#[test]
+fn empty_ingest_makes_no_requests() {
+    let report = QdrantVectorSearchTool::new("docs")
+        .with_qdrant_url("http://127.0.0.1:9")
+        .ingest(Vec::new())
+        .unwrap();
+
+    assert_eq!((report.inserted, report.failed), (0, 0));
+}
@@ -0,0 +1,174 @@
+//! VectorStore behaviour of InMemoryVectorStore, and the Qdrant adapter
+//! against a scripted server.
+
+#![cfg(feature = "rag")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crewai_tools::{Chunk, InMemoryVectorStore, LanceDbAdapter, QueryFilter, VectorStore};
+use serde_json::json;
+
+fn chunk(content: &str, source: &str) -> Chunk {
+    Chunk {
+        content: content.to_string(),
+        metadata: HashMap::from([("source".to_string(), json!(source))]),
+        index: 0,
+    }
+}
+
+fn filled() -> InMemoryVectorStore {
+    let store = InMemoryVectorStore::new();
+    store
+        .add(
+            vec![
+                chunk("rust ownership", "book/rust.md"),
+                chunk("python decorators", "book/python.md"),
+                chunk("rust lifetimes", "blog/rust.md"),
+            ],
+            vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.9, 0.1]],
+        )
+        .unwrap();
+    store
+}
+
+#[test]
+fn searches_rank_by_cosine_similarity_within_the_filter() {
+    let store: Arc<dyn VectorStore> = Arc::new(filled());
+
+    let hits = store.search(&[1.0, 0.0], 2, None).unwrap();
+    let contents: Vec<_> = hits.iter().map(|h| h.content.as_str()).collect();
+    assert_eq!(contents, ["rust ownership", "rust lifetimes"]);
+    assert!(hits[0].score > hits[1].score);
+    assert!(hits[0].id.is_some());
+
+    let books = QueryFilter::new().include_source("book/*");
+    let hits = store.search(&[1.0, 0.0], 5, Some(&books)).unwrap();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[1].content, "python decorators");
+}
+
+#[test]
+fn ids_come_from_content_so_re_adding_replaces() {
+    let store = filled();
+
+    let ids = store
+        .add(vec![chunk("rust ownership", "book/rust-2e.md")], vec![vec![0.0, -1.0]])
+        .unwrap();
+
+    assert_eq!(store.len(), 3);
+    let hits = store.search(&[0.0, -1.0], 1, None).unwrap();
+    assert_eq!(hits[0].id.as_deref(), Some(ids[0].as_str()));
+    assert_eq!(hits[0].metadata["source"], json!("book/rust-2e.md"));
+
+    assert_eq!(store.delete(&[ids[0].clone(), "missing".to_string()]).unwrap(), 1);
+    assert_eq!(store.len(), 2);
+}
+
+#[test]
+fn persists_to_json_and_loads_back() {
+    let path = std::env::temp_dir().join(format!("crewai-store-{}.json", std::process::id()));
+    let store = filled();
+    store.persist(&path).unwrap();
+
+    let loaded = InMemoryVectorStore::open(&path).unwrap();
+
+    assert_eq!(loaded.len(), 3);
+    let hits = loaded.search(&[0.0, 1.0], 1, None).unwrap();
+    assert_eq!(hits[0].content, "python decorators");
+    assert_eq!(hits[0].metadata["source"], json!("book/python.md"));
+
+    std::fs::write(&path, "{\"version\": 99, \"entries\": []}").unwrap();
+    let err = InMemoryVectorStore::open(&path).unwrap_err();
+    assert!(err.to_string().contains("format version 99"), "{}", err);
+}
+
+#[test]
+fn mismatched_embeddings_are_rejected() {
+    let store = InMemoryVectorStore::new();
+    assert!(store.add(vec![chunk("a", "a.md")], Vec::new()).is_err());
+    assert!(store.is_empty());
+}
+
+#[test]
+fn server_backed_stores_do_not_persist_to_files() {
+    let lance = LanceDbAdapter::new("/tmp/lance");
+    let err = lance.persist(std::path::Path::new("store.json")).unwrap_err();
+    assert!(err.to_string().contains("cannot persist"), "{}", err);
+    assert_eq!(lance.store_name(), "LanceDbAdapter");
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn qdrant_pushes_down_equality_and_filters_sources_locally() {
+    use crewai_tools::{MetadataCondition, QdrantVectorSearchTool};
+    use serde_json::Value;
+
+    let (url, bodies) = common::body_recording_mock_server(vec![(
+        "200 OK",
+        "",
+        r#"{"result":[
+            {"id":"a","score":0.9,
+             "payload":{"text":"one","metadata":{"source":"blog/x.md","lang":"en"}}},
+            {"id":"b","score":0.8,
+             "payload":{"text":"two","metadata":{"source":"book/y.md","lang":"en"}}}
+        ]}"#,
+    )]);
+    let store = QdrantVectorSearchTool::new("docs").with_qdrant_url(&url);
+    let filter = QueryFilter::new()
+        .include_source("book/*")
+        .with_condition(MetadataCondition::equals("lang", json!("en")));
+
+    let hits = store.search(&[0.1, 0.2], 1, Some(&filter)).unwrap();
+
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].id.as_deref(), Some("b"));
+    assert_eq!(hits[0].content, "two");
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body["limit"], json!(4));
+    assert_eq!(
+        body["filter"],
+        json!({"must": [{"key": "metadata.lang", "match": {"value": "en"}}]})
+    );
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn qdrant_deletes_points_by_id() {
+    use crewai_tools::QdrantVectorSearchTool;
+
+    let (url, hits, heads) = common::recording_mock_server(vec![(
+        "200 OK",
+        "",
+        r#"{"result":{"status":"completed"}}"#,
+    )]);
+    let store = QdrantVectorSearchTool::new("docs").with_qdrant_url(&url);
+
+    let removed = store.delete(&["a".to_string(), "b".to_string()]).unwrap();
+
+    assert_eq!(removed, 2);
+    assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    let heads = heads.lock().unwrap();
+    let expected = "post /collections/docs/points/delete?wait=true ";
+    assert!(heads[0].starts_with(expected), "{}", heads[0]);
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn qdrant_reports_which_ids_it_already_holds() {
+    use crewai_tools::QdrantVectorSearchTool;
+
+    let (url, _hits, heads) = common::recording_mock_server(vec![
+        ("200 OK", "", r#"{"result":[{"id":"b"}]}"#),
+        ("404 Not Found", "", r#"{"status":{"error":"Not found: Collection"}}"#),
+    ]);
+    let store = QdrantVectorSearchTool::new("docs").with_qdrant_url(&url);
+    let ids = ["a".to_string(), "b".to_string()];
+
+    assert_eq!(store.contains(&ids).unwrap(), [false, true]);
+    assert_eq!(store.contains(&ids).unwrap(), [false, false]);
+    let heads = heads.lock().unwrap();
+    assert!(heads[0].starts_with("post /collections/docs/points "), "{}", heads[0]);
+}
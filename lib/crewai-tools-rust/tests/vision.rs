@@ -0,0 +1,90 @@
+//! VisionTool against a scripted OpenAI-compatible server.
+
+#![cfg(feature = "ai_ml")]
+
+mod common;
+
+use common::{args, body_recording_mock_server, recording_mock_server};
+use crewai_tools::VisionTool;
+use serde_json::{json, Value};
+
+const ANSWER: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{
+        "model": "llava:13b",
+        "choices": [{"message": {"role": "assistant", "content": "A red square."}}],
+        "usage": {"prompt_tokens": 85, "completion_tokens": 4, "total_tokens": 89}
+    }"#,
+);
+
+#[test]
+fn remote_image_is_sent_by_url_and_usage_returned() {
+    let (url, _, requests) = recording_mock_server(vec![ANSWER]);
+
+    let result = VisionTool::new()
+        .with_base_url(format!("{}/v1", url))
+        .with_api_key("sk-test")
+        .run(args(&[
+            ("image_url", json!("https://example.com/cat.png")),
+            ("question", json!("What colour is it?")),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["answer"], "A red square.");
+    assert_eq!(result["model"], "llava:13b");
+    assert_eq!(result["usage"]["total_tokens"], 89);
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /v1/chat/completions"), "{}", head);
+    assert!(head.contains("authorization: bearer sk-test"), "{}", head);
+}
+
+#[test]
+fn local_image_is_inlined_as_a_data_url() {
+    let path = std::env::temp_dir().join(format!("crewai-vision-{}.bin", std::process::id()));
+    std::fs::write(&path, b"\x89PNG\r\n\x1a\n0000").unwrap();
+    let (url, bodies) = body_recording_mock_server(vec![ANSWER]);
+
+    VisionTool::new()
+        .with_base_url(url)
+        .run(args(&[("image_path", json!(path.to_string_lossy()))]))
+        .unwrap();
+
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    let content = &body["messages"][0]["content"];
+    assert_eq!(content[0]["text"], "Describe this image in detail.");
+    assert_eq!(
+        content[1]["image_url"]["url"],
+        "data:image/png;base64,iVBORw0KGgowMDAw"
+    );
+}
+
+#[test]
+fn oversized_local_image_is_rejected_with_a_hint() {
+    let path = std::env::temp_dir().join(format!("crewai-vision-big-{}.png", std::process::id()));
+    std::fs::write(&path, vec![0u8; 2048]).unwrap();
+
+    let err = VisionTool::new()
+        .with_base_url("http://127.0.0.1:9")
+        .with_max_image_bytes(1024)
+        .run(args(&[("image_path", json!(path.to_string_lossy()))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("downscale"), "{}", err);
+}
+
+#[test]
+fn api_errors_surface_the_message() {
+    let (url, _) = body_recording_mock_server(vec![(
+        "400 Bad Request",
+        "",
+        r#"{"error": {"message": "model does not support images"}}"#,
+    )]);
+
+    let err = VisionTool::new()
+        .with_base_url(url)
+        .run(args(&[("image_url", json!("https://example.com/a.png"))]))
+        .unwrap_err();
+
+    assert!(err.to_string().contains("model does not support images"), "{}", err);
+}
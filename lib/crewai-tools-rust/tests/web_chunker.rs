@@ -0,0 +1,121 @@
+//! WebChunker and html_to_markdown on docs pages: code blocks stay whole,
+//! keep their indentation and carry their language hint.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{html_to_markdown, BaseChunker, Document, WebChunker};
+
+/// A method section in the shape rustdoc renders it: highlighted spans
+/// inside `<pre class="rust">`, inline `<code>` in prose, and an
+/// `example-wrap` around each example.
+const RUSTDOC_PAGE: &str = r##"<!DOCTYPE html><html><head><title>Vec in std::vec - Rust</title>
+<style>.kw { color: red }</style></head>
+<body><nav class="sidebar"><a href="../index.html">std</a></nav>
+<main><section id="main-content" class="content">
+<h1>Struct <a class="struct" href="#">std::vec::Vec</a></h1>
+<details class="toggle method-toggle" open><summary><section id="method.retain" class="method">
+<h4 class="code-header">pub fn <a href="#method.retain" class="fn">retain</a>&lt;F&gt;(&amp;mut self, f: F)</h4></section></summary>
+<div class="docblock"><p>Retains only the elements specified by the predicate.</p>
+<p>In other words, remove all elements <code>e</code> for which <code>f(&amp;e)</code> returns <code>false</code>.
+This method operates in place, visiting each element exactly once in the original order.</p>
+<h5 id="examples"><a class="doc-anchor" href="#examples">§</a>Examples</h5>
+<div class="example-wrap"><pre class="rust rust-example-rendered"><code><span class="kw">let </span><span class="kw-2">mut </span>vec = <span class="macro">vec!</span>[<span class="number">1</span>, <span class="number">2</span>, <span class="number">3</span>, <span class="number">4</span>];
+vec.retain(|<span class="kw-2">&amp;</span>x| x % <span class="number">2 </span>== <span class="number">0</span>);
+<span class="macro">assert_eq!</span>(vec, [<span class="number">2</span>, <span class="number">4</span>]);</code></pre></div>
+<p>Because the elements are visited exactly once in the original order, external state may be used to decide which elements to keep.</p>
+<div class="example-wrap"><pre class="rust rust-example-rendered"><code><span class="kw">fn </span>keep_every_other(vec: <span class="kw-2">&amp;mut </span>Vec&lt;i32&gt;) {
+    <span class="kw">let </span>keep = [<span class="bool-val">false</span>, <span class="bool-val">true</span>, <span class="bool-val">true</span>];
+    <span class="kw">let </span><span class="kw-2">mut </span>iter = keep.iter();
+    vec.retain(|<span class="kw">_</span>| {
+        <span class="kw-2">*</span>iter.next().unwrap()
+    });
+}</code></pre></div>
+<p>Shell output from the same example, from a page using highlight.js classes:</p>
+<pre><code class="language-bash">$ cargo run --example retain
+[2, 3]</code></pre>
+<p>And a plain block with no hint:</p>
+<pre>no language here</pre>
+</div></details></section></main>
+<footer>Rust 1.80</footer></body></html>"##;
+
+const RETAIN_EXAMPLE: &str = "```rust
+let mut vec = vec![1, 2, 3, 4];
+vec.retain(|&x| x % 2 == 0);
+assert_eq!(vec, [2, 4]);
+```";
+
+const KEEP_EVERY_OTHER: &str = "```rust
+fn keep_every_other(vec: &mut Vec<i32>) {
+    let keep = [false, true, true];
+    let mut iter = keep.iter();
+    vec.retain(|_| {
+        *iter.next().unwrap()
+    });
+}
+```";
+
+#[test]
+fn rustdoc_code_blocks_are_fenced_verbatim_with_their_language() {
+    let markdown = html_to_markdown(RUSTDOC_PAGE);
+
+    assert!(markdown.contains(RETAIN_EXAMPLE), "{}", markdown);
+    assert!(markdown.contains(KEEP_EVERY_OTHER), "{}", markdown);
+    assert!(markdown.contains("```bash\n$ cargo run --example retain\n[2, 3]\n```"), "{}", markdown);
+    assert!(markdown.contains("```\nno language here\n```"), "{}", markdown);
+    assert!(!markdown.contains("color: red"));
+}
+
+#[test]
+fn inline_code_becomes_backticks() {
+    let markdown = html_to_markdown(RUSTDOC_PAGE);
+
+    assert!(
+        markdown.contains("remove all elements `e` for which `f(&e)` returns `false`."),
+        "{}",
+        markdown
+    );
+    assert_eq!(html_to_markdown("<p>Use <code>a`b</code> here</p>"), "Use ``a`b`` here");
+}
+
+#[test]
+fn fences_outgrow_backticks_inside_the_code() {
+    let html = "<pre class=\"language-markdown\">```rust\nfn main() {}\n```</pre>";
+
+    assert_eq!(html_to_markdown(html), "````markdown\n```rust\nfn main() {}\n```\n````");
+}
+
+#[test]
+fn chunks_never_split_a_code_block() {
+    let document = Document::new(RUSTDOC_PAGE);
+    let chunker = WebChunker::new().with_chunk_size(220);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    assert!(chunks.len() > 2);
+    let holding = |block: &str| chunks.iter().filter(|c| c.content.contains(block)).count();
+    assert_eq!(holding(RETAIN_EXAMPLE), 1);
+    assert_eq!(holding(KEEP_EVERY_OTHER), 1);
+    for chunk in &chunks {
+        let fences = chunk.content.lines().filter(|l| l.starts_with("```")).count();
+        assert_eq!(fences % 2, 0, "chunk {} opens a fence it does not close:\n{}", chunk.index, chunk.content);
+    }
+}
+
+#[test]
+fn oversize_code_block_becomes_its_own_flagged_chunk() {
+    let document = Document::new(RUSTDOC_PAGE);
+    let chunker = WebChunker::new().with_chunk_size(150);
+
+    let chunks = chunker.chunk(&document).unwrap();
+
+    let oversize: Vec<_> = chunks
+        .iter()
+        .filter(|c| c.metadata.contains_key("oversize_code_block"))
+        .collect();
+    assert_eq!(oversize.len(), 1);
+    assert_eq!(oversize[0].content, KEEP_EVERY_OTHER);
+    assert!(oversize[0].content.chars().count() > 150);
+    for chunk in chunks.iter().filter(|c| !c.metadata.contains_key("oversize_code_block")) {
+        assert!(chunk.content.chars().count() <= 150, "{}", chunk.content);
+    }
+}
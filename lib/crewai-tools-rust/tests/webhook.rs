@@ -0,0 +1,107 @@
+//! WebhookTool against a scripted endpoint.
+
+#![cfg(feature = "automation")]
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use common::{UNAVAILABLE, args, body_recording_mock_server, mock_server, recording_mock_server};
+use crewai_tools::WebhookTool;
+use serde_json::{json, Value};
+
+#[test]
+fn posts_payload_and_returns_status_headers_and_body() {
+    let (url, bodies) = body_recording_mock_server(vec![(
+        "201 Created",
+        "X-Request-Id: abc\r\n",
+        r#"{"received": true}"#,
+    )]);
+    let tool = WebhookTool::new().with_url(format!("{}/hooks/deploy", url));
+    let result = tool
+        .run(args(&[("payload", json!({"service": "api", "version": 3}))]))
+        .unwrap();
+
+    assert_eq!(result["status"], 201);
+    assert_eq!(result["headers"]["x-request-id"], "abc");
+    assert_eq!(result["body"], json!({"received": true}));
+    let sent: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(sent, json!({"service": "api", "version": 3}));
+}
+
+#[test]
+fn sends_bearer_token_and_default_headers() {
+    let (url, _, heads) = recording_mock_server(vec![("200 OK", "", "ok")]);
+    let tool = WebhookTool::new()
+        .with_url(format!("{}/hook", url))
+        .with_header("X-Source", "crew")
+        .with_bearer_token("s3cret");
+    let result = tool.run(args(&[("payload", json!({"a": 1}))])).unwrap();
+
+    assert_eq!(result["body"], "ok");
+    let head = &heads.lock().unwrap()[0];
+    assert!(head.starts_with("post /hook "), "{}", head);
+    assert!(head.contains("authorization: bearer s3cret"), "{}", head);
+    assert!(head.contains("x-source: crew"), "{}", head);
+    assert!(!format!("{:?}", tool).contains("s3cret"));
+    assert!(!serde_json::to_string(&tool).unwrap().contains("s3cret"));
+}
+
+#[test]
+fn get_sends_object_payload_as_query() {
+    let (url, _, heads) = recording_mock_server(vec![("200 OK", "", "{}")]);
+    let tool = WebhookTool::new()
+        .with_url(format!("{}/status", url))
+        .with_method("GET");
+    tool.run(args(&[("payload", json!({"id": "42"}))])).unwrap();
+
+    assert!(heads.lock().unwrap()[0].starts_with("get /status?id=42 "));
+}
+
+#[test]
+fn rejects_hosts_outside_the_allowlist() {
+    let (url, hits) = mock_server(vec![("200 OK", "", "{}")]);
+    let tool = WebhookTool::new().with_url(format!("{}/hook", url));
+
+    let err = tool
+        .run(args(&[("url", json!("http://169.254.169.254/latest/meta-data"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not in allowed_hosts"), "{}", err);
+    assert_eq!(hits.load(Ordering::SeqCst), 0);
+
+    let wildcard = WebhookTool::new().with_allowed_hosts(vec!["*.example.com".to_string()]);
+    let err = wildcard
+        .run(args(&[("url", json!("https://example.com.evil.net/x"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not in allowed_hosts"), "{}", err);
+}
+
+#[test]
+fn retries_server_errors_with_backoff() {
+    let (url, hits) = mock_server(vec![UNAVAILABLE, ("200 OK", "", r#"{"ok": true}"#)]);
+    let tool = WebhookTool::new()
+        .with_url(format!("{}/hook", url))
+        .with_backoff_base_ms(1);
+    let result = tool.run(args(&[("payload", json!({}))])).unwrap();
+
+    assert_eq!(result["status"], 200);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn client_errors_are_returned_not_raised() {
+    let (url, hits) = mock_server(vec![("404 Not Found", "", "no such hook")]);
+    let tool = WebhookTool::new().with_url(format!("{}/gone", url));
+    let result = tool.run(args(&[("payload", json!({}))])).unwrap();
+
+    assert_eq!(result["status"], 404);
+    assert_eq!(result["body"], "no such hook");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn requires_a_url() {
+    let err = WebhookTool::new().run(HashMap::new()).unwrap_err();
+    assert!(err.to_string().contains("url"), "{}", err);
+}
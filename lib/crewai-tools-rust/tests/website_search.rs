@@ -0,0 +1,105 @@
+//! WebsiteSearchTool crawling and ranking against a mock site.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use std::sync::atomic::Ordering;
+
+use common::{args, mock_server, recording_mock_server, Scripted};
+use crewai_tools::WebsiteSearchTool;
+use serde_json::json;
+
+const HTML: &str = "Content-Type: text/html\r\n";
+
+const INDEX: Scripted = (
+    "200 OK",
+    HTML,
+    r#"<html><body><h1>Home</h1><p>Welcome to the rust garden.</p>
+       <a href="/a">A</a> <a href='b#top'>B</a> <a href="/private/x">X</a>
+       <a href="http://elsewhere.example/rust">Away</a> <a href="/feed.json">Feed</a>
+       <a href="/a#again">A again</a></body></html>"#,
+);
+const PAGE_A: Scripted = (
+    "200 OK",
+    HTML,
+    r#"<html><body><script>var rust = 1;</script><p>Rust, rust and more rust.</p>
+       <a href="/deeper">Deeper</a></body></html>"#,
+);
+const PAGE_B: Scripted = ("200 OK", HTML, "<html><body><p>Nothing to see.</p></body></html>");
+const FEED: Scripted = ("200 OK", "", r#"{"rust": true}"#);
+
+fn tool(base: &str) -> WebsiteSearchTool {
+    WebsiteSearchTool::new().with_website_url(format!("{}/", base)).with_crawl_delay_ms(0)
+}
+
+#[test]
+fn crawls_same_origin_links_and_ranks_pages_by_matches() {
+    let robots: Scripted = ("200 OK", "Content-Type: text/plain\r\n", "User-agent: *\nDisallow: /private\n");
+    let (base, hits, requests) = recording_mock_server(vec![robots, INDEX, PAGE_A, PAGE_B, FEED]);
+
+    let result = tool(&base).run(args(&[("search_query", json!("rust"))])).unwrap();
+
+    let paths: Vec<String> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.split_whitespace().nth(1).unwrap().to_string())
+        .collect();
+    assert_eq!(paths, ["/robots.txt", "/", "/a", "/b", "/feed.json"]);
+    assert_eq!(hits.load(Ordering::SeqCst), 5);
+
+    assert_eq!(result["ranking"], "keyword");
+    assert_eq!(result["pages_crawled"], 3);
+    assert_eq!(result["pages_skipped"], 1);
+    assert_eq!(result["pages_blocked"].as_array().unwrap().len(), 1);
+    assert!(result["pages_blocked"][0]["url"].as_str().unwrap().ends_with("/private/x"));
+
+    // Script text is not searched; the page with more hits ranks first.
+    let ranked: Vec<(String, u64)> = result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| (r["url"].as_str().unwrap().to_string(), r["matches"].as_u64().unwrap()))
+        .collect();
+    assert_eq!(ranked, [(format!("{}/a", base), 3), (format!("{}/", base), 1)]);
+}
+
+#[test]
+fn max_pages_stops_the_crawl() {
+    let (base, hits) = mock_server(vec![INDEX, PAGE_A, PAGE_B]);
+
+    let result = tool(&base)
+        .with_ignore_robots(true)
+        .with_max_pages(2)
+        .run(args(&[("search_query", json!("rust"))]))
+        .unwrap();
+
+    assert_eq!(result["pages_crawled"], 2);
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn depth_zero_fetches_only_the_start_page() {
+    let (base, hits) = mock_server(vec![INDEX, PAGE_A]);
+
+    let result = tool(&base)
+        .with_ignore_robots(true)
+        .with_max_depth(0)
+        .with_max_results(1)
+        .run(args(&[("search_query", json!("garden"))]))
+        .unwrap();
+
+    assert_eq!(result["pages_crawled"], 1);
+    assert_eq!(result["count"], 1);
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn an_unreachable_start_page_is_an_error() {
+    let (base, _) = mock_server(vec![]);
+
+    let result = tool(&base).with_ignore_robots(true).run(args(&[("search_query", json!("rust"))]));
+
+    assert!(result.unwrap_err().to_string().contains("Failed to fetch"));
+}
@@ -0,0 +1,133 @@
+//! XmlLoader XPath selection, RSS/Atom items and whole-file text.
+
+#![cfg(feature = "rag")]
+
+use crewai_tools::{BaseLoader, MetadataCondition, XmlLoader};
+use serde_json::json;
+
+/// A temp XML file holding `contents`.
+fn xml_file(name: &str, contents: &str) -> String {
+    let path = std::env::temp_dir().join(format!("crewai-xml-{}-{}.xml", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+const CATALOG: &str = r#"<?xml version="1.0"?>
+<catalog>
+  <book id="b1" lang="en"><title>Dune</title><price>9.99</price></book>
+  <book id="b2" lang="fr"><title>Vendredi</title><price>7.50</price></book>
+</catalog>"#;
+
+#[test]
+fn xpath_selects_one_document_per_node() {
+    let path = xml_file("catalog", CATALOG);
+
+    let documents = XmlLoader::new(&path).with_content_xpath("//book").load().unwrap();
+
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[1].content, "Vendredi7.50");
+    assert_eq!(documents[1].metadata["path"], json!("/catalog/book[2]"));
+    assert_eq!(documents[1].metadata["attributes"], json!({"id": "b2", "lang": "fr"}));
+    assert_eq!(documents[1].metadata["source"], json!(path));
+
+    let titles = XmlLoader::new(&path).with_content_xpath("//book/title").load().unwrap();
+    assert_eq!(titles[0].content, "Dune");
+    assert_eq!(titles[0].metadata["path"], json!("/catalog/book[1]/title"));
+
+    let ids = XmlLoader::new(&path).with_content_xpath("//book/@id").load().unwrap();
+    assert_eq!(ids[0].content, "b1");
+    assert_eq!(ids[0].metadata["path"], json!("/catalog/book[1]/@id"));
+}
+
+#[test]
+fn without_xpath_the_element_text_is_one_document() {
+    let path = xml_file("whole", CATALOG);
+
+    let documents = XmlLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0].content, "Dune\n9.99\nVendredi\n7.50");
+    assert_eq!(documents[0].metadata["root"], json!("catalog"));
+}
+
+#[test]
+fn rss_items_map_into_metadata() {
+    let rss = r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Example News</title>
+    <link>https://example.com/</link>
+    <item>
+      <title>Launch day</title>
+      <link>https://example.com/launch</link>
+      <description><![CDATA[<p>We <b>shipped</b> it.</p>]]></description>
+      <pubDate>Tue, 07 May 2024 09:00:00 GMT</pubDate>
+      <dc:creator>Ada</dc:creator>
+      <guid>launch-1</guid>
+    </item>
+    <item>
+      <title>Follow-up</title>
+      <link>https://example.com/follow-up</link>
+    </item>
+  </channel>
+</rss>"#;
+    let path = xml_file("rss", rss);
+
+    let documents = XmlLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 2);
+    let first = &documents[0];
+    assert_eq!(first.content, "Launch day\n\nWe **shipped** it.");
+    assert_eq!(first.metadata["title"], json!("Launch day"));
+    assert_eq!(first.metadata["link"], json!("https://example.com/launch"));
+    assert_eq!(first.metadata["description"], json!("We **shipped** it."));
+    assert_eq!(first.metadata["published"], json!("Tue, 07 May 2024 09:00:00 GMT"));
+    assert_eq!(first.metadata["author"], json!("Ada"));
+    assert_eq!(first.metadata["id"], json!("launch-1"));
+    assert_eq!(first.metadata["feed_title"], json!("Example News"));
+    assert_eq!(documents[1].content, "Follow-up");
+    assert_eq!(documents[1].metadata["item_index"], json!(1));
+    assert!(MetadataCondition::equals("title", json!("Follow-up")).matches(&documents[1].metadata));
+
+    let whole = XmlLoader::new(&path).with_detect_feeds(false).load().unwrap();
+    assert_eq!(whole.len(), 1);
+}
+
+#[test]
+fn atom_entries_use_the_alternate_link() {
+    let atom = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Dev Blog</title>
+  <entry>
+    <title>Release 2.0</title>
+    <link rel="edit" href="https://example.com/api/posts/2"/>
+    <link href="https://example.com/posts/2"/>
+    <id>urn:uuid:2</id>
+    <updated>2024-05-07T09:00:00Z</updated>
+    <summary>Faster builds.</summary>
+    <author><name>Grace</name></author>
+  </entry>
+</feed>"#;
+    let path = xml_file("atom", atom);
+
+    let documents = XmlLoader::new(&path).load().unwrap();
+
+    assert_eq!(documents.len(), 1);
+    let entry = &documents[0];
+    assert_eq!(entry.content, "Release 2.0\n\nFaster builds.");
+    assert_eq!(entry.metadata["link"], json!("https://example.com/posts/2"));
+    assert_eq!(entry.metadata["published"], json!("2024-05-07T09:00:00Z"));
+    assert_eq!(entry.metadata["author"], json!("Grace"));
+    assert_eq!(entry.metadata["id"], json!("urn:uuid:2"));
+    assert_eq!(entry.metadata["feed_title"], json!("Dev Blog"));
+}
+
+#[test]
+fn malformed_xml_and_bad_xpath_are_errors() {
+    let path = xml_file("broken", "<a><b></a>");
+    let err = XmlLoader::new(&path).load().unwrap_err();
+    assert!(err.to_string().contains("Failed to parse XML"), "{}", err);
+
+    let path = xml_file("badxpath", CATALOG);
+    assert!(XmlLoader::new(&path).with_content_xpath("//book[").load().is_err());
+}
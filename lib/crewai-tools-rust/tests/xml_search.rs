@@ -0,0 +1,162 @@
+//! XmlSearchTool over a namespaced fixture: each supported XPath construct,
+//! XPath combined with `search_query`, and result truncation.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::args;
+use crewai_tools::XmlSearchTool;
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+const LIBRARY: &str = r#"<?xml version="1.0"?>
+<library xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <shelf id="s1">
+    <book id="b1" lang="en"><dc:title>Dune</dc:title><author>Herbert</author></book>
+    <book id="b2" lang="de"><dc:title>Der Process</dc:title><author>Kafka</author></book>
+    <book id="b3"><dc:title>Emma</dc:title><author>Austen</author></book>
+  </shelf>
+  <shelf id="s2">
+    <book id="b4" lang="en"><dc:title>Solaris</dc:title><author>Lem</author></book>
+    <magazine id="m1"><dc:title>Wired</dc:title></magazine>
+  </shelf>
+</library>"#;
+
+fn search(pairs: &[(&str, Value)]) -> Result<Value, anyhow::Error> {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("library.xml");
+    std::fs::write(&path, LIBRARY).unwrap();
+    XmlSearchTool::new()
+        .with_file_path(path.to_string_lossy())
+        .run(args(pairs))
+}
+
+fn field(result: &Value, key: &str) -> Vec<String> {
+    result["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r[key].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// The `path` of every node `expr` selects.
+fn paths(expr: &str) -> Vec<String> {
+    field(&search(&[("xpath", json!(expr))]).unwrap(), "path")
+}
+
+/// The `text` of every node `expr` selects.
+fn texts(expr: &str) -> Vec<String> {
+    field(&search(&[("xpath", json!(expr))]).unwrap(), "text")
+}
+
+#[test]
+fn absolute_relative_and_descendant_paths() {
+    assert_eq!(texts("/library/shelf/book/author"), ["Herbert", "Kafka", "Austen", "Lem"]);
+    assert_eq!(texts("shelf/magazine/title"), ["Wired"]);
+    assert_eq!(texts("/library//author"), ["Herbert", "Kafka", "Austen", "Lem"]);
+    assert_eq!(paths("//shelf[2]/*"), ["/library/shelf[2]/book", "/library/shelf[2]/magazine"]);
+}
+
+#[test]
+fn positions_count_per_parent() {
+    assert_eq!(paths("//book[2]"), ["/library/shelf[1]/book[2]"]);
+    assert_eq!(paths("//book[last()]"), ["/library/shelf[1]/book[3]", "/library/shelf[2]/book"]);
+    assert!(paths("//book[4]").is_empty());
+}
+
+#[test]
+fn attribute_predicates() {
+    assert_eq!(texts("//book[@lang='en']/@id"), ["b1", "b4"]);
+    assert_eq!(texts("//book[@lang]/@id"), ["b1", "b2", "b4"]);
+    assert_eq!(texts("//*[contains(@id, '3')]/title"), ["Emma"]);
+}
+
+#[test]
+fn child_text_and_contains_predicates() {
+    assert_eq!(texts("//book[author='Kafka']/title"), ["Der Process"]);
+    assert_eq!(texts("//title[.='Emma']"), ["Emma"]);
+    assert_eq!(texts("//book[contains(., 'Sol')]/author"), ["Lem"]);
+    assert_eq!(texts("//title[contains(text(), 'Pro')]"), ["Der Process"]);
+}
+
+#[test]
+fn local_name_and_parent_steps() {
+    assert_eq!(texts("//*[local-name()='magazine']/@id"), ["m1"]);
+    assert_eq!(texts("//author[.='Lem']/../@id"), ["b4"]);
+    assert_eq!(texts("//magazine/./@id"), ["m1"]);
+}
+
+#[test]
+fn prefixed_names_match_on_local_name() {
+    let titles = ["Dune", "Der Process", "Emma", "Solaris", "Wired"];
+    assert_eq!(texts("//dc:title"), titles);
+    assert_eq!(texts("//title"), titles);
+    // Prefixes are not resolved, so any prefix matches.
+    assert_eq!(texts("//other:title"), titles);
+}
+
+#[test]
+fn terminal_attribute_and_text_steps() {
+    assert_eq!(texts("//magazine/@*"), ["m1"]);
+    assert_eq!(paths("//magazine/@*"), ["/library/shelf[2]/magazine/@id"]);
+    assert_eq!(texts("//book[1]/author/text()"), ["Herbert", "Lem"]);
+    assert_eq!(
+        paths("//book[1]/author/text()"),
+        ["/library/shelf[1]/book[1]/author/text()", "/library/shelf[2]/book/author/text()"]
+    );
+}
+
+#[test]
+fn unsupported_expressions_are_errors() {
+    for expr in ["//book/@id[1]", "//author/text()[1]", "//book/@id/title", "//book[", "//book[position() > 1]", ""] {
+        assert!(search(&[("xpath", json!(expr))]).is_err(), "{}", expr);
+    }
+    let err = search(&[("xpath", json!("//book/@lang[.='en']"))]).unwrap_err();
+    assert!(err.to_string().contains("predicates on @attribute and text()"), "{}", err);
+}
+
+#[test]
+fn search_query_is_scoped_to_the_xpath_and_searches_attributes() {
+    let result = search(&[("xpath", json!("//shelf[2]")), ("search_query", json!("E"))]).unwrap();
+
+    assert_eq!(
+        field(&result, "path"),
+        [
+            "/library/shelf[2]/book/@lang",
+            "/library/shelf[2]/book/author",
+            "/library/shelf[2]/magazine/title",
+        ]
+    );
+    assert_eq!(field(&result, "text"), ["en", "Lem", "Wired"]);
+}
+
+#[test]
+fn overlapping_selections_are_searched_once() {
+    let result = search(&[("xpath", json!("//*")), ("search_query", json!("kafka"))]).unwrap();
+
+    assert_eq!(field(&result, "path"), ["/library/shelf[1]/book[2]/author"]);
+    assert_eq!(result["truncated"], false);
+}
+
+#[test]
+fn results_beyond_max_results_are_reported_as_truncated() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("library.xml");
+    std::fs::write(&path, LIBRARY).unwrap();
+    let tool = XmlSearchTool::new().with_file_path(path.to_string_lossy()).with_max_results(2);
+
+    let result = tool.run(args(&[("xpath", json!("//book"))])).unwrap();
+
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["truncated"], true);
+    let all = tool.with_max_results(4).run(args(&[("xpath", json!("//book"))])).unwrap();
+    assert_eq!(all["truncated"], false);
+}
+
+#[test]
+fn xpath_or_search_query_is_required() {
+    let err = search(&[]).unwrap_err();
+    assert!(err.to_string().contains("xpath or search_query"), "{}", err);
+}
@@ -0,0 +1,206 @@
+//! YoutubeVideoLoader windows, chapters from description timestamps,
+//! caption fallbacks and missing transcripts against a mock watch page.
+
+#![cfg(feature = "rag")]
+
+mod common;
+
+use common::{mock_server, recording_mock_server};
+use crewai_tools::{BaseLoader, NoTranscript, YoutubeVideoLoader};
+use serde_json::json;
+
+const HTML: &str = "Content-Type: text/html\r\n";
+
+const CAPTIONS: &str = r#"{"events":[
+  {"tStartMs":0,"segs":[{"utf8":"Welcome to"},{"utf8":" the show"}]},
+  {"tStartMs":30000,"segs":[{"utf8":"First topic"}]},
+  {"tStartMs":65000,"segs":[{"utf8":"Second topic"}]}
+]}"#;
+
+/// A watch page offering `tracks`, whose captions are served from a
+/// second mock server.
+fn watch_page(tracks: &str) -> &'static str {
+    let (captions_url, _) = mock_server(vec![("200 OK", "", CAPTIONS)]);
+    let tracks = tracks.replace("CAPTIONS", &format!("{}/api/timedtext?v=x", captions_url));
+    let page = format!(
+        r#"<html><script>var ytInitialPlayerResponse = {{"videoDetails":{{"title":"Demo",
+        "author":"Demo Channel","lengthSeconds":"90","shortDescription":""}},
+        "captions":{{"playerCaptionsTracklistRenderer":{{"captionTracks":{}}}}}}};
+        </script></html>"#,
+        tracks
+    );
+    Box::leak(page.into_boxed_str())
+}
+
+#[test]
+fn splits_transcript_into_timestamped_windows() {
+    let page = watch_page(
+        r#"[{"languageCode":"en","kind":"asr","baseUrl":"CAPTIONS"},
+            {"languageCode":"de","baseUrl":"unused"}]"#,
+    );
+    let (url, _, heads) = recording_mock_server(vec![("200 OK", HTML, page)]);
+
+    let documents = YoutubeVideoLoader::new("https://youtu.be/dQw4w9WgXcQ?si=share")
+        .with_base_url(url)
+        .load()
+        .unwrap();
+
+    assert!(heads.lock().unwrap()[0].starts_with("get /watch?v=dqw4w9wgxcq "));
+    assert_eq!(documents.len(), 2);
+    assert_eq!(documents[0].content, "Welcome to the show First topic");
+    assert_eq!(documents[0].metadata["timestamp"], json!("0:00"));
+    assert_eq!(documents[0].metadata["end_timestamp"], json!("1:00"));
+    assert_eq!(documents[1].content, "Second topic");
+    assert_eq!(documents[1].metadata["start_seconds"], json!(60));
+    assert_eq!(documents[1].metadata["end_seconds"], json!(90));
+    assert_eq!(
+        documents[1].metadata["source"],
+        json!("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=60s")
+    );
+    assert_eq!(documents[1].metadata["title"], json!("Demo"));
+    assert_eq!(documents[1].metadata["channel"], json!("Demo Channel"));
+    assert_eq!(documents[1].metadata["auto_generated"], json!(true));
+    assert_eq!(
+        documents[1].metadata["transcript_note"],
+        json!("No creator 'en' captions; using auto-generated ones")
+    );
+}
+
+#[test]
+fn falls_back_to_another_language_with_a_note() {
+    let page = watch_page(r#"[{"languageCode":"de","baseUrl":"CAPTIONS"}]"#);
+    let (url, _) = mock_server(vec![("200 OK", HTML, page)]);
+
+    let documents = YoutubeVideoLoader::new("dQw4w9WgXcQ").with_base_url(url).load().unwrap();
+
+    assert_eq!(documents[0].metadata["transcript_language"], json!("de"));
+    assert_eq!(documents[0].metadata["auto_generated"], json!(false));
+    assert_eq!(
+        documents[0].metadata["transcript_note"],
+        json!("No 'en' transcript; using 'de' captions")
+    );
+}
+
+#[test]
+fn creator_captions_in_the_language_carry_no_note() {
+    let page = watch_page(r#"[{"languageCode":"en-GB","baseUrl":"CAPTIONS"}]"#);
+    let (url, _) = mock_server(vec![("200 OK", HTML, page)]);
+
+    let video = "https://www.youtube.com/watch?feature=share&v=dQw4w9WgXcQ";
+    let documents = YoutubeVideoLoader::new(video).with_base_url(url).load().unwrap();
+
+    assert_eq!(documents[0].metadata["transcript_language"], json!("en-GB"));
+    assert!(!documents[0].metadata.contains_key("transcript_note"));
+}
+
+#[test]
+fn video_without_captions_is_no_transcript() {
+    let page =
+        r#"<script>var ytInitialPlayerResponse = {"videoDetails":{"title":"Silent"}};</script>"#;
+    let (url, _) = mock_server(vec![("200 OK", HTML, page)]);
+
+    let err = YoutubeVideoLoader::new("dQw4w9WgXcQ").with_base_url(url).load().unwrap_err();
+
+    let missing = err.downcast_ref::<NoTranscript>().expect("NoTranscript");
+    assert_eq!(missing.video_id, "dQw4w9WgXcQ");
+}
+
+/// Load a video whose description is `description` and whose captions have
+/// one cue at each of `cue_seconds`, returning each document's chapter (or
+/// `""`), start and citation.
+fn chapters(description: &str, cue_seconds: &[u32]) -> Vec<(String, u64, String)> {
+    let events: Vec<_> = cue_seconds
+        .iter()
+        .map(|s| json!({"tStartMs": s * 1000, "segs": [{"utf8": format!("cue at {}", s)}]}))
+        .collect();
+    let captions = Box::leak(json!({ "events": events }).to_string().into_boxed_str());
+    let (captions_url, _) = mock_server(vec![("200 OK", "", captions)]);
+    let player = json!({
+        "videoDetails": {"title": "Demo", "author": "Demo Channel", "lengthSeconds": "90", "shortDescription": description},
+        "captions": {"playerCaptionsTracklistRenderer": {"captionTracks": [
+            {"languageCode": "en", "baseUrl": format!("{}/api/timedtext?v=x", captions_url)}
+        ]}}
+    });
+    let page = format!("<html><script>var ytInitialPlayerResponse = {};</script></html>", player);
+    let (url, _) = mock_server(vec![("200 OK", HTML, Box::leak(page.into_boxed_str()))]);
+
+    YoutubeVideoLoader::new("dQw4w9WgXcQ")
+        .with_base_url(url)
+        .load()
+        .unwrap()
+        .into_iter()
+        .map(|d| {
+            (
+                d.metadata.get("chapter").and_then(|c| c.as_str()).unwrap_or_default().to_string(),
+                d.metadata["start_seconds"].as_u64().unwrap(),
+                d.metadata["citation"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn titles_and_starts(chapters: &[(String, u64, String)]) -> Vec<(&str, u64)> {
+    chapters.iter().map(|(t, s, _)| (t.as_str(), *s)).collect()
+}
+
+#[test]
+fn chapters_from_mm_ss_timestamps() {
+    let found = chapters("Links below!\n\n0:00 Intro\n01:30 Setup\n14:05 Pricing deep-dive\n", &[5, 95, 850]);
+
+    assert_eq!(titles_and_starts(&found), [("Intro", 0), ("Setup", 90), ("Pricing deep-dive", 845)]);
+    assert_eq!(found[2].2, "Chapter: Pricing deep-dive (14:05)");
+}
+
+#[test]
+fn chapters_from_h_mm_ss_timestamps_mixed_with_mm_ss() {
+    let found = chapters("00:00 Welcome\n59:59 Almost an hour\n1:02:03 Overtime", &[1, 3600, 3724]);
+
+    assert_eq!(
+        titles_and_starts(&found),
+        [("Welcome", 0), ("Almost an hour", 3599), ("Overtime", 3723)]
+    );
+    assert_eq!(found[2].2, "Chapter: Overtime (1:02:03)");
+}
+
+#[test]
+fn chapters_with_unicode_dashes_brackets_and_trailing_timestamps() {
+    let description = "[0:00] \u{2013} Cold open\n(0:40) \u{2014} Why it matters\nQ&A \u{2015} 1:10\n1:50 - Wrap-up";
+
+    let found = chapters(description, &[0, 41, 71, 111]);
+
+    assert_eq!(
+        titles_and_starts(&found),
+        [("Cold open", 0), ("Why it matters", 40), ("Q&A", 70), ("Wrap-up", 110)]
+    );
+}
+
+#[test]
+fn several_chapters_on_one_line() {
+    let found = chapters("Chapters: 0:00 Intro | 1:15 Demo \u{2022} 2:40 Outro 3:05 Bloopers", &[2, 76, 161, 186]);
+
+    assert_eq!(
+        titles_and_starts(&found),
+        [("Intro", 0), ("Demo", 75), ("Outro", 160), ("Bloopers", 185)]
+    );
+}
+
+#[test]
+fn speech_before_the_first_chapter_is_untitled() {
+    let found = chapters("0:20 Main part\n1:00 End", &[3, 25, 61]);
+
+    assert_eq!(titles_and_starts(&found), [("", 0), ("Main part", 20), ("End", 60)]);
+    assert_eq!(found[0].2, "0:00\u{2013}0:20");
+}
+
+#[test]
+fn stray_or_unordered_times_fall_back_to_windows() {
+    for description in [
+        "Live at 8:30 tonight, see you there",
+        "5:00 Second\n1:00 First",
+        "build v1:20 and 12:345 are not timestamps",
+    ] {
+        let found = chapters(description, &[5, 70]);
+
+        assert_eq!(titles_and_starts(&found), [("", 0), ("", 60)], "{}", description);
+    }
+}
@@ -0,0 +1,83 @@
+//! YoutubeVideoSearchTool deep links and missing transcripts against a mock
+//! watch page.
+
+#![cfg(feature = "search")]
+
+mod common;
+
+use common::{args, mock_server};
+use crewai_tools::{NoTranscript, YoutubeVideoSearchTool};
+use serde_json::json;
+
+const HTML: &str = "Content-Type: text/html\r\n";
+
+/// A watch page with one English track whose captions, `captions`, are
+/// served from a second mock server.
+fn watch_page(captions: &'static str) -> &'static str {
+    let (captions_url, _) = mock_server(vec![("200 OK", "", captions)]);
+    let page = format!(
+        r#"<html><script>var ytInitialPlayerResponse = {{"videoDetails":{{"title":"Demo",
+        "author":"Demo Channel","lengthSeconds":"90","shortDescription":""}},
+        "captions":{{"playerCaptionsTracklistRenderer":{{"captionTracks":[
+        {{"baseUrl":"{}/api/timedtext?v=x","languageCode":"en"}}]}}}}}};
+        </script></html>"#,
+        captions_url
+    );
+    Box::leak(page.into_boxed_str())
+}
+
+fn search(page: &'static str) -> Result<serde_json::Value, anyhow::Error> {
+    let (url, _) = mock_server(vec![("200 OK", HTML, page)]);
+    YoutubeVideoSearchTool::new().with_base_url(url).run(args(&[
+        ("search_query", json!("topic")),
+        ("video_url", json!("https://youtu.be/dQw4w9WgXcQ")),
+    ]))
+}
+
+#[test]
+fn matching_cues_get_deep_links() {
+    let captions = r#"{"events":[
+      {"tStartMs":0,"segs":[{"utf8":"Welcome"}]},
+      {"tStartMs":30000,"segs":[{"utf8":"First topic"}]},
+      {"tStartMs":65000,"segs":[{"utf8":"Second topic"}]}
+    ]}"#;
+
+    let result = search(watch_page(captions)).unwrap();
+
+    assert_eq!(result["video_id"], "dQw4w9WgXcQ");
+    assert_eq!(result["segmentation"], "window");
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["results"][0]["url"], "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=30s");
+    assert_eq!(result["results"][1]["hits"][0]["timestamp"], "1:05");
+}
+
+#[test]
+fn video_without_caption_tracks_is_no_transcript() {
+    let page =
+        r#"<script>var ytInitialPlayerResponse = {"videoDetails":{"title":"Silent"}};</script>"#;
+
+    let err = search(page).unwrap_err();
+
+    let missing = err.downcast_ref::<NoTranscript>().expect("NoTranscript");
+    assert_eq!(missing.video_id, "dQw4w9WgXcQ");
+    assert_eq!(err.to_string(), "No transcript available for YouTube video dQw4w9WgXcQ");
+}
+
+#[test]
+fn an_empty_caption_track_is_no_transcript() {
+    let err = search(watch_page(r#"{"events":[]}"#)).unwrap_err();
+
+    assert!(err.downcast_ref::<NoTranscript>().is_some(), "{}", err);
+}
+
+#[test]
+fn other_failures_are_not_no_transcript() {
+    let (url, _) = mock_server(vec![("500 Internal Server Error", HTML, "oops")]);
+
+    let err = YoutubeVideoSearchTool::new()
+        .with_base_url(url)
+        .run(args(&[("search_query", json!("x")), ("video_url", json!("dQw4w9WgXcQ"))]))
+        .unwrap_err();
+
+    assert!(err.downcast_ref::<NoTranscript>().is_none());
+}
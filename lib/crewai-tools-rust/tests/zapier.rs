@@ -0,0 +1,125 @@
+//! ZapierActionTools and the ZapierActionTool adapter against a scripted
+//! NLA API.
+
+#![cfg(feature = "automation")]
+
+mod common;
+
+use std::collections::HashMap;
+
+use common::{args, body_recording_mock_server, recording_mock_server};
+use crewai_tools::{ZapierActionTool, ZapierActionTools};
+use serde_json::{json, Value};
+
+const EXPOSED: common::Scripted = (
+    "200 OK",
+    "",
+    r#"{"results": [
+        {"id": "01GMAIL", "operation_id": "gmail_send", "description": "Gmail: Send Email", "params": {"to": "To"}},
+        {"id": "01SLACK", "operation_id": "slack_post", "description": "Slack: Send Message", "params": {"channel": "Channel"}}
+    ]}"#,
+);
+
+const PREVIEW: common::Scripted = (
+    "200 OK",
+    "",
+    r##"{"id": "exec-1", "status": "preview", "review_url": "https://nla.zapier.com/execution/exec-1/",
+        "input_params": {"channel": "#ops"}, "result": null}"##,
+);
+
+#[test]
+fn listing_is_filtered_by_allowed_actions() {
+    let (url, _, requests) = recording_mock_server(vec![EXPOSED]);
+
+    let result = ZapierActionTools::new()
+        .with_base_url(&url)
+        .with_api_key("nla-key")
+        .with_allowed_actions(vec!["01SLACK".to_string()])
+        .run(HashMap::new())
+        .unwrap();
+
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["actions"][0]["description"], "Slack: Send Message");
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("get /exposed/ "), "{}", head);
+    assert!(head.contains("x-api-key: nla-key"), "{}", head);
+}
+
+#[test]
+fn preview_execution_returns_the_review_url() {
+    let (url, _, requests) = recording_mock_server(vec![PREVIEW]);
+
+    let result = ZapierActionTools::new()
+        .with_base_url(&url)
+        .with_api_key("k")
+        .run(args(&[
+            ("action_id", json!("01SLACK")),
+            ("instructions", json!("Tell #ops the deploy finished")),
+            ("preview", json!(true)),
+        ]))
+        .unwrap();
+
+    assert_eq!(result["status"], "preview");
+    assert_eq!(result["review_url"], "https://nla.zapier.com/execution/exec-1/");
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /exposed/01slack/execute/?preview_only=true "), "{}", head);
+}
+
+#[test]
+fn disallowed_action_is_refused_before_any_request() {
+    let err = ZapierActionTools::new()
+        .with_base_url("http://127.0.0.1:9")
+        .with_api_key("k")
+        .with_allowed_actions(vec!["01SLACK".to_string()])
+        .run(args(&[("action_id", json!("01GMAIL")), ("instructions", json!("x"))]))
+        .unwrap_err();
+    assert!(err.to_string().contains("not in allowed_actions"), "{}", err);
+}
+
+#[test]
+fn adapter_shares_the_client_and_reports_failures_with_review_url() {
+    let failed = (
+        "200 OK",
+        "",
+        r#"{"status": "error", "error": "Channel not found", "review_url": "https://nla.zapier.com/execution/exec-2/"}"#,
+    );
+    let (url, bodies) = body_recording_mock_server(vec![failed]);
+
+    let err = ZapierActionTool::new()
+        .with_base_url(url)
+        .with_api_key("k")
+        .with_action_id("01SLACK")
+        .run(args(&[
+            ("instructions", json!("Post hello")),
+            ("params", json!({"channel": "#nope"})),
+        ]))
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.contains("Channel not found"), "{}", err);
+    assert!(err.contains("execution/exec-2/"), "{}", err);
+    let body: Value = serde_json::from_str(&bodies.lock().unwrap()[0]).unwrap();
+    assert_eq!(body, json!({"instructions": "Post hello", "channel": "#nope"}));
+}
+
+#[test]
+fn action_tool_reuses_the_key_and_api_root() {
+    let (url, _, requests) = recording_mock_server(vec![PREVIEW]);
+    let tools = ZapierActionTools::new()
+        .with_base_url(&url)
+        .with_api_key("nla-key")
+        .with_allowed_actions(vec!["01SLACK".to_string()]);
+
+    let slack = tools.action_tool("01SLACK").unwrap();
+    let result = slack
+        .run(args(&[("instructions", json!("Tell #ops")), ("preview", json!(true))]))
+        .unwrap();
+
+    assert_eq!(result["status"], "preview");
+    let head = &requests.lock().unwrap()[0];
+    assert!(head.starts_with("post /exposed/01slack/execute/?preview_only=true "), "{}", head);
+    assert!(head.contains("x-api-key: nla-key"), "{}", head);
+
+    let err = tools.action_tool("01GMAIL").unwrap_err();
+    assert!(err.to_string().contains("not in allowed_actions"), "{}", err);
+}